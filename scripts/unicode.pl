@@ -0,0 +1,568 @@
+#!/usr/bin/env perl
+#
+# Generates the Unicode property tables in `src/tables` from the Unicode
+# Character Database bundled with Perl, so the crate does not depend on
+# the Unicode version of the Rust toolchain.
+#
+# Usage (from the repository root):
+#
+#   perl scripts/unicode.pl
+
+use strict;
+use warnings;
+
+use File::Basename qw(dirname);
+use File::Spec;
+use Unicode::Normalize qw(getCanon);
+use Unicode::UCD qw(all_casefolds charinfo prop_invmap prop_value_aliases search_invlist);
+
+my $ROOT = File::Spec->catdir(dirname(__FILE__), '..');
+my $TABLES = File::Spec->catdir($ROOT, 'src', 'tables');
+my $VERSION = Unicode::UCD::UnicodeVersion();
+
+# HELPERS
+
+# Convert a property value name to a Rust identifier.
+sub camel_case {
+    my ($name) = @_;
+    my $ident = join '', map { ucfirst lc } grep { length } split /[^A-Za-z0-9]+/, $name;
+    $ident = "_$ident" if $ident =~ /^[0-9]/;
+    return $ident;
+}
+
+# Get the inclusive `[start, end, value]` ranges of an enumerated property,
+# omitting the property's default value. An optional `$rename` maps
+# property values before adjacent ranges are merged.
+sub enum_ranges {
+    my ($prop, $rename) = @_;
+    my ($list, $map, $format, $default) = prop_invmap($prop);
+    die "unknown property $prop\n" unless defined $list;
+    $rename ||= sub { $_[0] };
+    my @ranges;
+    for my $i (0 .. $#$list) {
+        my $value = $rename->($map->[$i]);
+        next if $value eq $default;
+        my $end = $i < $#$list ? $list->[$i + 1] - 1 : 0x10FFFF;
+        if (@ranges && $ranges[-1][1] + 1 == $list->[$i] && $ranges[-1][2] eq $value) {
+            $ranges[-1][1] = $end;
+        } else {
+            push @ranges, [$list->[$i], $end, $value];
+        }
+    }
+    return (\@ranges, $default);
+}
+
+# Get the inclusive `[start, end]` ranges of a binary property.
+sub bool_ranges {
+    my ($prop) = @_;
+    my ($ranges) = enum_ranges($prop);
+    return [map { [$_->[0], $_->[1]] } grep { $_->[2] eq 'Y' } @$ranges];
+}
+
+# Sorted, unique values of a property, including the default.
+sub enum_values {
+    my ($ranges, $default) = @_;
+    my %seen = ($default => 1);
+    $seen{$_->[2]} = 1 for @$ranges;
+    return sort keys %seen;
+}
+
+sub open_table {
+    my ($name) = @_;
+    my $path = File::Spec->catfile($TABLES, "$name.rs");
+    open my $fh, '>', $path or die "cannot write $path: $!\n";
+    print $fh "// Generated by `scripts/unicode.pl` from the Unicode $VERSION\n";
+    print $fh "// character database. Do not edit by hand.\n\n";
+    return $fh;
+}
+
+sub write_bool_table {
+    my ($fh, $const, $ranges) = @_;
+    print $fh "pub(crate) const $const: &[(u32, u32)] = &[\n";
+    printf $fh "    (0x%04X, 0x%04X),\n", @$_ for @$ranges;
+    print $fh "];\n";
+}
+
+# Write a sorted table mapping single code points to single code points.
+sub write_map_table {
+    my ($fh, $const, $pairs) = @_;
+    print $fh "pub(crate) const $const: &[(u32, u32)] = &[\n";
+    printf $fh "    (0x%04X, 0x%04X),\n", @$_ for @$pairs;
+    print $fh "];\n";
+}
+
+# Write a sorted table mapping single code points to strings.
+sub write_str_table {
+    my ($fh, $const, $pairs) = @_;
+    print $fh "pub(crate) const $const: &[(u32, &str)] = &[\n";
+    for my $pair (@$pairs) {
+        my $string = join '', map { sprintf '\\u{%X}', $_ } @{$pair->[1]};
+        printf $fh "    (0x%04X, \"%s\"),\n", $pair->[0], $string;
+    }
+    print $fh "];\n";
+}
+
+# Write an enumerated property as a Rust enum and a range table.
+#   `ident` optionally maps property values to variant names,
+#   `describe` property values to variant documentation, `rename`
+#   property values to the values to write, and `adjust` the ranges
+#   before they are written.
+sub write_enum_table {
+    my ($fh, %args) = @_;
+    my ($ranges, $default) = enum_ranges($args{prop}, $args{rename});
+    $ranges = $args{adjust}->($ranges) if $args{adjust};
+    my $ident = $args{ident} || \&camel_case;
+    my $describe = $args{describe} || sub { my ($name) = @_; $name =~ tr/_/ /; $name };
+    my @values = enum_values($ranges, $default);
+
+    print $fh length $_ ? "/// $_\n" : "///\n" for @{$args{doc}};
+    print $fh "#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]\n";
+    print $fh "pub enum $args{enum} {\n";
+    for my $value (@values) {
+        print $fh "    /// ", $describe->($value), "\n";
+        print $fh "    ", $ident->($value), ",\n";
+    }
+    print $fh "}\n\n";
+
+    printf $fh "impl Default for %s {\n", $args{enum};
+    print $fh "    #[inline]\n";
+    print $fh "    fn default() -> Self {\n";
+    printf $fh "        %s::%s\n", $args{enum}, $ident->($default);
+    print $fh "    }\n";
+    print $fh "}\n\n";
+
+    printf $fh "pub(crate) const %s: &[(u32, u32, %s)] = &[\n", $args{const}, $args{enum};
+    for my $range (@$ranges) {
+        printf $fh "    (0x%04X, 0x%04X, %s::%s),\n",
+            $range->[0], $range->[1], $args{enum}, $ident->($range->[2]);
+    }
+    print $fh "];\n";
+}
+
+# TABLES
+
+{
+    my $fh = open_table('general_category');
+    write_enum_table($fh,
+        prop => 'General_Category',
+        enum => 'GeneralCategory',
+        const => 'GENERAL_CATEGORY',
+        doc => [
+            'The Unicode `General_Category` property of a character.',
+            '',
+            'Variants use the short property value aliases, such as `Lu` for',
+            'an uppercase letter.',
+        ],
+        ident => sub { $_[0] },
+        describe => sub {
+            my (undef, $name) = prop_value_aliases('gc', $_[0]);
+            $name =~ tr/_/ /;
+            return $name;
+        },
+    );
+    close $fh;
+}
+
+{
+    my $fh = open_table('script');
+    write_enum_table($fh,
+        prop => 'Script',
+        enum => 'Script',
+        const => 'SCRIPT',
+        doc => ['The Unicode `Script` property of a character.'],
+    );
+
+    # Script extensions, only where they differ from the script.
+    my ($sc_list, $sc_map) = prop_invmap('Script');
+    my ($scx_list, $scx_map) = prop_invmap('Script_Extensions');
+    my %bounds = map { $_ => 1 } @$sc_list, @$scx_list;
+    my @starts = sort { $a <=> $b } keys %bounds;
+    my @extensions;
+    for my $i (0 .. $#starts) {
+        my $end = $i < $#starts ? $starts[$i + 1] - 1 : 0x10FFFF;
+        my $script = $sc_map->[search_invlist($sc_list, $starts[$i])];
+        my $extension = $scx_map->[search_invlist($scx_list, $starts[$i])];
+        my @scripts = ref $extension ? @$extension : ($extension);
+        next if @scripts == 1 && $scripts[0] eq $script;
+        my $value = join ', ', map { 'Script::' . camel_case($_) } @scripts;
+        if (@extensions && $extensions[-1][1] + 1 == $starts[$i] && $extensions[-1][2] eq $value) {
+            $extensions[-1][1] = $end;
+        } else {
+            push @extensions, [$starts[$i], $end, $value];
+        }
+    }
+    print $fh "\n#[cfg(feature = \"alloc\")]\npub(crate) const SCRIPT_EXTENSIONS: &[(u32, u32, &[Script])] = &[\n";
+    printf $fh "    (0x%04X, 0x%04X, &[%s]),\n", @$_ for @extensions;
+    print $fh "];\n";
+    close $fh;
+}
+
+{
+    my $fh = open_table('block');
+    write_enum_table($fh,
+        prop => 'Block',
+        enum => 'Block',
+        const => 'BLOCK',
+        doc => [
+            'The Unicode `Block` property of a character.',
+            '',
+            'Blocks are contiguous ranges, so unassigned code points inside an',
+            'allocated block still belong to it.',
+        ],
+    );
+    close $fh;
+}
+
+{
+    # Wide and narrow compatibility decompositions all map a single
+    # code point to a single code point.
+    my ($ranges) = enum_ranges('Decomposition_Type');
+    my @pairs;
+    for my $range (grep { $_->[2] =~ /^(wide|narrow)$/ } @$ranges) {
+        for my $cp ($range->[0] .. $range->[1]) {
+            my $info = charinfo($cp) or next;
+            my ($target) = $info->{decomposition} =~ /^<(?:wide|narrow)> ([0-9A-F]+)$/
+                or die sprintf("unexpected decomposition for U+%04X\n", $cp);
+            push @pairs, [$cp, hex $target];
+        }
+    }
+    my $fh = open_table('width');
+    write_map_table($fh, 'WIDTH_FOLDING', \@pairs);
+    close $fh;
+}
+
+{
+    my $fh = open_table('white_space');
+    write_bool_table($fh, 'WHITE_SPACE', bool_ranges('White_Space'));
+    print $fh "\n";
+    write_bool_table($fh, 'DEFAULT_IGNORABLE', bool_ranges('Default_Ignorable_Code_Point'));
+    close $fh;
+}
+
+{
+    # Decimal digits are encoded in contiguous runs from zero to nine, so
+    # the value of a digit is its offset from the start of its range,
+    # modulo 10.
+    my ($ranges) = enum_ranges('General_Category');
+    my @digits = map { [$_->[0], $_->[1]] } grep { $_->[2] eq 'Nd' } @$ranges;
+    for my $range (@digits) {
+        die sprintf("unaligned decimal digits at U+%04X\n", $range->[0])
+            if ($range->[1] - $range->[0] + 1) % 10;
+    }
+    my $fh = open_table('decimal');
+    write_bool_table($fh, 'DECIMAL_DIGIT', \@digits);
+    close $fh;
+}
+
+{
+    # Full canonical decompositions, except for Hangul syllables, which
+    # are decomposed algorithmically.
+    my ($ranges) = enum_ranges('Decomposition_Type');
+    my @pairs;
+    for my $range (grep { $_->[2] eq 'Canonical' } @$ranges) {
+        for my $cp ($range->[0] .. $range->[1]) {
+            next if $cp >= 0xAC00 && $cp <= 0xD7A3;
+            my $canon = getCanon($cp);
+            push @pairs, [$cp, [map { ord } split //, $canon]] if defined $canon;
+        }
+    }
+    my ($classes) = enum_ranges('Canonical_Combining_Class');
+
+    # Primary composites: canonical pairs that are not excluded from
+    # composition, sorted by the pair.
+    my %excluded = map { $_ => 1 } map { $_->[0] .. $_->[1] } @{bool_ranges('Full_Composition_Exclusion')};
+    my @compositions;
+    for my $range (grep { $_->[2] eq 'Canonical' } @$ranges) {
+        for my $cp ($range->[0] .. $range->[1]) {
+            next if $excluded{$cp} || ($cp >= 0xAC00 && $cp <= 0xD7A3);
+            my @pair = map { hex } split / /, charinfo($cp)->{decomposition};
+            push @compositions, [@pair, $cp] if @pair == 2;
+        }
+    }
+    @compositions = sort { $a->[0] <=> $b->[0] || $a->[1] <=> $b->[1] } @compositions;
+
+    my ($quick_check) = enum_ranges('NFC_Quick_Check');
+    my @quick_check_no = map { [$_->[0], $_->[1]] } grep { $_->[2] eq 'N' } @$quick_check;
+    my @quick_check_maybe = map { [$_->[0], $_->[1]] } grep { $_->[2] eq 'M' } @$quick_check;
+
+    my $fh = open_table('normalization');
+    write_str_table($fh, 'CANONICAL_DECOMPOSITION', \@pairs);
+    print $fh "\npub(crate) const COMBINING_CLASS: &[(u32, u32, u8)] = &[\n";
+    printf $fh "    (0x%04X, 0x%04X, %d),\n", @$_ for @$classes;
+    print $fh "];\n";
+    print $fh "\npub(crate) const COMPOSITION: &[(u32, u32, u32)] = &[\n";
+    printf $fh "    (0x%04X, 0x%04X, 0x%04X),\n", @$_ for @compositions;
+    print $fh "];\n";
+    print $fh "\n";
+    write_bool_table($fh, 'NFC_QUICK_CHECK_NO', \@quick_check_no);
+    print $fh "\n";
+    write_bool_table($fh, 'NFC_QUICK_CHECK_MAYBE', \@quick_check_maybe);
+    close $fh;
+}
+
+{
+    my $path = File::Spec->catfile($ROOT, 'scripts', 'data', 'confusables.txt');
+    open my $in, '<:encoding(UTF-8)', $path or die "cannot read $path: $!\n";
+    my (@pairs, $data_version);
+    while (my $line = <$in>) {
+        $data_version = $1 if $line =~ /^#\s*Version:\s*(\S+)/;
+        $line =~ s/#.*//;
+        next unless $line =~ /^\s*([0-9A-F]+)\s*;\s*([0-9A-F ]+?)\s*;/;
+        push @pairs, [hex $1, [map { hex } split / /, $2]];
+    }
+    close $in;
+    @pairs = sort { $a->[0] <=> $b->[0] } @pairs;
+
+    die "no version in $path\n" unless defined $data_version;
+
+    my $fh = open_table('confusables');
+    print $fh "// The mappings are from `confusables.txt` version $data_version.\n\n";
+    write_str_table($fh, 'CONFUSABLES', \@pairs);
+    close $fh;
+}
+
+{
+    my $fh = open_table('cased');
+    write_bool_table($fh, 'CASED', bool_ranges('Cased'));
+    close $fh;
+}
+
+{
+    # Simple case folding maps single code points to single code points,
+    # from the `C` and `S` entries of `CaseFolding.txt`.
+    my $folds = all_casefolds();
+    my @pairs;
+    for my $cp (sort { $a <=> $b } keys %$folds) {
+        my $simple = $folds->{$cp}{simple};
+        push @pairs, [$cp, hex $simple] if length $simple;
+    }
+
+    # Full case folding adds the `F` entries, which expand to several
+    # code points, such as U+00DF to "ss".
+    my @full;
+    for my $cp (sort { $a <=> $b } keys %$folds) {
+        my @mapping = map { hex } split ' ', $folds->{$cp}{full};
+        push @full, [$cp, \@mapping] if @mapping > 1;
+    }
+
+    my $fh = open_table('case_folding');
+    write_map_table($fh, 'SIMPLE_CASE_FOLDING', \@pairs);
+    print $fh "\n";
+    write_str_table($fh, 'FULL_CASE_FOLDING', \@full);
+    close $fh;
+}
+
+{
+    # The emoji properties of `emoji-data.txt` are versioned with Unicode.
+    my $fh = open_table('emoji');
+    write_bool_table($fh, 'EMOJI', bool_ranges('Emoji'));
+    print $fh "\n";
+    write_bool_table($fh, 'EMOJI_PRESENTATION', bool_ranges('Emoji_Presentation'));
+    print $fh "\n";
+    write_bool_table($fh, 'EMOJI_MODIFIER', bool_ranges('Emoji_Modifier'));
+    print $fh "\n";
+    write_bool_table($fh, 'EMOJI_MODIFIER_BASE', bool_ranges('Emoji_Modifier_Base'));
+    close $fh;
+}
+
+{
+    # Identifier properties from UAX #31, and their NFKC closures.
+    my $fh = open_table('xid');
+    write_bool_table($fh, 'XID_START', bool_ranges('XID_Start'));
+    print $fh "\n";
+    write_bool_table($fh, 'XID_CONTINUE', bool_ranges('XID_Continue'));
+    print $fh "\n";
+    write_bool_table($fh, 'ID_START', bool_ranges('ID_Start'));
+    print $fh "\n";
+    write_bool_table($fh, 'ID_CONTINUE', bool_ranges('ID_Continue'));
+    close $fh;
+}
+
+{
+    my $fh = open_table('joining_type');
+    write_enum_table($fh,
+        prop => 'Joining_Type',
+        enum => 'JoiningType',
+        const => 'JOINING_TYPE',
+        doc => [
+            'The Unicode `Joining_Type` property of a character.',
+            '',
+            'Variants use the short property value aliases from',
+            '`ArabicShaping.txt`, such as `D` for a dual joining character.',
+        ],
+        ident => sub { (prop_value_aliases('jt', $_[0]))[0] },
+        describe => sub {
+            my (undef, $name) = prop_value_aliases('jt', $_[0]);
+            $name =~ tr/_/ /;
+            return $name;
+        },
+    );
+    close $fh;
+}
+
+{
+    # Perl folds `Extended_Pictographic` into `Grapheme_Cluster_Break` as
+    # `ExtPict_XX`, which is `Other` in the character database.
+    my $fh = open_table('grapheme');
+    write_enum_table($fh,
+        prop => 'Grapheme_Cluster_Break',
+        enum => 'GraphemeClusterBreak',
+        const => 'GRAPHEME_CLUSTER_BREAK',
+        doc => ['The Unicode `Grapheme_Cluster_Break` property of a character.'],
+        ident => sub { $_[0] eq 'SpacingMark' ? $_[0] : camel_case($_[0]) },
+        rename => sub { $_[0] eq 'ExtPict_XX' ? 'Other' : $_[0] },
+    );
+    print $fh "\n";
+    write_bool_table($fh, 'EXTENDED_PICTOGRAPHIC', bool_ranges('Extended_Pictographic'));
+    close $fh;
+}
+
+{
+    # Immutable syntax properties from UAX #31, for operators and spacing.
+    my $fh = open_table('pattern');
+    write_bool_table($fh, 'PATTERN_SYNTAX', bool_ranges('Pattern_Syntax'));
+    print $fh "\n";
+    write_bool_table($fh, 'PATTERN_WHITE_SPACE', bool_ranges('Pattern_White_Space'));
+    close $fh;
+}
+
+{
+    # The derived `Math` property, for formula tokenizers.
+    my $fh = open_table('math');
+    write_bool_table($fh, 'MATH', bool_ranges('Math'));
+    close $fh;
+}
+
+{
+    # Punctuation properties from `PropList.txt`, for typographic text.
+    my $fh = open_table('punctuation');
+    write_bool_table($fh, 'DASH', bool_ranges('Dash'));
+    print $fh "\n";
+    write_bool_table($fh, 'QUOTATION_MARK', bool_ranges('Quotation_Mark'));
+    print $fh "\n";
+    write_bool_table($fh, 'TERMINAL_PUNCTUATION', bool_ranges('Terminal_Punctuation'));
+    close $fh;
+}
+
+{
+    # `Numeric_Value` as reduced rationals. Perl adjusts integer values
+    # across a range, so expand each range before merging equal values.
+    my ($list, $map) = prop_invmap('Numeric_Value');
+    my @ranges;
+    for my $i (0 .. $#$list - 1) {
+        my $value = $map->[$i];
+        next if $value eq 'NaN';
+        for my $cp ($list->[$i] .. $list->[$i + 1] - 1) {
+            my ($numerator, $denominator) = $value =~ m{^(-?\d+)(?:/(\d+))?$}
+                or die "unexpected numeric value $value";
+            $denominator //= 1;
+            $numerator += ($cp - $list->[$i]) * $denominator if $value !~ m{/};
+            if (@ranges && $ranges[-1][1] == $cp - 1
+                && $ranges[-1][2] == $numerator && $ranges[-1][3] == $denominator) {
+                $ranges[-1][1] = $cp;
+            } else {
+                push @ranges, [$cp, $cp, $numerator, $denominator];
+            }
+        }
+    }
+
+    my $fh = open_table('numeric');
+    print $fh "pub(crate) const NUMERIC_VALUE: &[(u32, u32, (i64, u32))] = &[\n";
+    printf $fh "    (0x%04X, 0x%04X, (%s, %s)),\n", @$_ for @ranges;
+    print $fh "];\n";
+    close $fh;
+}
+
+{
+    # Perl tailors `Word_Break`: it folds `Extended_Pictographic` into the
+    # values as `ExtPict_LE` and `ExtPict_XX`, and replaces `WSegSpace`
+    # with all horizontal space. Undo both, since `WSegSpace` is the space
+    # separators other than the no-break spaces.
+    my %not_wseg = map { $_ => 1 } (0x0009, 0x00A0, 0x2007);
+    my $fh = open_table('word_break');
+    write_enum_table($fh,
+        prop => 'Word_Break',
+        enum => 'WordBreak',
+        const => 'WORD_BREAK',
+        doc => ['The Unicode `Word_Break` property of a character.'],
+        ident => sub { $_[0] =~ /[a-z]/ ? $_[0] =~ s/_//gr : camel_case($_[0]) },
+        rename => sub {
+            my ($value) = @_;
+            return 'ALetter' if $value eq 'ExtPict_LE';
+            return 'Other' if $value eq 'ExtPict_XX';
+            return 'WSegSpace' if $value eq 'Perl_Tailored_HSpace';
+            return $value;
+        },
+        adjust => sub {
+            my ($ranges) = @_;
+            my @adjusted;
+            for my $range (@$ranges) {
+                my ($start, $end, $value) = @$range;
+                if ($value ne 'WSegSpace') {
+                    push @adjusted, $range;
+                    next;
+                }
+                for my $cp ($start .. $end) {
+                    next if $not_wseg{$cp};
+                    if (@adjusted && $adjusted[-1][1] + 1 == $cp && $adjusted[-1][2] eq $value) {
+                        $adjusted[-1][1] = $cp;
+                    } else {
+                        push @adjusted, [$cp, $cp, $value];
+                    }
+                }
+            }
+            return \@adjusted;
+        },
+    );
+    close $fh;
+}
+
+{
+    my $fh = open_table('sentence_break');
+    write_enum_table($fh,
+        prop => 'Sentence_Break',
+        enum => 'SentenceBreak',
+        const => 'SENTENCE_BREAK',
+        doc => ['The Unicode `Sentence_Break` property of a character.'],
+        ident => sub { $_[0] =~ /[a-z]/ ? $_[0] =~ s/_//gr : camel_case($_[0]) },
+    );
+    close $fh;
+}
+
+{
+    my $fh = open_table('line_break');
+    write_enum_table($fh,
+        prop => 'Line_Break',
+        enum => 'LineBreakClass',
+        const => 'LINE_BREAK',
+        doc => [
+            'The Unicode `Line_Break` property of a character.',
+            '',
+            'Variants use the short property value aliases, such as `BA` for',
+            'break after.',
+        ],
+        ident => sub { (prop_value_aliases('lb', $_[0]))[0] },
+        describe => sub {
+            my (undef, $name) = prop_value_aliases('lb', $_[0]);
+            $name =~ tr/_/ /;
+            return $name;
+        },
+    );
+    close $fh;
+}
+
+{
+    my $fh = open_table('east_asian_width');
+    write_enum_table($fh,
+        prop => 'East_Asian_Width',
+        enum => 'EastAsianWidth',
+        const => 'EAST_ASIAN_WIDTH',
+        doc => ['The Unicode `East_Asian_Width` property of a character.'],
+        ident => sub { (prop_value_aliases('ea', $_[0]))[0] },
+        describe => sub {
+            my (undef, $name) = prop_value_aliases('ea', $_[0]);
+            return $name;
+        },
+    );
+    close $fh;
+}
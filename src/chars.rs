@@ -0,0 +1,337 @@
+//! Character slices as parser inputs.
+//!
+//! Nom only implements its input traits for `&str` and `&[u8]`, so a
+//! pre-tokenized `&[char]` cannot be parsed directly. `CharSlice` wraps
+//! the slice and implements the input traits, so every parser in this
+//! crate accepts it, returning sub-slices of the original characters.
+//!
+//! Slices of raw code points, such as `u32` values from a binary format,
+//! can hold surrogates or values above U+10FFFF. Wrapping each value in
+//! `CodePoint` makes them usable as items: invalid values never match a
+//! character class, and can be consumed with `invalid_scalar0/1`.
+
+use core::iter::{Copied, Enumerate};
+use core::ops::{Deref, Range, RangeFrom, RangeFull, RangeTo};
+use core::slice;
+use nom::{AsChar, InputIter, InputLength, InputTake, Needed, Offset, Slice, UnspecializedInput};
+use crate::IsChar;
+
+/// A slice of characters usable as parser input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CharSlice<'a, C = char>(pub &'a [C]);
+
+impl<'a, C> CharSlice<'a, C> {
+    /// Get the wrapped slice.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [C] {
+        self.0
+    }
+}
+
+impl<'a, C> From<&'a [C]> for CharSlice<'a, C> {
+    #[inline]
+    fn from(slice: &'a [C]) -> Self {
+        CharSlice(slice)
+    }
+}
+
+impl<'a, C> Deref for CharSlice<'a, C> {
+    type Target = [C];
+
+    #[inline]
+    fn deref(&self) -> &[C] {
+        self.0
+    }
+}
+
+impl<'a, C> InputLength for CharSlice<'a, C> {
+    #[inline]
+    fn input_len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<'a, C: Copy> InputIter for CharSlice<'a, C> {
+    type Item = C;
+    type Iter = Enumerate<Self::IterElem>;
+    type IterElem = Copied<slice::Iter<'a, C>>;
+
+    #[inline]
+    fn iter_indices(&self) -> Self::Iter {
+        self.iter_elements().enumerate()
+    }
+
+    #[inline]
+    fn iter_elements(&self) -> Self::IterElem {
+        self.0.iter().copied()
+    }
+
+    #[inline]
+    fn position<P>(&self, predicate: P) -> Option<usize>
+        where P: Fn(Self::Item) -> bool
+    {
+        self.0.iter().position(|&c| predicate(c))
+    }
+
+    #[inline]
+    fn slice_index(&self, count: usize) -> Result<usize, Needed> {
+        if self.0.len() >= count {
+            Ok(count)
+        } else {
+            Err(Needed::new(count - self.0.len()))
+        }
+    }
+}
+
+impl<'a, C> InputTake for CharSlice<'a, C> {
+    #[inline]
+    fn take(&self, count: usize) -> Self {
+        CharSlice(&self.0[..count])
+    }
+
+    #[inline]
+    fn take_split(&self, count: usize) -> (Self, Self) {
+        let (prefix, suffix) = self.0.split_at(count);
+        (CharSlice(suffix), CharSlice(prefix))
+    }
+}
+
+impl<'a, C> UnspecializedInput for CharSlice<'a, C> {
+}
+
+impl<'a, C> Offset for CharSlice<'a, C> {
+    #[inline]
+    fn offset(&self, second: &Self) -> usize {
+        let size = core::mem::size_of::<C>().max(1);
+        (second.0.as_ptr() as usize - self.0.as_ptr() as usize) / size
+    }
+}
+
+/// A raw code point, which may not be a Unicode scalar value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CodePoint(pub u32);
+
+impl CodePoint {
+    /// Get the code point as a character, if it is a scalar value.
+    #[inline]
+    pub fn to_char(self) -> Option<char> {
+        core::char::from_u32(self.0)
+    }
+}
+
+impl From<char> for CodePoint {
+    #[inline]
+    fn from(c: char) -> Self {
+        CodePoint(c as u32)
+    }
+}
+
+// Invalid code points convert to U+FFFD REPLACEMENT CHARACTER, but are
+// never alphabetic or digits.
+impl AsChar for CodePoint {
+    #[inline]
+    fn as_char(self) -> char {
+        self.to_char().unwrap_or(core::char::REPLACEMENT_CHARACTER)
+    }
+
+    #[inline]
+    fn is_alpha(self) -> bool {
+        matches!(self.to_char(), Some(c) if c.is_alpha())
+    }
+
+    #[inline]
+    fn is_alphanum(self) -> bool {
+        matches!(self.to_char(), Some(c) if c.is_alphanum())
+    }
+
+    #[inline]
+    fn is_dec_digit(self) -> bool {
+        matches!(self.to_char(), Some(c) if c.is_dec_digit())
+    }
+
+    #[inline]
+    fn is_hex_digit(self) -> bool {
+        matches!(self.to_char(), Some(c) if c.is_hex_digit())
+    }
+
+    #[inline]
+    fn is_oct_digit(self) -> bool {
+        matches!(self.to_char(), Some(c) if c.is_oct_digit())
+    }
+
+    #[inline]
+    fn len(self) -> usize {
+        self.as_char().len_utf8()
+    }
+}
+
+impl IsChar for CodePoint {
+    #[inline(always)]
+    fn to_char(self) -> Option<char> {
+        core::char::from_u32(self.0)
+    }
+
+    #[inline(always)]
+    fn to_code_point(self) -> u32 {
+        self.0
+    }
+}
+
+macro_rules! slice_impl {
+    ($($range:ty)*) => ($(
+        impl<'a, C> Slice<$range> for CharSlice<'a, C> {
+            #[inline]
+            fn slice(&self, range: $range) -> Self {
+                CharSlice(&self.0[range])
+            }
+        }
+    )*);
+}
+
+slice_impl! {
+    Range<usize>
+    RangeTo<usize>
+    RangeFrom<usize>
+    RangeFull
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complete;
+    use nom::IResult;
+    use nom::error::Error as NError;
+    use nom::Err::Error;
+
+    const INPUTS: &[&str] = &[
+        "latin",
+        "latin123",
+        "LATIN123",
+        "123",
+        "erfüllen123",
+        "조선글123",
+        " \t\n\x08",
+        "\u{200b}",
+        "\u{1680}\u{200a}\u{2028}\u{202f}\u{205f}\u{3000}\u{200b}",
+        "\x00\x01\x02\u{80}",
+        "\u{94}\u{100}",
+        "ᠭ\u{180b}ᠠ བོད་སྐད",
+        "\u{e000}😀𐌰x",
+        "",
+    ];
+
+    // Check a parser gives the same split over characters as over the string.
+    fn check<Fs, Fc>(str_parser: Fs, char_parser: Fc)
+        where Fs: Fn(&str) -> IResult<&str, &str>,
+              Fc: Fn(CharSlice) -> IResult<CharSlice, CharSlice, NError<CharSlice>>
+    {
+        for input in INPUTS {
+            let chars: Vec<char> = input.chars().collect();
+            let expected = match str_parser(input) {
+                Ok((_, matched)) => Ok(matched.chars().count()),
+                Err(Error(e)) => Err(e.code),
+                Err(e) => panic!("unexpected error {:?}", e),
+            };
+            let actual = match char_parser(CharSlice(&chars)) {
+                Ok((rest, matched)) => {
+                    // The results must be sub-slices of the original.
+                    assert_eq!(matched.as_ptr(), chars.as_ptr());
+                    assert_eq!(matched.len() + rest.len(), chars.len());
+                    assert_eq!(rest.0, &chars[matched.len()..]);
+                    Ok(matched.len())
+                },
+                Err(Error(e)) => {
+                    assert_eq!(e.input.0, &chars[..]);
+                    Err(e.code)
+                },
+                Err(e) => panic!("unexpected error {:?}", e),
+            };
+            assert_eq!(actual, expected, "{:?}", input);
+        }
+    }
+
+    macro_rules! check_impl {
+        ($($name:ident)*) => {
+            #[test]
+            fn complete_test() {
+                $(
+                    check(|i| complete::$name(i), |i| complete::$name(i));
+                )*
+            }
+        };
+    }
+
+    check_impl! {
+        alpha0 alpha1
+        lower0 lower1
+        upper0 upper1
+        space0 space1
+        alphanumeric0 alphanumeric1
+        control0 control1
+        digit0 digit1
+        ascii0 ascii1
+        mongolian0 mongolian1
+        tibetan0 tibetan1
+        private_use0 private_use1
+        bmp0 bmp1
+        supplementary0 supplementary1
+        fullwidth_form0 fullwidth_form1
+        wsprop0 wsprop1
+        invalid_scalar0 invalid_scalar1
+        combining_marks0 combining_marks1
+        combining_marks_for_symbols0 combining_marks_for_symbols1
+        decimal0 decimal1
+        cased0 cased1
+        integer_digits1
+        not_noncharacter0
+    }
+
+    #[test]
+    fn factory_test() {
+        let block = crate::Block::BasicLatin;
+        check(|i| complete::block1(block)(i), |i| complete::block1(block)(i));
+        check(|i| complete::char_range1('a'..='z')(i), |i| complete::char_range1('a'..='z')(i));
+        let extra = crate::EXTENDED_WHITESPACE;
+        check(|i| complete::space_with0(extra)(i), |i| complete::space_with0(extra)(i));
+        check(|i| complete::skip_until_class(char::is_numeric)(i), |i| complete::skip_until_class(char::is_numeric)(i));
+    }
+
+    #[test]
+    fn code_point_test() {
+        let points = [CodePoint(0x61), CodePoint(0x62), CodePoint(0xD800), CodePoint(0x110000), CodePoint(0x63)];
+        let input = CharSlice(&points[..]);
+        let (rest, alpha) = complete::alpha1::<_, NError<_>>(input).unwrap();
+        assert_eq!(alpha.0, &points[..2]);
+        assert_eq!(complete::alpha1::<_, NError<_>>(rest), Err(Error(NError::new(rest, nom::error::ErrorKind::Alpha))));
+        assert_eq!(complete::alpha0::<_, NError<_>>(rest), Ok((rest, CharSlice(&points[2..2]))));
+
+        let (rest, invalid) = complete::invalid_scalar1::<_, NError<_>>(rest).unwrap();
+        assert_eq!(invalid.0, &points[2..4]);
+        assert_eq!(rest.0, &points[4..]);
+        assert!(complete::invalid_scalar1::<_, NError<_>>(input).is_err());
+
+        // Invalid code points match no class, even negated ones.
+        let surrogate = CharSlice(&points[2..3]);
+        assert!(complete::bmp1::<_, NError<_>>(surrogate).is_err());
+        assert!(complete::supplementary1::<_, NError<_>>(surrogate).is_err());
+        assert!(complete::char_range1::<_, NError<_>>('\0'..='\u{10FFFF}')(surrogate).is_err());
+        assert!(complete::block1::<_, NError<_>>(crate::Block::HighSurrogates)(surrogate).is_err());
+        assert_eq!(complete::not_noncharacter0::<_, NError<_>>(surrogate), Ok((surrogate, CharSlice(&points[2..2]))));
+        assert_eq!(crate::plane_of(CodePoint(0xD800)), u8::MAX);
+        assert_eq!(crate::block_of(CodePoint(0xD800)), crate::Block::NoBlock);
+        assert_eq!(CodePoint(0x110000).as_char(), '\u{FFFD}');
+        assert_eq!(CodePoint::from('a').to_char(), Some('a'));
+    }
+
+    #[test]
+    fn offset_test() {
+        let chars: Vec<char> = "조선글123".chars().collect();
+        let input = CharSlice(&chars);
+        let (rest, _) = complete::alpha1::<_, NError<_>>(input).unwrap();
+        assert_eq!(input.offset(&rest), 3);
+        assert_eq!(input.slice(3..), rest);
+    }
+}
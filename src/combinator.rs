@@ -0,0 +1,470 @@
+//! Unicode-aware combinators, independent of the parsing mode.
+
+use super::*;
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use nom::error::{ErrorKind, ParseError};
+use core::ops::{RangeInclusive, RangeTo};
+use nom::{Err, IResult, InputIter, InputLength, Offset, Parser, Slice};
+
+/// Runs `parser`, and fails if its output contains a supplementary character.
+///
+/// This validates that the output is representable in UCS-2, which only
+/// supports characters in the Basic Multilingual Plane. On failure, the
+/// error is an `ErrorKind::Verify` at the original input.
+pub fn reject_supplementary<I, O, E, P>(mut parser: P)
+    -> impl FnMut(I) -> IResult<I, O, E>
+    where I: Clone,
+          O: InputIter,
+          <O as InputIter>::Item: IsChar,
+          E: ParseError<I>,
+          P: Parser<I, O, E>
+{
+    move |input: I| {
+        let (rest, output) = parser.parse(input.clone())?;
+        if output.iter_elements().any(is_supplementary) {
+            Err(Err::Error(E::from_error_kind(input, ErrorKind::Verify)))
+        } else {
+            Ok((rest, output))
+        }
+    }
+}
+
+/// Runs `parser`, and returns its output with the length of the consumed input.
+///
+/// The output is `(output, byte_len, char_count)`. `byte_len` is the
+/// offset from the input to the remaining input, so it is exact for
+/// `&str` and `&[u8]`, and counts elements for other inputs such as
+/// `CharSlice`. `char_count` is the number of items consumed.
+pub fn consumed_info<I, O, E, P>(mut parser: P)
+    -> impl FnMut(I) -> IResult<I, (O, usize, usize), E>
+    where I: Clone + Offset + Slice<RangeTo<usize>> + InputIter,
+          E: ParseError<I>,
+          P: Parser<I, O, E>
+{
+    move |input: I| {
+        let (rest, output) = parser.parse(input.clone())?;
+        let byte_len = input.offset(&rest);
+        let char_count = input.slice(..byte_len).iter_elements().count();
+        Ok((rest, (output, byte_len, char_count)))
+    }
+}
+
+/// Runs `parser`, and fails if the number of characters it consumed is outside `range`.
+///
+/// The length counts the items consumed, which are scalar values for
+/// `&str` and `CharSlice`, so a name of five emoji is five characters
+/// long rather than twenty bytes. On failure, the error is an
+/// `ErrorKind::Verify` at the original input.
+pub fn length_chars<I, O, E, P>(mut parser: P, range: RangeInclusive<usize>)
+    -> impl FnMut(I) -> IResult<I, O, E>
+    where I: Clone + Offset + Slice<RangeTo<usize>> + InputIter,
+          E: ParseError<I>,
+          P: Parser<I, O, E>
+{
+    move |input: I| {
+        let (rest, output) = parser.parse(input.clone())?;
+        let count = input.slice(..input.offset(&rest)).iter_elements().count();
+        if range.contains(&count) {
+            Ok((rest, output))
+        } else {
+            Err(Err::Error(E::from_error_kind(input, ErrorKind::Verify)))
+        }
+    }
+}
+
+/// Runs `parser`, and fails if the input it consumed is wider than `max` columns.
+///
+/// The width is that of `display_width`, so CJK ideographs and most
+/// emoji take two columns, and combining marks none, which bounds a
+/// field to the space it takes in a terminal. On failure, the error is
+/// an `ErrorKind::Verify` at the original input.
+pub fn bounded_display_width<I, O, E, P>(mut parser: P, max: usize)
+    -> impl FnMut(I) -> IResult<I, O, E>
+    where I: Clone + Offset + Slice<RangeTo<usize>> + InputIter,
+          <I as InputIter>::Item: IsChar,
+          E: ParseError<I>,
+          P: Parser<I, O, E>
+{
+    move |input: I| {
+        let (rest, output) = parser.parse(input.clone())?;
+        let width: usize = input.slice(..input.offset(&rest))
+            .iter_elements()
+            .filter_map(|item| item.to_char())
+            .map(char_width)
+            .sum();
+        if width <= max {
+            Ok((rest, output))
+        } else {
+            Err(Err::Error(E::from_error_kind(input, ErrorKind::Verify)))
+        }
+    }
+}
+
+/// Runs `parser`, and returns the input it consumed without variation selectors.
+///
+/// The variation selectors are those of `is_variation_selector`, so
+/// `"❤\u{FE0F}"` and `"葛\u{E0100}"` become `"❤"` and `"葛"`. The
+/// remaining input is the one after the consumed input, so the selectors
+/// count towards the length consumed. Items that are not scalar values
+/// are dropped along with them.
+#[cfg(feature = "alloc")]
+pub fn without_variation_selectors<I, O, E, P>(mut parser: P)
+    -> impl FnMut(I) -> IResult<I, String, E>
+    where I: Clone + Offset + Slice<RangeTo<usize>> + InputIter,
+          <I as InputIter>::Item: IsChar,
+          E: ParseError<I>,
+          P: Parser<I, O, E>
+{
+    move |input: I| {
+        let (rest, _) = parser.parse(input.clone())?;
+        let consumed = input.slice(..input.offset(&rest));
+        let output = consumed.iter_elements()
+            .filter_map(|item| item.to_char())
+            .filter(|&c| !is_variation_selector(c))
+            .collect();
+        Ok((rest, output))
+    }
+}
+
+// Map each character of `s`, borrowing it if no character changes.
+#[cfg(feature = "alloc")]
+fn map_chars_cow<F, M>(s: &str, map: F) -> Cow<'_, str>
+    where F: Fn(char) -> M,
+          M: IntoIterator<Item = char>
+{
+    let unchanged = |c: char| {
+        let mut mapped = map(c).into_iter();
+        mapped.next() == Some(c) && mapped.next().is_none()
+    };
+    match s.char_indices().find(|&(_, c)| !unchanged(c)) {
+        None => Cow::Borrowed(s),
+        Some((index, _)) => {
+            let mut output = String::from(&s[..index]);
+            s[index..].chars().for_each(|c| output.extend(map(c)));
+            Cow::Owned(output)
+        },
+    }
+}
+
+/// Runs `parser`, and returns the NFC of the input it consumed.
+///
+/// The output is borrowed from the input if it is already in NFC, which
+/// is the common case, so only denormalized runs allocate. For example,
+/// `"e\u{301}"` is returned as an owned `"é"`.
+#[cfg(feature = "alloc")]
+pub fn normalized_nfc<'a, O, E, P>(mut parser: P)
+    -> impl FnMut(&'a str) -> IResult<&'a str, Cow<'a, str>, E>
+    where E: ParseError<&'a str>,
+          P: Parser<&'a str, O, E>
+{
+    move |input: &'a str| {
+        let (rest, _) = parser.parse(input)?;
+        let consumed = &input[..input.offset(rest)];
+        Ok((rest, crate::normalize::to_nfc(consumed)))
+    }
+}
+
+/// Runs `parser`, and returns the simple case folding of the input it consumed.
+///
+/// Each character is folded with `simple_fold`, so `"Straße"` becomes
+/// `"straße"`. The output is borrowed from the input if it is already
+/// folded, and otherwise only allocates once.
+#[cfg(feature = "alloc")]
+pub fn case_folded<'a, O, E, P>(mut parser: P)
+    -> impl FnMut(&'a str) -> IResult<&'a str, Cow<'a, str>, E>
+    where E: ParseError<&'a str>,
+          P: Parser<&'a str, O, E>
+{
+    move |input: &'a str| {
+        let (rest, _) = parser.parse(input)?;
+        let consumed = &input[..input.offset(rest)];
+        Ok((rest, map_chars_cow(consumed, |c| core::iter::once(simple_fold(c)))))
+    }
+}
+
+/// Runs `parser`, and returns the lowercase mapping of the input it consumed.
+///
+/// This uses the full mappings of `char::to_lowercase`, so `"İ"` becomes
+/// `"i\u{307}"`. The output is borrowed from the input if it is already
+/// lowercase.
+#[cfg(feature = "alloc")]
+pub fn lowercased<'a, O, E, P>(mut parser: P)
+    -> impl FnMut(&'a str) -> IResult<&'a str, Cow<'a, str>, E>
+    where E: ParseError<&'a str>,
+          P: Parser<&'a str, O, E>
+{
+    move |input: &'a str| {
+        let (rest, _) = parser.parse(input)?;
+        let consumed = &input[..input.offset(rest)];
+        Ok((rest, map_chars_cow(consumed, char::to_lowercase)))
+    }
+}
+
+/// Runs `parser` on a part of `original`, and locates its errors in `original`.
+///
+/// Errors are wrapped in a `Located`, with their byte, character and
+/// grapheme offsets from the start of `original`, so this is usually
+/// the outermost parser. The input must be a suffix of `original`.
+///
+/// # Panics
+///
+/// Panics on an error if the input is not a suffix of `original`.
+pub fn located<'a, O, E, P>(original: &'a str, mut parser: P)
+    -> impl FnMut(&'a str) -> IResult<&'a str, O, Located<E>>
+    where E: ParseError<&'a str> + ErrorInput<&'a str>,
+          P: Parser<&'a str, O, E>
+{
+    move |input: &'a str| parser.parse(input).map_err(|e| e.map(|error| Located::new(original, error)))
+}
+
+/// Runs every parser, and returns the result that consumed the most input.
+///
+/// On equal lengths, the earliest parser in `parsers` wins. If every
+/// parser fails, returns the error from the first parser, and if there
+/// are no parsers, fails with `ErrorKind::Alt`. `Incomplete` and
+/// `Failure` results are returned immediately, since the longest match
+/// cannot be decided.
+pub fn longest_of<'a, T, Error>(parsers: &'a [&'a dyn Fn(T) -> IResult<T, T, Error>])
+    -> impl Fn(T) -> IResult<T, T, Error> + 'a
+    where T: Clone + InputLength,
+          Error: ParseError<T>
+{
+    move |input: T| {
+        let mut best: Option<(T, T)> = None;
+        let mut first_error = None;
+        for parser in parsers {
+            match parser(input.clone()) {
+                Ok((rest, output)) => {
+                    let longer = match &best {
+                        Some((best_rest, _)) => rest.input_len() < best_rest.input_len(),
+                        None => true,
+                    };
+                    if longer {
+                        best = Some((rest, output));
+                    }
+                },
+                Err(Err::Error(e)) => {
+                    if first_error.is_none() {
+                        first_error = Some(e);
+                    }
+                },
+                Err(e) => return Err(e),
+            }
+        }
+        match (best, first_error) {
+            (Some(result), _) => Ok(result),
+            (None, Some(e)) => Err(Err::Error(e)),
+            (None, None) => Err(Err::Error(Error::from_error_kind(input, ErrorKind::Alt))),
+        }
+    }
+}
+
+/// Runs `parser`, and fails if its output is confusable with `against`.
+///
+/// The output is confusable if it has the same UTS #39 skeleton as
+/// `against`, so a parser for identifiers can reject lookalikes of a
+/// reserved name. On failure, the error is an `ErrorKind::Verify` at
+/// the original input.
+#[cfg(feature = "confusables")]
+pub fn verify_not_confusable<I, O, E, P>(mut parser: P, against: &str)
+    -> impl FnMut(I) -> IResult<I, O, E>
+    where I: Clone,
+          O: InputIter,
+          <O as InputIter>::Item: IsChar,
+          E: ParseError<I>,
+          P: Parser<I, O, E>
+{
+    let expected = skeleton(against);
+    move |input: I| {
+        let (rest, output) = parser.parse(input.clone())?;
+        let chars = output.iter_elements().map(|item| item.as_char());
+        if confusables::skeleton_chars(chars) == expected {
+            Err(Err::Error(E::from_error_kind(input, ErrorKind::Verify)))
+        } else {
+            Ok((rest, output))
+        }
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::error::Error as NError;
+
+    #[test]
+    fn consumed_info_test() {
+        let mut parser = consumed_info(complete::alpha1::<_, NError<&str>>);
+        assert_eq!(parser("erfüllen 123"), Ok((" 123", ("erfüllen", 9, 8))));
+        assert_eq!(parser("조선글"), Ok(("", ("조선글", 9, 3))));
+        assert_eq!(parser("123"), Err(Err::Error(NError::new("123", ErrorKind::Alpha))));
+
+        // Any parser works, even if its output is not the consumed input.
+        #[cfg(feature = "alloc")]
+        {
+            let mut parser = consumed_info(complete::map_chars1::<_, NError<&str>, _, _>(to_decimal_digit));
+            assert_eq!(parser("١٢3x"), Ok(("x", (vec![1, 2, 3], 5, 3))));
+        }
+
+        let chars = ['𐌰', '𐌰', 'x'];
+        let mut parser = consumed_info(complete::supplementary1::<_, NError<_>>);
+        let (_, (_, len, count)) = parser(CharSlice(&chars)).unwrap();
+        assert_eq!((len, count), (2, 2));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn transformed_cow_test() {
+        let mut parser = normalized_nfc(complete::non_space1::<_, NError<&str>>);
+        assert!(matches!(parser("café 1"), Ok((" 1", Cow::Borrowed("café")))));
+        assert!(matches!(parser("e\u{301}t\u{e9} "), Ok((" ", Cow::Owned(ref s))) if s == "été"));
+        assert_eq!(parser(" "), Err(Err::Error(NError::new(" ", ErrorKind::TakeTill1))));
+
+        let mut parser = case_folded(complete::alpha1::<_, NError<&str>>);
+        assert!(matches!(parser("straße"), Ok(("", Cow::Borrowed("straße")))));
+        assert!(matches!(parser("Straße"), Ok(("", Cow::Owned(ref s))) if s == "straße"));
+        assert!(matches!(parser("ΣΑΣ"), Ok(("", Cow::Owned(ref s))) if s == "σασ"));
+
+        let mut parser = lowercased(complete::alpha1::<_, NError<&str>>);
+        assert!(matches!(parser("hello"), Ok(("", Cow::Borrowed("hello")))));
+        assert!(matches!(parser("İx"), Ok(("", Cow::Owned(ref s))) if s == "i\u{307}x"));
+    }
+
+    #[test]
+    fn located_test() {
+        let input = "日本語x";
+        let mut parser = located(input, nom::sequence::terminated(
+            nom::bytes::complete::take_while1::<_, _, NError<&str>>(|c| script_of(c) == Script::Han),
+            nom::combinator::eof
+        ));
+        let error = match parser(input) {
+            Err(Err::Error(error)) => error,
+            result => panic!("unexpected result {:?}", result),
+        };
+        assert_eq!(error.error, NError::new("x", ErrorKind::Eof));
+        assert_eq!((error.byte_offset, error.char_offset), (9, 3));
+
+        // A failure inside a larger grammar, with combining marks.
+        let input = "e\u{301}e\u{301} 1";
+        let mut parser = located(input, nom::sequence::separated_pair(
+            complete::non_space1::<_, UnicodeError<&str>>,
+            complete::space1,
+            expected::alpha1::<_, _, Complete>
+        ));
+        let error = match parser(input) {
+            Err(Err::Error(error)) => error,
+            result => panic!("unexpected result {:?}", result),
+        };
+        assert_eq!(error.error.expected, Some(Expected::Alpha));
+        assert_eq!((error.byte_offset, error.char_offset), (7, 5));
+        #[cfg(feature = "grapheme")]
+        assert_eq!(error.grapheme_offset, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a suffix")]
+    fn located_not_suffix_test() {
+        let mut parser = located("abc", complete::digit1::<_, NError<&str>>);
+        let _ = parser("xyz");
+    }
+
+    #[test]
+    fn length_chars_test() {
+        let mut username = length_chars(complete::alphanumeric1::<_, NError<&str>>, 3..=16);
+        assert_eq!(username("jürgen rest"), Ok((" rest", "jürgen")));
+        assert_eq!(username("조선글"), Ok(("", "조선글")));
+        assert_eq!(username("ab"), Err(Err::Error(NError::new("ab", ErrorKind::Verify))));
+        assert_eq!(username("abcdefghijklmnopq"), Err(Err::Error(NError::new("abcdefghijklmnopq", ErrorKind::Verify))));
+        assert_eq!(username("!"), Err(Err::Error(NError::new("!", ErrorKind::AlphaNumeric))));
+
+        // Five emoji are 20 bytes, but five characters.
+        let mut emoji = length_chars(complete::satisfy::<_, NError<&str>, _>(is_supplementary), 1..=1);
+        assert_eq!(emoji("😀😀"), Ok(("😀", '😀')));
+        let mut name = length_chars(complete::supplementary1::<_, NError<&str>>, 1..=5);
+        assert_eq!(name("😀😀😀😀😀"), Ok(("", "😀😀😀😀😀")));
+        assert_eq!(name("😀😀😀😀😀😀"), Err(Err::Error(NError::new("😀😀😀😀😀😀", ErrorKind::Verify))));
+    }
+
+    #[test]
+    fn bounded_display_width_test() {
+        let mut field = bounded_display_width(complete::alpha1::<_, NError<&str>>, 4);
+        assert_eq!(field("abcd efgh"), Ok((" efgh", "abcd")));
+        assert_eq!(field("日本 x"), Ok((" x", "日本")));
+        assert_eq!(field("日本語"), Err(Err::Error(NError::new("日本語", ErrorKind::Verify))));
+        assert_eq!(field("abcde"), Err(Err::Error(NError::new("abcde", ErrorKind::Verify))));
+        assert_eq!(field("123"), Err(Err::Error(NError::new("123", ErrorKind::Alpha))));
+
+        // Combining marks take no columns.
+        let mut field = bounded_display_width(nom::bytes::complete::take_till::<_, _, NError<&str>>(|c| c == ' '), 4);
+        assert_eq!(field("e\u{301}e\u{301}e\u{301}e\u{301} x"), Ok((" x", "e\u{301}e\u{301}e\u{301}e\u{301}")));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn without_variation_selectors_test() {
+        let mut parser = without_variation_selectors(nom::bytes::complete::take_till::<_, _, NError<&str>>(|c| c == ' '));
+        assert_eq!(parser("❤\u{FE0F}葛\u{E0100}x rest"), Ok((" rest", "❤葛x".to_string())));
+        assert_eq!(parser("plain"), Ok(("", "plain".to_string())));
+
+        let mut parser = without_variation_selectors(complete::alpha1::<_, NError<&str>>);
+        assert_eq!(parser("123"), Err(Err::Error(NError::new("123", ErrorKind::Alpha))));
+    }
+
+    #[test]
+    fn longest_of_test() {
+        type Parser<'a> = &'a dyn Fn(&'a str) -> IResult<&'a str, &'a str>;
+        let alpha1: Parser = &complete::alpha1;
+        let alphanumeric1: Parser = &complete::alphanumeric1;
+        let digit1: Parser = &complete::digit1;
+        let space1: Parser = &complete::space1;
+
+        let parsers = [alpha1, alphanumeric1, digit1];
+        let parser = longest_of(&parsers);
+        assert_eq!(parser("abc123 x"), Ok((" x", "abc123")));
+        assert_eq!(parser("123abc x"), Ok((" x", "123abc")));
+        assert_eq!(parser(" x"), Err(Err::Error(NError::new(" x", ErrorKind::Alpha))));
+
+        // Ties go to the first parser.
+        let first: Parser = &|i| Ok((&i[2..], "first"));
+        let second: Parser = &|i| Ok((&i[2..], "second"));
+        let parsers = [space1, first, second];
+        let parser = longest_of(&parsers);
+        assert_eq!(parser("ab"), Ok(("", "first")));
+
+        let parser = longest_of::<&str, NError<&str>>(&[]);
+        assert_eq!(parser("abc"), Err(Err::Error(NError::new("abc", ErrorKind::Alt))));
+
+        let streaming_alpha1: Parser = &streaming::alpha1;
+        let parsers = [digit1, streaming_alpha1];
+        let parser = longest_of(&parsers);
+        assert_eq!(parser("abc"), Err(Err::Incomplete(nom::Needed::new(1))));
+    }
+
+    #[cfg(feature = "confusables")]
+    #[test]
+    fn verify_not_confusable_test() {
+        let mut parser = verify_not_confusable(complete::alpha1::<_, NError<&str>>, "paypal");
+        assert_eq!(parser("paypa1"), Ok(("1", "paypa")));
+        assert_eq!(parser("amazon"), Ok(("", "amazon")));
+        assert_eq!(parser("paypal"), Err(Err::Error(NError::new("paypal", ErrorKind::Verify))));
+        assert_eq!(parser("раураl."), Err(Err::Error(NError::new("раураl.", ErrorKind::Verify))));
+
+        let mut parser = verify_not_confusable(complete::alphanumeric1::<_, NError<&str>>, "modern");
+        assert_eq!(parser("rnodern"), Err(Err::Error(NError::new("rnodern", ErrorKind::Verify))));
+        assert_eq!(parser("modem"), Err(Err::Error(NError::new("modem", ErrorKind::Verify))));
+        assert_eq!(parser("models"), Ok(("", "models")));
+    }
+
+    #[test]
+    fn reject_supplementary_test() {
+        let mut parser = reject_supplementary(complete::alpha1::<_, NError<&str>>);
+        assert_eq!(parser("abc조선글 x"), Ok((" x", "abc조선글")));
+        assert_eq!(parser("ab𐌰c x"), Err(Err::Error(NError::new("ab𐌰c x", ErrorKind::Verify))));
+        assert_eq!(parser("123"), Err(Err::Error(NError::new("123", ErrorKind::Alpha))));
+    }
+}
@@ -0,0 +1,56 @@
+//! Confusable detection from UTS #39, Unicode Security Mechanisms.
+//!
+//! Two strings are confusable when they have the same skeleton. The
+//! bundled mapping is generated from `scripts/data/confusables.txt`,
+//! the complete UTS #39 mapping of Unicode 16.0.0. It is newer than the
+//! other tables, so it also maps a few characters added since Unicode
+//! 14.0.0.
+
+use crate::normalize::{decompose, nfd};
+use crate::tables;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Get the UTS #39 skeleton of a sequence of characters.
+pub(crate) fn skeleton_chars<I: IntoIterator<Item = char>>(chars: I) -> String {
+    let mut mapped = Vec::new();
+    for c in nfd(chars) {
+        match tables::lookup_str(c, tables::CONFUSABLES) {
+            Some(prototype) => prototype.chars().for_each(|p| decompose(p, &mut mapped)),
+            None => mapped.push(c),
+        }
+    }
+    nfd(mapped).into_iter().collect()
+}
+
+/// Get the UTS #39 skeleton of a string.
+///
+/// The skeleton is the NFD of the string, with every character replaced
+/// by its prototype from the confusables mapping, and decomposed again.
+/// Two strings are confusable if their skeletons are equal, so
+/// `skeleton("раураl")` (with Cyrillic letters) equals `skeleton("paypal")`.
+pub fn skeleton(s: &str) -> String {
+    skeleton_chars(s.chars())
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skeleton_test() {
+        assert_eq!(skeleton("paypal"), "paypal");
+        assert_eq!(skeleton("раураl"), skeleton("paypal"));
+        assert_eq!(skeleton("rn"), skeleton("m"));
+        assert_eq!(skeleton("I1l|"), "llll");
+        assert_eq!(skeleton("ΒΟΟΚ"), "BOOK");
+        assert_eq!(skeleton("é"), "e\u{301}");
+        assert_ne!(skeleton("paypal"), skeleton("paypa1x"));
+        // Mappings outside Latin, Greek and Cyrillic.
+        assert_eq!(skeleton("\u{13AA}\u{13A2}"), skeleton("AT"));
+        assert_eq!(skeleton("\u{FF21}\u{2160}"), "Al");
+    }
+}
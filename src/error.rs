@@ -0,0 +1,389 @@
+//! Errors recording the character class a parser expected.
+//!
+//! Nom's `ErrorKind` has no kinds for Unicode classes, so most run
+//! parsers fail with a generic kind such as `ErrorKind::TakeWhile1`.
+//! The run parsers of the `expected` module also report an `Expected`
+//! class through `ExpectedError`, which `UnicodeError` keeps for error
+//! messages, and `VerboseError` adds to its context stack.
+
+use core::fmt;
+use nom::InputIter;
+use nom::error::{ContextError, ErrorKind, FromExternalError, ParseError};
+use crate::{general_category_of, GeneralCategory, IsChar};
+
+/// The character class expected by a run parser.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Expected {
+    /// Alphabetic character, from `alpha1`.
+    Alpha,
+    /// Lowercase character, from `lower1`.
+    Lower,
+    /// Uppercase character, from `upper1`.
+    Upper,
+    /// Whitespace, from `space1`.
+    Space,
+    /// Non-whitespace character, from `non_space1`.
+    NonSpace,
+    /// Whitespace or ignorable character, from `space_or_ignorable1`.
+    SpaceOrIgnorable,
+    /// Whitespace, from `multispace1`.
+    Multispace,
+    /// ASCII space or tab, from `ascii_space1`.
+    AsciiSpace,
+    /// Horizontal space, from `horizontal_space1`.
+    HorizontalSpace,
+    /// Breaking space, from `breaking_space1`.
+    BreakingSpace,
+    /// Non-breaking space, from `non_breaking_space1`.
+    NonBreakingSpace,
+    /// Line terminator, from `vertical_space1`.
+    VerticalSpace,
+    /// Alphanumeric character, from `alphanumeric1`.
+    Alphanumeric,
+    /// Control character, from `control1`.
+    Control,
+    /// C0 control character, from `c0_control1`.
+    C0Control,
+    /// C1 control character, from `c1_control1`.
+    C1Control,
+    /// Numeric character, from `digit1`.
+    Digit,
+    /// ASCII character, from `ascii1`.
+    Ascii,
+    /// Mongolian character, from `mongolian1`.
+    Mongolian,
+    /// Tibetan character, from `tibetan1`.
+    Tibetan,
+    /// Private use character, from `private_use1`.
+    PrivateUse,
+    /// Basic Multilingual Plane character, from `bmp1`.
+    Bmp,
+    /// Supplementary plane character, from `supplementary1`.
+    Supplementary,
+    /// Halfwidth or fullwidth form, from `fullwidth_form1`.
+    FullwidthForm,
+    /// `White_Space` character, from `wsprop1`.
+    WhiteSpace,
+    /// Invalid scalar value, from `invalid_scalar1`.
+    InvalidScalar,
+    /// Combining diacritical mark, from `combining_marks1`.
+    CombiningMarks,
+    /// Extended combining diacritical mark, from `combining_marks_extended1`.
+    CombiningMarksExtended,
+    /// Supplementary combining diacritical mark, from `combining_marks_supplement1`.
+    CombiningMarksSupplement,
+    /// Combining diacritical mark for symbols, from `combining_marks_for_symbols1`.
+    CombiningMarksForSymbols,
+    /// Decimal digit, from `decimal1`.
+    Decimal,
+    /// Assigned character, from `assigned1`.
+    Assigned,
+    /// Cased character, from `cased1`.
+    Cased,
+    /// Operator character, from `operator1`.
+    Operator,
+    /// `Pattern_White_Space` character, from `pattern_white_space1`.
+    PatternWhiteSpace,
+    /// Dash, from `dash1`.
+    Dash,
+    /// Quotation mark, from `quotation_mark1`.
+    QuotationMark,
+    /// Terminal punctuation, from `terminal_punctuation1`.
+    TerminalPunctuation,
+    /// Math character, from `math1`.
+    Math,
+    /// Symbol, from `symbol1`.
+    Symbol,
+    /// Currency symbol, from `currency_symbol1`.
+    CurrencySymbol,
+    /// Modifier symbol, from `modifier_symbol1`.
+    ModifierSymbol,
+    /// Math symbol, from `math_symbol1`.
+    MathSymbol,
+    /// Other symbol, from `other_symbol1`.
+    OtherSymbol,
+    /// Dual joining character, from `dual_joining1`.
+    DualJoining,
+    /// Transparent joining character, from `transparent1`.
+    Transparent,
+    /// Variation selector, from `variation_selector1`.
+    VariationSelector,
+    /// Emoji, from `emoji_scalar1`.
+    EmojiScalar,
+    /// Code point of at most U+10FFFF, from `char_from_radix`.
+    CodePoint,
+    /// Unicode scalar value, not a surrogate, from `char_from_radix`.
+    ScalarValue,
+}
+
+impl Expected {
+    /// Get a short description of the class, such as `"alphabetic character"`.
+    pub fn description(self) -> &'static str {
+        match self {
+            Expected::Alpha => "alphabetic character",
+            Expected::Lower => "lowercase character",
+            Expected::Upper => "uppercase character",
+            Expected::Space => "whitespace",
+            Expected::NonSpace => "non-whitespace character",
+            Expected::SpaceOrIgnorable => "whitespace or ignorable character",
+            Expected::Multispace => "whitespace",
+            Expected::AsciiSpace => "ASCII space or tab",
+            Expected::HorizontalSpace => "horizontal space",
+            Expected::BreakingSpace => "breaking space",
+            Expected::NonBreakingSpace => "non-breaking space",
+            Expected::VerticalSpace => "line terminator",
+            Expected::Alphanumeric => "alphanumeric character",
+            Expected::Control => "control character",
+            Expected::C0Control => "C0 control character",
+            Expected::C1Control => "C1 control character",
+            Expected::Digit => "numeric character",
+            Expected::Ascii => "ASCII character",
+            Expected::Mongolian => "Mongolian character",
+            Expected::Tibetan => "Tibetan character",
+            Expected::PrivateUse => "private use character",
+            Expected::Bmp => "Basic Multilingual Plane character",
+            Expected::Supplementary => "supplementary plane character",
+            Expected::FullwidthForm => "halfwidth or fullwidth form",
+            Expected::WhiteSpace => "White_Space character",
+            Expected::InvalidScalar => "invalid scalar value",
+            Expected::CombiningMarks => "combining diacritical mark",
+            Expected::CombiningMarksExtended => "extended combining diacritical mark",
+            Expected::CombiningMarksSupplement => "supplementary combining diacritical mark",
+            Expected::CombiningMarksForSymbols => "combining diacritical mark for symbols",
+            Expected::Decimal => "decimal digit",
+            Expected::Assigned => "assigned character",
+            Expected::Cased => "cased character",
+            Expected::Operator => "operator character",
+            Expected::PatternWhiteSpace => "Pattern_White_Space character",
+            Expected::Dash => "dash",
+            Expected::QuotationMark => "quotation mark",
+            Expected::TerminalPunctuation => "terminal punctuation",
+            Expected::Math => "math character",
+            Expected::Symbol => "symbol",
+            Expected::CurrencySymbol => "currency symbol",
+            Expected::ModifierSymbol => "modifier symbol",
+            Expected::MathSymbol => "math symbol",
+            Expected::OtherSymbol => "other symbol",
+            Expected::DualJoining => "dual joining character",
+            Expected::Transparent => "transparent joining character",
+            Expected::VariationSelector => "variation selector",
+            Expected::EmojiScalar => "emoji",
+            Expected::CodePoint => "code point",
+            Expected::ScalarValue => "Unicode scalar value",
+        }
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+/// A parse error that can record the character class a parser expected.
+///
+/// The run parsers of the `expected` module, such as `alpha1` and
+/// `control1`, create their errors with `from_expected`. The default
+/// implementation drops the class, so any `ParseError` supports them
+/// with an empty impl, while `UnicodeError` keeps it. `VerboseError` adds the class
+/// description, such as `"alphabetic character"`, as a context.
+pub trait ExpectedError<I>: ParseError<I> {
+    /// Create an error from the input, the nom error kind, and the expected class.
+    #[inline]
+    fn from_expected(input: I, kind: ErrorKind, expected: Expected) -> Self {
+        let _ = expected;
+        Self::from_error_kind(input, kind)
+    }
+}
+
+impl<I> ExpectedError<I> for nom::error::Error<I> {
+}
+
+impl<I> ExpectedError<I> for (I, ErrorKind) {
+}
+
+impl<I> ExpectedError<I> for () {
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Clone> ExpectedError<I> for nom::error::VerboseError<I> {
+    #[inline]
+    fn from_expected(input: I, kind: ErrorKind, expected: Expected) -> Self {
+        let error = Self::from_error_kind(input.clone(), kind);
+        Self::add_context(input, expected.description(), error)
+    }
+}
+
+/// A parse error with the character class the parser expected, if any.
+///
+/// This is `nom::error::Error` with an `expected` field, and converts
+/// into it for compatibility. Only the run parsers of the `expected`
+/// module set `expected`, so errors from other parsers have `None`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnicodeError<I> {
+    /// The input position of the error.
+    pub input: I,
+    /// The nom error kind.
+    pub code: ErrorKind,
+    /// The character class the parser expected.
+    pub expected: Option<Expected>,
+}
+
+impl<I> UnicodeError<I> {
+    /// Create an error without an expected class.
+    #[inline]
+    pub fn new(input: I, code: ErrorKind) -> Self {
+        UnicodeError { input, code, expected: None }
+    }
+}
+
+impl<I> UnicodeError<I>
+    where I: InputIter,
+          <I as InputIter>::Item: IsChar
+{
+    /// Get the character at the error position, and its general category.
+    ///
+    /// For the `1` run parsers, this is the first character that did not
+    /// match, so `digit1("xy")` fails at `('x', GeneralCategory::Ll)`.
+    /// Returns `None` at the end of the input, or if the item there is
+    /// not a scalar value.
+    ///
+    /// The error only keeps its input, so checks on a whole run, such as
+    /// `verify` or `length_chars`, report the first character of the run
+    /// rather than where it stopped: `length_chars(digit1, 3..=5)` fails
+    /// on `"12x"` at `('1', GeneralCategory::Nd)`. The remaining input of
+    /// the run parser itself gives the character it stopped at.
+    #[inline]
+    pub fn offending_char(&self) -> Option<(char, GeneralCategory)> {
+        let c = self.input.iter_elements().next()?.to_char()?;
+        Some((c, general_category_of(c)))
+    }
+}
+
+impl<I> ParseError<I> for UnicodeError<I> {
+    #[inline]
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        UnicodeError::new(input, kind)
+    }
+
+    #[inline]
+    fn append(_: I, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<I> ExpectedError<I> for UnicodeError<I> {
+    #[inline]
+    fn from_expected(input: I, kind: ErrorKind, expected: Expected) -> Self {
+        UnicodeError { input, code: kind, expected: Some(expected) }
+    }
+}
+
+impl<I> ContextError<I> for UnicodeError<I> {
+}
+
+impl<I, E> FromExternalError<I, E> for UnicodeError<I> {
+    #[inline]
+    fn from_external_error(input: I, kind: ErrorKind, _: E) -> Self {
+        UnicodeError::new(input, kind)
+    }
+}
+
+impl<I> From<UnicodeError<I>> for nom::error::Error<I> {
+    #[inline]
+    fn from(error: UnicodeError<I>) -> Self {
+        nom::error::Error::new(error.input, error.code)
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for UnicodeError<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.expected {
+            Some(expected) => write!(f, "expected {} at: {}", expected, self.input),
+            None => write!(f, "error {:?} at: {}", self.code, self.input),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I: fmt::Debug + fmt::Display> std::error::Error for UnicodeError<I> {
+}
+
+/// A parse error that knows the input it failed at.
+pub trait ErrorInput<I> {
+    /// Get the input at the error position.
+    fn input(&self) -> &I;
+}
+
+impl<I> ErrorInput<I> for nom::error::Error<I> {
+    #[inline]
+    fn input(&self) -> &I {
+        &self.input
+    }
+}
+
+impl<I> ErrorInput<I> for (I, ErrorKind) {
+    #[inline]
+    fn input(&self) -> &I {
+        &self.0
+    }
+}
+
+impl<I> ErrorInput<I> for UnicodeError<I> {
+    #[inline]
+    fn input(&self) -> &I {
+        &self.input
+    }
+}
+
+/// A parse error with its position as byte, character and grapheme offsets.
+///
+/// The offsets are from the start of the original input, for editors and
+/// other tools that do not count in bytes. See `combinator::located`.
+/// The fields depend on the enabled features, so create it with `new`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Located<E> {
+    /// The wrapped error.
+    pub error: E,
+    /// The byte offset of the error.
+    pub byte_offset: usize,
+    /// The character offset of the error, from `char_offset`.
+    pub char_offset: usize,
+    /// The extended grapheme cluster offset of the error, from `grapheme_offset`.
+    #[cfg(feature = "grapheme")]
+    pub grapheme_offset: usize,
+}
+
+impl<E> Located<E> {
+    /// Locate an error from parsing `original`.
+    ///
+    /// The input of the error must be a suffix of `original`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input of the error is not a suffix of `original`.
+    pub fn new<'a>(original: &'a str, error: E) -> Self
+        where E: ErrorInput<&'a str>
+    {
+        let remainder = *error.input();
+        Located {
+            byte_offset: crate::suffix_offset(original, remainder),
+            char_offset: crate::char_offset(original, remainder),
+            #[cfg(feature = "grapheme")]
+            grapheme_offset: crate::grapheme_offset(original, remainder),
+            error,
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Located<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (character {})", self.error, self.char_offset)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: fmt::Debug + fmt::Display> std::error::Error for Located<E> {
+}
@@ -0,0 +1,173 @@
+//! Extended grapheme cluster boundaries, following UAX #29.
+//!
+//! This implements the grapheme cluster boundary rules of Unicode 14.0,
+//! using the tables bundled with the crate.
+//!
+//! Legacy grapheme clusters omit two rules of extended clusters: GB9a,
+//! which attaches a spacing mark (`SpacingMark`, most `Mc` characters) to
+//! the preceding character, and GB9b, which attaches a `Prepend`
+//! character to the following one. So `"क\u{93F}"` is one extended
+//! cluster, but two legacy clusters.
+
+use crate::tables::{self, GraphemeClusterBreak as Gcb};
+
+/// Get the `Grapheme_Cluster_Break` property of a character.
+#[inline]
+fn break_property(c: char) -> Gcb {
+    tables::lookup(c, tables::GRAPHEME_CLUSTER_BREAK)
+}
+
+/// Check if a character has the `Extended_Pictographic` property.
+#[inline]
+fn is_extended_pictographic(c: char) -> bool {
+    tables::contains(c, tables::EXTENDED_PICTOGRAPHIC)
+}
+
+/// The kind of grapheme clusters to find.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum GraphemeMode {
+    /// Extended grapheme clusters, with every rule of UAX #29.
+    Extended,
+    /// Legacy grapheme clusters, without rules GB9a and GB9b.
+    Legacy,
+}
+
+impl Default for GraphemeMode {
+    #[inline]
+    fn default() -> Self {
+        GraphemeMode::Extended
+    }
+}
+
+/// Finds grapheme cluster boundaries in a sequence of characters.
+///
+/// Characters are pushed in order, and each push reports whether a
+/// cluster boundary precedes the character.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Segmenter {
+    // The kind of clusters to find.
+    mode: GraphemeMode,
+    // The property of the previous character.
+    prev: Option<Gcb>,
+    // If the previous characters are `ExtPict Extend*` (GB11).
+    pictographic: bool,
+    // If the previous characters are `ExtPict Extend* ZWJ` (GB11).
+    pictographic_zwj: bool,
+    // If the previous characters end an odd run of regional indicators (GB12, GB13).
+    odd_regional: bool,
+}
+
+impl Segmenter {
+    /// Create a segmenter for the kind of clusters `mode`.
+    #[inline]
+    pub(crate) fn new(mode: GraphemeMode) -> Self {
+        Segmenter { mode, ..Segmenter::default() }
+    }
+
+    /// Push the next character, and return if a boundary precedes it.
+    ///
+    /// The first character always starts a cluster (GB1).
+    pub(crate) fn push(&mut self, c: char) -> bool {
+        let next = break_property(c);
+        let pictographic = is_extended_pictographic(c);
+        let boundary = match self.prev {
+            None => true,
+            Some(prev) => self.is_boundary(prev, next, pictographic),
+        };
+
+        self.pictographic_zwj = self.pictographic && next == Gcb::Zwj;
+        self.pictographic = pictographic || (self.pictographic && next == Gcb::Extend);
+        let paired = self.prev == Some(Gcb::RegionalIndicator) && self.odd_regional;
+        self.odd_regional = next == Gcb::RegionalIndicator && !paired;
+        self.prev = Some(next);
+        boundary
+    }
+
+    /// Check if the cluster ending with the last character is complete.
+    ///
+    /// Any cluster can be extended by a following combining mark, except
+    /// after a control character or a line feed (GB4).
+    #[inline]
+    pub(crate) fn is_closed(&self) -> bool {
+        matches!(self.prev, Some(Gcb::Control) | Some(Gcb::Lf))
+    }
+
+    fn is_boundary(&self, prev: Gcb, next: Gcb, pictographic: bool) -> bool {
+        match (prev, next) {
+            (Gcb::Cr, Gcb::Lf) => false,
+            (Gcb::Control, _) | (Gcb::Cr, _) | (Gcb::Lf, _) => true,
+            (_, Gcb::Control) | (_, Gcb::Cr) | (_, Gcb::Lf) => true,
+            (Gcb::L, Gcb::L) | (Gcb::L, Gcb::V) | (Gcb::L, Gcb::Lv) | (Gcb::L, Gcb::Lvt) => false,
+            (Gcb::Lv, Gcb::V) | (Gcb::Lv, Gcb::T) | (Gcb::V, Gcb::V) | (Gcb::V, Gcb::T) => false,
+            (Gcb::Lvt, Gcb::T) | (Gcb::T, Gcb::T) => false,
+            (_, Gcb::Extend) | (_, Gcb::Zwj) => false,
+            (_, Gcb::SpacingMark) | (Gcb::Prepend, _) => self.mode == GraphemeMode::Legacy,
+            (Gcb::Zwj, _) if self.pictographic_zwj && pictographic => false,
+            (Gcb::RegionalIndicator, Gcb::RegionalIndicator) => !self.odd_regional,
+            _ => true,
+        }
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clusters(s: &str) -> Vec<&str> {
+        clusters_mode(s, GraphemeMode::Extended)
+    }
+
+    fn clusters_mode(s: &str, mode: GraphemeMode) -> Vec<&str> {
+        let mut segmenter = Segmenter::new(mode);
+        let mut starts: Vec<usize> = s.char_indices()
+            .filter(|&(_, c)| segmenter.push(c))
+            .map(|(index, _)| index)
+            .collect();
+        starts.push(s.len());
+        starts.windows(2).map(|w| &s[w[0]..w[1]]).collect()
+    }
+
+    #[test]
+    fn segmenter_test() {
+        assert_eq!(clusters("abc"), ["a", "b", "c"]);
+        assert_eq!(clusters("e\u{301}x"), ["e\u{301}", "x"]);
+        assert_eq!(clusters("\r\n\n"), ["\r\n", "\n"]);
+        assert_eq!(clusters("\u{301}a"), ["\u{301}", "a"]);
+        // Hangul syllables and jamo.
+        assert_eq!(clusters("\u{1112}\u{1161}\u{11AB}한"), ["\u{1112}\u{1161}\u{11AB}", "한"]);
+        // Spacing marks and prepended characters.
+        assert_eq!(clusters("क\u{93F}"), ["क\u{93F}"]);
+        assert_eq!(clusters("\u{600}1"), ["\u{600}1"]);
+        // Emoji modifier and ZWJ sequences.
+        assert_eq!(clusters("👍🏽👍"), ["👍🏽", "👍"]);
+        assert_eq!(clusters("👩\u{200D}👩\u{200D}👧x"), ["👩\u{200D}👩\u{200D}👧", "x"]);
+        assert_eq!(clusters("a\u{200D}👧"), ["a\u{200D}", "👧"]);
+        // Regional indicators pair up.
+        assert_eq!(clusters("🇫🇷🇩🇪🇺"), ["🇫🇷", "🇩🇪", "🇺"]);
+        assert_eq!(clusters("\u{600}🇫🇷🇺"), ["\u{600}🇫🇷", "🇺"]);
+    }
+
+    #[test]
+    fn legacy_test() {
+        let legacy = |s| clusters_mode(s, GraphemeMode::Legacy);
+        assert_eq!(legacy("क\u{93F}"), ["क", "\u{93F}"]);
+        assert_eq!(legacy("\u{600}1"), ["\u{600}", "1"]);
+        assert_eq!(legacy("e\u{301}👩\u{200D}👧"), ["e\u{301}", "👩\u{200D}👧"]);
+        assert_eq!(legacy("🇫🇷🇩🇪"), ["🇫🇷", "🇩🇪"]);
+    }
+
+    #[test]
+    fn is_closed_test() {
+        let mut segmenter = Segmenter::default();
+        assert!(!segmenter.is_closed());
+        segmenter.push('a');
+        assert!(!segmenter.is_closed());
+        segmenter.push('\r');
+        assert!(!segmenter.is_closed());
+        segmenter.push('\n');
+        assert!(segmenter.is_closed());
+    }
+}
@@ -0,0 +1,428 @@
+//! Configurable identifiers, following UAX #31.
+
+use super::*;
+use crate::script_set::ScriptSet;
+use alloc::vec::Vec;
+use nom::error::{ErrorKind, ParseError};
+use nom::{Err, IResult, InputIter, InputLength, InputTake, Needed};
+
+/// A restriction on the scripts mixed in an identifier, from UTS #39.
+///
+/// `Common` and `Inherited` characters, such as digits and combining
+/// marks, are allowed with any script.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RestrictionLevel {
+    /// All characters share a script, such as `"paypal"` or `"пайпал"`.
+    ///
+    /// Han is also allowed with Hiragana and Katakana, Hangul, or
+    /// Bopomofo, which are written together.
+    SingleScript,
+    /// The characters share a script, or are Latin mixed with one of the
+    /// combinations of Han allowed with `SingleScript`, such as `"東京abc"`.
+    HighlyRestrictive,
+    /// The characters are allowed with `HighlyRestrictive`, or are Latin
+    /// mixed with any one other script except Cyrillic, Greek, and
+    /// Cherokee, such as `"abcكتب"`.
+    ModeratelyRestrictive,
+}
+
+impl RestrictionLevel {
+    // Find the first character that breaks the restriction.
+    pub(crate) fn find_violation<T>(self, input: &T) -> Option<usize>
+        where T: InputIter,
+              <T as InputIter>::Item: IsChar
+    {
+        let mut covers = [
+            ScriptSet::new(&[Script::Latin, Script::Han, Script::Hiragana, Script::Katakana]),
+            ScriptSet::new(&[Script::Latin, Script::Han, Script::Bopomofo]),
+            ScriptSet::new(&[Script::Latin, Script::Han, Script::Hangul]),
+        ];
+        // The scripts of the characters that are not Latin, which can be
+        // mixed with Latin unless they look too much alike.
+        let excluded = ScriptSet::new(&[Script::Cyrillic, Script::Greek, Script::Cherokee]);
+        let mut other = ScriptSet::ALL;
+        let mut resolved = ScriptSet::ALL;
+        for (index, item) in input.iter_indices() {
+            let scripts = item.to_char().map_or(ScriptSet::new(&[Script::Unknown]), ScriptSet::of);
+            resolved = resolved.intersection(scripts);
+            for cover in covers.iter_mut() {
+                if cover.intersection(scripts).is_empty() {
+                    *cover = ScriptSet::EMPTY;
+                }
+            }
+            if !scripts.contains(Script::Latin) {
+                other = other.intersection(scripts);
+            }
+            let covered = match self {
+                RestrictionLevel::SingleScript => false,
+                RestrictionLevel::HighlyRestrictive => covers.iter().any(|cover| !cover.is_empty()),
+                RestrictionLevel::ModeratelyRestrictive => {
+                    covers.iter().any(|cover| !cover.is_empty())
+                        || !other.difference(excluded).is_empty()
+                },
+            };
+            if resolved.is_empty() && !covered {
+                return Some(index);
+            }
+        }
+        None
+    }
+}
+
+// The identifier properties an identifier syntax extends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Base {
+    // `XID_Start` and `XID_Continue`, which are closed under NFKC.
+    Xid,
+    // `ID_Start` and `ID_Continue`.
+    Id,
+}
+
+impl Default for Base {
+    #[inline]
+    fn default() -> Self {
+        Base::Xid
+    }
+}
+
+/// The characters allowed in an identifier.
+///
+/// The default syntax of UAX #31 is an `XID_Start` character followed
+/// by any `XID_Continue` characters. Languages extend it: Rust allows
+/// `'_'` at the start, and some languages allow `'$'`. Characters
+/// allowed at the start may also continue an identifier.
+///
+/// Medial characters, such as U+200C ZERO WIDTH NON-JOINER in Persian
+/// (UAX #31 R1a), are only allowed between two continue characters, so
+/// they can neither end an identifier nor follow each other.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IdentifierSpec {
+    base: Base,
+    start: Vec<char>,
+    start_categories: Vec<GeneralCategory>,
+    continues: Vec<char>,
+    continue_categories: Vec<GeneralCategory>,
+    medial: Vec<char>,
+    restriction: Option<RestrictionLevel>,
+}
+
+impl IdentifierSpec {
+    /// Create the default identifier syntax of UAX #31.
+    #[inline]
+    pub fn default_uax31() -> Self {
+        Self::default()
+    }
+
+    /// Create the identifier syntax of Rust.
+    ///
+    /// This is `XID_Start` or `'_'`, followed by `XID_Continue`. A lone
+    /// `'_'` is recognized, though Rust reserves it.
+    #[inline]
+    pub fn rust() -> Self {
+        Self::default_uax31().allow_start('_')
+    }
+
+    /// Create the identifier syntax of Python 3, from PEP 3131.
+    ///
+    /// This recognizes the same identifiers as `rust`. Python compares
+    /// identifiers after NFKC normalization, so `"ﬁle"` and `"file"` name
+    /// the same variable, but the identifier is returned as written.
+    #[inline]
+    pub fn python() -> Self {
+        Self::default_uax31().allow_start('_')
+    }
+
+    /// Create the identifier syntax of JavaScript (ECMAScript).
+    ///
+    /// This is `ID_Start`, `'$'` or `'_'`, followed by `ID_Continue`,
+    /// `'$'`, U+200C ZERO WIDTH NON-JOINER or U+200D ZERO WIDTH JOINER.
+    /// Unlike `XID_Start`, `ID_Start` includes characters such as U+309B
+    /// that change under NFKC. `\u` escapes in identifiers are not
+    /// decoded.
+    #[inline]
+    pub fn javascript() -> Self {
+        IdentifierSpec { base: Base::Id, ..Self::default() }
+            .allow_start('$')
+            .allow_start('_')
+            .allow_continue('\u{200C}')
+            .allow_continue('\u{200D}')
+    }
+
+    /// Allow `c` at the start of an identifier.
+    #[inline]
+    pub fn allow_start(mut self, c: char) -> Self {
+        self.start.push(c);
+        self
+    }
+
+    /// Allow characters in the general `category` at the start of an identifier.
+    #[inline]
+    pub fn allow_start_category(mut self, category: GeneralCategory) -> Self {
+        self.start_categories.push(category);
+        self
+    }
+
+    /// Allow `c` after the start of an identifier.
+    #[inline]
+    pub fn allow_continue(mut self, c: char) -> Self {
+        self.continues.push(c);
+        self
+    }
+
+    /// Allow characters in the general `category` after the start of an identifier.
+    #[inline]
+    pub fn allow_continue_category(mut self, category: GeneralCategory) -> Self {
+        self.continue_categories.push(category);
+        self
+    }
+
+    /// Allow each of `chars` between two continue characters.
+    #[inline]
+    pub fn allow_medial(mut self, chars: &[char]) -> Self {
+        self.medial.extend_from_slice(chars);
+        self
+    }
+
+    /// Restrict the scripts that can be mixed in an identifier.
+    ///
+    /// An identifier that breaks the restriction fails with
+    /// `ErrorKind::Verify`, at the first character that breaks it.
+    #[inline]
+    pub fn restriction(mut self, level: RestrictionLevel) -> Self {
+        self.restriction = Some(level);
+        self
+    }
+
+    /// Check if a character can start an identifier.
+    #[inline]
+    pub fn is_start(&self, c: char) -> bool {
+        let base = match self.base {
+            Base::Xid => is_xid_start(c),
+            Base::Id => is_id_start(c),
+        };
+        base
+            || self.start.contains(&c)
+            || self.start_categories.contains(&general_category_of(c))
+    }
+
+    /// Check if a character can continue an identifier.
+    #[inline]
+    pub fn is_continue(&self, c: char) -> bool {
+        let base = match self.base {
+            Base::Xid => is_xid_continue(c),
+            Base::Id => is_id_continue(c),
+        };
+        base
+            || self.continues.contains(&c)
+            || self.continue_categories.contains(&general_category_of(c))
+            || self.is_start(c)
+    }
+
+    /// Check if a character is only allowed between two continue characters.
+    #[inline]
+    pub fn is_medial(&self, c: char) -> bool {
+        self.medial.contains(&c)
+    }
+
+    /// Get a complete parser for identifiers, as `complete::identifier`.
+    #[inline]
+    pub fn parser<T, Error>(&self) -> impl Fn(T) -> IResult<T, T, Error> + '_
+        where T: InputIter + InputTake + InputLength,
+              <T as InputIter>::Item: IsChar,
+              Error: ParseError<T>
+    {
+        move |input: T| self.parse(input, false)
+    }
+
+    /// Get a streaming parser for identifiers, as `streaming::identifier`.
+    #[inline]
+    pub fn streaming_parser<T, Error>(&self) -> impl Fn(T) -> IResult<T, T, Error> + '_
+        where T: InputIter + InputTake + InputLength,
+              <T as InputIter>::Item: IsChar,
+              Error: ParseError<T>
+    {
+        move |input: T| self.parse(input, true)
+    }
+
+    // Recognize an identifier at the start of `input`.
+    //
+    // A streaming parser cannot end the identifier at the end of the
+    // input, since a continue character could still follow.
+    pub(crate) fn parse<T, Error>(&self, input: T, streaming: bool)
+        -> IResult<T, T, Error>
+        where T: InputIter + InputTake + InputLength,
+              <T as InputIter>::Item: IsChar,
+              Error: ParseError<T>
+    {
+        let mut iter = input.iter_indices();
+        match iter.next().map(|(_, item)| item.to_char()) {
+            Some(Some(c)) if self.is_start(c) => (),
+            None if streaming => return Err(Err::Incomplete(Needed::new(1))),
+            _ => return Err(Err::Error(Error::from_error_kind(input, ErrorKind::TakeWhile1))),
+        }
+
+        // The end of the last continue character, and if a medial
+        // character follows it.
+        let mut end = 0;
+        let mut medial = false;
+        for (index, item) in iter {
+            if !medial {
+                end = index;
+            }
+            match item.to_char() {
+                Some(c) if self.is_continue(c) => medial = false,
+                Some(c) if !medial && self.is_medial(c) => medial = true,
+                _ => return self.finish(input, end),
+            }
+        }
+
+        if streaming {
+            Err(Err::Incomplete(Needed::new(1)))
+        } else if medial {
+            self.finish(input, end)
+        } else {
+            let length = input.input_len();
+            self.finish(input, length)
+        }
+    }
+
+    // Split the identifier ending at `end`, if it meets the restriction.
+    fn finish<T, Error>(&self, input: T, end: usize)
+        -> IResult<T, T, Error>
+        where T: InputIter + InputTake,
+              <T as InputIter>::Item: IsChar,
+              Error: ParseError<T>
+    {
+        let (rest, ident) = input.take_split(end);
+        match self.restriction.and_then(|level| level.find_violation(&ident)) {
+            Some(index) => Err(Err::Error(Error::from_error_kind(input.take_split(index).0, ErrorKind::Verify))),
+            None => Ok((rest, ident)),
+        }
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::error::Error as NError;
+
+    fn persian() -> IdentifierSpec {
+        IdentifierSpec::default_uax31().allow_medial(&['\u{200C}'])
+    }
+
+    #[test]
+    fn default_uax31_test() {
+        let spec = IdentifierSpec::default_uax31();
+        let parser = spec.parser::<_, NError<&str>>();
+        assert_eq!(parser("foo_bar1 x"), Ok((" x", "foo_bar1")));
+        assert_eq!(parser("été"), Ok(("", "été")));
+        assert_eq!(parser("_foo"), Err(Err::Error(NError::new("_foo", ErrorKind::TakeWhile1))));
+        assert_eq!(parser("1foo"), Err(Err::Error(NError::new("1foo", ErrorKind::TakeWhile1))));
+        assert_eq!(parser(""), Err(Err::Error(NError::new("", ErrorKind::TakeWhile1))));
+    }
+
+    #[test]
+    fn allow_test() {
+        let spec = IdentifierSpec::default_uax31()
+            .allow_start('_')
+            .allow_start('$')
+            .allow_continue('-')
+            .allow_continue_category(GeneralCategory::Pc);
+        let parser = spec.parser::<_, NError<&str>>();
+        assert_eq!(parser("_foo"), Ok(("", "_foo")));
+        assert_eq!(parser("$a$b"), Ok(("", "$a$b")));
+        assert_eq!(parser("a-b‿c"), Ok(("", "a-b‿c")));
+        assert_eq!(parser("-a"), Err(Err::Error(NError::new("-a", ErrorKind::TakeWhile1))));
+
+        let spec = IdentifierSpec::default_uax31().allow_start_category(GeneralCategory::So);
+        assert_eq!(spec.parser::<_, NError<&str>>()("🦀rs"), Ok(("", "🦀rs")));
+    }
+
+    #[test]
+    fn medial_test() {
+        let spec = persian();
+        let parser = spec.parser::<_, NError<&str>>();
+        assert_eq!(parser("می\u{200C}خواهم"), Ok(("", "می\u{200C}خواهم")));
+        assert_eq!(parser("می\u{200C}خواهم "), Ok((" ", "می\u{200C}خواهم")));
+        assert_eq!(parser("نامه\u{200C}"), Ok(("\u{200C}", "نامه")));
+        assert_eq!(parser("نامه\u{200C} "), Ok(("\u{200C} ", "نامه")));
+        assert_eq!(parser("a\u{200C}\u{200C}b"), Ok(("\u{200C}\u{200C}b", "a")));
+        assert_eq!(parser("\u{200C}a"), Err(Err::Error(NError::new("\u{200C}a", ErrorKind::TakeWhile1))));
+
+        // Without the medial, the identifier stops at the ZWNJ.
+        let spec = IdentifierSpec::default_uax31();
+        assert_eq!(spec.parser::<_, NError<&str>>()("می\u{200C}خواهم"), Ok(("\u{200C}خواهم", "می")));
+    }
+
+    #[test]
+    fn restriction_test() {
+        let spec = IdentifierSpec::default_uax31().restriction(RestrictionLevel::SingleScript);
+        let parser = spec.parser::<_, NError<&str>>();
+        assert_eq!(parser("paypal"), Ok(("", "paypal")));
+        assert_eq!(parser("пайпал"), Ok(("", "пайпал")));
+        assert_eq!(parser("東京タワー"), Ok(("", "東京タワー")));
+        assert_eq!(parser("latin1_x"), Ok(("", "latin1_x")));
+        // The error is at the first character that mixes scripts.
+        assert_eq!(parser("p\u{430}ypal"), Err(Err::Error(NError::new("\u{430}ypal", ErrorKind::Verify))));
+        assert_eq!(parser("東京abc"), Err(Err::Error(NError::new("abc", ErrorKind::Verify))));
+
+        let spec = IdentifierSpec::default_uax31().restriction(RestrictionLevel::HighlyRestrictive);
+        let parser = spec.parser::<_, NError<&str>>();
+        assert_eq!(parser("東京abc"), Ok(("", "東京abc")));
+        assert_eq!(parser("abc한글漢字"), Ok(("", "abc한글漢字")));
+        assert_eq!(parser("p\u{430}ypal"), Err(Err::Error(NError::new("\u{430}ypal", ErrorKind::Verify))));
+        assert_eq!(parser("abcひら한"), Err(Err::Error(NError::new("한", ErrorKind::Verify))));
+        assert_eq!(parser("abcαβ"), Err(Err::Error(NError::new("αβ", ErrorKind::Verify))));
+
+        let spec = IdentifierSpec::default_uax31().restriction(RestrictionLevel::ModeratelyRestrictive);
+        let parser = spec.parser::<_, NError<&str>>();
+        assert_eq!(parser("東京abc"), Ok(("", "東京abc")));
+        assert_eq!(parser("abcكتب"), Ok(("", "abcكتب")));
+        assert_eq!(parser("abcकखग"), Ok(("", "abcकखग")));
+        assert_eq!(parser("p\u{430}ypal"), Err(Err::Error(NError::new("\u{430}ypal", ErrorKind::Verify))));
+        assert_eq!(parser("abcαβ"), Err(Err::Error(NError::new("αβ", ErrorKind::Verify))));
+        assert_eq!(parser("abcكتبकखग"), Err(Err::Error(NError::new("कखग", ErrorKind::Verify))));
+    }
+
+    #[test]
+    fn presets_test() {
+        // Whether each identifier is accepted whole by Rust, Python and JavaScript.
+        let table = [
+            ("foo", true, true, true),
+            ("_foo", true, true, true),
+            ("_", true, true, true),
+            ("$foo", false, false, true),
+            ("foo$", false, false, true),
+            ("a\u{200C}b", false, false, true),
+            ("a\u{200D}", false, false, true),
+            ("été", true, true, true),
+            ("ﬁle", true, true, true),
+            ("a\u{309B}", false, false, true),
+            ("\u{37A}", false, false, true),
+            ("1foo", false, false, false),
+            ("foo-bar", false, false, false),
+        ];
+        let accepts = |spec: &IdentifierSpec, input| {
+            matches!(spec.parser::<_, NError<&str>>()(input), Ok(("", _)))
+        };
+        for &(input, rust, python, javascript) in table.iter() {
+            assert_eq!(accepts(&IdentifierSpec::rust(), input), rust, "rust: {:?}", input);
+            assert_eq!(accepts(&IdentifierSpec::python(), input), python, "python: {:?}", input);
+            assert_eq!(accepts(&IdentifierSpec::javascript(), input), javascript, "javascript: {:?}", input);
+        }
+    }
+
+    #[test]
+    fn streaming_parser_test() {
+        let spec = persian();
+        let parser = spec.streaming_parser::<_, NError<&str>>();
+        assert_eq!(parser("می\u{200C}خواهم "), Ok((" ", "می\u{200C}خواهم")));
+        assert_eq!(parser("نامه\u{200C} "), Ok(("\u{200C} ", "نامه")));
+        assert_eq!(parser("نامه\u{200C}"), Err(Err::Incomplete(Needed::new(1))));
+        assert_eq!(parser("نامه"), Err(Err::Incomplete(Needed::new(1))));
+        assert_eq!(parser(""), Err(Err::Incomplete(Needed::new(1))));
+    }
+}
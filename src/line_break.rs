@@ -0,0 +1,293 @@
+//! Line break opportunities, following UAX #14.
+//!
+//! This implements the default line breaking algorithm of Unicode 14.0,
+//! using the tables bundled with the crate, without tailoring. Complex
+//! context characters (`SA`), such as Thai, are treated as alphabetic,
+//! since finding their breaks needs a dictionary.
+
+use crate::tables::{self, EastAsianWidth, GeneralCategory, LineBreakClass as Lb};
+
+// Get the class of a character after LB1, and if it is wide for LB30.
+#[inline]
+fn resolve(c: char) -> (Lb, bool) {
+    let class = match tables::lookup(c, tables::LINE_BREAK) {
+        Lb::AI | Lb::SG | Lb::XX => Lb::AL,
+        Lb::SA => match tables::lookup(c, tables::GENERAL_CATEGORY) {
+            GeneralCategory::Mn | GeneralCategory::Mc => Lb::CM,
+            _ => Lb::AL,
+        },
+        Lb::CJ => Lb::NS,
+        class => class,
+    };
+    let wide = matches!(tables::lookup(c, tables::EAST_ASIAN_WIDTH), EastAsianWidth::F | EastAsianWidth::W | EastAsianWidth::H);
+    (class, wide)
+}
+
+// An unassigned `Extended_Pictographic` character, for LB30b.
+#[inline]
+fn is_unassigned_pictographic(c: char) -> bool {
+    tables::contains(c, tables::EXTENDED_PICTOGRAPHIC)
+        && tables::lookup(c, tables::GENERAL_CATEGORY) == GeneralCategory::Cn
+}
+
+// If the class ends the line (LB4 and LB5).
+#[inline]
+fn is_mandatory(class: Lb) -> bool {
+    matches!(class, Lb::BK | Lb::CR | Lb::LF | Lb::NL)
+}
+
+// If a combining mark or ZWJ after the class is part of the same
+// character (LB9).
+#[inline]
+fn takes_marks(class: Lb) -> bool {
+    !matches!(class, Lb::BK | Lb::CR | Lb::LF | Lb::NL | Lb::SP | Lb::ZW)
+}
+
+// The classes of the Hangul syllable blocks.
+#[inline]
+fn is_hangul(class: Lb) -> bool {
+    matches!(class, Lb::JL | Lb::JV | Lb::JT | Lb::H2 | Lb::H3)
+}
+
+// The context of the characters before a possible break.
+#[derive(Clone, Copy, Debug)]
+struct Context {
+    // The class of the previous character, after LB9 and LB10.
+    prev: Lb,
+    // The class of the character before `prev`.
+    prev2: Option<Lb>,
+    // The class of the last character that was not a space.
+    before_spaces: Lb,
+    // If the previous character is wide, for LB30.
+    wide: bool,
+    // If the previous character is an unassigned pictographic, for LB30b.
+    pictographic: bool,
+    // The count of regional indicators ending the text, for LB30a.
+    regional: usize,
+}
+
+impl Context {
+    // Apply the rules from LB11 on to a pair of characters.
+    fn keeps(&self, next: Lb, next_wide: bool) -> bool {
+        let prev = self.prev;
+        match (prev, next) {
+            // LB11 to LB13.
+            (Lb::WJ, _) | (_, Lb::WJ) | (Lb::GL, _) => true,
+            (p, Lb::GL) if !matches!(p, Lb::SP | Lb::BA | Lb::HY) => true,
+            (_, Lb::CL) | (_, Lb::CP) | (_, Lb::EX) | (_, Lb::IS) | (_, Lb::SY) => true,
+            // LB14 to LB17, across spaces.
+            _ if self.before_spaces == Lb::OP => true,
+            (_, Lb::OP) if self.before_spaces == Lb::QU => true,
+            (_, Lb::NS) if matches!(self.before_spaces, Lb::CL | Lb::CP) => true,
+            (_, Lb::B2) if self.before_spaces == Lb::B2 => true,
+            // LB18 to LB22.
+            (Lb::SP, _) => false,
+            (Lb::QU, _) | (_, Lb::QU) => true,
+            (Lb::CB, _) | (_, Lb::CB) => false,
+            (_, Lb::BA) | (_, Lb::HY) | (_, Lb::NS) | (Lb::BB, _) => true,
+            (Lb::HY, _) | (Lb::BA, _) if self.prev2 == Some(Lb::HL) => true,
+            (Lb::SY, Lb::HL) => true,
+            (_, Lb::IN) => true,
+            // LB23 to LB29: numbers, Hangul and letters.
+            (Lb::AL, Lb::NU) | (Lb::HL, Lb::NU) | (Lb::NU, Lb::AL) | (Lb::NU, Lb::HL) => true,
+            (Lb::PR, Lb::ID) | (Lb::PR, Lb::EB) | (Lb::PR, Lb::EM)
+            | (Lb::ID, Lb::PO) | (Lb::EB, Lb::PO) | (Lb::EM, Lb::PO) => true,
+            (Lb::PR, Lb::AL) | (Lb::PR, Lb::HL) | (Lb::PO, Lb::AL) | (Lb::PO, Lb::HL)
+            | (Lb::AL, Lb::PR) | (Lb::AL, Lb::PO) | (Lb::HL, Lb::PR) | (Lb::HL, Lb::PO) => true,
+            (Lb::CL, Lb::PO) | (Lb::CL, Lb::PR) | (Lb::CP, Lb::PO)
+            | (Lb::CP, Lb::PR) | (Lb::NU, Lb::PO) | (Lb::NU, Lb::PR) => true,
+            (Lb::PO, Lb::OP) | (Lb::PO, Lb::NU) | (Lb::PR, Lb::OP) | (Lb::PR, Lb::NU)
+            | (Lb::HY, Lb::NU) | (Lb::IS, Lb::NU) | (Lb::NU, Lb::NU) | (Lb::SY, Lb::NU) => true,
+            (Lb::JL, Lb::JL) | (Lb::JL, Lb::JV) | (Lb::JL, Lb::H2) | (Lb::JL, Lb::H3) => true,
+            (Lb::JV, Lb::JV) | (Lb::JV, Lb::JT) | (Lb::H2, Lb::JV) | (Lb::H2, Lb::JT)
+            | (Lb::JT, Lb::JT) | (Lb::H3, Lb::JT) => true,
+            (p, Lb::PO) if is_hangul(p) => true,
+            (Lb::PR, n) if is_hangul(n) => true,
+            (Lb::AL, Lb::AL) | (Lb::AL, Lb::HL) | (Lb::HL, Lb::AL) | (Lb::HL, Lb::HL)
+            | (Lb::IS, Lb::AL) | (Lb::IS, Lb::HL) => true,
+            // LB30 to LB30b.
+            (Lb::AL, Lb::OP) | (Lb::HL, Lb::OP) | (Lb::NU, Lb::OP) => !next_wide,
+            (Lb::CP, Lb::AL) | (Lb::CP, Lb::HL) | (Lb::CP, Lb::NU) => !self.wide,
+            (Lb::RI, Lb::RI) => self.regional % 2 == 1,
+            (Lb::EB, Lb::EM) => true,
+            (_, Lb::EM) => self.pictographic,
+            _ => false,
+        }
+    }
+
+    // Move past a character that was kept with the previous one.
+    fn push(&mut self, class: Lb, c: char, wide: bool) {
+        self.prev2 = Some(self.prev);
+        self.prev = class;
+        if class != Lb::SP {
+            self.before_spaces = class;
+        }
+        self.wide = wide;
+        self.pictographic = is_unassigned_pictographic(c);
+        self.regional = if class == Lb::RI { self.regional + 1 } else { 0 };
+    }
+}
+
+/// Find the end of the text before the first line break opportunity.
+///
+/// The characters are given with their indexes, and `None` for items
+/// that are not scalar values, which always end the text. `length` is
+/// the length of the input. The text includes any spaces before the
+/// opportunity, and the line terminator of a mandatory break. Returns
+/// `Err` with the end at the end of the input if more input could move
+/// it, which streaming parsers need to treat as incomplete.
+pub(crate) fn break_opportunity<I>(chars: I, length: usize) -> Result<usize, usize>
+    where I: IntoIterator<Item = (usize, Option<char>)>
+{
+    let mut chars = chars.into_iter();
+    let (first, c) = match chars.next() {
+        Some((_, Some(c))) => (resolve(c), c),
+        Some((index, None)) => return Ok(index),
+        None => return Err(length),
+    };
+
+    // The class of the previous character, before LB9 and LB10.
+    let mut raw = first.0;
+    let class = match first.0 {
+        Lb::CM | Lb::ZWJ => Lb::AL,
+        class => class,
+    };
+    let mut context = Context {
+        prev: class,
+        prev2: None,
+        before_spaces: class,
+        wide: first.1,
+        pictographic: is_unassigned_pictographic(c),
+        regional: usize::from(class == Lb::RI),
+    };
+    for (index, c) in chars {
+        let c = match c {
+            Some(c) => c,
+            None => return Ok(index),
+        };
+        let (next, wide) = resolve(c);
+        let keep = match next {
+            Lb::LF if raw == Lb::CR => true,
+            _ if is_mandatory(raw) => false,
+            Lb::BK | Lb::CR | Lb::LF | Lb::NL | Lb::SP | Lb::ZW => true,
+            _ if context.before_spaces == Lb::ZW => false,
+            Lb::CM | Lb::ZWJ if takes_marks(raw) => {
+                // LB9: the mark is part of the previous character.
+                raw = next;
+                continue;
+            },
+            // LB8a, after LB9 so marks after a joiner are absorbed too.
+            _ if raw == Lb::ZWJ => true,
+            Lb::CM | Lb::ZWJ => context.keeps(Lb::AL, wide),
+            _ => context.keeps(next, wide),
+        };
+        if !keep {
+            return Ok(index);
+        }
+        raw = next;
+        let class = match next {
+            Lb::CM | Lb::ZWJ => Lb::AL,
+            class => class,
+        };
+        context.push(class, c, wide);
+    }
+
+    match raw {
+        // A mandatory break always ends the text.
+        Lb::BK | Lb::LF | Lb::NL => Ok(length),
+        _ => Err(length),
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(s: &str) -> Vec<&str> {
+        let mut lines = Vec::new();
+        let mut rest = s;
+        while !rest.is_empty() {
+            let end = break_opportunity(rest.char_indices().map(|(i, c)| (i, Some(c))), rest.len()).unwrap_or_else(|end| end);
+            lines.push(&rest[..end]);
+            rest = &rest[end..];
+        }
+        lines
+    }
+
+    #[test]
+    fn break_opportunity_test() {
+        // Spaces stay with the text before them.
+        assert_eq!(lines("hello big  world"), ["hello ", "big  ", "world"]);
+        assert_eq!(lines("a ! b"), ["a ! ", "b"]);
+        assert_eq!(lines("( a"), ["( a"]);
+        // Mandatory breaks.
+        assert_eq!(lines("a\r\nb\nc\u{85}d\u{2028}"), ["a\r\n", "b\n", "c\u{85}", "d\u{2028}"]);
+        assert_eq!(lines("a\r\rb"), ["a\r", "\r", "b"]);
+        // BA: break after hyphens and some spaces, but not after a Hebrew letter's.
+        assert_eq!(lines("well-known"), ["well-", "known"]);
+        assert_eq!(lines("a\u{2010}b"), ["a\u{2010}", "b"]);
+        assert_eq!(lines("a\u{2002}b"), ["a\u{2002}", "b"]);
+        assert_eq!(lines("\u{5D0}-\u{5D1}"), ["\u{5D0}-\u{5D1}"]);
+        // GL: no-break spaces glue both sides.
+        assert_eq!(lines("10\u{A0}km"), ["10\u{A0}km"]);
+        assert_eq!(lines("a \u{A0}b"), ["a ", "\u{A0}b"]);
+        assert_eq!(lines("a\u{2060}\u{FEFF}b"), ["a\u{2060}\u{FEFF}b"]);
+        // NS: small kana and iteration marks do not start a line.
+        assert_eq!(lines("あぁ々い"), ["あぁ々", "い"]);
+        assert_eq!(lines("「あ」。い"), ["「あ」。", "い"]);
+        // ID: ideographs break between each other.
+        assert_eq!(lines("日本語"), ["日", "本", "語"]);
+        assert_eq!(lines("日本 語"), ["日", "本 ", "語"]);
+        // Numbers.
+        assert_eq!(lines("$1,000.50% x"), ["$1,000.50% ", "x"]);
+        assert_eq!(lines("-5 (a)b"), ["-5 ", "(a)b"]);
+        assert_eq!(lines("a\u{FF08}b"), ["a", "\u{FF08}b"]);
+        // Combining marks, joiners and regional indicators.
+        assert_eq!(lines("e\u{301}\u{301} \u{301}"), ["e\u{301}\u{301} ", "\u{301}"]);
+        assert_eq!(lines("👩\u{200D}👧👍\u{1F3FB}"), ["👩\u{200D}👧", "👍\u{1F3FB}"]);
+        assert_eq!(lines("」\u{200D}\u{301}a"), ["」\u{200D}\u{301}", "a"]);
+        assert_eq!(lines("」\u{200D}a"), ["」\u{200D}a"]);
+        assert_eq!(lines("🇫🇷🇩🇪🇺"), ["🇫🇷", "🇩🇪", "🇺"]);
+        // Zero width spaces break even before punctuation.
+        assert_eq!(lines("a\u{200B}b\u{200B} !"), ["a\u{200B}", "b\u{200B} ", "!"]);
+    }
+
+    // The test cases of `LineBreakTest.txt`, which use the untailored
+    // LB25 like this module.
+    #[test]
+    fn conformance_test() {
+        let data = include_str!("../scripts/data/LineBreakTest.txt");
+        for line in data.lines().filter(|line| !line.starts_with('#')) {
+            let mut text = String::new();
+            let mut expected = Vec::new();
+            for token in line.split_whitespace() {
+                match token {
+                    "\u{F7}" if !text.is_empty() => expected.push(text.len()),
+                    "\u{F7}" | "\u{D7}" => (),
+                    code => text.push(core::char::from_u32(u32::from_str_radix(code, 16).unwrap()).unwrap()),
+                }
+            }
+            let actual: Vec<_> = lines(&text).iter()
+                .scan(0, |end, line| {
+                    *end += line.len();
+                    Some(*end)
+                })
+                .collect();
+            assert_eq!(actual, expected, "{}", line);
+        }
+    }
+
+    #[test]
+    fn provisional_test() {
+        let end = |s: &str| break_opportunity(s.char_indices().map(|(i, c)| (i, Some(c))), s.len());
+        assert_eq!(end("a b"), Ok(2));
+        assert_eq!(end("a "), Err(2));
+        assert_eq!(end("ab"), Err(2));
+        assert_eq!(end("a\r"), Err(2));
+        assert_eq!(end("a\n"), Ok(2));
+        assert_eq!(end(""), Err(0));
+    }
+}
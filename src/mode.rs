@@ -0,0 +1,88 @@
+//! Completeness modes, to share a grammar between complete and streaming input.
+
+use nom::{IResult, InputTakeAtPosition};
+use nom::error::{ErrorKind, ParseError};
+
+/// How a parser treats the end of its input.
+///
+/// Parsers generic over the mode, such as those in [`generic`](crate::generic),
+/// can be instantiated for buffered input with [`Complete`], or for
+/// partial input with [`Streaming`].
+pub trait Mode {
+    /// If the end of the input could be followed by more data.
+    const STREAMING: bool;
+
+    /// Split the input at the first item matching `pred`.
+    ///
+    /// This is `split_at_position` or `split_at_position_complete`.
+    fn split_at_position0<T, P, Error>(input: T, pred: P)
+        -> IResult<T, T, Error>
+        where T: InputTakeAtPosition,
+              P: Fn(<T as InputTakeAtPosition>::Item) -> bool,
+              Error: ParseError<T>;
+
+    /// Split the input at the first item matching `pred`, failing with `kind` if it is the first.
+    ///
+    /// This is `split_at_position1` or `split_at_position1_complete`.
+    fn split_at_position1<T, P, Error>(input: T, pred: P, kind: ErrorKind)
+        -> IResult<T, T, Error>
+        where T: InputTakeAtPosition,
+              P: Fn(<T as InputTakeAtPosition>::Item) -> bool,
+              Error: ParseError<T>;
+}
+
+/// Mode for complete input, where the end of the input ends every parser.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Complete;
+
+impl Mode for Complete {
+    const STREAMING: bool = false;
+
+    #[inline]
+    fn split_at_position0<T, P, Error>(input: T, pred: P)
+        -> IResult<T, T, Error>
+        where T: InputTakeAtPosition,
+              P: Fn(<T as InputTakeAtPosition>::Item) -> bool,
+              Error: ParseError<T>
+    {
+        input.split_at_position_complete(pred)
+    }
+
+    #[inline]
+    fn split_at_position1<T, P, Error>(input: T, pred: P, kind: ErrorKind)
+        -> IResult<T, T, Error>
+        where T: InputTakeAtPosition,
+              P: Fn(<T as InputTakeAtPosition>::Item) -> bool,
+              Error: ParseError<T>
+    {
+        input.split_at_position1_complete(pred, kind)
+    }
+}
+
+/// Mode for partial input, where parsers return `Incomplete` at the end of the input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Streaming;
+
+impl Mode for Streaming {
+    const STREAMING: bool = true;
+
+    #[inline]
+    fn split_at_position0<T, P, Error>(input: T, pred: P)
+        -> IResult<T, T, Error>
+        where T: InputTakeAtPosition,
+              P: Fn(<T as InputTakeAtPosition>::Item) -> bool,
+              Error: ParseError<T>
+    {
+        input.split_at_position(pred)
+    }
+
+    #[inline]
+    fn split_at_position1<T, P, Error>(input: T, pred: P, kind: ErrorKind)
+        -> IResult<T, T, Error>
+        where T: InputTakeAtPosition,
+              P: Fn(<T as InputTakeAtPosition>::Item) -> bool,
+              Error: ParseError<T>
+    {
+        input.split_at_position1(pred, kind)
+    }
+}
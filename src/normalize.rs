@@ -0,0 +1,236 @@
+//! Canonical decomposition and ordering of characters.
+//!
+//! This implements canonical decomposition and composition from the
+//! Unicode Normalization Algorithm (UAX #15), using the tables bundled
+//! with the crate.
+
+use crate::tables;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// Hangul syllables are decomposed algorithmically (Unicode 3.12).
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// Get the canonical combining class of a character.
+#[inline]
+pub(crate) fn combining_class(c: char) -> u8 {
+    tables::lookup(c, tables::COMBINING_CLASS)
+}
+
+/// Append the full canonical decomposition of a character to `out`.
+pub(crate) fn decompose(c: char, out: &mut Vec<char>) {
+    let index = (c as u32).wrapping_sub(S_BASE);
+    if index < S_COUNT {
+        // The jamo are all valid scalar values.
+        let jamo = |value| core::char::from_u32(value).unwrap();
+        out.push(jamo(L_BASE + index / N_COUNT));
+        out.push(jamo(V_BASE + (index % N_COUNT) / T_COUNT));
+        let trailing = index % T_COUNT;
+        if trailing != 0 {
+            out.push(jamo(T_BASE + trailing));
+        }
+    } else if let Some(decomposition) = tables::lookup_str(c, tables::CANONICAL_DECOMPOSITION) {
+        out.extend(decomposition.chars());
+    } else {
+        out.push(c);
+    }
+}
+
+/// Sort each run of non-starters by their canonical combining class.
+///
+/// The sort is stable, so marks with the same class keep their order.
+pub(crate) fn reorder(chars: &mut [char]) {
+    let mut start = 0;
+    while start < chars.len() {
+        if combining_class(chars[start]) == 0 {
+            start += 1;
+            continue;
+        }
+        let end = chars[start..].iter()
+            .position(|&c| combining_class(c) == 0)
+            .map_or(chars.len(), |length| start + length);
+        chars[start..end].sort_by_key(|&c| combining_class(c));
+        start = end;
+    }
+}
+
+/// Get the Normalization Form D (NFD) of a sequence of characters.
+pub(crate) fn nfd<I: IntoIterator<Item = char>>(chars: I) -> Vec<char> {
+    let mut out = Vec::new();
+    for c in chars {
+        decompose(c, &mut out);
+    }
+    reorder(&mut out);
+    out
+}
+
+/// Check if a character decomposes to a sequence starting with a non-starter.
+///
+/// Such a character always belongs to the preceding combining sequence.
+#[inline]
+pub(crate) fn is_non_starter(c: char) -> bool {
+    let first = tables::lookup_str(c, tables::CANONICAL_DECOMPOSITION)
+        .and_then(|decomposition| decomposition.chars().next())
+        .unwrap_or(c);
+    combining_class(first) != 0
+}
+
+/// Get the primary composite of a pair of characters, if any.
+pub(crate) fn compose(first: char, second: char) -> Option<char> {
+    let (x, y) = (first as u32, second as u32);
+    let l_index = x.wrapping_sub(L_BASE);
+    let v_index = y.wrapping_sub(V_BASE);
+    if l_index < L_COUNT && v_index < V_COUNT {
+        return core::char::from_u32(S_BASE + (l_index * V_COUNT + v_index) * T_COUNT);
+    }
+    let s_index = x.wrapping_sub(S_BASE);
+    let t_index = y.wrapping_sub(T_BASE);
+    if s_index < S_COUNT && s_index % T_COUNT == 0 && t_index > 0 && t_index < T_COUNT {
+        return core::char::from_u32(x + t_index);
+    }
+    tables::COMPOSITION.binary_search_by_key(&(x, y), |&(a, b, _)| (a, b))
+        .ok()
+        .and_then(|index| core::char::from_u32(tables::COMPOSITION[index].2))
+}
+
+/// Get the Normalization Form C (NFC) of a sequence of characters.
+pub(crate) fn nfc<I: IntoIterator<Item = char>>(chars: I) -> Vec<char> {
+    let decomposed = nfd(chars);
+    let mut out: Vec<char> = Vec::with_capacity(decomposed.len());
+    // The last starter, and the class of the last character after it.
+    let mut starter = None;
+    let mut last_class = None;
+    for c in decomposed {
+        let class = combining_class(c);
+        if let Some(index) = starter {
+            let blocked = matches!(last_class, Some(last) if last == 0 || last >= class);
+            if !blocked {
+                if let Some(composite) = compose(out[index], c) {
+                    out[index] = composite;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(out.len());
+            last_class = None;
+        } else {
+            last_class = Some(class);
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// The result of a normalization quick check.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum QuickCheck {
+    Yes,
+    No,
+    Maybe,
+}
+
+/// Check if a sequence of characters is in NFC, without normalizing it.
+///
+/// Returns `QuickCheck::Maybe` if the answer needs a full normalization.
+pub(crate) fn nfc_quick_check<I: IntoIterator<Item = char>>(chars: I) -> QuickCheck {
+    let mut result = QuickCheck::Yes;
+    let mut last_class = 0;
+    for c in chars {
+        let class = combining_class(c);
+        if last_class > class && class != 0 {
+            return QuickCheck::No;
+        }
+        if tables::contains(c, tables::NFC_QUICK_CHECK_NO) {
+            return QuickCheck::No;
+        }
+        if tables::contains(c, tables::NFC_QUICK_CHECK_MAYBE) {
+            result = QuickCheck::Maybe;
+        }
+        last_class = class;
+    }
+    result
+}
+
+/// Get the NFC of a string, borrowing it if it is already in NFC.
+pub(crate) fn to_nfc(s: &str) -> Cow<'_, str> {
+    if nfc_quick_check(s.chars()) == QuickCheck::Yes {
+        return Cow::Borrowed(s);
+    }
+    let normalized: String = nfc(s.chars()).into_iter().collect();
+    if normalized == s {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(normalized)
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nfd_str(s: &str) -> String {
+        nfd(s.chars()).into_iter().collect()
+    }
+
+    #[test]
+    fn nfd_test() {
+        assert_eq!(nfd_str("é"), "e\u{301}");
+        assert_eq!(nfd_str("ḉ"), "c\u{327}\u{301}");
+        assert_eq!(nfd_str("latin"), "latin");
+        assert_eq!(nfd_str("\u{212B}"), "A\u{30A}");
+        // Hangul syllables, with and without a trailing consonant.
+        assert_eq!(nfd_str("한"), "\u{1112}\u{1161}\u{11AB}");
+        assert_eq!(nfd_str("가"), "\u{1100}\u{1161}");
+        // Marks are reordered by combining class: cedilla (202) before acute (230).
+        assert_eq!(nfd_str("c\u{301}\u{327}"), "c\u{327}\u{301}");
+        assert_eq!(nfd_str("a\u{301}\u{300}"), "a\u{301}\u{300}");
+    }
+
+    fn nfc_str(s: &str) -> String {
+        nfc(s.chars()).into_iter().collect()
+    }
+
+    #[test]
+    fn nfc_test() {
+        assert_eq!(nfc_str("e\u{301}"), "é");
+        assert_eq!(nfc_str("é"), "é");
+        assert_eq!(nfc_str("\u{212B}"), "Å");
+        assert_eq!(nfc_str("c\u{301}\u{327}"), "ḉ");
+        assert_eq!(nfc_str("\u{1112}\u{1161}\u{11AB}"), "한");
+        assert_eq!(nfc_str("\u{0B47}\u{0B3E}"), "\u{0B4B}");
+        // The second acute is blocked by the first.
+        assert_eq!(nfc_str("a\u{301}\u{301}"), "á\u{301}");
+        // Composition exclusions stay decomposed.
+        assert_eq!(nfc_str("\u{0958}"), "\u{0915}\u{093C}");
+    }
+
+    #[test]
+    fn nfc_quick_check_test() {
+        assert_eq!(nfc_quick_check("latin".chars()), QuickCheck::Yes);
+        assert_eq!(nfc_quick_check("été".chars()), QuickCheck::Yes);
+        assert_eq!(nfc_quick_check("e\u{301}".chars()), QuickCheck::Maybe);
+        assert_eq!(nfc_quick_check("\u{212B}".chars()), QuickCheck::No);
+        assert_eq!(nfc_quick_check("a\u{301}\u{327}".chars()), QuickCheck::No);
+    }
+
+    #[test]
+    fn to_nfc_test() {
+        assert!(matches!(to_nfc("été"), Cow::Borrowed("été")));
+        assert!(matches!(to_nfc("\u{301}"), Cow::Borrowed("\u{301}")));
+        assert_eq!(to_nfc("e\u{301}te\u{301}"), Cow::<str>::Owned("été".into()));
+        assert_eq!(to_nfc("\u{212B}"), "Å");
+    }
+}
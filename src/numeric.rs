@@ -0,0 +1,67 @@
+//! Numeric values of characters.
+//!
+//! The Unicode `Numeric_Value` property gives a value to far more than
+//! the decimal digits: vulgar fractions such as `'½'`, Roman numerals such
+//! as `'Ⅻ'`, circled numbers such as `'㊿'`, and numeric ideographs. The
+//! values are all rationals, with small denominators.
+
+use core::fmt;
+
+/// The Unicode `Numeric_Value` of a character, as a reduced rational.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NumericValue {
+    numerator: i64,
+    denominator: u32,
+}
+
+impl NumericValue {
+    // Create a value from the reduced fraction in the table.
+    #[inline]
+    pub(crate) const fn new(numerator: i64, denominator: u32) -> Self {
+        NumericValue { numerator, denominator }
+    }
+
+    /// Get the numerator, which carries the sign.
+    #[inline]
+    pub fn numerator(self) -> i64 {
+        self.numerator
+    }
+
+    /// Get the denominator, which is always positive.
+    #[inline]
+    pub fn denominator(self) -> u32 {
+        self.denominator
+    }
+
+    /// Check if the value is an integer.
+    #[inline]
+    pub fn is_integer(self) -> bool {
+        self.denominator == 1
+    }
+
+    /// Get the value as an integer, if it is one.
+    #[inline]
+    pub fn to_integer(self) -> Option<i64> {
+        if self.is_integer() {
+            Some(self.numerator)
+        } else {
+            None
+        }
+    }
+
+    /// Get the value as a float, which may round large values.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+impl fmt::Display for NumericValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Property tests for the run parsers, over random Unicode strings.
+//!
+//! Every row of `parse_table!` is checked in each mode: the match and
+//! the remainder concatenate to the input, every matched character
+//! satisfies the predicate, and the first remaining one does not.
+//!
+//! Proptest needs a newer compiler than the crate, so these only run
+//! with the `proptest` feature: `cargo test --features proptest`.
+
+use super::*;
+use alloc::string::String;
+use core::num::NonZeroUsize;
+use nom::error::Error as NError;
+use nom::{Err, IResult, Needed};
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+
+type Run = fn(&str) -> IResult<&str, &str, NError<&str>>;
+
+// The parsers of a row, in every mode, with its predicate.
+struct Row {
+    name: &'static str,
+    complete0: Run,
+    complete1: Run,
+    streaming0: Run,
+    streaming1: Run,
+    generic0: Run,
+    generic1: Run,
+    pred: fn(char) -> bool,
+}
+
+macro_rules! row_impl {
+    ($($(#[$attr:meta])* $name0:ident $struct0:ident, $name1:ident $struct1:ident, $kind:ident, $expected:ident, $callback:ident, $comment:expr)*) => (
+        fn rows() -> Vec<Row> {
+            vec![$(
+                $(#[$attr])*
+                Row {
+                    name: stringify!($name0),
+                    complete0: |input: &str| complete::$name0(input),
+                    complete1: |input: &str| complete::$name1(input),
+                    streaming0: |input: &str| streaming::$name0(input),
+                    streaming1: |input: &str| streaming::$name1(input),
+                    generic0: |input: &str| generic::$name0::<_, _, Complete>(input),
+                    generic1: |input: &str| generic::$name1::<_, _, Streaming>(input),
+                    pred: |c| $callback(c),
+                },
+            )*]
+        }
+    );
+}
+
+parse_table!(row_impl);
+
+// Reduce a result to the position of its error, since the kinds vary.
+fn position<'a>(result: IResult<&'a str, &'a str, NError<&'a str>>) -> IResult<&'a str, &'a str, &'a str> {
+    result.map_err(|e| e.map(|error| error.input))
+}
+
+// Check the invariants of a row on an input.
+fn check_row(row: &Row, input: &str) -> Result<(), TestCaseError> {
+    let one = NonZeroUsize::new(1).unwrap();
+    let name = row.name;
+    let (rest, matched) = match (row.complete0)(input) {
+        Ok(result) => result,
+        Err(e) => return Err(TestCaseError::fail(format!("{} failed on {:?}: {:?}", name, input, e))),
+    };
+    let mut joined = String::from(matched);
+    joined.push_str(rest);
+    prop_assert_eq!(&joined, input, "{} split {:?}", name, input);
+    prop_assert!(matched.chars().all(row.pred), "{} matched {:?}", name, matched);
+    prop_assert!(!rest.chars().next().map_or(false, row.pred), "{} stopped early at {:?}", name, rest);
+    prop_assert_eq!((row.generic0)(input), Ok((rest, matched)), "generic {}", name);
+
+    // The `1` variants fail at the input if nothing matches.
+    let complete1 = position((row.complete1)(input));
+    if matched.is_empty() {
+        prop_assert_eq!(complete1, Err(Err::Error(input)), "{}1 on {:?}", name, input);
+    } else {
+        prop_assert_eq!(complete1, Ok((rest, matched)), "{}1 on {:?}", name, input);
+    }
+
+    // Streaming parsers cannot end a run at the end of the input.
+    let streaming0 = position((row.streaming0)(input));
+    let streaming1 = position((row.streaming1)(input));
+    prop_assert_eq!(&position((row.generic1)(input)), &streaming1, "generic {}1", name);
+    if rest.is_empty() {
+        prop_assert_eq!(streaming0, Err(Err::Incomplete(Needed::Size(one))), "streaming {}", name);
+        prop_assert_eq!(streaming1, Err(Err::Incomplete(Needed::Size(one))), "streaming {}1", name);
+    } else if matched.is_empty() {
+        prop_assert_eq!(streaming0, Ok((rest, matched)), "streaming {} on {:?}", name, input);
+        prop_assert_eq!(streaming1, Err(Err::Error(input)), "streaming {}1 on {:?}", name, input);
+    } else {
+        prop_assert_eq!(streaming0, Ok((rest, matched)), "streaming {} on {:?}", name, input);
+        prop_assert_eq!(streaming1, Ok((rest, matched)), "streaming {}1 on {:?}", name, input);
+    }
+    Ok(())
+}
+
+// Random strings, biased towards ASCII, spaces, combining marks and
+// supplementary characters, so most classes match a few characters.
+fn unicode_string() -> impl Strategy<Value = String> {
+    let chars = prop_oneof![
+        any::<char>(),
+        proptest::char::range('\0', '\u{7F}'),
+        proptest::char::range('\u{80}', '\u{24F}'),
+        proptest::char::range('\u{300}', '\u{36F}'),
+        proptest::char::range('\u{2000}', '\u{206F}'),
+        proptest::char::range('\u{10000}', '\u{10FFFF}'),
+        proptest::sample::select(vec![' ', '\t', '\n', '\r', '\u{A0}', '\u{3000}', '\u{FEFF}']),
+    ];
+    proptest::collection::vec(chars, 0..24).prop_map(|chars| chars.into_iter().collect())
+}
+
+proptest! {
+    #[test]
+    fn run_parsers_test(input in unicode_string()) {
+        for row in rows().iter() {
+            check_row(row, &input)?;
+        }
+    }
+
+    #[test]
+    fn run_parsers_repeated_test(c in any::<char>(), count in 1usize..4, tail in unicode_string()) {
+        let mut input: String = core::iter::repeat(c).take(count).collect();
+        input.push_str(&tail);
+        for row in rows().iter() {
+            check_row(row, &input)?;
+        }
+    }
+}
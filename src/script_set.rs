@@ -0,0 +1,139 @@
+//! Sets of scripts, for the mixed-script detection of UTS #39.
+
+use crate::tables::{self, Script};
+
+// Bits for the augmented scripts of UTS #39: Han with Bopomofo, Japanese,
+// and Korean. They follow the bits of the `Script` values.
+const HANB: usize = 253;
+const JPAN: usize = 254;
+const KORE: usize = 255;
+
+/// A set of scripts, as a bit set indexed by `Script`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct ScriptSet([u64; 4]);
+
+impl ScriptSet {
+    /// The set of all scripts.
+    pub(crate) const ALL: ScriptSet = ScriptSet([u64::MAX; 4]);
+
+    /// The empty set.
+    pub(crate) const EMPTY: ScriptSet = ScriptSet([0; 4]);
+
+    /// Create a set of scripts.
+    pub(crate) fn new(scripts: &[Script]) -> Self {
+        let mut set = ScriptSet::EMPTY;
+        for &script in scripts {
+            set.insert(script as usize);
+        }
+        set
+    }
+
+    // Create a set of scripts, augmented as in UTS #39.
+    fn augmented(scripts: &[Script]) -> Self {
+        let mut set = ScriptSet::new(scripts);
+        for &script in scripts {
+            match script {
+                Script::Han => {
+                    set.insert(HANB);
+                    set.insert(JPAN);
+                    set.insert(KORE);
+                },
+                Script::Hiragana | Script::Katakana => set.insert(JPAN),
+                Script::Hangul => set.insert(KORE),
+                Script::Bopomofo => set.insert(HANB),
+                _ => (),
+            }
+        }
+        set
+    }
+
+    /// Get the augmented script extensions of a character.
+    ///
+    /// `Common` and `Inherited` characters are in every script.
+    pub(crate) fn of(c: char) -> Self {
+        let extensions = tables::lookup(c, tables::SCRIPT_EXTENSIONS);
+        if !extensions.is_empty() {
+            return ScriptSet::augmented(extensions);
+        }
+        match tables::lookup(c, tables::SCRIPT) {
+            Script::Common | Script::Inherited => ScriptSet::ALL,
+            script => ScriptSet::augmented(&[script]),
+        }
+    }
+
+    /// Get the scripts in both sets.
+    #[inline]
+    pub(crate) fn intersection(self, other: ScriptSet) -> Self {
+        let mut bits = self.0;
+        for (bit, other) in bits.iter_mut().zip(other.0.iter()) {
+            *bit &= other;
+        }
+        ScriptSet(bits)
+    }
+
+    /// Get the scripts in this set, but not in `other`.
+    #[inline]
+    pub(crate) fn difference(self, other: ScriptSet) -> Self {
+        let mut bits = self.0;
+        for (bit, other) in bits.iter_mut().zip(other.0.iter()) {
+            *bit &= !other;
+        }
+        ScriptSet(bits)
+    }
+
+    /// Check if the set has a script.
+    #[inline]
+    pub(crate) fn contains(self, script: Script) -> bool {
+        let bit = script as usize;
+        self.0[bit / 64] & (1 << (bit % 64)) != 0
+    }
+
+    /// Check if the set has no scripts.
+    #[inline]
+    pub(crate) fn is_empty(self) -> bool {
+        self.0.iter().all(|&bits| bits == 0)
+    }
+
+    #[inline]
+    fn insert(&mut self, bit: usize) {
+        self.0[bit / 64] |= 1 << (bit % 64);
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(s: &str) -> ScriptSet {
+        s.chars().fold(ScriptSet::ALL, |set, c| set.intersection(ScriptSet::of(c)))
+    }
+
+    #[test]
+    fn resolve_test() {
+        assert!(!resolve("paypal").is_empty());
+        assert!(!resolve("пайпал").is_empty());
+        assert!(resolve("p\u{430}ypal").is_empty());
+        assert!(!resolve("123-_").is_empty());
+        // Han is augmented with the Japanese and Korean scripts.
+        assert!(!resolve("東京ひらがなカタカナ").is_empty());
+        assert!(!resolve("漢字한글").is_empty());
+        assert!(resolve("ひらがな한글").is_empty());
+        assert!(resolve("東京abc").is_empty());
+        // U+30FC KATAKANA-HIRAGANA PROLONGED SOUND MARK has both scripts.
+        assert!(!resolve("ら\u{30FC}メン").is_empty());
+    }
+
+    #[test]
+    fn difference_test() {
+        let set = ScriptSet::new(&[Script::Latin, Script::Greek]);
+        assert!(set.contains(Script::Latin));
+        assert!(!set.contains(Script::Cyrillic));
+        let rest = set.difference(ScriptSet::new(&[Script::Latin]));
+        assert!(!rest.contains(Script::Latin));
+        assert!(rest.contains(Script::Greek));
+        assert!(set.difference(set).is_empty());
+    }
+}
@@ -0,0 +1,187 @@
+//! Sentence boundaries, following UAX #29.
+//!
+//! This implements the default sentence boundary rules of Unicode 14.0,
+//! using the tables bundled with the crate, without any tailoring for
+//! abbreviations. A sentence includes the spaces after its terminator,
+//! and up to one paragraph separator.
+
+use crate::tables::{self, SentenceBreak as Sb};
+
+/// Get the `Sentence_Break` property of a character.
+#[inline]
+fn break_property(c: char) -> Sb {
+    tables::lookup(c, tables::SENTENCE_BREAK)
+}
+
+// `Sep`, `CR` or `LF`.
+#[inline]
+fn is_para_sep(sb: Sb) -> bool {
+    matches!(sb, Sb::Sep | Sb::Cr | Sb::Lf)
+}
+
+// `STerm` or `ATerm`.
+#[inline]
+fn is_sa_term(sb: Sb) -> bool {
+    matches!(sb, Sb::STerm | Sb::ATerm)
+}
+
+// A terminator, followed by `Close*` and `Sp*` (SB8 to SB11).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Terminator {
+    // If the terminator is an `ATerm`, rather than an `STerm`.
+    aterm: bool,
+    // If any `Sp` follows the terminator.
+    space: bool,
+}
+
+impl Terminator {
+    #[inline]
+    fn new(sb: Sb) -> Option<Self> {
+        if is_sa_term(sb) {
+            Some(Terminator { aterm: sb == Sb::ATerm, space: false })
+        } else {
+            None
+        }
+    }
+}
+
+/// Find the end of the first sentence of a sequence of characters.
+///
+/// The characters are given with their indexes, and `None` for items
+/// that are not scalar values, which always end the sentence. `length`
+/// is the length of the input. Returns `Err` with the end at the end of
+/// the input if more input could move it, which streaming parsers need
+/// to treat as incomplete.
+pub(crate) fn sentence_end<I>(chars: I, length: usize) -> Result<usize, usize>
+    where I: IntoIterator<Item = (usize, Option<char>)>
+{
+    let mut chars = chars.into_iter();
+    let first = match chars.next() {
+        Some((_, Some(c))) => break_property(c),
+        Some((index, None)) => return Ok(index),
+        None => return Err(length),
+    };
+
+    // The class of the previous character, and of the previous two
+    // characters that were not ignored (SB5).
+    let mut raw = first;
+    let mut prev = first;
+    let mut prev2 = None;
+    let mut terminator = Terminator::new(first);
+    // A possible boundary after a terminator, while looking for the
+    // lowercase letter that would continue the sentence (SB8).
+    let mut pending: Option<usize> = None;
+    for (index, c) in chars {
+        let c = match c {
+            Some(c) => c,
+            None => return Ok(pending.unwrap_or(index)),
+        };
+        let next = break_property(c);
+        if raw == Sb::Cr && next == Sb::Lf {
+            raw = next;
+            continue;
+        } else if is_para_sep(raw) {
+            return Ok(index);
+        } else if matches!(next, Sb::Extend | Sb::Format) {
+            continue;
+        }
+
+        if let Some(start) = pending {
+            match next {
+                Sb::Lower => pending = None,
+                Sb::OLetter | Sb::Upper | Sb::Sep | Sb::Cr | Sb::Lf | Sb::STerm | Sb::ATerm => return Ok(start),
+                _ => (),
+            }
+        } else if let Some(term) = terminator.as_mut() {
+            match next {
+                // SB6 and SB7.
+                Sb::Numeric if prev == Sb::ATerm => terminator = None,
+                Sb::Upper if prev == Sb::ATerm && matches!(prev2, Some(Sb::Upper) | Some(Sb::Lower)) => terminator = None,
+                // SB8.
+                Sb::Lower if term.aterm => terminator = None,
+                // SB8a.
+                Sb::SContinue => terminator = None,
+                Sb::STerm | Sb::ATerm => terminator = Terminator::new(next),
+                // SB9 and SB10.
+                Sb::Close if !term.space => (),
+                Sb::Sp => term.space = true,
+                Sb::Sep | Sb::Cr | Sb::Lf => terminator = None,
+                // SB8 may still apply after other characters.
+                Sb::Close | Sb::Numeric | Sb::Other if term.aterm => {
+                    pending = Some(index);
+                    terminator = None;
+                },
+                // SB11.
+                _ => return Ok(index),
+            }
+        } else {
+            terminator = Terminator::new(next);
+        }
+        prev2 = Some(prev);
+        prev = next;
+        raw = next;
+    }
+
+    match pending {
+        Some(start) => Err(start),
+        // A paragraph separator always ends the sentence (SB4).
+        None if matches!(raw, Sb::Lf | Sb::Sep) => Ok(length),
+        None => Err(length),
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sentences(s: &str) -> Vec<&str> {
+        let mut sentences = Vec::new();
+        let mut rest = s;
+        while !rest.is_empty() {
+            let end = sentence_end(rest.char_indices().map(|(i, c)| (i, Some(c))), rest.len()).unwrap_or_else(|end| end);
+            sentences.push(&rest[..end]);
+            rest = &rest[end..];
+        }
+        sentences
+    }
+
+    #[test]
+    fn sentence_end_test() {
+        assert_eq!(sentences("Hello there. How are you?"), ["Hello there. ", "How are you?"]);
+        // Abbreviations only continue before a lowercase letter.
+        assert_eq!(sentences("Mr. Smith went to Washington."), ["Mr. ", "Smith went to Washington."]);
+        assert_eq!(sentences("See e.g. the docs. Or not."), ["See e.g. the docs. ", "Or not."]);
+        assert_eq!(sentences("It is etc. (and so on) done."), ["It is etc. (and so on) done."]);
+        assert_eq!(sentences("U.S.A. Today"), ["U.S.A. ", "Today"]);
+        assert_eq!(sentences("Pi is 3.14. Yes."), ["Pi is 3.14. ", "Yes."]);
+        // Closing quotes and brackets stay with the terminator.
+        assert_eq!(sentences("Is it?\u{201D} She left."), ["Is it?\u{201D} ", "She left."]);
+        assert_eq!(sentences("(Done.) Next"), ["(Done.) ", "Next"]);
+        // Ideographic and fullwidth terminators.
+        assert_eq!(sentences("你好。再见。"), ["你好。", "再见。"]);
+        assert_eq!(sentences("本当！？はい"), ["本当！？", "はい"]);
+        // Ellipses.
+        assert_eq!(sentences("Wait... what? No"), ["Wait... what? ", "No"]);
+        assert_eq!(sentences("Wait... What"), ["Wait... ", "What"]);
+        assert_eq!(sentences("Hmm\u{2026} fine\u{2026}！ Yes"), ["Hmm\u{2026} fine\u{2026}！ ", "Yes"]);
+        // Paragraph separators.
+        assert_eq!(sentences("no stop\r\nNext"), ["no stop\r\n", "Next"]);
+        assert_eq!(sentences("End.\n\nNext"), ["End.\n", "\n", "Next"]);
+        assert_eq!(sentences("a\u{2029}b"), ["a\u{2029}", "b"]);
+    }
+
+    #[test]
+    fn provisional_test() {
+        let end = |s: &str| sentence_end(s.char_indices().map(|(i, c)| (i, Some(c))), s.len());
+        assert_eq!(end("Hi. There"), Ok(4));
+        assert_eq!(end("Hi. "), Err(4));
+        assert_eq!(end("Hi. 12"), Err(4));
+        assert_eq!(end("Hi"), Err(2));
+        assert_eq!(end("Hi\r"), Err(3));
+        assert_eq!(end("Hi\n"), Ok(3));
+        assert_eq!(end(""), Err(0));
+    }
+}
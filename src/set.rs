@@ -0,0 +1,100 @@
+//! Sets of characters built from inclusive ranges.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::RangeInclusive;
+
+/// A set of characters, stored as sorted, non-overlapping ranges.
+///
+/// The ranges may be given in any order, and may overlap or be empty:
+/// they are sorted and merged on construction so lookups are a binary
+/// search.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CharSet {
+    ranges: Vec<(char, char)>,
+}
+
+impl CharSet {
+    /// Create a set from a list of inclusive ranges.
+    pub fn new(ranges: &[RangeInclusive<char>]) -> Self {
+        let mut sorted: Vec<(char, char)> = ranges.iter()
+            .filter(|range| range.start() <= range.end())
+            .map(|range| (*range.start(), *range.end()))
+            .collect();
+        sorted.sort_unstable();
+
+        let mut merged: Vec<(char, char)> = Vec::with_capacity(sorted.len());
+        for (start, end) in sorted {
+            match merged.last_mut() {
+                Some(last) if adjacent(last.1, start) => {
+                    if end > last.1 {
+                        last.1 = end;
+                    }
+                },
+                _ => merged.push((start, end)),
+            }
+        }
+        CharSet { ranges: merged }
+    }
+
+    /// Check if the set contains no characters.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Get the merged ranges in the set, in ascending order.
+    #[inline]
+    pub fn ranges(&self) -> &[(char, char)] {
+        &self.ranges
+    }
+
+    /// Check if the set contains a character.
+    #[inline]
+    pub fn contains(&self, c: char) -> bool {
+        self.ranges.binary_search_by(|&(start, end)| {
+            if end < c {
+                Ordering::Less
+            } else if start > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        }).is_ok()
+    }
+}
+
+// Check if a range starting at `start` overlaps or directly follows `end`.
+#[inline]
+fn adjacent(end: char, start: char) -> bool {
+    start as u32 <= end as u32 + 1
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let set = CharSet::new(&['x'..='z', 'a'..='c', 'b'..='e', 'f'..='f', 'q'..='p']);
+        assert_eq!(set.ranges(), &[('a', 'f'), ('x', 'z')]);
+        assert!(CharSet::new(&[]).is_empty());
+        assert!(CharSet::new(&['b'..='a']).is_empty());
+    }
+
+    #[test]
+    fn contains_test() {
+        let set = CharSet::new(&['\u{4E00}'..='\u{9FFF}', '0'..='9', '\u{20000}'..='\u{2A6DF}']);
+        assert!(set.contains('0'));
+        assert!(set.contains('9'));
+        assert!(set.contains('漢'));
+        assert!(set.contains('\u{20000}'));
+        assert!(!set.contains('/'));
+        assert!(!set.contains(':'));
+        assert!(!set.contains('\u{4DFF}'));
+        assert!(!set.contains('\u{A000}'));
+    }
+}
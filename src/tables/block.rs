@@ -0,0 +1,982 @@
+// Generated by `scripts/unicode.pl` from the Unicode 14.0.0
+// character database. Do not edit by hand.
+
+/// The Unicode `Block` property of a character.
+///
+/// Blocks are contiguous ranges, so unassigned code points inside an
+/// allocated block still belong to it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Block {
+    /// Adlam
+    Adlam,
+    /// Aegean Numbers
+    AegeanNumbers,
+    /// Ahom
+    Ahom,
+    /// Alchemical Symbols
+    AlchemicalSymbols,
+    /// Alphabetic Presentation Forms
+    AlphabeticPresentationForms,
+    /// Anatolian Hieroglyphs
+    AnatolianHieroglyphs,
+    /// Ancient Greek Musical Notation
+    AncientGreekMusicalNotation,
+    /// Ancient Greek Numbers
+    AncientGreekNumbers,
+    /// Ancient Symbols
+    AncientSymbols,
+    /// Arabic
+    Arabic,
+    /// Arabic Extended-A
+    ArabicExtendedA,
+    /// Arabic Extended-B
+    ArabicExtendedB,
+    /// Arabic Mathematical Alphabetic Symbols
+    ArabicMathematicalAlphabeticSymbols,
+    /// Arabic Presentation Forms-A
+    ArabicPresentationFormsA,
+    /// Arabic Presentation Forms-B
+    ArabicPresentationFormsB,
+    /// Arabic Supplement
+    ArabicSupplement,
+    /// Armenian
+    Armenian,
+    /// Arrows
+    Arrows,
+    /// Avestan
+    Avestan,
+    /// Balinese
+    Balinese,
+    /// Bamum
+    Bamum,
+    /// Bamum Supplement
+    BamumSupplement,
+    /// Basic Latin
+    BasicLatin,
+    /// Bassa Vah
+    BassaVah,
+    /// Batak
+    Batak,
+    /// Bengali
+    Bengali,
+    /// Bhaiksuki
+    Bhaiksuki,
+    /// Block Elements
+    BlockElements,
+    /// Bopomofo
+    Bopomofo,
+    /// Bopomofo Extended
+    BopomofoExtended,
+    /// Box Drawing
+    BoxDrawing,
+    /// Brahmi
+    Brahmi,
+    /// Braille Patterns
+    BraillePatterns,
+    /// Buginese
+    Buginese,
+    /// Buhid
+    Buhid,
+    /// Byzantine Musical Symbols
+    ByzantineMusicalSymbols,
+    /// CJK Compatibility
+    CjkCompatibility,
+    /// CJK Compatibility Forms
+    CjkCompatibilityForms,
+    /// CJK Compatibility Ideographs
+    CjkCompatibilityIdeographs,
+    /// CJK Compatibility Ideographs Supplement
+    CjkCompatibilityIdeographsSupplement,
+    /// CJK Radicals Supplement
+    CjkRadicalsSupplement,
+    /// CJK Strokes
+    CjkStrokes,
+    /// CJK Symbols and Punctuation
+    CjkSymbolsAndPunctuation,
+    /// CJK Unified Ideographs
+    CjkUnifiedIdeographs,
+    /// CJK Unified Ideographs Extension A
+    CjkUnifiedIdeographsExtensionA,
+    /// CJK Unified Ideographs Extension B
+    CjkUnifiedIdeographsExtensionB,
+    /// CJK Unified Ideographs Extension C
+    CjkUnifiedIdeographsExtensionC,
+    /// CJK Unified Ideographs Extension D
+    CjkUnifiedIdeographsExtensionD,
+    /// CJK Unified Ideographs Extension E
+    CjkUnifiedIdeographsExtensionE,
+    /// CJK Unified Ideographs Extension F
+    CjkUnifiedIdeographsExtensionF,
+    /// CJK Unified Ideographs Extension G
+    CjkUnifiedIdeographsExtensionG,
+    /// Carian
+    Carian,
+    /// Caucasian Albanian
+    CaucasianAlbanian,
+    /// Chakma
+    Chakma,
+    /// Cham
+    Cham,
+    /// Cherokee
+    Cherokee,
+    /// Cherokee Supplement
+    CherokeeSupplement,
+    /// Chess Symbols
+    ChessSymbols,
+    /// Chorasmian
+    Chorasmian,
+    /// Combining Diacritical Marks
+    CombiningDiacriticalMarks,
+    /// Combining Diacritical Marks Extended
+    CombiningDiacriticalMarksExtended,
+    /// Combining Diacritical Marks Supplement
+    CombiningDiacriticalMarksSupplement,
+    /// Combining Diacritical Marks for Symbols
+    CombiningDiacriticalMarksForSymbols,
+    /// Combining Half Marks
+    CombiningHalfMarks,
+    /// Common Indic Number Forms
+    CommonIndicNumberForms,
+    /// Control Pictures
+    ControlPictures,
+    /// Coptic
+    Coptic,
+    /// Coptic Epact Numbers
+    CopticEpactNumbers,
+    /// Counting Rod Numerals
+    CountingRodNumerals,
+    /// Cuneiform
+    Cuneiform,
+    /// Cuneiform Numbers and Punctuation
+    CuneiformNumbersAndPunctuation,
+    /// Currency Symbols
+    CurrencySymbols,
+    /// Cypriot Syllabary
+    CypriotSyllabary,
+    /// Cypro-Minoan
+    CyproMinoan,
+    /// Cyrillic
+    Cyrillic,
+    /// Cyrillic Extended-A
+    CyrillicExtendedA,
+    /// Cyrillic Extended-B
+    CyrillicExtendedB,
+    /// Cyrillic Extended-C
+    CyrillicExtendedC,
+    /// Cyrillic Supplement
+    CyrillicSupplement,
+    /// Deseret
+    Deseret,
+    /// Devanagari
+    Devanagari,
+    /// Devanagari Extended
+    DevanagariExtended,
+    /// Dingbats
+    Dingbats,
+    /// Dives Akuru
+    DivesAkuru,
+    /// Dogra
+    Dogra,
+    /// Domino Tiles
+    DominoTiles,
+    /// Duployan
+    Duployan,
+    /// Early Dynastic Cuneiform
+    EarlyDynasticCuneiform,
+    /// Egyptian Hieroglyph Format Controls
+    EgyptianHieroglyphFormatControls,
+    /// Egyptian Hieroglyphs
+    EgyptianHieroglyphs,
+    /// Elbasan
+    Elbasan,
+    /// Elymaic
+    Elymaic,
+    /// Emoticons
+    Emoticons,
+    /// Enclosed Alphanumeric Supplement
+    EnclosedAlphanumericSupplement,
+    /// Enclosed Alphanumerics
+    EnclosedAlphanumerics,
+    /// Enclosed CJK Letters and Months
+    EnclosedCjkLettersAndMonths,
+    /// Enclosed Ideographic Supplement
+    EnclosedIdeographicSupplement,
+    /// Ethiopic
+    Ethiopic,
+    /// Ethiopic Extended
+    EthiopicExtended,
+    /// Ethiopic Extended-A
+    EthiopicExtendedA,
+    /// Ethiopic Extended-B
+    EthiopicExtendedB,
+    /// Ethiopic Supplement
+    EthiopicSupplement,
+    /// General Punctuation
+    GeneralPunctuation,
+    /// Geometric Shapes
+    GeometricShapes,
+    /// Geometric Shapes Extended
+    GeometricShapesExtended,
+    /// Georgian
+    Georgian,
+    /// Georgian Extended
+    GeorgianExtended,
+    /// Georgian Supplement
+    GeorgianSupplement,
+    /// Glagolitic
+    Glagolitic,
+    /// Glagolitic Supplement
+    GlagoliticSupplement,
+    /// Gothic
+    Gothic,
+    /// Grantha
+    Grantha,
+    /// Greek Extended
+    GreekExtended,
+    /// Greek and Coptic
+    GreekAndCoptic,
+    /// Gujarati
+    Gujarati,
+    /// Gunjala Gondi
+    GunjalaGondi,
+    /// Gurmukhi
+    Gurmukhi,
+    /// Halfwidth and Fullwidth Forms
+    HalfwidthAndFullwidthForms,
+    /// Hangul Compatibility Jamo
+    HangulCompatibilityJamo,
+    /// Hangul Jamo
+    HangulJamo,
+    /// Hangul Jamo Extended-A
+    HangulJamoExtendedA,
+    /// Hangul Jamo Extended-B
+    HangulJamoExtendedB,
+    /// Hangul Syllables
+    HangulSyllables,
+    /// Hanifi Rohingya
+    HanifiRohingya,
+    /// Hanunoo
+    Hanunoo,
+    /// Hatran
+    Hatran,
+    /// Hebrew
+    Hebrew,
+    /// High Private Use Surrogates
+    HighPrivateUseSurrogates,
+    /// High Surrogates
+    HighSurrogates,
+    /// Hiragana
+    Hiragana,
+    /// IPA Extensions
+    IpaExtensions,
+    /// Ideographic Description Characters
+    IdeographicDescriptionCharacters,
+    /// Ideographic Symbols and Punctuation
+    IdeographicSymbolsAndPunctuation,
+    /// Imperial Aramaic
+    ImperialAramaic,
+    /// Indic Siyaq Numbers
+    IndicSiyaqNumbers,
+    /// Inscriptional Pahlavi
+    InscriptionalPahlavi,
+    /// Inscriptional Parthian
+    InscriptionalParthian,
+    /// Javanese
+    Javanese,
+    /// Kaithi
+    Kaithi,
+    /// Kana Extended-A
+    KanaExtendedA,
+    /// Kana Extended-B
+    KanaExtendedB,
+    /// Kana Supplement
+    KanaSupplement,
+    /// Kanbun
+    Kanbun,
+    /// Kangxi Radicals
+    KangxiRadicals,
+    /// Kannada
+    Kannada,
+    /// Katakana
+    Katakana,
+    /// Katakana Phonetic Extensions
+    KatakanaPhoneticExtensions,
+    /// Kayah Li
+    KayahLi,
+    /// Kharoshthi
+    Kharoshthi,
+    /// Khitan Small Script
+    KhitanSmallScript,
+    /// Khmer
+    Khmer,
+    /// Khmer Symbols
+    KhmerSymbols,
+    /// Khojki
+    Khojki,
+    /// Khudawadi
+    Khudawadi,
+    /// Lao
+    Lao,
+    /// Latin Extended Additional
+    LatinExtendedAdditional,
+    /// Latin Extended-A
+    LatinExtendedA,
+    /// Latin Extended-B
+    LatinExtendedB,
+    /// Latin Extended-C
+    LatinExtendedC,
+    /// Latin Extended-D
+    LatinExtendedD,
+    /// Latin Extended-E
+    LatinExtendedE,
+    /// Latin Extended-F
+    LatinExtendedF,
+    /// Latin Extended-G
+    LatinExtendedG,
+    /// Latin-1 Supplement
+    Latin1Supplement,
+    /// Lepcha
+    Lepcha,
+    /// Letterlike Symbols
+    LetterlikeSymbols,
+    /// Limbu
+    Limbu,
+    /// Linear A
+    LinearA,
+    /// Linear B Ideograms
+    LinearBIdeograms,
+    /// Linear B Syllabary
+    LinearBSyllabary,
+    /// Lisu
+    Lisu,
+    /// Lisu Supplement
+    LisuSupplement,
+    /// Low Surrogates
+    LowSurrogates,
+    /// Lycian
+    Lycian,
+    /// Lydian
+    Lydian,
+    /// Mahajani
+    Mahajani,
+    /// Mahjong Tiles
+    MahjongTiles,
+    /// Makasar
+    Makasar,
+    /// Malayalam
+    Malayalam,
+    /// Mandaic
+    Mandaic,
+    /// Manichaean
+    Manichaean,
+    /// Marchen
+    Marchen,
+    /// Masaram Gondi
+    MasaramGondi,
+    /// Mathematical Alphanumeric Symbols
+    MathematicalAlphanumericSymbols,
+    /// Mathematical Operators
+    MathematicalOperators,
+    /// Mayan Numerals
+    MayanNumerals,
+    /// Medefaidrin
+    Medefaidrin,
+    /// Meetei Mayek
+    MeeteiMayek,
+    /// Meetei Mayek Extensions
+    MeeteiMayekExtensions,
+    /// Mende Kikakui
+    MendeKikakui,
+    /// Meroitic Cursive
+    MeroiticCursive,
+    /// Meroitic Hieroglyphs
+    MeroiticHieroglyphs,
+    /// Miao
+    Miao,
+    /// Miscellaneous Mathematical Symbols-A
+    MiscellaneousMathematicalSymbolsA,
+    /// Miscellaneous Mathematical Symbols-B
+    MiscellaneousMathematicalSymbolsB,
+    /// Miscellaneous Symbols
+    MiscellaneousSymbols,
+    /// Miscellaneous Symbols and Arrows
+    MiscellaneousSymbolsAndArrows,
+    /// Miscellaneous Symbols and Pictographs
+    MiscellaneousSymbolsAndPictographs,
+    /// Miscellaneous Technical
+    MiscellaneousTechnical,
+    /// Modi
+    Modi,
+    /// Modifier Tone Letters
+    ModifierToneLetters,
+    /// Mongolian
+    Mongolian,
+    /// Mongolian Supplement
+    MongolianSupplement,
+    /// Mro
+    Mro,
+    /// Multani
+    Multani,
+    /// Musical Symbols
+    MusicalSymbols,
+    /// Myanmar
+    Myanmar,
+    /// Myanmar Extended-A
+    MyanmarExtendedA,
+    /// Myanmar Extended-B
+    MyanmarExtendedB,
+    /// NKo
+    Nko,
+    /// Nabataean
+    Nabataean,
+    /// Nandinagari
+    Nandinagari,
+    /// New Tai Lue
+    NewTaiLue,
+    /// Newa
+    Newa,
+    /// No Block
+    NoBlock,
+    /// Number Forms
+    NumberForms,
+    /// Nushu
+    Nushu,
+    /// Nyiakeng Puachue Hmong
+    NyiakengPuachueHmong,
+    /// Ogham
+    Ogham,
+    /// Ol Chiki
+    OlChiki,
+    /// Old Hungarian
+    OldHungarian,
+    /// Old Italic
+    OldItalic,
+    /// Old North Arabian
+    OldNorthArabian,
+    /// Old Permic
+    OldPermic,
+    /// Old Persian
+    OldPersian,
+    /// Old Sogdian
+    OldSogdian,
+    /// Old South Arabian
+    OldSouthArabian,
+    /// Old Turkic
+    OldTurkic,
+    /// Old Uyghur
+    OldUyghur,
+    /// Optical Character Recognition
+    OpticalCharacterRecognition,
+    /// Oriya
+    Oriya,
+    /// Ornamental Dingbats
+    OrnamentalDingbats,
+    /// Osage
+    Osage,
+    /// Osmanya
+    Osmanya,
+    /// Ottoman Siyaq Numbers
+    OttomanSiyaqNumbers,
+    /// Pahawh Hmong
+    PahawhHmong,
+    /// Palmyrene
+    Palmyrene,
+    /// Pau Cin Hau
+    PauCinHau,
+    /// Phags-pa
+    PhagsPa,
+    /// Phaistos Disc
+    PhaistosDisc,
+    /// Phoenician
+    Phoenician,
+    /// Phonetic Extensions
+    PhoneticExtensions,
+    /// Phonetic Extensions Supplement
+    PhoneticExtensionsSupplement,
+    /// Playing Cards
+    PlayingCards,
+    /// Private Use Area
+    PrivateUseArea,
+    /// Psalter Pahlavi
+    PsalterPahlavi,
+    /// Rejang
+    Rejang,
+    /// Rumi Numeral Symbols
+    RumiNumeralSymbols,
+    /// Runic
+    Runic,
+    /// Samaritan
+    Samaritan,
+    /// Saurashtra
+    Saurashtra,
+    /// Sharada
+    Sharada,
+    /// Shavian
+    Shavian,
+    /// Shorthand Format Controls
+    ShorthandFormatControls,
+    /// Siddham
+    Siddham,
+    /// Sinhala
+    Sinhala,
+    /// Sinhala Archaic Numbers
+    SinhalaArchaicNumbers,
+    /// Small Form Variants
+    SmallFormVariants,
+    /// Small Kana Extension
+    SmallKanaExtension,
+    /// Sogdian
+    Sogdian,
+    /// Sora Sompeng
+    SoraSompeng,
+    /// Soyombo
+    Soyombo,
+    /// Spacing Modifier Letters
+    SpacingModifierLetters,
+    /// Specials
+    Specials,
+    /// Sundanese
+    Sundanese,
+    /// Sundanese Supplement
+    SundaneseSupplement,
+    /// Superscripts and Subscripts
+    SuperscriptsAndSubscripts,
+    /// Supplemental Arrows-A
+    SupplementalArrowsA,
+    /// Supplemental Arrows-B
+    SupplementalArrowsB,
+    /// Supplemental Arrows-C
+    SupplementalArrowsC,
+    /// Supplemental Mathematical Operators
+    SupplementalMathematicalOperators,
+    /// Supplemental Punctuation
+    SupplementalPunctuation,
+    /// Supplemental Symbols and Pictographs
+    SupplementalSymbolsAndPictographs,
+    /// Supplementary Private Use Area-A
+    SupplementaryPrivateUseAreaA,
+    /// Supplementary Private Use Area-B
+    SupplementaryPrivateUseAreaB,
+    /// Sutton SignWriting
+    SuttonSignwriting,
+    /// Syloti Nagri
+    SylotiNagri,
+    /// Symbols and Pictographs Extended-A
+    SymbolsAndPictographsExtendedA,
+    /// Symbols for Legacy Computing
+    SymbolsForLegacyComputing,
+    /// Syriac
+    Syriac,
+    /// Syriac Supplement
+    SyriacSupplement,
+    /// Tagalog
+    Tagalog,
+    /// Tagbanwa
+    Tagbanwa,
+    /// Tags
+    Tags,
+    /// Tai Le
+    TaiLe,
+    /// Tai Tham
+    TaiTham,
+    /// Tai Viet
+    TaiViet,
+    /// Tai Xuan Jing Symbols
+    TaiXuanJingSymbols,
+    /// Takri
+    Takri,
+    /// Tamil
+    Tamil,
+    /// Tamil Supplement
+    TamilSupplement,
+    /// Tangsa
+    Tangsa,
+    /// Tangut
+    Tangut,
+    /// Tangut Components
+    TangutComponents,
+    /// Tangut Supplement
+    TangutSupplement,
+    /// Telugu
+    Telugu,
+    /// Thaana
+    Thaana,
+    /// Thai
+    Thai,
+    /// Tibetan
+    Tibetan,
+    /// Tifinagh
+    Tifinagh,
+    /// Tirhuta
+    Tirhuta,
+    /// Toto
+    Toto,
+    /// Transport and Map Symbols
+    TransportAndMapSymbols,
+    /// Ugaritic
+    Ugaritic,
+    /// Unified Canadian Aboriginal Syllabics
+    UnifiedCanadianAboriginalSyllabics,
+    /// Unified Canadian Aboriginal Syllabics Extended
+    UnifiedCanadianAboriginalSyllabicsExtended,
+    /// Unified Canadian Aboriginal Syllabics Extended-A
+    UnifiedCanadianAboriginalSyllabicsExtendedA,
+    /// Vai
+    Vai,
+    /// Variation Selectors
+    VariationSelectors,
+    /// Variation Selectors Supplement
+    VariationSelectorsSupplement,
+    /// Vedic Extensions
+    VedicExtensions,
+    /// Vertical Forms
+    VerticalForms,
+    /// Vithkuqi
+    Vithkuqi,
+    /// Wancho
+    Wancho,
+    /// Warang Citi
+    WarangCiti,
+    /// Yezidi
+    Yezidi,
+    /// Yi Radicals
+    YiRadicals,
+    /// Yi Syllables
+    YiSyllables,
+    /// Yijing Hexagram Symbols
+    YijingHexagramSymbols,
+    /// Zanabazar Square
+    ZanabazarSquare,
+    /// Znamenny Musical Notation
+    ZnamennyMusicalNotation,
+}
+
+impl Default for Block {
+    #[inline]
+    fn default() -> Self {
+        Block::NoBlock
+    }
+}
+
+pub(crate) const BLOCK: &[(u32, u32, Block)] = &[
+    (0x0000, 0x007F, Block::BasicLatin),
+    (0x0080, 0x00FF, Block::Latin1Supplement),
+    (0x0100, 0x017F, Block::LatinExtendedA),
+    (0x0180, 0x024F, Block::LatinExtendedB),
+    (0x0250, 0x02AF, Block::IpaExtensions),
+    (0x02B0, 0x02FF, Block::SpacingModifierLetters),
+    (0x0300, 0x036F, Block::CombiningDiacriticalMarks),
+    (0x0370, 0x03FF, Block::GreekAndCoptic),
+    (0x0400, 0x04FF, Block::Cyrillic),
+    (0x0500, 0x052F, Block::CyrillicSupplement),
+    (0x0530, 0x058F, Block::Armenian),
+    (0x0590, 0x05FF, Block::Hebrew),
+    (0x0600, 0x06FF, Block::Arabic),
+    (0x0700, 0x074F, Block::Syriac),
+    (0x0750, 0x077F, Block::ArabicSupplement),
+    (0x0780, 0x07BF, Block::Thaana),
+    (0x07C0, 0x07FF, Block::Nko),
+    (0x0800, 0x083F, Block::Samaritan),
+    (0x0840, 0x085F, Block::Mandaic),
+    (0x0860, 0x086F, Block::SyriacSupplement),
+    (0x0870, 0x089F, Block::ArabicExtendedB),
+    (0x08A0, 0x08FF, Block::ArabicExtendedA),
+    (0x0900, 0x097F, Block::Devanagari),
+    (0x0980, 0x09FF, Block::Bengali),
+    (0x0A00, 0x0A7F, Block::Gurmukhi),
+    (0x0A80, 0x0AFF, Block::Gujarati),
+    (0x0B00, 0x0B7F, Block::Oriya),
+    (0x0B80, 0x0BFF, Block::Tamil),
+    (0x0C00, 0x0C7F, Block::Telugu),
+    (0x0C80, 0x0CFF, Block::Kannada),
+    (0x0D00, 0x0D7F, Block::Malayalam),
+    (0x0D80, 0x0DFF, Block::Sinhala),
+    (0x0E00, 0x0E7F, Block::Thai),
+    (0x0E80, 0x0EFF, Block::Lao),
+    (0x0F00, 0x0FFF, Block::Tibetan),
+    (0x1000, 0x109F, Block::Myanmar),
+    (0x10A0, 0x10FF, Block::Georgian),
+    (0x1100, 0x11FF, Block::HangulJamo),
+    (0x1200, 0x137F, Block::Ethiopic),
+    (0x1380, 0x139F, Block::EthiopicSupplement),
+    (0x13A0, 0x13FF, Block::Cherokee),
+    (0x1400, 0x167F, Block::UnifiedCanadianAboriginalSyllabics),
+    (0x1680, 0x169F, Block::Ogham),
+    (0x16A0, 0x16FF, Block::Runic),
+    (0x1700, 0x171F, Block::Tagalog),
+    (0x1720, 0x173F, Block::Hanunoo),
+    (0x1740, 0x175F, Block::Buhid),
+    (0x1760, 0x177F, Block::Tagbanwa),
+    (0x1780, 0x17FF, Block::Khmer),
+    (0x1800, 0x18AF, Block::Mongolian),
+    (0x18B0, 0x18FF, Block::UnifiedCanadianAboriginalSyllabicsExtended),
+    (0x1900, 0x194F, Block::Limbu),
+    (0x1950, 0x197F, Block::TaiLe),
+    (0x1980, 0x19DF, Block::NewTaiLue),
+    (0x19E0, 0x19FF, Block::KhmerSymbols),
+    (0x1A00, 0x1A1F, Block::Buginese),
+    (0x1A20, 0x1AAF, Block::TaiTham),
+    (0x1AB0, 0x1AFF, Block::CombiningDiacriticalMarksExtended),
+    (0x1B00, 0x1B7F, Block::Balinese),
+    (0x1B80, 0x1BBF, Block::Sundanese),
+    (0x1BC0, 0x1BFF, Block::Batak),
+    (0x1C00, 0x1C4F, Block::Lepcha),
+    (0x1C50, 0x1C7F, Block::OlChiki),
+    (0x1C80, 0x1C8F, Block::CyrillicExtendedC),
+    (0x1C90, 0x1CBF, Block::GeorgianExtended),
+    (0x1CC0, 0x1CCF, Block::SundaneseSupplement),
+    (0x1CD0, 0x1CFF, Block::VedicExtensions),
+    (0x1D00, 0x1D7F, Block::PhoneticExtensions),
+    (0x1D80, 0x1DBF, Block::PhoneticExtensionsSupplement),
+    (0x1DC0, 0x1DFF, Block::CombiningDiacriticalMarksSupplement),
+    (0x1E00, 0x1EFF, Block::LatinExtendedAdditional),
+    (0x1F00, 0x1FFF, Block::GreekExtended),
+    (0x2000, 0x206F, Block::GeneralPunctuation),
+    (0x2070, 0x209F, Block::SuperscriptsAndSubscripts),
+    (0x20A0, 0x20CF, Block::CurrencySymbols),
+    (0x20D0, 0x20FF, Block::CombiningDiacriticalMarksForSymbols),
+    (0x2100, 0x214F, Block::LetterlikeSymbols),
+    (0x2150, 0x218F, Block::NumberForms),
+    (0x2190, 0x21FF, Block::Arrows),
+    (0x2200, 0x22FF, Block::MathematicalOperators),
+    (0x2300, 0x23FF, Block::MiscellaneousTechnical),
+    (0x2400, 0x243F, Block::ControlPictures),
+    (0x2440, 0x245F, Block::OpticalCharacterRecognition),
+    (0x2460, 0x24FF, Block::EnclosedAlphanumerics),
+    (0x2500, 0x257F, Block::BoxDrawing),
+    (0x2580, 0x259F, Block::BlockElements),
+    (0x25A0, 0x25FF, Block::GeometricShapes),
+    (0x2600, 0x26FF, Block::MiscellaneousSymbols),
+    (0x2700, 0x27BF, Block::Dingbats),
+    (0x27C0, 0x27EF, Block::MiscellaneousMathematicalSymbolsA),
+    (0x27F0, 0x27FF, Block::SupplementalArrowsA),
+    (0x2800, 0x28FF, Block::BraillePatterns),
+    (0x2900, 0x297F, Block::SupplementalArrowsB),
+    (0x2980, 0x29FF, Block::MiscellaneousMathematicalSymbolsB),
+    (0x2A00, 0x2AFF, Block::SupplementalMathematicalOperators),
+    (0x2B00, 0x2BFF, Block::MiscellaneousSymbolsAndArrows),
+    (0x2C00, 0x2C5F, Block::Glagolitic),
+    (0x2C60, 0x2C7F, Block::LatinExtendedC),
+    (0x2C80, 0x2CFF, Block::Coptic),
+    (0x2D00, 0x2D2F, Block::GeorgianSupplement),
+    (0x2D30, 0x2D7F, Block::Tifinagh),
+    (0x2D80, 0x2DDF, Block::EthiopicExtended),
+    (0x2DE0, 0x2DFF, Block::CyrillicExtendedA),
+    (0x2E00, 0x2E7F, Block::SupplementalPunctuation),
+    (0x2E80, 0x2EFF, Block::CjkRadicalsSupplement),
+    (0x2F00, 0x2FDF, Block::KangxiRadicals),
+    (0x2FF0, 0x2FFF, Block::IdeographicDescriptionCharacters),
+    (0x3000, 0x303F, Block::CjkSymbolsAndPunctuation),
+    (0x3040, 0x309F, Block::Hiragana),
+    (0x30A0, 0x30FF, Block::Katakana),
+    (0x3100, 0x312F, Block::Bopomofo),
+    (0x3130, 0x318F, Block::HangulCompatibilityJamo),
+    (0x3190, 0x319F, Block::Kanbun),
+    (0x31A0, 0x31BF, Block::BopomofoExtended),
+    (0x31C0, 0x31EF, Block::CjkStrokes),
+    (0x31F0, 0x31FF, Block::KatakanaPhoneticExtensions),
+    (0x3200, 0x32FF, Block::EnclosedCjkLettersAndMonths),
+    (0x3300, 0x33FF, Block::CjkCompatibility),
+    (0x3400, 0x4DBF, Block::CjkUnifiedIdeographsExtensionA),
+    (0x4DC0, 0x4DFF, Block::YijingHexagramSymbols),
+    (0x4E00, 0x9FFF, Block::CjkUnifiedIdeographs),
+    (0xA000, 0xA48F, Block::YiSyllables),
+    (0xA490, 0xA4CF, Block::YiRadicals),
+    (0xA4D0, 0xA4FF, Block::Lisu),
+    (0xA500, 0xA63F, Block::Vai),
+    (0xA640, 0xA69F, Block::CyrillicExtendedB),
+    (0xA6A0, 0xA6FF, Block::Bamum),
+    (0xA700, 0xA71F, Block::ModifierToneLetters),
+    (0xA720, 0xA7FF, Block::LatinExtendedD),
+    (0xA800, 0xA82F, Block::SylotiNagri),
+    (0xA830, 0xA83F, Block::CommonIndicNumberForms),
+    (0xA840, 0xA87F, Block::PhagsPa),
+    (0xA880, 0xA8DF, Block::Saurashtra),
+    (0xA8E0, 0xA8FF, Block::DevanagariExtended),
+    (0xA900, 0xA92F, Block::KayahLi),
+    (0xA930, 0xA95F, Block::Rejang),
+    (0xA960, 0xA97F, Block::HangulJamoExtendedA),
+    (0xA980, 0xA9DF, Block::Javanese),
+    (0xA9E0, 0xA9FF, Block::MyanmarExtendedB),
+    (0xAA00, 0xAA5F, Block::Cham),
+    (0xAA60, 0xAA7F, Block::MyanmarExtendedA),
+    (0xAA80, 0xAADF, Block::TaiViet),
+    (0xAAE0, 0xAAFF, Block::MeeteiMayekExtensions),
+    (0xAB00, 0xAB2F, Block::EthiopicExtendedA),
+    (0xAB30, 0xAB6F, Block::LatinExtendedE),
+    (0xAB70, 0xABBF, Block::CherokeeSupplement),
+    (0xABC0, 0xABFF, Block::MeeteiMayek),
+    (0xAC00, 0xD7AF, Block::HangulSyllables),
+    (0xD7B0, 0xD7FF, Block::HangulJamoExtendedB),
+    (0xD800, 0xDB7F, Block::HighSurrogates),
+    (0xDB80, 0xDBFF, Block::HighPrivateUseSurrogates),
+    (0xDC00, 0xDFFF, Block::LowSurrogates),
+    (0xE000, 0xF8FF, Block::PrivateUseArea),
+    (0xF900, 0xFAFF, Block::CjkCompatibilityIdeographs),
+    (0xFB00, 0xFB4F, Block::AlphabeticPresentationForms),
+    (0xFB50, 0xFDFF, Block::ArabicPresentationFormsA),
+    (0xFE00, 0xFE0F, Block::VariationSelectors),
+    (0xFE10, 0xFE1F, Block::VerticalForms),
+    (0xFE20, 0xFE2F, Block::CombiningHalfMarks),
+    (0xFE30, 0xFE4F, Block::CjkCompatibilityForms),
+    (0xFE50, 0xFE6F, Block::SmallFormVariants),
+    (0xFE70, 0xFEFF, Block::ArabicPresentationFormsB),
+    (0xFF00, 0xFFEF, Block::HalfwidthAndFullwidthForms),
+    (0xFFF0, 0xFFFF, Block::Specials),
+    (0x10000, 0x1007F, Block::LinearBSyllabary),
+    (0x10080, 0x100FF, Block::LinearBIdeograms),
+    (0x10100, 0x1013F, Block::AegeanNumbers),
+    (0x10140, 0x1018F, Block::AncientGreekNumbers),
+    (0x10190, 0x101CF, Block::AncientSymbols),
+    (0x101D0, 0x101FF, Block::PhaistosDisc),
+    (0x10280, 0x1029F, Block::Lycian),
+    (0x102A0, 0x102DF, Block::Carian),
+    (0x102E0, 0x102FF, Block::CopticEpactNumbers),
+    (0x10300, 0x1032F, Block::OldItalic),
+    (0x10330, 0x1034F, Block::Gothic),
+    (0x10350, 0x1037F, Block::OldPermic),
+    (0x10380, 0x1039F, Block::Ugaritic),
+    (0x103A0, 0x103DF, Block::OldPersian),
+    (0x10400, 0x1044F, Block::Deseret),
+    (0x10450, 0x1047F, Block::Shavian),
+    (0x10480, 0x104AF, Block::Osmanya),
+    (0x104B0, 0x104FF, Block::Osage),
+    (0x10500, 0x1052F, Block::Elbasan),
+    (0x10530, 0x1056F, Block::CaucasianAlbanian),
+    (0x10570, 0x105BF, Block::Vithkuqi),
+    (0x10600, 0x1077F, Block::LinearA),
+    (0x10780, 0x107BF, Block::LatinExtendedF),
+    (0x10800, 0x1083F, Block::CypriotSyllabary),
+    (0x10840, 0x1085F, Block::ImperialAramaic),
+    (0x10860, 0x1087F, Block::Palmyrene),
+    (0x10880, 0x108AF, Block::Nabataean),
+    (0x108E0, 0x108FF, Block::Hatran),
+    (0x10900, 0x1091F, Block::Phoenician),
+    (0x10920, 0x1093F, Block::Lydian),
+    (0x10980, 0x1099F, Block::MeroiticHieroglyphs),
+    (0x109A0, 0x109FF, Block::MeroiticCursive),
+    (0x10A00, 0x10A5F, Block::Kharoshthi),
+    (0x10A60, 0x10A7F, Block::OldSouthArabian),
+    (0x10A80, 0x10A9F, Block::OldNorthArabian),
+    (0x10AC0, 0x10AFF, Block::Manichaean),
+    (0x10B00, 0x10B3F, Block::Avestan),
+    (0x10B40, 0x10B5F, Block::InscriptionalParthian),
+    (0x10B60, 0x10B7F, Block::InscriptionalPahlavi),
+    (0x10B80, 0x10BAF, Block::PsalterPahlavi),
+    (0x10C00, 0x10C4F, Block::OldTurkic),
+    (0x10C80, 0x10CFF, Block::OldHungarian),
+    (0x10D00, 0x10D3F, Block::HanifiRohingya),
+    (0x10E60, 0x10E7F, Block::RumiNumeralSymbols),
+    (0x10E80, 0x10EBF, Block::Yezidi),
+    (0x10F00, 0x10F2F, Block::OldSogdian),
+    (0x10F30, 0x10F6F, Block::Sogdian),
+    (0x10F70, 0x10FAF, Block::OldUyghur),
+    (0x10FB0, 0x10FDF, Block::Chorasmian),
+    (0x10FE0, 0x10FFF, Block::Elymaic),
+    (0x11000, 0x1107F, Block::Brahmi),
+    (0x11080, 0x110CF, Block::Kaithi),
+    (0x110D0, 0x110FF, Block::SoraSompeng),
+    (0x11100, 0x1114F, Block::Chakma),
+    (0x11150, 0x1117F, Block::Mahajani),
+    (0x11180, 0x111DF, Block::Sharada),
+    (0x111E0, 0x111FF, Block::SinhalaArchaicNumbers),
+    (0x11200, 0x1124F, Block::Khojki),
+    (0x11280, 0x112AF, Block::Multani),
+    (0x112B0, 0x112FF, Block::Khudawadi),
+    (0x11300, 0x1137F, Block::Grantha),
+    (0x11400, 0x1147F, Block::Newa),
+    (0x11480, 0x114DF, Block::Tirhuta),
+    (0x11580, 0x115FF, Block::Siddham),
+    (0x11600, 0x1165F, Block::Modi),
+    (0x11660, 0x1167F, Block::MongolianSupplement),
+    (0x11680, 0x116CF, Block::Takri),
+    (0x11700, 0x1174F, Block::Ahom),
+    (0x11800, 0x1184F, Block::Dogra),
+    (0x118A0, 0x118FF, Block::WarangCiti),
+    (0x11900, 0x1195F, Block::DivesAkuru),
+    (0x119A0, 0x119FF, Block::Nandinagari),
+    (0x11A00, 0x11A4F, Block::ZanabazarSquare),
+    (0x11A50, 0x11AAF, Block::Soyombo),
+    (0x11AB0, 0x11ABF, Block::UnifiedCanadianAboriginalSyllabicsExtendedA),
+    (0x11AC0, 0x11AFF, Block::PauCinHau),
+    (0x11C00, 0x11C6F, Block::Bhaiksuki),
+    (0x11C70, 0x11CBF, Block::Marchen),
+    (0x11D00, 0x11D5F, Block::MasaramGondi),
+    (0x11D60, 0x11DAF, Block::GunjalaGondi),
+    (0x11EE0, 0x11EFF, Block::Makasar),
+    (0x11FB0, 0x11FBF, Block::LisuSupplement),
+    (0x11FC0, 0x11FFF, Block::TamilSupplement),
+    (0x12000, 0x123FF, Block::Cuneiform),
+    (0x12400, 0x1247F, Block::CuneiformNumbersAndPunctuation),
+    (0x12480, 0x1254F, Block::EarlyDynasticCuneiform),
+    (0x12F90, 0x12FFF, Block::CyproMinoan),
+    (0x13000, 0x1342F, Block::EgyptianHieroglyphs),
+    (0x13430, 0x1343F, Block::EgyptianHieroglyphFormatControls),
+    (0x14400, 0x1467F, Block::AnatolianHieroglyphs),
+    (0x16800, 0x16A3F, Block::BamumSupplement),
+    (0x16A40, 0x16A6F, Block::Mro),
+    (0x16A70, 0x16ACF, Block::Tangsa),
+    (0x16AD0, 0x16AFF, Block::BassaVah),
+    (0x16B00, 0x16B8F, Block::PahawhHmong),
+    (0x16E40, 0x16E9F, Block::Medefaidrin),
+    (0x16F00, 0x16F9F, Block::Miao),
+    (0x16FE0, 0x16FFF, Block::IdeographicSymbolsAndPunctuation),
+    (0x17000, 0x187FF, Block::Tangut),
+    (0x18800, 0x18AFF, Block::TangutComponents),
+    (0x18B00, 0x18CFF, Block::KhitanSmallScript),
+    (0x18D00, 0x18D7F, Block::TangutSupplement),
+    (0x1AFF0, 0x1AFFF, Block::KanaExtendedB),
+    (0x1B000, 0x1B0FF, Block::KanaSupplement),
+    (0x1B100, 0x1B12F, Block::KanaExtendedA),
+    (0x1B130, 0x1B16F, Block::SmallKanaExtension),
+    (0x1B170, 0x1B2FF, Block::Nushu),
+    (0x1BC00, 0x1BC9F, Block::Duployan),
+    (0x1BCA0, 0x1BCAF, Block::ShorthandFormatControls),
+    (0x1CF00, 0x1CFCF, Block::ZnamennyMusicalNotation),
+    (0x1D000, 0x1D0FF, Block::ByzantineMusicalSymbols),
+    (0x1D100, 0x1D1FF, Block::MusicalSymbols),
+    (0x1D200, 0x1D24F, Block::AncientGreekMusicalNotation),
+    (0x1D2E0, 0x1D2FF, Block::MayanNumerals),
+    (0x1D300, 0x1D35F, Block::TaiXuanJingSymbols),
+    (0x1D360, 0x1D37F, Block::CountingRodNumerals),
+    (0x1D400, 0x1D7FF, Block::MathematicalAlphanumericSymbols),
+    (0x1D800, 0x1DAAF, Block::SuttonSignwriting),
+    (0x1DF00, 0x1DFFF, Block::LatinExtendedG),
+    (0x1E000, 0x1E02F, Block::GlagoliticSupplement),
+    (0x1E100, 0x1E14F, Block::NyiakengPuachueHmong),
+    (0x1E290, 0x1E2BF, Block::Toto),
+    (0x1E2C0, 0x1E2FF, Block::Wancho),
+    (0x1E7E0, 0x1E7FF, Block::EthiopicExtendedB),
+    (0x1E800, 0x1E8DF, Block::MendeKikakui),
+    (0x1E900, 0x1E95F, Block::Adlam),
+    (0x1EC70, 0x1ECBF, Block::IndicSiyaqNumbers),
+    (0x1ED00, 0x1ED4F, Block::OttomanSiyaqNumbers),
+    (0x1EE00, 0x1EEFF, Block::ArabicMathematicalAlphabeticSymbols),
+    (0x1F000, 0x1F02F, Block::MahjongTiles),
+    (0x1F030, 0x1F09F, Block::DominoTiles),
+    (0x1F0A0, 0x1F0FF, Block::PlayingCards),
+    (0x1F100, 0x1F1FF, Block::EnclosedAlphanumericSupplement),
+    (0x1F200, 0x1F2FF, Block::EnclosedIdeographicSupplement),
+    (0x1F300, 0x1F5FF, Block::MiscellaneousSymbolsAndPictographs),
+    (0x1F600, 0x1F64F, Block::Emoticons),
+    (0x1F650, 0x1F67F, Block::OrnamentalDingbats),
+    (0x1F680, 0x1F6FF, Block::TransportAndMapSymbols),
+    (0x1F700, 0x1F77F, Block::AlchemicalSymbols),
+    (0x1F780, 0x1F7FF, Block::GeometricShapesExtended),
+    (0x1F800, 0x1F8FF, Block::SupplementalArrowsC),
+    (0x1F900, 0x1F9FF, Block::SupplementalSymbolsAndPictographs),
+    (0x1FA00, 0x1FA6F, Block::ChessSymbols),
+    (0x1FA70, 0x1FAFF, Block::SymbolsAndPictographsExtendedA),
+    (0x1FB00, 0x1FBFF, Block::SymbolsForLegacyComputing),
+    (0x20000, 0x2A6DF, Block::CjkUnifiedIdeographsExtensionB),
+    (0x2A700, 0x2B73F, Block::CjkUnifiedIdeographsExtensionC),
+    (0x2B740, 0x2B81F, Block::CjkUnifiedIdeographsExtensionD),
+    (0x2B820, 0x2CEAF, Block::CjkUnifiedIdeographsExtensionE),
+    (0x2CEB0, 0x2EBEF, Block::CjkUnifiedIdeographsExtensionF),
+    (0x2F800, 0x2FA1F, Block::CjkCompatibilityIdeographsSupplement),
+    (0x30000, 0x3134F, Block::CjkUnifiedIdeographsExtensionG),
+    (0xE0000, 0xE007F, Block::Tags),
+    (0xE0100, 0xE01EF, Block::VariationSelectorsSupplement),
+    (0xF0000, 0xFFFFF, Block::SupplementaryPrivateUseAreaA),
+    (0x100000, 0x10FFFF, Block::SupplementaryPrivateUseAreaB),
+];
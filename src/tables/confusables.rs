@@ -0,0 +1,6362 @@
+// Generated by `scripts/unicode.pl` from the Unicode 14.0.0
+// character database. Do not edit by hand.
+
+// The mappings are from `confusables.txt` version 16.0.0.
+
+pub(crate) const CONFUSABLES: &[(u32, &str)] = &[
+    (0x0022, "\u{27}\u{27}"),
+    (0x0025, "\u{BA}\u{2F}\u{2080}"),
+    (0x0030, "\u{4F}"),
+    (0x0031, "\u{6C}"),
+    (0x0049, "\u{6C}"),
+    (0x0060, "\u{27}"),
+    (0x006D, "\u{72}\u{6E}"),
+    (0x007C, "\u{6C}"),
+    (0x00A0, "\u{20}"),
+    (0x00A2, "\u{63}\u{338}"),
+    (0x00A5, "\u{59}\u{335}"),
+    (0x00AF, "\u{2C9}"),
+    (0x00B4, "\u{27}"),
+    (0x00B5, "\u{3BC}"),
+    (0x00B8, "\u{2C}"),
+    (0x00C6, "\u{41}\u{45}"),
+    (0x00C7, "\u{43}\u{326}"),
+    (0x00D0, "\u{44}\u{335}"),
+    (0x00D7, "\u{78}"),
+    (0x00D8, "\u{4F}\u{338}"),
+    (0x00E6, "\u{61}\u{65}"),
+    (0x00E7, "\u{63}\u{326}"),
+    (0x00F0, "\u{2202}\u{335}"),
+    (0x00F6, "\u{629}"),
+    (0x00F8, "\u{6F}\u{338}"),
+    (0x0110, "\u{44}\u{335}"),
+    (0x0111, "\u{64}\u{335}"),
+    (0x011A, "\u{114}"),
+    (0x011B, "\u{115}"),
+    (0x0126, "\u{48}\u{335}"),
+    (0x0127, "\u{68}\u{335}"),
+    (0x0131, "\u{69}"),
+    (0x0132, "\u{6C}\u{4A}"),
+    (0x0133, "\u{69}\u{6A}"),
+    (0x013F, "\u{6C}\u{B7}"),
+    (0x0140, "\u{6C}\u{B7}"),
+    (0x0141, "\u{4C}\u{338}"),
+    (0x0142, "\u{6C}\u{338}"),
+    (0x0146, "\u{272}"),
+    (0x0149, "\u{27}\u{6E}"),
+    (0x0150, "\u{D6}"),
+    (0x0152, "\u{4F}\u{45}"),
+    (0x0153, "\u{6F}\u{65}"),
+    (0x0163, "\u{1AB}"),
+    (0x0166, "\u{54}\u{335}"),
+    (0x0167, "\u{74}\u{335}"),
+    (0x017F, "\u{66}"),
+    (0x0180, "\u{62}\u{335}"),
+    (0x0181, "\u{27}\u{42}"),
+    (0x0182, "\u{62}\u{304}"),
+    (0x0183, "\u{62}\u{304}"),
+    (0x0184, "\u{62}"),
+    (0x0187, "\u{43}\u{27}"),
+    (0x0189, "\u{44}\u{335}"),
+    (0x018A, "\u{27}\u{44}"),
+    (0x018C, "\u{64}\u{304}"),
+    (0x018D, "\u{67}"),
+    (0x0191, "\u{46}\u{326}"),
+    (0x0192, "\u{66}\u{326}"),
+    (0x0193, "\u{47}\u{27}"),
+    (0x0196, "\u{6C}"),
+    (0x0197, "\u{6C}\u{335}"),
+    (0x0198, "\u{4B}\u{27}"),
+    (0x0199, "\u{6B}\u{314}"),
+    (0x019A, "\u{6C}\u{335}"),
+    (0x019B, "\u{3BB}\u{338}"),
+    (0x019D, "\u{4E}\u{326}"),
+    (0x019E, "\u{6E}\u{329}"),
+    (0x019F, "\u{4F}\u{335}"),
+    (0x01A0, "\u{4F}\u{27}"),
+    (0x01A1, "\u{6F}\u{27}"),
+    (0x01A4, "\u{27}\u{50}"),
+    (0x01A5, "\u{70}\u{314}"),
+    (0x01A6, "\u{52}"),
+    (0x01A7, "\u{32}"),
+    (0x01AC, "\u{27}\u{54}"),
+    (0x01AD, "\u{74}\u{314}"),
+    (0x01AE, "\u{54}\u{328}"),
+    (0x01B3, "\u{27}\u{59}"),
+    (0x01B4, "\u{79}\u{314}"),
+    (0x01B5, "\u{5A}\u{335}"),
+    (0x01B6, "\u{7A}\u{335}"),
+    (0x01B7, "\u{33}"),
+    (0x01BB, "\u{32}\u{335}"),
+    (0x01BC, "\u{35}"),
+    (0x01BD, "\u{73}"),
+    (0x01BF, "\u{FE}"),
+    (0x01C0, "\u{6C}"),
+    (0x01C1, "\u{6C}\u{6C}"),
+    (0x01C3, "\u{21}"),
+    (0x01C4, "\u{44}\u{17D}"),
+    (0x01C5, "\u{44}\u{17E}"),
+    (0x01C6, "\u{64}\u{17E}"),
+    (0x01C7, "\u{4C}\u{4A}"),
+    (0x01C8, "\u{4C}\u{6A}"),
+    (0x01C9, "\u{6C}\u{6A}"),
+    (0x01CA, "\u{4E}\u{4A}"),
+    (0x01CB, "\u{4E}\u{6A}"),
+    (0x01CC, "\u{6E}\u{6A}"),
+    (0x01CD, "\u{102}"),
+    (0x01CE, "\u{103}"),
+    (0x01CF, "\u{12C}"),
+    (0x01D0, "\u{12D}"),
+    (0x01D1, "\u{14E}"),
+    (0x01D2, "\u{14F}"),
+    (0x01D3, "\u{16C}"),
+    (0x01D4, "\u{16D}"),
+    (0x01E4, "\u{47}\u{335}"),
+    (0x01E5, "\u{67}\u{335}"),
+    (0x01E6, "\u{11E}"),
+    (0x01E7, "\u{11F}"),
+    (0x01F1, "\u{44}\u{5A}"),
+    (0x01F2, "\u{44}\u{7A}"),
+    (0x01F3, "\u{64}\u{7A}"),
+    (0x01F5, "\u{123}"),
+    (0x01FE, "\u{4F}\u{338}\u{301}"),
+    (0x021A, "\u{162}"),
+    (0x021B, "\u{1AB}"),
+    (0x021C, "\u{33}"),
+    (0x0222, "\u{38}"),
+    (0x0223, "\u{38}"),
+    (0x0224, "\u{5A}\u{326}"),
+    (0x0225, "\u{7A}\u{326}"),
+    (0x0226, "\u{C5}"),
+    (0x0227, "\u{E5}"),
+    (0x023C, "\u{63}\u{338}"),
+    (0x023E, "\u{54}\u{338}"),
+    (0x0241, "\u{3F}"),
+    (0x0244, "\u{55}\u{335}"),
+    (0x0246, "\u{45}\u{338}"),
+    (0x0247, "\u{65}\u{338}"),
+    (0x0248, "\u{4A}\u{335}"),
+    (0x0249, "\u{6A}\u{335}"),
+    (0x024D, "\u{72}\u{335}"),
+    (0x024E, "\u{59}\u{335}"),
+    (0x024F, "\u{79}\u{335}"),
+    (0x0251, "\u{61}"),
+    (0x0253, "\u{62}\u{314}"),
+    (0x0256, "\u{64}\u{328}"),
+    (0x0257, "\u{64}\u{314}"),
+    (0x0259, "\u{1DD}"),
+    (0x025A, "\u{1DD}\u{2DE}"),
+    (0x025B, "\u{A793}"),
+    (0x0260, "\u{67}\u{314}"),
+    (0x0261, "\u{67}"),
+    (0x0263, "\u{79}"),
+    (0x0266, "\u{68}\u{314}"),
+    (0x0268, "\u{69}\u{335}"),
+    (0x0269, "\u{69}"),
+    (0x026A, "\u{69}"),
+    (0x026B, "\u{6C}\u{334}"),
+    (0x026D, "\u{6C}\u{328}"),
+    (0x026E, "\u{6C}\u{21D}"),
+    (0x026F, "\u{77}"),
+    (0x0271, "\u{72}\u{6E}\u{326}"),
+    (0x0273, "\u{6E}\u{328}"),
+    (0x0275, "\u{6F}\u{335}"),
+    (0x0276, "\u{6F}\u{1D07}"),
+    (0x027C, "\u{72}\u{329}"),
+    (0x027D, "\u{72}\u{328}"),
+    (0x0282, "\u{73}\u{328}"),
+    (0x028B, "\u{75}"),
+    (0x028F, "\u{79}"),
+    (0x0290, "\u{7A}\u{328}"),
+    (0x0292, "\u{21D}"),
+    (0x0294, "\u{3F}"),
+    (0x02A0, "\u{71}\u{314}"),
+    (0x02A3, "\u{64}\u{7A}"),
+    (0x02A4, "\u{64}\u{21D}"),
+    (0x02A5, "\u{64}\u{291}"),
+    (0x02A6, "\u{74}\u{73}"),
+    (0x02A7, "\u{74}\u{283}"),
+    (0x02A8, "\u{74}\u{255}"),
+    (0x02A9, "\u{66}\u{14B}"),
+    (0x02AA, "\u{6C}\u{73}"),
+    (0x02AB, "\u{6C}\u{7A}"),
+    (0x02B3, "\u{18F4}"),
+    (0x02B9, "\u{27}"),
+    (0x02BA, "\u{27}\u{27}"),
+    (0x02BB, "\u{27}"),
+    (0x02BC, "\u{27}"),
+    (0x02BD, "\u{27}"),
+    (0x02BE, "\u{27}"),
+    (0x02BF, "\u{559}"),
+    (0x02C2, "\u{3C}"),
+    (0x02C3, "\u{3E}"),
+    (0x02C4, "\u{5E}"),
+    (0x02C6, "\u{5E}"),
+    (0x02C8, "\u{27}"),
+    (0x02CA, "\u{27}"),
+    (0x02CB, "\u{27}"),
+    (0x02D0, "\u{3A}"),
+    (0x02D3, "\u{559}"),
+    (0x02D7, "\u{2D}"),
+    (0x02D8, "\u{2C7}"),
+    (0x02D9, "\u{971}"),
+    (0x02DA, "\u{B0}"),
+    (0x02DB, "\u{69}"),
+    (0x02DC, "\u{7E}"),
+    (0x02DD, "\u{27}\u{27}"),
+    (0x02E1, "\u{18F3}"),
+    (0x02E2, "\u{18F5}"),
+    (0x02E4, "\u{2C1}"),
+    (0x02EE, "\u{27}\u{27}"),
+    (0x02F4, "\u{27}"),
+    (0x02F6, "\u{27}\u{27}"),
+    (0x02F8, "\u{3A}"),
+    (0x02FB, "\u{2EA}"),
+    (0x0305, "\u{304}"),
+    (0x030C, "\u{306}"),
+    (0x030D, "\u{670}"),
+    (0x0310, "\u{306}\u{307}"),
+    (0x0311, "\u{302}"),
+    (0x0315, "\u{313}"),
+    (0x0317, "\u{650}"),
+    (0x0320, "\u{331}"),
+    (0x0321, "\u{326}"),
+    (0x0322, "\u{328}"),
+    (0x0327, "\u{326}"),
+    (0x0336, "\u{335}"),
+    (0x0337, "\u{338}"),
+    (0x0339, "\u{326}"),
+    (0x0340, "\u{300}"),
+    (0x0341, "\u{301}"),
+    (0x0342, "\u{303}"),
+    (0x0343, "\u{313}"),
+    (0x0345, "\u{328}"),
+    (0x0347, "\u{333}"),
+    (0x0357, "\u{350}"),
+    (0x0358, "\u{307}"),
+    (0x0366, "\u{30A}"),
+    (0x036E, "\u{306}"),
+    (0x0370, "\u{2C75}"),
+    (0x0374, "\u{27}"),
+    (0x0375, "\u{2CF}"),
+    (0x0376, "\u{418}"),
+    (0x0377, "\u{1D0E}"),
+    (0x037A, "\u{69}"),
+    (0x037B, "\u{254}"),
+    (0x037D, "\u{A73F}"),
+    (0x037E, "\u{3B}"),
+    (0x037F, "\u{4A}"),
+    (0x0384, "\u{27}"),
+    (0x0387, "\u{B7}"),
+    (0x0391, "\u{41}"),
+    (0x0392, "\u{42}"),
+    (0x0395, "\u{45}"),
+    (0x0396, "\u{5A}"),
+    (0x0397, "\u{48}"),
+    (0x0398, "\u{4F}\u{335}"),
+    (0x0399, "\u{6C}"),
+    (0x039A, "\u{4B}"),
+    (0x039B, "\u{245}"),
+    (0x039C, "\u{4D}"),
+    (0x039D, "\u{4E}"),
+    (0x039F, "\u{4F}"),
+    (0x03A1, "\u{50}"),
+    (0x03A3, "\u{1A9}"),
+    (0x03A4, "\u{54}"),
+    (0x03A5, "\u{59}"),
+    (0x03A7, "\u{58}"),
+    (0x03B1, "\u{61}"),
+    (0x03B2, "\u{DF}"),
+    (0x03B3, "\u{79}"),
+    (0x03B4, "\u{1E9F}"),
+    (0x03B5, "\u{A793}"),
+    (0x03B7, "\u{6E}\u{329}"),
+    (0x03B8, "\u{4F}\u{335}"),
+    (0x03B9, "\u{69}"),
+    (0x03BA, "\u{138}"),
+    (0x03BD, "\u{76}"),
+    (0x03BF, "\u{6F}"),
+    (0x03C1, "\u{70}"),
+    (0x03C3, "\u{6F}"),
+    (0x03C4, "\u{1D1B}"),
+    (0x03C5, "\u{75}"),
+    (0x03C6, "\u{278}"),
+    (0x03D0, "\u{DF}"),
+    (0x03D1, "\u{4F}\u{335}"),
+    (0x03D2, "\u{59}"),
+    (0x03D5, "\u{278}"),
+    (0x03D6, "\u{3C0}"),
+    (0x03DB, "\u{3C2}"),
+    (0x03DC, "\u{46}"),
+    (0x03E8, "\u{32}"),
+    (0x03E9, "\u{1A8}"),
+    (0x03F0, "\u{138}"),
+    (0x03F1, "\u{70}"),
+    (0x03F2, "\u{63}"),
+    (0x03F3, "\u{6A}"),
+    (0x03F4, "\u{4F}\u{335}"),
+    (0x03F5, "\u{A793}"),
+    (0x03F7, "\u{DE}"),
+    (0x03F8, "\u{FE}"),
+    (0x03F9, "\u{43}"),
+    (0x03FA, "\u{4D}"),
+    (0x03FD, "\u{186}"),
+    (0x03FF, "\u{A73E}"),
+    (0x0404, "\u{A792}"),
+    (0x0405, "\u{53}"),
+    (0x0406, "\u{6C}"),
+    (0x0408, "\u{4A}"),
+    (0x0410, "\u{41}"),
+    (0x0411, "\u{62}\u{304}"),
+    (0x0412, "\u{42}"),
+    (0x0413, "\u{393}"),
+    (0x0415, "\u{45}"),
+    (0x0417, "\u{33}"),
+    (0x0419, "\u{40D}"),
+    (0x041A, "\u{4B}"),
+    (0x041B, "\u{245}"),
+    (0x041C, "\u{4D}"),
+    (0x041D, "\u{48}"),
+    (0x041E, "\u{4F}"),
+    (0x041F, "\u{3A0}"),
+    (0x0420, "\u{50}"),
+    (0x0421, "\u{43}"),
+    (0x0422, "\u{54}"),
+    (0x0423, "\u{59}"),
+    (0x0424, "\u{3A6}"),
+    (0x0425, "\u{58}"),
+    (0x042B, "\u{62}\u{6C}"),
+    (0x042C, "\u{62}"),
+    (0x042E, "\u{6C}\u{4F}"),
+    (0x0430, "\u{61}"),
+    (0x0431, "\u{36}"),
+    (0x0432, "\u{299}"),
+    (0x0433, "\u{72}"),
+    (0x0435, "\u{65}"),
+    (0x0437, "\u{25C}"),
+    (0x0438, "\u{1D0E}"),
+    (0x043A, "\u{138}"),
+    (0x043C, "\u{28D}"),
+    (0x043D, "\u{29C}"),
+    (0x043E, "\u{6F}"),
+    (0x043F, "\u{3C0}"),
+    (0x0440, "\u{70}"),
+    (0x0441, "\u{63}"),
+    (0x0442, "\u{1D1B}"),
+    (0x0443, "\u{79}"),
+    (0x0444, "\u{278}"),
+    (0x0445, "\u{78}"),
+    (0x044A, "\u{2C9}\u{62}"),
+    (0x044B, "\u{185}\u{69}"),
+    (0x044C, "\u{185}"),
+    (0x044F, "\u{1D19}"),
+    (0x0454, "\u{A793}"),
+    (0x0455, "\u{73}"),
+    (0x0456, "\u{69}"),
+    (0x0458, "\u{6A}"),
+    (0x045B, "\u{68}\u{335}"),
+    (0x045D, "\u{439}"),
+    (0x0461, "\u{77}"),
+    (0x0462, "\u{62}\u{335}"),
+    (0x0463, "\u{62}\u{335}"),
+    (0x0470, "\u{3A8}"),
+    (0x0471, "\u{3C8}"),
+    (0x0472, "\u{4F}\u{335}"),
+    (0x0473, "\u{6F}\u{335}"),
+    (0x0474, "\u{56}"),
+    (0x0475, "\u{76}"),
+    (0x047C, "\u{460}\u{486}\u{487}"),
+    (0x047D, "\u{77}\u{486}\u{487}"),
+    (0x048A, "\u{40D}\u{326}"),
+    (0x048B, "\u{439}\u{326}"),
+    (0x048C, "\u{62}\u{335}"),
+    (0x048D, "\u{62}\u{335}"),
+    (0x0490, "\u{393}\u{27}"),
+    (0x0491, "\u{72}\u{27}"),
+    (0x0492, "\u{393}\u{335}"),
+    (0x0493, "\u{72}\u{335}"),
+    (0x0496, "\u{416}\u{329}"),
+    (0x0497, "\u{436}\u{329}"),
+    (0x0498, "\u{33}\u{326}"),
+    (0x0499, "\u{25C}\u{326}"),
+    (0x049A, "\u{4B}\u{329}"),
+    (0x049B, "\u{138}\u{329}"),
+    (0x049E, "\u{4B}\u{335}"),
+    (0x049F, "\u{138}\u{335}"),
+    (0x04A2, "\u{48}\u{329}"),
+    (0x04A3, "\u{29C}\u{329}"),
+    (0x04AA, "\u{43}\u{326}"),
+    (0x04AB, "\u{63}\u{326}"),
+    (0x04AC, "\u{54}\u{329}"),
+    (0x04AD, "\u{1D1B}\u{329}"),
+    (0x04AE, "\u{59}"),
+    (0x04AF, "\u{79}"),
+    (0x04B0, "\u{59}\u{335}"),
+    (0x04B1, "\u{79}\u{335}"),
+    (0x04B2, "\u{58}\u{329}"),
+    (0x04BB, "\u{68}"),
+    (0x04BD, "\u{65}"),
+    (0x04BE, "\u{4BC}\u{328}"),
+    (0x04BF, "\u{65}\u{328}"),
+    (0x04C0, "\u{6C}"),
+    (0x04C5, "\u{245}\u{326}"),
+    (0x04C6, "\u{43B}\u{326}"),
+    (0x04C7, "\u{48}\u{326}"),
+    (0x04C8, "\u{29C}\u{326}"),
+    (0x04C9, "\u{48}\u{326}"),
+    (0x04CA, "\u{29C}\u{326}"),
+    (0x04CB, "\u{4B6}"),
+    (0x04CC, "\u{4B7}"),
+    (0x04CD, "\u{4D}\u{326}"),
+    (0x04CE, "\u{28D}\u{326}"),
+    (0x04CF, "\u{69}"),
+    (0x04D4, "\u{41}\u{45}"),
+    (0x04D5, "\u{61}\u{65}"),
+    (0x04D8, "\u{18F}"),
+    (0x04D9, "\u{1DD}"),
+    (0x04E0, "\u{33}"),
+    (0x04E1, "\u{21D}"),
+    (0x04E8, "\u{4F}\u{335}"),
+    (0x04E9, "\u{6F}\u{335}"),
+    (0x0501, "\u{64}"),
+    (0x050A, "\u{1F6}"),
+    (0x050C, "\u{47}"),
+    (0x050D, "\u{262}"),
+    (0x0510, "\u{190}"),
+    (0x0511, "\u{A793}"),
+    (0x051B, "\u{71}"),
+    (0x051C, "\u{57}"),
+    (0x051D, "\u{77}"),
+    (0x053B, "\u{12AE}"),
+    (0x0544, "\u{1206}"),
+    (0x054A, "\u{1323}"),
+    (0x054C, "\u{1261}"),
+    (0x054D, "\u{55}"),
+    (0x054F, "\u{53}"),
+    (0x0553, "\u{3A6}"),
+    (0x0555, "\u{4F}"),
+    (0x055A, "\u{27}"),
+    (0x055D, "\u{27}"),
+    (0x0561, "\u{77}"),
+    (0x0563, "\u{71}"),
+    (0x0566, "\u{71}"),
+    (0x056E, "\u{1E9F}"),
+    (0x0570, "\u{68}"),
+    (0x0575, "\u{237}"),
+    (0x0578, "\u{6E}"),
+    (0x057A, "\u{270}"),
+    (0x057C, "\u{6E}"),
+    (0x057D, "\u{75}"),
+    (0x0581, "\u{67}"),
+    (0x0584, "\u{66}"),
+    (0x0585, "\u{6F}"),
+    (0x0587, "\u{565}\u{582}"),
+    (0x0589, "\u{3A}"),
+    (0x059C, "\u{301}"),
+    (0x059D, "\u{301}"),
+    (0x05A4, "\u{59A}"),
+    (0x05A8, "\u{599}"),
+    (0x05AD, "\u{596}"),
+    (0x05AE, "\u{598}"),
+    (0x05AF, "\u{30A}"),
+    (0x05B4, "\u{323}"),
+    (0x05B9, "\u{307}"),
+    (0x05BA, "\u{307}"),
+    (0x05C0, "\u{6C}"),
+    (0x05C1, "\u{307}"),
+    (0x05C2, "\u{307}"),
+    (0x05C3, "\u{3A}"),
+    (0x05C4, "\u{307}"),
+    (0x05C5, "\u{323}"),
+    (0x05D5, "\u{6C}"),
+    (0x05D8, "\u{76}"),
+    (0x05D9, "\u{27}"),
+    (0x05DF, "\u{6C}"),
+    (0x05E1, "\u{6F}"),
+    (0x05F0, "\u{6C}\u{6C}"),
+    (0x05F1, "\u{6C}\u{27}"),
+    (0x05F2, "\u{27}\u{27}"),
+    (0x05F3, "\u{27}"),
+    (0x05F4, "\u{27}\u{27}"),
+    (0x0609, "\u{BA}\u{2F}\u{2080}\u{2080}"),
+    (0x060A, "\u{BA}\u{2F}\u{2080}\u{2080}\u{2080}"),
+    (0x060D, "\u{2C}"),
+    (0x060F, "\u{639}"),
+    (0x0618, "\u{301}"),
+    (0x0619, "\u{313}"),
+    (0x061A, "\u{650}"),
+    (0x0623, "\u{6C}\u{674}"),
+    (0x0624, "\u{648}\u{674}"),
+    (0x0625, "\u{6C}\u{655}"),
+    (0x0626, "\u{649}\u{674}"),
+    (0x0627, "\u{6C}"),
+    (0x062B, "\u{649}\u{6DB}"),
+    (0x0634, "\u{633}\u{6DB}"),
+    (0x063D, "\u{649}\u{302}"),
+    (0x063F, "\u{649}\u{6DB}"),
+    (0x0647, "\u{6F}"),
+    (0x064A, "\u{649}"),
+    (0x064B, "\u{30B}"),
+    (0x064E, "\u{301}"),
+    (0x064F, "\u{313}"),
+    (0x0652, "\u{30A}"),
+    (0x0653, "\u{303}"),
+    (0x0656, "\u{329}"),
+    (0x0657, "\u{312}"),
+    (0x0658, "\u{306}"),
+    (0x0659, "\u{304}"),
+    (0x065A, "\u{306}"),
+    (0x065B, "\u{302}"),
+    (0x065C, "\u{323}"),
+    (0x065D, "\u{314}"),
+    (0x065F, "\u{655}"),
+    (0x0660, "\u{2E}"),
+    (0x0661, "\u{6C}"),
+    (0x0665, "\u{6F}"),
+    (0x0667, "\u{56}"),
+    (0x0668, "\u{245}"),
+    (0x066A, "\u{BA}\u{2F}\u{2080}"),
+    (0x066B, "\u{2C}"),
+    (0x066C, "\u{60C}"),
+    (0x066D, "\u{2A}"),
+    (0x066E, "\u{649}"),
+    (0x066F, "\u{6A1}"),
+    (0x0672, "\u{6C}\u{674}"),
+    (0x0673, "\u{6C}\u{655}"),
+    (0x0675, "\u{6C}\u{674}"),
+    (0x0676, "\u{648}\u{674}"),
+    (0x0677, "\u{648}\u{313}\u{674}"),
+    (0x0678, "\u{649}\u{674}"),
+    (0x0679, "\u{649}\u{615}"),
+    (0x067E, "\u{649}\u{6DB}"),
+    (0x0681, "\u{62D}\u{654}"),
+    (0x0685, "\u{62D}\u{6DB}"),
+    (0x0688, "\u{62F}\u{615}"),
+    (0x068B, "\u{68A}\u{615}"),
+    (0x068E, "\u{62F}\u{6DB}"),
+    (0x0691, "\u{631}\u{615}"),
+    (0x0692, "\u{631}\u{306}"),
+    (0x0698, "\u{631}\u{6DB}"),
+    (0x069E, "\u{635}\u{6DB}"),
+    (0x069F, "\u{637}\u{6DB}"),
+    (0x06A4, "\u{6A1}\u{6DB}"),
+    (0x06A7, "\u{641}"),
+    (0x06A8, "\u{6A1}\u{6DB}"),
+    (0x06A9, "\u{643}"),
+    (0x06AA, "\u{643}"),
+    (0x06AD, "\u{643}\u{6DB}"),
+    (0x06B4, "\u{6AF}\u{6DB}"),
+    (0x06B5, "\u{644}\u{306}"),
+    (0x06B7, "\u{644}\u{6DB}"),
+    (0x06BA, "\u{649}"),
+    (0x06BB, "\u{649}\u{615}"),
+    (0x06BD, "\u{649}\u{6DB}"),
+    (0x06BE, "\u{6F}"),
+    (0x06C1, "\u{6F}"),
+    (0x06C2, "\u{6C0}"),
+    (0x06C3, "\u{629}"),
+    (0x06C6, "\u{648}\u{306}"),
+    (0x06C7, "\u{648}\u{313}"),
+    (0x06C8, "\u{648}\u{670}"),
+    (0x06C9, "\u{648}\u{302}"),
+    (0x06CB, "\u{648}\u{6DB}"),
+    (0x06CC, "\u{649}"),
+    (0x06CE, "\u{649}\u{306}"),
+    (0x06D0, "\u{67B}"),
+    (0x06D1, "\u{649}\u{6DB}"),
+    (0x06D2, "\u{649}"),
+    (0x06D4, "\u{2D}"),
+    (0x06D5, "\u{6F}"),
+    (0x06DF, "\u{30A}"),
+    (0x06E8, "\u{306}\u{307}"),
+    (0x06EC, "\u{307}"),
+    (0x06EE, "\u{62F}\u{302}"),
+    (0x06EF, "\u{631}\u{302}"),
+    (0x06F0, "\u{2E}"),
+    (0x06F1, "\u{6C}"),
+    (0x06F2, "\u{662}"),
+    (0x06F3, "\u{663}"),
+    (0x06F4, "\u{664}"),
+    (0x06F5, "\u{6F}"),
+    (0x06F6, "\u{666}"),
+    (0x06F7, "\u{56}"),
+    (0x06F8, "\u{245}"),
+    (0x06F9, "\u{669}"),
+    (0x06FD, "\u{621}\u{348}"),
+    (0x06FE, "\u{645}\u{348}"),
+    (0x06FF, "\u{6F}\u{302}"),
+    (0x0701, "\u{2E}"),
+    (0x0702, "\u{2E}"),
+    (0x0703, "\u{3A}"),
+    (0x0704, "\u{3A}"),
+    (0x0740, "\u{307}"),
+    (0x0741, "\u{307}"),
+    (0x0742, "\u{73C}"),
+    (0x0747, "\u{301}"),
+    (0x0751, "\u{628}\u{6DB}"),
+    (0x0756, "\u{649}\u{306}"),
+    (0x0762, "\u{6AC}"),
+    (0x0763, "\u{643}\u{6DB}"),
+    (0x0767, "\u{754}"),
+    (0x0768, "\u{646}\u{615}"),
+    (0x0769, "\u{646}\u{306}"),
+    (0x076C, "\u{631}\u{654}"),
+    (0x0771, "\u{697}\u{615}"),
+    (0x0772, "\u{62D}\u{654}"),
+    (0x077E, "\u{633}\u{302}"),
+    (0x07C0, "\u{4F}"),
+    (0x07CA, "\u{6C}"),
+    (0x07EB, "\u{304}"),
+    (0x07ED, "\u{307}"),
+    (0x07EE, "\u{302}"),
+    (0x07F3, "\u{308}"),
+    (0x07F4, "\u{27}"),
+    (0x07F5, "\u{27}"),
+    (0x07FA, "\u{5F}"),
+    (0x08A1, "\u{628}\u{654}"),
+    (0x08A4, "\u{6A2}\u{6DB}"),
+    (0x08A7, "\u{645}\u{6DB}"),
+    (0x08A8, "\u{649}\u{654}"),
+    (0x08A9, "\u{754}"),
+    (0x08AE, "\u{62F}\u{324}\u{323}"),
+    (0x08AF, "\u{635}\u{324}\u{323}"),
+    (0x08B0, "\u{6AF}"),
+    (0x08B1, "\u{648}"),
+    (0x08B2, "\u{632}\u{302}"),
+    (0x08B6, "\u{628}\u{6E2}"),
+    (0x08B7, "\u{649}\u{6DB}\u{6E2}"),
+    (0x08B9, "\u{631}\u{306}\u{307}"),
+    (0x08BA, "\u{649}\u{306}\u{307}"),
+    (0x08BB, "\u{6A1}"),
+    (0x08BC, "\u{6A1}"),
+    (0x08BD, "\u{649}"),
+    (0x08E5, "\u{64C}"),
+    (0x08E8, "\u{64C}"),
+    (0x08EA, "\u{307}"),
+    (0x08EB, "\u{308}"),
+    (0x08ED, "\u{323}"),
+    (0x08EE, "\u{324}"),
+    (0x08F0, "\u{30B}"),
+    (0x08F1, "\u{64C}"),
+    (0x08F2, "\u{64D}"),
+    (0x08F3, "\u{313}"),
+    (0x08F8, "\u{350}"),
+    (0x08F9, "\u{354}"),
+    (0x08FA, "\u{355}"),
+    (0x08FF, "\u{350}"),
+    (0x0900, "\u{352}"),
+    (0x0901, "\u{306}\u{307}"),
+    (0x0902, "\u{307}"),
+    (0x0903, "\u{3A}"),
+    (0x0904, "\u{905}\u{946}"),
+    (0x0906, "\u{905}\u{93E}"),
+    (0x0908, "\u{930}\u{94D}\u{907}"),
+    (0x090D, "\u{90F}\u{945}"),
+    (0x090E, "\u{90F}\u{946}"),
+    (0x0910, "\u{90F}\u{947}"),
+    (0x0911, "\u{905}\u{949}"),
+    (0x0912, "\u{905}\u{93E}\u{946}"),
+    (0x0913, "\u{905}\u{93E}\u{947}"),
+    (0x0914, "\u{905}\u{93E}\u{948}"),
+    (0x093C, "\u{323}"),
+    (0x0952, "\u{331}"),
+    (0x0953, "\u{300}"),
+    (0x0954, "\u{301}"),
+    (0x0965, "\u{964}\u{964}"),
+    (0x0966, "\u{6F}"),
+    (0x0967, "\u{669}"),
+    (0x097D, "\u{3F}"),
+    (0x0981, "\u{306}\u{307}"),
+    (0x0986, "\u{985}\u{9BE}"),
+    (0x09BC, "\u{323}"),
+    (0x09E0, "\u{98B}\u{9C3}"),
+    (0x09E1, "\u{98B}\u{9C3}"),
+    (0x09E6, "\u{4F}"),
+    (0x09EA, "\u{38}"),
+    (0x09ED, "\u{39}"),
+    (0x0A02, "\u{307}"),
+    (0x0A03, "\u{983}"),
+    (0x0A06, "\u{A05}\u{A3E}"),
+    (0x0A07, "\u{A72}\u{A3F}"),
+    (0x0A08, "\u{A72}\u{A40}"),
+    (0x0A09, "\u{A73}\u{A41}"),
+    (0x0A0A, "\u{A73}\u{A42}"),
+    (0x0A0F, "\u{A72}\u{A47}"),
+    (0x0A10, "\u{A05}\u{A48}"),
+    (0x0A14, "\u{A05}\u{A4C}"),
+    (0x0A3C, "\u{323}"),
+    (0x0A4B, "\u{946}"),
+    (0x0A4D, "\u{94D}"),
+    (0x0A66, "\u{6F}"),
+    (0x0A67, "\u{39}"),
+    (0x0A6A, "\u{38}"),
+    (0x0A81, "\u{306}\u{307}"),
+    (0x0A82, "\u{307}"),
+    (0x0A83, "\u{3A}"),
+    (0x0A86, "\u{A85}\u{ABE}"),
+    (0x0A8D, "\u{A85}\u{AC5}"),
+    (0x0A8F, "\u{A85}\u{AC7}"),
+    (0x0A90, "\u{A85}\u{AC8}"),
+    (0x0A91, "\u{A85}\u{ABE}\u{AC5}"),
+    (0x0A93, "\u{A85}\u{ABE}\u{AC7}"),
+    (0x0A94, "\u{A85}\u{ABE}\u{AC8}"),
+    (0x0ABC, "\u{323}"),
+    (0x0ABD, "\u{93D}"),
+    (0x0AC1, "\u{941}"),
+    (0x0AC2, "\u{942}"),
+    (0x0ACD, "\u{94D}"),
+    (0x0AE6, "\u{6F}"),
+    (0x0AE8, "\u{968}"),
+    (0x0AE9, "\u{969}"),
+    (0x0AEA, "\u{96A}"),
+    (0x0AEE, "\u{96E}"),
+    (0x0AF0, "\u{970}"),
+    (0x0B01, "\u{306}\u{307}"),
+    (0x0B03, "\u{38}"),
+    (0x0B06, "\u{B05}\u{B3E}"),
+    (0x0B20, "\u{4F}"),
+    (0x0B3C, "\u{323}"),
+    (0x0B66, "\u{4F}"),
+    (0x0B68, "\u{39}"),
+    (0x0B82, "\u{30A}"),
+    (0x0B8A, "\u{B89}\u{BB3}"),
+    (0x0B9C, "\u{B90}"),
+    (0x0BB0, "\u{B88}"),
+    (0x0BBE, "\u{B88}"),
+    (0x0BC8, "\u{BA9}"),
+    (0x0BCA, "\u{BC6}\u{B88}"),
+    (0x0BCB, "\u{BC7}\u{B88}"),
+    (0x0BCC, "\u{BC6}\u{BB3}"),
+    (0x0BCD, "\u{307}"),
+    (0x0BD7, "\u{BB3}"),
+    (0x0BE6, "\u{6F}"),
+    (0x0BE7, "\u{B95}"),
+    (0x0BE8, "\u{B89}"),
+    (0x0BEA, "\u{B9A}"),
+    (0x0BEB, "\u{B88}\u{BC1}"),
+    (0x0BEC, "\u{B9A}\u{BC1}"),
+    (0x0BED, "\u{B8E}"),
+    (0x0BEE, "\u{B85}"),
+    (0x0BF0, "\u{BAF}"),
+    (0x0BF2, "\u{B9A}\u{BC2}"),
+    (0x0BF4, "\u{BAE}\u{BC0}"),
+    (0x0BF5, "\u{BF3}"),
+    (0x0BF7, "\u{B8E}\u{BB5}"),
+    (0x0BF8, "\u{BB7}"),
+    (0x0BFA, "\u{BA8}\u{BC0}"),
+    (0x0C00, "\u{306}\u{307}"),
+    (0x0C02, "\u{6F}"),
+    (0x0C03, "\u{983}"),
+    (0x0C13, "\u{C12}\u{C55}"),
+    (0x0C14, "\u{C12}\u{C4C}"),
+    (0x0C20, "\u{C30}\u{5BC}"),
+    (0x0C22, "\u{C21}\u{323}"),
+    (0x0C25, "\u{C27}\u{5BC}"),
+    (0x0C2D, "\u{C2C}\u{323}"),
+    (0x0C2E, "\u{C35}\u{C41}"),
+    (0x0C37, "\u{C35}\u{323}"),
+    (0x0C39, "\u{C35}\u{C3E}"),
+    (0x0C42, "\u{C41}\u{C3E}"),
+    (0x0C44, "\u{C43}\u{C3E}"),
+    (0x0C60, "\u{C0B}\u{C3E}"),
+    (0x0C61, "\u{C0C}\u{C3E}"),
+    (0x0C66, "\u{6F}"),
+    (0x0C81, "\u{306}\u{307}"),
+    (0x0C82, "\u{6F}"),
+    (0x0C83, "\u{983}"),
+    (0x0C85, "\u{C05}"),
+    (0x0C86, "\u{C06}"),
+    (0x0C87, "\u{C07}"),
+    (0x0C92, "\u{C12}"),
+    (0x0C93, "\u{C12}\u{C55}"),
+    (0x0C94, "\u{C12}\u{C4C}"),
+    (0x0C9C, "\u{C1C}"),
+    (0x0C9E, "\u{C1E}"),
+    (0x0CA3, "\u{C23}"),
+    (0x0CAF, "\u{C2F}"),
+    (0x0CB1, "\u{C31}"),
+    (0x0CB2, "\u{C32}"),
+    (0x0CE1, "\u{C8C}\u{CBE}"),
+    (0x0CE6, "\u{6F}"),
+    (0x0CE7, "\u{C67}"),
+    (0x0CE8, "\u{C68}"),
+    (0x0CEF, "\u{C6F}"),
+    (0x0D01, "\u{306}\u{307}"),
+    (0x0D02, "\u{6F}"),
+    (0x0D03, "\u{983}"),
+    (0x0D08, "\u{D07}\u{D57}"),
+    (0x0D09, "\u{B89}"),
+    (0x0D0A, "\u{B89}\u{D57}"),
+    (0x0D0C, "\u{D28}\u{D41}"),
+    (0x0D10, "\u{D0E}\u{D46}"),
+    (0x0D13, "\u{D12}\u{D3E}"),
+    (0x0D14, "\u{D12}\u{D57}"),
+    (0x0D19, "\u{D28}\u{D41}"),
+    (0x0D1C, "\u{B90}"),
+    (0x0D20, "\u{6F}"),
+    (0x0D23, "\u{BA3}"),
+    (0x0D31, "\u{D30}"),
+    (0x0D34, "\u{BB4}"),
+    (0x0D36, "\u{BB6}"),
+    (0x0D3A, "\u{B9F}\u{BBF}"),
+    (0x0D3F, "\u{BBF}"),
+    (0x0D40, "\u{BBF}"),
+    (0x0D42, "\u{D41}"),
+    (0x0D43, "\u{D41}"),
+    (0x0D48, "\u{D46}\u{D46}"),
+    (0x0D4E, "\u{971}"),
+    (0x0D5A, "\u{D28}\u{D4D}\u{D2E}"),
+    (0x0D5F, "\u{6F}\u{D30}\u{6F}"),
+    (0x0D61, "\u{D1E}"),
+    (0x0D66, "\u{6F}"),
+    (0x0D6A, "\u{D30}\u{D4D}"),
+    (0x0D6B, "\u{D26}\u{D4D}\u{D30}"),
+    (0x0D6C, "\u{D28}\u{D4D}\u{D28}"),
+    (0x0D6D, "\u{39}"),
+    (0x0D6E, "\u{D35}\u{D4D}\u{D30}"),
+    (0x0D6F, "\u{D28}\u{D4D}"),
+    (0x0D76, "\u{D39}\u{D4D}\u{D2E}"),
+    (0x0D79, "\u{D28}\u{D41}"),
+    (0x0D7B, "\u{D28}\u{D4D}"),
+    (0x0D7C, "\u{D30}\u{D4D}"),
+    (0x0D82, "\u{6F}"),
+    (0x0D83, "\u{983}"),
+    (0x0DE9, "\u{DE8}\u{DCF}"),
+    (0x0DEA, "\u{DA2}"),
+    (0x0DEB, "\u{DAF}"),
+    (0x0DEF, "\u{DE8}\u{DD3}"),
+    (0x0E03, "\u{E02}"),
+    (0x0E0B, "\u{E0A}"),
+    (0x0E0F, "\u{E0E}"),
+    (0x0E14, "\u{E04}"),
+    (0x0E15, "\u{E04}"),
+    (0x0E17, "\u{E11}"),
+    (0x0E21, "\u{E06}"),
+    (0x0E26, "\u{E20}"),
+    (0x0E33, "\u{30A}\u{E32}"),
+    (0x0E41, "\u{E40}\u{E40}"),
+    (0x0E45, "\u{E32}"),
+    (0x0E4D, "\u{30A}"),
+    (0x0E50, "\u{6F}"),
+    (0x0E88, "\u{E08}"),
+    (0x0E8D, "\u{E22}"),
+    (0x0E9A, "\u{E1A}"),
+    (0x0E9B, "\u{E1B}"),
+    (0x0E9D, "\u{E1D}"),
+    (0x0E9E, "\u{E1E}"),
+    (0x0E9F, "\u{E1F}"),
+    (0x0EB3, "\u{30A}\u{EB2}"),
+    (0x0EB8, "\u{E38}"),
+    (0x0EB9, "\u{E39}"),
+    (0x0EC8, "\u{E48}"),
+    (0x0EC9, "\u{E49}"),
+    (0x0ECA, "\u{E4A}"),
+    (0x0ECB, "\u{E4B}"),
+    (0x0ECD, "\u{30A}"),
+    (0x0ED0, "\u{6F}"),
+    (0x0EDC, "\u{EAB}\u{E99}"),
+    (0x0EDD, "\u{EAB}\u{EA1}"),
+    (0x0F00, "\u{F68}\u{F7C}\u{F7E}"),
+    (0x0F02, "\u{F60}\u{F74}\u{F82}\u{F7F}"),
+    (0x0F03, "\u{F60}\u{F74}\u{F82}\u{F14}"),
+    (0x0F0C, "\u{F0B}"),
+    (0x0F0E, "\u{F0D}\u{F0D}"),
+    (0x0F1B, "\u{F1A}\u{F1A}"),
+    (0x0F1E, "\u{F1D}\u{F1D}"),
+    (0x0F1F, "\u{F1A}\u{F1D}"),
+    (0x0F37, "\u{325}"),
+    (0x0F6A, "\u{F62}"),
+    (0x0F77, "\u{FB2}\u{F71}\u{F80}"),
+    (0x0F79, "\u{FB3}\u{F71}\u{F80}"),
+    (0x0FCE, "\u{F1D}\u{F1A}"),
+    (0x0FD5, "\u{5350}"),
+    (0x0FD6, "\u{534D}"),
+    (0x1000, "\u{1002}\u{102C}"),
+    (0x1010, "\u{6F}\u{102C}"),
+    (0x101D, "\u{6F}"),
+    (0x101F, "\u{1015}\u{102C}"),
+    (0x1029, "\u{101E}\u{103C}"),
+    (0x102A, "\u{101E}\u{103C}\u{1031}\u{102C}\u{103A}"),
+    (0x1036, "\u{30A}"),
+    (0x1038, "\u{983}"),
+    (0x1040, "\u{6F}"),
+    (0x104B, "\u{104A}\u{104A}"),
+    (0x1065, "\u{1041}"),
+    (0x1066, "\u{1015}\u{103E}"),
+    (0x106F, "\u{1015}\u{102C}\u{103E}"),
+    (0x1070, "\u{1003}\u{103E}"),
+    (0x107E, "\u{107D}\u{103E}"),
+    (0x1081, "\u{1002}\u{103E}"),
+    (0x109E, "\u{1083}\u{30A}"),
+    (0x10A0, "\u{A786}"),
+    (0x10E7, "\u{79}"),
+    (0x10F3, "\u{21D}"),
+    (0x10FF, "\u{6F}"),
+    (0x1101, "\u{1100}\u{1100}"),
+    (0x1104, "\u{1103}\u{1103}"),
+    (0x1108, "\u{1107}\u{1107}"),
+    (0x110A, "\u{1109}\u{1109}"),
+    (0x110D, "\u{110C}\u{110C}"),
+    (0x1113, "\u{1102}\u{1100}"),
+    (0x1114, "\u{1102}\u{1102}"),
+    (0x1115, "\u{1102}\u{1103}"),
+    (0x1116, "\u{1102}\u{1107}"),
+    (0x1117, "\u{1103}\u{1100}"),
+    (0x1118, "\u{1105}\u{1102}"),
+    (0x1119, "\u{1105}\u{1105}"),
+    (0x111A, "\u{1105}\u{1112}"),
+    (0x111B, "\u{1105}\u{110B}"),
+    (0x111C, "\u{1106}\u{1107}"),
+    (0x111D, "\u{1106}\u{110B}"),
+    (0x111E, "\u{1107}\u{1100}"),
+    (0x111F, "\u{1107}\u{1102}"),
+    (0x1120, "\u{1107}\u{1103}"),
+    (0x1121, "\u{1107}\u{1109}"),
+    (0x1122, "\u{1107}\u{1109}\u{1100}"),
+    (0x1123, "\u{1107}\u{1109}\u{1103}"),
+    (0x1124, "\u{1107}\u{1109}\u{1107}"),
+    (0x1125, "\u{1107}\u{1109}\u{1109}"),
+    (0x1126, "\u{1107}\u{1109}\u{110C}"),
+    (0x1127, "\u{1107}\u{110C}"),
+    (0x1128, "\u{1107}\u{110E}"),
+    (0x1129, "\u{1107}\u{1110}"),
+    (0x112A, "\u{1107}\u{1111}"),
+    (0x112B, "\u{1107}\u{110B}"),
+    (0x112C, "\u{1107}\u{1107}\u{110B}"),
+    (0x112D, "\u{1109}\u{1100}"),
+    (0x112E, "\u{1109}\u{1102}"),
+    (0x112F, "\u{1109}\u{1103}"),
+    (0x1130, "\u{1109}\u{1105}"),
+    (0x1131, "\u{1109}\u{1106}"),
+    (0x1132, "\u{1109}\u{1107}"),
+    (0x1133, "\u{1109}\u{1107}\u{1100}"),
+    (0x1134, "\u{1109}\u{1109}\u{1109}"),
+    (0x1135, "\u{1109}\u{110B}"),
+    (0x1136, "\u{1109}\u{110C}"),
+    (0x1137, "\u{1109}\u{110E}"),
+    (0x1138, "\u{1109}\u{110F}"),
+    (0x1139, "\u{1109}\u{1110}"),
+    (0x113A, "\u{1109}\u{1111}"),
+    (0x113B, "\u{1105}\u{1112}"),
+    (0x113D, "\u{113C}\u{113C}"),
+    (0x113F, "\u{113E}\u{113E}"),
+    (0x1141, "\u{110B}\u{1100}"),
+    (0x1142, "\u{110B}\u{1103}"),
+    (0x1143, "\u{110B}\u{1106}"),
+    (0x1144, "\u{110B}\u{1107}"),
+    (0x1145, "\u{110B}\u{1109}"),
+    (0x1146, "\u{110B}\u{1140}"),
+    (0x1147, "\u{110B}\u{110B}"),
+    (0x1148, "\u{110B}\u{110C}"),
+    (0x1149, "\u{110B}\u{110E}"),
+    (0x114A, "\u{110B}\u{1110}"),
+    (0x114B, "\u{110B}\u{1111}"),
+    (0x114D, "\u{110C}\u{110B}"),
+    (0x114F, "\u{114E}\u{114E}"),
+    (0x1151, "\u{1150}\u{1150}"),
+    (0x1152, "\u{110E}\u{110F}"),
+    (0x1153, "\u{110E}\u{1112}"),
+    (0x1156, "\u{1111}\u{1107}"),
+    (0x1157, "\u{1111}\u{110B}"),
+    (0x1158, "\u{1112}\u{1112}"),
+    (0x115A, "\u{1100}\u{1103}"),
+    (0x115B, "\u{1102}\u{1109}"),
+    (0x115C, "\u{1102}\u{110C}"),
+    (0x115D, "\u{1102}\u{1112}"),
+    (0x115E, "\u{1103}\u{1105}"),
+    (0x1162, "\u{1161}\u{4E28}"),
+    (0x1164, "\u{1163}\u{4E28}"),
+    (0x1166, "\u{1165}\u{4E28}"),
+    (0x1168, "\u{1167}\u{4E28}"),
+    (0x116A, "\u{1169}\u{1161}"),
+    (0x116B, "\u{1169}\u{1161}\u{4E28}"),
+    (0x116C, "\u{1169}\u{4E28}"),
+    (0x116F, "\u{116E}\u{1165}"),
+    (0x1170, "\u{116E}\u{1165}\u{4E28}"),
+    (0x1171, "\u{116E}\u{4E28}"),
+    (0x1173, "\u{30FC}"),
+    (0x1174, "\u{30FC}\u{4E28}"),
+    (0x1175, "\u{4E28}"),
+    (0x1176, "\u{1161}\u{1169}"),
+    (0x1177, "\u{1161}\u{116E}"),
+    (0x1178, "\u{1163}\u{1169}"),
+    (0x1179, "\u{1163}\u{116D}"),
+    (0x117A, "\u{1165}\u{1169}"),
+    (0x117B, "\u{1165}\u{116E}"),
+    (0x117C, "\u{1165}\u{30FC}"),
+    (0x117D, "\u{1167}\u{1169}"),
+    (0x117E, "\u{1167}\u{116E}"),
+    (0x117F, "\u{1169}\u{1165}"),
+    (0x1180, "\u{1169}\u{1165}\u{4E28}"),
+    (0x1181, "\u{1169}\u{1167}\u{4E28}"),
+    (0x1182, "\u{1169}\u{1169}"),
+    (0x1183, "\u{1169}\u{116E}"),
+    (0x1184, "\u{116D}\u{1163}"),
+    (0x1185, "\u{116D}\u{1163}\u{4E28}"),
+    (0x1186, "\u{116D}\u{1163}"),
+    (0x1187, "\u{116D}\u{1169}"),
+    (0x1188, "\u{116D}\u{4E28}"),
+    (0x1189, "\u{116E}\u{1161}"),
+    (0x118A, "\u{116E}\u{1161}\u{4E28}"),
+    (0x118B, "\u{116E}\u{1165}\u{30FC}"),
+    (0x118C, "\u{116E}\u{1167}\u{4E28}"),
+    (0x118D, "\u{116E}\u{116E}"),
+    (0x118E, "\u{1172}\u{1161}"),
+    (0x118F, "\u{1172}\u{1165}"),
+    (0x1190, "\u{1172}\u{1165}\u{4E28}"),
+    (0x1191, "\u{1172}\u{1167}"),
+    (0x1192, "\u{1172}\u{1167}\u{4E28}"),
+    (0x1193, "\u{1172}\u{116E}"),
+    (0x1194, "\u{1172}\u{4E28}"),
+    (0x1195, "\u{30FC}\u{116E}"),
+    (0x1196, "\u{30FC}\u{30FC}"),
+    (0x1197, "\u{30FC}\u{4E28}\u{116E}"),
+    (0x1198, "\u{4E28}\u{1161}"),
+    (0x1199, "\u{4E28}\u{1163}"),
+    (0x119A, "\u{4E28}\u{1169}"),
+    (0x119B, "\u{4E28}\u{116E}"),
+    (0x119C, "\u{4E28}\u{30FC}"),
+    (0x119D, "\u{4E28}\u{119E}"),
+    (0x119F, "\u{119E}\u{1165}"),
+    (0x11A0, "\u{119E}\u{116E}"),
+    (0x11A1, "\u{119E}\u{4E28}"),
+    (0x11A2, "\u{119E}\u{119E}"),
+    (0x11A3, "\u{1161}\u{30FC}"),
+    (0x11A4, "\u{1163}\u{116E}"),
+    (0x11A5, "\u{1167}\u{1163}"),
+    (0x11A6, "\u{1169}\u{1163}"),
+    (0x11A7, "\u{1169}\u{1163}\u{4E28}"),
+    (0x11A8, "\u{1100}"),
+    (0x11A9, "\u{1100}\u{1100}"),
+    (0x11AA, "\u{1100}\u{1109}"),
+    (0x11AB, "\u{1102}"),
+    (0x11AC, "\u{1102}\u{110C}"),
+    (0x11AD, "\u{1102}\u{1112}"),
+    (0x11AE, "\u{1103}"),
+    (0x11AF, "\u{1105}"),
+    (0x11B0, "\u{1105}\u{1100}"),
+    (0x11B1, "\u{1105}\u{1106}"),
+    (0x11B2, "\u{1105}\u{1107}"),
+    (0x11B3, "\u{1105}\u{1109}"),
+    (0x11B4, "\u{1105}\u{1110}"),
+    (0x11B5, "\u{1105}\u{1111}"),
+    (0x11B6, "\u{1105}\u{1112}"),
+    (0x11B7, "\u{1106}"),
+    (0x11B8, "\u{1107}"),
+    (0x11B9, "\u{1107}\u{1109}"),
+    (0x11BA, "\u{1109}"),
+    (0x11BB, "\u{1109}\u{1109}"),
+    (0x11BC, "\u{110B}"),
+    (0x11BD, "\u{110C}"),
+    (0x11BE, "\u{110E}"),
+    (0x11BF, "\u{110F}"),
+    (0x11C0, "\u{1110}"),
+    (0x11C1, "\u{1111}"),
+    (0x11C2, "\u{1112}"),
+    (0x11C3, "\u{1100}\u{1105}"),
+    (0x11C4, "\u{1100}\u{1109}\u{1100}"),
+    (0x11C5, "\u{1102}\u{1100}"),
+    (0x11C6, "\u{1102}\u{1103}"),
+    (0x11C7, "\u{1102}\u{1109}"),
+    (0x11C8, "\u{1102}\u{1140}"),
+    (0x11C9, "\u{1102}\u{1110}"),
+    (0x11CA, "\u{1103}\u{1100}"),
+    (0x11CB, "\u{1103}\u{1105}"),
+    (0x11CC, "\u{1105}\u{1100}\u{1109}"),
+    (0x11CD, "\u{1105}\u{1102}"),
+    (0x11CE, "\u{1105}\u{1103}"),
+    (0x11CF, "\u{1105}\u{1103}\u{1112}"),
+    (0x11D0, "\u{1105}\u{1105}"),
+    (0x11D1, "\u{1105}\u{1106}\u{1100}"),
+    (0x11D2, "\u{1105}\u{1106}\u{1109}"),
+    (0x11D3, "\u{1105}\u{1107}\u{1109}"),
+    (0x11D4, "\u{1105}\u{1107}\u{1112}"),
+    (0x11D5, "\u{1105}\u{1107}\u{110B}"),
+    (0x11D6, "\u{1105}\u{1109}\u{1109}"),
+    (0x11D7, "\u{1105}\u{1140}"),
+    (0x11D8, "\u{1105}\u{110F}"),
+    (0x11D9, "\u{1105}\u{1159}"),
+    (0x11DA, "\u{1106}\u{1100}"),
+    (0x11DB, "\u{1106}\u{1105}"),
+    (0x11DC, "\u{1106}\u{1107}"),
+    (0x11DD, "\u{1106}\u{1109}"),
+    (0x11DE, "\u{1106}\u{1109}\u{1109}"),
+    (0x11DF, "\u{1106}\u{1140}"),
+    (0x11E0, "\u{1106}\u{110E}"),
+    (0x11E1, "\u{1106}\u{1112}"),
+    (0x11E2, "\u{1106}\u{110B}"),
+    (0x11E3, "\u{1107}\u{1105}"),
+    (0x11E4, "\u{1107}\u{1111}"),
+    (0x11E5, "\u{1107}\u{1112}"),
+    (0x11E6, "\u{1107}\u{110B}"),
+    (0x11E7, "\u{1109}\u{1100}"),
+    (0x11E8, "\u{1109}\u{1103}"),
+    (0x11E9, "\u{1109}\u{1105}"),
+    (0x11EA, "\u{1109}\u{1107}"),
+    (0x11EB, "\u{1140}"),
+    (0x11EC, "\u{110B}\u{1100}"),
+    (0x11ED, "\u{110B}\u{1100}\u{1100}"),
+    (0x11EE, "\u{110B}\u{110B}"),
+    (0x11EF, "\u{110B}\u{110F}"),
+    (0x11F0, "\u{114C}"),
+    (0x11F1, "\u{110B}\u{1109}"),
+    (0x11F2, "\u{110B}\u{1140}"),
+    (0x11F3, "\u{1111}\u{1107}"),
+    (0x11F4, "\u{1111}\u{110B}"),
+    (0x11F5, "\u{1112}\u{1102}"),
+    (0x11F6, "\u{1112}\u{1105}"),
+    (0x11F7, "\u{1112}\u{1106}"),
+    (0x11F8, "\u{1112}\u{1107}"),
+    (0x11F9, "\u{1159}"),
+    (0x11FA, "\u{1100}\u{1102}"),
+    (0x11FB, "\u{1100}\u{1107}"),
+    (0x11FC, "\u{1100}\u{110E}"),
+    (0x11FD, "\u{1100}\u{110F}"),
+    (0x11FE, "\u{1100}\u{1112}"),
+    (0x11FF, "\u{1102}\u{1102}"),
+    (0x1200, "\u{55}"),
+    (0x1223, "\u{270}"),
+    (0x1240, "\u{3A6}"),
+    (0x1260, "\u{548}"),
+    (0x1294, "\u{571}"),
+    (0x12D0, "\u{4F}"),
+    (0x13A0, "\u{44}"),
+    (0x13A1, "\u{52}"),
+    (0x13A2, "\u{54}"),
+    (0x13A4, "\u{4F}\u{27}"),
+    (0x13A5, "\u{69}"),
+    (0x13A8, "\u{2C75}"),
+    (0x13A9, "\u{59}"),
+    (0x13AA, "\u{41}"),
+    (0x13AB, "\u{4A}"),
+    (0x13AC, "\u{45}"),
+    (0x13AE, "\u{3F}"),
+    (0x13B0, "\u{2C75}"),
+    (0x13B1, "\u{393}"),
+    (0x13B3, "\u{57}"),
+    (0x13B7, "\u{4D}"),
+    (0x13BB, "\u{48}"),
+    (0x13BD, "\u{59}"),
+    (0x13BE, "\u{4F}\u{335}"),
+    (0x13BF, "\u{1AB}"),
+    (0x13C0, "\u{47}"),
+    (0x13C2, "\u{68}"),
+    (0x13C3, "\u{5A}"),
+    (0x13C7, "\u{460}"),
+    (0x13CB, "\u{190}"),
+    (0x13CC, "\u{55}\u{335}"),
+    (0x13CE, "\u{34}"),
+    (0x13CF, "\u{62}"),
+    (0x13D2, "\u{52}"),
+    (0x13D4, "\u{57}"),
+    (0x13D5, "\u{53}"),
+    (0x13D9, "\u{56}"),
+    (0x13DA, "\u{53}"),
+    (0x13DE, "\u{4C}"),
+    (0x13DF, "\u{43}"),
+    (0x13E2, "\u{50}"),
+    (0x13E6, "\u{4B}"),
+    (0x13E7, "\u{64}"),
+    (0x13EB, "\u{4F}\u{335}"),
+    (0x13EE, "\u{36}"),
+    (0x13F0, "\u{DF}"),
+    (0x13F2, "\u{68}\u{314}"),
+    (0x13F3, "\u{47}"),
+    (0x13F4, "\u{42}"),
+    (0x13FB, "\u{262}"),
+    (0x13FC, "\u{299}"),
+    (0x1400, "\u{3D}"),
+    (0x1403, "\u{394}"),
+    (0x140C, "\u{B7}\u{1401}"),
+    (0x140D, "\u{1401}\u{B7}"),
+    (0x140E, "\u{B7}\u{394}"),
+    (0x140F, "\u{394}\u{B7}"),
+    (0x1410, "\u{B7}\u{1404}"),
+    (0x1411, "\u{1404}\u{B7}"),
+    (0x1412, "\u{B7}\u{1405}"),
+    (0x1413, "\u{1405}\u{B7}"),
+    (0x1414, "\u{B7}\u{1406}"),
+    (0x1415, "\u{1406}\u{B7}"),
+    (0x1417, "\u{B7}\u{140A}"),
+    (0x1418, "\u{140A}\u{B7}"),
+    (0x1419, "\u{B7}\u{140B}"),
+    (0x141A, "\u{140B}\u{B7}"),
+    (0x1427, "\u{B7}"),
+    (0x142B, "\u{1401}\u{1420}"),
+    (0x142C, "\u{394}\u{1420}"),
+    (0x142D, "\u{1405}\u{1420}"),
+    (0x142E, "\u{140A}\u{1420}"),
+    (0x142F, "\u{56}"),
+    (0x1431, "\u{245}"),
+    (0x1433, "\u{3E}"),
+    (0x1437, "\u{B7}\u{3E}"),
+    (0x1438, "\u{3C}"),
+    (0x143A, "\u{B7}\u{56}"),
+    (0x143B, "\u{56}\u{B7}"),
+    (0x143C, "\u{B7}\u{245}"),
+    (0x143D, "\u{245}\u{B7}"),
+    (0x143E, "\u{B7}\u{1432}"),
+    (0x143F, "\u{1432}\u{B7}"),
+    (0x1440, "\u{B7}\u{3E}"),
+    (0x1441, "\u{3E}\u{B7}"),
+    (0x1442, "\u{B7}\u{1434}"),
+    (0x1443, "\u{1434}\u{B7}"),
+    (0x1444, "\u{B7}\u{3C}"),
+    (0x1445, "\u{3C}\u{B7}"),
+    (0x1446, "\u{B7}\u{1439}"),
+    (0x1447, "\u{1439}\u{B7}"),
+    (0x144A, "\u{27}"),
+    (0x144C, "\u{55}"),
+    (0x144E, "\u{548}"),
+    (0x1454, "\u{B7}\u{1450}"),
+    (0x1457, "\u{B7}\u{55}"),
+    (0x1458, "\u{55}\u{B7}"),
+    (0x1459, "\u{B7}\u{548}"),
+    (0x145A, "\u{548}\u{B7}"),
+    (0x145B, "\u{B7}\u{144F}"),
+    (0x145C, "\u{144F}\u{B7}"),
+    (0x145D, "\u{B7}\u{1450}"),
+    (0x145E, "\u{1450}\u{B7}"),
+    (0x145F, "\u{B7}\u{1451}"),
+    (0x1460, "\u{1451}\u{B7}"),
+    (0x1461, "\u{B7}\u{1455}"),
+    (0x1462, "\u{1455}\u{B7}"),
+    (0x1463, "\u{B7}\u{1456}"),
+    (0x1464, "\u{1456}\u{B7}"),
+    (0x1467, "\u{55}\u{27}"),
+    (0x1468, "\u{548}\u{27}"),
+    (0x1469, "\u{1450}\u{27}"),
+    (0x146A, "\u{1455}\u{27}"),
+    (0x146D, "\u{50}"),
+    (0x146F, "\u{64}"),
+    (0x1472, "\u{62}"),
+    (0x1473, "\u{62}\u{307}"),
+    (0x1474, "\u{B7}\u{146B}"),
+    (0x1475, "\u{146B}\u{B7}"),
+    (0x1476, "\u{B7}\u{50}"),
+    (0x1477, "\u{70}\u{B7}"),
+    (0x1478, "\u{B7}\u{146E}"),
+    (0x1479, "\u{146E}\u{B7}"),
+    (0x147A, "\u{B7}\u{64}"),
+    (0x147B, "\u{64}\u{B7}"),
+    (0x147C, "\u{B7}\u{1470}"),
+    (0x147D, "\u{1470}\u{B7}"),
+    (0x147E, "\u{B7}\u{62}"),
+    (0x147F, "\u{62}\u{B7}"),
+    (0x1480, "\u{B7}\u{62}\u{307}"),
+    (0x1481, "\u{62}\u{307}\u{B7}"),
+    (0x1485, "\u{146B}\u{27}"),
+    (0x1486, "\u{50}\u{27}"),
+    (0x1487, "\u{64}\u{27}"),
+    (0x1488, "\u{62}\u{27}"),
+    (0x148D, "\u{4A}"),
+    (0x1492, "\u{B7}\u{1489}"),
+    (0x1493, "\u{1489}\u{B7}"),
+    (0x1494, "\u{B7}\u{148B}"),
+    (0x1495, "\u{148B}\u{B7}"),
+    (0x1496, "\u{B7}\u{148C}"),
+    (0x1497, "\u{148C}\u{B7}"),
+    (0x1498, "\u{B7}\u{4A}"),
+    (0x1499, "\u{4A}\u{B7}"),
+    (0x149A, "\u{B7}\u{148E}"),
+    (0x149B, "\u{148E}\u{B7}"),
+    (0x149C, "\u{B7}\u{1490}"),
+    (0x149D, "\u{1490}\u{B7}"),
+    (0x149E, "\u{B7}\u{1491}"),
+    (0x149F, "\u{1491}\u{B7}"),
+    (0x14A5, "\u{393}"),
+    (0x14AA, "\u{4C}"),
+    (0x14AC, "\u{B7}\u{14A3}"),
+    (0x14AD, "\u{14A3}\u{B7}"),
+    (0x14AE, "\u{B7}\u{393}"),
+    (0x14AF, "\u{393}\u{B7}"),
+    (0x14B0, "\u{B7}\u{14A6}"),
+    (0x14B1, "\u{14A6}\u{B7}"),
+    (0x14B2, "\u{B7}\u{14A7}"),
+    (0x14B3, "\u{14A7}\u{B7}"),
+    (0x14B4, "\u{B7}\u{14A8}"),
+    (0x14B5, "\u{14A8}\u{B7}"),
+    (0x14B6, "\u{B7}\u{4C}"),
+    (0x14B7, "\u{6C}\u{B7}"),
+    (0x14B8, "\u{B7}\u{14AB}"),
+    (0x14B9, "\u{14AB}\u{B7}"),
+    (0x14BF, "\u{32}"),
+    (0x14C9, "\u{B7}\u{14C0}"),
+    (0x14CA, "\u{14C0}\u{B7}"),
+    (0x14CB, "\u{B7}\u{14C7}"),
+    (0x14CC, "\u{14C7}\u{B7}"),
+    (0x14CD, "\u{B7}\u{14C8}"),
+    (0x14CE, "\u{14C8}\u{B7}"),
+    (0x14D1, "\u{1421}"),
+    (0x14DC, "\u{B7}\u{14D3}"),
+    (0x14DD, "\u{14D3}\u{B7}"),
+    (0x14DE, "\u{B7}\u{14D5}"),
+    (0x14DF, "\u{14D5}\u{B7}"),
+    (0x14E0, "\u{B7}\u{14D6}"),
+    (0x14E1, "\u{14D6}\u{B7}"),
+    (0x14E2, "\u{B7}\u{14D7}"),
+    (0x14E3, "\u{14D7}\u{B7}"),
+    (0x14E4, "\u{B7}\u{14D8}"),
+    (0x14E5, "\u{14D8}\u{B7}"),
+    (0x14E6, "\u{B7}\u{14DA}"),
+    (0x14E7, "\u{14DA}\u{B7}"),
+    (0x14E8, "\u{B7}\u{14DB}"),
+    (0x14E9, "\u{14DB}\u{B7}"),
+    (0x14F6, "\u{B7}\u{14ED}"),
+    (0x14F7, "\u{14ED}\u{B7}"),
+    (0x14F8, "\u{B7}\u{14EF}"),
+    (0x14F9, "\u{14EF}\u{B7}"),
+    (0x14FA, "\u{B7}\u{14F0}"),
+    (0x14FB, "\u{14F0}\u{B7}"),
+    (0x14FC, "\u{B7}\u{14F1}"),
+    (0x14FD, "\u{14F1}\u{B7}"),
+    (0x14FE, "\u{B7}\u{14F2}"),
+    (0x14FF, "\u{14F2}\u{B7}"),
+    (0x1500, "\u{B7}\u{14F4}"),
+    (0x1501, "\u{14F4}\u{B7}"),
+    (0x1502, "\u{B7}\u{14F5}"),
+    (0x1503, "\u{14F5}\u{B7}"),
+    (0x150C, "\u{150B}\u{3C}"),
+    (0x150D, "\u{150B}\u{1455}"),
+    (0x150E, "\u{150B}\u{62}"),
+    (0x150F, "\u{150B}\u{1490}"),
+    (0x1517, "\u{B7}\u{1510}"),
+    (0x1518, "\u{1510}\u{B7}"),
+    (0x1519, "\u{B7}\u{1511}"),
+    (0x151A, "\u{1511}\u{B7}"),
+    (0x151B, "\u{B7}\u{1512}"),
+    (0x151C, "\u{1512}\u{B7}"),
+    (0x151D, "\u{B7}\u{1513}"),
+    (0x151E, "\u{1513}\u{B7}"),
+    (0x151F, "\u{B7}\u{1514}"),
+    (0x1520, "\u{1514}\u{B7}"),
+    (0x1521, "\u{B7}\u{1515}"),
+    (0x1522, "\u{1515}\u{B7}"),
+    (0x1523, "\u{B7}\u{1516}"),
+    (0x1524, "\u{1516}\u{B7}"),
+    (0x152F, "\u{B7}\u{34}"),
+    (0x1530, "\u{34}\u{B7}"),
+    (0x1531, "\u{B7}\u{1528}"),
+    (0x1532, "\u{1528}\u{B7}"),
+    (0x1533, "\u{B7}\u{1529}"),
+    (0x1534, "\u{1529}\u{B7}"),
+    (0x1535, "\u{B7}\u{152A}"),
+    (0x1536, "\u{152A}\u{B7}"),
+    (0x1537, "\u{B7}\u{152B}"),
+    (0x1538, "\u{152B}\u{B7}"),
+    (0x1539, "\u{B7}\u{152D}"),
+    (0x153A, "\u{152D}\u{B7}"),
+    (0x153B, "\u{B7}\u{152E}"),
+    (0x153C, "\u{152E}\u{B7}"),
+    (0x1540, "\u{1429}"),
+    (0x1541, "\u{78}"),
+    (0x154E, "\u{B7}\u{154C}"),
+    (0x154F, "\u{154C}\u{B7}"),
+    (0x155B, "\u{B7}\u{155A}"),
+    (0x155C, "\u{155A}\u{B7}"),
+    (0x1568, "\u{B7}\u{1567}"),
+    (0x1569, "\u{1567}\u{B7}"),
+    (0x1577, "\u{1E9F}"),
+    (0x157C, "\u{48}"),
+    (0x157D, "\u{78}"),
+    (0x157E, "\u{1550}\u{146C}"),
+    (0x157F, "\u{1550}\u{50}"),
+    (0x1580, "\u{1550}\u{146E}"),
+    (0x1581, "\u{1550}\u{64}"),
+    (0x1582, "\u{1550}\u{1470}"),
+    (0x1583, "\u{1550}\u{62}"),
+    (0x1584, "\u{1550}\u{62}\u{307}"),
+    (0x1585, "\u{1550}\u{1483}"),
+    (0x1587, "\u{52}"),
+    (0x158E, "\u{1595}\u{148A}"),
+    (0x158F, "\u{1595}\u{148B}"),
+    (0x1590, "\u{1595}\u{148C}"),
+    (0x1591, "\u{1595}\u{4A}"),
+    (0x1592, "\u{1595}\u{148E}"),
+    (0x1593, "\u{1595}\u{1490}"),
+    (0x1594, "\u{1595}\u{1491}"),
+    (0x15AF, "\u{62}"),
+    (0x15B4, "\u{46}"),
+    (0x15B5, "\u{2132}"),
+    (0x15B7, "\u{A7FB}"),
+    (0x15C4, "\u{2C6F}"),
+    (0x15C5, "\u{41}"),
+    (0x15DE, "\u{44}"),
+    (0x15EA, "\u{44}"),
+    (0x15EF, "\u{460}"),
+    (0x15F0, "\u{4D}"),
+    (0x15F7, "\u{42}"),
+    (0x1602, "\u{1490}"),
+    (0x1603, "\u{1489}"),
+    (0x1604, "\u{14D3}"),
+    (0x1607, "\u{14DA}"),
+    (0x1622, "\u{1543}"),
+    (0x1623, "\u{1546}"),
+    (0x1624, "\u{154A}"),
+    (0x162E, "\u{1B1}"),
+    (0x162F, "\u{3A9}"),
+    (0x1634, "\u{1B1}"),
+    (0x1635, "\u{3A9}"),
+    (0x166D, "\u{58}"),
+    (0x166E, "\u{78}"),
+    (0x166F, "\u{1550}\u{146B}"),
+    (0x1670, "\u{1595}\u{1489}"),
+    (0x1671, "\u{1596}\u{148B}"),
+    (0x1672, "\u{1596}\u{148C}"),
+    (0x1673, "\u{1596}\u{4A}"),
+    (0x1674, "\u{1596}\u{148E}"),
+    (0x1675, "\u{1596}\u{1490}"),
+    (0x1676, "\u{1596}\u{1491}"),
+    (0x1677, "\u{15A7}\u{B7}"),
+    (0x1678, "\u{15A8}\u{B7}"),
+    (0x1679, "\u{15A9}\u{B7}"),
+    (0x167A, "\u{15AA}\u{B7}"),
+    (0x167B, "\u{15AB}\u{B7}"),
+    (0x167C, "\u{15AC}\u{B7}"),
+    (0x167D, "\u{15AD}\u{B7}"),
+    (0x1680, "\u{20}"),
+    (0x16B2, "\u{3C}"),
+    (0x16B7, "\u{58}"),
+    (0x16C1, "\u{6C}"),
+    (0x16C2, "\u{16BD}"),
+    (0x16CC, "\u{27}"),
+    (0x16D5, "\u{4B}"),
+    (0x16D6, "\u{4D}"),
+    (0x16D8, "\u{3A8}"),
+    (0x16E1, "\u{16BC}"),
+    (0x16EB, "\u{B7}"),
+    (0x16EC, "\u{3A}"),
+    (0x16ED, "\u{2B}"),
+    (0x16F0, "\u{3A6}"),
+    (0x1734, "\u{1715}"),
+    (0x1735, "\u{2F}"),
+    (0x17A3, "\u{17A2}"),
+    (0x17B7, "\u{E34}"),
+    (0x17B8, "\u{E35}"),
+    (0x17B9, "\u{E36}"),
+    (0x17BA, "\u{E37}"),
+    (0x17C6, "\u{30A}"),
+    (0x17CB, "\u{E48}"),
+    (0x17D3, "\u{30A}"),
+    (0x17D4, "\u{E2F}"),
+    (0x17D5, "\u{E5A}"),
+    (0x17D9, "\u{E4F}"),
+    (0x17DA, "\u{E5B}"),
+    (0x1803, "\u{3A}"),
+    (0x1809, "\u{3A}"),
+    (0x1855, "\u{1835}"),
+    (0x1896, "\u{185C}"),
+    (0x18B3, "\u{B7}\u{18B1}"),
+    (0x18B6, "\u{B7}\u{18B4}"),
+    (0x18B9, "\u{B7}\u{18B8}"),
+    (0x18C2, "\u{B7}\u{18C0}"),
+    (0x18C6, "\u{B7}\u{14C2}"),
+    (0x18C7, "\u{14C2}\u{B7}"),
+    (0x18C8, "\u{B7}\u{14C3}"),
+    (0x18C9, "\u{14C3}\u{B7}"),
+    (0x18CA, "\u{B7}\u{14C4}"),
+    (0x18CB, "\u{14C4}\u{B7}"),
+    (0x18CC, "\u{B7}\u{14C5}"),
+    (0x18CD, "\u{14C5}\u{B7}"),
+    (0x18CE, "\u{B7}\u{1543}"),
+    (0x18CF, "\u{B7}\u{1546}"),
+    (0x18D0, "\u{B7}\u{1547}"),
+    (0x18D1, "\u{B7}\u{1548}"),
+    (0x18D2, "\u{B7}\u{1549}"),
+    (0x18D3, "\u{B7}\u{154B}"),
+    (0x18DB, "\u{18F5}"),
+    (0x18DC, "\u{18DF}\u{141E}"),
+    (0x18DD, "\u{141E}\u{18DF}"),
+    (0x18E0, "\u{1543}\u{B7}"),
+    (0x18E3, "\u{155E}\u{B7}"),
+    (0x18E4, "\u{1566}\u{B7}"),
+    (0x18E5, "\u{156B}\u{B7}"),
+    (0x18E8, "\u{1586}\u{B7}"),
+    (0x18EA, "\u{1597}\u{B7}"),
+    (0x18ED, "\u{460}\u{B7}"),
+    (0x18F0, "\u{15F4}\u{B7}"),
+    (0x18F2, "\u{161B}\u{B7}"),
+    (0x19D0, "\u{199E}"),
+    (0x19D1, "\u{19B1}"),
+    (0x1A80, "\u{1A45}"),
+    (0x1A90, "\u{1A45}"),
+    (0x1AA9, "\u{1AA8}\u{1AA8}"),
+    (0x1AAB, "\u{1AAA}\u{1AA8}"),
+    (0x1AB4, "\u{6DB}"),
+    (0x1AB7, "\u{328}"),
+    (0x1B52, "\u{1B0D}"),
+    (0x1B53, "\u{1B11}"),
+    (0x1B58, "\u{1B28}"),
+    (0x1B5C, "\u{1B50}"),
+    (0x1B5F, "\u{1B5E}\u{1B5E}"),
+    (0x1C3C, "\u{1C3B}\u{1C3B}"),
+    (0x1C7F, "\u{1C7E}\u{1C7E}"),
+    (0x1CD0, "\u{302}"),
+    (0x1CD2, "\u{304}"),
+    (0x1CD3, "\u{27}\u{27}"),
+    (0x1CD5, "\u{32B}"),
+    (0x1CD8, "\u{32E}"),
+    (0x1CD9, "\u{32D}"),
+    (0x1CDA, "\u{30E}"),
+    (0x1CDC, "\u{329}"),
+    (0x1CDD, "\u{323}"),
+    (0x1CDE, "\u{324}"),
+    (0x1CED, "\u{316}"),
+    (0x1D04, "\u{63}"),
+    (0x1D08, "\u{25C}"),
+    (0x1D0B, "\u{138}"),
+    (0x1D0D, "\u{28D}"),
+    (0x1D0F, "\u{6F}"),
+    (0x1D10, "\u{254}"),
+    (0x1D11, "\u{6F}"),
+    (0x1D14, "\u{1DD}\u{6F}"),
+    (0x1D1C, "\u{75}"),
+    (0x1D20, "\u{76}"),
+    (0x1D21, "\u{77}"),
+    (0x1D22, "\u{7A}"),
+    (0x1D24, "\u{1A8}"),
+    (0x1D26, "\u{72}"),
+    (0x1D27, "\u{28C}"),
+    (0x1D28, "\u{3C0}"),
+    (0x1D29, "\u{1D18}"),
+    (0x1D2B, "\u{43B}"),
+    (0x1D3E, "\u{18D6}"),
+    (0x1D52, "\u{BA}"),
+    (0x1D6B, "\u{75}\u{65}"),
+    (0x1D6E, "\u{66}\u{334}"),
+    (0x1D6F, "\u{72}\u{6E}\u{334}"),
+    (0x1D70, "\u{6E}\u{334}"),
+    (0x1D72, "\u{72}\u{334}"),
+    (0x1D73, "\u{27E}\u{334}"),
+    (0x1D74, "\u{73}\u{334}"),
+    (0x1D75, "\u{74}\u{334}"),
+    (0x1D76, "\u{7A}\u{334}"),
+    (0x1D78, "\u{1D34}"),
+    (0x1D7B, "\u{69}\u{335}"),
+    (0x1D7C, "\u{69}\u{335}"),
+    (0x1D7D, "\u{70}\u{335}"),
+    (0x1D7E, "\u{75}\u{335}"),
+    (0x1D7F, "\u{28A}\u{335}"),
+    (0x1D83, "\u{67}"),
+    (0x1D8C, "\u{79}"),
+    (0x1D90, "\u{24B}"),
+    (0x1D9F, "\u{1D4B}"),
+    (0x1DA2, "\u{1D4D}"),
+    (0x1DBA, "\u{18D4}"),
+    (0x1DBB, "\u{1646}"),
+    (0x1DEE, "\u{2DEC}"),
+    (0x1E43, "\u{AB51}"),
+    (0x1E9A, "\u{1EA3}"),
+    (0x1E9D, "\u{66}"),
+    (0x1E9E, "\u{DF}"),
+    (0x1EFF, "\u{79}"),
+    (0x1F7D, "\u{1FF4}"),
+    (0x1FBD, "\u{27}"),
+    (0x1FBE, "\u{69}"),
+    (0x1FBF, "\u{27}"),
+    (0x1FC0, "\u{7E}"),
+    (0x1FEF, "\u{27}"),
+    (0x1FF6, "\u{13EF}"),
+    (0x1FFD, "\u{27}"),
+    (0x1FFE, "\u{27}"),
+    (0x2000, "\u{20}"),
+    (0x2001, "\u{20}"),
+    (0x2002, "\u{20}"),
+    (0x2003, "\u{20}"),
+    (0x2004, "\u{20}"),
+    (0x2005, "\u{20}"),
+    (0x2006, "\u{20}"),
+    (0x2007, "\u{20}"),
+    (0x2008, "\u{20}"),
+    (0x2009, "\u{20}"),
+    (0x200A, "\u{20}"),
+    (0x2010, "\u{2D}"),
+    (0x2011, "\u{2D}"),
+    (0x2012, "\u{2D}"),
+    (0x2013, "\u{2D}"),
+    (0x2014, "\u{30FC}"),
+    (0x2015, "\u{30FC}"),
+    (0x2016, "\u{6C}\u{6C}"),
+    (0x2018, "\u{27}"),
+    (0x2019, "\u{27}"),
+    (0x201A, "\u{2C}"),
+    (0x201B, "\u{27}"),
+    (0x201C, "\u{27}\u{27}"),
+    (0x201D, "\u{27}\u{27}"),
+    (0x201F, "\u{27}\u{27}"),
+    (0x2022, "\u{B7}"),
+    (0x2024, "\u{2E}"),
+    (0x2025, "\u{2E}\u{2E}"),
+    (0x2026, "\u{2E}\u{2E}\u{2E}"),
+    (0x2027, "\u{B7}"),
+    (0x2028, "\u{20}"),
+    (0x2029, "\u{20}"),
+    (0x202F, "\u{20}"),
+    (0x2030, "\u{BA}\u{2F}\u{2080}\u{2080}"),
+    (0x2031, "\u{BA}\u{2F}\u{2080}\u{2080}\u{2080}"),
+    (0x2032, "\u{27}"),
+    (0x2033, "\u{27}\u{27}"),
+    (0x2034, "\u{27}\u{27}\u{27}"),
+    (0x2035, "\u{27}"),
+    (0x2036, "\u{27}\u{27}"),
+    (0x2037, "\u{27}\u{27}\u{27}"),
+    (0x2039, "\u{3C}"),
+    (0x203A, "\u{3E}"),
+    (0x203C, "\u{21}\u{21}"),
+    (0x203E, "\u{2C9}"),
+    (0x2041, "\u{2F}"),
+    (0x2043, "\u{2D}"),
+    (0x2044, "\u{2F}"),
+    (0x2047, "\u{3F}\u{3F}"),
+    (0x2048, "\u{3F}\u{21}"),
+    (0x2049, "\u{21}\u{3F}"),
+    (0x204E, "\u{2A}"),
+    (0x2052, "\u{BA}\u{2F}\u{2080}"),
+    (0x2053, "\u{7E}"),
+    (0x2057, "\u{27}\u{27}\u{27}\u{27}"),
+    (0x205A, "\u{3A}"),
+    (0x205D, "\u{2D57}"),
+    (0x205E, "\u{2D42}"),
+    (0x205F, "\u{20}"),
+    (0x2070, "\u{BA}"),
+    (0x2079, "\u{A770}"),
+    (0x20A1, "\u{43}\u{20EB}"),
+    (0x20A4, "\u{A3}"),
+    (0x20A5, "\u{72}\u{6E}\u{338}"),
+    (0x20A8, "\u{52}\u{73}"),
+    (0x20A9, "\u{57}\u{335}"),
+    (0x20AB, "\u{64}\u{335}\u{331}"),
+    (0x20AC, "\u{A792}"),
+    (0x20AD, "\u{4B}\u{335}"),
+    (0x20AE, "\u{54}\u{20EB}"),
+    (0x20B6, "\u{6C}\u{74}"),
+    (0x20BD, "\u{554}"),
+    (0x20DB, "\u{6DB}"),
+    (0x2100, "\u{61}\u{2F}\u{63}"),
+    (0x2101, "\u{61}\u{2F}\u{73}"),
+    (0x2102, "\u{43}"),
+    (0x2103, "\u{B0}\u{43}"),
+    (0x2105, "\u{63}\u{2F}\u{6F}"),
+    (0x2106, "\u{63}\u{2F}\u{75}"),
+    (0x2107, "\u{190}"),
+    (0x2108, "\u{42D}"),
+    (0x2109, "\u{B0}\u{46}"),
+    (0x210A, "\u{67}"),
+    (0x210B, "\u{48}"),
+    (0x210C, "\u{48}"),
+    (0x210D, "\u{48}"),
+    (0x210E, "\u{68}"),
+    (0x210F, "\u{68}\u{335}"),
+    (0x2110, "\u{6C}"),
+    (0x2111, "\u{6C}"),
+    (0x2112, "\u{4C}"),
+    (0x2113, "\u{6C}"),
+    (0x2115, "\u{4E}"),
+    (0x2116, "\u{4E}\u{6F}"),
+    (0x2119, "\u{50}"),
+    (0x211A, "\u{51}"),
+    (0x211B, "\u{52}"),
+    (0x211C, "\u{52}"),
+    (0x211D, "\u{52}"),
+    (0x2121, "\u{54}\u{45}\u{4C}"),
+    (0x2124, "\u{5A}"),
+    (0x2126, "\u{3A9}"),
+    (0x2127, "\u{1B1}"),
+    (0x2128, "\u{5A}"),
+    (0x2129, "\u{27F}"),
+    (0x212A, "\u{4B}"),
+    (0x212C, "\u{42}"),
+    (0x212D, "\u{43}"),
+    (0x212E, "\u{65}"),
+    (0x212F, "\u{65}"),
+    (0x2130, "\u{45}"),
+    (0x2131, "\u{46}"),
+    (0x2133, "\u{4D}"),
+    (0x2134, "\u{6F}"),
+    (0x2135, "\u{5D0}"),
+    (0x2136, "\u{5D1}"),
+    (0x2137, "\u{5D2}"),
+    (0x2138, "\u{5D3}"),
+    (0x2139, "\u{69}"),
+    (0x213B, "\u{46}\u{41}\u{58}"),
+    (0x213C, "\u{3C0}"),
+    (0x213D, "\u{79}"),
+    (0x213E, "\u{393}"),
+    (0x213F, "\u{3A0}"),
+    (0x2140, "\u{1A9}"),
+    (0x2141, "\u{A4E8}"),
+    (0x2142, "\u{A4F6}"),
+    (0x2143, "\u{16F00}"),
+    (0x2145, "\u{44}"),
+    (0x2146, "\u{64}"),
+    (0x2147, "\u{65}"),
+    (0x2148, "\u{69}"),
+    (0x2149, "\u{6A}"),
+    (0x2160, "\u{6C}"),
+    (0x2161, "\u{6C}\u{6C}"),
+    (0x2162, "\u{6C}\u{6C}\u{6C}"),
+    (0x2163, "\u{6C}\u{56}"),
+    (0x2164, "\u{56}"),
+    (0x2165, "\u{56}\u{6C}"),
+    (0x2166, "\u{56}\u{6C}\u{6C}"),
+    (0x2167, "\u{56}\u{6C}\u{6C}\u{6C}"),
+    (0x2168, "\u{6C}\u{58}"),
+    (0x2169, "\u{58}"),
+    (0x216A, "\u{58}\u{6C}"),
+    (0x216B, "\u{58}\u{6C}\u{6C}"),
+    (0x216C, "\u{4C}"),
+    (0x216D, "\u{43}"),
+    (0x216E, "\u{44}"),
+    (0x216F, "\u{4D}"),
+    (0x2170, "\u{69}"),
+    (0x2171, "\u{69}\u{69}"),
+    (0x2172, "\u{69}\u{69}\u{69}"),
+    (0x2173, "\u{69}\u{76}"),
+    (0x2174, "\u{76}"),
+    (0x2175, "\u{76}\u{69}"),
+    (0x2176, "\u{76}\u{69}\u{69}"),
+    (0x2177, "\u{76}\u{69}\u{69}\u{69}"),
+    (0x2178, "\u{69}\u{78}"),
+    (0x2179, "\u{78}"),
+    (0x217A, "\u{78}\u{69}"),
+    (0x217B, "\u{78}\u{69}\u{69}"),
+    (0x217C, "\u{6C}"),
+    (0x217D, "\u{63}"),
+    (0x217E, "\u{64}"),
+    (0x217F, "\u{72}\u{6E}"),
+    (0x2183, "\u{186}"),
+    (0x2184, "\u{254}"),
+    (0x2191, "\u{16CF}"),
+    (0x2195, "\u{16E8}"),
+    (0x21B5, "\u{21B2}"),
+    (0x21BA, "\u{1F10E}"),
+    (0x21BE, "\u{16DA}"),
+    (0x21BF, "\u{16D0}"),
+    (0x2200, "\u{2C6F}"),
+    (0x2203, "\u{18E}"),
+    (0x2206, "\u{394}"),
+    (0x220F, "\u{3A0}"),
+    (0x2211, "\u{1A9}"),
+    (0x2212, "\u{2D}"),
+    (0x2214, "\u{2B}\u{307}"),
+    (0x2215, "\u{2F}"),
+    (0x2216, "\u{5C}"),
+    (0x2217, "\u{2A}"),
+    (0x2218, "\u{B0}"),
+    (0x2219, "\u{B7}"),
+    (0x221E, "\u{6F}\u{6F}"),
+    (0x2223, "\u{6C}"),
+    (0x2225, "\u{6C}\u{6C}"),
+    (0x2228, "\u{76}"),
+    (0x2229, "\u{548}"),
+    (0x222A, "\u{55}"),
+    (0x222B, "\u{283}"),
+    (0x222C, "\u{283}\u{283}"),
+    (0x222D, "\u{283}\u{283}\u{283}"),
+    (0x222F, "\u{222E}\u{222E}"),
+    (0x2230, "\u{222E}\u{222E}\u{222E}"),
+    (0x2236, "\u{3A}"),
+    (0x2238, "\u{2D}\u{307}"),
+    (0x223C, "\u{7E}"),
+    (0x2250, "\u{3D}\u{307}"),
+    (0x2251, "\u{3D}\u{307}\u{323}"),
+    (0x2257, "\u{3D}\u{30A}"),
+    (0x2259, "\u{3D}\u{302}"),
+    (0x225A, "\u{3D}\u{306}"),
+    (0x225E, "\u{3D}\u{36B}"),
+    (0x2263, "\u{2261}"),
+    (0x226A, "\u{3C}\u{3C}"),
+    (0x226B, "\u{3E}\u{3E}"),
+    (0x2282, "\u{1455}"),
+    (0x2283, "\u{1450}"),
+    (0x2295, "\u{102A8}"),
+    (0x2296, "\u{4F}\u{335}"),
+    (0x2299, "\u{298}"),
+    (0x229D, "\u{4F}\u{335}"),
+    (0x22A4, "\u{54}"),
+    (0x22A5, "\u{A4D5}"),
+    (0x22C0, "\u{2227}"),
+    (0x22C1, "\u{76}"),
+    (0x22C2, "\u{548}"),
+    (0x22C3, "\u{55}"),
+    (0x22C4, "\u{16DC}"),
+    (0x22C5, "\u{B7}"),
+    (0x22C8, "\u{16DE}"),
+    (0x22D6, "\u{3C}\u{B7}"),
+    (0x22D7, "\u{B7}\u{3E}"),
+    (0x22D8, "\u{3C}\u{3C}\u{3C}"),
+    (0x22D9, "\u{3E}\u{3E}\u{3E}"),
+    (0x22EE, "\u{2D57}"),
+    (0x22EF, "\u{B7}\u{B7}\u{B7}"),
+    (0x22F4, "\u{A793}"),
+    (0x22FF, "\u{45}"),
+    (0x2300, "\u{2205}"),
+    (0x2325, "\u{2324}"),
+    (0x2329, "\u{276C}"),
+    (0x232A, "\u{276D}"),
+    (0x2341, "\u{303C}"),
+    (0x2359, "\u{394}\u{332}"),
+    (0x235A, "\u{16DC}\u{332}"),
+    (0x235C, "\u{B0}\u{332}"),
+    (0x235F, "\u{229B}"),
+    (0x2361, "\u{54}\u{308}"),
+    (0x2362, "\u{2207}\u{308}"),
+    (0x2363, "\u{22C6}\u{308}"),
+    (0x2364, "\u{B0}\u{308}"),
+    (0x2365, "\u{629}"),
+    (0x2368, "\u{7E}\u{308}"),
+    (0x2369, "\u{1435}"),
+    (0x236B, "\u{2207}\u{334}"),
+    (0x236C, "\u{4F}\u{335}"),
+    (0x2373, "\u{69}"),
+    (0x2374, "\u{70}"),
+    (0x2375, "\u{3C9}"),
+    (0x2376, "\u{61}\u{332}"),
+    (0x2377, "\u{A793}\u{332}"),
+    (0x2378, "\u{69}\u{332}"),
+    (0x2379, "\u{3C9}\u{332}"),
+    (0x237A, "\u{61}"),
+    (0x237F, "\u{16BD}"),
+    (0x239C, "\u{4E28}"),
+    (0x239F, "\u{4E28}"),
+    (0x23A2, "\u{4E28}"),
+    (0x23A5, "\u{4E28}"),
+    (0x23AA, "\u{4E28}"),
+    (0x23AE, "\u{4E28}"),
+    (0x23C1, "\u{2355}"),
+    (0x23C2, "\u{234E}"),
+    (0x23C3, "\u{234B}"),
+    (0x23C6, "\u{236D}"),
+    (0x23E8, "\u{2081}\u{2080}"),
+    (0x23FC, "\u{23FB}"),
+    (0x23FD, "\u{6C}"),
+    (0x23FE, "\u{263E}"),
+    (0x244A, "\u{5C}\u{5C}"),
+    (0x2460, "\u{2780}"),
+    (0x2461, "\u{2781}"),
+    (0x2462, "\u{2782}"),
+    (0x2463, "\u{2783}"),
+    (0x2464, "\u{2784}"),
+    (0x2465, "\u{2785}"),
+    (0x2466, "\u{2786}"),
+    (0x2467, "\u{2787}"),
+    (0x2468, "\u{2788}"),
+    (0x2469, "\u{2789}"),
+    (0x2474, "\u{28}\u{6C}\u{29}"),
+    (0x2475, "\u{28}\u{32}\u{29}"),
+    (0x2476, "\u{28}\u{33}\u{29}"),
+    (0x2477, "\u{28}\u{34}\u{29}"),
+    (0x2478, "\u{28}\u{35}\u{29}"),
+    (0x2479, "\u{28}\u{36}\u{29}"),
+    (0x247A, "\u{28}\u{37}\u{29}"),
+    (0x247B, "\u{28}\u{38}\u{29}"),
+    (0x247C, "\u{28}\u{39}\u{29}"),
+    (0x247D, "\u{28}\u{6C}\u{4F}\u{29}"),
+    (0x247E, "\u{28}\u{6C}\u{6C}\u{29}"),
+    (0x247F, "\u{28}\u{6C}\u{32}\u{29}"),
+    (0x2480, "\u{28}\u{6C}\u{33}\u{29}"),
+    (0x2481, "\u{28}\u{6C}\u{34}\u{29}"),
+    (0x2482, "\u{28}\u{6C}\u{35}\u{29}"),
+    (0x2483, "\u{28}\u{6C}\u{36}\u{29}"),
+    (0x2484, "\u{28}\u{6C}\u{37}\u{29}"),
+    (0x2485, "\u{28}\u{6C}\u{38}\u{29}"),
+    (0x2486, "\u{28}\u{6C}\u{39}\u{29}"),
+    (0x2487, "\u{28}\u{32}\u{4F}\u{29}"),
+    (0x2488, "\u{6C}\u{2E}"),
+    (0x2489, "\u{32}\u{2E}"),
+    (0x248A, "\u{33}\u{2E}"),
+    (0x248B, "\u{34}\u{2E}"),
+    (0x248C, "\u{35}\u{2E}"),
+    (0x248D, "\u{36}\u{2E}"),
+    (0x248E, "\u{37}\u{2E}"),
+    (0x248F, "\u{38}\u{2E}"),
+    (0x2490, "\u{39}\u{2E}"),
+    (0x2491, "\u{6C}\u{4F}\u{2E}"),
+    (0x2492, "\u{6C}\u{6C}\u{2E}"),
+    (0x2493, "\u{6C}\u{32}\u{2E}"),
+    (0x2494, "\u{6C}\u{33}\u{2E}"),
+    (0x2495, "\u{6C}\u{34}\u{2E}"),
+    (0x2496, "\u{6C}\u{35}\u{2E}"),
+    (0x2497, "\u{6C}\u{36}\u{2E}"),
+    (0x2498, "\u{6C}\u{37}\u{2E}"),
+    (0x2499, "\u{6C}\u{38}\u{2E}"),
+    (0x249A, "\u{6C}\u{39}\u{2E}"),
+    (0x249B, "\u{32}\u{4F}\u{2E}"),
+    (0x249C, "\u{28}\u{61}\u{29}"),
+    (0x249D, "\u{28}\u{62}\u{29}"),
+    (0x249E, "\u{28}\u{63}\u{29}"),
+    (0x249F, "\u{28}\u{64}\u{29}"),
+    (0x24A0, "\u{28}\u{65}\u{29}"),
+    (0x24A1, "\u{28}\u{66}\u{29}"),
+    (0x24A2, "\u{28}\u{67}\u{29}"),
+    (0x24A3, "\u{28}\u{68}\u{29}"),
+    (0x24A4, "\u{28}\u{69}\u{29}"),
+    (0x24A5, "\u{28}\u{6A}\u{29}"),
+    (0x24A6, "\u{28}\u{6B}\u{29}"),
+    (0x24A7, "\u{28}\u{6C}\u{29}"),
+    (0x24A8, "\u{28}\u{72}\u{6E}\u{29}"),
+    (0x24A9, "\u{28}\u{6E}\u{29}"),
+    (0x24AA, "\u{28}\u{6F}\u{29}"),
+    (0x24AB, "\u{28}\u{70}\u{29}"),
+    (0x24AC, "\u{28}\u{71}\u{29}"),
+    (0x24AD, "\u{28}\u{72}\u{29}"),
+    (0x24AE, "\u{28}\u{73}\u{29}"),
+    (0x24AF, "\u{28}\u{74}\u{29}"),
+    (0x24B0, "\u{28}\u{75}\u{29}"),
+    (0x24B1, "\u{28}\u{76}\u{29}"),
+    (0x24B2, "\u{28}\u{77}\u{29}"),
+    (0x24B3, "\u{28}\u{78}\u{29}"),
+    (0x24B4, "\u{28}\u{79}\u{29}"),
+    (0x24B5, "\u{28}\u{7A}\u{29}"),
+    (0x24B8, "\u{A9}"),
+    (0x24C5, "\u{2117}"),
+    (0x24C7, "\u{AE}"),
+    (0x24DB, "\u{24BE}"),
+    (0x24EA, "\u{1F10D}"),
+    (0x2500, "\u{30FC}"),
+    (0x2501, "\u{30FC}"),
+    (0x2503, "\u{2502}"),
+    (0x250F, "\u{250C}"),
+    (0x2523, "\u{251C}"),
+    (0x2571, "\u{2F}"),
+    (0x2573, "\u{58}"),
+    (0x2588, "\u{220E}"),
+    (0x2590, "\u{258C}"),
+    (0x2594, "\u{2C9}"),
+    (0x2597, "\u{2596}"),
+    (0x259D, "\u{2598}"),
+    (0x25A0, "\u{220E}"),
+    (0x25B1, "\u{23E5}"),
+    (0x25B3, "\u{394}"),
+    (0x25B7, "\u{22B3}"),
+    (0x25B8, "\u{25B6}"),
+    (0x25BA, "\u{25B6}"),
+    (0x25BD, "\u{102BC}"),
+    (0x25C1, "\u{22B2}"),
+    (0x25C7, "\u{16DC}"),
+    (0x25CA, "\u{16DC}"),
+    (0x25CB, "\u{B0}"),
+    (0x25CE, "\u{233E}"),
+    (0x25E0, "\u{2312}"),
+    (0x25E6, "\u{B0}"),
+    (0x2609, "\u{298}"),
+    (0x2610, "\u{25A1}"),
+    (0x2625, "\u{1099E}"),
+    (0x2630, "\u{2CB6}"),
+    (0x2638, "\u{2388}"),
+    (0x264E, "\u{224F}"),
+    (0x2662, "\u{16DC}"),
+    (0x2669, "\u{1D158}\u{1D165}"),
+    (0x266A, "\u{1D158}\u{1D165}\u{1D16E}"),
+    (0x26AC, "\u{970}"),
+    (0x2768, "\u{28}"),
+    (0x2769, "\u{29}"),
+    (0x276E, "\u{3C}"),
+    (0x276F, "\u{3E}"),
+    (0x2772, "\u{28}"),
+    (0x2773, "\u{29}"),
+    (0x2774, "\u{7B}"),
+    (0x2775, "\u{7D}"),
+    (0x2795, "\u{2B}"),
+    (0x2796, "\u{2D}"),
+    (0x2797, "\u{F7}"),
+    (0x27C2, "\u{A4D5}"),
+    (0x27C8, "\u{5C}\u{1455}"),
+    (0x27C9, "\u{1450}\u{2F}"),
+    (0x27CB, "\u{2F}"),
+    (0x27CD, "\u{5C}"),
+    (0x27D9, "\u{54}"),
+    (0x27E8, "\u{276C}"),
+    (0x27E9, "\u{276D}"),
+    (0x292B, "\u{78}"),
+    (0x292C, "\u{78}"),
+    (0x2963, "\u{16D0}\u{16DA}"),
+    (0x2965, "\u{21C3}\u{21C2}"),
+    (0x296E, "\u{16D0}\u{21C2}"),
+    (0x296F, "\u{21C3}\u{16DA}"),
+    (0x2999, "\u{2D42}"),
+    (0x29B0, "\u{2349}"),
+    (0x29BE, "\u{233E}"),
+    (0x29C4, "\u{303C}"),
+    (0x29C5, "\u{2342}"),
+    (0x29C7, "\u{233B}"),
+    (0x29D6, "\u{102C0}"),
+    (0x29D9, "\u{299A}"),
+    (0x29F4, "\u{3A}\u{2192}"),
+    (0x29F5, "\u{5C}"),
+    (0x29F6, "\u{2F}\u{304}"),
+    (0x29F8, "\u{2F}"),
+    (0x29F9, "\u{5C}"),
+    (0x2A00, "\u{298}"),
+    (0x2A01, "\u{102A8}"),
+    (0x2A02, "\u{2297}"),
+    (0x2A03, "\u{228D}"),
+    (0x2A04, "\u{228E}"),
+    (0x2A05, "\u{2293}"),
+    (0x2A06, "\u{2294}"),
+    (0x2A0C, "\u{283}\u{283}\u{283}\u{283}"),
+    (0x2A1D, "\u{16DE}"),
+    (0x2A20, "\u{3E}\u{3E}"),
+    (0x2A21, "\u{16DA}"),
+    (0x2A22, "\u{2B}\u{30A}"),
+    (0x2A23, "\u{2B}\u{302}"),
+    (0x2A24, "\u{2B}\u{303}"),
+    (0x2A25, "\u{2B}\u{323}"),
+    (0x2A26, "\u{2B}\u{330}"),
+    (0x2A27, "\u{2B}\u{2082}"),
+    (0x2A29, "\u{2D}\u{313}"),
+    (0x2A2A, "\u{2D}\u{323}"),
+    (0x2A2F, "\u{78}"),
+    (0x2A30, "\u{78}\u{307}"),
+    (0x2A3D, "\u{2319}"),
+    (0x2A3E, "\u{2A1F}"),
+    (0x2A3F, "\u{2210}"),
+    (0x2A6A, "\u{7E}\u{307}"),
+    (0x2A6E, "\u{3D}\u{20F0}"),
+    (0x2A74, "\u{3A}\u{3A}\u{3D}"),
+    (0x2A75, "\u{3D}\u{3D}"),
+    (0x2A76, "\u{3D}\u{3D}\u{3D}"),
+    (0x2AA5, "\u{3E}\u{3C}"),
+    (0x2AAA, "\u{15D5}"),
+    (0x2AAB, "\u{15D2}"),
+    (0x2AD7, "\u{1450}\u{1455}"),
+    (0x2AFB, "\u{2F}\u{2F}\u{2F}"),
+    (0x2AFD, "\u{2F}\u{2F}"),
+    (0x2BEC, "\u{219E}"),
+    (0x2BED, "\u{219F}"),
+    (0x2BEE, "\u{21A0}"),
+    (0x2BEF, "\u{21A1}"),
+    (0x2C67, "\u{48}\u{329}"),
+    (0x2C69, "\u{4B}\u{329}"),
+    (0x2C84, "\u{393}"),
+    (0x2C85, "\u{72}"),
+    (0x2C86, "\u{394}"),
+    (0x2C88, "\u{A792}"),
+    (0x2C89, "\u{A793}"),
+    (0x2C8E, "\u{48}"),
+    (0x2C92, "\u{6C}"),
+    (0x2C94, "\u{4B}"),
+    (0x2C95, "\u{138}"),
+    (0x2C96, "\u{3BB}"),
+    (0x2C98, "\u{4D}"),
+    (0x2C9A, "\u{4E}"),
+    (0x2C9E, "\u{4F}"),
+    (0x2C9F, "\u{6F}"),
+    (0x2CA0, "\u{3A0}"),
+    (0x2CA2, "\u{50}"),
+    (0x2CA3, "\u{70}"),
+    (0x2CA4, "\u{43}"),
+    (0x2CA5, "\u{63}"),
+    (0x2CA6, "\u{54}"),
+    (0x2CA8, "\u{59}"),
+    (0x2CAA, "\u{3A6}"),
+    (0x2CAB, "\u{278}"),
+    (0x2CAC, "\u{58}"),
+    (0x2CAD, "\u{3C7}"),
+    (0x2CAE, "\u{3A8}"),
+    (0x2CB1, "\u{3C9}"),
+    (0x2CB4, "\u{3C}\u{B7}"),
+    (0x2CBA, "\u{2D}"),
+    (0x2CBC, "\u{428}"),
+    (0x2CBD, "\u{448}"),
+    (0x2CC6, "\u{2F}"),
+    (0x2CCA, "\u{39}"),
+    (0x2CCC, "\u{33}"),
+    (0x2CCD, "\u{21D}"),
+    (0x2CD0, "\u{4C}"),
+    (0x2CD1, "\u{29F}"),
+    (0x2CD2, "\u{36}"),
+    (0x2CDC, "\u{3EC}"),
+    (0x2CE4, "\u{3D7}"),
+    (0x2CE9, "\u{2627}"),
+    (0x2CF9, "\u{5C}\u{5C}"),
+    (0x2D31, "\u{4F}\u{335}"),
+    (0x2D37, "\u{245}"),
+    (0x2D38, "\u{56}"),
+    (0x2D39, "\u{45}"),
+    (0x2D3A, "\u{18E}"),
+    (0x2D41, "\u{4F}\u{338}"),
+    (0x2D48, "\u{B7}\u{B7}\u{B7}"),
+    (0x2D49, "\u{1A9}"),
+    (0x2D4F, "\u{6C}"),
+    (0x2D51, "\u{21}"),
+    (0x2D54, "\u{4F}"),
+    (0x2D55, "\u{51}"),
+    (0x2D59, "\u{298}"),
+    (0x2D5D, "\u{58}"),
+    (0x2D60, "\u{394}"),
+    (0x2D63, "\u{16EF}"),
+    (0x2DE8, "\u{1DDF}"),
+    (0x2DEA, "\u{30A}"),
+    (0x2DED, "\u{368}"),
+    (0x2DEF, "\u{36F}"),
+    (0x2DF6, "\u{363}"),
+    (0x2DF7, "\u{364}"),
+    (0x2E1A, "\u{2D}\u{308}"),
+    (0x2E1E, "\u{7E}\u{307}"),
+    (0x2E1F, "\u{7E}\u{323}"),
+    (0x2E26, "\u{1455}"),
+    (0x2E27, "\u{1450}"),
+    (0x2E28, "\u{28}\u{28}"),
+    (0x2E29, "\u{29}\u{29}"),
+    (0x2E2A, "\u{2235}"),
+    (0x2E2B, "\u{2234}"),
+    (0x2E2C, "\u{2237}"),
+    (0x2E2E, "\u{61F}"),
+    (0x2E30, "\u{B0}"),
+    (0x2E31, "\u{B7}"),
+    (0x2E32, "\u{60C}"),
+    (0x2E35, "\u{61B}"),
+    (0x2E39, "\u{1E9F}"),
+    (0x2E3D, "\u{2D42}"),
+    (0x2E3F, "\u{B6}"),
+    (0x2E40, "\u{3D}"),
+    (0x2E82, "\u{4E5B}"),
+    (0x2E83, "\u{4E5A}"),
+    (0x2E85, "\u{4EBB}"),
+    (0x2E89, "\u{5202}"),
+    (0x2E8B, "\u{353E}"),
+    (0x2E8E, "\u{5140}"),
+    (0x2E8F, "\u{5C23}"),
+    (0x2E90, "\u{5C22}"),
+    (0x2E92, "\u{5DF3}"),
+    (0x2E93, "\u{5E7A}"),
+    (0x2E94, "\u{5F51}"),
+    (0x2E96, "\u{5FC4}"),
+    (0x2E97, "\u{38FA}"),
+    (0x2E98, "\u{624C}"),
+    (0x2E99, "\u{6535}"),
+    (0x2E9B, "\u{65E1}"),
+    (0x2E9E, "\u{6B7A}"),
+    (0x2E9F, "\u{6BCD}"),
+    (0x2EA0, "\u{6C11}"),
+    (0x2EA1, "\u{6C35}"),
+    (0x2EA2, "\u{6C3A}"),
+    (0x2EA3, "\u{706C}"),
+    (0x2EA4, "\u{722B}"),
+    (0x2EA6, "\u{4E2C}"),
+    (0x2EA8, "\u{72AD}"),
+    (0x2EAB, "\u{7F52}"),
+    (0x2EAD, "\u{793B}"),
+    (0x2EAF, "\u{7CF9}"),
+    (0x2EB1, "\u{7F53}"),
+    (0x2EB2, "\u{7F52}"),
+    (0x2EB9, "\u{8002}"),
+    (0x2EBA, "\u{8080}"),
+    (0x2EBE, "\u{8279}"),
+    (0x2EBF, "\u{8279}"),
+    (0x2EC0, "\u{8279}"),
+    (0x2EC1, "\u{864E}"),
+    (0x2EC2, "\u{8864}"),
+    (0x2EC3, "\u{8980}"),
+    (0x2EC4, "\u{897F}"),
+    (0x2EC5, "\u{89C1}"),
+    (0x2EC8, "\u{8BA0}"),
+    (0x2EC9, "\u{8D1D}"),
+    (0x2ECB, "\u{8F66}"),
+    (0x2ECC, "\u{8FB6}"),
+    (0x2ECD, "\u{8FB6}"),
+    (0x2ECF, "\u{961D}"),
+    (0x2ED0, "\u{9485}"),
+    (0x2ED1, "\u{9577}"),
+    (0x2ED2, "\u{9578}"),
+    (0x2ED3, "\u{957F}"),
+    (0x2ED4, "\u{95E8}"),
+    (0x2ED6, "\u{961D}"),
+    (0x2ED8, "\u{9752}"),
+    (0x2ED9, "\u{97E6}"),
+    (0x2EDA, "\u{9875}"),
+    (0x2EDB, "\u{98CE}"),
+    (0x2EDC, "\u{98DE}"),
+    (0x2EDD, "\u{98DF}"),
+    (0x2EDF, "\u{98E0}"),
+    (0x2EE0, "\u{9963}"),
+    (0x2EE2, "\u{9A6C}"),
+    (0x2EE4, "\u{9B3C}"),
+    (0x2EE5, "\u{9C7C}"),
+    (0x2EE8, "\u{9EA6}"),
+    (0x2EE9, "\u{9EC4}"),
+    (0x2EEB, "\u{6589}"),
+    (0x2EEC, "\u{9F50}"),
+    (0x2EED, "\u{6B6F}"),
+    (0x2EEE, "\u{9F7F}"),
+    (0x2EEF, "\u{7ADC}"),
+    (0x2EF0, "\u{9F99}"),
+    (0x2EF2, "\u{4E80}"),
+    (0x2EF3, "\u{9F9F}"),
+    (0x2F00, "\u{30FC}"),
+    (0x2F01, "\u{4E28}"),
+    (0x2F02, "\u{5C}"),
+    (0x2F03, "\u{2F}"),
+    (0x2F04, "\u{4E59}"),
+    (0x2F05, "\u{4E85}"),
+    (0x2F06, "\u{4E8C}"),
+    (0x2F07, "\u{4EA0}"),
+    (0x2F08, "\u{4EBA}"),
+    (0x2F09, "\u{513F}"),
+    (0x2F0A, "\u{5165}"),
+    (0x2F0B, "\u{516B}"),
+    (0x2F0C, "\u{5182}"),
+    (0x2F0D, "\u{5196}"),
+    (0x2F0E, "\u{51AB}"),
+    (0x2F0F, "\u{51E0}"),
+    (0x2F10, "\u{51F5}"),
+    (0x2F11, "\u{5200}"),
+    (0x2F12, "\u{529B}"),
+    (0x2F13, "\u{52F9}"),
+    (0x2F14, "\u{5315}"),
+    (0x2F15, "\u{531A}"),
+    (0x2F16, "\u{5338}"),
+    (0x2F17, "\u{5341}"),
+    (0x2F18, "\u{535C}"),
+    (0x2F19, "\u{5369}"),
+    (0x2F1A, "\u{5382}"),
+    (0x2F1B, "\u{53B6}"),
+    (0x2F1C, "\u{53C8}"),
+    (0x2F1D, "\u{53E3}"),
+    (0x2F1E, "\u{53E3}"),
+    (0x2F1F, "\u{571F}"),
+    (0x2F20, "\u{571F}"),
+    (0x2F21, "\u{5902}"),
+    (0x2F22, "\u{590A}"),
+    (0x2F23, "\u{5915}"),
+    (0x2F24, "\u{5927}"),
+    (0x2F25, "\u{5973}"),
+    (0x2F26, "\u{5B50}"),
+    (0x2F27, "\u{5B80}"),
+    (0x2F28, "\u{5BF8}"),
+    (0x2F29, "\u{5C0F}"),
+    (0x2F2A, "\u{5C22}"),
+    (0x2F2B, "\u{5C38}"),
+    (0x2F2C, "\u{5C6E}"),
+    (0x2F2D, "\u{5C71}"),
+    (0x2F2E, "\u{5DDB}"),
+    (0x2F2F, "\u{5DE5}"),
+    (0x2F30, "\u{5DF1}"),
+    (0x2F31, "\u{5DFE}"),
+    (0x2F32, "\u{5E72}"),
+    (0x2F33, "\u{5E7A}"),
+    (0x2F34, "\u{5E7F}"),
+    (0x2F35, "\u{5EF4}"),
+    (0x2F36, "\u{5EFE}"),
+    (0x2F37, "\u{5F0B}"),
+    (0x2F38, "\u{5F13}"),
+    (0x2F39, "\u{5F50}"),
+    (0x2F3A, "\u{5F61}"),
+    (0x2F3B, "\u{5F73}"),
+    (0x2F3C, "\u{5FC3}"),
+    (0x2F3D, "\u{6208}"),
+    (0x2F3E, "\u{6236}"),
+    (0x2F3F, "\u{624B}"),
+    (0x2F40, "\u{652F}"),
+    (0x2F41, "\u{6534}"),
+    (0x2F42, "\u{6587}"),
+    (0x2F43, "\u{6597}"),
+    (0x2F44, "\u{65A4}"),
+    (0x2F45, "\u{65B9}"),
+    (0x2F46, "\u{65E0}"),
+    (0x2F47, "\u{65E5}"),
+    (0x2F48, "\u{66F0}"),
+    (0x2F49, "\u{6708}"),
+    (0x2F4A, "\u{6728}"),
+    (0x2F4B, "\u{6B20}"),
+    (0x2F4C, "\u{6B62}"),
+    (0x2F4D, "\u{6B79}"),
+    (0x2F4E, "\u{6BB3}"),
+    (0x2F4F, "\u{6BCB}"),
+    (0x2F50, "\u{6BD4}"),
+    (0x2F51, "\u{6BDB}"),
+    (0x2F52, "\u{6C0F}"),
+    (0x2F53, "\u{6C14}"),
+    (0x2F54, "\u{6C34}"),
+    (0x2F55, "\u{706B}"),
+    (0x2F56, "\u{722A}"),
+    (0x2F57, "\u{7236}"),
+    (0x2F58, "\u{723B}"),
+    (0x2F59, "\u{723F}"),
+    (0x2F5A, "\u{7247}"),
+    (0x2F5B, "\u{7259}"),
+    (0x2F5C, "\u{725B}"),
+    (0x2F5D, "\u{72AC}"),
+    (0x2F5E, "\u{7384}"),
+    (0x2F5F, "\u{7389}"),
+    (0x2F60, "\u{74DC}"),
+    (0x2F61, "\u{74E6}"),
+    (0x2F62, "\u{7518}"),
+    (0x2F63, "\u{751F}"),
+    (0x2F64, "\u{7528}"),
+    (0x2F65, "\u{7530}"),
+    (0x2F66, "\u{758B}"),
+    (0x2F67, "\u{7592}"),
+    (0x2F68, "\u{7676}"),
+    (0x2F69, "\u{767D}"),
+    (0x2F6A, "\u{76AE}"),
+    (0x2F6B, "\u{76BF}"),
+    (0x2F6C, "\u{76EE}"),
+    (0x2F6D, "\u{77DB}"),
+    (0x2F6E, "\u{77E2}"),
+    (0x2F6F, "\u{77F3}"),
+    (0x2F70, "\u{793A}"),
+    (0x2F71, "\u{79B8}"),
+    (0x2F72, "\u{79BE}"),
+    (0x2F73, "\u{7A74}"),
+    (0x2F74, "\u{7ACB}"),
+    (0x2F75, "\u{7AF9}"),
+    (0x2F76, "\u{7C73}"),
+    (0x2F77, "\u{7CF8}"),
+    (0x2F78, "\u{7F36}"),
+    (0x2F79, "\u{7F51}"),
+    (0x2F7A, "\u{7F8A}"),
+    (0x2F7B, "\u{7FBD}"),
+    (0x2F7C, "\u{8001}"),
+    (0x2F7D, "\u{800C}"),
+    (0x2F7E, "\u{8012}"),
+    (0x2F7F, "\u{8033}"),
+    (0x2F80, "\u{807F}"),
+    (0x2F81, "\u{8089}"),
+    (0x2F82, "\u{81E3}"),
+    (0x2F83, "\u{81EA}"),
+    (0x2F84, "\u{81F3}"),
+    (0x2F85, "\u{81FC}"),
+    (0x2F86, "\u{820C}"),
+    (0x2F87, "\u{821B}"),
+    (0x2F88, "\u{821F}"),
+    (0x2F89, "\u{826E}"),
+    (0x2F8A, "\u{8272}"),
+    (0x2F8B, "\u{8278}"),
+    (0x2F8C, "\u{864D}"),
+    (0x2F8D, "\u{866B}"),
+    (0x2F8E, "\u{8840}"),
+    (0x2F8F, "\u{884C}"),
+    (0x2F90, "\u{8863}"),
+    (0x2F91, "\u{897E}"),
+    (0x2F92, "\u{898B}"),
+    (0x2F93, "\u{89D2}"),
+    (0x2F94, "\u{8A00}"),
+    (0x2F95, "\u{8C37}"),
+    (0x2F96, "\u{8C46}"),
+    (0x2F97, "\u{8C55}"),
+    (0x2F98, "\u{8C78}"),
+    (0x2F99, "\u{8C9D}"),
+    (0x2F9A, "\u{8D64}"),
+    (0x2F9B, "\u{8D70}"),
+    (0x2F9C, "\u{8DB3}"),
+    (0x2F9D, "\u{8EAB}"),
+    (0x2F9E, "\u{8ECA}"),
+    (0x2F9F, "\u{8F9B}"),
+    (0x2FA0, "\u{8FB0}"),
+    (0x2FA1, "\u{8FB5}"),
+    (0x2FA2, "\u{9091}"),
+    (0x2FA3, "\u{9149}"),
+    (0x2FA4, "\u{91C6}"),
+    (0x2FA5, "\u{91CC}"),
+    (0x2FA6, "\u{91D1}"),
+    (0x2FA7, "\u{9577}"),
+    (0x2FA8, "\u{9580}"),
+    (0x2FA9, "\u{961C}"),
+    (0x2FAA, "\u{96B6}"),
+    (0x2FAB, "\u{96B9}"),
+    (0x2FAC, "\u{96E8}"),
+    (0x2FAD, "\u{9751}"),
+    (0x2FAE, "\u{975E}"),
+    (0x2FAF, "\u{9762}"),
+    (0x2FB0, "\u{9769}"),
+    (0x2FB1, "\u{97CB}"),
+    (0x2FB2, "\u{97ED}"),
+    (0x2FB3, "\u{97F3}"),
+    (0x2FB4, "\u{9801}"),
+    (0x2FB5, "\u{98A8}"),
+    (0x2FB6, "\u{98DB}"),
+    (0x2FB7, "\u{98DF}"),
+    (0x2FB8, "\u{9996}"),
+    (0x2FB9, "\u{9999}"),
+    (0x2FBA, "\u{99AC}"),
+    (0x2FBB, "\u{9AA8}"),
+    (0x2FBC, "\u{9AD8}"),
+    (0x2FBD, "\u{9ADF}"),
+    (0x2FBE, "\u{9B25}"),
+    (0x2FBF, "\u{9B2F}"),
+    (0x2FC0, "\u{9B32}"),
+    (0x2FC1, "\u{9B3C}"),
+    (0x2FC2, "\u{9B5A}"),
+    (0x2FC3, "\u{9CE5}"),
+    (0x2FC4, "\u{9E75}"),
+    (0x2FC5, "\u{9E7F}"),
+    (0x2FC6, "\u{9EA5}"),
+    (0x2FC7, "\u{9EBB}"),
+    (0x2FC8, "\u{9EC3}"),
+    (0x2FC9, "\u{9ECD}"),
+    (0x2FCA, "\u{9ED1}"),
+    (0x2FCB, "\u{9EF9}"),
+    (0x2FCC, "\u{9EFD}"),
+    (0x2FCD, "\u{9F0E}"),
+    (0x2FCE, "\u{9F13}"),
+    (0x2FCF, "\u{9F20}"),
+    (0x2FD0, "\u{9F3B}"),
+    (0x2FD1, "\u{9F4A}"),
+    (0x2FD2, "\u{9F52}"),
+    (0x2FD3, "\u{9F8D}"),
+    (0x2FD4, "\u{9F9C}"),
+    (0x2FD5, "\u{9FA0}"),
+    (0x3002, "\u{2F3}"),
+    (0x3003, "\u{27}\u{27}"),
+    (0x3007, "\u{4F}"),
+    (0x3008, "\u{276C}"),
+    (0x3009, "\u{276D}"),
+    (0x3012, "\u{20B8}"),
+    (0x3014, "\u{28}"),
+    (0x3015, "\u{29}"),
+    (0x301A, "\u{27E6}"),
+    (0x301B, "\u{27E7}"),
+    (0x302C, "\u{309}"),
+    (0x302D, "\u{325}"),
+    (0x3033, "\u{2F}"),
+    (0x3036, "\u{20B8}"),
+    (0x3038, "\u{5341}"),
+    (0x3039, "\u{5344}"),
+    (0x303A, "\u{5345}"),
+    (0x304F, "\u{276C}"),
+    (0x309A, "\u{30A}"),
+    (0x309B, "\u{FF9E}"),
+    (0x309C, "\u{FF9F}"),
+    (0x30A0, "\u{3D}"),
+    (0x30A4, "\u{4EBB}"),
+    (0x30A8, "\u{5DE5}"),
+    (0x30AB, "\u{529B}"),
+    (0x30BF, "\u{5915}"),
+    (0x30C8, "\u{535C}"),
+    (0x30CB, "\u{4E8C}"),
+    (0x30CE, "\u{2F}"),
+    (0x30CF, "\u{516B}"),
+    (0x30D8, "\u{3078}"),
+    (0x30ED, "\u{53E3}"),
+    (0x30FB, "\u{B7}"),
+    (0x3126, "\u{513F}"),
+    (0x3131, "\u{1100}"),
+    (0x3132, "\u{1100}\u{1100}"),
+    (0x3133, "\u{1100}\u{1109}"),
+    (0x3134, "\u{1102}"),
+    (0x3135, "\u{1102}\u{110C}"),
+    (0x3136, "\u{1102}\u{1112}"),
+    (0x3137, "\u{1103}"),
+    (0x3138, "\u{1103}\u{1103}"),
+    (0x3139, "\u{1105}"),
+    (0x313A, "\u{1105}\u{1100}"),
+    (0x313B, "\u{1105}\u{1106}"),
+    (0x313C, "\u{1105}\u{1107}"),
+    (0x313D, "\u{1105}\u{1109}"),
+    (0x313E, "\u{1105}\u{1110}"),
+    (0x313F, "\u{1105}\u{1111}"),
+    (0x3140, "\u{1105}\u{1112}"),
+    (0x3141, "\u{1106}"),
+    (0x3142, "\u{1107}"),
+    (0x3143, "\u{1107}\u{1107}"),
+    (0x3144, "\u{1107}\u{1109}"),
+    (0x3145, "\u{1109}"),
+    (0x3146, "\u{1109}\u{1109}"),
+    (0x3147, "\u{110B}"),
+    (0x3148, "\u{110C}"),
+    (0x3149, "\u{110C}\u{110C}"),
+    (0x314A, "\u{110E}"),
+    (0x314B, "\u{110F}"),
+    (0x314C, "\u{1110}"),
+    (0x314D, "\u{1111}"),
+    (0x314E, "\u{1112}"),
+    (0x314F, "\u{1161}"),
+    (0x3150, "\u{1161}\u{4E28}"),
+    (0x3151, "\u{1163}"),
+    (0x3152, "\u{1163}\u{4E28}"),
+    (0x3153, "\u{1165}"),
+    (0x3154, "\u{1165}\u{4E28}"),
+    (0x3155, "\u{1167}"),
+    (0x3156, "\u{1167}\u{4E28}"),
+    (0x3157, "\u{1169}"),
+    (0x3158, "\u{1169}\u{1161}"),
+    (0x3159, "\u{1169}\u{1161}\u{4E28}"),
+    (0x315A, "\u{1169}\u{4E28}"),
+    (0x315B, "\u{116D}"),
+    (0x315C, "\u{116E}"),
+    (0x315D, "\u{116E}\u{1165}"),
+    (0x315E, "\u{116E}\u{1165}\u{4E28}"),
+    (0x315F, "\u{116E}\u{4E28}"),
+    (0x3160, "\u{1172}"),
+    (0x3161, "\u{30FC}"),
+    (0x3162, "\u{30FC}\u{4E28}"),
+    (0x3163, "\u{4E28}"),
+    (0x3164, "\u{1160}"),
+    (0x3165, "\u{1102}\u{1102}"),
+    (0x3166, "\u{1102}\u{1103}"),
+    (0x3167, "\u{1102}\u{1109}"),
+    (0x3168, "\u{1102}\u{1140}"),
+    (0x3169, "\u{1105}\u{1100}\u{1109}"),
+    (0x316A, "\u{1105}\u{1103}"),
+    (0x316B, "\u{1105}\u{1107}\u{1109}"),
+    (0x316C, "\u{1105}\u{1140}"),
+    (0x316D, "\u{1105}\u{1159}"),
+    (0x316E, "\u{1106}\u{1107}"),
+    (0x316F, "\u{1106}\u{1109}"),
+    (0x3170, "\u{1106}\u{1140}"),
+    (0x3171, "\u{1106}\u{110B}"),
+    (0x3172, "\u{1107}\u{1100}"),
+    (0x3173, "\u{1107}\u{1103}"),
+    (0x3174, "\u{1107}\u{1109}\u{1100}"),
+    (0x3175, "\u{1107}\u{1109}\u{1103}"),
+    (0x3176, "\u{1107}\u{110C}"),
+    (0x3177, "\u{1107}\u{1110}"),
+    (0x3178, "\u{1107}\u{110B}"),
+    (0x3179, "\u{1107}\u{1107}\u{110B}"),
+    (0x317A, "\u{1109}\u{1100}"),
+    (0x317B, "\u{1109}\u{1102}"),
+    (0x317C, "\u{1109}\u{1103}"),
+    (0x317D, "\u{1109}\u{1107}"),
+    (0x317E, "\u{1109}\u{110C}"),
+    (0x317F, "\u{1140}"),
+    (0x3180, "\u{110B}\u{110B}"),
+    (0x3181, "\u{114C}"),
+    (0x3182, "\u{110B}\u{1109}"),
+    (0x3183, "\u{110B}\u{1140}"),
+    (0x3184, "\u{1111}\u{110B}"),
+    (0x3185, "\u{1112}\u{1112}"),
+    (0x3186, "\u{1159}"),
+    (0x3187, "\u{116D}\u{1163}"),
+    (0x3188, "\u{116D}\u{1163}\u{4E28}"),
+    (0x3189, "\u{116D}\u{4E28}"),
+    (0x318A, "\u{1172}\u{1167}"),
+    (0x318B, "\u{1172}\u{1167}\u{4E28}"),
+    (0x318C, "\u{1172}\u{4E28}"),
+    (0x318D, "\u{119E}"),
+    (0x318E, "\u{119E}\u{4E28}"),
+    (0x31D0, "\u{30FC}"),
+    (0x31D1, "\u{4E28}"),
+    (0x31D3, "\u{2F}"),
+    (0x31D4, "\u{5C}"),
+    (0x31D6, "\u{4E5B}"),
+    (0x31DA, "\u{4E85}"),
+    (0x31DB, "\u{276C}"),
+    (0x31DF, "\u{4E5A}"),
+    (0x31E0, "\u{4E59}"),
+    (0x3200, "\u{28}\u{1100}\u{29}"),
+    (0x3201, "\u{28}\u{1102}\u{29}"),
+    (0x3202, "\u{28}\u{1103}\u{29}"),
+    (0x3203, "\u{28}\u{1105}\u{29}"),
+    (0x3204, "\u{28}\u{1106}\u{29}"),
+    (0x3205, "\u{28}\u{1107}\u{29}"),
+    (0x3206, "\u{28}\u{1109}\u{29}"),
+    (0x3207, "\u{28}\u{110B}\u{29}"),
+    (0x3208, "\u{28}\u{110C}\u{29}"),
+    (0x3209, "\u{28}\u{110E}\u{29}"),
+    (0x320A, "\u{28}\u{110F}\u{29}"),
+    (0x320B, "\u{28}\u{1110}\u{29}"),
+    (0x320C, "\u{28}\u{1111}\u{29}"),
+    (0x320D, "\u{28}\u{1112}\u{29}"),
+    (0x320E, "\u{28}\u{AC00}\u{29}"),
+    (0x320F, "\u{28}\u{B098}\u{29}"),
+    (0x3210, "\u{28}\u{B2E4}\u{29}"),
+    (0x3211, "\u{28}\u{B77C}\u{29}"),
+    (0x3212, "\u{28}\u{B9C8}\u{29}"),
+    (0x3213, "\u{28}\u{BC14}\u{29}"),
+    (0x3214, "\u{28}\u{C0AC}\u{29}"),
+    (0x3215, "\u{28}\u{C544}\u{29}"),
+    (0x3216, "\u{28}\u{C790}\u{29}"),
+    (0x3217, "\u{28}\u{CC28}\u{29}"),
+    (0x3218, "\u{28}\u{CE74}\u{29}"),
+    (0x3219, "\u{28}\u{D0C0}\u{29}"),
+    (0x321A, "\u{28}\u{D30C}\u{29}"),
+    (0x321B, "\u{28}\u{D558}\u{29}"),
+    (0x321C, "\u{28}\u{C8FC}\u{29}"),
+    (0x321D, "\u{28}\u{C624}\u{C804}\u{29}"),
+    (0x321E, "\u{28}\u{C624}\u{D6C4}\u{29}"),
+    (0x3220, "\u{28}\u{30FC}\u{29}"),
+    (0x3221, "\u{28}\u{4E8C}\u{29}"),
+    (0x3222, "\u{28}\u{4E09}\u{29}"),
+    (0x3223, "\u{28}\u{56DB}\u{29}"),
+    (0x3224, "\u{28}\u{4E94}\u{29}"),
+    (0x3225, "\u{28}\u{516D}\u{29}"),
+    (0x3226, "\u{28}\u{4E03}\u{29}"),
+    (0x3227, "\u{28}\u{516B}\u{29}"),
+    (0x3228, "\u{28}\u{4E5D}\u{29}"),
+    (0x3229, "\u{28}\u{5341}\u{29}"),
+    (0x322A, "\u{28}\u{6708}\u{29}"),
+    (0x322B, "\u{28}\u{706B}\u{29}"),
+    (0x322C, "\u{28}\u{6C34}\u{29}"),
+    (0x322D, "\u{28}\u{6728}\u{29}"),
+    (0x322E, "\u{28}\u{91D1}\u{29}"),
+    (0x322F, "\u{28}\u{571F}\u{29}"),
+    (0x3230, "\u{28}\u{65E5}\u{29}"),
+    (0x3231, "\u{28}\u{682A}\u{29}"),
+    (0x3232, "\u{28}\u{6709}\u{29}"),
+    (0x3233, "\u{28}\u{793E}\u{29}"),
+    (0x3234, "\u{28}\u{540D}\u{29}"),
+    (0x3235, "\u{28}\u{7279}\u{29}"),
+    (0x3236, "\u{28}\u{8CA1}\u{29}"),
+    (0x3237, "\u{28}\u{795D}\u{29}"),
+    (0x3238, "\u{28}\u{52B4}\u{29}"),
+    (0x3239, "\u{28}\u{4EE3}\u{29}"),
+    (0x323A, "\u{28}\u{547C}\u{29}"),
+    (0x323B, "\u{28}\u{5B66}\u{29}"),
+    (0x323C, "\u{28}\u{76E3}\u{29}"),
+    (0x323D, "\u{28}\u{4F01}\u{29}"),
+    (0x323E, "\u{28}\u{8CC7}\u{29}"),
+    (0x323F, "\u{28}\u{5354}\u{29}"),
+    (0x3240, "\u{28}\u{796D}\u{29}"),
+    (0x3241, "\u{28}\u{4F11}\u{29}"),
+    (0x3242, "\u{28}\u{81EA}\u{29}"),
+    (0x3243, "\u{28}\u{81F3}\u{29}"),
+    (0x32C0, "\u{6C}\u{6708}"),
+    (0x32C1, "\u{32}\u{6708}"),
+    (0x32C2, "\u{33}\u{6708}"),
+    (0x32C3, "\u{34}\u{6708}"),
+    (0x32C4, "\u{35}\u{6708}"),
+    (0x32C5, "\u{36}\u{6708}"),
+    (0x32C6, "\u{37}\u{6708}"),
+    (0x32C7, "\u{38}\u{6708}"),
+    (0x32C8, "\u{39}\u{6708}"),
+    (0x32C9, "\u{6C}\u{4F}\u{6708}"),
+    (0x32CA, "\u{6C}\u{6C}\u{6708}"),
+    (0x32CB, "\u{6C}\u{32}\u{6708}"),
+    (0x3358, "\u{4F}\u{70B9}"),
+    (0x3359, "\u{6C}\u{70B9}"),
+    (0x335A, "\u{32}\u{70B9}"),
+    (0x335B, "\u{33}\u{70B9}"),
+    (0x335C, "\u{34}\u{70B9}"),
+    (0x335D, "\u{35}\u{70B9}"),
+    (0x335E, "\u{36}\u{70B9}"),
+    (0x335F, "\u{37}\u{70B9}"),
+    (0x3360, "\u{38}\u{70B9}"),
+    (0x3361, "\u{39}\u{70B9}"),
+    (0x3362, "\u{6C}\u{4F}\u{70B9}"),
+    (0x3363, "\u{6C}\u{6C}\u{70B9}"),
+    (0x3364, "\u{6C}\u{32}\u{70B9}"),
+    (0x3365, "\u{6C}\u{33}\u{70B9}"),
+    (0x3366, "\u{6C}\u{34}\u{70B9}"),
+    (0x3367, "\u{6C}\u{35}\u{70B9}"),
+    (0x3368, "\u{6C}\u{36}\u{70B9}"),
+    (0x3369, "\u{6C}\u{37}\u{70B9}"),
+    (0x336A, "\u{6C}\u{38}\u{70B9}"),
+    (0x336B, "\u{6C}\u{39}\u{70B9}"),
+    (0x336C, "\u{32}\u{4F}\u{70B9}"),
+    (0x336D, "\u{32}\u{6C}\u{70B9}"),
+    (0x336E, "\u{32}\u{32}\u{70B9}"),
+    (0x336F, "\u{32}\u{33}\u{70B9}"),
+    (0x3370, "\u{32}\u{34}\u{70B9}"),
+    (0x33E0, "\u{6C}\u{65E5}"),
+    (0x33E1, "\u{32}\u{65E5}"),
+    (0x33E2, "\u{33}\u{65E5}"),
+    (0x33E3, "\u{34}\u{65E5}"),
+    (0x33E4, "\u{35}\u{65E5}"),
+    (0x33E5, "\u{36}\u{65E5}"),
+    (0x33E6, "\u{37}\u{65E5}"),
+    (0x33E7, "\u{38}\u{65E5}"),
+    (0x33E8, "\u{39}\u{65E5}"),
+    (0x33E9, "\u{6C}\u{4F}\u{65E5}"),
+    (0x33EA, "\u{6C}\u{6C}\u{65E5}"),
+    (0x33EB, "\u{6C}\u{32}\u{65E5}"),
+    (0x33EC, "\u{6C}\u{33}\u{65E5}"),
+    (0x33ED, "\u{6C}\u{34}\u{65E5}"),
+    (0x33EE, "\u{6C}\u{35}\u{65E5}"),
+    (0x33EF, "\u{6C}\u{36}\u{65E5}"),
+    (0x33F0, "\u{6C}\u{37}\u{65E5}"),
+    (0x33F1, "\u{6C}\u{38}\u{65E5}"),
+    (0x33F2, "\u{6C}\u{39}\u{65E5}"),
+    (0x33F3, "\u{32}\u{4F}\u{65E5}"),
+    (0x33F4, "\u{32}\u{6C}\u{65E5}"),
+    (0x33F5, "\u{32}\u{32}\u{65E5}"),
+    (0x33F6, "\u{32}\u{33}\u{65E5}"),
+    (0x33F7, "\u{32}\u{34}\u{65E5}"),
+    (0x33F8, "\u{32}\u{35}\u{65E5}"),
+    (0x33F9, "\u{32}\u{36}\u{65E5}"),
+    (0x33FA, "\u{32}\u{37}\u{65E5}"),
+    (0x33FB, "\u{32}\u{38}\u{65E5}"),
+    (0x33FC, "\u{32}\u{39}\u{65E5}"),
+    (0x33FD, "\u{33}\u{4F}\u{65E5}"),
+    (0x33FE, "\u{33}\u{6C}\u{65E5}"),
+    (0x39B3, "\u{363D}"),
+    (0x439B, "\u{3588}"),
+    (0x4420, "\u{3B3B}"),
+    (0x4E00, "\u{30FC}"),
+    (0x4E36, "\u{5C}"),
+    (0x4E3F, "\u{2F}"),
+    (0x5002, "\u{4F75}"),
+    (0x503C, "\u{5024}"),
+    (0x555F, "\u{5553}"),
+    (0x56D7, "\u{53E3}"),
+    (0x586B, "\u{5861}"),
+    (0x58EB, "\u{571F}"),
+    (0x58FF, "\u{58AB}"),
+    (0x5B00, "\u{5AAF}"),
+    (0x5E32, "\u{5E21}"),
+    (0x5E50, "\u{3B3A}"),
+    (0x6238, "\u{6236}"),
+    (0x6409, "\u{3A41}"),
+    (0x6663, "\u{403F}"),
+    (0x6669, "\u{665A}"),
+    (0x66F6, "\u{3ADA}"),
+    (0x6726, "\u{4443}"),
+    (0x67FF, "\u{676E}"),
+    (0x69E9, "\u{3BA3}"),
+    (0x6A27, "\u{699D}"),
+    (0x6F59, "\u{6E88}"),
+    (0x784F, "\u{7814}"),
+    (0x7D76, "\u{7D55}"),
+    (0x80A6, "\u{670C}"),
+    (0x80CA, "\u{6710}"),
+    (0x80D0, "\u{670F}"),
+    (0x80F6, "\u{3B35}"),
+    (0x8101, "\u{6713}"),
+    (0x8127, "\u{6718}"),
+    (0x8141, "\u{80FC}"),
+    (0x81A7, "\u{6723}"),
+    (0x853F, "\u{848D}"),
+    (0x8641, "\u{8637}"),
+    (0x8A1E, "\u{46B6}"),
+    (0x8A7D, "\u{8A2E}"),
+    (0x8B8F, "\u{8B86}"),
+    (0x8C63, "\u{8C5C}"),
+    (0x8D86, "\u{8D7F}"),
+    (0x8DFA, "\u{8DE5}"),
+    (0x8E9B, "\u{8E97}"),
+    (0x8F27, "\u{8EFF}"),
+    (0x90DE, "\u{90CE}"),
+    (0x93AE, "\u{93AD}"),
+    (0x96B8, "\u{96B7}"),
+    (0x9E43, "\u{9E42}"),
+    (0x9ED2, "\u{9ED1}"),
+    (0x9FC3, "\u{4039}"),
+    (0xA494, "\u{A2CD}"),
+    (0xA49C, "\u{A0C0}"),
+    (0xA49E, "\u{A04A}"),
+    (0xA4A7, "\u{A458}"),
+    (0xA4A8, "\u{A132}"),
+    (0xA4AC, "\u{A050}"),
+    (0xA4B0, "\u{A3C2}"),
+    (0xA4BA, "\u{A3BF}"),
+    (0xA4BE, "\u{A2B1}"),
+    (0xA4BF, "\u{A259}"),
+    (0xA4C0, "\u{A3AB}"),
+    (0xA4C2, "\u{A3B5}"),
+    (0xA4D0, "\u{42}"),
+    (0xA4D1, "\u{50}"),
+    (0xA4D2, "\u{64}"),
+    (0xA4D3, "\u{44}"),
+    (0xA4D4, "\u{54}"),
+    (0xA4D6, "\u{47}"),
+    (0xA4D7, "\u{4B}"),
+    (0xA4D9, "\u{4A}"),
+    (0xA4DA, "\u{43}"),
+    (0xA4DB, "\u{186}"),
+    (0xA4DC, "\u{5A}"),
+    (0xA4DD, "\u{46}"),
+    (0xA4DE, "\u{2132}"),
+    (0xA4DF, "\u{4D}"),
+    (0xA4E0, "\u{4E}"),
+    (0xA4E1, "\u{4C}"),
+    (0xA4E2, "\u{53}"),
+    (0xA4E3, "\u{52}"),
+    (0xA4E5, "\u{245}"),
+    (0xA4E6, "\u{56}"),
+    (0xA4E7, "\u{48}"),
+    (0xA4EA, "\u{57}"),
+    (0xA4EB, "\u{58}"),
+    (0xA4EC, "\u{59}"),
+    (0xA4ED, "\u{1660}"),
+    (0xA4EE, "\u{41}"),
+    (0xA4EF, "\u{2C6F}"),
+    (0xA4F0, "\u{45}"),
+    (0xA4F1, "\u{18E}"),
+    (0xA4F2, "\u{6C}"),
+    (0xA4F3, "\u{4F}"),
+    (0xA4F4, "\u{55}"),
+    (0xA4F5, "\u{548}"),
+    (0xA4F7, "\u{15E1}"),
+    (0xA4F8, "\u{2E}"),
+    (0xA4F9, "\u{2C}"),
+    (0xA4FA, "\u{2E}\u{2E}"),
+    (0xA4FB, "\u{2E}\u{2C}"),
+    (0xA4FD, "\u{3A}"),
+    (0xA4FE, "\u{2D}\u{2E}"),
+    (0xA4FF, "\u{3D}"),
+    (0xA60E, "\u{2E}"),
+    (0xA644, "\u{32}"),
+    (0xA645, "\u{1A8}"),
+    (0xA647, "\u{69}"),
+    (0xA64D, "\u{3C9}"),
+    (0xA650, "\u{42A}\u{6C}"),
+    (0xA651, "\u{2C9}\u{62}\u{69}"),
+    (0xA668, "\u{298}"),
+    (0xA66F, "\u{20E9}"),
+    (0xA67C, "\u{306}"),
+    (0xA67E, "\u{2C7}"),
+    (0xA695, "\u{68}\u{314}"),
+    (0xA698, "\u{4F}\u{4F}"),
+    (0xA699, "\u{6F}\u{6F}"),
+    (0xA69A, "\u{102A8}"),
+    (0xA6A1, "\u{418}"),
+    (0xA6B0, "\u{16B9}"),
+    (0xA6B1, "\u{2C75}"),
+    (0xA6CD, "\u{2A1}"),
+    (0xA6CE, "\u{245}"),
+    (0xA6DB, "\u{3A0}"),
+    (0xA6DF, "\u{56}"),
+    (0xA6EB, "\u{3F}"),
+    (0xA6EF, "\u{32}"),
+    (0xA6F0, "\u{302}"),
+    (0xA6F1, "\u{304}"),
+    (0xA6F4, "\u{A6F3}\u{A6F3}"),
+    (0xA714, "\u{2EB}"),
+    (0xA716, "\u{2EA}"),
+    (0xA728, "\u{54}\u{33}"),
+    (0xA729, "\u{74}\u{21D}"),
+    (0xA731, "\u{73}"),
+    (0xA732, "\u{41}\u{41}"),
+    (0xA733, "\u{61}\u{61}"),
+    (0xA734, "\u{41}\u{4F}"),
+    (0xA735, "\u{61}\u{6F}"),
+    (0xA736, "\u{41}\u{55}"),
+    (0xA737, "\u{61}\u{75}"),
+    (0xA738, "\u{41}\u{56}"),
+    (0xA739, "\u{61}\u{76}"),
+    (0xA73A, "\u{41}\u{56}"),
+    (0xA73B, "\u{61}\u{76}"),
+    (0xA73C, "\u{41}\u{59}"),
+    (0xA73D, "\u{61}\u{79}"),
+    (0xA740, "\u{4B}\u{335}"),
+    (0xA74A, "\u{4F}\u{335}"),
+    (0xA74B, "\u{6F}\u{335}"),
+    (0xA74E, "\u{4F}\u{4F}"),
+    (0xA74F, "\u{6F}\u{6F}"),
+    (0xA75A, "\u{32}"),
+    (0xA761, "\u{77}\u{326}"),
+    (0xA76A, "\u{33}"),
+    (0xA76B, "\u{21D}"),
+    (0xA76E, "\u{39}"),
+    (0xA777, "\u{74}\u{66}"),
+    (0xA778, "\u{26}"),
+    (0xA77A, "\u{A779}"),
+    (0xA789, "\u{3A}"),
+    (0xA78C, "\u{27}"),
+    (0xA78F, "\u{B7}"),
+    (0xA795, "\u{A727}"),
+    (0xA798, "\u{46}"),
+    (0xA799, "\u{66}"),
+    (0xA79A, "\u{10412}"),
+    (0xA79B, "\u{1043A}"),
+    (0xA79D, "\u{29A}"),
+    (0xA79E, "\u{A4E4}"),
+    (0xA79F, "\u{75}"),
+    (0xA7AB, "\u{33}"),
+    (0xA7B1, "\u{A4D5}"),
+    (0xA7B2, "\u{4A}"),
+    (0xA7B3, "\u{58}"),
+    (0xA7B4, "\u{42}"),
+    (0xA7B5, "\u{DF}"),
+    (0xA7B6, "\u{A64C}"),
+    (0xA7B7, "\u{3C9}"),
+    (0xA7D6, "\u{DF}"),
+    (0xA7DA, "\u{245}"),
+    (0xA7DB, "\u{3BB}"),
+    (0xA7DC, "\u{245}\u{338}"),
+    (0xA7F7, "\u{30FC}"),
+    (0xA830, "\u{964}"),
+    (0xA960, "\u{1103}\u{1106}"),
+    (0xA961, "\u{1103}\u{1107}"),
+    (0xA962, "\u{1103}\u{1109}"),
+    (0xA963, "\u{1103}\u{110C}"),
+    (0xA964, "\u{1105}\u{1100}"),
+    (0xA965, "\u{1105}\u{1100}\u{1100}"),
+    (0xA966, "\u{1105}\u{1103}"),
+    (0xA967, "\u{1105}\u{1103}\u{1103}"),
+    (0xA968, "\u{1105}\u{1106}"),
+    (0xA969, "\u{1105}\u{1107}"),
+    (0xA96A, "\u{1105}\u{1107}\u{1107}"),
+    (0xA96B, "\u{1105}\u{1107}\u{110B}"),
+    (0xA96C, "\u{1105}\u{1109}"),
+    (0xA96D, "\u{1105}\u{110C}"),
+    (0xA96E, "\u{1105}\u{110F}"),
+    (0xA96F, "\u{1106}\u{1100}"),
+    (0xA970, "\u{1106}\u{1103}"),
+    (0xA971, "\u{1106}\u{1109}"),
+    (0xA972, "\u{1107}\u{1109}\u{1110}"),
+    (0xA973, "\u{1107}\u{110F}"),
+    (0xA974, "\u{1107}\u{1112}"),
+    (0xA975, "\u{1109}\u{1109}\u{1107}"),
+    (0xA976, "\u{110B}\u{1105}"),
+    (0xA977, "\u{110B}\u{1112}"),
+    (0xA978, "\u{110C}\u{110C}\u{1112}"),
+    (0xA979, "\u{1110}\u{1110}"),
+    (0xA97A, "\u{1111}\u{1112}"),
+    (0xA97B, "\u{1112}\u{1109}"),
+    (0xA97C, "\u{1159}\u{1159}"),
+    (0xA992, "\u{2C3F}"),
+    (0xA9A3, "\u{A99D}"),
+    (0xA9C6, "\u{A9D0}"),
+    (0xA9CF, "\u{662}"),
+    (0xAA53, "\u{AA01}"),
+    (0xAA56, "\u{AA23}"),
+    (0xAB32, "\u{65}"),
+    (0xAB35, "\u{66}"),
+    (0xAB3D, "\u{6F}"),
+    (0xAB3E, "\u{6F}\u{338}"),
+    (0xAB3F, "\u{254}\u{338}"),
+    (0xAB41, "\u{1DD}\u{6F}\u{338}"),
+    (0xAB42, "\u{1DD}\u{6F}\u{335}"),
+    (0xAB47, "\u{72}"),
+    (0xAB48, "\u{72}"),
+    (0xAB4D, "\u{283}"),
+    (0xAB4E, "\u{75}"),
+    (0xAB52, "\u{75}"),
+    (0xAB53, "\u{3C7}"),
+    (0xAB55, "\u{3C7}"),
+    (0xAB5A, "\u{79}"),
+    (0xAB60, "\u{459}"),
+    (0xAB62, "\u{254}\u{65}"),
+    (0xAB63, "\u{75}\u{6F}"),
+    (0xAB70, "\u{1D05}"),
+    (0xAB71, "\u{280}"),
+    (0xAB72, "\u{1D1B}"),
+    (0xAB74, "\u{6F}\u{31B}"),
+    (0xAB75, "\u{69}"),
+    (0xAB7A, "\u{1D00}"),
+    (0xAB7B, "\u{1D0A}"),
+    (0xAB7C, "\u{1D07}"),
+    (0xAB7E, "\u{242}"),
+    (0xAB80, "\u{2C76}"),
+    (0xAB81, "\u{72}"),
+    (0xAB83, "\u{77}"),
+    (0xAB87, "\u{28D}"),
+    (0xAB8B, "\u{29C}"),
+    (0xAB8E, "\u{6F}\u{335}"),
+    (0xAB90, "\u{262}"),
+    (0xAB93, "\u{7A}"),
+    (0xAB9B, "\u{A793}"),
+    (0xAB9C, "\u{75}\u{335}"),
+    (0xAB9F, "\u{185}"),
+    (0xABA2, "\u{280}"),
+    (0xABA9, "\u{76}"),
+    (0xABAA, "\u{73}"),
+    (0xABAE, "\u{29F}"),
+    (0xABAF, "\u{63}"),
+    (0xABB2, "\u{1D18}"),
+    (0xABB6, "\u{138}"),
+    (0xABBB, "\u{6F}\u{335}"),
+    (0xD7B0, "\u{1169}\u{1167}"),
+    (0xD7B1, "\u{1169}\u{1169}\u{4E28}"),
+    (0xD7B2, "\u{116D}\u{1161}"),
+    (0xD7B3, "\u{116D}\u{1161}\u{4E28}"),
+    (0xD7B4, "\u{116D}\u{1165}"),
+    (0xD7B5, "\u{116E}\u{1167}"),
+    (0xD7B6, "\u{116E}\u{4E28}\u{4E28}"),
+    (0xD7B7, "\u{1172}\u{1161}\u{4E28}"),
+    (0xD7B8, "\u{1172}\u{1169}"),
+    (0xD7B9, "\u{30FC}\u{1161}"),
+    (0xD7BA, "\u{30FC}\u{1165}"),
+    (0xD7BB, "\u{30FC}\u{1165}\u{4E28}"),
+    (0xD7BC, "\u{30FC}\u{1169}"),
+    (0xD7BD, "\u{4E28}\u{1163}\u{1169}"),
+    (0xD7BE, "\u{4E28}\u{1163}\u{4E28}"),
+    (0xD7BF, "\u{4E28}\u{1167}"),
+    (0xD7C0, "\u{4E28}\u{1167}\u{4E28}"),
+    (0xD7C1, "\u{4E28}\u{1169}\u{4E28}"),
+    (0xD7C2, "\u{4E28}\u{116D}"),
+    (0xD7C3, "\u{4E28}\u{1172}"),
+    (0xD7C4, "\u{4E28}\u{4E28}"),
+    (0xD7C5, "\u{119E}\u{1161}"),
+    (0xD7C6, "\u{119E}\u{1165}\u{4E28}"),
+    (0xD7CB, "\u{1102}\u{1105}"),
+    (0xD7CC, "\u{1102}\u{110E}"),
+    (0xD7CD, "\u{1103}\u{1103}"),
+    (0xD7CE, "\u{1103}\u{1103}\u{1107}"),
+    (0xD7CF, "\u{1103}\u{1107}"),
+    (0xD7D0, "\u{1103}\u{1109}"),
+    (0xD7D1, "\u{1103}\u{1109}\u{1100}"),
+    (0xD7D2, "\u{1103}\u{110C}"),
+    (0xD7D3, "\u{1103}\u{110E}"),
+    (0xD7D4, "\u{1103}\u{1110}"),
+    (0xD7D5, "\u{1105}\u{1100}\u{1100}"),
+    (0xD7D6, "\u{1105}\u{1100}\u{1112}"),
+    (0xD7D7, "\u{1105}\u{1105}\u{110F}"),
+    (0xD7D8, "\u{1105}\u{1106}\u{1112}"),
+    (0xD7D9, "\u{1105}\u{1107}\u{1103}"),
+    (0xD7DA, "\u{1105}\u{1107}\u{1111}"),
+    (0xD7DB, "\u{1105}\u{114C}"),
+    (0xD7DC, "\u{1105}\u{1159}\u{1112}"),
+    (0xD7DD, "\u{1105}\u{110B}"),
+    (0xD7DE, "\u{1106}\u{1102}"),
+    (0xD7DF, "\u{1106}\u{1102}\u{1102}"),
+    (0xD7E0, "\u{1106}\u{1106}"),
+    (0xD7E1, "\u{1106}\u{1107}\u{1109}"),
+    (0xD7E2, "\u{1106}\u{110C}"),
+    (0xD7E3, "\u{1107}\u{1103}"),
+    (0xD7E4, "\u{1107}\u{1105}\u{1111}"),
+    (0xD7E5, "\u{1107}\u{1106}"),
+    (0xD7E6, "\u{1107}\u{1107}"),
+    (0xD7E7, "\u{1107}\u{1109}\u{1103}"),
+    (0xD7E8, "\u{1107}\u{110C}"),
+    (0xD7E9, "\u{1107}\u{110E}"),
+    (0xD7EA, "\u{1109}\u{1106}"),
+    (0xD7EB, "\u{1109}\u{1107}\u{110B}"),
+    (0xD7EC, "\u{1109}\u{1109}\u{1100}"),
+    (0xD7ED, "\u{1109}\u{1109}\u{1103}"),
+    (0xD7EE, "\u{1109}\u{1140}"),
+    (0xD7EF, "\u{1109}\u{110C}"),
+    (0xD7F0, "\u{1109}\u{110E}"),
+    (0xD7F1, "\u{1109}\u{1110}"),
+    (0xD7F2, "\u{1105}\u{1112}"),
+    (0xD7F3, "\u{1140}\u{1107}"),
+    (0xD7F4, "\u{1140}\u{1107}\u{110B}"),
+    (0xD7F5, "\u{114C}\u{1106}"),
+    (0xD7F6, "\u{114C}\u{1112}"),
+    (0xD7F7, "\u{110C}\u{1107}"),
+    (0xD7F8, "\u{110C}\u{1107}\u{1107}"),
+    (0xD7F9, "\u{110C}\u{110C}"),
+    (0xD7FA, "\u{1111}\u{1109}"),
+    (0xD7FB, "\u{1111}\u{1110}"),
+    (0xF900, "\u{8C48}"),
+    (0xF901, "\u{66F4}"),
+    (0xF902, "\u{8ECA}"),
+    (0xF903, "\u{8CC8}"),
+    (0xF904, "\u{6ED1}"),
+    (0xF905, "\u{4E32}"),
+    (0xF906, "\u{53E5}"),
+    (0xF907, "\u{9F9C}"),
+    (0xF908, "\u{9F9C}"),
+    (0xF909, "\u{5951}"),
+    (0xF90A, "\u{91D1}"),
+    (0xF90B, "\u{5587}"),
+    (0xF90C, "\u{5948}"),
+    (0xF90D, "\u{61F6}"),
+    (0xF90E, "\u{7669}"),
+    (0xF90F, "\u{7F85}"),
+    (0xF910, "\u{863F}"),
+    (0xF911, "\u{87BA}"),
+    (0xF912, "\u{88F8}"),
+    (0xF913, "\u{908F}"),
+    (0xF914, "\u{6A02}"),
+    (0xF915, "\u{6D1B}"),
+    (0xF916, "\u{70D9}"),
+    (0xF917, "\u{73DE}"),
+    (0xF918, "\u{843D}"),
+    (0xF919, "\u{916A}"),
+    (0xF91A, "\u{99F1}"),
+    (0xF91B, "\u{4E82}"),
+    (0xF91C, "\u{5375}"),
+    (0xF91D, "\u{6B04}"),
+    (0xF91E, "\u{721B}"),
+    (0xF91F, "\u{862D}"),
+    (0xF920, "\u{9E1E}"),
+    (0xF921, "\u{5D50}"),
+    (0xF922, "\u{6FEB}"),
+    (0xF923, "\u{85CD}"),
+    (0xF924, "\u{8964}"),
+    (0xF925, "\u{62C9}"),
+    (0xF926, "\u{81D8}"),
+    (0xF927, "\u{881F}"),
+    (0xF928, "\u{5ECA}"),
+    (0xF929, "\u{6717}"),
+    (0xF92A, "\u{6D6A}"),
+    (0xF92B, "\u{72FC}"),
+    (0xF92C, "\u{90CE}"),
+    (0xF92D, "\u{4F86}"),
+    (0xF92E, "\u{51B7}"),
+    (0xF92F, "\u{52DE}"),
+    (0xF930, "\u{64C4}"),
+    (0xF931, "\u{6AD3}"),
+    (0xF932, "\u{7210}"),
+    (0xF933, "\u{76E7}"),
+    (0xF934, "\u{8001}"),
+    (0xF935, "\u{8606}"),
+    (0xF936, "\u{865C}"),
+    (0xF937, "\u{8DEF}"),
+    (0xF938, "\u{9732}"),
+    (0xF939, "\u{9B6F}"),
+    (0xF93A, "\u{9DFA}"),
+    (0xF93B, "\u{788C}"),
+    (0xF93C, "\u{797F}"),
+    (0xF93D, "\u{7DA0}"),
+    (0xF93E, "\u{83C9}"),
+    (0xF93F, "\u{9304}"),
+    (0xF940, "\u{9E7F}"),
+    (0xF941, "\u{8AD6}"),
+    (0xF942, "\u{58DF}"),
+    (0xF943, "\u{5F04}"),
+    (0xF944, "\u{7C60}"),
+    (0xF945, "\u{807E}"),
+    (0xF946, "\u{7262}"),
+    (0xF947, "\u{78CA}"),
+    (0xF948, "\u{8CC2}"),
+    (0xF949, "\u{96F7}"),
+    (0xF94A, "\u{58D8}"),
+    (0xF94B, "\u{5C62}"),
+    (0xF94C, "\u{6A13}"),
+    (0xF94D, "\u{6DDA}"),
+    (0xF94E, "\u{6F0F}"),
+    (0xF94F, "\u{7D2F}"),
+    (0xF950, "\u{7E37}"),
+    (0xF951, "\u{964B}"),
+    (0xF952, "\u{52D2}"),
+    (0xF953, "\u{808B}"),
+    (0xF954, "\u{51DC}"),
+    (0xF955, "\u{51CC}"),
+    (0xF956, "\u{7A1C}"),
+    (0xF957, "\u{7DBE}"),
+    (0xF958, "\u{83F1}"),
+    (0xF959, "\u{9675}"),
+    (0xF95A, "\u{8B80}"),
+    (0xF95B, "\u{62CF}"),
+    (0xF95C, "\u{6A02}"),
+    (0xF95D, "\u{8AFE}"),
+    (0xF95E, "\u{4E39}"),
+    (0xF95F, "\u{5BE7}"),
+    (0xF960, "\u{6012}"),
+    (0xF961, "\u{7387}"),
+    (0xF962, "\u{7570}"),
+    (0xF963, "\u{5317}"),
+    (0xF964, "\u{78FB}"),
+    (0xF965, "\u{4FBF}"),
+    (0xF966, "\u{5FA9}"),
+    (0xF967, "\u{4E0D}"),
+    (0xF968, "\u{6CCC}"),
+    (0xF969, "\u{6578}"),
+    (0xF96A, "\u{7D22}"),
+    (0xF96B, "\u{53C3}"),
+    (0xF96C, "\u{585E}"),
+    (0xF96D, "\u{7701}"),
+    (0xF96E, "\u{8449}"),
+    (0xF96F, "\u{8AAA}"),
+    (0xF970, "\u{6BBA}"),
+    (0xF971, "\u{8FB0}"),
+    (0xF972, "\u{6C88}"),
+    (0xF973, "\u{62FE}"),
+    (0xF974, "\u{82E5}"),
+    (0xF975, "\u{63A0}"),
+    (0xF976, "\u{7565}"),
+    (0xF977, "\u{4EAE}"),
+    (0xF978, "\u{5169}"),
+    (0xF979, "\u{51C9}"),
+    (0xF97A, "\u{6881}"),
+    (0xF97B, "\u{7CE7}"),
+    (0xF97C, "\u{826F}"),
+    (0xF97D, "\u{8AD2}"),
+    (0xF97E, "\u{91CF}"),
+    (0xF97F, "\u{52F5}"),
+    (0xF980, "\u{5442}"),
+    (0xF981, "\u{5973}"),
+    (0xF982, "\u{5EEC}"),
+    (0xF983, "\u{65C5}"),
+    (0xF984, "\u{6FFE}"),
+    (0xF985, "\u{792A}"),
+    (0xF986, "\u{95AD}"),
+    (0xF987, "\u{9A6A}"),
+    (0xF988, "\u{9E97}"),
+    (0xF989, "\u{9ECE}"),
+    (0xF98A, "\u{529B}"),
+    (0xF98B, "\u{66C6}"),
+    (0xF98C, "\u{6B77}"),
+    (0xF98D, "\u{8F62}"),
+    (0xF98E, "\u{5E74}"),
+    (0xF98F, "\u{6190}"),
+    (0xF990, "\u{6200}"),
+    (0xF991, "\u{649A}"),
+    (0xF992, "\u{6F23}"),
+    (0xF993, "\u{7149}"),
+    (0xF994, "\u{7489}"),
+    (0xF995, "\u{79CA}"),
+    (0xF996, "\u{7DF4}"),
+    (0xF997, "\u{806F}"),
+    (0xF998, "\u{8F26}"),
+    (0xF999, "\u{84EE}"),
+    (0xF99A, "\u{9023}"),
+    (0xF99B, "\u{934A}"),
+    (0xF99C, "\u{5217}"),
+    (0xF99D, "\u{52A3}"),
+    (0xF99E, "\u{54BD}"),
+    (0xF99F, "\u{70C8}"),
+    (0xF9A0, "\u{88C2}"),
+    (0xF9A1, "\u{8AAA}"),
+    (0xF9A2, "\u{5EC9}"),
+    (0xF9A3, "\u{5FF5}"),
+    (0xF9A4, "\u{637B}"),
+    (0xF9A5, "\u{6BAE}"),
+    (0xF9A6, "\u{7C3E}"),
+    (0xF9A7, "\u{7375}"),
+    (0xF9A8, "\u{4EE4}"),
+    (0xF9A9, "\u{56F9}"),
+    (0xF9AA, "\u{5BE7}"),
+    (0xF9AB, "\u{5DBA}"),
+    (0xF9AC, "\u{601C}"),
+    (0xF9AD, "\u{73B2}"),
+    (0xF9AE, "\u{7469}"),
+    (0xF9AF, "\u{7F9A}"),
+    (0xF9B0, "\u{8046}"),
+    (0xF9B1, "\u{9234}"),
+    (0xF9B2, "\u{96F6}"),
+    (0xF9B3, "\u{9748}"),
+    (0xF9B4, "\u{9818}"),
+    (0xF9B5, "\u{4F8B}"),
+    (0xF9B6, "\u{79AE}"),
+    (0xF9B7, "\u{91B4}"),
+    (0xF9B8, "\u{96B7}"),
+    (0xF9B9, "\u{60E1}"),
+    (0xF9BA, "\u{4E86}"),
+    (0xF9BB, "\u{50DA}"),
+    (0xF9BC, "\u{5BEE}"),
+    (0xF9BD, "\u{5C3F}"),
+    (0xF9BE, "\u{6599}"),
+    (0xF9BF, "\u{6A02}"),
+    (0xF9C0, "\u{71CE}"),
+    (0xF9C1, "\u{7642}"),
+    (0xF9C2, "\u{84FC}"),
+    (0xF9C3, "\u{907C}"),
+    (0xF9C4, "\u{9F8D}"),
+    (0xF9C5, "\u{6688}"),
+    (0xF9C6, "\u{962E}"),
+    (0xF9C7, "\u{5289}"),
+    (0xF9C8, "\u{677B}"),
+    (0xF9C9, "\u{67F3}"),
+    (0xF9CA, "\u{6D41}"),
+    (0xF9CB, "\u{6E9C}"),
+    (0xF9CC, "\u{7409}"),
+    (0xF9CD, "\u{7559}"),
+    (0xF9CE, "\u{786B}"),
+    (0xF9CF, "\u{7D10}"),
+    (0xF9D0, "\u{985E}"),
+    (0xF9D1, "\u{516D}"),
+    (0xF9D2, "\u{622E}"),
+    (0xF9D3, "\u{9678}"),
+    (0xF9D4, "\u{502B}"),
+    (0xF9D5, "\u{5D19}"),
+    (0xF9D6, "\u{6DEA}"),
+    (0xF9D7, "\u{8F2A}"),
+    (0xF9D8, "\u{5F8B}"),
+    (0xF9D9, "\u{6144}"),
+    (0xF9DA, "\u{6817}"),
+    (0xF9DB, "\u{7387}"),
+    (0xF9DC, "\u{9686}"),
+    (0xF9DD, "\u{5229}"),
+    (0xF9DE, "\u{540F}"),
+    (0xF9DF, "\u{5C65}"),
+    (0xF9E0, "\u{6613}"),
+    (0xF9E1, "\u{674E}"),
+    (0xF9E2, "\u{68A8}"),
+    (0xF9E3, "\u{6CE5}"),
+    (0xF9E4, "\u{7406}"),
+    (0xF9E5, "\u{75E2}"),
+    (0xF9E6, "\u{7F79}"),
+    (0xF9E7, "\u{88CF}"),
+    (0xF9E8, "\u{88E1}"),
+    (0xF9E9, "\u{91CC}"),
+    (0xF9EA, "\u{96E2}"),
+    (0xF9EB, "\u{533F}"),
+    (0xF9EC, "\u{6EBA}"),
+    (0xF9ED, "\u{541D}"),
+    (0xF9EE, "\u{71D0}"),
+    (0xF9EF, "\u{7498}"),
+    (0xF9F0, "\u{85FA}"),
+    (0xF9F1, "\u{96A3}"),
+    (0xF9F2, "\u{9C57}"),
+    (0xF9F3, "\u{9E9F}"),
+    (0xF9F4, "\u{6797}"),
+    (0xF9F5, "\u{6DCB}"),
+    (0xF9F6, "\u{81E8}"),
+    (0xF9F7, "\u{7ACB}"),
+    (0xF9F8, "\u{7B20}"),
+    (0xF9F9, "\u{7C92}"),
+    (0xF9FA, "\u{72C0}"),
+    (0xF9FB, "\u{7099}"),
+    (0xF9FC, "\u{8B58}"),
+    (0xF9FD, "\u{4EC0}"),
+    (0xF9FE, "\u{8336}"),
+    (0xF9FF, "\u{523A}"),
+    (0xFA00, "\u{5207}"),
+    (0xFA01, "\u{5EA6}"),
+    (0xFA02, "\u{62D3}"),
+    (0xFA03, "\u{7CD6}"),
+    (0xFA04, "\u{5B85}"),
+    (0xFA05, "\u{6D1E}"),
+    (0xFA06, "\u{66B4}"),
+    (0xFA07, "\u{8F3B}"),
+    (0xFA08, "\u{884C}"),
+    (0xFA09, "\u{964D}"),
+    (0xFA0A, "\u{898B}"),
+    (0xFA0B, "\u{5ED3}"),
+    (0xFA0C, "\u{5140}"),
+    (0xFA0D, "\u{55C0}"),
+    (0xFA10, "\u{585A}"),
+    (0xFA12, "\u{6674}"),
+    (0xFA15, "\u{51DE}"),
+    (0xFA16, "\u{732A}"),
+    (0xFA17, "\u{76CA}"),
+    (0xFA18, "\u{793C}"),
+    (0xFA19, "\u{795E}"),
+    (0xFA1A, "\u{7965}"),
+    (0xFA1B, "\u{798F}"),
+    (0xFA1C, "\u{9756}"),
+    (0xFA1D, "\u{7CBE}"),
+    (0xFA1E, "\u{7FBD}"),
+    (0xFA20, "\u{8612}"),
+    (0xFA22, "\u{8AF8}"),
+    (0xFA25, "\u{9038}"),
+    (0xFA26, "\u{90FD}"),
+    (0xFA2A, "\u{98EF}"),
+    (0xFA2B, "\u{98FC}"),
+    (0xFA2C, "\u{9928}"),
+    (0xFA2D, "\u{9DB4}"),
+    (0xFA2E, "\u{90CE}"),
+    (0xFA2F, "\u{96B7}"),
+    (0xFA30, "\u{4FAE}"),
+    (0xFA31, "\u{50E7}"),
+    (0xFA32, "\u{514D}"),
+    (0xFA33, "\u{52C9}"),
+    (0xFA34, "\u{52E4}"),
+    (0xFA35, "\u{5351}"),
+    (0xFA36, "\u{559D}"),
+    (0xFA37, "\u{5606}"),
+    (0xFA38, "\u{5668}"),
+    (0xFA39, "\u{5840}"),
+    (0xFA3A, "\u{58A8}"),
+    (0xFA3B, "\u{5C64}"),
+    (0xFA3C, "\u{5C6E}"),
+    (0xFA3D, "\u{6094}"),
+    (0xFA3E, "\u{6168}"),
+    (0xFA3F, "\u{618E}"),
+    (0xFA40, "\u{61F2}"),
+    (0xFA41, "\u{654F}"),
+    (0xFA42, "\u{65E2}"),
+    (0xFA43, "\u{6691}"),
+    (0xFA44, "\u{6885}"),
+    (0xFA45, "\u{6D77}"),
+    (0xFA46, "\u{6E1A}"),
+    (0xFA47, "\u{6F22}"),
+    (0xFA48, "\u{716E}"),
+    (0xFA49, "\u{722B}"),
+    (0xFA4A, "\u{7422}"),
+    (0xFA4B, "\u{7891}"),
+    (0xFA4C, "\u{793E}"),
+    (0xFA4D, "\u{7949}"),
+    (0xFA4E, "\u{7948}"),
+    (0xFA4F, "\u{7950}"),
+    (0xFA50, "\u{7956}"),
+    (0xFA51, "\u{795D}"),
+    (0xFA52, "\u{798D}"),
+    (0xFA53, "\u{798E}"),
+    (0xFA54, "\u{7A40}"),
+    (0xFA55, "\u{7A81}"),
+    (0xFA56, "\u{7BC0}"),
+    (0xFA57, "\u{7DF4}"),
+    (0xFA58, "\u{7E09}"),
+    (0xFA59, "\u{7E41}"),
+    (0xFA5A, "\u{7F72}"),
+    (0xFA5B, "\u{8005}"),
+    (0xFA5C, "\u{81ED}"),
+    (0xFA5D, "\u{8279}"),
+    (0xFA5E, "\u{8279}"),
+    (0xFA5F, "\u{8457}"),
+    (0xFA60, "\u{8910}"),
+    (0xFA61, "\u{8996}"),
+    (0xFA62, "\u{8B01}"),
+    (0xFA63, "\u{8B39}"),
+    (0xFA64, "\u{8CD3}"),
+    (0xFA65, "\u{8D08}"),
+    (0xFA66, "\u{8FB6}"),
+    (0xFA67, "\u{9038}"),
+    (0xFA68, "\u{96E3}"),
+    (0xFA69, "\u{97FF}"),
+    (0xFA6A, "\u{983B}"),
+    (0xFA6B, "\u{6075}"),
+    (0xFA6C, "\u{242EE}"),
+    (0xFA6D, "\u{8218}"),
+    (0xFA70, "\u{4E26}"),
+    (0xFA71, "\u{51B5}"),
+    (0xFA72, "\u{5168}"),
+    (0xFA73, "\u{4F80}"),
+    (0xFA74, "\u{5145}"),
+    (0xFA75, "\u{5180}"),
+    (0xFA76, "\u{52C7}"),
+    (0xFA77, "\u{52FA}"),
+    (0xFA78, "\u{559D}"),
+    (0xFA79, "\u{5555}"),
+    (0xFA7A, "\u{5599}"),
+    (0xFA7B, "\u{55E2}"),
+    (0xFA7C, "\u{585A}"),
+    (0xFA7D, "\u{58B3}"),
+    (0xFA7E, "\u{5944}"),
+    (0xFA7F, "\u{5954}"),
+    (0xFA80, "\u{5A62}"),
+    (0xFA81, "\u{5B28}"),
+    (0xFA82, "\u{5ED2}"),
+    (0xFA83, "\u{5ED9}"),
+    (0xFA84, "\u{5F69}"),
+    (0xFA85, "\u{5FAD}"),
+    (0xFA86, "\u{60D8}"),
+    (0xFA87, "\u{614E}"),
+    (0xFA88, "\u{6108}"),
+    (0xFA89, "\u{618E}"),
+    (0xFA8A, "\u{6160}"),
+    (0xFA8B, "\u{61F2}"),
+    (0xFA8C, "\u{6234}"),
+    (0xFA8D, "\u{63C4}"),
+    (0xFA8E, "\u{641C}"),
+    (0xFA8F, "\u{6452}"),
+    (0xFA90, "\u{6556}"),
+    (0xFA91, "\u{6674}"),
+    (0xFA92, "\u{6717}"),
+    (0xFA93, "\u{671B}"),
+    (0xFA94, "\u{6756}"),
+    (0xFA95, "\u{6B79}"),
+    (0xFA96, "\u{6BBA}"),
+    (0xFA97, "\u{6D41}"),
+    (0xFA98, "\u{6EDB}"),
+    (0xFA99, "\u{6ECB}"),
+    (0xFA9A, "\u{6F22}"),
+    (0xFA9B, "\u{701E}"),
+    (0xFA9C, "\u{716E}"),
+    (0xFA9D, "\u{77A7}"),
+    (0xFA9E, "\u{7235}"),
+    (0xFA9F, "\u{72AF}"),
+    (0xFAA0, "\u{732A}"),
+    (0xFAA1, "\u{7471}"),
+    (0xFAA2, "\u{7506}"),
+    (0xFAA3, "\u{753B}"),
+    (0xFAA4, "\u{761D}"),
+    (0xFAA5, "\u{761F}"),
+    (0xFAA6, "\u{76CA}"),
+    (0xFAA7, "\u{76DB}"),
+    (0xFAA8, "\u{76F4}"),
+    (0xFAA9, "\u{774A}"),
+    (0xFAAA, "\u{7740}"),
+    (0xFAAB, "\u{78CC}"),
+    (0xFAAC, "\u{7AB1}"),
+    (0xFAAD, "\u{7BC0}"),
+    (0xFAAE, "\u{7C7B}"),
+    (0xFAAF, "\u{7D5B}"),
+    (0xFAB0, "\u{7DF4}"),
+    (0xFAB1, "\u{7F3E}"),
+    (0xFAB2, "\u{8005}"),
+    (0xFAB3, "\u{8352}"),
+    (0xFAB4, "\u{83EF}"),
+    (0xFAB5, "\u{8779}"),
+    (0xFAB6, "\u{8941}"),
+    (0xFAB7, "\u{8986}"),
+    (0xFAB8, "\u{8996}"),
+    (0xFAB9, "\u{8ABF}"),
+    (0xFABA, "\u{8AF8}"),
+    (0xFABB, "\u{8ACB}"),
+    (0xFABC, "\u{8B01}"),
+    (0xFABD, "\u{8AFE}"),
+    (0xFABE, "\u{8AED}"),
+    (0xFABF, "\u{8B39}"),
+    (0xFAC0, "\u{8B8A}"),
+    (0xFAC1, "\u{8D08}"),
+    (0xFAC2, "\u{8F38}"),
+    (0xFAC3, "\u{9072}"),
+    (0xFAC4, "\u{9199}"),
+    (0xFAC5, "\u{9276}"),
+    (0xFAC6, "\u{967C}"),
+    (0xFAC7, "\u{96E3}"),
+    (0xFAC8, "\u{9756}"),
+    (0xFAC9, "\u{97DB}"),
+    (0xFACA, "\u{97FF}"),
+    (0xFACB, "\u{980B}"),
+    (0xFACC, "\u{983B}"),
+    (0xFACD, "\u{9B12}"),
+    (0xFACE, "\u{9F9C}"),
+    (0xFACF, "\u{2284A}"),
+    (0xFAD0, "\u{22844}"),
+    (0xFAD1, "\u{233D5}"),
+    (0xFAD2, "\u{3B9D}"),
+    (0xFAD3, "\u{4018}"),
+    (0xFAD4, "\u{4039}"),
+    (0xFAD5, "\u{25249}"),
+    (0xFAD6, "\u{25CD0}"),
+    (0xFAD7, "\u{27ED3}"),
+    (0xFAD8, "\u{9F43}"),
+    (0xFAD9, "\u{9F8E}"),
+    (0xFB00, "\u{66}\u{66}"),
+    (0xFB01, "\u{66}\u{69}"),
+    (0xFB02, "\u{66}\u{6C}"),
+    (0xFB03, "\u{66}\u{66}\u{69}"),
+    (0xFB04, "\u{66}\u{66}\u{6C}"),
+    (0xFB06, "\u{73}\u{74}"),
+    (0xFB13, "\u{574}\u{576}"),
+    (0xFB14, "\u{574}\u{565}"),
+    (0xFB15, "\u{574}\u{56B}"),
+    (0xFB16, "\u{57E}\u{576}"),
+    (0xFB17, "\u{574}\u{56D}"),
+    (0xFB20, "\u{5E2}"),
+    (0xFB21, "\u{5D0}"),
+    (0xFB22, "\u{5D3}"),
+    (0xFB23, "\u{5D4}"),
+    (0xFB24, "\u{5DB}"),
+    (0xFB25, "\u{5DC}"),
+    (0xFB26, "\u{5DD}"),
+    (0xFB27, "\u{5E8}"),
+    (0xFB28, "\u{5EA}"),
+    (0xFB29, "\u{2D}\u{307}"),
+    (0xFB2B, "\u{FB2A}"),
+    (0xFB2D, "\u{FB2C}"),
+    (0xFB2F, "\u{FB2E}"),
+    (0xFB30, "\u{FB2E}"),
+    (0xFB39, "\u{FB1D}"),
+    (0xFB49, "\u{FB2A}"),
+    (0xFB4F, "\u{5D0}\u{5DC}"),
+    (0xFB50, "\u{671}"),
+    (0xFB51, "\u{671}"),
+    (0xFB52, "\u{67B}"),
+    (0xFB53, "\u{67B}"),
+    (0xFB54, "\u{67B}"),
+    (0xFB55, "\u{67B}"),
+    (0xFB56, "\u{649}\u{6DB}"),
+    (0xFB57, "\u{649}\u{6DB}"),
+    (0xFB58, "\u{649}\u{6DB}"),
+    (0xFB59, "\u{649}\u{6DB}"),
+    (0xFB5A, "\u{680}"),
+    (0xFB5B, "\u{680}"),
+    (0xFB5C, "\u{680}"),
+    (0xFB5D, "\u{680}"),
+    (0xFB5E, "\u{67A}"),
+    (0xFB5F, "\u{67A}"),
+    (0xFB60, "\u{67A}"),
+    (0xFB61, "\u{67A}"),
+    (0xFB62, "\u{67F}"),
+    (0xFB63, "\u{67F}"),
+    (0xFB64, "\u{67F}"),
+    (0xFB65, "\u{67F}"),
+    (0xFB66, "\u{649}\u{615}"),
+    (0xFB67, "\u{649}\u{615}"),
+    (0xFB68, "\u{649}\u{615}"),
+    (0xFB69, "\u{649}\u{615}"),
+    (0xFB6A, "\u{6A1}\u{6DB}"),
+    (0xFB6B, "\u{6A1}\u{6DB}"),
+    (0xFB6C, "\u{6A1}\u{6DB}"),
+    (0xFB6D, "\u{6A1}\u{6DB}"),
+    (0xFB6E, "\u{6A6}"),
+    (0xFB6F, "\u{6A6}"),
+    (0xFB70, "\u{6A6}"),
+    (0xFB71, "\u{6A6}"),
+    (0xFB72, "\u{684}"),
+    (0xFB73, "\u{684}"),
+    (0xFB74, "\u{684}"),
+    (0xFB75, "\u{684}"),
+    (0xFB76, "\u{683}"),
+    (0xFB77, "\u{683}"),
+    (0xFB78, "\u{683}"),
+    (0xFB79, "\u{683}"),
+    (0xFB7A, "\u{686}"),
+    (0xFB7B, "\u{686}"),
+    (0xFB7C, "\u{686}"),
+    (0xFB7D, "\u{686}"),
+    (0xFB7E, "\u{687}"),
+    (0xFB7F, "\u{687}"),
+    (0xFB80, "\u{687}"),
+    (0xFB81, "\u{687}"),
+    (0xFB82, "\u{68D}"),
+    (0xFB83, "\u{68D}"),
+    (0xFB84, "\u{68C}"),
+    (0xFB85, "\u{68C}"),
+    (0xFB86, "\u{62F}\u{6DB}"),
+    (0xFB87, "\u{62F}\u{6DB}"),
+    (0xFB88, "\u{62F}\u{615}"),
+    (0xFB89, "\u{62F}\u{615}"),
+    (0xFB8A, "\u{631}\u{6DB}"),
+    (0xFB8B, "\u{631}\u{6DB}"),
+    (0xFB8C, "\u{631}\u{615}"),
+    (0xFB8D, "\u{631}\u{615}"),
+    (0xFB8E, "\u{643}"),
+    (0xFB8F, "\u{643}"),
+    (0xFB90, "\u{643}"),
+    (0xFB91, "\u{643}"),
+    (0xFB92, "\u{6AF}"),
+    (0xFB93, "\u{6AF}"),
+    (0xFB94, "\u{6AF}"),
+    (0xFB95, "\u{6AF}"),
+    (0xFB96, "\u{6B3}"),
+    (0xFB97, "\u{6B3}"),
+    (0xFB98, "\u{6B3}"),
+    (0xFB99, "\u{6B3}"),
+    (0xFB9A, "\u{6B1}"),
+    (0xFB9B, "\u{6B1}"),
+    (0xFB9C, "\u{6B1}"),
+    (0xFB9D, "\u{6B1}"),
+    (0xFB9E, "\u{649}"),
+    (0xFB9F, "\u{649}"),
+    (0xFBA0, "\u{649}\u{615}"),
+    (0xFBA1, "\u{649}\u{615}"),
+    (0xFBA2, "\u{649}\u{615}"),
+    (0xFBA3, "\u{649}\u{615}"),
+    (0xFBA4, "\u{6C0}"),
+    (0xFBA5, "\u{6C0}"),
+    (0xFBA6, "\u{6F}"),
+    (0xFBA7, "\u{6F}"),
+    (0xFBA8, "\u{6F}"),
+    (0xFBA9, "\u{6F}"),
+    (0xFBAA, "\u{6F}"),
+    (0xFBAB, "\u{6F}"),
+    (0xFBAC, "\u{6F}"),
+    (0xFBAD, "\u{6F}"),
+    (0xFBAE, "\u{649}"),
+    (0xFBAF, "\u{649}"),
+    (0xFBB0, "\u{6D3}"),
+    (0xFBB1, "\u{6D3}"),
+    (0xFBD3, "\u{643}\u{6DB}"),
+    (0xFBD4, "\u{643}\u{6DB}"),
+    (0xFBD5, "\u{643}\u{6DB}"),
+    (0xFBD6, "\u{643}\u{6DB}"),
+    (0xFBD7, "\u{648}\u{313}"),
+    (0xFBD8, "\u{648}\u{313}"),
+    (0xFBD9, "\u{648}\u{306}"),
+    (0xFBDA, "\u{648}\u{306}"),
+    (0xFBDB, "\u{648}\u{670}"),
+    (0xFBDC, "\u{648}\u{670}"),
+    (0xFBDD, "\u{648}\u{313}\u{674}"),
+    (0xFBDE, "\u{648}\u{6DB}"),
+    (0xFBDF, "\u{648}\u{6DB}"),
+    (0xFBE0, "\u{6C5}"),
+    (0xFBE1, "\u{6C5}"),
+    (0xFBE2, "\u{648}\u{302}"),
+    (0xFBE3, "\u{648}\u{302}"),
+    (0xFBE4, "\u{67B}"),
+    (0xFBE5, "\u{67B}"),
+    (0xFBE6, "\u{67B}"),
+    (0xFBE7, "\u{67B}"),
+    (0xFBE8, "\u{649}"),
+    (0xFBE9, "\u{649}"),
+    (0xFBEA, "\u{649}\u{674}\u{6C}"),
+    (0xFBEB, "\u{649}\u{674}\u{6C}"),
+    (0xFBEC, "\u{649}\u{674}\u{6F}"),
+    (0xFBED, "\u{649}\u{674}\u{6F}"),
+    (0xFBEE, "\u{649}\u{674}\u{648}"),
+    (0xFBEF, "\u{649}\u{674}\u{648}"),
+    (0xFBF0, "\u{649}\u{674}\u{648}\u{313}"),
+    (0xFBF1, "\u{649}\u{674}\u{648}\u{313}"),
+    (0xFBF2, "\u{649}\u{674}\u{648}\u{306}"),
+    (0xFBF3, "\u{649}\u{674}\u{648}\u{306}"),
+    (0xFBF4, "\u{649}\u{674}\u{648}\u{670}"),
+    (0xFBF5, "\u{649}\u{674}\u{648}\u{670}"),
+    (0xFBF6, "\u{649}\u{674}\u{67B}"),
+    (0xFBF7, "\u{649}\u{674}\u{67B}"),
+    (0xFBF8, "\u{649}\u{674}\u{67B}"),
+    (0xFBF9, "\u{649}\u{674}\u{649}"),
+    (0xFBFA, "\u{649}\u{674}\u{649}"),
+    (0xFBFB, "\u{649}\u{674}\u{649}"),
+    (0xFBFC, "\u{649}"),
+    (0xFBFD, "\u{649}"),
+    (0xFBFE, "\u{649}"),
+    (0xFBFF, "\u{649}"),
+    (0xFC00, "\u{649}\u{674}\u{62C}"),
+    (0xFC01, "\u{649}\u{674}\u{62D}"),
+    (0xFC02, "\u{649}\u{674}\u{645}"),
+    (0xFC03, "\u{649}\u{674}\u{649}"),
+    (0xFC04, "\u{649}\u{674}\u{649}"),
+    (0xFC05, "\u{628}\u{62C}"),
+    (0xFC06, "\u{628}\u{62D}"),
+    (0xFC07, "\u{628}\u{62E}"),
+    (0xFC08, "\u{628}\u{645}"),
+    (0xFC09, "\u{628}\u{649}"),
+    (0xFC0A, "\u{628}\u{649}"),
+    (0xFC0B, "\u{62A}\u{62C}"),
+    (0xFC0C, "\u{62A}\u{62D}"),
+    (0xFC0D, "\u{62A}\u{62E}"),
+    (0xFC0E, "\u{62A}\u{645}"),
+    (0xFC0F, "\u{62A}\u{649}"),
+    (0xFC10, "\u{62A}\u{649}"),
+    (0xFC11, "\u{649}\u{6DB}\u{62C}"),
+    (0xFC12, "\u{649}\u{6DB}\u{645}"),
+    (0xFC13, "\u{649}\u{6DB}\u{649}"),
+    (0xFC14, "\u{649}\u{6DB}\u{649}"),
+    (0xFC15, "\u{62C}\u{62D}"),
+    (0xFC16, "\u{62C}\u{645}"),
+    (0xFC17, "\u{62D}\u{62C}"),
+    (0xFC18, "\u{62D}\u{645}"),
+    (0xFC19, "\u{62E}\u{62C}"),
+    (0xFC1A, "\u{62E}\u{62D}"),
+    (0xFC1B, "\u{62E}\u{645}"),
+    (0xFC1C, "\u{633}\u{62C}"),
+    (0xFC1D, "\u{633}\u{62D}"),
+    (0xFC1E, "\u{633}\u{62E}"),
+    (0xFC1F, "\u{633}\u{645}"),
+    (0xFC20, "\u{635}\u{62D}"),
+    (0xFC21, "\u{635}\u{645}"),
+    (0xFC22, "\u{636}\u{62C}"),
+    (0xFC23, "\u{636}\u{62D}"),
+    (0xFC24, "\u{636}\u{62E}"),
+    (0xFC25, "\u{636}\u{645}"),
+    (0xFC26, "\u{637}\u{62D}"),
+    (0xFC27, "\u{637}\u{645}"),
+    (0xFC28, "\u{638}\u{645}"),
+    (0xFC29, "\u{639}\u{62C}"),
+    (0xFC2A, "\u{639}\u{645}"),
+    (0xFC2B, "\u{63A}\u{62C}"),
+    (0xFC2C, "\u{63A}\u{645}"),
+    (0xFC2D, "\u{641}\u{62C}"),
+    (0xFC2E, "\u{641}\u{62D}"),
+    (0xFC2F, "\u{641}\u{62E}"),
+    (0xFC30, "\u{641}\u{645}"),
+    (0xFC31, "\u{641}\u{649}"),
+    (0xFC32, "\u{641}\u{649}"),
+    (0xFC33, "\u{642}\u{62D}"),
+    (0xFC34, "\u{642}\u{645}"),
+    (0xFC35, "\u{642}\u{649}"),
+    (0xFC36, "\u{642}\u{649}"),
+    (0xFC37, "\u{643}\u{6C}"),
+    (0xFC38, "\u{643}\u{62C}"),
+    (0xFC39, "\u{643}\u{62D}"),
+    (0xFC3A, "\u{643}\u{62E}"),
+    (0xFC3B, "\u{643}\u{644}"),
+    (0xFC3C, "\u{643}\u{645}"),
+    (0xFC3D, "\u{643}\u{649}"),
+    (0xFC3E, "\u{643}\u{649}"),
+    (0xFC3F, "\u{644}\u{62C}"),
+    (0xFC40, "\u{644}\u{62D}"),
+    (0xFC41, "\u{644}\u{62E}"),
+    (0xFC42, "\u{644}\u{645}"),
+    (0xFC43, "\u{644}\u{649}"),
+    (0xFC44, "\u{644}\u{649}"),
+    (0xFC45, "\u{645}\u{62C}"),
+    (0xFC46, "\u{645}\u{62D}"),
+    (0xFC47, "\u{645}\u{62E}"),
+    (0xFC48, "\u{645}\u{645}"),
+    (0xFC49, "\u{645}\u{649}"),
+    (0xFC4A, "\u{645}\u{649}"),
+    (0xFC4B, "\u{628}\u{62E}"),
+    (0xFC4C, "\u{646}\u{62D}"),
+    (0xFC4D, "\u{646}\u{62E}"),
+    (0xFC4E, "\u{646}\u{645}"),
+    (0xFC4F, "\u{646}\u{649}"),
+    (0xFC50, "\u{646}\u{649}"),
+    (0xFC51, "\u{6F}\u{62C}"),
+    (0xFC52, "\u{6F}\u{645}"),
+    (0xFC53, "\u{6F}\u{649}"),
+    (0xFC54, "\u{6F}\u{649}"),
+    (0xFC55, "\u{649}\u{62C}"),
+    (0xFC56, "\u{649}\u{62D}"),
+    (0xFC57, "\u{649}\u{62E}"),
+    (0xFC58, "\u{649}\u{645}"),
+    (0xFC59, "\u{649}\u{649}"),
+    (0xFC5A, "\u{649}\u{649}"),
+    (0xFC5B, "\u{630}\u{670}"),
+    (0xFC5C, "\u{631}\u{670}"),
+    (0xFC5D, "\u{649}\u{670}"),
+    (0xFC5E, "\u{FE72}\u{651}"),
+    (0xFC5F, "\u{FE74}\u{651}"),
+    (0xFC60, "\u{FE76}\u{651}"),
+    (0xFC61, "\u{FE78}\u{651}"),
+    (0xFC62, "\u{FE7A}\u{651}"),
+    (0xFC63, "\u{FE7C}\u{670}"),
+    (0xFC64, "\u{649}\u{674}\u{631}"),
+    (0xFC65, "\u{649}\u{674}\u{632}"),
+    (0xFC66, "\u{649}\u{674}\u{645}"),
+    (0xFC67, "\u{649}\u{674}\u{646}"),
+    (0xFC68, "\u{649}\u{674}\u{649}"),
+    (0xFC69, "\u{649}\u{674}\u{649}"),
+    (0xFC6A, "\u{628}\u{631}"),
+    (0xFC6B, "\u{628}\u{632}"),
+    (0xFC6C, "\u{628}\u{645}"),
+    (0xFC6D, "\u{628}\u{646}"),
+    (0xFC6E, "\u{628}\u{649}"),
+    (0xFC6F, "\u{628}\u{649}"),
+    (0xFC70, "\u{62A}\u{631}"),
+    (0xFC71, "\u{62A}\u{632}"),
+    (0xFC72, "\u{62A}\u{645}"),
+    (0xFC73, "\u{62A}\u{646}"),
+    (0xFC74, "\u{62A}\u{649}"),
+    (0xFC75, "\u{62A}\u{649}"),
+    (0xFC76, "\u{649}\u{6DB}\u{631}"),
+    (0xFC77, "\u{649}\u{6DB}\u{632}"),
+    (0xFC78, "\u{649}\u{6DB}\u{645}"),
+    (0xFC79, "\u{649}\u{6DB}\u{646}"),
+    (0xFC7A, "\u{649}\u{6DB}\u{649}"),
+    (0xFC7B, "\u{649}\u{6DB}\u{649}"),
+    (0xFC7C, "\u{641}\u{649}"),
+    (0xFC7D, "\u{641}\u{649}"),
+    (0xFC7E, "\u{642}\u{649}"),
+    (0xFC7F, "\u{642}\u{649}"),
+    (0xFC80, "\u{643}\u{6C}"),
+    (0xFC81, "\u{643}\u{644}"),
+    (0xFC82, "\u{643}\u{645}"),
+    (0xFC83, "\u{643}\u{649}"),
+    (0xFC84, "\u{643}\u{649}"),
+    (0xFC85, "\u{644}\u{645}"),
+    (0xFC86, "\u{644}\u{649}"),
+    (0xFC87, "\u{644}\u{649}"),
+    (0xFC88, "\u{645}\u{6C}"),
+    (0xFC89, "\u{645}\u{645}"),
+    (0xFC8A, "\u{646}\u{631}"),
+    (0xFC8B, "\u{646}\u{632}"),
+    (0xFC8C, "\u{646}\u{645}"),
+    (0xFC8D, "\u{646}\u{646}"),
+    (0xFC8E, "\u{646}\u{649}"),
+    (0xFC8F, "\u{646}\u{649}"),
+    (0xFC90, "\u{649}\u{670}"),
+    (0xFC91, "\u{649}\u{631}"),
+    (0xFC92, "\u{649}\u{632}"),
+    (0xFC93, "\u{649}\u{645}"),
+    (0xFC94, "\u{649}\u{646}"),
+    (0xFC95, "\u{649}\u{649}"),
+    (0xFC96, "\u{649}\u{649}"),
+    (0xFC97, "\u{649}\u{674}\u{62C}"),
+    (0xFC98, "\u{649}\u{674}\u{62D}"),
+    (0xFC99, "\u{649}\u{674}\u{62E}"),
+    (0xFC9A, "\u{649}\u{674}\u{645}"),
+    (0xFC9B, "\u{649}\u{674}\u{6F}"),
+    (0xFC9C, "\u{628}\u{62C}"),
+    (0xFC9D, "\u{628}\u{62D}"),
+    (0xFC9E, "\u{628}\u{62E}"),
+    (0xFC9F, "\u{628}\u{645}"),
+    (0xFCA0, "\u{628}\u{6F}"),
+    (0xFCA1, "\u{62A}\u{62C}"),
+    (0xFCA2, "\u{62A}\u{62D}"),
+    (0xFCA3, "\u{62A}\u{62E}"),
+    (0xFCA4, "\u{62A}\u{645}"),
+    (0xFCA5, "\u{62A}\u{6F}"),
+    (0xFCA6, "\u{649}\u{6DB}\u{645}"),
+    (0xFCA7, "\u{62C}\u{62D}"),
+    (0xFCA8, "\u{62C}\u{645}"),
+    (0xFCA9, "\u{62D}\u{62C}"),
+    (0xFCAA, "\u{62D}\u{645}"),
+    (0xFCAB, "\u{62E}\u{62C}"),
+    (0xFCAC, "\u{62E}\u{645}"),
+    (0xFCAD, "\u{633}\u{62C}"),
+    (0xFCAE, "\u{633}\u{62D}"),
+    (0xFCAF, "\u{633}\u{62E}"),
+    (0xFCB0, "\u{633}\u{645}"),
+    (0xFCB1, "\u{635}\u{62D}"),
+    (0xFCB2, "\u{635}\u{62E}"),
+    (0xFCB3, "\u{635}\u{645}"),
+    (0xFCB4, "\u{636}\u{62C}"),
+    (0xFCB5, "\u{636}\u{62D}"),
+    (0xFCB6, "\u{636}\u{62E}"),
+    (0xFCB7, "\u{636}\u{645}"),
+    (0xFCB8, "\u{637}\u{62D}"),
+    (0xFCB9, "\u{638}\u{645}"),
+    (0xFCBA, "\u{639}\u{62C}"),
+    (0xFCBB, "\u{639}\u{645}"),
+    (0xFCBC, "\u{63A}\u{62C}"),
+    (0xFCBD, "\u{63A}\u{645}"),
+    (0xFCBE, "\u{641}\u{62C}"),
+    (0xFCBF, "\u{641}\u{62D}"),
+    (0xFCC0, "\u{641}\u{62E}"),
+    (0xFCC1, "\u{641}\u{645}"),
+    (0xFCC2, "\u{642}\u{62D}"),
+    (0xFCC3, "\u{642}\u{645}"),
+    (0xFCC4, "\u{643}\u{62C}"),
+    (0xFCC5, "\u{643}\u{62D}"),
+    (0xFCC6, "\u{643}\u{62E}"),
+    (0xFCC7, "\u{643}\u{644}"),
+    (0xFCC8, "\u{643}\u{645}"),
+    (0xFCC9, "\u{644}\u{62C}"),
+    (0xFCCA, "\u{644}\u{62D}"),
+    (0xFCCB, "\u{644}\u{62E}"),
+    (0xFCCC, "\u{644}\u{645}"),
+    (0xFCCD, "\u{644}\u{6F}"),
+    (0xFCCE, "\u{645}\u{62C}"),
+    (0xFCCF, "\u{645}\u{62D}"),
+    (0xFCD0, "\u{645}\u{62E}"),
+    (0xFCD1, "\u{645}\u{645}"),
+    (0xFCD2, "\u{628}\u{62E}"),
+    (0xFCD3, "\u{646}\u{62D}"),
+    (0xFCD4, "\u{646}\u{62E}"),
+    (0xFCD5, "\u{646}\u{645}"),
+    (0xFCD6, "\u{646}\u{6F}"),
+    (0xFCD7, "\u{6F}\u{62C}"),
+    (0xFCD8, "\u{6F}\u{645}"),
+    (0xFCD9, "\u{6F}\u{670}"),
+    (0xFCDA, "\u{649}\u{62C}"),
+    (0xFCDB, "\u{649}\u{62D}"),
+    (0xFCDC, "\u{649}\u{62E}"),
+    (0xFCDD, "\u{649}\u{645}"),
+    (0xFCDE, "\u{649}\u{6F}"),
+    (0xFCDF, "\u{649}\u{674}\u{645}"),
+    (0xFCE0, "\u{649}\u{674}\u{6F}"),
+    (0xFCE1, "\u{628}\u{645}"),
+    (0xFCE2, "\u{628}\u{6F}"),
+    (0xFCE3, "\u{62A}\u{645}"),
+    (0xFCE4, "\u{62A}\u{6F}"),
+    (0xFCE5, "\u{649}\u{6DB}\u{645}"),
+    (0xFCE6, "\u{649}\u{6DB}\u{6F}"),
+    (0xFCE7, "\u{633}\u{645}"),
+    (0xFCE8, "\u{633}\u{6F}"),
+    (0xFCE9, "\u{633}\u{6DB}\u{645}"),
+    (0xFCEA, "\u{633}\u{6DB}\u{6F}"),
+    (0xFCEB, "\u{643}\u{644}"),
+    (0xFCEC, "\u{643}\u{645}"),
+    (0xFCED, "\u{644}\u{645}"),
+    (0xFCEE, "\u{646}\u{645}"),
+    (0xFCEF, "\u{646}\u{6F}"),
+    (0xFCF0, "\u{649}\u{645}"),
+    (0xFCF1, "\u{649}\u{6F}"),
+    (0xFCF2, "\u{FE77}\u{651}"),
+    (0xFCF3, "\u{FE79}\u{651}"),
+    (0xFCF4, "\u{FE7B}\u{651}"),
+    (0xFCF5, "\u{637}\u{649}"),
+    (0xFCF6, "\u{637}\u{649}"),
+    (0xFCF7, "\u{639}\u{649}"),
+    (0xFCF8, "\u{639}\u{649}"),
+    (0xFCF9, "\u{63A}\u{649}"),
+    (0xFCFA, "\u{63A}\u{649}"),
+    (0xFCFB, "\u{633}\u{649}"),
+    (0xFCFC, "\u{633}\u{649}"),
+    (0xFCFD, "\u{633}\u{6DB}\u{649}"),
+    (0xFCFE, "\u{633}\u{6DB}\u{649}"),
+    (0xFCFF, "\u{62D}\u{649}"),
+    (0xFD00, "\u{62D}\u{649}"),
+    (0xFD01, "\u{62C}\u{649}"),
+    (0xFD02, "\u{62C}\u{649}"),
+    (0xFD03, "\u{62E}\u{649}"),
+    (0xFD04, "\u{62E}\u{649}"),
+    (0xFD05, "\u{635}\u{649}"),
+    (0xFD06, "\u{635}\u{649}"),
+    (0xFD07, "\u{636}\u{649}"),
+    (0xFD08, "\u{636}\u{649}"),
+    (0xFD09, "\u{633}\u{6DB}\u{62C}"),
+    (0xFD0A, "\u{633}\u{6DB}\u{62D}"),
+    (0xFD0B, "\u{633}\u{6DB}\u{62E}"),
+    (0xFD0C, "\u{633}\u{6DB}\u{645}"),
+    (0xFD0D, "\u{633}\u{6DB}\u{631}"),
+    (0xFD0E, "\u{633}\u{631}"),
+    (0xFD0F, "\u{635}\u{631}"),
+    (0xFD10, "\u{636}\u{631}"),
+    (0xFD11, "\u{637}\u{649}"),
+    (0xFD12, "\u{637}\u{649}"),
+    (0xFD13, "\u{639}\u{649}"),
+    (0xFD14, "\u{639}\u{649}"),
+    (0xFD15, "\u{63A}\u{649}"),
+    (0xFD16, "\u{63A}\u{649}"),
+    (0xFD17, "\u{633}\u{649}"),
+    (0xFD18, "\u{633}\u{649}"),
+    (0xFD19, "\u{633}\u{6DB}\u{649}"),
+    (0xFD1A, "\u{633}\u{6DB}\u{649}"),
+    (0xFD1B, "\u{62D}\u{649}"),
+    (0xFD1C, "\u{62D}\u{649}"),
+    (0xFD1D, "\u{62C}\u{649}"),
+    (0xFD1E, "\u{62C}\u{649}"),
+    (0xFD1F, "\u{62E}\u{649}"),
+    (0xFD20, "\u{62E}\u{649}"),
+    (0xFD21, "\u{635}\u{649}"),
+    (0xFD22, "\u{635}\u{649}"),
+    (0xFD23, "\u{636}\u{649}"),
+    (0xFD24, "\u{636}\u{649}"),
+    (0xFD25, "\u{633}\u{6DB}\u{62C}"),
+    (0xFD26, "\u{633}\u{6DB}\u{62D}"),
+    (0xFD27, "\u{633}\u{6DB}\u{62E}"),
+    (0xFD28, "\u{633}\u{6DB}\u{645}"),
+    (0xFD29, "\u{633}\u{6DB}\u{631}"),
+    (0xFD2A, "\u{633}\u{631}"),
+    (0xFD2B, "\u{635}\u{631}"),
+    (0xFD2C, "\u{636}\u{631}"),
+    (0xFD2D, "\u{633}\u{6DB}\u{62C}"),
+    (0xFD2E, "\u{633}\u{6DB}\u{62D}"),
+    (0xFD2F, "\u{633}\u{6DB}\u{62E}"),
+    (0xFD30, "\u{633}\u{6DB}\u{645}"),
+    (0xFD31, "\u{633}\u{6F}"),
+    (0xFD32, "\u{633}\u{6DB}\u{6F}"),
+    (0xFD33, "\u{637}\u{645}"),
+    (0xFD34, "\u{633}\u{62C}"),
+    (0xFD35, "\u{633}\u{62D}"),
+    (0xFD36, "\u{633}\u{62E}"),
+    (0xFD37, "\u{633}\u{6DB}\u{62C}"),
+    (0xFD38, "\u{633}\u{6DB}\u{62D}"),
+    (0xFD39, "\u{633}\u{6DB}\u{62E}"),
+    (0xFD3A, "\u{637}\u{645}"),
+    (0xFD3B, "\u{638}\u{645}"),
+    (0xFD3C, "\u{6C}\u{30B}"),
+    (0xFD3D, "\u{6C}\u{30B}"),
+    (0xFD3E, "\u{28}"),
+    (0xFD3F, "\u{29}"),
+    (0xFD50, "\u{62A}\u{62C}\u{645}"),
+    (0xFD51, "\u{62A}\u{62D}\u{62C}"),
+    (0xFD52, "\u{62A}\u{62D}\u{62C}"),
+    (0xFD53, "\u{62A}\u{62D}\u{645}"),
+    (0xFD54, "\u{62A}\u{62E}\u{645}"),
+    (0xFD55, "\u{62A}\u{645}\u{62C}"),
+    (0xFD56, "\u{62A}\u{645}\u{62D}"),
+    (0xFD57, "\u{62A}\u{645}\u{62E}"),
+    (0xFD58, "\u{62C}\u{645}\u{62D}"),
+    (0xFD59, "\u{62C}\u{645}\u{62D}"),
+    (0xFD5A, "\u{62D}\u{645}\u{649}"),
+    (0xFD5B, "\u{62D}\u{645}\u{649}"),
+    (0xFD5C, "\u{633}\u{62D}\u{62C}"),
+    (0xFD5D, "\u{633}\u{62C}\u{62D}"),
+    (0xFD5E, "\u{633}\u{62C}\u{649}"),
+    (0xFD5F, "\u{633}\u{645}\u{62D}"),
+    (0xFD60, "\u{633}\u{645}\u{62D}"),
+    (0xFD61, "\u{633}\u{645}\u{62C}"),
+    (0xFD62, "\u{633}\u{645}\u{645}"),
+    (0xFD63, "\u{633}\u{645}\u{645}"),
+    (0xFD64, "\u{635}\u{62D}\u{62D}"),
+    (0xFD65, "\u{635}\u{62D}\u{62D}"),
+    (0xFD66, "\u{635}\u{645}\u{645}"),
+    (0xFD67, "\u{633}\u{6DB}\u{62D}\u{645}"),
+    (0xFD68, "\u{633}\u{6DB}\u{62D}\u{645}"),
+    (0xFD69, "\u{633}\u{6DB}\u{62C}\u{649}"),
+    (0xFD6A, "\u{633}\u{6DB}\u{645}\u{62E}"),
+    (0xFD6B, "\u{633}\u{6DB}\u{645}\u{62E}"),
+    (0xFD6C, "\u{633}\u{6DB}\u{645}\u{645}"),
+    (0xFD6D, "\u{633}\u{6DB}\u{645}\u{645}"),
+    (0xFD6E, "\u{636}\u{62D}\u{649}"),
+    (0xFD6F, "\u{636}\u{62E}\u{645}"),
+    (0xFD70, "\u{636}\u{62E}\u{645}"),
+    (0xFD71, "\u{637}\u{645}\u{62D}"),
+    (0xFD72, "\u{637}\u{645}\u{62D}"),
+    (0xFD73, "\u{637}\u{645}\u{645}"),
+    (0xFD74, "\u{637}\u{645}\u{649}"),
+    (0xFD75, "\u{639}\u{62C}\u{645}"),
+    (0xFD76, "\u{639}\u{645}\u{645}"),
+    (0xFD77, "\u{639}\u{645}\u{645}"),
+    (0xFD78, "\u{639}\u{645}\u{649}"),
+    (0xFD79, "\u{63A}\u{645}\u{645}"),
+    (0xFD7A, "\u{63A}\u{645}\u{649}"),
+    (0xFD7B, "\u{63A}\u{645}\u{649}"),
+    (0xFD7C, "\u{641}\u{62E}\u{645}"),
+    (0xFD7D, "\u{641}\u{62E}\u{645}"),
+    (0xFD7E, "\u{642}\u{645}\u{62D}"),
+    (0xFD7F, "\u{642}\u{645}\u{645}"),
+    (0xFD80, "\u{644}\u{62D}\u{645}"),
+    (0xFD81, "\u{644}\u{62D}\u{649}"),
+    (0xFD82, "\u{644}\u{62D}\u{649}"),
+    (0xFD83, "\u{644}\u{62C}\u{62C}"),
+    (0xFD84, "\u{644}\u{62C}\u{62C}"),
+    (0xFD85, "\u{644}\u{62E}\u{645}"),
+    (0xFD86, "\u{644}\u{62E}\u{645}"),
+    (0xFD87, "\u{644}\u{645}\u{62D}"),
+    (0xFD88, "\u{644}\u{645}\u{62D}"),
+    (0xFD89, "\u{645}\u{62D}\u{62C}"),
+    (0xFD8A, "\u{645}\u{62D}\u{645}"),
+    (0xFD8B, "\u{645}\u{62D}\u{649}"),
+    (0xFD8C, "\u{645}\u{62C}\u{62D}"),
+    (0xFD8D, "\u{645}\u{62C}\u{645}"),
+    (0xFD8E, "\u{645}\u{62E}\u{62C}"),
+    (0xFD8F, "\u{645}\u{62E}\u{645}"),
+    (0xFD92, "\u{645}\u{62C}\u{62E}"),
+    (0xFD93, "\u{6F}\u{645}\u{62C}"),
+    (0xFD94, "\u{6F}\u{645}\u{645}"),
+    (0xFD95, "\u{646}\u{62D}\u{645}"),
+    (0xFD96, "\u{646}\u{62D}\u{649}"),
+    (0xFD97, "\u{646}\u{62C}\u{645}"),
+    (0xFD98, "\u{646}\u{62C}\u{645}"),
+    (0xFD99, "\u{646}\u{62C}\u{649}"),
+    (0xFD9A, "\u{646}\u{645}\u{649}"),
+    (0xFD9B, "\u{646}\u{645}\u{649}"),
+    (0xFD9C, "\u{649}\u{645}\u{645}"),
+    (0xFD9D, "\u{649}\u{645}\u{645}"),
+    (0xFD9E, "\u{628}\u{62E}\u{649}"),
+    (0xFD9F, "\u{62A}\u{62C}\u{649}"),
+    (0xFDA0, "\u{62A}\u{62C}\u{649}"),
+    (0xFDA1, "\u{62A}\u{62E}\u{649}"),
+    (0xFDA2, "\u{62A}\u{62E}\u{649}"),
+    (0xFDA3, "\u{62A}\u{645}\u{649}"),
+    (0xFDA4, "\u{62A}\u{645}\u{649}"),
+    (0xFDA5, "\u{62C}\u{645}\u{649}"),
+    (0xFDA6, "\u{62C}\u{62D}\u{649}"),
+    (0xFDA7, "\u{62C}\u{645}\u{649}"),
+    (0xFDA8, "\u{633}\u{62E}\u{649}"),
+    (0xFDA9, "\u{635}\u{62D}\u{649}"),
+    (0xFDAA, "\u{633}\u{6DB}\u{62D}\u{649}"),
+    (0xFDAB, "\u{636}\u{62D}\u{649}"),
+    (0xFDAC, "\u{644}\u{62C}\u{649}"),
+    (0xFDAD, "\u{644}\u{645}\u{649}"),
+    (0xFDAE, "\u{649}\u{62D}\u{649}"),
+    (0xFDAF, "\u{649}\u{62C}\u{649}"),
+    (0xFDB0, "\u{649}\u{645}\u{649}"),
+    (0xFDB1, "\u{645}\u{645}\u{649}"),
+    (0xFDB2, "\u{642}\u{645}\u{649}"),
+    (0xFDB3, "\u{646}\u{62D}\u{649}"),
+    (0xFDB4, "\u{642}\u{645}\u{62D}"),
+    (0xFDB5, "\u{644}\u{62D}\u{645}"),
+    (0xFDB6, "\u{639}\u{645}\u{649}"),
+    (0xFDB7, "\u{643}\u{645}\u{649}"),
+    (0xFDB8, "\u{646}\u{62C}\u{62D}"),
+    (0xFDB9, "\u{645}\u{62E}\u{649}"),
+    (0xFDBA, "\u{644}\u{62C}\u{645}"),
+    (0xFDBB, "\u{643}\u{645}\u{645}"),
+    (0xFDBC, "\u{644}\u{62C}\u{645}"),
+    (0xFDBD, "\u{646}\u{62C}\u{62D}"),
+    (0xFDBE, "\u{62C}\u{62D}\u{649}"),
+    (0xFDBF, "\u{62D}\u{62C}\u{649}"),
+    (0xFDC0, "\u{645}\u{62C}\u{649}"),
+    (0xFDC1, "\u{641}\u{645}\u{649}"),
+    (0xFDC2, "\u{628}\u{62D}\u{649}"),
+    (0xFDC3, "\u{643}\u{645}\u{645}"),
+    (0xFDC4, "\u{639}\u{62C}\u{645}"),
+    (0xFDC5, "\u{635}\u{645}\u{645}"),
+    (0xFDC6, "\u{633}\u{62E}\u{649}"),
+    (0xFDC7, "\u{646}\u{62C}\u{649}"),
+    (0xFDF0, "\u{635}\u{644}\u{649}"),
+    (0xFDF1, "\u{642}\u{644}\u{649}"),
+    (0xFDF2, "\u{6C}\u{644}\u{644}\u{651}\u{670}\u{6F}"),
+    (0xFDF3, "\u{6C}\u{643}\u{628}\u{631}"),
+    (0xFDF4, "\u{645}\u{62D}\u{645}\u{62F}"),
+    (0xFDF5, "\u{635}\u{644}\u{639}\u{645}"),
+    (0xFDF6, "\u{631}\u{633}\u{648}\u{644}"),
+    (0xFDF7, "\u{639}\u{644}\u{649}\u{6F}"),
+    (0xFDF8, "\u{648}\u{633}\u{644}\u{645}"),
+    (0xFDF9, "\u{635}\u{644}\u{649}"),
+    (0xFDFA, "\u{635}\u{644}\u{649}\u{20}\u{6C}\u{644}\u{644}\u{6F}\u{20}\u{639}\u{644}\u{649}\u{6F}\u{20}\u{648}\u{633}\u{644}\u{645}"),
+    (0xFDFB, "\u{62C}\u{644}\u{20}\u{62C}\u{644}\u{6C}\u{644}\u{6F}"),
+    (0xFDFC, "\u{631}\u{649}\u{6C}\u{644}"),
+    (0xFE19, "\u{2D57}"),
+    (0xFE30, "\u{3A}"),
+    (0xFE31, "\u{2502}"),
+    (0xFE34, "\u{2307}"),
+    (0xFE35, "\u{23DC}"),
+    (0xFE36, "\u{23DD}"),
+    (0xFE37, "\u{23DE}"),
+    (0xFE38, "\u{23DF}"),
+    (0xFE39, "\u{23E0}"),
+    (0xFE3A, "\u{23E1}"),
+    (0xFE49, "\u{2C9}"),
+    (0xFE4A, "\u{2C9}"),
+    (0xFE4B, "\u{2C9}"),
+    (0xFE4C, "\u{2C9}"),
+    (0xFE4D, "\u{5F}"),
+    (0xFE4E, "\u{5F}"),
+    (0xFE4F, "\u{5F}"),
+    (0xFE58, "\u{2D}"),
+    (0xFE68, "\u{5C}"),
+    (0xFE80, "\u{621}"),
+    (0xFE81, "\u{622}"),
+    (0xFE82, "\u{622}"),
+    (0xFE83, "\u{6C}\u{674}"),
+    (0xFE84, "\u{6C}\u{674}"),
+    (0xFE85, "\u{648}\u{674}"),
+    (0xFE86, "\u{648}\u{674}"),
+    (0xFE87, "\u{6C}\u{655}"),
+    (0xFE88, "\u{6C}\u{655}"),
+    (0xFE89, "\u{649}\u{674}"),
+    (0xFE8A, "\u{649}\u{674}"),
+    (0xFE8B, "\u{649}\u{674}"),
+    (0xFE8C, "\u{649}\u{674}"),
+    (0xFE8D, "\u{6C}"),
+    (0xFE8E, "\u{6C}"),
+    (0xFE8F, "\u{628}"),
+    (0xFE90, "\u{628}"),
+    (0xFE91, "\u{628}"),
+    (0xFE92, "\u{628}"),
+    (0xFE93, "\u{629}"),
+    (0xFE94, "\u{629}"),
+    (0xFE95, "\u{62A}"),
+    (0xFE96, "\u{62A}"),
+    (0xFE97, "\u{62A}"),
+    (0xFE98, "\u{62A}"),
+    (0xFE99, "\u{649}\u{6DB}"),
+    (0xFE9A, "\u{649}\u{6DB}"),
+    (0xFE9B, "\u{649}\u{6DB}"),
+    (0xFE9C, "\u{649}\u{6DB}"),
+    (0xFE9D, "\u{62C}"),
+    (0xFE9E, "\u{62C}"),
+    (0xFE9F, "\u{62C}"),
+    (0xFEA0, "\u{62C}"),
+    (0xFEA1, "\u{62D}"),
+    (0xFEA2, "\u{62D}"),
+    (0xFEA3, "\u{62D}"),
+    (0xFEA4, "\u{62D}"),
+    (0xFEA5, "\u{62E}"),
+    (0xFEA6, "\u{62E}"),
+    (0xFEA7, "\u{62E}"),
+    (0xFEA8, "\u{62E}"),
+    (0xFEA9, "\u{62F}"),
+    (0xFEAA, "\u{62F}"),
+    (0xFEAB, "\u{630}"),
+    (0xFEAC, "\u{630}"),
+    (0xFEAD, "\u{631}"),
+    (0xFEAE, "\u{631}"),
+    (0xFEAF, "\u{632}"),
+    (0xFEB0, "\u{632}"),
+    (0xFEB1, "\u{633}"),
+    (0xFEB2, "\u{633}"),
+    (0xFEB3, "\u{633}"),
+    (0xFEB4, "\u{633}"),
+    (0xFEB5, "\u{633}\u{6DB}"),
+    (0xFEB6, "\u{633}\u{6DB}"),
+    (0xFEB7, "\u{633}\u{6DB}"),
+    (0xFEB8, "\u{633}\u{6DB}"),
+    (0xFEB9, "\u{635}"),
+    (0xFEBA, "\u{635}"),
+    (0xFEBB, "\u{635}"),
+    (0xFEBC, "\u{635}"),
+    (0xFEBD, "\u{636}"),
+    (0xFEBE, "\u{636}"),
+    (0xFEBF, "\u{636}"),
+    (0xFEC0, "\u{636}"),
+    (0xFEC1, "\u{637}"),
+    (0xFEC2, "\u{637}"),
+    (0xFEC3, "\u{637}"),
+    (0xFEC4, "\u{637}"),
+    (0xFEC5, "\u{638}"),
+    (0xFEC6, "\u{638}"),
+    (0xFEC7, "\u{638}"),
+    (0xFEC8, "\u{638}"),
+    (0xFEC9, "\u{639}"),
+    (0xFECA, "\u{639}"),
+    (0xFECB, "\u{639}"),
+    (0xFECC, "\u{639}"),
+    (0xFECD, "\u{63A}"),
+    (0xFECE, "\u{63A}"),
+    (0xFECF, "\u{63A}"),
+    (0xFED0, "\u{63A}"),
+    (0xFED1, "\u{641}"),
+    (0xFED2, "\u{641}"),
+    (0xFED3, "\u{641}"),
+    (0xFED4, "\u{641}"),
+    (0xFED5, "\u{642}"),
+    (0xFED6, "\u{642}"),
+    (0xFED7, "\u{642}"),
+    (0xFED8, "\u{642}"),
+    (0xFED9, "\u{643}"),
+    (0xFEDA, "\u{643}"),
+    (0xFEDB, "\u{643}"),
+    (0xFEDC, "\u{643}"),
+    (0xFEDD, "\u{644}"),
+    (0xFEDE, "\u{644}"),
+    (0xFEDF, "\u{644}"),
+    (0xFEE0, "\u{644}"),
+    (0xFEE1, "\u{645}"),
+    (0xFEE2, "\u{645}"),
+    (0xFEE3, "\u{645}"),
+    (0xFEE4, "\u{645}"),
+    (0xFEE5, "\u{646}"),
+    (0xFEE6, "\u{646}"),
+    (0xFEE7, "\u{646}"),
+    (0xFEE8, "\u{646}"),
+    (0xFEE9, "\u{6F}"),
+    (0xFEEA, "\u{6F}"),
+    (0xFEEB, "\u{6F}"),
+    (0xFEEC, "\u{6F}"),
+    (0xFEED, "\u{648}"),
+    (0xFEEE, "\u{648}"),
+    (0xFEEF, "\u{649}"),
+    (0xFEF0, "\u{649}"),
+    (0xFEF1, "\u{649}"),
+    (0xFEF2, "\u{649}"),
+    (0xFEF3, "\u{649}"),
+    (0xFEF4, "\u{649}"),
+    (0xFEF5, "\u{644}\u{622}"),
+    (0xFEF6, "\u{644}\u{622}"),
+    (0xFEF7, "\u{644}\u{6C}\u{674}"),
+    (0xFEF8, "\u{644}\u{6C}\u{674}"),
+    (0xFEF9, "\u{644}\u{6C}\u{655}"),
+    (0xFEFA, "\u{644}\u{6C}\u{655}"),
+    (0xFEFB, "\u{644}\u{6C}"),
+    (0xFEFC, "\u{644}\u{6C}"),
+    (0xFF01, "\u{21}"),
+    (0xFF02, "\u{27}\u{27}"),
+    (0xFF07, "\u{27}"),
+    (0xFF0D, "\u{30FC}"),
+    (0xFF1A, "\u{3A}"),
+    (0xFF21, "\u{41}"),
+    (0xFF22, "\u{42}"),
+    (0xFF23, "\u{43}"),
+    (0xFF25, "\u{45}"),
+    (0xFF28, "\u{48}"),
+    (0xFF29, "\u{6C}"),
+    (0xFF2A, "\u{4A}"),
+    (0xFF2B, "\u{4B}"),
+    (0xFF2D, "\u{4D}"),
+    (0xFF2E, "\u{4E}"),
+    (0xFF2F, "\u{4F}"),
+    (0xFF30, "\u{50}"),
+    (0xFF33, "\u{53}"),
+    (0xFF34, "\u{54}"),
+    (0xFF38, "\u{58}"),
+    (0xFF39, "\u{59}"),
+    (0xFF3A, "\u{5A}"),
+    (0xFF3B, "\u{28}"),
+    (0xFF3C, "\u{5C}"),
+    (0xFF3D, "\u{29}"),
+    (0xFF3E, "\u{FE3F}"),
+    (0xFF40, "\u{27}"),
+    (0xFF41, "\u{61}"),
+    (0xFF43, "\u{63}"),
+    (0xFF45, "\u{65}"),
+    (0xFF47, "\u{67}"),
+    (0xFF48, "\u{68}"),
+    (0xFF49, "\u{69}"),
+    (0xFF4A, "\u{6A}"),
+    (0xFF4C, "\u{6C}"),
+    (0xFF4F, "\u{6F}"),
+    (0xFF50, "\u{70}"),
+    (0xFF53, "\u{73}"),
+    (0xFF56, "\u{76}"),
+    (0xFF58, "\u{78}"),
+    (0xFF59, "\u{79}"),
+    (0xFF5C, "\u{2502}"),
+    (0xFF5E, "\u{301C}"),
+    (0xFF65, "\u{B7}"),
+    (0xFFE3, "\u{2C9}"),
+    (0xFFE8, "\u{6C}"),
+    (0xFFED, "\u{25AA}"),
+    (0x10101, "\u{B7}"),
+    (0x1018E, "\u{4E}\u{30A}"),
+    (0x10196, "\u{58}\u{335}"),
+    (0x10197, "\u{56}\u{335}"),
+    (0x10198, "\u{6C}\u{335}\u{6C}\u{335}\u{53}\u{335}"),
+    (0x10199, "\u{6C}\u{335}\u{6C}\u{335}"),
+    (0x101A0, "\u{2CE8}"),
+    (0x10282, "\u{42}"),
+    (0x10285, "\u{394}"),
+    (0x10286, "\u{45}"),
+    (0x10287, "\u{46}"),
+    (0x1028A, "\u{6C}"),
+    (0x1028D, "\u{245}"),
+    (0x10290, "\u{58}"),
+    (0x10292, "\u{4F}"),
+    (0x10294, "\u{16DC}"),
+    (0x10295, "\u{50}"),
+    (0x10296, "\u{53}"),
+    (0x10297, "\u{54}"),
+    (0x1029B, "\u{2B}"),
+    (0x102A0, "\u{41}"),
+    (0x102A1, "\u{42}"),
+    (0x102A2, "\u{43}"),
+    (0x102A3, "\u{394}"),
+    (0x102A5, "\u{46}"),
+    (0x102AB, "\u{4F}"),
+    (0x102AD, "\u{3D8}"),
+    (0x102B0, "\u{4D}"),
+    (0x102B1, "\u{54}"),
+    (0x102B2, "\u{59}"),
+    (0x102B3, "\u{3A6}"),
+    (0x102B4, "\u{58}"),
+    (0x102B5, "\u{3A8}"),
+    (0x102B6, "\u{3A9}"),
+    (0x102B8, "\u{2D40}"),
+    (0x102CF, "\u{48}"),
+    (0x102E1, "\u{62F}"),
+    (0x102E4, "\u{648}"),
+    (0x102E8, "\u{637}"),
+    (0x102F2, "\u{635}"),
+    (0x102F5, "\u{5A}"),
+    (0x10301, "\u{42}"),
+    (0x10302, "\u{43}"),
+    (0x10309, "\u{6C}"),
+    (0x10311, "\u{4D}"),
+    (0x10312, "\u{3D8}"),
+    (0x10315, "\u{54}"),
+    (0x10317, "\u{58}"),
+    (0x1031A, "\u{38}"),
+    (0x1031F, "\u{2A}"),
+    (0x10320, "\u{6C}"),
+    (0x10322, "\u{58}"),
+    (0x103D1, "\u{10382}"),
+    (0x103D3, "\u{10393}"),
+    (0x10401, "\u{190}"),
+    (0x10404, "\u{4F}"),
+    (0x10411, "\u{A4F6}"),
+    (0x10415, "\u{43}"),
+    (0x1041B, "\u{4C}"),
+    (0x1041F, "\u{2C70}"),
+    (0x10420, "\u{53}"),
+    (0x10423, "\u{186}"),
+    (0x10425, "\u{418}"),
+    (0x10429, "\u{A793}"),
+    (0x1042A, "\u{29A}"),
+    (0x1042C, "\u{6F}"),
+    (0x1043D, "\u{63}"),
+    (0x1043F, "\u{277}"),
+    (0x10442, "\u{25E}"),
+    (0x10443, "\u{29F}"),
+    (0x10448, "\u{73}"),
+    (0x1044B, "\u{254}"),
+    (0x1044D, "\u{1D0E}"),
+    (0x104A0, "\u{10486}"),
+    (0x104B0, "\u{245}"),
+    (0x104B4, "\u{52}"),
+    (0x104BC, "\u{4C3}"),
+    (0x104C2, "\u{4F}"),
+    (0x104C3, "\u{298}"),
+    (0x104C4, "\u{DE}"),
+    (0x104CD, "\u{40B}"),
+    (0x104CE, "\u{55}"),
+    (0x104D0, "\u{16E6}"),
+    (0x104D1, "\u{3A8}"),
+    (0x104D2, "\u{37}"),
+    (0x104D8, "\u{28C}"),
+    (0x104DB, "\u{3BB}"),
+    (0x104EA, "\u{6F}"),
+    (0x104EB, "\u{A669}"),
+    (0x104F6, "\u{75}"),
+    (0x104F9, "\u{3C8}"),
+    (0x10513, "\u{4E}"),
+    (0x10516, "\u{4F}"),
+    (0x10518, "\u{4B}"),
+    (0x1051C, "\u{43}"),
+    (0x1051D, "\u{56}"),
+    (0x10525, "\u{46}"),
+    (0x10526, "\u{4C}"),
+    (0x10527, "\u{58}"),
+    (0x10A3A, "\u{323}"),
+    (0x10A50, "\u{2E}"),
+    (0x10A57, "\u{10A56}\u{10A56}"),
+    (0x10CFA, "\u{10CA5}"),
+    (0x10CFC, "\u{10C82}"),
+    (0x110BB, "\u{970}"),
+    (0x111C7, "\u{970}"),
+    (0x111CA, "\u{323}"),
+    (0x111CB, "\u{93A}"),
+    (0x111DB, "\u{A8FC}"),
+    (0x111DC, "\u{A8FB}"),
+    (0x111DE, "\u{2248}"),
+    (0x11300, "\u{30A}"),
+    (0x11413, "\u{11434}\u{11442}\u{11412}"),
+    (0x11419, "\u{11434}\u{11442}\u{11418}"),
+    (0x11424, "\u{11434}\u{11442}\u{11423}"),
+    (0x1142A, "\u{11434}\u{11442}\u{11429}"),
+    (0x1142D, "\u{11434}\u{11442}\u{1142C}"),
+    (0x1142F, "\u{11434}\u{11442}\u{1142E}"),
+    (0x1144C, "\u{1144B}\u{1144B}"),
+    (0x11492, "\u{998}"),
+    (0x11494, "\u{99A}"),
+    (0x11496, "\u{99C}"),
+    (0x11498, "\u{99E}"),
+    (0x11499, "\u{99F}"),
+    (0x1149B, "\u{9A1}"),
+    (0x1149D, "\u{9B2}"),
+    (0x1149E, "\u{9A4}"),
+    (0x1149F, "\u{9A5}"),
+    (0x114A0, "\u{9A6}"),
+    (0x114A1, "\u{9A7}"),
+    (0x114A2, "\u{9A8}"),
+    (0x114A3, "\u{9AA}"),
+    (0x114A7, "\u{9AE}"),
+    (0x114A8, "\u{9AF}"),
+    (0x114A9, "\u{9AC}"),
+    (0x114AA, "\u{9A3}"),
+    (0x114AB, "\u{9B0}"),
+    (0x114AD, "\u{9B7}"),
+    (0x114AE, "\u{9B8}"),
+    (0x114B0, "\u{9BE}"),
+    (0x114B1, "\u{9BF}"),
+    (0x114B9, "\u{9C7}"),
+    (0x114BC, "\u{9CB}"),
+    (0x114BD, "\u{9D7}"),
+    (0x114BE, "\u{9CC}"),
+    (0x114BF, "\u{306}\u{307}"),
+    (0x114C1, "\u{983}"),
+    (0x114C2, "\u{9CD}"),
+    (0x114C3, "\u{323}"),
+    (0x114C4, "\u{9BD}"),
+    (0x114C5, "\u{77}\u{307}"),
+    (0x114D0, "\u{4F}"),
+    (0x114D1, "\u{9E7}"),
+    (0x114D2, "\u{9E8}"),
+    (0x114D6, "\u{9EC}"),
+    (0x115D8, "\u{11582}"),
+    (0x115D9, "\u{11582}"),
+    (0x115DA, "\u{11583}"),
+    (0x115DB, "\u{11584}"),
+    (0x115DC, "\u{115B2}"),
+    (0x115DD, "\u{115B3}"),
+    (0x11642, "\u{11641}\u{11641}"),
+    (0x11700, "\u{72}\u{6E}"),
+    (0x11706, "\u{76}"),
+    (0x1170A, "\u{77}"),
+    (0x1170E, "\u{77}"),
+    (0x1170F, "\u{77}"),
+    (0x118A0, "\u{56}"),
+    (0x118A2, "\u{46}"),
+    (0x118A3, "\u{4C}"),
+    (0x118A4, "\u{59}"),
+    (0x118A6, "\u{45}"),
+    (0x118A8, "\u{2207}"),
+    (0x118A9, "\u{5A}"),
+    (0x118AC, "\u{39}"),
+    (0x118AE, "\u{45}"),
+    (0x118AF, "\u{34}"),
+    (0x118B2, "\u{4C}"),
+    (0x118B5, "\u{4F}"),
+    (0x118B7, "\u{16DC}"),
+    (0x118B8, "\u{55}"),
+    (0x118BB, "\u{35}"),
+    (0x118BC, "\u{54}"),
+    (0x118C0, "\u{76}"),
+    (0x118C1, "\u{73}"),
+    (0x118C2, "\u{46}"),
+    (0x118C3, "\u{69}"),
+    (0x118C4, "\u{7A}"),
+    (0x118C6, "\u{37}"),
+    (0x118C8, "\u{6F}"),
+    (0x118CA, "\u{33}"),
+    (0x118CC, "\u{39}"),
+    (0x118CE, "\u{A793}"),
+    (0x118D5, "\u{36}"),
+    (0x118D6, "\u{39}"),
+    (0x118D7, "\u{6F}"),
+    (0x118D8, "\u{75}"),
+    (0x118DC, "\u{79}"),
+    (0x118E0, "\u{4F}"),
+    (0x118E3, "\u{72}\u{6E}"),
+    (0x118E4, "\u{669}"),
+    (0x118E5, "\u{5A}"),
+    (0x118E6, "\u{57}"),
+    (0x118E9, "\u{43}"),
+    (0x118EC, "\u{58}"),
+    (0x118EF, "\u{57}"),
+    (0x118F2, "\u{43}"),
+    (0x11AE6, "\u{11AE5}\u{11AEF}"),
+    (0x11AE7, "\u{11AE5}\u{11AF0}"),
+    (0x11AE8, "\u{11AE5}\u{11AE5}"),
+    (0x11AE9, "\u{11AE5}\u{11AE5}\u{11AEF}"),
+    (0x11AEA, "\u{11AE5}\u{11AE5}\u{11AF0}"),
+    (0x11AEC, "\u{11AEB}\u{11AEF}"),
+    (0x11AED, "\u{11AEB}\u{11AEB}"),
+    (0x11AEE, "\u{11AEB}\u{11AEB}\u{11AEF}"),
+    (0x11AF4, "\u{11AF3}\u{11AEF}"),
+    (0x11AF5, "\u{11AF3}\u{11AF0}"),
+    (0x11AF6, "\u{11AF3}\u{11AF3}"),
+    (0x11AF7, "\u{11AF3}\u{11AF3}\u{11AEF}"),
+    (0x11AF8, "\u{11AF3}\u{11AF3}\u{11AF0}"),
+    (0x11C42, "\u{11C41}\u{11C41}"),
+    (0x11CB2, "\u{11CAA}"),
+    (0x12038, "\u{1039A}"),
+    (0x132F9, "\u{1099E}"),
+    (0x16F07, "\u{393}"),
+    (0x16F08, "\u{56}"),
+    (0x16F0A, "\u{54}"),
+    (0x16F16, "\u{4C}"),
+    (0x16F1A, "\u{394}"),
+    (0x16F1C, "\u{A658}"),
+    (0x16F26, "\u{A4F6}"),
+    (0x16F28, "\u{6C}"),
+    (0x16F2D, "\u{190}"),
+    (0x16F35, "\u{52}"),
+    (0x16F3A, "\u{53}"),
+    (0x16F3B, "\u{33}"),
+    (0x16F3D, "\u{245}"),
+    (0x16F3F, "\u{3E}"),
+    (0x16F40, "\u{41}"),
+    (0x16F42, "\u{55}"),
+    (0x16F43, "\u{59}"),
+    (0x16F51, "\u{27}"),
+    (0x16F52, "\u{27}"),
+    (0x1CCD6, "\u{41}"),
+    (0x1CCD7, "\u{42}"),
+    (0x1CCD8, "\u{43}"),
+    (0x1CCD9, "\u{44}"),
+    (0x1CCDA, "\u{45}"),
+    (0x1CCDB, "\u{46}"),
+    (0x1CCDC, "\u{47}"),
+    (0x1CCDD, "\u{48}"),
+    (0x1CCDE, "\u{6C}"),
+    (0x1CCDF, "\u{4A}"),
+    (0x1CCE0, "\u{4B}"),
+    (0x1CCE1, "\u{4C}"),
+    (0x1CCE2, "\u{4D}"),
+    (0x1CCE3, "\u{4E}"),
+    (0x1CCE4, "\u{4F}"),
+    (0x1CCE5, "\u{50}"),
+    (0x1CCE6, "\u{51}"),
+    (0x1CCE7, "\u{52}"),
+    (0x1CCE8, "\u{53}"),
+    (0x1CCE9, "\u{54}"),
+    (0x1CCEA, "\u{55}"),
+    (0x1CCEB, "\u{56}"),
+    (0x1CCEC, "\u{57}"),
+    (0x1CCED, "\u{58}"),
+    (0x1CCEE, "\u{59}"),
+    (0x1CCEF, "\u{5A}"),
+    (0x1CCF0, "\u{4F}"),
+    (0x1CCF1, "\u{6C}"),
+    (0x1CCF2, "\u{32}"),
+    (0x1CCF3, "\u{33}"),
+    (0x1CCF4, "\u{34}"),
+    (0x1CCF5, "\u{35}"),
+    (0x1CCF6, "\u{36}"),
+    (0x1CCF7, "\u{37}"),
+    (0x1CCF8, "\u{38}"),
+    (0x1CCF9, "\u{39}"),
+    (0x1D114, "\u{7B}"),
+    (0x1D16D, "\u{2E}"),
+    (0x1D202, "\u{4FE}"),
+    (0x1D206, "\u{33}"),
+    (0x1D20B, "\u{418}"),
+    (0x1D20D, "\u{56}"),
+    (0x1D20F, "\u{5C}"),
+    (0x1D212, "\u{37}"),
+    (0x1D213, "\u{46}"),
+    (0x1D214, "\u{102BC}"),
+    (0x1D215, "\u{A4F6}"),
+    (0x1D216, "\u{52}"),
+    (0x1D217, "\u{2C6F}"),
+    (0x1D21A, "\u{4F}\u{335}"),
+    (0x1D21B, "\u{2144}"),
+    (0x1D21C, "\u{A4D5}"),
+    (0x1D221, "\u{190}"),
+    (0x1D222, "\u{460}"),
+    (0x1D22A, "\u{4C}"),
+    (0x1D22B, "\u{A4F6}"),
+    (0x1D230, "\u{A7FB}"),
+    (0x1D236, "\u{3C}"),
+    (0x1D237, "\u{3E}"),
+    (0x1D238, "\u{228F}"),
+    (0x1D239, "\u{2290}"),
+    (0x1D23A, "\u{2F}"),
+    (0x1D23B, "\u{5C}"),
+    (0x1D23F, "\u{16CB}"),
+    (0x1D245, "\u{548}"),
+    (0x1D400, "\u{41}"),
+    (0x1D401, "\u{42}"),
+    (0x1D402, "\u{43}"),
+    (0x1D403, "\u{44}"),
+    (0x1D404, "\u{45}"),
+    (0x1D405, "\u{46}"),
+    (0x1D406, "\u{47}"),
+    (0x1D407, "\u{48}"),
+    (0x1D408, "\u{6C}"),
+    (0x1D409, "\u{4A}"),
+    (0x1D40A, "\u{4B}"),
+    (0x1D40B, "\u{4C}"),
+    (0x1D40C, "\u{4D}"),
+    (0x1D40D, "\u{4E}"),
+    (0x1D40E, "\u{4F}"),
+    (0x1D40F, "\u{50}"),
+    (0x1D410, "\u{51}"),
+    (0x1D411, "\u{52}"),
+    (0x1D412, "\u{53}"),
+    (0x1D413, "\u{54}"),
+    (0x1D414, "\u{55}"),
+    (0x1D415, "\u{56}"),
+    (0x1D416, "\u{57}"),
+    (0x1D417, "\u{58}"),
+    (0x1D418, "\u{59}"),
+    (0x1D419, "\u{5A}"),
+    (0x1D41A, "\u{61}"),
+    (0x1D41B, "\u{62}"),
+    (0x1D41C, "\u{63}"),
+    (0x1D41D, "\u{64}"),
+    (0x1D41E, "\u{65}"),
+    (0x1D41F, "\u{66}"),
+    (0x1D420, "\u{67}"),
+    (0x1D421, "\u{68}"),
+    (0x1D422, "\u{69}"),
+    (0x1D423, "\u{6A}"),
+    (0x1D424, "\u{6B}"),
+    (0x1D425, "\u{6C}"),
+    (0x1D426, "\u{72}\u{6E}"),
+    (0x1D427, "\u{6E}"),
+    (0x1D428, "\u{6F}"),
+    (0x1D429, "\u{70}"),
+    (0x1D42A, "\u{71}"),
+    (0x1D42B, "\u{72}"),
+    (0x1D42C, "\u{73}"),
+    (0x1D42D, "\u{74}"),
+    (0x1D42E, "\u{75}"),
+    (0x1D42F, "\u{76}"),
+    (0x1D430, "\u{77}"),
+    (0x1D431, "\u{78}"),
+    (0x1D432, "\u{79}"),
+    (0x1D433, "\u{7A}"),
+    (0x1D434, "\u{41}"),
+    (0x1D435, "\u{42}"),
+    (0x1D436, "\u{43}"),
+    (0x1D437, "\u{44}"),
+    (0x1D438, "\u{45}"),
+    (0x1D439, "\u{46}"),
+    (0x1D43A, "\u{47}"),
+    (0x1D43B, "\u{48}"),
+    (0x1D43C, "\u{6C}"),
+    (0x1D43D, "\u{4A}"),
+    (0x1D43E, "\u{4B}"),
+    (0x1D43F, "\u{4C}"),
+    (0x1D440, "\u{4D}"),
+    (0x1D441, "\u{4E}"),
+    (0x1D442, "\u{4F}"),
+    (0x1D443, "\u{50}"),
+    (0x1D444, "\u{51}"),
+    (0x1D445, "\u{52}"),
+    (0x1D446, "\u{53}"),
+    (0x1D447, "\u{54}"),
+    (0x1D448, "\u{55}"),
+    (0x1D449, "\u{56}"),
+    (0x1D44A, "\u{57}"),
+    (0x1D44B, "\u{58}"),
+    (0x1D44C, "\u{59}"),
+    (0x1D44D, "\u{5A}"),
+    (0x1D44E, "\u{61}"),
+    (0x1D44F, "\u{62}"),
+    (0x1D450, "\u{63}"),
+    (0x1D451, "\u{64}"),
+    (0x1D452, "\u{65}"),
+    (0x1D453, "\u{66}"),
+    (0x1D454, "\u{67}"),
+    (0x1D456, "\u{69}"),
+    (0x1D457, "\u{6A}"),
+    (0x1D458, "\u{6B}"),
+    (0x1D459, "\u{6C}"),
+    (0x1D45A, "\u{72}\u{6E}"),
+    (0x1D45B, "\u{6E}"),
+    (0x1D45C, "\u{6F}"),
+    (0x1D45D, "\u{70}"),
+    (0x1D45E, "\u{71}"),
+    (0x1D45F, "\u{72}"),
+    (0x1D460, "\u{73}"),
+    (0x1D461, "\u{74}"),
+    (0x1D462, "\u{75}"),
+    (0x1D463, "\u{76}"),
+    (0x1D464, "\u{77}"),
+    (0x1D465, "\u{78}"),
+    (0x1D466, "\u{79}"),
+    (0x1D467, "\u{7A}"),
+    (0x1D468, "\u{41}"),
+    (0x1D469, "\u{42}"),
+    (0x1D46A, "\u{43}"),
+    (0x1D46B, "\u{44}"),
+    (0x1D46C, "\u{45}"),
+    (0x1D46D, "\u{46}"),
+    (0x1D46E, "\u{47}"),
+    (0x1D46F, "\u{48}"),
+    (0x1D470, "\u{6C}"),
+    (0x1D471, "\u{4A}"),
+    (0x1D472, "\u{4B}"),
+    (0x1D473, "\u{4C}"),
+    (0x1D474, "\u{4D}"),
+    (0x1D475, "\u{4E}"),
+    (0x1D476, "\u{4F}"),
+    (0x1D477, "\u{50}"),
+    (0x1D478, "\u{51}"),
+    (0x1D479, "\u{52}"),
+    (0x1D47A, "\u{53}"),
+    (0x1D47B, "\u{54}"),
+    (0x1D47C, "\u{55}"),
+    (0x1D47D, "\u{56}"),
+    (0x1D47E, "\u{57}"),
+    (0x1D47F, "\u{58}"),
+    (0x1D480, "\u{59}"),
+    (0x1D481, "\u{5A}"),
+    (0x1D482, "\u{61}"),
+    (0x1D483, "\u{62}"),
+    (0x1D484, "\u{63}"),
+    (0x1D485, "\u{64}"),
+    (0x1D486, "\u{65}"),
+    (0x1D487, "\u{66}"),
+    (0x1D488, "\u{67}"),
+    (0x1D489, "\u{68}"),
+    (0x1D48A, "\u{69}"),
+    (0x1D48B, "\u{6A}"),
+    (0x1D48C, "\u{6B}"),
+    (0x1D48D, "\u{6C}"),
+    (0x1D48E, "\u{72}\u{6E}"),
+    (0x1D48F, "\u{6E}"),
+    (0x1D490, "\u{6F}"),
+    (0x1D491, "\u{70}"),
+    (0x1D492, "\u{71}"),
+    (0x1D493, "\u{72}"),
+    (0x1D494, "\u{73}"),
+    (0x1D495, "\u{74}"),
+    (0x1D496, "\u{75}"),
+    (0x1D497, "\u{76}"),
+    (0x1D498, "\u{77}"),
+    (0x1D499, "\u{78}"),
+    (0x1D49A, "\u{79}"),
+    (0x1D49B, "\u{7A}"),
+    (0x1D49C, "\u{41}"),
+    (0x1D49E, "\u{43}"),
+    (0x1D49F, "\u{44}"),
+    (0x1D4A2, "\u{47}"),
+    (0x1D4A5, "\u{4A}"),
+    (0x1D4A6, "\u{4B}"),
+    (0x1D4A9, "\u{4E}"),
+    (0x1D4AA, "\u{4F}"),
+    (0x1D4AB, "\u{50}"),
+    (0x1D4AC, "\u{51}"),
+    (0x1D4AE, "\u{53}"),
+    (0x1D4AF, "\u{54}"),
+    (0x1D4B0, "\u{55}"),
+    (0x1D4B1, "\u{56}"),
+    (0x1D4B2, "\u{57}"),
+    (0x1D4B3, "\u{58}"),
+    (0x1D4B4, "\u{59}"),
+    (0x1D4B5, "\u{5A}"),
+    (0x1D4B6, "\u{61}"),
+    (0x1D4B7, "\u{62}"),
+    (0x1D4B8, "\u{63}"),
+    (0x1D4B9, "\u{64}"),
+    (0x1D4BB, "\u{66}"),
+    (0x1D4BD, "\u{68}"),
+    (0x1D4BE, "\u{69}"),
+    (0x1D4BF, "\u{6A}"),
+    (0x1D4C0, "\u{6B}"),
+    (0x1D4C1, "\u{6C}"),
+    (0x1D4C2, "\u{72}\u{6E}"),
+    (0x1D4C3, "\u{6E}"),
+    (0x1D4C5, "\u{70}"),
+    (0x1D4C6, "\u{71}"),
+    (0x1D4C7, "\u{72}"),
+    (0x1D4C8, "\u{73}"),
+    (0x1D4C9, "\u{74}"),
+    (0x1D4CA, "\u{75}"),
+    (0x1D4CB, "\u{76}"),
+    (0x1D4CC, "\u{77}"),
+    (0x1D4CD, "\u{78}"),
+    (0x1D4CE, "\u{79}"),
+    (0x1D4CF, "\u{7A}"),
+    (0x1D4D0, "\u{41}"),
+    (0x1D4D1, "\u{42}"),
+    (0x1D4D2, "\u{43}"),
+    (0x1D4D3, "\u{44}"),
+    (0x1D4D4, "\u{45}"),
+    (0x1D4D5, "\u{46}"),
+    (0x1D4D6, "\u{47}"),
+    (0x1D4D7, "\u{48}"),
+    (0x1D4D8, "\u{6C}"),
+    (0x1D4D9, "\u{4A}"),
+    (0x1D4DA, "\u{4B}"),
+    (0x1D4DB, "\u{4C}"),
+    (0x1D4DC, "\u{4D}"),
+    (0x1D4DD, "\u{4E}"),
+    (0x1D4DE, "\u{4F}"),
+    (0x1D4DF, "\u{50}"),
+    (0x1D4E0, "\u{51}"),
+    (0x1D4E1, "\u{52}"),
+    (0x1D4E2, "\u{53}"),
+    (0x1D4E3, "\u{54}"),
+    (0x1D4E4, "\u{55}"),
+    (0x1D4E5, "\u{56}"),
+    (0x1D4E6, "\u{57}"),
+    (0x1D4E7, "\u{58}"),
+    (0x1D4E8, "\u{59}"),
+    (0x1D4E9, "\u{5A}"),
+    (0x1D4EA, "\u{61}"),
+    (0x1D4EB, "\u{62}"),
+    (0x1D4EC, "\u{63}"),
+    (0x1D4ED, "\u{64}"),
+    (0x1D4EE, "\u{65}"),
+    (0x1D4EF, "\u{66}"),
+    (0x1D4F0, "\u{67}"),
+    (0x1D4F1, "\u{68}"),
+    (0x1D4F2, "\u{69}"),
+    (0x1D4F3, "\u{6A}"),
+    (0x1D4F4, "\u{6B}"),
+    (0x1D4F5, "\u{6C}"),
+    (0x1D4F6, "\u{72}\u{6E}"),
+    (0x1D4F7, "\u{6E}"),
+    (0x1D4F8, "\u{6F}"),
+    (0x1D4F9, "\u{70}"),
+    (0x1D4FA, "\u{71}"),
+    (0x1D4FB, "\u{72}"),
+    (0x1D4FC, "\u{73}"),
+    (0x1D4FD, "\u{74}"),
+    (0x1D4FE, "\u{75}"),
+    (0x1D4FF, "\u{76}"),
+    (0x1D500, "\u{77}"),
+    (0x1D501, "\u{78}"),
+    (0x1D502, "\u{79}"),
+    (0x1D503, "\u{7A}"),
+    (0x1D504, "\u{41}"),
+    (0x1D505, "\u{42}"),
+    (0x1D507, "\u{44}"),
+    (0x1D508, "\u{45}"),
+    (0x1D509, "\u{46}"),
+    (0x1D50A, "\u{47}"),
+    (0x1D50D, "\u{4A}"),
+    (0x1D50E, "\u{4B}"),
+    (0x1D50F, "\u{4C}"),
+    (0x1D510, "\u{4D}"),
+    (0x1D511, "\u{4E}"),
+    (0x1D512, "\u{4F}"),
+    (0x1D513, "\u{50}"),
+    (0x1D514, "\u{51}"),
+    (0x1D516, "\u{53}"),
+    (0x1D517, "\u{54}"),
+    (0x1D518, "\u{55}"),
+    (0x1D519, "\u{56}"),
+    (0x1D51A, "\u{57}"),
+    (0x1D51B, "\u{58}"),
+    (0x1D51C, "\u{59}"),
+    (0x1D51E, "\u{61}"),
+    (0x1D51F, "\u{62}"),
+    (0x1D520, "\u{63}"),
+    (0x1D521, "\u{64}"),
+    (0x1D522, "\u{65}"),
+    (0x1D523, "\u{66}"),
+    (0x1D524, "\u{67}"),
+    (0x1D525, "\u{68}"),
+    (0x1D526, "\u{69}"),
+    (0x1D527, "\u{6A}"),
+    (0x1D528, "\u{6B}"),
+    (0x1D529, "\u{6C}"),
+    (0x1D52A, "\u{72}\u{6E}"),
+    (0x1D52B, "\u{6E}"),
+    (0x1D52C, "\u{6F}"),
+    (0x1D52D, "\u{70}"),
+    (0x1D52E, "\u{71}"),
+    (0x1D52F, "\u{72}"),
+    (0x1D530, "\u{73}"),
+    (0x1D531, "\u{74}"),
+    (0x1D532, "\u{75}"),
+    (0x1D533, "\u{76}"),
+    (0x1D534, "\u{77}"),
+    (0x1D535, "\u{78}"),
+    (0x1D536, "\u{79}"),
+    (0x1D537, "\u{7A}"),
+    (0x1D538, "\u{41}"),
+    (0x1D539, "\u{42}"),
+    (0x1D53B, "\u{44}"),
+    (0x1D53C, "\u{45}"),
+    (0x1D53D, "\u{46}"),
+    (0x1D53E, "\u{47}"),
+    (0x1D540, "\u{6C}"),
+    (0x1D541, "\u{4A}"),
+    (0x1D542, "\u{4B}"),
+    (0x1D543, "\u{4C}"),
+    (0x1D544, "\u{4D}"),
+    (0x1D546, "\u{4F}"),
+    (0x1D54A, "\u{53}"),
+    (0x1D54B, "\u{54}"),
+    (0x1D54C, "\u{55}"),
+    (0x1D54D, "\u{56}"),
+    (0x1D54E, "\u{57}"),
+    (0x1D54F, "\u{58}"),
+    (0x1D550, "\u{59}"),
+    (0x1D552, "\u{61}"),
+    (0x1D553, "\u{62}"),
+    (0x1D554, "\u{63}"),
+    (0x1D555, "\u{64}"),
+    (0x1D556, "\u{65}"),
+    (0x1D557, "\u{66}"),
+    (0x1D558, "\u{67}"),
+    (0x1D559, "\u{68}"),
+    (0x1D55A, "\u{69}"),
+    (0x1D55B, "\u{6A}"),
+    (0x1D55C, "\u{6B}"),
+    (0x1D55D, "\u{6C}"),
+    (0x1D55E, "\u{72}\u{6E}"),
+    (0x1D55F, "\u{6E}"),
+    (0x1D560, "\u{6F}"),
+    (0x1D561, "\u{70}"),
+    (0x1D562, "\u{71}"),
+    (0x1D563, "\u{72}"),
+    (0x1D564, "\u{73}"),
+    (0x1D565, "\u{74}"),
+    (0x1D566, "\u{75}"),
+    (0x1D567, "\u{76}"),
+    (0x1D568, "\u{77}"),
+    (0x1D569, "\u{78}"),
+    (0x1D56A, "\u{79}"),
+    (0x1D56B, "\u{7A}"),
+    (0x1D56C, "\u{41}"),
+    (0x1D56D, "\u{42}"),
+    (0x1D56E, "\u{43}"),
+    (0x1D56F, "\u{44}"),
+    (0x1D570, "\u{45}"),
+    (0x1D571, "\u{46}"),
+    (0x1D572, "\u{47}"),
+    (0x1D573, "\u{48}"),
+    (0x1D574, "\u{6C}"),
+    (0x1D575, "\u{4A}"),
+    (0x1D576, "\u{4B}"),
+    (0x1D577, "\u{4C}"),
+    (0x1D578, "\u{4D}"),
+    (0x1D579, "\u{4E}"),
+    (0x1D57A, "\u{4F}"),
+    (0x1D57B, "\u{50}"),
+    (0x1D57C, "\u{51}"),
+    (0x1D57D, "\u{52}"),
+    (0x1D57E, "\u{53}"),
+    (0x1D57F, "\u{54}"),
+    (0x1D580, "\u{55}"),
+    (0x1D581, "\u{56}"),
+    (0x1D582, "\u{57}"),
+    (0x1D583, "\u{58}"),
+    (0x1D584, "\u{59}"),
+    (0x1D585, "\u{5A}"),
+    (0x1D586, "\u{61}"),
+    (0x1D587, "\u{62}"),
+    (0x1D588, "\u{63}"),
+    (0x1D589, "\u{64}"),
+    (0x1D58A, "\u{65}"),
+    (0x1D58B, "\u{66}"),
+    (0x1D58C, "\u{67}"),
+    (0x1D58D, "\u{68}"),
+    (0x1D58E, "\u{69}"),
+    (0x1D58F, "\u{6A}"),
+    (0x1D590, "\u{6B}"),
+    (0x1D591, "\u{6C}"),
+    (0x1D592, "\u{72}\u{6E}"),
+    (0x1D593, "\u{6E}"),
+    (0x1D594, "\u{6F}"),
+    (0x1D595, "\u{70}"),
+    (0x1D596, "\u{71}"),
+    (0x1D597, "\u{72}"),
+    (0x1D598, "\u{73}"),
+    (0x1D599, "\u{74}"),
+    (0x1D59A, "\u{75}"),
+    (0x1D59B, "\u{76}"),
+    (0x1D59C, "\u{77}"),
+    (0x1D59D, "\u{78}"),
+    (0x1D59E, "\u{79}"),
+    (0x1D59F, "\u{7A}"),
+    (0x1D5A0, "\u{41}"),
+    (0x1D5A1, "\u{42}"),
+    (0x1D5A2, "\u{43}"),
+    (0x1D5A3, "\u{44}"),
+    (0x1D5A4, "\u{45}"),
+    (0x1D5A5, "\u{46}"),
+    (0x1D5A6, "\u{47}"),
+    (0x1D5A7, "\u{48}"),
+    (0x1D5A8, "\u{6C}"),
+    (0x1D5A9, "\u{4A}"),
+    (0x1D5AA, "\u{4B}"),
+    (0x1D5AB, "\u{4C}"),
+    (0x1D5AC, "\u{4D}"),
+    (0x1D5AD, "\u{4E}"),
+    (0x1D5AE, "\u{4F}"),
+    (0x1D5AF, "\u{50}"),
+    (0x1D5B0, "\u{51}"),
+    (0x1D5B1, "\u{52}"),
+    (0x1D5B2, "\u{53}"),
+    (0x1D5B3, "\u{54}"),
+    (0x1D5B4, "\u{55}"),
+    (0x1D5B5, "\u{56}"),
+    (0x1D5B6, "\u{57}"),
+    (0x1D5B7, "\u{58}"),
+    (0x1D5B8, "\u{59}"),
+    (0x1D5B9, "\u{5A}"),
+    (0x1D5BA, "\u{61}"),
+    (0x1D5BB, "\u{62}"),
+    (0x1D5BC, "\u{63}"),
+    (0x1D5BD, "\u{64}"),
+    (0x1D5BE, "\u{65}"),
+    (0x1D5BF, "\u{66}"),
+    (0x1D5C0, "\u{67}"),
+    (0x1D5C1, "\u{68}"),
+    (0x1D5C2, "\u{69}"),
+    (0x1D5C3, "\u{6A}"),
+    (0x1D5C4, "\u{6B}"),
+    (0x1D5C5, "\u{6C}"),
+    (0x1D5C6, "\u{72}\u{6E}"),
+    (0x1D5C7, "\u{6E}"),
+    (0x1D5C8, "\u{6F}"),
+    (0x1D5C9, "\u{70}"),
+    (0x1D5CA, "\u{71}"),
+    (0x1D5CB, "\u{72}"),
+    (0x1D5CC, "\u{73}"),
+    (0x1D5CD, "\u{74}"),
+    (0x1D5CE, "\u{75}"),
+    (0x1D5CF, "\u{76}"),
+    (0x1D5D0, "\u{77}"),
+    (0x1D5D1, "\u{78}"),
+    (0x1D5D2, "\u{79}"),
+    (0x1D5D3, "\u{7A}"),
+    (0x1D5D4, "\u{41}"),
+    (0x1D5D5, "\u{42}"),
+    (0x1D5D6, "\u{43}"),
+    (0x1D5D7, "\u{44}"),
+    (0x1D5D8, "\u{45}"),
+    (0x1D5D9, "\u{46}"),
+    (0x1D5DA, "\u{47}"),
+    (0x1D5DB, "\u{48}"),
+    (0x1D5DC, "\u{6C}"),
+    (0x1D5DD, "\u{4A}"),
+    (0x1D5DE, "\u{4B}"),
+    (0x1D5DF, "\u{4C}"),
+    (0x1D5E0, "\u{4D}"),
+    (0x1D5E1, "\u{4E}"),
+    (0x1D5E2, "\u{4F}"),
+    (0x1D5E3, "\u{50}"),
+    (0x1D5E4, "\u{51}"),
+    (0x1D5E5, "\u{52}"),
+    (0x1D5E6, "\u{53}"),
+    (0x1D5E7, "\u{54}"),
+    (0x1D5E8, "\u{55}"),
+    (0x1D5E9, "\u{56}"),
+    (0x1D5EA, "\u{57}"),
+    (0x1D5EB, "\u{58}"),
+    (0x1D5EC, "\u{59}"),
+    (0x1D5ED, "\u{5A}"),
+    (0x1D5EE, "\u{61}"),
+    (0x1D5EF, "\u{62}"),
+    (0x1D5F0, "\u{63}"),
+    (0x1D5F1, "\u{64}"),
+    (0x1D5F2, "\u{65}"),
+    (0x1D5F3, "\u{66}"),
+    (0x1D5F4, "\u{67}"),
+    (0x1D5F5, "\u{68}"),
+    (0x1D5F6, "\u{69}"),
+    (0x1D5F7, "\u{6A}"),
+    (0x1D5F8, "\u{6B}"),
+    (0x1D5F9, "\u{6C}"),
+    (0x1D5FA, "\u{72}\u{6E}"),
+    (0x1D5FB, "\u{6E}"),
+    (0x1D5FC, "\u{6F}"),
+    (0x1D5FD, "\u{70}"),
+    (0x1D5FE, "\u{71}"),
+    (0x1D5FF, "\u{72}"),
+    (0x1D600, "\u{73}"),
+    (0x1D601, "\u{74}"),
+    (0x1D602, "\u{75}"),
+    (0x1D603, "\u{76}"),
+    (0x1D604, "\u{77}"),
+    (0x1D605, "\u{78}"),
+    (0x1D606, "\u{79}"),
+    (0x1D607, "\u{7A}"),
+    (0x1D608, "\u{41}"),
+    (0x1D609, "\u{42}"),
+    (0x1D60A, "\u{43}"),
+    (0x1D60B, "\u{44}"),
+    (0x1D60C, "\u{45}"),
+    (0x1D60D, "\u{46}"),
+    (0x1D60E, "\u{47}"),
+    (0x1D60F, "\u{48}"),
+    (0x1D610, "\u{6C}"),
+    (0x1D611, "\u{4A}"),
+    (0x1D612, "\u{4B}"),
+    (0x1D613, "\u{4C}"),
+    (0x1D614, "\u{4D}"),
+    (0x1D615, "\u{4E}"),
+    (0x1D616, "\u{4F}"),
+    (0x1D617, "\u{50}"),
+    (0x1D618, "\u{51}"),
+    (0x1D619, "\u{52}"),
+    (0x1D61A, "\u{53}"),
+    (0x1D61B, "\u{54}"),
+    (0x1D61C, "\u{55}"),
+    (0x1D61D, "\u{56}"),
+    (0x1D61E, "\u{57}"),
+    (0x1D61F, "\u{58}"),
+    (0x1D620, "\u{59}"),
+    (0x1D621, "\u{5A}"),
+    (0x1D622, "\u{61}"),
+    (0x1D623, "\u{62}"),
+    (0x1D624, "\u{63}"),
+    (0x1D625, "\u{64}"),
+    (0x1D626, "\u{65}"),
+    (0x1D627, "\u{66}"),
+    (0x1D628, "\u{67}"),
+    (0x1D629, "\u{68}"),
+    (0x1D62A, "\u{69}"),
+    (0x1D62B, "\u{6A}"),
+    (0x1D62C, "\u{6B}"),
+    (0x1D62D, "\u{6C}"),
+    (0x1D62E, "\u{72}\u{6E}"),
+    (0x1D62F, "\u{6E}"),
+    (0x1D630, "\u{6F}"),
+    (0x1D631, "\u{70}"),
+    (0x1D632, "\u{71}"),
+    (0x1D633, "\u{72}"),
+    (0x1D634, "\u{73}"),
+    (0x1D635, "\u{74}"),
+    (0x1D636, "\u{75}"),
+    (0x1D637, "\u{76}"),
+    (0x1D638, "\u{77}"),
+    (0x1D639, "\u{78}"),
+    (0x1D63A, "\u{79}"),
+    (0x1D63B, "\u{7A}"),
+    (0x1D63C, "\u{41}"),
+    (0x1D63D, "\u{42}"),
+    (0x1D63E, "\u{43}"),
+    (0x1D63F, "\u{44}"),
+    (0x1D640, "\u{45}"),
+    (0x1D641, "\u{46}"),
+    (0x1D642, "\u{47}"),
+    (0x1D643, "\u{48}"),
+    (0x1D644, "\u{6C}"),
+    (0x1D645, "\u{4A}"),
+    (0x1D646, "\u{4B}"),
+    (0x1D647, "\u{4C}"),
+    (0x1D648, "\u{4D}"),
+    (0x1D649, "\u{4E}"),
+    (0x1D64A, "\u{4F}"),
+    (0x1D64B, "\u{50}"),
+    (0x1D64C, "\u{51}"),
+    (0x1D64D, "\u{52}"),
+    (0x1D64E, "\u{53}"),
+    (0x1D64F, "\u{54}"),
+    (0x1D650, "\u{55}"),
+    (0x1D651, "\u{56}"),
+    (0x1D652, "\u{57}"),
+    (0x1D653, "\u{58}"),
+    (0x1D654, "\u{59}"),
+    (0x1D655, "\u{5A}"),
+    (0x1D656, "\u{61}"),
+    (0x1D657, "\u{62}"),
+    (0x1D658, "\u{63}"),
+    (0x1D659, "\u{64}"),
+    (0x1D65A, "\u{65}"),
+    (0x1D65B, "\u{66}"),
+    (0x1D65C, "\u{67}"),
+    (0x1D65D, "\u{68}"),
+    (0x1D65E, "\u{69}"),
+    (0x1D65F, "\u{6A}"),
+    (0x1D660, "\u{6B}"),
+    (0x1D661, "\u{6C}"),
+    (0x1D662, "\u{72}\u{6E}"),
+    (0x1D663, "\u{6E}"),
+    (0x1D664, "\u{6F}"),
+    (0x1D665, "\u{70}"),
+    (0x1D666, "\u{71}"),
+    (0x1D667, "\u{72}"),
+    (0x1D668, "\u{73}"),
+    (0x1D669, "\u{74}"),
+    (0x1D66A, "\u{75}"),
+    (0x1D66B, "\u{76}"),
+    (0x1D66C, "\u{77}"),
+    (0x1D66D, "\u{78}"),
+    (0x1D66E, "\u{79}"),
+    (0x1D66F, "\u{7A}"),
+    (0x1D670, "\u{41}"),
+    (0x1D671, "\u{42}"),
+    (0x1D672, "\u{43}"),
+    (0x1D673, "\u{44}"),
+    (0x1D674, "\u{45}"),
+    (0x1D675, "\u{46}"),
+    (0x1D676, "\u{47}"),
+    (0x1D677, "\u{48}"),
+    (0x1D678, "\u{6C}"),
+    (0x1D679, "\u{4A}"),
+    (0x1D67A, "\u{4B}"),
+    (0x1D67B, "\u{4C}"),
+    (0x1D67C, "\u{4D}"),
+    (0x1D67D, "\u{4E}"),
+    (0x1D67E, "\u{4F}"),
+    (0x1D67F, "\u{50}"),
+    (0x1D680, "\u{51}"),
+    (0x1D681, "\u{52}"),
+    (0x1D682, "\u{53}"),
+    (0x1D683, "\u{54}"),
+    (0x1D684, "\u{55}"),
+    (0x1D685, "\u{56}"),
+    (0x1D686, "\u{57}"),
+    (0x1D687, "\u{58}"),
+    (0x1D688, "\u{59}"),
+    (0x1D689, "\u{5A}"),
+    (0x1D68A, "\u{61}"),
+    (0x1D68B, "\u{62}"),
+    (0x1D68C, "\u{63}"),
+    (0x1D68D, "\u{64}"),
+    (0x1D68E, "\u{65}"),
+    (0x1D68F, "\u{66}"),
+    (0x1D690, "\u{67}"),
+    (0x1D691, "\u{68}"),
+    (0x1D692, "\u{69}"),
+    (0x1D693, "\u{6A}"),
+    (0x1D694, "\u{6B}"),
+    (0x1D695, "\u{6C}"),
+    (0x1D696, "\u{72}\u{6E}"),
+    (0x1D697, "\u{6E}"),
+    (0x1D698, "\u{6F}"),
+    (0x1D699, "\u{70}"),
+    (0x1D69A, "\u{71}"),
+    (0x1D69B, "\u{72}"),
+    (0x1D69C, "\u{73}"),
+    (0x1D69D, "\u{74}"),
+    (0x1D69E, "\u{75}"),
+    (0x1D69F, "\u{76}"),
+    (0x1D6A0, "\u{77}"),
+    (0x1D6A1, "\u{78}"),
+    (0x1D6A2, "\u{79}"),
+    (0x1D6A3, "\u{7A}"),
+    (0x1D6A4, "\u{69}"),
+    (0x1D6A5, "\u{237}"),
+    (0x1D6A8, "\u{41}"),
+    (0x1D6A9, "\u{42}"),
+    (0x1D6AA, "\u{393}"),
+    (0x1D6AB, "\u{394}"),
+    (0x1D6AC, "\u{45}"),
+    (0x1D6AD, "\u{5A}"),
+    (0x1D6AE, "\u{48}"),
+    (0x1D6AF, "\u{4F}\u{335}"),
+    (0x1D6B0, "\u{6C}"),
+    (0x1D6B1, "\u{4B}"),
+    (0x1D6B2, "\u{245}"),
+    (0x1D6B3, "\u{4D}"),
+    (0x1D6B4, "\u{4E}"),
+    (0x1D6B5, "\u{39E}"),
+    (0x1D6B6, "\u{4F}"),
+    (0x1D6B7, "\u{3A0}"),
+    (0x1D6B8, "\u{50}"),
+    (0x1D6B9, "\u{4F}\u{335}"),
+    (0x1D6BA, "\u{1A9}"),
+    (0x1D6BB, "\u{54}"),
+    (0x1D6BC, "\u{59}"),
+    (0x1D6BD, "\u{3A6}"),
+    (0x1D6BE, "\u{58}"),
+    (0x1D6BF, "\u{3A8}"),
+    (0x1D6C0, "\u{3A9}"),
+    (0x1D6C1, "\u{2207}"),
+    (0x1D6C2, "\u{61}"),
+    (0x1D6C3, "\u{DF}"),
+    (0x1D6C4, "\u{79}"),
+    (0x1D6C5, "\u{1E9F}"),
+    (0x1D6C6, "\u{A793}"),
+    (0x1D6C7, "\u{3B6}"),
+    (0x1D6C8, "\u{6E}\u{329}"),
+    (0x1D6C9, "\u{4F}\u{335}"),
+    (0x1D6CA, "\u{69}"),
+    (0x1D6CB, "\u{138}"),
+    (0x1D6CC, "\u{3BB}"),
+    (0x1D6CD, "\u{3BC}"),
+    (0x1D6CE, "\u{76}"),
+    (0x1D6CF, "\u{3BE}"),
+    (0x1D6D0, "\u{6F}"),
+    (0x1D6D1, "\u{3C0}"),
+    (0x1D6D2, "\u{70}"),
+    (0x1D6D3, "\u{3C2}"),
+    (0x1D6D4, "\u{6F}"),
+    (0x1D6D5, "\u{1D1B}"),
+    (0x1D6D6, "\u{75}"),
+    (0x1D6D7, "\u{278}"),
+    (0x1D6D8, "\u{3C7}"),
+    (0x1D6D9, "\u{3C8}"),
+    (0x1D6DA, "\u{3C9}"),
+    (0x1D6DB, "\u{2202}"),
+    (0x1D6DC, "\u{A793}"),
+    (0x1D6DD, "\u{4F}\u{335}"),
+    (0x1D6DE, "\u{138}"),
+    (0x1D6DF, "\u{278}"),
+    (0x1D6E0, "\u{70}"),
+    (0x1D6E1, "\u{3C0}"),
+    (0x1D6E2, "\u{41}"),
+    (0x1D6E3, "\u{42}"),
+    (0x1D6E4, "\u{393}"),
+    (0x1D6E5, "\u{394}"),
+    (0x1D6E6, "\u{45}"),
+    (0x1D6E7, "\u{5A}"),
+    (0x1D6E8, "\u{48}"),
+    (0x1D6E9, "\u{4F}\u{335}"),
+    (0x1D6EA, "\u{6C}"),
+    (0x1D6EB, "\u{4B}"),
+    (0x1D6EC, "\u{245}"),
+    (0x1D6ED, "\u{4D}"),
+    (0x1D6EE, "\u{4E}"),
+    (0x1D6EF, "\u{39E}"),
+    (0x1D6F0, "\u{4F}"),
+    (0x1D6F1, "\u{3A0}"),
+    (0x1D6F2, "\u{50}"),
+    (0x1D6F3, "\u{4F}\u{335}"),
+    (0x1D6F4, "\u{1A9}"),
+    (0x1D6F5, "\u{54}"),
+    (0x1D6F6, "\u{59}"),
+    (0x1D6F7, "\u{3A6}"),
+    (0x1D6F8, "\u{58}"),
+    (0x1D6F9, "\u{3A8}"),
+    (0x1D6FA, "\u{3A9}"),
+    (0x1D6FB, "\u{2207}"),
+    (0x1D6FC, "\u{61}"),
+    (0x1D6FD, "\u{DF}"),
+    (0x1D6FE, "\u{79}"),
+    (0x1D6FF, "\u{1E9F}"),
+    (0x1D700, "\u{A793}"),
+    (0x1D701, "\u{3B6}"),
+    (0x1D702, "\u{6E}\u{329}"),
+    (0x1D703, "\u{4F}\u{335}"),
+    (0x1D704, "\u{69}"),
+    (0x1D705, "\u{138}"),
+    (0x1D706, "\u{3BB}"),
+    (0x1D707, "\u{3BC}"),
+    (0x1D708, "\u{76}"),
+    (0x1D709, "\u{3BE}"),
+    (0x1D70A, "\u{6F}"),
+    (0x1D70B, "\u{3C0}"),
+    (0x1D70C, "\u{70}"),
+    (0x1D70D, "\u{3C2}"),
+    (0x1D70E, "\u{6F}"),
+    (0x1D70F, "\u{1D1B}"),
+    (0x1D710, "\u{75}"),
+    (0x1D711, "\u{278}"),
+    (0x1D712, "\u{3C7}"),
+    (0x1D713, "\u{3C8}"),
+    (0x1D714, "\u{3C9}"),
+    (0x1D715, "\u{2202}"),
+    (0x1D716, "\u{A793}"),
+    (0x1D717, "\u{4F}\u{335}"),
+    (0x1D718, "\u{138}"),
+    (0x1D719, "\u{278}"),
+    (0x1D71A, "\u{70}"),
+    (0x1D71B, "\u{3C0}"),
+    (0x1D71C, "\u{41}"),
+    (0x1D71D, "\u{42}"),
+    (0x1D71E, "\u{393}"),
+    (0x1D71F, "\u{394}"),
+    (0x1D720, "\u{45}"),
+    (0x1D721, "\u{5A}"),
+    (0x1D722, "\u{48}"),
+    (0x1D723, "\u{4F}\u{335}"),
+    (0x1D724, "\u{6C}"),
+    (0x1D725, "\u{4B}"),
+    (0x1D726, "\u{245}"),
+    (0x1D727, "\u{4D}"),
+    (0x1D728, "\u{4E}"),
+    (0x1D729, "\u{39E}"),
+    (0x1D72A, "\u{4F}"),
+    (0x1D72B, "\u{3A0}"),
+    (0x1D72C, "\u{50}"),
+    (0x1D72D, "\u{4F}\u{335}"),
+    (0x1D72E, "\u{1A9}"),
+    (0x1D72F, "\u{54}"),
+    (0x1D730, "\u{59}"),
+    (0x1D731, "\u{3A6}"),
+    (0x1D732, "\u{58}"),
+    (0x1D733, "\u{3A8}"),
+    (0x1D734, "\u{3A9}"),
+    (0x1D735, "\u{2207}"),
+    (0x1D736, "\u{61}"),
+    (0x1D737, "\u{DF}"),
+    (0x1D738, "\u{79}"),
+    (0x1D739, "\u{1E9F}"),
+    (0x1D73A, "\u{A793}"),
+    (0x1D73B, "\u{3B6}"),
+    (0x1D73C, "\u{6E}\u{329}"),
+    (0x1D73D, "\u{4F}\u{335}"),
+    (0x1D73E, "\u{69}"),
+    (0x1D73F, "\u{138}"),
+    (0x1D740, "\u{3BB}"),
+    (0x1D741, "\u{3BC}"),
+    (0x1D742, "\u{76}"),
+    (0x1D743, "\u{3BE}"),
+    (0x1D744, "\u{6F}"),
+    (0x1D745, "\u{3C0}"),
+    (0x1D746, "\u{70}"),
+    (0x1D747, "\u{3C2}"),
+    (0x1D748, "\u{6F}"),
+    (0x1D749, "\u{1D1B}"),
+    (0x1D74A, "\u{75}"),
+    (0x1D74B, "\u{278}"),
+    (0x1D74C, "\u{3C7}"),
+    (0x1D74D, "\u{3C8}"),
+    (0x1D74E, "\u{3C9}"),
+    (0x1D74F, "\u{2202}"),
+    (0x1D750, "\u{A793}"),
+    (0x1D751, "\u{4F}\u{335}"),
+    (0x1D752, "\u{138}"),
+    (0x1D753, "\u{278}"),
+    (0x1D754, "\u{70}"),
+    (0x1D755, "\u{3C0}"),
+    (0x1D756, "\u{41}"),
+    (0x1D757, "\u{42}"),
+    (0x1D758, "\u{393}"),
+    (0x1D759, "\u{394}"),
+    (0x1D75A, "\u{45}"),
+    (0x1D75B, "\u{5A}"),
+    (0x1D75C, "\u{48}"),
+    (0x1D75D, "\u{4F}\u{335}"),
+    (0x1D75E, "\u{6C}"),
+    (0x1D75F, "\u{4B}"),
+    (0x1D760, "\u{245}"),
+    (0x1D761, "\u{4D}"),
+    (0x1D762, "\u{4E}"),
+    (0x1D763, "\u{39E}"),
+    (0x1D764, "\u{4F}"),
+    (0x1D765, "\u{3A0}"),
+    (0x1D766, "\u{50}"),
+    (0x1D767, "\u{4F}\u{335}"),
+    (0x1D768, "\u{1A9}"),
+    (0x1D769, "\u{54}"),
+    (0x1D76A, "\u{59}"),
+    (0x1D76B, "\u{3A6}"),
+    (0x1D76C, "\u{58}"),
+    (0x1D76D, "\u{3A8}"),
+    (0x1D76E, "\u{3A9}"),
+    (0x1D76F, "\u{2207}"),
+    (0x1D770, "\u{61}"),
+    (0x1D771, "\u{DF}"),
+    (0x1D772, "\u{79}"),
+    (0x1D773, "\u{1E9F}"),
+    (0x1D774, "\u{A793}"),
+    (0x1D775, "\u{3B6}"),
+    (0x1D776, "\u{6E}\u{329}"),
+    (0x1D777, "\u{4F}\u{335}"),
+    (0x1D778, "\u{69}"),
+    (0x1D779, "\u{138}"),
+    (0x1D77A, "\u{3BB}"),
+    (0x1D77B, "\u{3BC}"),
+    (0x1D77C, "\u{76}"),
+    (0x1D77D, "\u{3BE}"),
+    (0x1D77E, "\u{6F}"),
+    (0x1D77F, "\u{3C0}"),
+    (0x1D780, "\u{70}"),
+    (0x1D781, "\u{3C2}"),
+    (0x1D782, "\u{6F}"),
+    (0x1D783, "\u{1D1B}"),
+    (0x1D784, "\u{75}"),
+    (0x1D785, "\u{278}"),
+    (0x1D786, "\u{3C7}"),
+    (0x1D787, "\u{3C8}"),
+    (0x1D788, "\u{3C9}"),
+    (0x1D789, "\u{2202}"),
+    (0x1D78A, "\u{A793}"),
+    (0x1D78B, "\u{4F}\u{335}"),
+    (0x1D78C, "\u{138}"),
+    (0x1D78D, "\u{278}"),
+    (0x1D78E, "\u{70}"),
+    (0x1D78F, "\u{3C0}"),
+    (0x1D790, "\u{41}"),
+    (0x1D791, "\u{42}"),
+    (0x1D792, "\u{393}"),
+    (0x1D793, "\u{394}"),
+    (0x1D794, "\u{45}"),
+    (0x1D795, "\u{5A}"),
+    (0x1D796, "\u{48}"),
+    (0x1D797, "\u{4F}\u{335}"),
+    (0x1D798, "\u{6C}"),
+    (0x1D799, "\u{4B}"),
+    (0x1D79A, "\u{245}"),
+    (0x1D79B, "\u{4D}"),
+    (0x1D79C, "\u{4E}"),
+    (0x1D79D, "\u{39E}"),
+    (0x1D79E, "\u{4F}"),
+    (0x1D79F, "\u{3A0}"),
+    (0x1D7A0, "\u{50}"),
+    (0x1D7A1, "\u{4F}\u{335}"),
+    (0x1D7A2, "\u{1A9}"),
+    (0x1D7A3, "\u{54}"),
+    (0x1D7A4, "\u{59}"),
+    (0x1D7A5, "\u{3A6}"),
+    (0x1D7A6, "\u{58}"),
+    (0x1D7A7, "\u{3A8}"),
+    (0x1D7A8, "\u{3A9}"),
+    (0x1D7A9, "\u{2207}"),
+    (0x1D7AA, "\u{61}"),
+    (0x1D7AB, "\u{DF}"),
+    (0x1D7AC, "\u{79}"),
+    (0x1D7AD, "\u{1E9F}"),
+    (0x1D7AE, "\u{A793}"),
+    (0x1D7AF, "\u{3B6}"),
+    (0x1D7B0, "\u{6E}\u{329}"),
+    (0x1D7B1, "\u{4F}\u{335}"),
+    (0x1D7B2, "\u{69}"),
+    (0x1D7B3, "\u{138}"),
+    (0x1D7B4, "\u{3BB}"),
+    (0x1D7B5, "\u{3BC}"),
+    (0x1D7B6, "\u{76}"),
+    (0x1D7B7, "\u{3BE}"),
+    (0x1D7B8, "\u{6F}"),
+    (0x1D7B9, "\u{3C0}"),
+    (0x1D7BA, "\u{70}"),
+    (0x1D7BB, "\u{3C2}"),
+    (0x1D7BC, "\u{6F}"),
+    (0x1D7BD, "\u{1D1B}"),
+    (0x1D7BE, "\u{75}"),
+    (0x1D7BF, "\u{278}"),
+    (0x1D7C0, "\u{3C7}"),
+    (0x1D7C1, "\u{3C8}"),
+    (0x1D7C2, "\u{3C9}"),
+    (0x1D7C3, "\u{2202}"),
+    (0x1D7C4, "\u{A793}"),
+    (0x1D7C5, "\u{4F}\u{335}"),
+    (0x1D7C6, "\u{138}"),
+    (0x1D7C7, "\u{278}"),
+    (0x1D7C8, "\u{70}"),
+    (0x1D7C9, "\u{3C0}"),
+    (0x1D7CA, "\u{46}"),
+    (0x1D7CB, "\u{3DD}"),
+    (0x1D7CE, "\u{4F}"),
+    (0x1D7CF, "\u{6C}"),
+    (0x1D7D0, "\u{32}"),
+    (0x1D7D1, "\u{33}"),
+    (0x1D7D2, "\u{34}"),
+    (0x1D7D3, "\u{35}"),
+    (0x1D7D4, "\u{36}"),
+    (0x1D7D5, "\u{37}"),
+    (0x1D7D6, "\u{38}"),
+    (0x1D7D7, "\u{39}"),
+    (0x1D7D8, "\u{4F}"),
+    (0x1D7D9, "\u{6C}"),
+    (0x1D7DA, "\u{32}"),
+    (0x1D7DB, "\u{33}"),
+    (0x1D7DC, "\u{34}"),
+    (0x1D7DD, "\u{35}"),
+    (0x1D7DE, "\u{36}"),
+    (0x1D7DF, "\u{37}"),
+    (0x1D7E0, "\u{38}"),
+    (0x1D7E1, "\u{39}"),
+    (0x1D7E2, "\u{4F}"),
+    (0x1D7E3, "\u{6C}"),
+    (0x1D7E4, "\u{32}"),
+    (0x1D7E5, "\u{33}"),
+    (0x1D7E6, "\u{34}"),
+    (0x1D7E7, "\u{35}"),
+    (0x1D7E8, "\u{36}"),
+    (0x1D7E9, "\u{37}"),
+    (0x1D7EA, "\u{38}"),
+    (0x1D7EB, "\u{39}"),
+    (0x1D7EC, "\u{4F}"),
+    (0x1D7ED, "\u{6C}"),
+    (0x1D7EE, "\u{32}"),
+    (0x1D7EF, "\u{33}"),
+    (0x1D7F0, "\u{34}"),
+    (0x1D7F1, "\u{35}"),
+    (0x1D7F2, "\u{36}"),
+    (0x1D7F3, "\u{37}"),
+    (0x1D7F4, "\u{38}"),
+    (0x1D7F5, "\u{39}"),
+    (0x1D7F6, "\u{4F}"),
+    (0x1D7F7, "\u{6C}"),
+    (0x1D7F8, "\u{32}"),
+    (0x1D7F9, "\u{33}"),
+    (0x1D7FA, "\u{34}"),
+    (0x1D7FB, "\u{35}"),
+    (0x1D7FC, "\u{36}"),
+    (0x1D7FD, "\u{37}"),
+    (0x1D7FE, "\u{38}"),
+    (0x1D7FF, "\u{39}"),
+    (0x1E8C7, "\u{6C}"),
+    (0x1E8C8, "\u{2220}"),
+    (0x1E8C9, "\u{663}"),
+    (0x1E8CB, "\u{38}"),
+    (0x1E8CC, "\u{2202}"),
+    (0x1E8CD, "\u{2202}\u{335}"),
+    (0x1EE00, "\u{6C}"),
+    (0x1EE01, "\u{628}"),
+    (0x1EE02, "\u{62C}"),
+    (0x1EE03, "\u{62F}"),
+    (0x1EE05, "\u{648}"),
+    (0x1EE06, "\u{632}"),
+    (0x1EE07, "\u{62D}"),
+    (0x1EE08, "\u{637}"),
+    (0x1EE09, "\u{649}"),
+    (0x1EE0A, "\u{643}"),
+    (0x1EE0B, "\u{644}"),
+    (0x1EE0C, "\u{645}"),
+    (0x1EE0D, "\u{646}"),
+    (0x1EE0E, "\u{633}"),
+    (0x1EE0F, "\u{639}"),
+    (0x1EE10, "\u{641}"),
+    (0x1EE11, "\u{635}"),
+    (0x1EE12, "\u{642}"),
+    (0x1EE13, "\u{631}"),
+    (0x1EE14, "\u{633}\u{6DB}"),
+    (0x1EE15, "\u{62A}"),
+    (0x1EE16, "\u{649}\u{6DB}"),
+    (0x1EE17, "\u{62E}"),
+    (0x1EE18, "\u{630}"),
+    (0x1EE19, "\u{636}"),
+    (0x1EE1A, "\u{638}"),
+    (0x1EE1B, "\u{63A}"),
+    (0x1EE1C, "\u{649}"),
+    (0x1EE1D, "\u{649}"),
+    (0x1EE1E, "\u{6A1}"),
+    (0x1EE1F, "\u{6A1}"),
+    (0x1EE21, "\u{628}"),
+    (0x1EE22, "\u{62C}"),
+    (0x1EE24, "\u{6F}"),
+    (0x1EE27, "\u{62D}"),
+    (0x1EE29, "\u{649}"),
+    (0x1EE2A, "\u{643}"),
+    (0x1EE2B, "\u{644}"),
+    (0x1EE2C, "\u{645}"),
+    (0x1EE2D, "\u{646}"),
+    (0x1EE2E, "\u{633}"),
+    (0x1EE2F, "\u{639}"),
+    (0x1EE30, "\u{641}"),
+    (0x1EE31, "\u{635}"),
+    (0x1EE32, "\u{642}"),
+    (0x1EE34, "\u{633}\u{6DB}"),
+    (0x1EE35, "\u{62A}"),
+    (0x1EE36, "\u{649}\u{6DB}"),
+    (0x1EE37, "\u{62E}"),
+    (0x1EE39, "\u{636}"),
+    (0x1EE3B, "\u{63A}"),
+    (0x1EE42, "\u{62C}"),
+    (0x1EE47, "\u{62D}"),
+    (0x1EE49, "\u{649}"),
+    (0x1EE4B, "\u{644}"),
+    (0x1EE4D, "\u{646}"),
+    (0x1EE4E, "\u{633}"),
+    (0x1EE4F, "\u{639}"),
+    (0x1EE51, "\u{635}"),
+    (0x1EE52, "\u{642}"),
+    (0x1EE54, "\u{633}\u{6DB}"),
+    (0x1EE57, "\u{62E}"),
+    (0x1EE59, "\u{636}"),
+    (0x1EE5B, "\u{63A}"),
+    (0x1EE5D, "\u{649}"),
+    (0x1EE5F, "\u{6A1}"),
+    (0x1EE61, "\u{628}"),
+    (0x1EE62, "\u{62C}"),
+    (0x1EE64, "\u{6F}"),
+    (0x1EE67, "\u{62D}"),
+    (0x1EE68, "\u{637}"),
+    (0x1EE69, "\u{649}"),
+    (0x1EE6A, "\u{643}"),
+    (0x1EE6C, "\u{645}"),
+    (0x1EE6D, "\u{646}"),
+    (0x1EE6E, "\u{633}"),
+    (0x1EE6F, "\u{639}"),
+    (0x1EE70, "\u{641}"),
+    (0x1EE71, "\u{635}"),
+    (0x1EE72, "\u{642}"),
+    (0x1EE74, "\u{633}\u{6DB}"),
+    (0x1EE75, "\u{62A}"),
+    (0x1EE76, "\u{649}\u{6DB}"),
+    (0x1EE77, "\u{62E}"),
+    (0x1EE79, "\u{636}"),
+    (0x1EE7A, "\u{638}"),
+    (0x1EE7B, "\u{63A}"),
+    (0x1EE7C, "\u{649}"),
+    (0x1EE7E, "\u{6A1}"),
+    (0x1EE80, "\u{6C}"),
+    (0x1EE81, "\u{628}"),
+    (0x1EE82, "\u{62C}"),
+    (0x1EE83, "\u{62F}"),
+    (0x1EE84, "\u{6F}"),
+    (0x1EE85, "\u{648}"),
+    (0x1EE86, "\u{632}"),
+    (0x1EE87, "\u{62D}"),
+    (0x1EE88, "\u{637}"),
+    (0x1EE89, "\u{649}"),
+    (0x1EE8B, "\u{644}"),
+    (0x1EE8C, "\u{645}"),
+    (0x1EE8D, "\u{646}"),
+    (0x1EE8E, "\u{633}"),
+    (0x1EE8F, "\u{639}"),
+    (0x1EE90, "\u{641}"),
+    (0x1EE91, "\u{635}"),
+    (0x1EE92, "\u{642}"),
+    (0x1EE93, "\u{631}"),
+    (0x1EE94, "\u{633}\u{6DB}"),
+    (0x1EE95, "\u{62A}"),
+    (0x1EE96, "\u{649}\u{6DB}"),
+    (0x1EE97, "\u{62E}"),
+    (0x1EE98, "\u{630}"),
+    (0x1EE99, "\u{636}"),
+    (0x1EE9A, "\u{638}"),
+    (0x1EE9B, "\u{63A}"),
+    (0x1EEA1, "\u{628}"),
+    (0x1EEA2, "\u{62C}"),
+    (0x1EEA3, "\u{62F}"),
+    (0x1EEA5, "\u{648}"),
+    (0x1EEA6, "\u{632}"),
+    (0x1EEA7, "\u{62D}"),
+    (0x1EEA8, "\u{637}"),
+    (0x1EEA9, "\u{649}"),
+    (0x1EEAB, "\u{644}"),
+    (0x1EEAC, "\u{645}"),
+    (0x1EEAD, "\u{646}"),
+    (0x1EEAE, "\u{633}"),
+    (0x1EEAF, "\u{639}"),
+    (0x1EEB0, "\u{641}"),
+    (0x1EEB1, "\u{635}"),
+    (0x1EEB2, "\u{642}"),
+    (0x1EEB3, "\u{631}"),
+    (0x1EEB4, "\u{633}\u{6DB}"),
+    (0x1EEB5, "\u{62A}"),
+    (0x1EEB6, "\u{649}\u{6DB}"),
+    (0x1EEB7, "\u{62E}"),
+    (0x1EEB8, "\u{630}"),
+    (0x1EEB9, "\u{636}"),
+    (0x1EEBA, "\u{638}"),
+    (0x1EEBB, "\u{63A}"),
+    (0x1F100, "\u{4F}\u{2E}"),
+    (0x1F101, "\u{4F}\u{2C}"),
+    (0x1F102, "\u{6C}\u{2C}"),
+    (0x1F103, "\u{32}\u{2C}"),
+    (0x1F104, "\u{33}\u{2C}"),
+    (0x1F105, "\u{34}\u{2C}"),
+    (0x1F106, "\u{35}\u{2C}"),
+    (0x1F107, "\u{36}\u{2C}"),
+    (0x1F108, "\u{37}\u{2C}"),
+    (0x1F109, "\u{38}\u{2C}"),
+    (0x1F10A, "\u{39}\u{2C}"),
+    (0x1F10F, "\u{24}\u{20E0}"),
+    (0x1F110, "\u{28}\u{41}\u{29}"),
+    (0x1F111, "\u{28}\u{42}\u{29}"),
+    (0x1F112, "\u{28}\u{43}\u{29}"),
+    (0x1F113, "\u{28}\u{44}\u{29}"),
+    (0x1F114, "\u{28}\u{45}\u{29}"),
+    (0x1F115, "\u{28}\u{46}\u{29}"),
+    (0x1F116, "\u{28}\u{47}\u{29}"),
+    (0x1F117, "\u{28}\u{48}\u{29}"),
+    (0x1F118, "\u{28}\u{6C}\u{29}"),
+    (0x1F119, "\u{28}\u{4A}\u{29}"),
+    (0x1F11A, "\u{28}\u{4B}\u{29}"),
+    (0x1F11B, "\u{28}\u{4C}\u{29}"),
+    (0x1F11C, "\u{28}\u{4D}\u{29}"),
+    (0x1F11D, "\u{28}\u{4E}\u{29}"),
+    (0x1F11E, "\u{28}\u{4F}\u{29}"),
+    (0x1F11F, "\u{28}\u{50}\u{29}"),
+    (0x1F120, "\u{28}\u{51}\u{29}"),
+    (0x1F121, "\u{28}\u{52}\u{29}"),
+    (0x1F122, "\u{28}\u{53}\u{29}"),
+    (0x1F123, "\u{28}\u{54}\u{29}"),
+    (0x1F124, "\u{28}\u{55}\u{29}"),
+    (0x1F125, "\u{28}\u{56}\u{29}"),
+    (0x1F126, "\u{28}\u{57}\u{29}"),
+    (0x1F127, "\u{28}\u{58}\u{29}"),
+    (0x1F128, "\u{28}\u{59}\u{29}"),
+    (0x1F129, "\u{28}\u{5A}\u{29}"),
+    (0x1F12A, "\u{28}\u{53}\u{29}"),
+    (0x1F16D, "\u{33C4}\u{9}\u{20DD}"),
+    (0x1F16E, "\u{43}\u{20E0}"),
+    (0x1F240, "\u{28}\u{672C}\u{29}"),
+    (0x1F241, "\u{28}\u{4E09}\u{29}"),
+    (0x1F242, "\u{28}\u{4E8C}\u{29}"),
+    (0x1F243, "\u{28}\u{5B89}\u{29}"),
+    (0x1F244, "\u{28}\u{70B9}\u{29}"),
+    (0x1F245, "\u{28}\u{6253}\u{29}"),
+    (0x1F246, "\u{28}\u{76D7}\u{29}"),
+    (0x1F247, "\u{28}\u{52DD}\u{29}"),
+    (0x1F248, "\u{28}\u{6557}\u{29}"),
+    (0x1F312, "\u{263D}"),
+    (0x1F318, "\u{263E}"),
+    (0x1F319, "\u{263D}"),
+    (0x1F700, "\u{51}\u{45}"),
+    (0x1F701, "\u{A658}"),
+    (0x1F702, "\u{394}"),
+    (0x1F704, "\u{102BC}"),
+    (0x1F707, "\u{41}\u{52}"),
+    (0x1F708, "\u{56}\u{1DE4}"),
+    (0x1F70A, "\u{2629}"),
+    (0x1F714, "\u{4F}\u{335}"),
+    (0x1F728, "\u{102A8}"),
+    (0x1F73A, "\u{29DF}"),
+    (0x1F74C, "\u{43}"),
+    (0x1F754, "\u{16DC}"),
+    (0x1F755, "\u{22A1}"),
+    (0x1F75C, "\u{73}\u{73}\u{73}"),
+    (0x1F75E, "\u{224F}"),
+    (0x1F768, "\u{54}"),
+    (0x1F76B, "\u{4D}\u{42}"),
+    (0x1F76C, "\u{56}\u{42}"),
+    (0x1F771, "\u{22A0}"),
+    (0x1FBF0, "\u{4F}"),
+    (0x1FBF1, "\u{6C}"),
+    (0x1FBF2, "\u{32}"),
+    (0x1FBF3, "\u{33}"),
+    (0x1FBF4, "\u{34}"),
+    (0x1FBF5, "\u{35}"),
+    (0x1FBF6, "\u{36}"),
+    (0x1FBF7, "\u{37}"),
+    (0x1FBF8, "\u{38}"),
+    (0x1FBF9, "\u{39}"),
+    (0x21FE8, "\u{276C}"),
+    (0x2F800, "\u{4E3D}"),
+    (0x2F801, "\u{4E38}"),
+    (0x2F802, "\u{4E41}"),
+    (0x2F803, "\u{20122}"),
+    (0x2F804, "\u{4F60}"),
+    (0x2F805, "\u{4FAE}"),
+    (0x2F806, "\u{4FBB}"),
+    (0x2F807, "\u{4F75}"),
+    (0x2F808, "\u{507A}"),
+    (0x2F809, "\u{5099}"),
+    (0x2F80A, "\u{50E7}"),
+    (0x2F80B, "\u{50CF}"),
+    (0x2F80C, "\u{349E}"),
+    (0x2F80D, "\u{2063A}"),
+    (0x2F80E, "\u{514D}"),
+    (0x2F80F, "\u{5154}"),
+    (0x2F810, "\u{5164}"),
+    (0x2F811, "\u{5177}"),
+    (0x2F812, "\u{2051C}"),
+    (0x2F813, "\u{34B9}"),
+    (0x2F814, "\u{5167}"),
+    (0x2F815, "\u{518D}"),
+    (0x2F816, "\u{2054B}"),
+    (0x2F817, "\u{5197}"),
+    (0x2F818, "\u{51A4}"),
+    (0x2F819, "\u{4ECC}"),
+    (0x2F81A, "\u{51AC}"),
+    (0x2F81B, "\u{51B5}"),
+    (0x2F81C, "\u{291DF}"),
+    (0x2F81D, "\u{51F5}"),
+    (0x2F81E, "\u{5203}"),
+    (0x2F81F, "\u{34DF}"),
+    (0x2F820, "\u{523B}"),
+    (0x2F821, "\u{5246}"),
+    (0x2F822, "\u{5272}"),
+    (0x2F823, "\u{5277}"),
+    (0x2F824, "\u{3515}"),
+    (0x2F825, "\u{52C7}"),
+    (0x2F826, "\u{52C9}"),
+    (0x2F827, "\u{52E4}"),
+    (0x2F828, "\u{52FA}"),
+    (0x2F829, "\u{5305}"),
+    (0x2F82A, "\u{5306}"),
+    (0x2F82B, "\u{5317}"),
+    (0x2F82C, "\u{5349}"),
+    (0x2F82D, "\u{5351}"),
+    (0x2F82E, "\u{535A}"),
+    (0x2F82F, "\u{5373}"),
+    (0x2F830, "\u{537D}"),
+    (0x2F831, "\u{537F}"),
+    (0x2F832, "\u{537F}"),
+    (0x2F833, "\u{537F}"),
+    (0x2F834, "\u{20A2C}"),
+    (0x2F835, "\u{7070}"),
+    (0x2F836, "\u{53CA}"),
+    (0x2F837, "\u{53DF}"),
+    (0x2F838, "\u{20B63}"),
+    (0x2F839, "\u{53EB}"),
+    (0x2F83A, "\u{53F1}"),
+    (0x2F83B, "\u{5406}"),
+    (0x2F83C, "\u{549E}"),
+    (0x2F83D, "\u{5438}"),
+    (0x2F83E, "\u{5448}"),
+    (0x2F83F, "\u{5468}"),
+    (0x2F840, "\u{54A2}"),
+    (0x2F841, "\u{54F6}"),
+    (0x2F842, "\u{5510}"),
+    (0x2F843, "\u{5553}"),
+    (0x2F844, "\u{5563}"),
+    (0x2F845, "\u{5584}"),
+    (0x2F846, "\u{5584}"),
+    (0x2F847, "\u{5599}"),
+    (0x2F848, "\u{55AB}"),
+    (0x2F849, "\u{55B3}"),
+    (0x2F84A, "\u{55C2}"),
+    (0x2F84B, "\u{5716}"),
+    (0x2F84C, "\u{5606}"),
+    (0x2F84D, "\u{5717}"),
+    (0x2F84E, "\u{5651}"),
+    (0x2F84F, "\u{5674}"),
+    (0x2F850, "\u{5207}"),
+    (0x2F851, "\u{58EE}"),
+    (0x2F852, "\u{57CE}"),
+    (0x2F853, "\u{57F4}"),
+    (0x2F854, "\u{580D}"),
+    (0x2F855, "\u{578B}"),
+    (0x2F856, "\u{5832}"),
+    (0x2F857, "\u{5831}"),
+    (0x2F858, "\u{58AC}"),
+    (0x2F859, "\u{214E4}"),
+    (0x2F85A, "\u{58F2}"),
+    (0x2F85B, "\u{58F7}"),
+    (0x2F85C, "\u{5906}"),
+    (0x2F85D, "\u{591A}"),
+    (0x2F85E, "\u{5922}"),
+    (0x2F85F, "\u{5962}"),
+    (0x2F860, "\u{216A8}"),
+    (0x2F861, "\u{216EA}"),
+    (0x2F862, "\u{59EC}"),
+    (0x2F863, "\u{5A1B}"),
+    (0x2F864, "\u{5A27}"),
+    (0x2F865, "\u{59D8}"),
+    (0x2F866, "\u{5A66}"),
+    (0x2F867, "\u{36EE}"),
+    (0x2F868, "\u{36FC}"),
+    (0x2F869, "\u{5B08}"),
+    (0x2F86A, "\u{5B3E}"),
+    (0x2F86B, "\u{5B3E}"),
+    (0x2F86C, "\u{219C8}"),
+    (0x2F86D, "\u{5BC3}"),
+    (0x2F86E, "\u{5BD8}"),
+    (0x2F86F, "\u{5BE7}"),
+    (0x2F870, "\u{5BF3}"),
+    (0x2F871, "\u{21B18}"),
+    (0x2F872, "\u{5BFF}"),
+    (0x2F873, "\u{5C06}"),
+    (0x2F874, "\u{5F53}"),
+    (0x2F875, "\u{5C22}"),
+    (0x2F876, "\u{3781}"),
+    (0x2F877, "\u{5C60}"),
+    (0x2F878, "\u{5C6E}"),
+    (0x2F879, "\u{5CC0}"),
+    (0x2F87A, "\u{5C8D}"),
+    (0x2F87B, "\u{21DE4}"),
+    (0x2F87C, "\u{5D43}"),
+    (0x2F87D, "\u{21DE6}"),
+    (0x2F87E, "\u{5D6E}"),
+    (0x2F87F, "\u{5D6B}"),
+    (0x2F880, "\u{5D7C}"),
+    (0x2F881, "\u{5DE1}"),
+    (0x2F882, "\u{5DE2}"),
+    (0x2F883, "\u{382F}"),
+    (0x2F884, "\u{5DFD}"),
+    (0x2F885, "\u{5E28}"),
+    (0x2F886, "\u{5E3D}"),
+    (0x2F887, "\u{5E69}"),
+    (0x2F888, "\u{3862}"),
+    (0x2F889, "\u{22183}"),
+    (0x2F88A, "\u{387C}"),
+    (0x2F88B, "\u{5EB0}"),
+    (0x2F88C, "\u{5EB3}"),
+    (0x2F88D, "\u{5EB6}"),
+    (0x2F88E, "\u{5ECA}"),
+    (0x2F88F, "\u{2A392}"),
+    (0x2F890, "\u{5EFE}"),
+    (0x2F891, "\u{22331}"),
+    (0x2F892, "\u{22331}"),
+    (0x2F893, "\u{8201}"),
+    (0x2F894, "\u{5F22}"),
+    (0x2F895, "\u{5F22}"),
+    (0x2F896, "\u{38C7}"),
+    (0x2F897, "\u{232B8}"),
+    (0x2F898, "\u{261DA}"),
+    (0x2F899, "\u{5F62}"),
+    (0x2F89A, "\u{5F6B}"),
+    (0x2F89B, "\u{38E3}"),
+    (0x2F89C, "\u{5F9A}"),
+    (0x2F89D, "\u{5FCD}"),
+    (0x2F89E, "\u{5FD7}"),
+    (0x2F89F, "\u{5FF9}"),
+    (0x2F8A0, "\u{6081}"),
+    (0x2F8A1, "\u{393A}"),
+    (0x2F8A2, "\u{391C}"),
+    (0x2F8A3, "\u{6094}"),
+    (0x2F8A4, "\u{226D4}"),
+    (0x2F8A5, "\u{60C7}"),
+    (0x2F8A6, "\u{6148}"),
+    (0x2F8A7, "\u{614C}"),
+    (0x2F8A8, "\u{614E}"),
+    (0x2F8A9, "\u{614C}"),
+    (0x2F8AA, "\u{617A}"),
+    (0x2F8AB, "\u{618E}"),
+    (0x2F8AC, "\u{61B2}"),
+    (0x2F8AD, "\u{61A4}"),
+    (0x2F8AE, "\u{61AF}"),
+    (0x2F8AF, "\u{61DE}"),
+    (0x2F8B0, "\u{61F2}"),
+    (0x2F8B1, "\u{61F6}"),
+    (0x2F8B2, "\u{6210}"),
+    (0x2F8B3, "\u{621B}"),
+    (0x2F8B4, "\u{625D}"),
+    (0x2F8B5, "\u{62B1}"),
+    (0x2F8B6, "\u{62D4}"),
+    (0x2F8B7, "\u{6350}"),
+    (0x2F8B8, "\u{22B0C}"),
+    (0x2F8B9, "\u{633D}"),
+    (0x2F8BA, "\u{62FC}"),
+    (0x2F8BB, "\u{6368}"),
+    (0x2F8BC, "\u{6383}"),
+    (0x2F8BD, "\u{63E4}"),
+    (0x2F8BE, "\u{22BF1}"),
+    (0x2F8BF, "\u{6422}"),
+    (0x2F8C0, "\u{63C5}"),
+    (0x2F8C1, "\u{63A9}"),
+    (0x2F8C2, "\u{3A2E}"),
+    (0x2F8C3, "\u{6469}"),
+    (0x2F8C4, "\u{647E}"),
+    (0x2F8C5, "\u{649D}"),
+    (0x2F8C6, "\u{6477}"),
+    (0x2F8C7, "\u{3A6C}"),
+    (0x2F8C8, "\u{654F}"),
+    (0x2F8C9, "\u{656C}"),
+    (0x2F8CA, "\u{2300A}"),
+    (0x2F8CB, "\u{65E3}"),
+    (0x2F8CC, "\u{66F8}"),
+    (0x2F8CD, "\u{6649}"),
+    (0x2F8CE, "\u{3B19}"),
+    (0x2F8CF, "\u{6691}"),
+    (0x2F8D0, "\u{3B08}"),
+    (0x2F8D1, "\u{3AE4}"),
+    (0x2F8D2, "\u{5192}"),
+    (0x2F8D3, "\u{5195}"),
+    (0x2F8D4, "\u{6700}"),
+    (0x2F8D5, "\u{669C}"),
+    (0x2F8D6, "\u{80AD}"),
+    (0x2F8D7, "\u{43D9}"),
+    (0x2F8D8, "\u{6717}"),
+    (0x2F8D9, "\u{671B}"),
+    (0x2F8DA, "\u{6721}"),
+    (0x2F8DB, "\u{675E}"),
+    (0x2F8DC, "\u{6753}"),
+    (0x2F8DD, "\u{233C3}"),
+    (0x2F8DE, "\u{3B49}"),
+    (0x2F8DF, "\u{67FA}"),
+    (0x2F8E0, "\u{6785}"),
+    (0x2F8E1, "\u{6852}"),
+    (0x2F8E2, "\u{6885}"),
+    (0x2F8E3, "\u{2346D}"),
+    (0x2F8E4, "\u{688E}"),
+    (0x2F8E5, "\u{681F}"),
+    (0x2F8E6, "\u{6914}"),
+    (0x2F8E7, "\u{3B9D}"),
+    (0x2F8E8, "\u{6942}"),
+    (0x2F8E9, "\u{69A3}"),
+    (0x2F8EA, "\u{69EA}"),
+    (0x2F8EB, "\u{6AA8}"),
+    (0x2F8EC, "\u{236A3}"),
+    (0x2F8ED, "\u{6ADB}"),
+    (0x2F8EE, "\u{3C18}"),
+    (0x2F8EF, "\u{6B21}"),
+    (0x2F8F0, "\u{238A7}"),
+    (0x2F8F1, "\u{6B54}"),
+    (0x2F8F2, "\u{3C4E}"),
+    (0x2F8F3, "\u{6B72}"),
+    (0x2F8F4, "\u{6B9F}"),
+    (0x2F8F5, "\u{6BBA}"),
+    (0x2F8F6, "\u{6BBB}"),
+    (0x2F8F7, "\u{23A8D}"),
+    (0x2F8F8, "\u{21D0B}"),
+    (0x2F8F9, "\u{23AFA}"),
+    (0x2F8FA, "\u{6C4E}"),
+    (0x2F8FB, "\u{23CBC}"),
+    (0x2F8FC, "\u{6CBF}"),
+    (0x2F8FD, "\u{6CCD}"),
+    (0x2F8FE, "\u{6C67}"),
+    (0x2F8FF, "\u{6D16}"),
+    (0x2F900, "\u{6D3E}"),
+    (0x2F901, "\u{6D77}"),
+    (0x2F902, "\u{6D41}"),
+    (0x2F903, "\u{6D69}"),
+    (0x2F904, "\u{6D78}"),
+    (0x2F905, "\u{6D85}"),
+    (0x2F906, "\u{23D1E}"),
+    (0x2F907, "\u{6D34}"),
+    (0x2F908, "\u{6E2F}"),
+    (0x2F909, "\u{6E6E}"),
+    (0x2F90A, "\u{3D33}"),
+    (0x2F90B, "\u{6ECB}"),
+    (0x2F90C, "\u{6EC7}"),
+    (0x2F90D, "\u{23ED1}"),
+    (0x2F90E, "\u{6DF9}"),
+    (0x2F90F, "\u{6F6E}"),
+    (0x2F910, "\u{23F5E}"),
+    (0x2F911, "\u{23F8E}"),
+    (0x2F912, "\u{6FC6}"),
+    (0x2F913, "\u{7039}"),
+    (0x2F914, "\u{701E}"),
+    (0x2F915, "\u{701B}"),
+    (0x2F916, "\u{3D96}"),
+    (0x2F917, "\u{704A}"),
+    (0x2F918, "\u{707D}"),
+    (0x2F919, "\u{7077}"),
+    (0x2F91A, "\u{70AD}"),
+    (0x2F91B, "\u{20525}"),
+    (0x2F91C, "\u{7145}"),
+    (0x2F91D, "\u{24263}"),
+    (0x2F91E, "\u{719C}"),
+    (0x2F91F, "\u{243AB}"),
+    (0x2F920, "\u{7228}"),
+    (0x2F921, "\u{7235}"),
+    (0x2F922, "\u{7250}"),
+    (0x2F923, "\u{24608}"),
+    (0x2F924, "\u{7280}"),
+    (0x2F925, "\u{7295}"),
+    (0x2F926, "\u{24735}"),
+    (0x2F927, "\u{24814}"),
+    (0x2F928, "\u{737A}"),
+    (0x2F929, "\u{738B}"),
+    (0x2F92A, "\u{3EAC}"),
+    (0x2F92B, "\u{73A5}"),
+    (0x2F92C, "\u{3EB8}"),
+    (0x2F92D, "\u{3EB8}"),
+    (0x2F92E, "\u{7447}"),
+    (0x2F92F, "\u{745C}"),
+    (0x2F930, "\u{7471}"),
+    (0x2F931, "\u{7485}"),
+    (0x2F932, "\u{74CA}"),
+    (0x2F933, "\u{3F1B}"),
+    (0x2F934, "\u{7524}"),
+    (0x2F935, "\u{24C36}"),
+    (0x2F936, "\u{753E}"),
+    (0x2F937, "\u{24C92}"),
+    (0x2F938, "\u{7570}"),
+    (0x2F939, "\u{2219F}"),
+    (0x2F93A, "\u{7610}"),
+    (0x2F93B, "\u{24FA1}"),
+    (0x2F93C, "\u{24FB8}"),
+    (0x2F93D, "\u{25044}"),
+    (0x2F93E, "\u{3FFC}"),
+    (0x2F93F, "\u{4008}"),
+    (0x2F940, "\u{76F4}"),
+    (0x2F941, "\u{250F3}"),
+    (0x2F942, "\u{250F2}"),
+    (0x2F943, "\u{25119}"),
+    (0x2F944, "\u{25133}"),
+    (0x2F945, "\u{771E}"),
+    (0x2F946, "\u{771F}"),
+    (0x2F947, "\u{771F}"),
+    (0x2F948, "\u{774A}"),
+    (0x2F949, "\u{4039}"),
+    (0x2F94A, "\u{778B}"),
+    (0x2F94B, "\u{4046}"),
+    (0x2F94C, "\u{4096}"),
+    (0x2F94D, "\u{2541D}"),
+    (0x2F94E, "\u{784E}"),
+    (0x2F94F, "\u{788C}"),
+    (0x2F950, "\u{78CC}"),
+    (0x2F951, "\u{40E3}"),
+    (0x2F952, "\u{25626}"),
+    (0x2F953, "\u{7956}"),
+    (0x2F954, "\u{2569A}"),
+    (0x2F955, "\u{256C5}"),
+    (0x2F956, "\u{798F}"),
+    (0x2F957, "\u{79EB}"),
+    (0x2F958, "\u{412F}"),
+    (0x2F959, "\u{7A40}"),
+    (0x2F95A, "\u{7A4A}"),
+    (0x2F95B, "\u{7A4F}"),
+    (0x2F95C, "\u{2597C}"),
+    (0x2F95D, "\u{25AA7}"),
+    (0x2F95E, "\u{25AA7}"),
+    (0x2F95F, "\u{7AEE}"),
+    (0x2F960, "\u{4202}"),
+    (0x2F961, "\u{25BAB}"),
+    (0x2F962, "\u{7BC6}"),
+    (0x2F963, "\u{7BC9}"),
+    (0x2F964, "\u{4227}"),
+    (0x2F965, "\u{25C80}"),
+    (0x2F966, "\u{7CD2}"),
+    (0x2F967, "\u{42A0}"),
+    (0x2F968, "\u{7CE8}"),
+    (0x2F969, "\u{7CE3}"),
+    (0x2F96A, "\u{7D00}"),
+    (0x2F96B, "\u{25F86}"),
+    (0x2F96C, "\u{7D63}"),
+    (0x2F96D, "\u{4301}"),
+    (0x2F96E, "\u{7DC7}"),
+    (0x2F96F, "\u{7E02}"),
+    (0x2F970, "\u{7E45}"),
+    (0x2F971, "\u{4334}"),
+    (0x2F972, "\u{26228}"),
+    (0x2F973, "\u{26247}"),
+    (0x2F974, "\u{4359}"),
+    (0x2F975, "\u{262D9}"),
+    (0x2F976, "\u{7F7A}"),
+    (0x2F977, "\u{2633E}"),
+    (0x2F978, "\u{7F95}"),
+    (0x2F979, "\u{7FFA}"),
+    (0x2F97A, "\u{8005}"),
+    (0x2F97B, "\u{264DA}"),
+    (0x2F97C, "\u{26523}"),
+    (0x2F97D, "\u{8060}"),
+    (0x2F97E, "\u{265A8}"),
+    (0x2F97F, "\u{8070}"),
+    (0x2F980, "\u{2335F}"),
+    (0x2F981, "\u{43D5}"),
+    (0x2F982, "\u{80B2}"),
+    (0x2F983, "\u{8103}"),
+    (0x2F984, "\u{440B}"),
+    (0x2F985, "\u{813E}"),
+    (0x2F986, "\u{5AB5}"),
+    (0x2F987, "\u{267A7}"),
+    (0x2F988, "\u{267B5}"),
+    (0x2F989, "\u{23393}"),
+    (0x2F98A, "\u{2339C}"),
+    (0x2F98B, "\u{8201}"),
+    (0x2F98C, "\u{8204}"),
+    (0x2F98D, "\u{8F9E}"),
+    (0x2F98E, "\u{446B}"),
+    (0x2F98F, "\u{8291}"),
+    (0x2F990, "\u{828B}"),
+    (0x2F991, "\u{829D}"),
+    (0x2F992, "\u{52B3}"),
+    (0x2F993, "\u{82B1}"),
+    (0x2F994, "\u{82B3}"),
+    (0x2F995, "\u{82BD}"),
+    (0x2F996, "\u{82E6}"),
+    (0x2F997, "\u{26B3C}"),
+    (0x2F998, "\u{82E5}"),
+    (0x2F999, "\u{831D}"),
+    (0x2F99A, "\u{8363}"),
+    (0x2F99B, "\u{83AD}"),
+    (0x2F99C, "\u{8323}"),
+    (0x2F99D, "\u{83BD}"),
+    (0x2F99E, "\u{83E7}"),
+    (0x2F99F, "\u{8457}"),
+    (0x2F9A0, "\u{8353}"),
+    (0x2F9A1, "\u{83CA}"),
+    (0x2F9A2, "\u{83CC}"),
+    (0x2F9A3, "\u{83DC}"),
+    (0x2F9A4, "\u{26C36}"),
+    (0x2F9A5, "\u{26D6B}"),
+    (0x2F9A6, "\u{26CD5}"),
+    (0x2F9A7, "\u{452B}"),
+    (0x2F9A8, "\u{84F1}"),
+    (0x2F9A9, "\u{84F3}"),
+    (0x2F9AA, "\u{8516}"),
+    (0x2F9AB, "\u{273CA}"),
+    (0x2F9AC, "\u{8564}"),
+    (0x2F9AD, "\u{26F2C}"),
+    (0x2F9AE, "\u{455D}"),
+    (0x2F9AF, "\u{4561}"),
+    (0x2F9B0, "\u{26FB1}"),
+    (0x2F9B1, "\u{270D2}"),
+    (0x2F9B2, "\u{456B}"),
+    (0x2F9B3, "\u{8650}"),
+    (0x2F9B4, "\u{865C}"),
+    (0x2F9B5, "\u{8667}"),
+    (0x2F9B6, "\u{8669}"),
+    (0x2F9B7, "\u{86A9}"),
+    (0x2F9B8, "\u{8688}"),
+    (0x2F9B9, "\u{870E}"),
+    (0x2F9BA, "\u{86E2}"),
+    (0x2F9BB, "\u{8779}"),
+    (0x2F9BC, "\u{8728}"),
+    (0x2F9BD, "\u{876B}"),
+    (0x2F9BE, "\u{8786}"),
+    (0x2F9BF, "\u{45D7}"),
+    (0x2F9C0, "\u{87E1}"),
+    (0x2F9C1, "\u{8801}"),
+    (0x2F9C2, "\u{45F9}"),
+    (0x2F9C3, "\u{8860}"),
+    (0x2F9C4, "\u{8863}"),
+    (0x2F9C5, "\u{27667}"),
+    (0x2F9C6, "\u{88D7}"),
+    (0x2F9C7, "\u{88DE}"),
+    (0x2F9C8, "\u{4635}"),
+    (0x2F9C9, "\u{88FA}"),
+    (0x2F9CA, "\u{34BB}"),
+    (0x2F9CB, "\u{278AE}"),
+    (0x2F9CC, "\u{27966}"),
+    (0x2F9CD, "\u{46BE}"),
+    (0x2F9CE, "\u{46C7}"),
+    (0x2F9CF, "\u{8AA0}"),
+    (0x2F9D0, "\u{8AED}"),
+    (0x2F9D1, "\u{8B8A}"),
+    (0x2F9D2, "\u{8C55}"),
+    (0x2F9D3, "\u{27CA8}"),
+    (0x2F9D4, "\u{8CAB}"),
+    (0x2F9D5, "\u{8CC1}"),
+    (0x2F9D6, "\u{8D1B}"),
+    (0x2F9D7, "\u{8D77}"),
+    (0x2F9D8, "\u{27F2F}"),
+    (0x2F9D9, "\u{20804}"),
+    (0x2F9DA, "\u{8DCB}"),
+    (0x2F9DB, "\u{8DBC}"),
+    (0x2F9DC, "\u{8DF0}"),
+    (0x2F9DD, "\u{208DE}"),
+    (0x2F9DE, "\u{8ED4}"),
+    (0x2F9DF, "\u{8F38}"),
+    (0x2F9E0, "\u{285D2}"),
+    (0x2F9E1, "\u{285ED}"),
+    (0x2F9E2, "\u{9094}"),
+    (0x2F9E3, "\u{90F1}"),
+    (0x2F9E4, "\u{9111}"),
+    (0x2F9E5, "\u{2872E}"),
+    (0x2F9E6, "\u{911B}"),
+    (0x2F9E7, "\u{9238}"),
+    (0x2F9E8, "\u{92D7}"),
+    (0x2F9E9, "\u{92D8}"),
+    (0x2F9EA, "\u{927C}"),
+    (0x2F9EB, "\u{93F9}"),
+    (0x2F9EC, "\u{9415}"),
+    (0x2F9ED, "\u{28BFA}"),
+    (0x2F9EE, "\u{958B}"),
+    (0x2F9EF, "\u{4995}"),
+    (0x2F9F0, "\u{95B7}"),
+    (0x2F9F1, "\u{28D77}"),
+    (0x2F9F2, "\u{49E6}"),
+    (0x2F9F3, "\u{96C3}"),
+    (0x2F9F4, "\u{5DB2}"),
+    (0x2F9F5, "\u{9723}"),
+    (0x2F9F6, "\u{29145}"),
+    (0x2F9F7, "\u{2921A}"),
+    (0x2F9F8, "\u{4A6E}"),
+    (0x2F9F9, "\u{4A76}"),
+    (0x2F9FA, "\u{97E0}"),
+    (0x2F9FB, "\u{2940A}"),
+    (0x2F9FC, "\u{4AB2}"),
+    (0x2F9FD, "\u{29496}"),
+    (0x2F9FE, "\u{980B}"),
+    (0x2F9FF, "\u{980B}"),
+    (0x2FA00, "\u{9829}"),
+    (0x2FA01, "\u{295B6}"),
+    (0x2FA02, "\u{98E2}"),
+    (0x2FA03, "\u{4B33}"),
+    (0x2FA04, "\u{9929}"),
+    (0x2FA05, "\u{99A7}"),
+    (0x2FA06, "\u{99C2}"),
+    (0x2FA07, "\u{99FE}"),
+    (0x2FA08, "\u{4BCE}"),
+    (0x2FA09, "\u{29B30}"),
+    (0x2FA0A, "\u{9B12}"),
+    (0x2FA0B, "\u{9C40}"),
+    (0x2FA0C, "\u{9CFD}"),
+    (0x2FA0D, "\u{4CCE}"),
+    (0x2FA0E, "\u{4CED}"),
+    (0x2FA0F, "\u{9D67}"),
+    (0x2FA10, "\u{2A0CE}"),
+    (0x2FA11, "\u{4CF8}"),
+    (0x2FA12, "\u{2A105}"),
+    (0x2FA13, "\u{2A20E}"),
+    (0x2FA14, "\u{2A291}"),
+    (0x2FA15, "\u{9EBB}"),
+    (0x2FA16, "\u{4D56}"),
+    (0x2FA17, "\u{9EF9}"),
+    (0x2FA18, "\u{9EFE}"),
+    (0x2FA19, "\u{9F05}"),
+    (0x2FA1A, "\u{9F0F}"),
+    (0x2FA1B, "\u{9F16}"),
+    (0x2FA1C, "\u{9F3B}"),
+    (0x2FA1D, "\u{2A600}"),
+];
@@ -0,0 +1,1481 @@
+// Generated by `scripts/unicode.pl` from the Unicode 14.0.0
+// character database. Do not edit by hand.
+
+/// The Unicode `Grapheme_Cluster_Break` property of a character.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GraphemeClusterBreak {
+    /// CR
+    Cr,
+    /// Control
+    Control,
+    /// Extend
+    Extend,
+    /// L
+    L,
+    /// LF
+    Lf,
+    /// LV
+    Lv,
+    /// LVT
+    Lvt,
+    /// Other
+    Other,
+    /// Prepend
+    Prepend,
+    /// Regional Indicator
+    RegionalIndicator,
+    /// SpacingMark
+    SpacingMark,
+    /// T
+    T,
+    /// V
+    V,
+    /// ZWJ
+    Zwj,
+}
+
+impl Default for GraphemeClusterBreak {
+    #[inline]
+    fn default() -> Self {
+        GraphemeClusterBreak::Other
+    }
+}
+
+pub(crate) const GRAPHEME_CLUSTER_BREAK: &[(u32, u32, GraphemeClusterBreak)] = &[
+    (0x0000, 0x0009, GraphemeClusterBreak::Control),
+    (0x000A, 0x000A, GraphemeClusterBreak::Lf),
+    (0x000B, 0x000C, GraphemeClusterBreak::Control),
+    (0x000D, 0x000D, GraphemeClusterBreak::Cr),
+    (0x000E, 0x001F, GraphemeClusterBreak::Control),
+    (0x007F, 0x009F, GraphemeClusterBreak::Control),
+    (0x00AD, 0x00AD, GraphemeClusterBreak::Control),
+    (0x0300, 0x036F, GraphemeClusterBreak::Extend),
+    (0x0483, 0x0489, GraphemeClusterBreak::Extend),
+    (0x0591, 0x05BD, GraphemeClusterBreak::Extend),
+    (0x05BF, 0x05BF, GraphemeClusterBreak::Extend),
+    (0x05C1, 0x05C2, GraphemeClusterBreak::Extend),
+    (0x05C4, 0x05C5, GraphemeClusterBreak::Extend),
+    (0x05C7, 0x05C7, GraphemeClusterBreak::Extend),
+    (0x0600, 0x0605, GraphemeClusterBreak::Prepend),
+    (0x0610, 0x061A, GraphemeClusterBreak::Extend),
+    (0x061C, 0x061C, GraphemeClusterBreak::Control),
+    (0x064B, 0x065F, GraphemeClusterBreak::Extend),
+    (0x0670, 0x0670, GraphemeClusterBreak::Extend),
+    (0x06D6, 0x06DC, GraphemeClusterBreak::Extend),
+    (0x06DD, 0x06DD, GraphemeClusterBreak::Prepend),
+    (0x06DF, 0x06E4, GraphemeClusterBreak::Extend),
+    (0x06E7, 0x06E8, GraphemeClusterBreak::Extend),
+    (0x06EA, 0x06ED, GraphemeClusterBreak::Extend),
+    (0x070F, 0x070F, GraphemeClusterBreak::Prepend),
+    (0x0711, 0x0711, GraphemeClusterBreak::Extend),
+    (0x0730, 0x074A, GraphemeClusterBreak::Extend),
+    (0x07A6, 0x07B0, GraphemeClusterBreak::Extend),
+    (0x07EB, 0x07F3, GraphemeClusterBreak::Extend),
+    (0x07FD, 0x07FD, GraphemeClusterBreak::Extend),
+    (0x0816, 0x0819, GraphemeClusterBreak::Extend),
+    (0x081B, 0x0823, GraphemeClusterBreak::Extend),
+    (0x0825, 0x0827, GraphemeClusterBreak::Extend),
+    (0x0829, 0x082D, GraphemeClusterBreak::Extend),
+    (0x0859, 0x085B, GraphemeClusterBreak::Extend),
+    (0x0890, 0x0891, GraphemeClusterBreak::Prepend),
+    (0x0898, 0x089F, GraphemeClusterBreak::Extend),
+    (0x08CA, 0x08E1, GraphemeClusterBreak::Extend),
+    (0x08E2, 0x08E2, GraphemeClusterBreak::Prepend),
+    (0x08E3, 0x0902, GraphemeClusterBreak::Extend),
+    (0x0903, 0x0903, GraphemeClusterBreak::SpacingMark),
+    (0x093A, 0x093A, GraphemeClusterBreak::Extend),
+    (0x093B, 0x093B, GraphemeClusterBreak::SpacingMark),
+    (0x093C, 0x093C, GraphemeClusterBreak::Extend),
+    (0x093E, 0x0940, GraphemeClusterBreak::SpacingMark),
+    (0x0941, 0x0948, GraphemeClusterBreak::Extend),
+    (0x0949, 0x094C, GraphemeClusterBreak::SpacingMark),
+    (0x094D, 0x094D, GraphemeClusterBreak::Extend),
+    (0x094E, 0x094F, GraphemeClusterBreak::SpacingMark),
+    (0x0951, 0x0957, GraphemeClusterBreak::Extend),
+    (0x0962, 0x0963, GraphemeClusterBreak::Extend),
+    (0x0981, 0x0981, GraphemeClusterBreak::Extend),
+    (0x0982, 0x0983, GraphemeClusterBreak::SpacingMark),
+    (0x09BC, 0x09BC, GraphemeClusterBreak::Extend),
+    (0x09BE, 0x09BE, GraphemeClusterBreak::Extend),
+    (0x09BF, 0x09C0, GraphemeClusterBreak::SpacingMark),
+    (0x09C1, 0x09C4, GraphemeClusterBreak::Extend),
+    (0x09C7, 0x09C8, GraphemeClusterBreak::SpacingMark),
+    (0x09CB, 0x09CC, GraphemeClusterBreak::SpacingMark),
+    (0x09CD, 0x09CD, GraphemeClusterBreak::Extend),
+    (0x09D7, 0x09D7, GraphemeClusterBreak::Extend),
+    (0x09E2, 0x09E3, GraphemeClusterBreak::Extend),
+    (0x09FE, 0x09FE, GraphemeClusterBreak::Extend),
+    (0x0A01, 0x0A02, GraphemeClusterBreak::Extend),
+    (0x0A03, 0x0A03, GraphemeClusterBreak::SpacingMark),
+    (0x0A3C, 0x0A3C, GraphemeClusterBreak::Extend),
+    (0x0A3E, 0x0A40, GraphemeClusterBreak::SpacingMark),
+    (0x0A41, 0x0A42, GraphemeClusterBreak::Extend),
+    (0x0A47, 0x0A48, GraphemeClusterBreak::Extend),
+    (0x0A4B, 0x0A4D, GraphemeClusterBreak::Extend),
+    (0x0A51, 0x0A51, GraphemeClusterBreak::Extend),
+    (0x0A70, 0x0A71, GraphemeClusterBreak::Extend),
+    (0x0A75, 0x0A75, GraphemeClusterBreak::Extend),
+    (0x0A81, 0x0A82, GraphemeClusterBreak::Extend),
+    (0x0A83, 0x0A83, GraphemeClusterBreak::SpacingMark),
+    (0x0ABC, 0x0ABC, GraphemeClusterBreak::Extend),
+    (0x0ABE, 0x0AC0, GraphemeClusterBreak::SpacingMark),
+    (0x0AC1, 0x0AC5, GraphemeClusterBreak::Extend),
+    (0x0AC7, 0x0AC8, GraphemeClusterBreak::Extend),
+    (0x0AC9, 0x0AC9, GraphemeClusterBreak::SpacingMark),
+    (0x0ACB, 0x0ACC, GraphemeClusterBreak::SpacingMark),
+    (0x0ACD, 0x0ACD, GraphemeClusterBreak::Extend),
+    (0x0AE2, 0x0AE3, GraphemeClusterBreak::Extend),
+    (0x0AFA, 0x0AFF, GraphemeClusterBreak::Extend),
+    (0x0B01, 0x0B01, GraphemeClusterBreak::Extend),
+    (0x0B02, 0x0B03, GraphemeClusterBreak::SpacingMark),
+    (0x0B3C, 0x0B3C, GraphemeClusterBreak::Extend),
+    (0x0B3E, 0x0B3F, GraphemeClusterBreak::Extend),
+    (0x0B40, 0x0B40, GraphemeClusterBreak::SpacingMark),
+    (0x0B41, 0x0B44, GraphemeClusterBreak::Extend),
+    (0x0B47, 0x0B48, GraphemeClusterBreak::SpacingMark),
+    (0x0B4B, 0x0B4C, GraphemeClusterBreak::SpacingMark),
+    (0x0B4D, 0x0B4D, GraphemeClusterBreak::Extend),
+    (0x0B55, 0x0B57, GraphemeClusterBreak::Extend),
+    (0x0B62, 0x0B63, GraphemeClusterBreak::Extend),
+    (0x0B82, 0x0B82, GraphemeClusterBreak::Extend),
+    (0x0BBE, 0x0BBE, GraphemeClusterBreak::Extend),
+    (0x0BBF, 0x0BBF, GraphemeClusterBreak::SpacingMark),
+    (0x0BC0, 0x0BC0, GraphemeClusterBreak::Extend),
+    (0x0BC1, 0x0BC2, GraphemeClusterBreak::SpacingMark),
+    (0x0BC6, 0x0BC8, GraphemeClusterBreak::SpacingMark),
+    (0x0BCA, 0x0BCC, GraphemeClusterBreak::SpacingMark),
+    (0x0BCD, 0x0BCD, GraphemeClusterBreak::Extend),
+    (0x0BD7, 0x0BD7, GraphemeClusterBreak::Extend),
+    (0x0C00, 0x0C00, GraphemeClusterBreak::Extend),
+    (0x0C01, 0x0C03, GraphemeClusterBreak::SpacingMark),
+    (0x0C04, 0x0C04, GraphemeClusterBreak::Extend),
+    (0x0C3C, 0x0C3C, GraphemeClusterBreak::Extend),
+    (0x0C3E, 0x0C40, GraphemeClusterBreak::Extend),
+    (0x0C41, 0x0C44, GraphemeClusterBreak::SpacingMark),
+    (0x0C46, 0x0C48, GraphemeClusterBreak::Extend),
+    (0x0C4A, 0x0C4D, GraphemeClusterBreak::Extend),
+    (0x0C55, 0x0C56, GraphemeClusterBreak::Extend),
+    (0x0C62, 0x0C63, GraphemeClusterBreak::Extend),
+    (0x0C81, 0x0C81, GraphemeClusterBreak::Extend),
+    (0x0C82, 0x0C83, GraphemeClusterBreak::SpacingMark),
+    (0x0CBC, 0x0CBC, GraphemeClusterBreak::Extend),
+    (0x0CBE, 0x0CBE, GraphemeClusterBreak::SpacingMark),
+    (0x0CBF, 0x0CBF, GraphemeClusterBreak::Extend),
+    (0x0CC0, 0x0CC1, GraphemeClusterBreak::SpacingMark),
+    (0x0CC2, 0x0CC2, GraphemeClusterBreak::Extend),
+    (0x0CC3, 0x0CC4, GraphemeClusterBreak::SpacingMark),
+    (0x0CC6, 0x0CC6, GraphemeClusterBreak::Extend),
+    (0x0CC7, 0x0CC8, GraphemeClusterBreak::SpacingMark),
+    (0x0CCA, 0x0CCB, GraphemeClusterBreak::SpacingMark),
+    (0x0CCC, 0x0CCD, GraphemeClusterBreak::Extend),
+    (0x0CD5, 0x0CD6, GraphemeClusterBreak::Extend),
+    (0x0CE2, 0x0CE3, GraphemeClusterBreak::Extend),
+    (0x0D00, 0x0D01, GraphemeClusterBreak::Extend),
+    (0x0D02, 0x0D03, GraphemeClusterBreak::SpacingMark),
+    (0x0D3B, 0x0D3C, GraphemeClusterBreak::Extend),
+    (0x0D3E, 0x0D3E, GraphemeClusterBreak::Extend),
+    (0x0D3F, 0x0D40, GraphemeClusterBreak::SpacingMark),
+    (0x0D41, 0x0D44, GraphemeClusterBreak::Extend),
+    (0x0D46, 0x0D48, GraphemeClusterBreak::SpacingMark),
+    (0x0D4A, 0x0D4C, GraphemeClusterBreak::SpacingMark),
+    (0x0D4D, 0x0D4D, GraphemeClusterBreak::Extend),
+    (0x0D4E, 0x0D4E, GraphemeClusterBreak::Prepend),
+    (0x0D57, 0x0D57, GraphemeClusterBreak::Extend),
+    (0x0D62, 0x0D63, GraphemeClusterBreak::Extend),
+    (0x0D81, 0x0D81, GraphemeClusterBreak::Extend),
+    (0x0D82, 0x0D83, GraphemeClusterBreak::SpacingMark),
+    (0x0DCA, 0x0DCA, GraphemeClusterBreak::Extend),
+    (0x0DCF, 0x0DCF, GraphemeClusterBreak::Extend),
+    (0x0DD0, 0x0DD1, GraphemeClusterBreak::SpacingMark),
+    (0x0DD2, 0x0DD4, GraphemeClusterBreak::Extend),
+    (0x0DD6, 0x0DD6, GraphemeClusterBreak::Extend),
+    (0x0DD8, 0x0DDE, GraphemeClusterBreak::SpacingMark),
+    (0x0DDF, 0x0DDF, GraphemeClusterBreak::Extend),
+    (0x0DF2, 0x0DF3, GraphemeClusterBreak::SpacingMark),
+    (0x0E31, 0x0E31, GraphemeClusterBreak::Extend),
+    (0x0E33, 0x0E33, GraphemeClusterBreak::SpacingMark),
+    (0x0E34, 0x0E3A, GraphemeClusterBreak::Extend),
+    (0x0E47, 0x0E4E, GraphemeClusterBreak::Extend),
+    (0x0EB1, 0x0EB1, GraphemeClusterBreak::Extend),
+    (0x0EB3, 0x0EB3, GraphemeClusterBreak::SpacingMark),
+    (0x0EB4, 0x0EBC, GraphemeClusterBreak::Extend),
+    (0x0EC8, 0x0ECD, GraphemeClusterBreak::Extend),
+    (0x0F18, 0x0F19, GraphemeClusterBreak::Extend),
+    (0x0F35, 0x0F35, GraphemeClusterBreak::Extend),
+    (0x0F37, 0x0F37, GraphemeClusterBreak::Extend),
+    (0x0F39, 0x0F39, GraphemeClusterBreak::Extend),
+    (0x0F3E, 0x0F3F, GraphemeClusterBreak::SpacingMark),
+    (0x0F71, 0x0F7E, GraphemeClusterBreak::Extend),
+    (0x0F7F, 0x0F7F, GraphemeClusterBreak::SpacingMark),
+    (0x0F80, 0x0F84, GraphemeClusterBreak::Extend),
+    (0x0F86, 0x0F87, GraphemeClusterBreak::Extend),
+    (0x0F8D, 0x0F97, GraphemeClusterBreak::Extend),
+    (0x0F99, 0x0FBC, GraphemeClusterBreak::Extend),
+    (0x0FC6, 0x0FC6, GraphemeClusterBreak::Extend),
+    (0x102D, 0x1030, GraphemeClusterBreak::Extend),
+    (0x1031, 0x1031, GraphemeClusterBreak::SpacingMark),
+    (0x1032, 0x1037, GraphemeClusterBreak::Extend),
+    (0x1039, 0x103A, GraphemeClusterBreak::Extend),
+    (0x103B, 0x103C, GraphemeClusterBreak::SpacingMark),
+    (0x103D, 0x103E, GraphemeClusterBreak::Extend),
+    (0x1056, 0x1057, GraphemeClusterBreak::SpacingMark),
+    (0x1058, 0x1059, GraphemeClusterBreak::Extend),
+    (0x105E, 0x1060, GraphemeClusterBreak::Extend),
+    (0x1071, 0x1074, GraphemeClusterBreak::Extend),
+    (0x1082, 0x1082, GraphemeClusterBreak::Extend),
+    (0x1084, 0x1084, GraphemeClusterBreak::SpacingMark),
+    (0x1085, 0x1086, GraphemeClusterBreak::Extend),
+    (0x108D, 0x108D, GraphemeClusterBreak::Extend),
+    (0x109D, 0x109D, GraphemeClusterBreak::Extend),
+    (0x1100, 0x115F, GraphemeClusterBreak::L),
+    (0x1160, 0x11A7, GraphemeClusterBreak::V),
+    (0x11A8, 0x11FF, GraphemeClusterBreak::T),
+    (0x135D, 0x135F, GraphemeClusterBreak::Extend),
+    (0x1712, 0x1714, GraphemeClusterBreak::Extend),
+    (0x1715, 0x1715, GraphemeClusterBreak::SpacingMark),
+    (0x1732, 0x1733, GraphemeClusterBreak::Extend),
+    (0x1734, 0x1734, GraphemeClusterBreak::SpacingMark),
+    (0x1752, 0x1753, GraphemeClusterBreak::Extend),
+    (0x1772, 0x1773, GraphemeClusterBreak::Extend),
+    (0x17B4, 0x17B5, GraphemeClusterBreak::Extend),
+    (0x17B6, 0x17B6, GraphemeClusterBreak::SpacingMark),
+    (0x17B7, 0x17BD, GraphemeClusterBreak::Extend),
+    (0x17BE, 0x17C5, GraphemeClusterBreak::SpacingMark),
+    (0x17C6, 0x17C6, GraphemeClusterBreak::Extend),
+    (0x17C7, 0x17C8, GraphemeClusterBreak::SpacingMark),
+    (0x17C9, 0x17D3, GraphemeClusterBreak::Extend),
+    (0x17DD, 0x17DD, GraphemeClusterBreak::Extend),
+    (0x180B, 0x180D, GraphemeClusterBreak::Extend),
+    (0x180E, 0x180E, GraphemeClusterBreak::Control),
+    (0x180F, 0x180F, GraphemeClusterBreak::Extend),
+    (0x1885, 0x1886, GraphemeClusterBreak::Extend),
+    (0x18A9, 0x18A9, GraphemeClusterBreak::Extend),
+    (0x1920, 0x1922, GraphemeClusterBreak::Extend),
+    (0x1923, 0x1926, GraphemeClusterBreak::SpacingMark),
+    (0x1927, 0x1928, GraphemeClusterBreak::Extend),
+    (0x1929, 0x192B, GraphemeClusterBreak::SpacingMark),
+    (0x1930, 0x1931, GraphemeClusterBreak::SpacingMark),
+    (0x1932, 0x1932, GraphemeClusterBreak::Extend),
+    (0x1933, 0x1938, GraphemeClusterBreak::SpacingMark),
+    (0x1939, 0x193B, GraphemeClusterBreak::Extend),
+    (0x1A17, 0x1A18, GraphemeClusterBreak::Extend),
+    (0x1A19, 0x1A1A, GraphemeClusterBreak::SpacingMark),
+    (0x1A1B, 0x1A1B, GraphemeClusterBreak::Extend),
+    (0x1A55, 0x1A55, GraphemeClusterBreak::SpacingMark),
+    (0x1A56, 0x1A56, GraphemeClusterBreak::Extend),
+    (0x1A57, 0x1A57, GraphemeClusterBreak::SpacingMark),
+    (0x1A58, 0x1A5E, GraphemeClusterBreak::Extend),
+    (0x1A60, 0x1A60, GraphemeClusterBreak::Extend),
+    (0x1A62, 0x1A62, GraphemeClusterBreak::Extend),
+    (0x1A65, 0x1A6C, GraphemeClusterBreak::Extend),
+    (0x1A6D, 0x1A72, GraphemeClusterBreak::SpacingMark),
+    (0x1A73, 0x1A7C, GraphemeClusterBreak::Extend),
+    (0x1A7F, 0x1A7F, GraphemeClusterBreak::Extend),
+    (0x1AB0, 0x1ACE, GraphemeClusterBreak::Extend),
+    (0x1B00, 0x1B03, GraphemeClusterBreak::Extend),
+    (0x1B04, 0x1B04, GraphemeClusterBreak::SpacingMark),
+    (0x1B34, 0x1B3A, GraphemeClusterBreak::Extend),
+    (0x1B3B, 0x1B3B, GraphemeClusterBreak::SpacingMark),
+    (0x1B3C, 0x1B3C, GraphemeClusterBreak::Extend),
+    (0x1B3D, 0x1B41, GraphemeClusterBreak::SpacingMark),
+    (0x1B42, 0x1B42, GraphemeClusterBreak::Extend),
+    (0x1B43, 0x1B44, GraphemeClusterBreak::SpacingMark),
+    (0x1B6B, 0x1B73, GraphemeClusterBreak::Extend),
+    (0x1B80, 0x1B81, GraphemeClusterBreak::Extend),
+    (0x1B82, 0x1B82, GraphemeClusterBreak::SpacingMark),
+    (0x1BA1, 0x1BA1, GraphemeClusterBreak::SpacingMark),
+    (0x1BA2, 0x1BA5, GraphemeClusterBreak::Extend),
+    (0x1BA6, 0x1BA7, GraphemeClusterBreak::SpacingMark),
+    (0x1BA8, 0x1BA9, GraphemeClusterBreak::Extend),
+    (0x1BAA, 0x1BAA, GraphemeClusterBreak::SpacingMark),
+    (0x1BAB, 0x1BAD, GraphemeClusterBreak::Extend),
+    (0x1BE6, 0x1BE6, GraphemeClusterBreak::Extend),
+    (0x1BE7, 0x1BE7, GraphemeClusterBreak::SpacingMark),
+    (0x1BE8, 0x1BE9, GraphemeClusterBreak::Extend),
+    (0x1BEA, 0x1BEC, GraphemeClusterBreak::SpacingMark),
+    (0x1BED, 0x1BED, GraphemeClusterBreak::Extend),
+    (0x1BEE, 0x1BEE, GraphemeClusterBreak::SpacingMark),
+    (0x1BEF, 0x1BF1, GraphemeClusterBreak::Extend),
+    (0x1BF2, 0x1BF3, GraphemeClusterBreak::SpacingMark),
+    (0x1C24, 0x1C2B, GraphemeClusterBreak::SpacingMark),
+    (0x1C2C, 0x1C33, GraphemeClusterBreak::Extend),
+    (0x1C34, 0x1C35, GraphemeClusterBreak::SpacingMark),
+    (0x1C36, 0x1C37, GraphemeClusterBreak::Extend),
+    (0x1CD0, 0x1CD2, GraphemeClusterBreak::Extend),
+    (0x1CD4, 0x1CE0, GraphemeClusterBreak::Extend),
+    (0x1CE1, 0x1CE1, GraphemeClusterBreak::SpacingMark),
+    (0x1CE2, 0x1CE8, GraphemeClusterBreak::Extend),
+    (0x1CED, 0x1CED, GraphemeClusterBreak::Extend),
+    (0x1CF4, 0x1CF4, GraphemeClusterBreak::Extend),
+    (0x1CF7, 0x1CF7, GraphemeClusterBreak::SpacingMark),
+    (0x1CF8, 0x1CF9, GraphemeClusterBreak::Extend),
+    (0x1DC0, 0x1DFF, GraphemeClusterBreak::Extend),
+    (0x200B, 0x200B, GraphemeClusterBreak::Control),
+    (0x200C, 0x200C, GraphemeClusterBreak::Extend),
+    (0x200D, 0x200D, GraphemeClusterBreak::Zwj),
+    (0x200E, 0x200F, GraphemeClusterBreak::Control),
+    (0x2028, 0x202E, GraphemeClusterBreak::Control),
+    (0x2060, 0x206F, GraphemeClusterBreak::Control),
+    (0x20D0, 0x20F0, GraphemeClusterBreak::Extend),
+    (0x2CEF, 0x2CF1, GraphemeClusterBreak::Extend),
+    (0x2D7F, 0x2D7F, GraphemeClusterBreak::Extend),
+    (0x2DE0, 0x2DFF, GraphemeClusterBreak::Extend),
+    (0x302A, 0x302F, GraphemeClusterBreak::Extend),
+    (0x3099, 0x309A, GraphemeClusterBreak::Extend),
+    (0xA66F, 0xA672, GraphemeClusterBreak::Extend),
+    (0xA674, 0xA67D, GraphemeClusterBreak::Extend),
+    (0xA69E, 0xA69F, GraphemeClusterBreak::Extend),
+    (0xA6F0, 0xA6F1, GraphemeClusterBreak::Extend),
+    (0xA802, 0xA802, GraphemeClusterBreak::Extend),
+    (0xA806, 0xA806, GraphemeClusterBreak::Extend),
+    (0xA80B, 0xA80B, GraphemeClusterBreak::Extend),
+    (0xA823, 0xA824, GraphemeClusterBreak::SpacingMark),
+    (0xA825, 0xA826, GraphemeClusterBreak::Extend),
+    (0xA827, 0xA827, GraphemeClusterBreak::SpacingMark),
+    (0xA82C, 0xA82C, GraphemeClusterBreak::Extend),
+    (0xA880, 0xA881, GraphemeClusterBreak::SpacingMark),
+    (0xA8B4, 0xA8C3, GraphemeClusterBreak::SpacingMark),
+    (0xA8C4, 0xA8C5, GraphemeClusterBreak::Extend),
+    (0xA8E0, 0xA8F1, GraphemeClusterBreak::Extend),
+    (0xA8FF, 0xA8FF, GraphemeClusterBreak::Extend),
+    (0xA926, 0xA92D, GraphemeClusterBreak::Extend),
+    (0xA947, 0xA951, GraphemeClusterBreak::Extend),
+    (0xA952, 0xA953, GraphemeClusterBreak::SpacingMark),
+    (0xA960, 0xA97C, GraphemeClusterBreak::L),
+    (0xA980, 0xA982, GraphemeClusterBreak::Extend),
+    (0xA983, 0xA983, GraphemeClusterBreak::SpacingMark),
+    (0xA9B3, 0xA9B3, GraphemeClusterBreak::Extend),
+    (0xA9B4, 0xA9B5, GraphemeClusterBreak::SpacingMark),
+    (0xA9B6, 0xA9B9, GraphemeClusterBreak::Extend),
+    (0xA9BA, 0xA9BB, GraphemeClusterBreak::SpacingMark),
+    (0xA9BC, 0xA9BD, GraphemeClusterBreak::Extend),
+    (0xA9BE, 0xA9C0, GraphemeClusterBreak::SpacingMark),
+    (0xA9E5, 0xA9E5, GraphemeClusterBreak::Extend),
+    (0xAA29, 0xAA2E, GraphemeClusterBreak::Extend),
+    (0xAA2F, 0xAA30, GraphemeClusterBreak::SpacingMark),
+    (0xAA31, 0xAA32, GraphemeClusterBreak::Extend),
+    (0xAA33, 0xAA34, GraphemeClusterBreak::SpacingMark),
+    (0xAA35, 0xAA36, GraphemeClusterBreak::Extend),
+    (0xAA43, 0xAA43, GraphemeClusterBreak::Extend),
+    (0xAA4C, 0xAA4C, GraphemeClusterBreak::Extend),
+    (0xAA4D, 0xAA4D, GraphemeClusterBreak::SpacingMark),
+    (0xAA7C, 0xAA7C, GraphemeClusterBreak::Extend),
+    (0xAAB0, 0xAAB0, GraphemeClusterBreak::Extend),
+    (0xAAB2, 0xAAB4, GraphemeClusterBreak::Extend),
+    (0xAAB7, 0xAAB8, GraphemeClusterBreak::Extend),
+    (0xAABE, 0xAABF, GraphemeClusterBreak::Extend),
+    (0xAAC1, 0xAAC1, GraphemeClusterBreak::Extend),
+    (0xAAEB, 0xAAEB, GraphemeClusterBreak::SpacingMark),
+    (0xAAEC, 0xAAED, GraphemeClusterBreak::Extend),
+    (0xAAEE, 0xAAEF, GraphemeClusterBreak::SpacingMark),
+    (0xAAF5, 0xAAF5, GraphemeClusterBreak::SpacingMark),
+    (0xAAF6, 0xAAF6, GraphemeClusterBreak::Extend),
+    (0xABE3, 0xABE4, GraphemeClusterBreak::SpacingMark),
+    (0xABE5, 0xABE5, GraphemeClusterBreak::Extend),
+    (0xABE6, 0xABE7, GraphemeClusterBreak::SpacingMark),
+    (0xABE8, 0xABE8, GraphemeClusterBreak::Extend),
+    (0xABE9, 0xABEA, GraphemeClusterBreak::SpacingMark),
+    (0xABEC, 0xABEC, GraphemeClusterBreak::SpacingMark),
+    (0xABED, 0xABED, GraphemeClusterBreak::Extend),
+    (0xAC00, 0xAC00, GraphemeClusterBreak::Lv),
+    (0xAC01, 0xAC1B, GraphemeClusterBreak::Lvt),
+    (0xAC1C, 0xAC1C, GraphemeClusterBreak::Lv),
+    (0xAC1D, 0xAC37, GraphemeClusterBreak::Lvt),
+    (0xAC38, 0xAC38, GraphemeClusterBreak::Lv),
+    (0xAC39, 0xAC53, GraphemeClusterBreak::Lvt),
+    (0xAC54, 0xAC54, GraphemeClusterBreak::Lv),
+    (0xAC55, 0xAC6F, GraphemeClusterBreak::Lvt),
+    (0xAC70, 0xAC70, GraphemeClusterBreak::Lv),
+    (0xAC71, 0xAC8B, GraphemeClusterBreak::Lvt),
+    (0xAC8C, 0xAC8C, GraphemeClusterBreak::Lv),
+    (0xAC8D, 0xACA7, GraphemeClusterBreak::Lvt),
+    (0xACA8, 0xACA8, GraphemeClusterBreak::Lv),
+    (0xACA9, 0xACC3, GraphemeClusterBreak::Lvt),
+    (0xACC4, 0xACC4, GraphemeClusterBreak::Lv),
+    (0xACC5, 0xACDF, GraphemeClusterBreak::Lvt),
+    (0xACE0, 0xACE0, GraphemeClusterBreak::Lv),
+    (0xACE1, 0xACFB, GraphemeClusterBreak::Lvt),
+    (0xACFC, 0xACFC, GraphemeClusterBreak::Lv),
+    (0xACFD, 0xAD17, GraphemeClusterBreak::Lvt),
+    (0xAD18, 0xAD18, GraphemeClusterBreak::Lv),
+    (0xAD19, 0xAD33, GraphemeClusterBreak::Lvt),
+    (0xAD34, 0xAD34, GraphemeClusterBreak::Lv),
+    (0xAD35, 0xAD4F, GraphemeClusterBreak::Lvt),
+    (0xAD50, 0xAD50, GraphemeClusterBreak::Lv),
+    (0xAD51, 0xAD6B, GraphemeClusterBreak::Lvt),
+    (0xAD6C, 0xAD6C, GraphemeClusterBreak::Lv),
+    (0xAD6D, 0xAD87, GraphemeClusterBreak::Lvt),
+    (0xAD88, 0xAD88, GraphemeClusterBreak::Lv),
+    (0xAD89, 0xADA3, GraphemeClusterBreak::Lvt),
+    (0xADA4, 0xADA4, GraphemeClusterBreak::Lv),
+    (0xADA5, 0xADBF, GraphemeClusterBreak::Lvt),
+    (0xADC0, 0xADC0, GraphemeClusterBreak::Lv),
+    (0xADC1, 0xADDB, GraphemeClusterBreak::Lvt),
+    (0xADDC, 0xADDC, GraphemeClusterBreak::Lv),
+    (0xADDD, 0xADF7, GraphemeClusterBreak::Lvt),
+    (0xADF8, 0xADF8, GraphemeClusterBreak::Lv),
+    (0xADF9, 0xAE13, GraphemeClusterBreak::Lvt),
+    (0xAE14, 0xAE14, GraphemeClusterBreak::Lv),
+    (0xAE15, 0xAE2F, GraphemeClusterBreak::Lvt),
+    (0xAE30, 0xAE30, GraphemeClusterBreak::Lv),
+    (0xAE31, 0xAE4B, GraphemeClusterBreak::Lvt),
+    (0xAE4C, 0xAE4C, GraphemeClusterBreak::Lv),
+    (0xAE4D, 0xAE67, GraphemeClusterBreak::Lvt),
+    (0xAE68, 0xAE68, GraphemeClusterBreak::Lv),
+    (0xAE69, 0xAE83, GraphemeClusterBreak::Lvt),
+    (0xAE84, 0xAE84, GraphemeClusterBreak::Lv),
+    (0xAE85, 0xAE9F, GraphemeClusterBreak::Lvt),
+    (0xAEA0, 0xAEA0, GraphemeClusterBreak::Lv),
+    (0xAEA1, 0xAEBB, GraphemeClusterBreak::Lvt),
+    (0xAEBC, 0xAEBC, GraphemeClusterBreak::Lv),
+    (0xAEBD, 0xAED7, GraphemeClusterBreak::Lvt),
+    (0xAED8, 0xAED8, GraphemeClusterBreak::Lv),
+    (0xAED9, 0xAEF3, GraphemeClusterBreak::Lvt),
+    (0xAEF4, 0xAEF4, GraphemeClusterBreak::Lv),
+    (0xAEF5, 0xAF0F, GraphemeClusterBreak::Lvt),
+    (0xAF10, 0xAF10, GraphemeClusterBreak::Lv),
+    (0xAF11, 0xAF2B, GraphemeClusterBreak::Lvt),
+    (0xAF2C, 0xAF2C, GraphemeClusterBreak::Lv),
+    (0xAF2D, 0xAF47, GraphemeClusterBreak::Lvt),
+    (0xAF48, 0xAF48, GraphemeClusterBreak::Lv),
+    (0xAF49, 0xAF63, GraphemeClusterBreak::Lvt),
+    (0xAF64, 0xAF64, GraphemeClusterBreak::Lv),
+    (0xAF65, 0xAF7F, GraphemeClusterBreak::Lvt),
+    (0xAF80, 0xAF80, GraphemeClusterBreak::Lv),
+    (0xAF81, 0xAF9B, GraphemeClusterBreak::Lvt),
+    (0xAF9C, 0xAF9C, GraphemeClusterBreak::Lv),
+    (0xAF9D, 0xAFB7, GraphemeClusterBreak::Lvt),
+    (0xAFB8, 0xAFB8, GraphemeClusterBreak::Lv),
+    (0xAFB9, 0xAFD3, GraphemeClusterBreak::Lvt),
+    (0xAFD4, 0xAFD4, GraphemeClusterBreak::Lv),
+    (0xAFD5, 0xAFEF, GraphemeClusterBreak::Lvt),
+    (0xAFF0, 0xAFF0, GraphemeClusterBreak::Lv),
+    (0xAFF1, 0xB00B, GraphemeClusterBreak::Lvt),
+    (0xB00C, 0xB00C, GraphemeClusterBreak::Lv),
+    (0xB00D, 0xB027, GraphemeClusterBreak::Lvt),
+    (0xB028, 0xB028, GraphemeClusterBreak::Lv),
+    (0xB029, 0xB043, GraphemeClusterBreak::Lvt),
+    (0xB044, 0xB044, GraphemeClusterBreak::Lv),
+    (0xB045, 0xB05F, GraphemeClusterBreak::Lvt),
+    (0xB060, 0xB060, GraphemeClusterBreak::Lv),
+    (0xB061, 0xB07B, GraphemeClusterBreak::Lvt),
+    (0xB07C, 0xB07C, GraphemeClusterBreak::Lv),
+    (0xB07D, 0xB097, GraphemeClusterBreak::Lvt),
+    (0xB098, 0xB098, GraphemeClusterBreak::Lv),
+    (0xB099, 0xB0B3, GraphemeClusterBreak::Lvt),
+    (0xB0B4, 0xB0B4, GraphemeClusterBreak::Lv),
+    (0xB0B5, 0xB0CF, GraphemeClusterBreak::Lvt),
+    (0xB0D0, 0xB0D0, GraphemeClusterBreak::Lv),
+    (0xB0D1, 0xB0EB, GraphemeClusterBreak::Lvt),
+    (0xB0EC, 0xB0EC, GraphemeClusterBreak::Lv),
+    (0xB0ED, 0xB107, GraphemeClusterBreak::Lvt),
+    (0xB108, 0xB108, GraphemeClusterBreak::Lv),
+    (0xB109, 0xB123, GraphemeClusterBreak::Lvt),
+    (0xB124, 0xB124, GraphemeClusterBreak::Lv),
+    (0xB125, 0xB13F, GraphemeClusterBreak::Lvt),
+    (0xB140, 0xB140, GraphemeClusterBreak::Lv),
+    (0xB141, 0xB15B, GraphemeClusterBreak::Lvt),
+    (0xB15C, 0xB15C, GraphemeClusterBreak::Lv),
+    (0xB15D, 0xB177, GraphemeClusterBreak::Lvt),
+    (0xB178, 0xB178, GraphemeClusterBreak::Lv),
+    (0xB179, 0xB193, GraphemeClusterBreak::Lvt),
+    (0xB194, 0xB194, GraphemeClusterBreak::Lv),
+    (0xB195, 0xB1AF, GraphemeClusterBreak::Lvt),
+    (0xB1B0, 0xB1B0, GraphemeClusterBreak::Lv),
+    (0xB1B1, 0xB1CB, GraphemeClusterBreak::Lvt),
+    (0xB1CC, 0xB1CC, GraphemeClusterBreak::Lv),
+    (0xB1CD, 0xB1E7, GraphemeClusterBreak::Lvt),
+    (0xB1E8, 0xB1E8, GraphemeClusterBreak::Lv),
+    (0xB1E9, 0xB203, GraphemeClusterBreak::Lvt),
+    (0xB204, 0xB204, GraphemeClusterBreak::Lv),
+    (0xB205, 0xB21F, GraphemeClusterBreak::Lvt),
+    (0xB220, 0xB220, GraphemeClusterBreak::Lv),
+    (0xB221, 0xB23B, GraphemeClusterBreak::Lvt),
+    (0xB23C, 0xB23C, GraphemeClusterBreak::Lv),
+    (0xB23D, 0xB257, GraphemeClusterBreak::Lvt),
+    (0xB258, 0xB258, GraphemeClusterBreak::Lv),
+    (0xB259, 0xB273, GraphemeClusterBreak::Lvt),
+    (0xB274, 0xB274, GraphemeClusterBreak::Lv),
+    (0xB275, 0xB28F, GraphemeClusterBreak::Lvt),
+    (0xB290, 0xB290, GraphemeClusterBreak::Lv),
+    (0xB291, 0xB2AB, GraphemeClusterBreak::Lvt),
+    (0xB2AC, 0xB2AC, GraphemeClusterBreak::Lv),
+    (0xB2AD, 0xB2C7, GraphemeClusterBreak::Lvt),
+    (0xB2C8, 0xB2C8, GraphemeClusterBreak::Lv),
+    (0xB2C9, 0xB2E3, GraphemeClusterBreak::Lvt),
+    (0xB2E4, 0xB2E4, GraphemeClusterBreak::Lv),
+    (0xB2E5, 0xB2FF, GraphemeClusterBreak::Lvt),
+    (0xB300, 0xB300, GraphemeClusterBreak::Lv),
+    (0xB301, 0xB31B, GraphemeClusterBreak::Lvt),
+    (0xB31C, 0xB31C, GraphemeClusterBreak::Lv),
+    (0xB31D, 0xB337, GraphemeClusterBreak::Lvt),
+    (0xB338, 0xB338, GraphemeClusterBreak::Lv),
+    (0xB339, 0xB353, GraphemeClusterBreak::Lvt),
+    (0xB354, 0xB354, GraphemeClusterBreak::Lv),
+    (0xB355, 0xB36F, GraphemeClusterBreak::Lvt),
+    (0xB370, 0xB370, GraphemeClusterBreak::Lv),
+    (0xB371, 0xB38B, GraphemeClusterBreak::Lvt),
+    (0xB38C, 0xB38C, GraphemeClusterBreak::Lv),
+    (0xB38D, 0xB3A7, GraphemeClusterBreak::Lvt),
+    (0xB3A8, 0xB3A8, GraphemeClusterBreak::Lv),
+    (0xB3A9, 0xB3C3, GraphemeClusterBreak::Lvt),
+    (0xB3C4, 0xB3C4, GraphemeClusterBreak::Lv),
+    (0xB3C5, 0xB3DF, GraphemeClusterBreak::Lvt),
+    (0xB3E0, 0xB3E0, GraphemeClusterBreak::Lv),
+    (0xB3E1, 0xB3FB, GraphemeClusterBreak::Lvt),
+    (0xB3FC, 0xB3FC, GraphemeClusterBreak::Lv),
+    (0xB3FD, 0xB417, GraphemeClusterBreak::Lvt),
+    (0xB418, 0xB418, GraphemeClusterBreak::Lv),
+    (0xB419, 0xB433, GraphemeClusterBreak::Lvt),
+    (0xB434, 0xB434, GraphemeClusterBreak::Lv),
+    (0xB435, 0xB44F, GraphemeClusterBreak::Lvt),
+    (0xB450, 0xB450, GraphemeClusterBreak::Lv),
+    (0xB451, 0xB46B, GraphemeClusterBreak::Lvt),
+    (0xB46C, 0xB46C, GraphemeClusterBreak::Lv),
+    (0xB46D, 0xB487, GraphemeClusterBreak::Lvt),
+    (0xB488, 0xB488, GraphemeClusterBreak::Lv),
+    (0xB489, 0xB4A3, GraphemeClusterBreak::Lvt),
+    (0xB4A4, 0xB4A4, GraphemeClusterBreak::Lv),
+    (0xB4A5, 0xB4BF, GraphemeClusterBreak::Lvt),
+    (0xB4C0, 0xB4C0, GraphemeClusterBreak::Lv),
+    (0xB4C1, 0xB4DB, GraphemeClusterBreak::Lvt),
+    (0xB4DC, 0xB4DC, GraphemeClusterBreak::Lv),
+    (0xB4DD, 0xB4F7, GraphemeClusterBreak::Lvt),
+    (0xB4F8, 0xB4F8, GraphemeClusterBreak::Lv),
+    (0xB4F9, 0xB513, GraphemeClusterBreak::Lvt),
+    (0xB514, 0xB514, GraphemeClusterBreak::Lv),
+    (0xB515, 0xB52F, GraphemeClusterBreak::Lvt),
+    (0xB530, 0xB530, GraphemeClusterBreak::Lv),
+    (0xB531, 0xB54B, GraphemeClusterBreak::Lvt),
+    (0xB54C, 0xB54C, GraphemeClusterBreak::Lv),
+    (0xB54D, 0xB567, GraphemeClusterBreak::Lvt),
+    (0xB568, 0xB568, GraphemeClusterBreak::Lv),
+    (0xB569, 0xB583, GraphemeClusterBreak::Lvt),
+    (0xB584, 0xB584, GraphemeClusterBreak::Lv),
+    (0xB585, 0xB59F, GraphemeClusterBreak::Lvt),
+    (0xB5A0, 0xB5A0, GraphemeClusterBreak::Lv),
+    (0xB5A1, 0xB5BB, GraphemeClusterBreak::Lvt),
+    (0xB5BC, 0xB5BC, GraphemeClusterBreak::Lv),
+    (0xB5BD, 0xB5D7, GraphemeClusterBreak::Lvt),
+    (0xB5D8, 0xB5D8, GraphemeClusterBreak::Lv),
+    (0xB5D9, 0xB5F3, GraphemeClusterBreak::Lvt),
+    (0xB5F4, 0xB5F4, GraphemeClusterBreak::Lv),
+    (0xB5F5, 0xB60F, GraphemeClusterBreak::Lvt),
+    (0xB610, 0xB610, GraphemeClusterBreak::Lv),
+    (0xB611, 0xB62B, GraphemeClusterBreak::Lvt),
+    (0xB62C, 0xB62C, GraphemeClusterBreak::Lv),
+    (0xB62D, 0xB647, GraphemeClusterBreak::Lvt),
+    (0xB648, 0xB648, GraphemeClusterBreak::Lv),
+    (0xB649, 0xB663, GraphemeClusterBreak::Lvt),
+    (0xB664, 0xB664, GraphemeClusterBreak::Lv),
+    (0xB665, 0xB67F, GraphemeClusterBreak::Lvt),
+    (0xB680, 0xB680, GraphemeClusterBreak::Lv),
+    (0xB681, 0xB69B, GraphemeClusterBreak::Lvt),
+    (0xB69C, 0xB69C, GraphemeClusterBreak::Lv),
+    (0xB69D, 0xB6B7, GraphemeClusterBreak::Lvt),
+    (0xB6B8, 0xB6B8, GraphemeClusterBreak::Lv),
+    (0xB6B9, 0xB6D3, GraphemeClusterBreak::Lvt),
+    (0xB6D4, 0xB6D4, GraphemeClusterBreak::Lv),
+    (0xB6D5, 0xB6EF, GraphemeClusterBreak::Lvt),
+    (0xB6F0, 0xB6F0, GraphemeClusterBreak::Lv),
+    (0xB6F1, 0xB70B, GraphemeClusterBreak::Lvt),
+    (0xB70C, 0xB70C, GraphemeClusterBreak::Lv),
+    (0xB70D, 0xB727, GraphemeClusterBreak::Lvt),
+    (0xB728, 0xB728, GraphemeClusterBreak::Lv),
+    (0xB729, 0xB743, GraphemeClusterBreak::Lvt),
+    (0xB744, 0xB744, GraphemeClusterBreak::Lv),
+    (0xB745, 0xB75F, GraphemeClusterBreak::Lvt),
+    (0xB760, 0xB760, GraphemeClusterBreak::Lv),
+    (0xB761, 0xB77B, GraphemeClusterBreak::Lvt),
+    (0xB77C, 0xB77C, GraphemeClusterBreak::Lv),
+    (0xB77D, 0xB797, GraphemeClusterBreak::Lvt),
+    (0xB798, 0xB798, GraphemeClusterBreak::Lv),
+    (0xB799, 0xB7B3, GraphemeClusterBreak::Lvt),
+    (0xB7B4, 0xB7B4, GraphemeClusterBreak::Lv),
+    (0xB7B5, 0xB7CF, GraphemeClusterBreak::Lvt),
+    (0xB7D0, 0xB7D0, GraphemeClusterBreak::Lv),
+    (0xB7D1, 0xB7EB, GraphemeClusterBreak::Lvt),
+    (0xB7EC, 0xB7EC, GraphemeClusterBreak::Lv),
+    (0xB7ED, 0xB807, GraphemeClusterBreak::Lvt),
+    (0xB808, 0xB808, GraphemeClusterBreak::Lv),
+    (0xB809, 0xB823, GraphemeClusterBreak::Lvt),
+    (0xB824, 0xB824, GraphemeClusterBreak::Lv),
+    (0xB825, 0xB83F, GraphemeClusterBreak::Lvt),
+    (0xB840, 0xB840, GraphemeClusterBreak::Lv),
+    (0xB841, 0xB85B, GraphemeClusterBreak::Lvt),
+    (0xB85C, 0xB85C, GraphemeClusterBreak::Lv),
+    (0xB85D, 0xB877, GraphemeClusterBreak::Lvt),
+    (0xB878, 0xB878, GraphemeClusterBreak::Lv),
+    (0xB879, 0xB893, GraphemeClusterBreak::Lvt),
+    (0xB894, 0xB894, GraphemeClusterBreak::Lv),
+    (0xB895, 0xB8AF, GraphemeClusterBreak::Lvt),
+    (0xB8B0, 0xB8B0, GraphemeClusterBreak::Lv),
+    (0xB8B1, 0xB8CB, GraphemeClusterBreak::Lvt),
+    (0xB8CC, 0xB8CC, GraphemeClusterBreak::Lv),
+    (0xB8CD, 0xB8E7, GraphemeClusterBreak::Lvt),
+    (0xB8E8, 0xB8E8, GraphemeClusterBreak::Lv),
+    (0xB8E9, 0xB903, GraphemeClusterBreak::Lvt),
+    (0xB904, 0xB904, GraphemeClusterBreak::Lv),
+    (0xB905, 0xB91F, GraphemeClusterBreak::Lvt),
+    (0xB920, 0xB920, GraphemeClusterBreak::Lv),
+    (0xB921, 0xB93B, GraphemeClusterBreak::Lvt),
+    (0xB93C, 0xB93C, GraphemeClusterBreak::Lv),
+    (0xB93D, 0xB957, GraphemeClusterBreak::Lvt),
+    (0xB958, 0xB958, GraphemeClusterBreak::Lv),
+    (0xB959, 0xB973, GraphemeClusterBreak::Lvt),
+    (0xB974, 0xB974, GraphemeClusterBreak::Lv),
+    (0xB975, 0xB98F, GraphemeClusterBreak::Lvt),
+    (0xB990, 0xB990, GraphemeClusterBreak::Lv),
+    (0xB991, 0xB9AB, GraphemeClusterBreak::Lvt),
+    (0xB9AC, 0xB9AC, GraphemeClusterBreak::Lv),
+    (0xB9AD, 0xB9C7, GraphemeClusterBreak::Lvt),
+    (0xB9C8, 0xB9C8, GraphemeClusterBreak::Lv),
+    (0xB9C9, 0xB9E3, GraphemeClusterBreak::Lvt),
+    (0xB9E4, 0xB9E4, GraphemeClusterBreak::Lv),
+    (0xB9E5, 0xB9FF, GraphemeClusterBreak::Lvt),
+    (0xBA00, 0xBA00, GraphemeClusterBreak::Lv),
+    (0xBA01, 0xBA1B, GraphemeClusterBreak::Lvt),
+    (0xBA1C, 0xBA1C, GraphemeClusterBreak::Lv),
+    (0xBA1D, 0xBA37, GraphemeClusterBreak::Lvt),
+    (0xBA38, 0xBA38, GraphemeClusterBreak::Lv),
+    (0xBA39, 0xBA53, GraphemeClusterBreak::Lvt),
+    (0xBA54, 0xBA54, GraphemeClusterBreak::Lv),
+    (0xBA55, 0xBA6F, GraphemeClusterBreak::Lvt),
+    (0xBA70, 0xBA70, GraphemeClusterBreak::Lv),
+    (0xBA71, 0xBA8B, GraphemeClusterBreak::Lvt),
+    (0xBA8C, 0xBA8C, GraphemeClusterBreak::Lv),
+    (0xBA8D, 0xBAA7, GraphemeClusterBreak::Lvt),
+    (0xBAA8, 0xBAA8, GraphemeClusterBreak::Lv),
+    (0xBAA9, 0xBAC3, GraphemeClusterBreak::Lvt),
+    (0xBAC4, 0xBAC4, GraphemeClusterBreak::Lv),
+    (0xBAC5, 0xBADF, GraphemeClusterBreak::Lvt),
+    (0xBAE0, 0xBAE0, GraphemeClusterBreak::Lv),
+    (0xBAE1, 0xBAFB, GraphemeClusterBreak::Lvt),
+    (0xBAFC, 0xBAFC, GraphemeClusterBreak::Lv),
+    (0xBAFD, 0xBB17, GraphemeClusterBreak::Lvt),
+    (0xBB18, 0xBB18, GraphemeClusterBreak::Lv),
+    (0xBB19, 0xBB33, GraphemeClusterBreak::Lvt),
+    (0xBB34, 0xBB34, GraphemeClusterBreak::Lv),
+    (0xBB35, 0xBB4F, GraphemeClusterBreak::Lvt),
+    (0xBB50, 0xBB50, GraphemeClusterBreak::Lv),
+    (0xBB51, 0xBB6B, GraphemeClusterBreak::Lvt),
+    (0xBB6C, 0xBB6C, GraphemeClusterBreak::Lv),
+    (0xBB6D, 0xBB87, GraphemeClusterBreak::Lvt),
+    (0xBB88, 0xBB88, GraphemeClusterBreak::Lv),
+    (0xBB89, 0xBBA3, GraphemeClusterBreak::Lvt),
+    (0xBBA4, 0xBBA4, GraphemeClusterBreak::Lv),
+    (0xBBA5, 0xBBBF, GraphemeClusterBreak::Lvt),
+    (0xBBC0, 0xBBC0, GraphemeClusterBreak::Lv),
+    (0xBBC1, 0xBBDB, GraphemeClusterBreak::Lvt),
+    (0xBBDC, 0xBBDC, GraphemeClusterBreak::Lv),
+    (0xBBDD, 0xBBF7, GraphemeClusterBreak::Lvt),
+    (0xBBF8, 0xBBF8, GraphemeClusterBreak::Lv),
+    (0xBBF9, 0xBC13, GraphemeClusterBreak::Lvt),
+    (0xBC14, 0xBC14, GraphemeClusterBreak::Lv),
+    (0xBC15, 0xBC2F, GraphemeClusterBreak::Lvt),
+    (0xBC30, 0xBC30, GraphemeClusterBreak::Lv),
+    (0xBC31, 0xBC4B, GraphemeClusterBreak::Lvt),
+    (0xBC4C, 0xBC4C, GraphemeClusterBreak::Lv),
+    (0xBC4D, 0xBC67, GraphemeClusterBreak::Lvt),
+    (0xBC68, 0xBC68, GraphemeClusterBreak::Lv),
+    (0xBC69, 0xBC83, GraphemeClusterBreak::Lvt),
+    (0xBC84, 0xBC84, GraphemeClusterBreak::Lv),
+    (0xBC85, 0xBC9F, GraphemeClusterBreak::Lvt),
+    (0xBCA0, 0xBCA0, GraphemeClusterBreak::Lv),
+    (0xBCA1, 0xBCBB, GraphemeClusterBreak::Lvt),
+    (0xBCBC, 0xBCBC, GraphemeClusterBreak::Lv),
+    (0xBCBD, 0xBCD7, GraphemeClusterBreak::Lvt),
+    (0xBCD8, 0xBCD8, GraphemeClusterBreak::Lv),
+    (0xBCD9, 0xBCF3, GraphemeClusterBreak::Lvt),
+    (0xBCF4, 0xBCF4, GraphemeClusterBreak::Lv),
+    (0xBCF5, 0xBD0F, GraphemeClusterBreak::Lvt),
+    (0xBD10, 0xBD10, GraphemeClusterBreak::Lv),
+    (0xBD11, 0xBD2B, GraphemeClusterBreak::Lvt),
+    (0xBD2C, 0xBD2C, GraphemeClusterBreak::Lv),
+    (0xBD2D, 0xBD47, GraphemeClusterBreak::Lvt),
+    (0xBD48, 0xBD48, GraphemeClusterBreak::Lv),
+    (0xBD49, 0xBD63, GraphemeClusterBreak::Lvt),
+    (0xBD64, 0xBD64, GraphemeClusterBreak::Lv),
+    (0xBD65, 0xBD7F, GraphemeClusterBreak::Lvt),
+    (0xBD80, 0xBD80, GraphemeClusterBreak::Lv),
+    (0xBD81, 0xBD9B, GraphemeClusterBreak::Lvt),
+    (0xBD9C, 0xBD9C, GraphemeClusterBreak::Lv),
+    (0xBD9D, 0xBDB7, GraphemeClusterBreak::Lvt),
+    (0xBDB8, 0xBDB8, GraphemeClusterBreak::Lv),
+    (0xBDB9, 0xBDD3, GraphemeClusterBreak::Lvt),
+    (0xBDD4, 0xBDD4, GraphemeClusterBreak::Lv),
+    (0xBDD5, 0xBDEF, GraphemeClusterBreak::Lvt),
+    (0xBDF0, 0xBDF0, GraphemeClusterBreak::Lv),
+    (0xBDF1, 0xBE0B, GraphemeClusterBreak::Lvt),
+    (0xBE0C, 0xBE0C, GraphemeClusterBreak::Lv),
+    (0xBE0D, 0xBE27, GraphemeClusterBreak::Lvt),
+    (0xBE28, 0xBE28, GraphemeClusterBreak::Lv),
+    (0xBE29, 0xBE43, GraphemeClusterBreak::Lvt),
+    (0xBE44, 0xBE44, GraphemeClusterBreak::Lv),
+    (0xBE45, 0xBE5F, GraphemeClusterBreak::Lvt),
+    (0xBE60, 0xBE60, GraphemeClusterBreak::Lv),
+    (0xBE61, 0xBE7B, GraphemeClusterBreak::Lvt),
+    (0xBE7C, 0xBE7C, GraphemeClusterBreak::Lv),
+    (0xBE7D, 0xBE97, GraphemeClusterBreak::Lvt),
+    (0xBE98, 0xBE98, GraphemeClusterBreak::Lv),
+    (0xBE99, 0xBEB3, GraphemeClusterBreak::Lvt),
+    (0xBEB4, 0xBEB4, GraphemeClusterBreak::Lv),
+    (0xBEB5, 0xBECF, GraphemeClusterBreak::Lvt),
+    (0xBED0, 0xBED0, GraphemeClusterBreak::Lv),
+    (0xBED1, 0xBEEB, GraphemeClusterBreak::Lvt),
+    (0xBEEC, 0xBEEC, GraphemeClusterBreak::Lv),
+    (0xBEED, 0xBF07, GraphemeClusterBreak::Lvt),
+    (0xBF08, 0xBF08, GraphemeClusterBreak::Lv),
+    (0xBF09, 0xBF23, GraphemeClusterBreak::Lvt),
+    (0xBF24, 0xBF24, GraphemeClusterBreak::Lv),
+    (0xBF25, 0xBF3F, GraphemeClusterBreak::Lvt),
+    (0xBF40, 0xBF40, GraphemeClusterBreak::Lv),
+    (0xBF41, 0xBF5B, GraphemeClusterBreak::Lvt),
+    (0xBF5C, 0xBF5C, GraphemeClusterBreak::Lv),
+    (0xBF5D, 0xBF77, GraphemeClusterBreak::Lvt),
+    (0xBF78, 0xBF78, GraphemeClusterBreak::Lv),
+    (0xBF79, 0xBF93, GraphemeClusterBreak::Lvt),
+    (0xBF94, 0xBF94, GraphemeClusterBreak::Lv),
+    (0xBF95, 0xBFAF, GraphemeClusterBreak::Lvt),
+    (0xBFB0, 0xBFB0, GraphemeClusterBreak::Lv),
+    (0xBFB1, 0xBFCB, GraphemeClusterBreak::Lvt),
+    (0xBFCC, 0xBFCC, GraphemeClusterBreak::Lv),
+    (0xBFCD, 0xBFE7, GraphemeClusterBreak::Lvt),
+    (0xBFE8, 0xBFE8, GraphemeClusterBreak::Lv),
+    (0xBFE9, 0xC003, GraphemeClusterBreak::Lvt),
+    (0xC004, 0xC004, GraphemeClusterBreak::Lv),
+    (0xC005, 0xC01F, GraphemeClusterBreak::Lvt),
+    (0xC020, 0xC020, GraphemeClusterBreak::Lv),
+    (0xC021, 0xC03B, GraphemeClusterBreak::Lvt),
+    (0xC03C, 0xC03C, GraphemeClusterBreak::Lv),
+    (0xC03D, 0xC057, GraphemeClusterBreak::Lvt),
+    (0xC058, 0xC058, GraphemeClusterBreak::Lv),
+    (0xC059, 0xC073, GraphemeClusterBreak::Lvt),
+    (0xC074, 0xC074, GraphemeClusterBreak::Lv),
+    (0xC075, 0xC08F, GraphemeClusterBreak::Lvt),
+    (0xC090, 0xC090, GraphemeClusterBreak::Lv),
+    (0xC091, 0xC0AB, GraphemeClusterBreak::Lvt),
+    (0xC0AC, 0xC0AC, GraphemeClusterBreak::Lv),
+    (0xC0AD, 0xC0C7, GraphemeClusterBreak::Lvt),
+    (0xC0C8, 0xC0C8, GraphemeClusterBreak::Lv),
+    (0xC0C9, 0xC0E3, GraphemeClusterBreak::Lvt),
+    (0xC0E4, 0xC0E4, GraphemeClusterBreak::Lv),
+    (0xC0E5, 0xC0FF, GraphemeClusterBreak::Lvt),
+    (0xC100, 0xC100, GraphemeClusterBreak::Lv),
+    (0xC101, 0xC11B, GraphemeClusterBreak::Lvt),
+    (0xC11C, 0xC11C, GraphemeClusterBreak::Lv),
+    (0xC11D, 0xC137, GraphemeClusterBreak::Lvt),
+    (0xC138, 0xC138, GraphemeClusterBreak::Lv),
+    (0xC139, 0xC153, GraphemeClusterBreak::Lvt),
+    (0xC154, 0xC154, GraphemeClusterBreak::Lv),
+    (0xC155, 0xC16F, GraphemeClusterBreak::Lvt),
+    (0xC170, 0xC170, GraphemeClusterBreak::Lv),
+    (0xC171, 0xC18B, GraphemeClusterBreak::Lvt),
+    (0xC18C, 0xC18C, GraphemeClusterBreak::Lv),
+    (0xC18D, 0xC1A7, GraphemeClusterBreak::Lvt),
+    (0xC1A8, 0xC1A8, GraphemeClusterBreak::Lv),
+    (0xC1A9, 0xC1C3, GraphemeClusterBreak::Lvt),
+    (0xC1C4, 0xC1C4, GraphemeClusterBreak::Lv),
+    (0xC1C5, 0xC1DF, GraphemeClusterBreak::Lvt),
+    (0xC1E0, 0xC1E0, GraphemeClusterBreak::Lv),
+    (0xC1E1, 0xC1FB, GraphemeClusterBreak::Lvt),
+    (0xC1FC, 0xC1FC, GraphemeClusterBreak::Lv),
+    (0xC1FD, 0xC217, GraphemeClusterBreak::Lvt),
+    (0xC218, 0xC218, GraphemeClusterBreak::Lv),
+    (0xC219, 0xC233, GraphemeClusterBreak::Lvt),
+    (0xC234, 0xC234, GraphemeClusterBreak::Lv),
+    (0xC235, 0xC24F, GraphemeClusterBreak::Lvt),
+    (0xC250, 0xC250, GraphemeClusterBreak::Lv),
+    (0xC251, 0xC26B, GraphemeClusterBreak::Lvt),
+    (0xC26C, 0xC26C, GraphemeClusterBreak::Lv),
+    (0xC26D, 0xC287, GraphemeClusterBreak::Lvt),
+    (0xC288, 0xC288, GraphemeClusterBreak::Lv),
+    (0xC289, 0xC2A3, GraphemeClusterBreak::Lvt),
+    (0xC2A4, 0xC2A4, GraphemeClusterBreak::Lv),
+    (0xC2A5, 0xC2BF, GraphemeClusterBreak::Lvt),
+    (0xC2C0, 0xC2C0, GraphemeClusterBreak::Lv),
+    (0xC2C1, 0xC2DB, GraphemeClusterBreak::Lvt),
+    (0xC2DC, 0xC2DC, GraphemeClusterBreak::Lv),
+    (0xC2DD, 0xC2F7, GraphemeClusterBreak::Lvt),
+    (0xC2F8, 0xC2F8, GraphemeClusterBreak::Lv),
+    (0xC2F9, 0xC313, GraphemeClusterBreak::Lvt),
+    (0xC314, 0xC314, GraphemeClusterBreak::Lv),
+    (0xC315, 0xC32F, GraphemeClusterBreak::Lvt),
+    (0xC330, 0xC330, GraphemeClusterBreak::Lv),
+    (0xC331, 0xC34B, GraphemeClusterBreak::Lvt),
+    (0xC34C, 0xC34C, GraphemeClusterBreak::Lv),
+    (0xC34D, 0xC367, GraphemeClusterBreak::Lvt),
+    (0xC368, 0xC368, GraphemeClusterBreak::Lv),
+    (0xC369, 0xC383, GraphemeClusterBreak::Lvt),
+    (0xC384, 0xC384, GraphemeClusterBreak::Lv),
+    (0xC385, 0xC39F, GraphemeClusterBreak::Lvt),
+    (0xC3A0, 0xC3A0, GraphemeClusterBreak::Lv),
+    (0xC3A1, 0xC3BB, GraphemeClusterBreak::Lvt),
+    (0xC3BC, 0xC3BC, GraphemeClusterBreak::Lv),
+    (0xC3BD, 0xC3D7, GraphemeClusterBreak::Lvt),
+    (0xC3D8, 0xC3D8, GraphemeClusterBreak::Lv),
+    (0xC3D9, 0xC3F3, GraphemeClusterBreak::Lvt),
+    (0xC3F4, 0xC3F4, GraphemeClusterBreak::Lv),
+    (0xC3F5, 0xC40F, GraphemeClusterBreak::Lvt),
+    (0xC410, 0xC410, GraphemeClusterBreak::Lv),
+    (0xC411, 0xC42B, GraphemeClusterBreak::Lvt),
+    (0xC42C, 0xC42C, GraphemeClusterBreak::Lv),
+    (0xC42D, 0xC447, GraphemeClusterBreak::Lvt),
+    (0xC448, 0xC448, GraphemeClusterBreak::Lv),
+    (0xC449, 0xC463, GraphemeClusterBreak::Lvt),
+    (0xC464, 0xC464, GraphemeClusterBreak::Lv),
+    (0xC465, 0xC47F, GraphemeClusterBreak::Lvt),
+    (0xC480, 0xC480, GraphemeClusterBreak::Lv),
+    (0xC481, 0xC49B, GraphemeClusterBreak::Lvt),
+    (0xC49C, 0xC49C, GraphemeClusterBreak::Lv),
+    (0xC49D, 0xC4B7, GraphemeClusterBreak::Lvt),
+    (0xC4B8, 0xC4B8, GraphemeClusterBreak::Lv),
+    (0xC4B9, 0xC4D3, GraphemeClusterBreak::Lvt),
+    (0xC4D4, 0xC4D4, GraphemeClusterBreak::Lv),
+    (0xC4D5, 0xC4EF, GraphemeClusterBreak::Lvt),
+    (0xC4F0, 0xC4F0, GraphemeClusterBreak::Lv),
+    (0xC4F1, 0xC50B, GraphemeClusterBreak::Lvt),
+    (0xC50C, 0xC50C, GraphemeClusterBreak::Lv),
+    (0xC50D, 0xC527, GraphemeClusterBreak::Lvt),
+    (0xC528, 0xC528, GraphemeClusterBreak::Lv),
+    (0xC529, 0xC543, GraphemeClusterBreak::Lvt),
+    (0xC544, 0xC544, GraphemeClusterBreak::Lv),
+    (0xC545, 0xC55F, GraphemeClusterBreak::Lvt),
+    (0xC560, 0xC560, GraphemeClusterBreak::Lv),
+    (0xC561, 0xC57B, GraphemeClusterBreak::Lvt),
+    (0xC57C, 0xC57C, GraphemeClusterBreak::Lv),
+    (0xC57D, 0xC597, GraphemeClusterBreak::Lvt),
+    (0xC598, 0xC598, GraphemeClusterBreak::Lv),
+    (0xC599, 0xC5B3, GraphemeClusterBreak::Lvt),
+    (0xC5B4, 0xC5B4, GraphemeClusterBreak::Lv),
+    (0xC5B5, 0xC5CF, GraphemeClusterBreak::Lvt),
+    (0xC5D0, 0xC5D0, GraphemeClusterBreak::Lv),
+    (0xC5D1, 0xC5EB, GraphemeClusterBreak::Lvt),
+    (0xC5EC, 0xC5EC, GraphemeClusterBreak::Lv),
+    (0xC5ED, 0xC607, GraphemeClusterBreak::Lvt),
+    (0xC608, 0xC608, GraphemeClusterBreak::Lv),
+    (0xC609, 0xC623, GraphemeClusterBreak::Lvt),
+    (0xC624, 0xC624, GraphemeClusterBreak::Lv),
+    (0xC625, 0xC63F, GraphemeClusterBreak::Lvt),
+    (0xC640, 0xC640, GraphemeClusterBreak::Lv),
+    (0xC641, 0xC65B, GraphemeClusterBreak::Lvt),
+    (0xC65C, 0xC65C, GraphemeClusterBreak::Lv),
+    (0xC65D, 0xC677, GraphemeClusterBreak::Lvt),
+    (0xC678, 0xC678, GraphemeClusterBreak::Lv),
+    (0xC679, 0xC693, GraphemeClusterBreak::Lvt),
+    (0xC694, 0xC694, GraphemeClusterBreak::Lv),
+    (0xC695, 0xC6AF, GraphemeClusterBreak::Lvt),
+    (0xC6B0, 0xC6B0, GraphemeClusterBreak::Lv),
+    (0xC6B1, 0xC6CB, GraphemeClusterBreak::Lvt),
+    (0xC6CC, 0xC6CC, GraphemeClusterBreak::Lv),
+    (0xC6CD, 0xC6E7, GraphemeClusterBreak::Lvt),
+    (0xC6E8, 0xC6E8, GraphemeClusterBreak::Lv),
+    (0xC6E9, 0xC703, GraphemeClusterBreak::Lvt),
+    (0xC704, 0xC704, GraphemeClusterBreak::Lv),
+    (0xC705, 0xC71F, GraphemeClusterBreak::Lvt),
+    (0xC720, 0xC720, GraphemeClusterBreak::Lv),
+    (0xC721, 0xC73B, GraphemeClusterBreak::Lvt),
+    (0xC73C, 0xC73C, GraphemeClusterBreak::Lv),
+    (0xC73D, 0xC757, GraphemeClusterBreak::Lvt),
+    (0xC758, 0xC758, GraphemeClusterBreak::Lv),
+    (0xC759, 0xC773, GraphemeClusterBreak::Lvt),
+    (0xC774, 0xC774, GraphemeClusterBreak::Lv),
+    (0xC775, 0xC78F, GraphemeClusterBreak::Lvt),
+    (0xC790, 0xC790, GraphemeClusterBreak::Lv),
+    (0xC791, 0xC7AB, GraphemeClusterBreak::Lvt),
+    (0xC7AC, 0xC7AC, GraphemeClusterBreak::Lv),
+    (0xC7AD, 0xC7C7, GraphemeClusterBreak::Lvt),
+    (0xC7C8, 0xC7C8, GraphemeClusterBreak::Lv),
+    (0xC7C9, 0xC7E3, GraphemeClusterBreak::Lvt),
+    (0xC7E4, 0xC7E4, GraphemeClusterBreak::Lv),
+    (0xC7E5, 0xC7FF, GraphemeClusterBreak::Lvt),
+    (0xC800, 0xC800, GraphemeClusterBreak::Lv),
+    (0xC801, 0xC81B, GraphemeClusterBreak::Lvt),
+    (0xC81C, 0xC81C, GraphemeClusterBreak::Lv),
+    (0xC81D, 0xC837, GraphemeClusterBreak::Lvt),
+    (0xC838, 0xC838, GraphemeClusterBreak::Lv),
+    (0xC839, 0xC853, GraphemeClusterBreak::Lvt),
+    (0xC854, 0xC854, GraphemeClusterBreak::Lv),
+    (0xC855, 0xC86F, GraphemeClusterBreak::Lvt),
+    (0xC870, 0xC870, GraphemeClusterBreak::Lv),
+    (0xC871, 0xC88B, GraphemeClusterBreak::Lvt),
+    (0xC88C, 0xC88C, GraphemeClusterBreak::Lv),
+    (0xC88D, 0xC8A7, GraphemeClusterBreak::Lvt),
+    (0xC8A8, 0xC8A8, GraphemeClusterBreak::Lv),
+    (0xC8A9, 0xC8C3, GraphemeClusterBreak::Lvt),
+    (0xC8C4, 0xC8C4, GraphemeClusterBreak::Lv),
+    (0xC8C5, 0xC8DF, GraphemeClusterBreak::Lvt),
+    (0xC8E0, 0xC8E0, GraphemeClusterBreak::Lv),
+    (0xC8E1, 0xC8FB, GraphemeClusterBreak::Lvt),
+    (0xC8FC, 0xC8FC, GraphemeClusterBreak::Lv),
+    (0xC8FD, 0xC917, GraphemeClusterBreak::Lvt),
+    (0xC918, 0xC918, GraphemeClusterBreak::Lv),
+    (0xC919, 0xC933, GraphemeClusterBreak::Lvt),
+    (0xC934, 0xC934, GraphemeClusterBreak::Lv),
+    (0xC935, 0xC94F, GraphemeClusterBreak::Lvt),
+    (0xC950, 0xC950, GraphemeClusterBreak::Lv),
+    (0xC951, 0xC96B, GraphemeClusterBreak::Lvt),
+    (0xC96C, 0xC96C, GraphemeClusterBreak::Lv),
+    (0xC96D, 0xC987, GraphemeClusterBreak::Lvt),
+    (0xC988, 0xC988, GraphemeClusterBreak::Lv),
+    (0xC989, 0xC9A3, GraphemeClusterBreak::Lvt),
+    (0xC9A4, 0xC9A4, GraphemeClusterBreak::Lv),
+    (0xC9A5, 0xC9BF, GraphemeClusterBreak::Lvt),
+    (0xC9C0, 0xC9C0, GraphemeClusterBreak::Lv),
+    (0xC9C1, 0xC9DB, GraphemeClusterBreak::Lvt),
+    (0xC9DC, 0xC9DC, GraphemeClusterBreak::Lv),
+    (0xC9DD, 0xC9F7, GraphemeClusterBreak::Lvt),
+    (0xC9F8, 0xC9F8, GraphemeClusterBreak::Lv),
+    (0xC9F9, 0xCA13, GraphemeClusterBreak::Lvt),
+    (0xCA14, 0xCA14, GraphemeClusterBreak::Lv),
+    (0xCA15, 0xCA2F, GraphemeClusterBreak::Lvt),
+    (0xCA30, 0xCA30, GraphemeClusterBreak::Lv),
+    (0xCA31, 0xCA4B, GraphemeClusterBreak::Lvt),
+    (0xCA4C, 0xCA4C, GraphemeClusterBreak::Lv),
+    (0xCA4D, 0xCA67, GraphemeClusterBreak::Lvt),
+    (0xCA68, 0xCA68, GraphemeClusterBreak::Lv),
+    (0xCA69, 0xCA83, GraphemeClusterBreak::Lvt),
+    (0xCA84, 0xCA84, GraphemeClusterBreak::Lv),
+    (0xCA85, 0xCA9F, GraphemeClusterBreak::Lvt),
+    (0xCAA0, 0xCAA0, GraphemeClusterBreak::Lv),
+    (0xCAA1, 0xCABB, GraphemeClusterBreak::Lvt),
+    (0xCABC, 0xCABC, GraphemeClusterBreak::Lv),
+    (0xCABD, 0xCAD7, GraphemeClusterBreak::Lvt),
+    (0xCAD8, 0xCAD8, GraphemeClusterBreak::Lv),
+    (0xCAD9, 0xCAF3, GraphemeClusterBreak::Lvt),
+    (0xCAF4, 0xCAF4, GraphemeClusterBreak::Lv),
+    (0xCAF5, 0xCB0F, GraphemeClusterBreak::Lvt),
+    (0xCB10, 0xCB10, GraphemeClusterBreak::Lv),
+    (0xCB11, 0xCB2B, GraphemeClusterBreak::Lvt),
+    (0xCB2C, 0xCB2C, GraphemeClusterBreak::Lv),
+    (0xCB2D, 0xCB47, GraphemeClusterBreak::Lvt),
+    (0xCB48, 0xCB48, GraphemeClusterBreak::Lv),
+    (0xCB49, 0xCB63, GraphemeClusterBreak::Lvt),
+    (0xCB64, 0xCB64, GraphemeClusterBreak::Lv),
+    (0xCB65, 0xCB7F, GraphemeClusterBreak::Lvt),
+    (0xCB80, 0xCB80, GraphemeClusterBreak::Lv),
+    (0xCB81, 0xCB9B, GraphemeClusterBreak::Lvt),
+    (0xCB9C, 0xCB9C, GraphemeClusterBreak::Lv),
+    (0xCB9D, 0xCBB7, GraphemeClusterBreak::Lvt),
+    (0xCBB8, 0xCBB8, GraphemeClusterBreak::Lv),
+    (0xCBB9, 0xCBD3, GraphemeClusterBreak::Lvt),
+    (0xCBD4, 0xCBD4, GraphemeClusterBreak::Lv),
+    (0xCBD5, 0xCBEF, GraphemeClusterBreak::Lvt),
+    (0xCBF0, 0xCBF0, GraphemeClusterBreak::Lv),
+    (0xCBF1, 0xCC0B, GraphemeClusterBreak::Lvt),
+    (0xCC0C, 0xCC0C, GraphemeClusterBreak::Lv),
+    (0xCC0D, 0xCC27, GraphemeClusterBreak::Lvt),
+    (0xCC28, 0xCC28, GraphemeClusterBreak::Lv),
+    (0xCC29, 0xCC43, GraphemeClusterBreak::Lvt),
+    (0xCC44, 0xCC44, GraphemeClusterBreak::Lv),
+    (0xCC45, 0xCC5F, GraphemeClusterBreak::Lvt),
+    (0xCC60, 0xCC60, GraphemeClusterBreak::Lv),
+    (0xCC61, 0xCC7B, GraphemeClusterBreak::Lvt),
+    (0xCC7C, 0xCC7C, GraphemeClusterBreak::Lv),
+    (0xCC7D, 0xCC97, GraphemeClusterBreak::Lvt),
+    (0xCC98, 0xCC98, GraphemeClusterBreak::Lv),
+    (0xCC99, 0xCCB3, GraphemeClusterBreak::Lvt),
+    (0xCCB4, 0xCCB4, GraphemeClusterBreak::Lv),
+    (0xCCB5, 0xCCCF, GraphemeClusterBreak::Lvt),
+    (0xCCD0, 0xCCD0, GraphemeClusterBreak::Lv),
+    (0xCCD1, 0xCCEB, GraphemeClusterBreak::Lvt),
+    (0xCCEC, 0xCCEC, GraphemeClusterBreak::Lv),
+    (0xCCED, 0xCD07, GraphemeClusterBreak::Lvt),
+    (0xCD08, 0xCD08, GraphemeClusterBreak::Lv),
+    (0xCD09, 0xCD23, GraphemeClusterBreak::Lvt),
+    (0xCD24, 0xCD24, GraphemeClusterBreak::Lv),
+    (0xCD25, 0xCD3F, GraphemeClusterBreak::Lvt),
+    (0xCD40, 0xCD40, GraphemeClusterBreak::Lv),
+    (0xCD41, 0xCD5B, GraphemeClusterBreak::Lvt),
+    (0xCD5C, 0xCD5C, GraphemeClusterBreak::Lv),
+    (0xCD5D, 0xCD77, GraphemeClusterBreak::Lvt),
+    (0xCD78, 0xCD78, GraphemeClusterBreak::Lv),
+    (0xCD79, 0xCD93, GraphemeClusterBreak::Lvt),
+    (0xCD94, 0xCD94, GraphemeClusterBreak::Lv),
+    (0xCD95, 0xCDAF, GraphemeClusterBreak::Lvt),
+    (0xCDB0, 0xCDB0, GraphemeClusterBreak::Lv),
+    (0xCDB1, 0xCDCB, GraphemeClusterBreak::Lvt),
+    (0xCDCC, 0xCDCC, GraphemeClusterBreak::Lv),
+    (0xCDCD, 0xCDE7, GraphemeClusterBreak::Lvt),
+    (0xCDE8, 0xCDE8, GraphemeClusterBreak::Lv),
+    (0xCDE9, 0xCE03, GraphemeClusterBreak::Lvt),
+    (0xCE04, 0xCE04, GraphemeClusterBreak::Lv),
+    (0xCE05, 0xCE1F, GraphemeClusterBreak::Lvt),
+    (0xCE20, 0xCE20, GraphemeClusterBreak::Lv),
+    (0xCE21, 0xCE3B, GraphemeClusterBreak::Lvt),
+    (0xCE3C, 0xCE3C, GraphemeClusterBreak::Lv),
+    (0xCE3D, 0xCE57, GraphemeClusterBreak::Lvt),
+    (0xCE58, 0xCE58, GraphemeClusterBreak::Lv),
+    (0xCE59, 0xCE73, GraphemeClusterBreak::Lvt),
+    (0xCE74, 0xCE74, GraphemeClusterBreak::Lv),
+    (0xCE75, 0xCE8F, GraphemeClusterBreak::Lvt),
+    (0xCE90, 0xCE90, GraphemeClusterBreak::Lv),
+    (0xCE91, 0xCEAB, GraphemeClusterBreak::Lvt),
+    (0xCEAC, 0xCEAC, GraphemeClusterBreak::Lv),
+    (0xCEAD, 0xCEC7, GraphemeClusterBreak::Lvt),
+    (0xCEC8, 0xCEC8, GraphemeClusterBreak::Lv),
+    (0xCEC9, 0xCEE3, GraphemeClusterBreak::Lvt),
+    (0xCEE4, 0xCEE4, GraphemeClusterBreak::Lv),
+    (0xCEE5, 0xCEFF, GraphemeClusterBreak::Lvt),
+    (0xCF00, 0xCF00, GraphemeClusterBreak::Lv),
+    (0xCF01, 0xCF1B, GraphemeClusterBreak::Lvt),
+    (0xCF1C, 0xCF1C, GraphemeClusterBreak::Lv),
+    (0xCF1D, 0xCF37, GraphemeClusterBreak::Lvt),
+    (0xCF38, 0xCF38, GraphemeClusterBreak::Lv),
+    (0xCF39, 0xCF53, GraphemeClusterBreak::Lvt),
+    (0xCF54, 0xCF54, GraphemeClusterBreak::Lv),
+    (0xCF55, 0xCF6F, GraphemeClusterBreak::Lvt),
+    (0xCF70, 0xCF70, GraphemeClusterBreak::Lv),
+    (0xCF71, 0xCF8B, GraphemeClusterBreak::Lvt),
+    (0xCF8C, 0xCF8C, GraphemeClusterBreak::Lv),
+    (0xCF8D, 0xCFA7, GraphemeClusterBreak::Lvt),
+    (0xCFA8, 0xCFA8, GraphemeClusterBreak::Lv),
+    (0xCFA9, 0xCFC3, GraphemeClusterBreak::Lvt),
+    (0xCFC4, 0xCFC4, GraphemeClusterBreak::Lv),
+    (0xCFC5, 0xCFDF, GraphemeClusterBreak::Lvt),
+    (0xCFE0, 0xCFE0, GraphemeClusterBreak::Lv),
+    (0xCFE1, 0xCFFB, GraphemeClusterBreak::Lvt),
+    (0xCFFC, 0xCFFC, GraphemeClusterBreak::Lv),
+    (0xCFFD, 0xD017, GraphemeClusterBreak::Lvt),
+    (0xD018, 0xD018, GraphemeClusterBreak::Lv),
+    (0xD019, 0xD033, GraphemeClusterBreak::Lvt),
+    (0xD034, 0xD034, GraphemeClusterBreak::Lv),
+    (0xD035, 0xD04F, GraphemeClusterBreak::Lvt),
+    (0xD050, 0xD050, GraphemeClusterBreak::Lv),
+    (0xD051, 0xD06B, GraphemeClusterBreak::Lvt),
+    (0xD06C, 0xD06C, GraphemeClusterBreak::Lv),
+    (0xD06D, 0xD087, GraphemeClusterBreak::Lvt),
+    (0xD088, 0xD088, GraphemeClusterBreak::Lv),
+    (0xD089, 0xD0A3, GraphemeClusterBreak::Lvt),
+    (0xD0A4, 0xD0A4, GraphemeClusterBreak::Lv),
+    (0xD0A5, 0xD0BF, GraphemeClusterBreak::Lvt),
+    (0xD0C0, 0xD0C0, GraphemeClusterBreak::Lv),
+    (0xD0C1, 0xD0DB, GraphemeClusterBreak::Lvt),
+    (0xD0DC, 0xD0DC, GraphemeClusterBreak::Lv),
+    (0xD0DD, 0xD0F7, GraphemeClusterBreak::Lvt),
+    (0xD0F8, 0xD0F8, GraphemeClusterBreak::Lv),
+    (0xD0F9, 0xD113, GraphemeClusterBreak::Lvt),
+    (0xD114, 0xD114, GraphemeClusterBreak::Lv),
+    (0xD115, 0xD12F, GraphemeClusterBreak::Lvt),
+    (0xD130, 0xD130, GraphemeClusterBreak::Lv),
+    (0xD131, 0xD14B, GraphemeClusterBreak::Lvt),
+    (0xD14C, 0xD14C, GraphemeClusterBreak::Lv),
+    (0xD14D, 0xD167, GraphemeClusterBreak::Lvt),
+    (0xD168, 0xD168, GraphemeClusterBreak::Lv),
+    (0xD169, 0xD183, GraphemeClusterBreak::Lvt),
+    (0xD184, 0xD184, GraphemeClusterBreak::Lv),
+    (0xD185, 0xD19F, GraphemeClusterBreak::Lvt),
+    (0xD1A0, 0xD1A0, GraphemeClusterBreak::Lv),
+    (0xD1A1, 0xD1BB, GraphemeClusterBreak::Lvt),
+    (0xD1BC, 0xD1BC, GraphemeClusterBreak::Lv),
+    (0xD1BD, 0xD1D7, GraphemeClusterBreak::Lvt),
+    (0xD1D8, 0xD1D8, GraphemeClusterBreak::Lv),
+    (0xD1D9, 0xD1F3, GraphemeClusterBreak::Lvt),
+    (0xD1F4, 0xD1F4, GraphemeClusterBreak::Lv),
+    (0xD1F5, 0xD20F, GraphemeClusterBreak::Lvt),
+    (0xD210, 0xD210, GraphemeClusterBreak::Lv),
+    (0xD211, 0xD22B, GraphemeClusterBreak::Lvt),
+    (0xD22C, 0xD22C, GraphemeClusterBreak::Lv),
+    (0xD22D, 0xD247, GraphemeClusterBreak::Lvt),
+    (0xD248, 0xD248, GraphemeClusterBreak::Lv),
+    (0xD249, 0xD263, GraphemeClusterBreak::Lvt),
+    (0xD264, 0xD264, GraphemeClusterBreak::Lv),
+    (0xD265, 0xD27F, GraphemeClusterBreak::Lvt),
+    (0xD280, 0xD280, GraphemeClusterBreak::Lv),
+    (0xD281, 0xD29B, GraphemeClusterBreak::Lvt),
+    (0xD29C, 0xD29C, GraphemeClusterBreak::Lv),
+    (0xD29D, 0xD2B7, GraphemeClusterBreak::Lvt),
+    (0xD2B8, 0xD2B8, GraphemeClusterBreak::Lv),
+    (0xD2B9, 0xD2D3, GraphemeClusterBreak::Lvt),
+    (0xD2D4, 0xD2D4, GraphemeClusterBreak::Lv),
+    (0xD2D5, 0xD2EF, GraphemeClusterBreak::Lvt),
+    (0xD2F0, 0xD2F0, GraphemeClusterBreak::Lv),
+    (0xD2F1, 0xD30B, GraphemeClusterBreak::Lvt),
+    (0xD30C, 0xD30C, GraphemeClusterBreak::Lv),
+    (0xD30D, 0xD327, GraphemeClusterBreak::Lvt),
+    (0xD328, 0xD328, GraphemeClusterBreak::Lv),
+    (0xD329, 0xD343, GraphemeClusterBreak::Lvt),
+    (0xD344, 0xD344, GraphemeClusterBreak::Lv),
+    (0xD345, 0xD35F, GraphemeClusterBreak::Lvt),
+    (0xD360, 0xD360, GraphemeClusterBreak::Lv),
+    (0xD361, 0xD37B, GraphemeClusterBreak::Lvt),
+    (0xD37C, 0xD37C, GraphemeClusterBreak::Lv),
+    (0xD37D, 0xD397, GraphemeClusterBreak::Lvt),
+    (0xD398, 0xD398, GraphemeClusterBreak::Lv),
+    (0xD399, 0xD3B3, GraphemeClusterBreak::Lvt),
+    (0xD3B4, 0xD3B4, GraphemeClusterBreak::Lv),
+    (0xD3B5, 0xD3CF, GraphemeClusterBreak::Lvt),
+    (0xD3D0, 0xD3D0, GraphemeClusterBreak::Lv),
+    (0xD3D1, 0xD3EB, GraphemeClusterBreak::Lvt),
+    (0xD3EC, 0xD3EC, GraphemeClusterBreak::Lv),
+    (0xD3ED, 0xD407, GraphemeClusterBreak::Lvt),
+    (0xD408, 0xD408, GraphemeClusterBreak::Lv),
+    (0xD409, 0xD423, GraphemeClusterBreak::Lvt),
+    (0xD424, 0xD424, GraphemeClusterBreak::Lv),
+    (0xD425, 0xD43F, GraphemeClusterBreak::Lvt),
+    (0xD440, 0xD440, GraphemeClusterBreak::Lv),
+    (0xD441, 0xD45B, GraphemeClusterBreak::Lvt),
+    (0xD45C, 0xD45C, GraphemeClusterBreak::Lv),
+    (0xD45D, 0xD477, GraphemeClusterBreak::Lvt),
+    (0xD478, 0xD478, GraphemeClusterBreak::Lv),
+    (0xD479, 0xD493, GraphemeClusterBreak::Lvt),
+    (0xD494, 0xD494, GraphemeClusterBreak::Lv),
+    (0xD495, 0xD4AF, GraphemeClusterBreak::Lvt),
+    (0xD4B0, 0xD4B0, GraphemeClusterBreak::Lv),
+    (0xD4B1, 0xD4CB, GraphemeClusterBreak::Lvt),
+    (0xD4CC, 0xD4CC, GraphemeClusterBreak::Lv),
+    (0xD4CD, 0xD4E7, GraphemeClusterBreak::Lvt),
+    (0xD4E8, 0xD4E8, GraphemeClusterBreak::Lv),
+    (0xD4E9, 0xD503, GraphemeClusterBreak::Lvt),
+    (0xD504, 0xD504, GraphemeClusterBreak::Lv),
+    (0xD505, 0xD51F, GraphemeClusterBreak::Lvt),
+    (0xD520, 0xD520, GraphemeClusterBreak::Lv),
+    (0xD521, 0xD53B, GraphemeClusterBreak::Lvt),
+    (0xD53C, 0xD53C, GraphemeClusterBreak::Lv),
+    (0xD53D, 0xD557, GraphemeClusterBreak::Lvt),
+    (0xD558, 0xD558, GraphemeClusterBreak::Lv),
+    (0xD559, 0xD573, GraphemeClusterBreak::Lvt),
+    (0xD574, 0xD574, GraphemeClusterBreak::Lv),
+    (0xD575, 0xD58F, GraphemeClusterBreak::Lvt),
+    (0xD590, 0xD590, GraphemeClusterBreak::Lv),
+    (0xD591, 0xD5AB, GraphemeClusterBreak::Lvt),
+    (0xD5AC, 0xD5AC, GraphemeClusterBreak::Lv),
+    (0xD5AD, 0xD5C7, GraphemeClusterBreak::Lvt),
+    (0xD5C8, 0xD5C8, GraphemeClusterBreak::Lv),
+    (0xD5C9, 0xD5E3, GraphemeClusterBreak::Lvt),
+    (0xD5E4, 0xD5E4, GraphemeClusterBreak::Lv),
+    (0xD5E5, 0xD5FF, GraphemeClusterBreak::Lvt),
+    (0xD600, 0xD600, GraphemeClusterBreak::Lv),
+    (0xD601, 0xD61B, GraphemeClusterBreak::Lvt),
+    (0xD61C, 0xD61C, GraphemeClusterBreak::Lv),
+    (0xD61D, 0xD637, GraphemeClusterBreak::Lvt),
+    (0xD638, 0xD638, GraphemeClusterBreak::Lv),
+    (0xD639, 0xD653, GraphemeClusterBreak::Lvt),
+    (0xD654, 0xD654, GraphemeClusterBreak::Lv),
+    (0xD655, 0xD66F, GraphemeClusterBreak::Lvt),
+    (0xD670, 0xD670, GraphemeClusterBreak::Lv),
+    (0xD671, 0xD68B, GraphemeClusterBreak::Lvt),
+    (0xD68C, 0xD68C, GraphemeClusterBreak::Lv),
+    (0xD68D, 0xD6A7, GraphemeClusterBreak::Lvt),
+    (0xD6A8, 0xD6A8, GraphemeClusterBreak::Lv),
+    (0xD6A9, 0xD6C3, GraphemeClusterBreak::Lvt),
+    (0xD6C4, 0xD6C4, GraphemeClusterBreak::Lv),
+    (0xD6C5, 0xD6DF, GraphemeClusterBreak::Lvt),
+    (0xD6E0, 0xD6E0, GraphemeClusterBreak::Lv),
+    (0xD6E1, 0xD6FB, GraphemeClusterBreak::Lvt),
+    (0xD6FC, 0xD6FC, GraphemeClusterBreak::Lv),
+    (0xD6FD, 0xD717, GraphemeClusterBreak::Lvt),
+    (0xD718, 0xD718, GraphemeClusterBreak::Lv),
+    (0xD719, 0xD733, GraphemeClusterBreak::Lvt),
+    (0xD734, 0xD734, GraphemeClusterBreak::Lv),
+    (0xD735, 0xD74F, GraphemeClusterBreak::Lvt),
+    (0xD750, 0xD750, GraphemeClusterBreak::Lv),
+    (0xD751, 0xD76B, GraphemeClusterBreak::Lvt),
+    (0xD76C, 0xD76C, GraphemeClusterBreak::Lv),
+    (0xD76D, 0xD787, GraphemeClusterBreak::Lvt),
+    (0xD788, 0xD788, GraphemeClusterBreak::Lv),
+    (0xD789, 0xD7A3, GraphemeClusterBreak::Lvt),
+    (0xD7B0, 0xD7C6, GraphemeClusterBreak::V),
+    (0xD7CB, 0xD7FB, GraphemeClusterBreak::T),
+    (0xFB1E, 0xFB1E, GraphemeClusterBreak::Extend),
+    (0xFE00, 0xFE0F, GraphemeClusterBreak::Extend),
+    (0xFE20, 0xFE2F, GraphemeClusterBreak::Extend),
+    (0xFEFF, 0xFEFF, GraphemeClusterBreak::Control),
+    (0xFF9E, 0xFF9F, GraphemeClusterBreak::Extend),
+    (0xFFF0, 0xFFFB, GraphemeClusterBreak::Control),
+    (0x101FD, 0x101FD, GraphemeClusterBreak::Extend),
+    (0x102E0, 0x102E0, GraphemeClusterBreak::Extend),
+    (0x10376, 0x1037A, GraphemeClusterBreak::Extend),
+    (0x10A01, 0x10A03, GraphemeClusterBreak::Extend),
+    (0x10A05, 0x10A06, GraphemeClusterBreak::Extend),
+    (0x10A0C, 0x10A0F, GraphemeClusterBreak::Extend),
+    (0x10A38, 0x10A3A, GraphemeClusterBreak::Extend),
+    (0x10A3F, 0x10A3F, GraphemeClusterBreak::Extend),
+    (0x10AE5, 0x10AE6, GraphemeClusterBreak::Extend),
+    (0x10D24, 0x10D27, GraphemeClusterBreak::Extend),
+    (0x10EAB, 0x10EAC, GraphemeClusterBreak::Extend),
+    (0x10F46, 0x10F50, GraphemeClusterBreak::Extend),
+    (0x10F82, 0x10F85, GraphemeClusterBreak::Extend),
+    (0x11000, 0x11000, GraphemeClusterBreak::SpacingMark),
+    (0x11001, 0x11001, GraphemeClusterBreak::Extend),
+    (0x11002, 0x11002, GraphemeClusterBreak::SpacingMark),
+    (0x11038, 0x11046, GraphemeClusterBreak::Extend),
+    (0x11070, 0x11070, GraphemeClusterBreak::Extend),
+    (0x11073, 0x11074, GraphemeClusterBreak::Extend),
+    (0x1107F, 0x11081, GraphemeClusterBreak::Extend),
+    (0x11082, 0x11082, GraphemeClusterBreak::SpacingMark),
+    (0x110B0, 0x110B2, GraphemeClusterBreak::SpacingMark),
+    (0x110B3, 0x110B6, GraphemeClusterBreak::Extend),
+    (0x110B7, 0x110B8, GraphemeClusterBreak::SpacingMark),
+    (0x110B9, 0x110BA, GraphemeClusterBreak::Extend),
+    (0x110BD, 0x110BD, GraphemeClusterBreak::Prepend),
+    (0x110C2, 0x110C2, GraphemeClusterBreak::Extend),
+    (0x110CD, 0x110CD, GraphemeClusterBreak::Prepend),
+    (0x11100, 0x11102, GraphemeClusterBreak::Extend),
+    (0x11127, 0x1112B, GraphemeClusterBreak::Extend),
+    (0x1112C, 0x1112C, GraphemeClusterBreak::SpacingMark),
+    (0x1112D, 0x11134, GraphemeClusterBreak::Extend),
+    (0x11145, 0x11146, GraphemeClusterBreak::SpacingMark),
+    (0x11173, 0x11173, GraphemeClusterBreak::Extend),
+    (0x11180, 0x11181, GraphemeClusterBreak::Extend),
+    (0x11182, 0x11182, GraphemeClusterBreak::SpacingMark),
+    (0x111B3, 0x111B5, GraphemeClusterBreak::SpacingMark),
+    (0x111B6, 0x111BE, GraphemeClusterBreak::Extend),
+    (0x111BF, 0x111C0, GraphemeClusterBreak::SpacingMark),
+    (0x111C2, 0x111C3, GraphemeClusterBreak::Prepend),
+    (0x111C9, 0x111CC, GraphemeClusterBreak::Extend),
+    (0x111CE, 0x111CE, GraphemeClusterBreak::SpacingMark),
+    (0x111CF, 0x111CF, GraphemeClusterBreak::Extend),
+    (0x1122C, 0x1122E, GraphemeClusterBreak::SpacingMark),
+    (0x1122F, 0x11231, GraphemeClusterBreak::Extend),
+    (0x11232, 0x11233, GraphemeClusterBreak::SpacingMark),
+    (0x11234, 0x11234, GraphemeClusterBreak::Extend),
+    (0x11235, 0x11235, GraphemeClusterBreak::SpacingMark),
+    (0x11236, 0x11237, GraphemeClusterBreak::Extend),
+    (0x1123E, 0x1123E, GraphemeClusterBreak::Extend),
+    (0x112DF, 0x112DF, GraphemeClusterBreak::Extend),
+    (0x112E0, 0x112E2, GraphemeClusterBreak::SpacingMark),
+    (0x112E3, 0x112EA, GraphemeClusterBreak::Extend),
+    (0x11300, 0x11301, GraphemeClusterBreak::Extend),
+    (0x11302, 0x11303, GraphemeClusterBreak::SpacingMark),
+    (0x1133B, 0x1133C, GraphemeClusterBreak::Extend),
+    (0x1133E, 0x1133E, GraphemeClusterBreak::Extend),
+    (0x1133F, 0x1133F, GraphemeClusterBreak::SpacingMark),
+    (0x11340, 0x11340, GraphemeClusterBreak::Extend),
+    (0x11341, 0x11344, GraphemeClusterBreak::SpacingMark),
+    (0x11347, 0x11348, GraphemeClusterBreak::SpacingMark),
+    (0x1134B, 0x1134D, GraphemeClusterBreak::SpacingMark),
+    (0x11357, 0x11357, GraphemeClusterBreak::Extend),
+    (0x11362, 0x11363, GraphemeClusterBreak::SpacingMark),
+    (0x11366, 0x1136C, GraphemeClusterBreak::Extend),
+    (0x11370, 0x11374, GraphemeClusterBreak::Extend),
+    (0x11435, 0x11437, GraphemeClusterBreak::SpacingMark),
+    (0x11438, 0x1143F, GraphemeClusterBreak::Extend),
+    (0x11440, 0x11441, GraphemeClusterBreak::SpacingMark),
+    (0x11442, 0x11444, GraphemeClusterBreak::Extend),
+    (0x11445, 0x11445, GraphemeClusterBreak::SpacingMark),
+    (0x11446, 0x11446, GraphemeClusterBreak::Extend),
+    (0x1145E, 0x1145E, GraphemeClusterBreak::Extend),
+    (0x114B0, 0x114B0, GraphemeClusterBreak::Extend),
+    (0x114B1, 0x114B2, GraphemeClusterBreak::SpacingMark),
+    (0x114B3, 0x114B8, GraphemeClusterBreak::Extend),
+    (0x114B9, 0x114B9, GraphemeClusterBreak::SpacingMark),
+    (0x114BA, 0x114BA, GraphemeClusterBreak::Extend),
+    (0x114BB, 0x114BC, GraphemeClusterBreak::SpacingMark),
+    (0x114BD, 0x114BD, GraphemeClusterBreak::Extend),
+    (0x114BE, 0x114BE, GraphemeClusterBreak::SpacingMark),
+    (0x114BF, 0x114C0, GraphemeClusterBreak::Extend),
+    (0x114C1, 0x114C1, GraphemeClusterBreak::SpacingMark),
+    (0x114C2, 0x114C3, GraphemeClusterBreak::Extend),
+    (0x115AF, 0x115AF, GraphemeClusterBreak::Extend),
+    (0x115B0, 0x115B1, GraphemeClusterBreak::SpacingMark),
+    (0x115B2, 0x115B5, GraphemeClusterBreak::Extend),
+    (0x115B8, 0x115BB, GraphemeClusterBreak::SpacingMark),
+    (0x115BC, 0x115BD, GraphemeClusterBreak::Extend),
+    (0x115BE, 0x115BE, GraphemeClusterBreak::SpacingMark),
+    (0x115BF, 0x115C0, GraphemeClusterBreak::Extend),
+    (0x115DC, 0x115DD, GraphemeClusterBreak::Extend),
+    (0x11630, 0x11632, GraphemeClusterBreak::SpacingMark),
+    (0x11633, 0x1163A, GraphemeClusterBreak::Extend),
+    (0x1163B, 0x1163C, GraphemeClusterBreak::SpacingMark),
+    (0x1163D, 0x1163D, GraphemeClusterBreak::Extend),
+    (0x1163E, 0x1163E, GraphemeClusterBreak::SpacingMark),
+    (0x1163F, 0x11640, GraphemeClusterBreak::Extend),
+    (0x116AB, 0x116AB, GraphemeClusterBreak::Extend),
+    (0x116AC, 0x116AC, GraphemeClusterBreak::SpacingMark),
+    (0x116AD, 0x116AD, GraphemeClusterBreak::Extend),
+    (0x116AE, 0x116AF, GraphemeClusterBreak::SpacingMark),
+    (0x116B0, 0x116B5, GraphemeClusterBreak::Extend),
+    (0x116B6, 0x116B6, GraphemeClusterBreak::SpacingMark),
+    (0x116B7, 0x116B7, GraphemeClusterBreak::Extend),
+    (0x1171D, 0x1171F, GraphemeClusterBreak::Extend),
+    (0x11722, 0x11725, GraphemeClusterBreak::Extend),
+    (0x11726, 0x11726, GraphemeClusterBreak::SpacingMark),
+    (0x11727, 0x1172B, GraphemeClusterBreak::Extend),
+    (0x1182C, 0x1182E, GraphemeClusterBreak::SpacingMark),
+    (0x1182F, 0x11837, GraphemeClusterBreak::Extend),
+    (0x11838, 0x11838, GraphemeClusterBreak::SpacingMark),
+    (0x11839, 0x1183A, GraphemeClusterBreak::Extend),
+    (0x11930, 0x11930, GraphemeClusterBreak::Extend),
+    (0x11931, 0x11935, GraphemeClusterBreak::SpacingMark),
+    (0x11937, 0x11938, GraphemeClusterBreak::SpacingMark),
+    (0x1193B, 0x1193C, GraphemeClusterBreak::Extend),
+    (0x1193D, 0x1193D, GraphemeClusterBreak::SpacingMark),
+    (0x1193E, 0x1193E, GraphemeClusterBreak::Extend),
+    (0x1193F, 0x1193F, GraphemeClusterBreak::Prepend),
+    (0x11940, 0x11940, GraphemeClusterBreak::SpacingMark),
+    (0x11941, 0x11941, GraphemeClusterBreak::Prepend),
+    (0x11942, 0x11942, GraphemeClusterBreak::SpacingMark),
+    (0x11943, 0x11943, GraphemeClusterBreak::Extend),
+    (0x119D1, 0x119D3, GraphemeClusterBreak::SpacingMark),
+    (0x119D4, 0x119D7, GraphemeClusterBreak::Extend),
+    (0x119DA, 0x119DB, GraphemeClusterBreak::Extend),
+    (0x119DC, 0x119DF, GraphemeClusterBreak::SpacingMark),
+    (0x119E0, 0x119E0, GraphemeClusterBreak::Extend),
+    (0x119E4, 0x119E4, GraphemeClusterBreak::SpacingMark),
+    (0x11A01, 0x11A0A, GraphemeClusterBreak::Extend),
+    (0x11A33, 0x11A38, GraphemeClusterBreak::Extend),
+    (0x11A39, 0x11A39, GraphemeClusterBreak::SpacingMark),
+    (0x11A3A, 0x11A3A, GraphemeClusterBreak::Prepend),
+    (0x11A3B, 0x11A3E, GraphemeClusterBreak::Extend),
+    (0x11A47, 0x11A47, GraphemeClusterBreak::Extend),
+    (0x11A51, 0x11A56, GraphemeClusterBreak::Extend),
+    (0x11A57, 0x11A58, GraphemeClusterBreak::SpacingMark),
+    (0x11A59, 0x11A5B, GraphemeClusterBreak::Extend),
+    (0x11A84, 0x11A89, GraphemeClusterBreak::Prepend),
+    (0x11A8A, 0x11A96, GraphemeClusterBreak::Extend),
+    (0x11A97, 0x11A97, GraphemeClusterBreak::SpacingMark),
+    (0x11A98, 0x11A99, GraphemeClusterBreak::Extend),
+    (0x11C2F, 0x11C2F, GraphemeClusterBreak::SpacingMark),
+    (0x11C30, 0x11C36, GraphemeClusterBreak::Extend),
+    (0x11C38, 0x11C3D, GraphemeClusterBreak::Extend),
+    (0x11C3E, 0x11C3E, GraphemeClusterBreak::SpacingMark),
+    (0x11C3F, 0x11C3F, GraphemeClusterBreak::Extend),
+    (0x11C92, 0x11CA7, GraphemeClusterBreak::Extend),
+    (0x11CA9, 0x11CA9, GraphemeClusterBreak::SpacingMark),
+    (0x11CAA, 0x11CB0, GraphemeClusterBreak::Extend),
+    (0x11CB1, 0x11CB1, GraphemeClusterBreak::SpacingMark),
+    (0x11CB2, 0x11CB3, GraphemeClusterBreak::Extend),
+    (0x11CB4, 0x11CB4, GraphemeClusterBreak::SpacingMark),
+    (0x11CB5, 0x11CB6, GraphemeClusterBreak::Extend),
+    (0x11D31, 0x11D36, GraphemeClusterBreak::Extend),
+    (0x11D3A, 0x11D3A, GraphemeClusterBreak::Extend),
+    (0x11D3C, 0x11D3D, GraphemeClusterBreak::Extend),
+    (0x11D3F, 0x11D45, GraphemeClusterBreak::Extend),
+    (0x11D46, 0x11D46, GraphemeClusterBreak::Prepend),
+    (0x11D47, 0x11D47, GraphemeClusterBreak::Extend),
+    (0x11D8A, 0x11D8E, GraphemeClusterBreak::SpacingMark),
+    (0x11D90, 0x11D91, GraphemeClusterBreak::Extend),
+    (0x11D93, 0x11D94, GraphemeClusterBreak::SpacingMark),
+    (0x11D95, 0x11D95, GraphemeClusterBreak::Extend),
+    (0x11D96, 0x11D96, GraphemeClusterBreak::SpacingMark),
+    (0x11D97, 0x11D97, GraphemeClusterBreak::Extend),
+    (0x11EF3, 0x11EF4, GraphemeClusterBreak::Extend),
+    (0x11EF5, 0x11EF6, GraphemeClusterBreak::SpacingMark),
+    (0x13430, 0x13438, GraphemeClusterBreak::Control),
+    (0x16AF0, 0x16AF4, GraphemeClusterBreak::Extend),
+    (0x16B30, 0x16B36, GraphemeClusterBreak::Extend),
+    (0x16F4F, 0x16F4F, GraphemeClusterBreak::Extend),
+    (0x16F51, 0x16F87, GraphemeClusterBreak::SpacingMark),
+    (0x16F8F, 0x16F92, GraphemeClusterBreak::Extend),
+    (0x16FE4, 0x16FE4, GraphemeClusterBreak::Extend),
+    (0x16FF0, 0x16FF1, GraphemeClusterBreak::SpacingMark),
+    (0x1BC9D, 0x1BC9E, GraphemeClusterBreak::Extend),
+    (0x1BCA0, 0x1BCA3, GraphemeClusterBreak::Control),
+    (0x1CF00, 0x1CF2D, GraphemeClusterBreak::Extend),
+    (0x1CF30, 0x1CF46, GraphemeClusterBreak::Extend),
+    (0x1D165, 0x1D165, GraphemeClusterBreak::Extend),
+    (0x1D166, 0x1D166, GraphemeClusterBreak::SpacingMark),
+    (0x1D167, 0x1D169, GraphemeClusterBreak::Extend),
+    (0x1D16D, 0x1D16D, GraphemeClusterBreak::SpacingMark),
+    (0x1D16E, 0x1D172, GraphemeClusterBreak::Extend),
+    (0x1D173, 0x1D17A, GraphemeClusterBreak::Control),
+    (0x1D17B, 0x1D182, GraphemeClusterBreak::Extend),
+    (0x1D185, 0x1D18B, GraphemeClusterBreak::Extend),
+    (0x1D1AA, 0x1D1AD, GraphemeClusterBreak::Extend),
+    (0x1D242, 0x1D244, GraphemeClusterBreak::Extend),
+    (0x1DA00, 0x1DA36, GraphemeClusterBreak::Extend),
+    (0x1DA3B, 0x1DA6C, GraphemeClusterBreak::Extend),
+    (0x1DA75, 0x1DA75, GraphemeClusterBreak::Extend),
+    (0x1DA84, 0x1DA84, GraphemeClusterBreak::Extend),
+    (0x1DA9B, 0x1DA9F, GraphemeClusterBreak::Extend),
+    (0x1DAA1, 0x1DAAF, GraphemeClusterBreak::Extend),
+    (0x1E000, 0x1E006, GraphemeClusterBreak::Extend),
+    (0x1E008, 0x1E018, GraphemeClusterBreak::Extend),
+    (0x1E01B, 0x1E021, GraphemeClusterBreak::Extend),
+    (0x1E023, 0x1E024, GraphemeClusterBreak::Extend),
+    (0x1E026, 0x1E02A, GraphemeClusterBreak::Extend),
+    (0x1E130, 0x1E136, GraphemeClusterBreak::Extend),
+    (0x1E2AE, 0x1E2AE, GraphemeClusterBreak::Extend),
+    (0x1E2EC, 0x1E2EF, GraphemeClusterBreak::Extend),
+    (0x1E8D0, 0x1E8D6, GraphemeClusterBreak::Extend),
+    (0x1E944, 0x1E94A, GraphemeClusterBreak::Extend),
+    (0x1F1E6, 0x1F1FF, GraphemeClusterBreak::RegionalIndicator),
+    (0x1F3FB, 0x1F3FF, GraphemeClusterBreak::Extend),
+    (0xE0000, 0xE001F, GraphemeClusterBreak::Control),
+    (0xE0020, 0xE007F, GraphemeClusterBreak::Extend),
+    (0xE0080, 0xE00FF, GraphemeClusterBreak::Control),
+    (0xE0100, 0xE01EF, GraphemeClusterBreak::Extend),
+    (0xE01F0, 0xE0FFF, GraphemeClusterBreak::Control),
+];
+
+pub(crate) const EXTENDED_PICTOGRAPHIC: &[(u32, u32)] = &[
+    (0x00A9, 0x00A9),
+    (0x00AE, 0x00AE),
+    (0x203C, 0x203C),
+    (0x2049, 0x2049),
+    (0x2122, 0x2122),
+    (0x2139, 0x2139),
+    (0x2194, 0x2199),
+    (0x21A9, 0x21AA),
+    (0x231A, 0x231B),
+    (0x2328, 0x2328),
+    (0x2388, 0x2388),
+    (0x23CF, 0x23CF),
+    (0x23E9, 0x23F3),
+    (0x23F8, 0x23FA),
+    (0x24C2, 0x24C2),
+    (0x25AA, 0x25AB),
+    (0x25B6, 0x25B6),
+    (0x25C0, 0x25C0),
+    (0x25FB, 0x25FE),
+    (0x2600, 0x2605),
+    (0x2607, 0x2612),
+    (0x2614, 0x2685),
+    (0x2690, 0x2705),
+    (0x2708, 0x2712),
+    (0x2714, 0x2714),
+    (0x2716, 0x2716),
+    (0x271D, 0x271D),
+    (0x2721, 0x2721),
+    (0x2728, 0x2728),
+    (0x2733, 0x2734),
+    (0x2744, 0x2744),
+    (0x2747, 0x2747),
+    (0x274C, 0x274C),
+    (0x274E, 0x274E),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2763, 0x2767),
+    (0x2795, 0x2797),
+    (0x27A1, 0x27A1),
+    (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF),
+    (0x2934, 0x2935),
+    (0x2B05, 0x2B07),
+    (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55),
+    (0x3030, 0x3030),
+    (0x303D, 0x303D),
+    (0x3297, 0x3297),
+    (0x3299, 0x3299),
+    (0x1F000, 0x1F0FF),
+    (0x1F10D, 0x1F10F),
+    (0x1F12F, 0x1F12F),
+    (0x1F16C, 0x1F171),
+    (0x1F17E, 0x1F17F),
+    (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A),
+    (0x1F1AD, 0x1F1E5),
+    (0x1F201, 0x1F20F),
+    (0x1F21A, 0x1F21A),
+    (0x1F22F, 0x1F22F),
+    (0x1F232, 0x1F23A),
+    (0x1F23C, 0x1F23F),
+    (0x1F249, 0x1F3FA),
+    (0x1F400, 0x1F53D),
+    (0x1F546, 0x1F64F),
+    (0x1F680, 0x1F6FF),
+    (0x1F774, 0x1F77F),
+    (0x1F7D5, 0x1F7FF),
+    (0x1F80C, 0x1F80F),
+    (0x1F848, 0x1F84F),
+    (0x1F85A, 0x1F85F),
+    (0x1F888, 0x1F88F),
+    (0x1F8AE, 0x1F8FF),
+    (0x1F90C, 0x1F93A),
+    (0x1F93C, 0x1F945),
+    (0x1F947, 0x1FAFF),
+    (0x1FC00, 0x1FFFD),
+];
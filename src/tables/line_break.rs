@@ -0,0 +1,2896 @@
+// Generated by `scripts/unicode.pl` from the Unicode 14.0.0
+// character database. Do not edit by hand.
+
+/// The Unicode `Line_Break` property of a character.
+///
+/// Variants use the short property value aliases, such as `BA` for
+/// break after.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LineBreakClass {
+    /// Ambiguous
+    AI,
+    /// Alphabetic
+    AL,
+    /// Break Both
+    B2,
+    /// Break After
+    BA,
+    /// Break Before
+    BB,
+    /// Mandatory Break
+    BK,
+    /// Contingent Break
+    CB,
+    /// Conditional Japanese Starter
+    CJ,
+    /// Close Punctuation
+    CL,
+    /// Combining Mark
+    CM,
+    /// Close Parenthesis
+    CP,
+    /// Carriage Return
+    CR,
+    /// E Base
+    EB,
+    /// E Modifier
+    EM,
+    /// Exclamation
+    EX,
+    /// Glue
+    GL,
+    /// H2
+    H2,
+    /// H3
+    H3,
+    /// Hebrew Letter
+    HL,
+    /// Hyphen
+    HY,
+    /// Ideographic
+    ID,
+    /// Inseparable
+    IN,
+    /// Infix Numeric
+    IS,
+    /// JL
+    JL,
+    /// JT
+    JT,
+    /// JV
+    JV,
+    /// Line Feed
+    LF,
+    /// Next Line
+    NL,
+    /// Nonstarter
+    NS,
+    /// Numeric
+    NU,
+    /// Open Punctuation
+    OP,
+    /// Postfix Numeric
+    PO,
+    /// Prefix Numeric
+    PR,
+    /// Quotation
+    QU,
+    /// Regional Indicator
+    RI,
+    /// Complex Context
+    SA,
+    /// Surrogate
+    SG,
+    /// Space
+    SP,
+    /// Break Symbols
+    SY,
+    /// Unknown
+    XX,
+    /// Word Joiner
+    WJ,
+    /// ZWSpace
+    ZW,
+    /// ZWJ
+    ZWJ,
+}
+
+impl Default for LineBreakClass {
+    #[inline]
+    fn default() -> Self {
+        LineBreakClass::XX
+    }
+}
+
+pub(crate) const LINE_BREAK: &[(u32, u32, LineBreakClass)] = &[
+    (0x0000, 0x0008, LineBreakClass::CM),
+    (0x0009, 0x0009, LineBreakClass::BA),
+    (0x000A, 0x000A, LineBreakClass::LF),
+    (0x000B, 0x000C, LineBreakClass::BK),
+    (0x000D, 0x000D, LineBreakClass::CR),
+    (0x000E, 0x001F, LineBreakClass::CM),
+    (0x0020, 0x0020, LineBreakClass::SP),
+    (0x0021, 0x0021, LineBreakClass::EX),
+    (0x0022, 0x0022, LineBreakClass::QU),
+    (0x0023, 0x0023, LineBreakClass::AL),
+    (0x0024, 0x0024, LineBreakClass::PR),
+    (0x0025, 0x0025, LineBreakClass::PO),
+    (0x0026, 0x0026, LineBreakClass::AL),
+    (0x0027, 0x0027, LineBreakClass::QU),
+    (0x0028, 0x0028, LineBreakClass::OP),
+    (0x0029, 0x0029, LineBreakClass::CP),
+    (0x002A, 0x002A, LineBreakClass::AL),
+    (0x002B, 0x002B, LineBreakClass::PR),
+    (0x002C, 0x002C, LineBreakClass::IS),
+    (0x002D, 0x002D, LineBreakClass::HY),
+    (0x002E, 0x002E, LineBreakClass::IS),
+    (0x002F, 0x002F, LineBreakClass::SY),
+    (0x0030, 0x0039, LineBreakClass::NU),
+    (0x003A, 0x003B, LineBreakClass::IS),
+    (0x003C, 0x003E, LineBreakClass::AL),
+    (0x003F, 0x003F, LineBreakClass::EX),
+    (0x0040, 0x005A, LineBreakClass::AL),
+    (0x005B, 0x005B, LineBreakClass::OP),
+    (0x005C, 0x005C, LineBreakClass::PR),
+    (0x005D, 0x005D, LineBreakClass::CP),
+    (0x005E, 0x007A, LineBreakClass::AL),
+    (0x007B, 0x007B, LineBreakClass::OP),
+    (0x007C, 0x007C, LineBreakClass::BA),
+    (0x007D, 0x007D, LineBreakClass::CL),
+    (0x007E, 0x007E, LineBreakClass::AL),
+    (0x007F, 0x0084, LineBreakClass::CM),
+    (0x0085, 0x0085, LineBreakClass::NL),
+    (0x0086, 0x009F, LineBreakClass::CM),
+    (0x00A0, 0x00A0, LineBreakClass::GL),
+    (0x00A1, 0x00A1, LineBreakClass::OP),
+    (0x00A2, 0x00A2, LineBreakClass::PO),
+    (0x00A3, 0x00A5, LineBreakClass::PR),
+    (0x00A6, 0x00A6, LineBreakClass::AL),
+    (0x00A7, 0x00A8, LineBreakClass::AI),
+    (0x00A9, 0x00A9, LineBreakClass::AL),
+    (0x00AA, 0x00AA, LineBreakClass::AI),
+    (0x00AB, 0x00AB, LineBreakClass::QU),
+    (0x00AC, 0x00AC, LineBreakClass::AL),
+    (0x00AD, 0x00AD, LineBreakClass::BA),
+    (0x00AE, 0x00AF, LineBreakClass::AL),
+    (0x00B0, 0x00B0, LineBreakClass::PO),
+    (0x00B1, 0x00B1, LineBreakClass::PR),
+    (0x00B2, 0x00B3, LineBreakClass::AI),
+    (0x00B4, 0x00B4, LineBreakClass::BB),
+    (0x00B5, 0x00B5, LineBreakClass::AL),
+    (0x00B6, 0x00BA, LineBreakClass::AI),
+    (0x00BB, 0x00BB, LineBreakClass::QU),
+    (0x00BC, 0x00BE, LineBreakClass::AI),
+    (0x00BF, 0x00BF, LineBreakClass::OP),
+    (0x00C0, 0x00D6, LineBreakClass::AL),
+    (0x00D7, 0x00D7, LineBreakClass::AI),
+    (0x00D8, 0x00F6, LineBreakClass::AL),
+    (0x00F7, 0x00F7, LineBreakClass::AI),
+    (0x00F8, 0x02C6, LineBreakClass::AL),
+    (0x02C7, 0x02C7, LineBreakClass::AI),
+    (0x02C8, 0x02C8, LineBreakClass::BB),
+    (0x02C9, 0x02CB, LineBreakClass::AI),
+    (0x02CC, 0x02CC, LineBreakClass::BB),
+    (0x02CD, 0x02CD, LineBreakClass::AI),
+    (0x02CE, 0x02CF, LineBreakClass::AL),
+    (0x02D0, 0x02D0, LineBreakClass::AI),
+    (0x02D1, 0x02D7, LineBreakClass::AL),
+    (0x02D8, 0x02DB, LineBreakClass::AI),
+    (0x02DC, 0x02DC, LineBreakClass::AL),
+    (0x02DD, 0x02DD, LineBreakClass::AI),
+    (0x02DE, 0x02DE, LineBreakClass::AL),
+    (0x02DF, 0x02DF, LineBreakClass::BB),
+    (0x02E0, 0x02FF, LineBreakClass::AL),
+    (0x0300, 0x034E, LineBreakClass::CM),
+    (0x034F, 0x034F, LineBreakClass::GL),
+    (0x0350, 0x035B, LineBreakClass::CM),
+    (0x035C, 0x0362, LineBreakClass::GL),
+    (0x0363, 0x036F, LineBreakClass::CM),
+    (0x0370, 0x0377, LineBreakClass::AL),
+    (0x037A, 0x037D, LineBreakClass::AL),
+    (0x037E, 0x037E, LineBreakClass::IS),
+    (0x037F, 0x037F, LineBreakClass::AL),
+    (0x0384, 0x038A, LineBreakClass::AL),
+    (0x038C, 0x038C, LineBreakClass::AL),
+    (0x038E, 0x03A1, LineBreakClass::AL),
+    (0x03A3, 0x0482, LineBreakClass::AL),
+    (0x0483, 0x0489, LineBreakClass::CM),
+    (0x048A, 0x052F, LineBreakClass::AL),
+    (0x0531, 0x0556, LineBreakClass::AL),
+    (0x0559, 0x0588, LineBreakClass::AL),
+    (0x0589, 0x0589, LineBreakClass::IS),
+    (0x058A, 0x058A, LineBreakClass::BA),
+    (0x058D, 0x058E, LineBreakClass::AL),
+    (0x058F, 0x058F, LineBreakClass::PR),
+    (0x0591, 0x05BD, LineBreakClass::CM),
+    (0x05BE, 0x05BE, LineBreakClass::BA),
+    (0x05BF, 0x05BF, LineBreakClass::CM),
+    (0x05C0, 0x05C0, LineBreakClass::AL),
+    (0x05C1, 0x05C2, LineBreakClass::CM),
+    (0x05C3, 0x05C3, LineBreakClass::AL),
+    (0x05C4, 0x05C5, LineBreakClass::CM),
+    (0x05C6, 0x05C6, LineBreakClass::EX),
+    (0x05C7, 0x05C7, LineBreakClass::CM),
+    (0x05D0, 0x05EA, LineBreakClass::HL),
+    (0x05EF, 0x05F2, LineBreakClass::HL),
+    (0x05F3, 0x05F4, LineBreakClass::AL),
+    (0x0600, 0x0608, LineBreakClass::AL),
+    (0x0609, 0x060B, LineBreakClass::PO),
+    (0x060C, 0x060D, LineBreakClass::IS),
+    (0x060E, 0x060F, LineBreakClass::AL),
+    (0x0610, 0x061A, LineBreakClass::CM),
+    (0x061B, 0x061B, LineBreakClass::EX),
+    (0x061C, 0x061C, LineBreakClass::CM),
+    (0x061D, 0x061F, LineBreakClass::EX),
+    (0x0620, 0x064A, LineBreakClass::AL),
+    (0x064B, 0x065F, LineBreakClass::CM),
+    (0x0660, 0x0669, LineBreakClass::NU),
+    (0x066A, 0x066A, LineBreakClass::PO),
+    (0x066B, 0x066C, LineBreakClass::NU),
+    (0x066D, 0x066F, LineBreakClass::AL),
+    (0x0670, 0x0670, LineBreakClass::CM),
+    (0x0671, 0x06D3, LineBreakClass::AL),
+    (0x06D4, 0x06D4, LineBreakClass::EX),
+    (0x06D5, 0x06D5, LineBreakClass::AL),
+    (0x06D6, 0x06DC, LineBreakClass::CM),
+    (0x06DD, 0x06DE, LineBreakClass::AL),
+    (0x06DF, 0x06E4, LineBreakClass::CM),
+    (0x06E5, 0x06E6, LineBreakClass::AL),
+    (0x06E7, 0x06E8, LineBreakClass::CM),
+    (0x06E9, 0x06E9, LineBreakClass::AL),
+    (0x06EA, 0x06ED, LineBreakClass::CM),
+    (0x06EE, 0x06EF, LineBreakClass::AL),
+    (0x06F0, 0x06F9, LineBreakClass::NU),
+    (0x06FA, 0x070D, LineBreakClass::AL),
+    (0x070F, 0x0710, LineBreakClass::AL),
+    (0x0711, 0x0711, LineBreakClass::CM),
+    (0x0712, 0x072F, LineBreakClass::AL),
+    (0x0730, 0x074A, LineBreakClass::CM),
+    (0x074D, 0x07A5, LineBreakClass::AL),
+    (0x07A6, 0x07B0, LineBreakClass::CM),
+    (0x07B1, 0x07B1, LineBreakClass::AL),
+    (0x07C0, 0x07C9, LineBreakClass::NU),
+    (0x07CA, 0x07EA, LineBreakClass::AL),
+    (0x07EB, 0x07F3, LineBreakClass::CM),
+    (0x07F4, 0x07F7, LineBreakClass::AL),
+    (0x07F8, 0x07F8, LineBreakClass::IS),
+    (0x07F9, 0x07F9, LineBreakClass::EX),
+    (0x07FA, 0x07FA, LineBreakClass::AL),
+    (0x07FD, 0x07FD, LineBreakClass::CM),
+    (0x07FE, 0x07FF, LineBreakClass::PR),
+    (0x0800, 0x0815, LineBreakClass::AL),
+    (0x0816, 0x0819, LineBreakClass::CM),
+    (0x081A, 0x081A, LineBreakClass::AL),
+    (0x081B, 0x0823, LineBreakClass::CM),
+    (0x0824, 0x0824, LineBreakClass::AL),
+    (0x0825, 0x0827, LineBreakClass::CM),
+    (0x0828, 0x0828, LineBreakClass::AL),
+    (0x0829, 0x082D, LineBreakClass::CM),
+    (0x0830, 0x083E, LineBreakClass::AL),
+    (0x0840, 0x0858, LineBreakClass::AL),
+    (0x0859, 0x085B, LineBreakClass::CM),
+    (0x085E, 0x085E, LineBreakClass::AL),
+    (0x0860, 0x086A, LineBreakClass::AL),
+    (0x0870, 0x088E, LineBreakClass::AL),
+    (0x0890, 0x0891, LineBreakClass::AL),
+    (0x0898, 0x089F, LineBreakClass::CM),
+    (0x08A0, 0x08C9, LineBreakClass::AL),
+    (0x08CA, 0x08E1, LineBreakClass::CM),
+    (0x08E2, 0x08E2, LineBreakClass::AL),
+    (0x08E3, 0x0903, LineBreakClass::CM),
+    (0x0904, 0x0939, LineBreakClass::AL),
+    (0x093A, 0x093C, LineBreakClass::CM),
+    (0x093D, 0x093D, LineBreakClass::AL),
+    (0x093E, 0x094F, LineBreakClass::CM),
+    (0x0950, 0x0950, LineBreakClass::AL),
+    (0x0951, 0x0957, LineBreakClass::CM),
+    (0x0958, 0x0961, LineBreakClass::AL),
+    (0x0962, 0x0963, LineBreakClass::CM),
+    (0x0964, 0x0965, LineBreakClass::BA),
+    (0x0966, 0x096F, LineBreakClass::NU),
+    (0x0970, 0x0980, LineBreakClass::AL),
+    (0x0981, 0x0983, LineBreakClass::CM),
+    (0x0985, 0x098C, LineBreakClass::AL),
+    (0x098F, 0x0990, LineBreakClass::AL),
+    (0x0993, 0x09A8, LineBreakClass::AL),
+    (0x09AA, 0x09B0, LineBreakClass::AL),
+    (0x09B2, 0x09B2, LineBreakClass::AL),
+    (0x09B6, 0x09B9, LineBreakClass::AL),
+    (0x09BC, 0x09BC, LineBreakClass::CM),
+    (0x09BD, 0x09BD, LineBreakClass::AL),
+    (0x09BE, 0x09C4, LineBreakClass::CM),
+    (0x09C7, 0x09C8, LineBreakClass::CM),
+    (0x09CB, 0x09CD, LineBreakClass::CM),
+    (0x09CE, 0x09CE, LineBreakClass::AL),
+    (0x09D7, 0x09D7, LineBreakClass::CM),
+    (0x09DC, 0x09DD, LineBreakClass::AL),
+    (0x09DF, 0x09E1, LineBreakClass::AL),
+    (0x09E2, 0x09E3, LineBreakClass::CM),
+    (0x09E6, 0x09EF, LineBreakClass::NU),
+    (0x09F0, 0x09F1, LineBreakClass::AL),
+    (0x09F2, 0x09F3, LineBreakClass::PO),
+    (0x09F4, 0x09F8, LineBreakClass::AL),
+    (0x09F9, 0x09F9, LineBreakClass::PO),
+    (0x09FA, 0x09FA, LineBreakClass::AL),
+    (0x09FB, 0x09FB, LineBreakClass::PR),
+    (0x09FC, 0x09FD, LineBreakClass::AL),
+    (0x09FE, 0x09FE, LineBreakClass::CM),
+    (0x0A01, 0x0A03, LineBreakClass::CM),
+    (0x0A05, 0x0A0A, LineBreakClass::AL),
+    (0x0A0F, 0x0A10, LineBreakClass::AL),
+    (0x0A13, 0x0A28, LineBreakClass::AL),
+    (0x0A2A, 0x0A30, LineBreakClass::AL),
+    (0x0A32, 0x0A33, LineBreakClass::AL),
+    (0x0A35, 0x0A36, LineBreakClass::AL),
+    (0x0A38, 0x0A39, LineBreakClass::AL),
+    (0x0A3C, 0x0A3C, LineBreakClass::CM),
+    (0x0A3E, 0x0A42, LineBreakClass::CM),
+    (0x0A47, 0x0A48, LineBreakClass::CM),
+    (0x0A4B, 0x0A4D, LineBreakClass::CM),
+    (0x0A51, 0x0A51, LineBreakClass::CM),
+    (0x0A59, 0x0A5C, LineBreakClass::AL),
+    (0x0A5E, 0x0A5E, LineBreakClass::AL),
+    (0x0A66, 0x0A6F, LineBreakClass::NU),
+    (0x0A70, 0x0A71, LineBreakClass::CM),
+    (0x0A72, 0x0A74, LineBreakClass::AL),
+    (0x0A75, 0x0A75, LineBreakClass::CM),
+    (0x0A76, 0x0A76, LineBreakClass::AL),
+    (0x0A81, 0x0A83, LineBreakClass::CM),
+    (0x0A85, 0x0A8D, LineBreakClass::AL),
+    (0x0A8F, 0x0A91, LineBreakClass::AL),
+    (0x0A93, 0x0AA8, LineBreakClass::AL),
+    (0x0AAA, 0x0AB0, LineBreakClass::AL),
+    (0x0AB2, 0x0AB3, LineBreakClass::AL),
+    (0x0AB5, 0x0AB9, LineBreakClass::AL),
+    (0x0ABC, 0x0ABC, LineBreakClass::CM),
+    (0x0ABD, 0x0ABD, LineBreakClass::AL),
+    (0x0ABE, 0x0AC5, LineBreakClass::CM),
+    (0x0AC7, 0x0AC9, LineBreakClass::CM),
+    (0x0ACB, 0x0ACD, LineBreakClass::CM),
+    (0x0AD0, 0x0AD0, LineBreakClass::AL),
+    (0x0AE0, 0x0AE1, LineBreakClass::AL),
+    (0x0AE2, 0x0AE3, LineBreakClass::CM),
+    (0x0AE6, 0x0AEF, LineBreakClass::NU),
+    (0x0AF0, 0x0AF0, LineBreakClass::AL),
+    (0x0AF1, 0x0AF1, LineBreakClass::PR),
+    (0x0AF9, 0x0AF9, LineBreakClass::AL),
+    (0x0AFA, 0x0AFF, LineBreakClass::CM),
+    (0x0B01, 0x0B03, LineBreakClass::CM),
+    (0x0B05, 0x0B0C, LineBreakClass::AL),
+    (0x0B0F, 0x0B10, LineBreakClass::AL),
+    (0x0B13, 0x0B28, LineBreakClass::AL),
+    (0x0B2A, 0x0B30, LineBreakClass::AL),
+    (0x0B32, 0x0B33, LineBreakClass::AL),
+    (0x0B35, 0x0B39, LineBreakClass::AL),
+    (0x0B3C, 0x0B3C, LineBreakClass::CM),
+    (0x0B3D, 0x0B3D, LineBreakClass::AL),
+    (0x0B3E, 0x0B44, LineBreakClass::CM),
+    (0x0B47, 0x0B48, LineBreakClass::CM),
+    (0x0B4B, 0x0B4D, LineBreakClass::CM),
+    (0x0B55, 0x0B57, LineBreakClass::CM),
+    (0x0B5C, 0x0B5D, LineBreakClass::AL),
+    (0x0B5F, 0x0B61, LineBreakClass::AL),
+    (0x0B62, 0x0B63, LineBreakClass::CM),
+    (0x0B66, 0x0B6F, LineBreakClass::NU),
+    (0x0B70, 0x0B77, LineBreakClass::AL),
+    (0x0B82, 0x0B82, LineBreakClass::CM),
+    (0x0B83, 0x0B83, LineBreakClass::AL),
+    (0x0B85, 0x0B8A, LineBreakClass::AL),
+    (0x0B8E, 0x0B90, LineBreakClass::AL),
+    (0x0B92, 0x0B95, LineBreakClass::AL),
+    (0x0B99, 0x0B9A, LineBreakClass::AL),
+    (0x0B9C, 0x0B9C, LineBreakClass::AL),
+    (0x0B9E, 0x0B9F, LineBreakClass::AL),
+    (0x0BA3, 0x0BA4, LineBreakClass::AL),
+    (0x0BA8, 0x0BAA, LineBreakClass::AL),
+    (0x0BAE, 0x0BB9, LineBreakClass::AL),
+    (0x0BBE, 0x0BC2, LineBreakClass::CM),
+    (0x0BC6, 0x0BC8, LineBreakClass::CM),
+    (0x0BCA, 0x0BCD, LineBreakClass::CM),
+    (0x0BD0, 0x0BD0, LineBreakClass::AL),
+    (0x0BD7, 0x0BD7, LineBreakClass::CM),
+    (0x0BE6, 0x0BEF, LineBreakClass::NU),
+    (0x0BF0, 0x0BF8, LineBreakClass::AL),
+    (0x0BF9, 0x0BF9, LineBreakClass::PR),
+    (0x0BFA, 0x0BFA, LineBreakClass::AL),
+    (0x0C00, 0x0C04, LineBreakClass::CM),
+    (0x0C05, 0x0C0C, LineBreakClass::AL),
+    (0x0C0E, 0x0C10, LineBreakClass::AL),
+    (0x0C12, 0x0C28, LineBreakClass::AL),
+    (0x0C2A, 0x0C39, LineBreakClass::AL),
+    (0x0C3C, 0x0C3C, LineBreakClass::CM),
+    (0x0C3D, 0x0C3D, LineBreakClass::AL),
+    (0x0C3E, 0x0C44, LineBreakClass::CM),
+    (0x0C46, 0x0C48, LineBreakClass::CM),
+    (0x0C4A, 0x0C4D, LineBreakClass::CM),
+    (0x0C55, 0x0C56, LineBreakClass::CM),
+    (0x0C58, 0x0C5A, LineBreakClass::AL),
+    (0x0C5D, 0x0C5D, LineBreakClass::AL),
+    (0x0C60, 0x0C61, LineBreakClass::AL),
+    (0x0C62, 0x0C63, LineBreakClass::CM),
+    (0x0C66, 0x0C6F, LineBreakClass::NU),
+    (0x0C77, 0x0C77, LineBreakClass::BB),
+    (0x0C78, 0x0C80, LineBreakClass::AL),
+    (0x0C81, 0x0C83, LineBreakClass::CM),
+    (0x0C84, 0x0C84, LineBreakClass::BB),
+    (0x0C85, 0x0C8C, LineBreakClass::AL),
+    (0x0C8E, 0x0C90, LineBreakClass::AL),
+    (0x0C92, 0x0CA8, LineBreakClass::AL),
+    (0x0CAA, 0x0CB3, LineBreakClass::AL),
+    (0x0CB5, 0x0CB9, LineBreakClass::AL),
+    (0x0CBC, 0x0CBC, LineBreakClass::CM),
+    (0x0CBD, 0x0CBD, LineBreakClass::AL),
+    (0x0CBE, 0x0CC4, LineBreakClass::CM),
+    (0x0CC6, 0x0CC8, LineBreakClass::CM),
+    (0x0CCA, 0x0CCD, LineBreakClass::CM),
+    (0x0CD5, 0x0CD6, LineBreakClass::CM),
+    (0x0CDD, 0x0CDE, LineBreakClass::AL),
+    (0x0CE0, 0x0CE1, LineBreakClass::AL),
+    (0x0CE2, 0x0CE3, LineBreakClass::CM),
+    (0x0CE6, 0x0CEF, LineBreakClass::NU),
+    (0x0CF1, 0x0CF2, LineBreakClass::AL),
+    (0x0D00, 0x0D03, LineBreakClass::CM),
+    (0x0D04, 0x0D0C, LineBreakClass::AL),
+    (0x0D0E, 0x0D10, LineBreakClass::AL),
+    (0x0D12, 0x0D3A, LineBreakClass::AL),
+    (0x0D3B, 0x0D3C, LineBreakClass::CM),
+    (0x0D3D, 0x0D3D, LineBreakClass::AL),
+    (0x0D3E, 0x0D44, LineBreakClass::CM),
+    (0x0D46, 0x0D48, LineBreakClass::CM),
+    (0x0D4A, 0x0D4D, LineBreakClass::CM),
+    (0x0D4E, 0x0D4F, LineBreakClass::AL),
+    (0x0D54, 0x0D56, LineBreakClass::AL),
+    (0x0D57, 0x0D57, LineBreakClass::CM),
+    (0x0D58, 0x0D61, LineBreakClass::AL),
+    (0x0D62, 0x0D63, LineBreakClass::CM),
+    (0x0D66, 0x0D6F, LineBreakClass::NU),
+    (0x0D70, 0x0D78, LineBreakClass::AL),
+    (0x0D79, 0x0D79, LineBreakClass::PO),
+    (0x0D7A, 0x0D7F, LineBreakClass::AL),
+    (0x0D81, 0x0D83, LineBreakClass::CM),
+    (0x0D85, 0x0D96, LineBreakClass::AL),
+    (0x0D9A, 0x0DB1, LineBreakClass::AL),
+    (0x0DB3, 0x0DBB, LineBreakClass::AL),
+    (0x0DBD, 0x0DBD, LineBreakClass::AL),
+    (0x0DC0, 0x0DC6, LineBreakClass::AL),
+    (0x0DCA, 0x0DCA, LineBreakClass::CM),
+    (0x0DCF, 0x0DD4, LineBreakClass::CM),
+    (0x0DD6, 0x0DD6, LineBreakClass::CM),
+    (0x0DD8, 0x0DDF, LineBreakClass::CM),
+    (0x0DE6, 0x0DEF, LineBreakClass::NU),
+    (0x0DF2, 0x0DF3, LineBreakClass::CM),
+    (0x0DF4, 0x0DF4, LineBreakClass::AL),
+    (0x0E01, 0x0E3A, LineBreakClass::SA),
+    (0x0E3F, 0x0E3F, LineBreakClass::PR),
+    (0x0E40, 0x0E4E, LineBreakClass::SA),
+    (0x0E4F, 0x0E4F, LineBreakClass::AL),
+    (0x0E50, 0x0E59, LineBreakClass::NU),
+    (0x0E5A, 0x0E5B, LineBreakClass::BA),
+    (0x0E81, 0x0E82, LineBreakClass::SA),
+    (0x0E84, 0x0E84, LineBreakClass::SA),
+    (0x0E86, 0x0E8A, LineBreakClass::SA),
+    (0x0E8C, 0x0EA3, LineBreakClass::SA),
+    (0x0EA5, 0x0EA5, LineBreakClass::SA),
+    (0x0EA7, 0x0EBD, LineBreakClass::SA),
+    (0x0EC0, 0x0EC4, LineBreakClass::SA),
+    (0x0EC6, 0x0EC6, LineBreakClass::SA),
+    (0x0EC8, 0x0ECD, LineBreakClass::SA),
+    (0x0ED0, 0x0ED9, LineBreakClass::NU),
+    (0x0EDC, 0x0EDF, LineBreakClass::SA),
+    (0x0F00, 0x0F00, LineBreakClass::AL),
+    (0x0F01, 0x0F04, LineBreakClass::BB),
+    (0x0F05, 0x0F05, LineBreakClass::AL),
+    (0x0F06, 0x0F07, LineBreakClass::BB),
+    (0x0F08, 0x0F08, LineBreakClass::GL),
+    (0x0F09, 0x0F0A, LineBreakClass::BB),
+    (0x0F0B, 0x0F0B, LineBreakClass::BA),
+    (0x0F0C, 0x0F0C, LineBreakClass::GL),
+    (0x0F0D, 0x0F11, LineBreakClass::EX),
+    (0x0F12, 0x0F12, LineBreakClass::GL),
+    (0x0F13, 0x0F13, LineBreakClass::AL),
+    (0x0F14, 0x0F14, LineBreakClass::EX),
+    (0x0F15, 0x0F17, LineBreakClass::AL),
+    (0x0F18, 0x0F19, LineBreakClass::CM),
+    (0x0F1A, 0x0F1F, LineBreakClass::AL),
+    (0x0F20, 0x0F29, LineBreakClass::NU),
+    (0x0F2A, 0x0F33, LineBreakClass::AL),
+    (0x0F34, 0x0F34, LineBreakClass::BA),
+    (0x0F35, 0x0F35, LineBreakClass::CM),
+    (0x0F36, 0x0F36, LineBreakClass::AL),
+    (0x0F37, 0x0F37, LineBreakClass::CM),
+    (0x0F38, 0x0F38, LineBreakClass::AL),
+    (0x0F39, 0x0F39, LineBreakClass::CM),
+    (0x0F3A, 0x0F3A, LineBreakClass::OP),
+    (0x0F3B, 0x0F3B, LineBreakClass::CL),
+    (0x0F3C, 0x0F3C, LineBreakClass::OP),
+    (0x0F3D, 0x0F3D, LineBreakClass::CL),
+    (0x0F3E, 0x0F3F, LineBreakClass::CM),
+    (0x0F40, 0x0F47, LineBreakClass::AL),
+    (0x0F49, 0x0F6C, LineBreakClass::AL),
+    (0x0F71, 0x0F7E, LineBreakClass::CM),
+    (0x0F7F, 0x0F7F, LineBreakClass::BA),
+    (0x0F80, 0x0F84, LineBreakClass::CM),
+    (0x0F85, 0x0F85, LineBreakClass::BA),
+    (0x0F86, 0x0F87, LineBreakClass::CM),
+    (0x0F88, 0x0F8C, LineBreakClass::AL),
+    (0x0F8D, 0x0F97, LineBreakClass::CM),
+    (0x0F99, 0x0FBC, LineBreakClass::CM),
+    (0x0FBE, 0x0FBF, LineBreakClass::BA),
+    (0x0FC0, 0x0FC5, LineBreakClass::AL),
+    (0x0FC6, 0x0FC6, LineBreakClass::CM),
+    (0x0FC7, 0x0FCC, LineBreakClass::AL),
+    (0x0FCE, 0x0FCF, LineBreakClass::AL),
+    (0x0FD0, 0x0FD1, LineBreakClass::BB),
+    (0x0FD2, 0x0FD2, LineBreakClass::BA),
+    (0x0FD3, 0x0FD3, LineBreakClass::BB),
+    (0x0FD4, 0x0FD8, LineBreakClass::AL),
+    (0x0FD9, 0x0FDA, LineBreakClass::GL),
+    (0x1000, 0x103F, LineBreakClass::SA),
+    (0x1040, 0x1049, LineBreakClass::NU),
+    (0x104A, 0x104B, LineBreakClass::BA),
+    (0x104C, 0x104F, LineBreakClass::AL),
+    (0x1050, 0x108F, LineBreakClass::SA),
+    (0x1090, 0x1099, LineBreakClass::NU),
+    (0x109A, 0x109F, LineBreakClass::SA),
+    (0x10A0, 0x10C5, LineBreakClass::AL),
+    (0x10C7, 0x10C7, LineBreakClass::AL),
+    (0x10CD, 0x10CD, LineBreakClass::AL),
+    (0x10D0, 0x10FF, LineBreakClass::AL),
+    (0x1100, 0x115F, LineBreakClass::JL),
+    (0x1160, 0x11A7, LineBreakClass::JV),
+    (0x11A8, 0x11FF, LineBreakClass::JT),
+    (0x1200, 0x1248, LineBreakClass::AL),
+    (0x124A, 0x124D, LineBreakClass::AL),
+    (0x1250, 0x1256, LineBreakClass::AL),
+    (0x1258, 0x1258, LineBreakClass::AL),
+    (0x125A, 0x125D, LineBreakClass::AL),
+    (0x1260, 0x1288, LineBreakClass::AL),
+    (0x128A, 0x128D, LineBreakClass::AL),
+    (0x1290, 0x12B0, LineBreakClass::AL),
+    (0x12B2, 0x12B5, LineBreakClass::AL),
+    (0x12B8, 0x12BE, LineBreakClass::AL),
+    (0x12C0, 0x12C0, LineBreakClass::AL),
+    (0x12C2, 0x12C5, LineBreakClass::AL),
+    (0x12C8, 0x12D6, LineBreakClass::AL),
+    (0x12D8, 0x1310, LineBreakClass::AL),
+    (0x1312, 0x1315, LineBreakClass::AL),
+    (0x1318, 0x135A, LineBreakClass::AL),
+    (0x135D, 0x135F, LineBreakClass::CM),
+    (0x1360, 0x1360, LineBreakClass::AL),
+    (0x1361, 0x1361, LineBreakClass::BA),
+    (0x1362, 0x137C, LineBreakClass::AL),
+    (0x1380, 0x1399, LineBreakClass::AL),
+    (0x13A0, 0x13F5, LineBreakClass::AL),
+    (0x13F8, 0x13FD, LineBreakClass::AL),
+    (0x1400, 0x1400, LineBreakClass::BA),
+    (0x1401, 0x167F, LineBreakClass::AL),
+    (0x1680, 0x1680, LineBreakClass::BA),
+    (0x1681, 0x169A, LineBreakClass::AL),
+    (0x169B, 0x169B, LineBreakClass::OP),
+    (0x169C, 0x169C, LineBreakClass::CL),
+    (0x16A0, 0x16EA, LineBreakClass::AL),
+    (0x16EB, 0x16ED, LineBreakClass::BA),
+    (0x16EE, 0x16F8, LineBreakClass::AL),
+    (0x1700, 0x1711, LineBreakClass::AL),
+    (0x1712, 0x1715, LineBreakClass::CM),
+    (0x171F, 0x1731, LineBreakClass::AL),
+    (0x1732, 0x1734, LineBreakClass::CM),
+    (0x1735, 0x1736, LineBreakClass::BA),
+    (0x1740, 0x1751, LineBreakClass::AL),
+    (0x1752, 0x1753, LineBreakClass::CM),
+    (0x1760, 0x176C, LineBreakClass::AL),
+    (0x176E, 0x1770, LineBreakClass::AL),
+    (0x1772, 0x1773, LineBreakClass::CM),
+    (0x1780, 0x17D3, LineBreakClass::SA),
+    (0x17D4, 0x17D5, LineBreakClass::BA),
+    (0x17D6, 0x17D6, LineBreakClass::NS),
+    (0x17D7, 0x17D7, LineBreakClass::SA),
+    (0x17D8, 0x17D8, LineBreakClass::BA),
+    (0x17D9, 0x17D9, LineBreakClass::AL),
+    (0x17DA, 0x17DA, LineBreakClass::BA),
+    (0x17DB, 0x17DB, LineBreakClass::PR),
+    (0x17DC, 0x17DD, LineBreakClass::SA),
+    (0x17E0, 0x17E9, LineBreakClass::NU),
+    (0x17F0, 0x17F9, LineBreakClass::AL),
+    (0x1800, 0x1801, LineBreakClass::AL),
+    (0x1802, 0x1803, LineBreakClass::EX),
+    (0x1804, 0x1805, LineBreakClass::BA),
+    (0x1806, 0x1806, LineBreakClass::BB),
+    (0x1807, 0x1807, LineBreakClass::AL),
+    (0x1808, 0x1809, LineBreakClass::EX),
+    (0x180A, 0x180A, LineBreakClass::AL),
+    (0x180B, 0x180D, LineBreakClass::CM),
+    (0x180E, 0x180E, LineBreakClass::GL),
+    (0x180F, 0x180F, LineBreakClass::CM),
+    (0x1810, 0x1819, LineBreakClass::NU),
+    (0x1820, 0x1878, LineBreakClass::AL),
+    (0x1880, 0x1884, LineBreakClass::AL),
+    (0x1885, 0x1886, LineBreakClass::CM),
+    (0x1887, 0x18A8, LineBreakClass::AL),
+    (0x18A9, 0x18A9, LineBreakClass::CM),
+    (0x18AA, 0x18AA, LineBreakClass::AL),
+    (0x18B0, 0x18F5, LineBreakClass::AL),
+    (0x1900, 0x191E, LineBreakClass::AL),
+    (0x1920, 0x192B, LineBreakClass::CM),
+    (0x1930, 0x193B, LineBreakClass::CM),
+    (0x1940, 0x1940, LineBreakClass::AL),
+    (0x1944, 0x1945, LineBreakClass::EX),
+    (0x1946, 0x194F, LineBreakClass::NU),
+    (0x1950, 0x196D, LineBreakClass::SA),
+    (0x1970, 0x1974, LineBreakClass::SA),
+    (0x1980, 0x19AB, LineBreakClass::SA),
+    (0x19B0, 0x19C9, LineBreakClass::SA),
+    (0x19D0, 0x19D9, LineBreakClass::NU),
+    (0x19DA, 0x19DA, LineBreakClass::SA),
+    (0x19DE, 0x19DF, LineBreakClass::SA),
+    (0x19E0, 0x1A16, LineBreakClass::AL),
+    (0x1A17, 0x1A1B, LineBreakClass::CM),
+    (0x1A1E, 0x1A1F, LineBreakClass::AL),
+    (0x1A20, 0x1A5E, LineBreakClass::SA),
+    (0x1A60, 0x1A7C, LineBreakClass::SA),
+    (0x1A7F, 0x1A7F, LineBreakClass::CM),
+    (0x1A80, 0x1A89, LineBreakClass::NU),
+    (0x1A90, 0x1A99, LineBreakClass::NU),
+    (0x1AA0, 0x1AAD, LineBreakClass::SA),
+    (0x1AB0, 0x1ACE, LineBreakClass::CM),
+    (0x1B00, 0x1B04, LineBreakClass::CM),
+    (0x1B05, 0x1B33, LineBreakClass::AL),
+    (0x1B34, 0x1B44, LineBreakClass::CM),
+    (0x1B45, 0x1B4C, LineBreakClass::AL),
+    (0x1B50, 0x1B59, LineBreakClass::NU),
+    (0x1B5A, 0x1B5B, LineBreakClass::BA),
+    (0x1B5C, 0x1B5C, LineBreakClass::AL),
+    (0x1B5D, 0x1B60, LineBreakClass::BA),
+    (0x1B61, 0x1B6A, LineBreakClass::AL),
+    (0x1B6B, 0x1B73, LineBreakClass::CM),
+    (0x1B74, 0x1B7C, LineBreakClass::AL),
+    (0x1B7D, 0x1B7E, LineBreakClass::BA),
+    (0x1B80, 0x1B82, LineBreakClass::CM),
+    (0x1B83, 0x1BA0, LineBreakClass::AL),
+    (0x1BA1, 0x1BAD, LineBreakClass::CM),
+    (0x1BAE, 0x1BAF, LineBreakClass::AL),
+    (0x1BB0, 0x1BB9, LineBreakClass::NU),
+    (0x1BBA, 0x1BE5, LineBreakClass::AL),
+    (0x1BE6, 0x1BF3, LineBreakClass::CM),
+    (0x1BFC, 0x1C23, LineBreakClass::AL),
+    (0x1C24, 0x1C37, LineBreakClass::CM),
+    (0x1C3B, 0x1C3F, LineBreakClass::BA),
+    (0x1C40, 0x1C49, LineBreakClass::NU),
+    (0x1C4D, 0x1C4F, LineBreakClass::AL),
+    (0x1C50, 0x1C59, LineBreakClass::NU),
+    (0x1C5A, 0x1C7D, LineBreakClass::AL),
+    (0x1C7E, 0x1C7F, LineBreakClass::BA),
+    (0x1C80, 0x1C88, LineBreakClass::AL),
+    (0x1C90, 0x1CBA, LineBreakClass::AL),
+    (0x1CBD, 0x1CC7, LineBreakClass::AL),
+    (0x1CD0, 0x1CD2, LineBreakClass::CM),
+    (0x1CD3, 0x1CD3, LineBreakClass::AL),
+    (0x1CD4, 0x1CE8, LineBreakClass::CM),
+    (0x1CE9, 0x1CEC, LineBreakClass::AL),
+    (0x1CED, 0x1CED, LineBreakClass::CM),
+    (0x1CEE, 0x1CF3, LineBreakClass::AL),
+    (0x1CF4, 0x1CF4, LineBreakClass::CM),
+    (0x1CF5, 0x1CF6, LineBreakClass::AL),
+    (0x1CF7, 0x1CF9, LineBreakClass::CM),
+    (0x1CFA, 0x1CFA, LineBreakClass::AL),
+    (0x1D00, 0x1DBF, LineBreakClass::AL),
+    (0x1DC0, 0x1DFF, LineBreakClass::CM),
+    (0x1E00, 0x1F15, LineBreakClass::AL),
+    (0x1F18, 0x1F1D, LineBreakClass::AL),
+    (0x1F20, 0x1F45, LineBreakClass::AL),
+    (0x1F48, 0x1F4D, LineBreakClass::AL),
+    (0x1F50, 0x1F57, LineBreakClass::AL),
+    (0x1F59, 0x1F59, LineBreakClass::AL),
+    (0x1F5B, 0x1F5B, LineBreakClass::AL),
+    (0x1F5D, 0x1F5D, LineBreakClass::AL),
+    (0x1F5F, 0x1F7D, LineBreakClass::AL),
+    (0x1F80, 0x1FB4, LineBreakClass::AL),
+    (0x1FB6, 0x1FC4, LineBreakClass::AL),
+    (0x1FC6, 0x1FD3, LineBreakClass::AL),
+    (0x1FD6, 0x1FDB, LineBreakClass::AL),
+    (0x1FDD, 0x1FEF, LineBreakClass::AL),
+    (0x1FF2, 0x1FF4, LineBreakClass::AL),
+    (0x1FF6, 0x1FFC, LineBreakClass::AL),
+    (0x1FFD, 0x1FFD, LineBreakClass::BB),
+    (0x1FFE, 0x1FFE, LineBreakClass::AL),
+    (0x2000, 0x2006, LineBreakClass::BA),
+    (0x2007, 0x2007, LineBreakClass::GL),
+    (0x2008, 0x200A, LineBreakClass::BA),
+    (0x200B, 0x200B, LineBreakClass::ZW),
+    (0x200C, 0x200C, LineBreakClass::CM),
+    (0x200D, 0x200D, LineBreakClass::ZWJ),
+    (0x200E, 0x200F, LineBreakClass::CM),
+    (0x2010, 0x2010, LineBreakClass::BA),
+    (0x2011, 0x2011, LineBreakClass::GL),
+    (0x2012, 0x2013, LineBreakClass::BA),
+    (0x2014, 0x2014, LineBreakClass::B2),
+    (0x2015, 0x2016, LineBreakClass::AI),
+    (0x2017, 0x2017, LineBreakClass::AL),
+    (0x2018, 0x2019, LineBreakClass::QU),
+    (0x201A, 0x201A, LineBreakClass::OP),
+    (0x201B, 0x201D, LineBreakClass::QU),
+    (0x201E, 0x201E, LineBreakClass::OP),
+    (0x201F, 0x201F, LineBreakClass::QU),
+    (0x2020, 0x2021, LineBreakClass::AI),
+    (0x2022, 0x2023, LineBreakClass::AL),
+    (0x2024, 0x2026, LineBreakClass::IN),
+    (0x2027, 0x2027, LineBreakClass::BA),
+    (0x2028, 0x2029, LineBreakClass::BK),
+    (0x202A, 0x202E, LineBreakClass::CM),
+    (0x202F, 0x202F, LineBreakClass::GL),
+    (0x2030, 0x2037, LineBreakClass::PO),
+    (0x2038, 0x2038, LineBreakClass::AL),
+    (0x2039, 0x203A, LineBreakClass::QU),
+    (0x203B, 0x203B, LineBreakClass::AI),
+    (0x203C, 0x203D, LineBreakClass::NS),
+    (0x203E, 0x2043, LineBreakClass::AL),
+    (0x2044, 0x2044, LineBreakClass::IS),
+    (0x2045, 0x2045, LineBreakClass::OP),
+    (0x2046, 0x2046, LineBreakClass::CL),
+    (0x2047, 0x2049, LineBreakClass::NS),
+    (0x204A, 0x2055, LineBreakClass::AL),
+    (0x2056, 0x2056, LineBreakClass::BA),
+    (0x2057, 0x2057, LineBreakClass::AL),
+    (0x2058, 0x205B, LineBreakClass::BA),
+    (0x205C, 0x205C, LineBreakClass::AL),
+    (0x205D, 0x205F, LineBreakClass::BA),
+    (0x2060, 0x2060, LineBreakClass::WJ),
+    (0x2061, 0x2064, LineBreakClass::AL),
+    (0x2066, 0x206F, LineBreakClass::CM),
+    (0x2070, 0x2071, LineBreakClass::AL),
+    (0x2074, 0x2074, LineBreakClass::AI),
+    (0x2075, 0x207C, LineBreakClass::AL),
+    (0x207D, 0x207D, LineBreakClass::OP),
+    (0x207E, 0x207E, LineBreakClass::CL),
+    (0x207F, 0x207F, LineBreakClass::AI),
+    (0x2080, 0x2080, LineBreakClass::AL),
+    (0x2081, 0x2084, LineBreakClass::AI),
+    (0x2085, 0x208C, LineBreakClass::AL),
+    (0x208D, 0x208D, LineBreakClass::OP),
+    (0x208E, 0x208E, LineBreakClass::CL),
+    (0x2090, 0x209C, LineBreakClass::AL),
+    (0x20A0, 0x20A6, LineBreakClass::PR),
+    (0x20A7, 0x20A7, LineBreakClass::PO),
+    (0x20A8, 0x20B5, LineBreakClass::PR),
+    (0x20B6, 0x20B6, LineBreakClass::PO),
+    (0x20B7, 0x20BA, LineBreakClass::PR),
+    (0x20BB, 0x20BB, LineBreakClass::PO),
+    (0x20BC, 0x20BD, LineBreakClass::PR),
+    (0x20BE, 0x20BE, LineBreakClass::PO),
+    (0x20BF, 0x20BF, LineBreakClass::PR),
+    (0x20C0, 0x20C0, LineBreakClass::PO),
+    (0x20C1, 0x20CF, LineBreakClass::PR),
+    (0x20D0, 0x20F0, LineBreakClass::CM),
+    (0x2100, 0x2102, LineBreakClass::AL),
+    (0x2103, 0x2103, LineBreakClass::PO),
+    (0x2104, 0x2104, LineBreakClass::AL),
+    (0x2105, 0x2105, LineBreakClass::AI),
+    (0x2106, 0x2108, LineBreakClass::AL),
+    (0x2109, 0x2109, LineBreakClass::PO),
+    (0x210A, 0x2112, LineBreakClass::AL),
+    (0x2113, 0x2113, LineBreakClass::AI),
+    (0x2114, 0x2115, LineBreakClass::AL),
+    (0x2116, 0x2116, LineBreakClass::PR),
+    (0x2117, 0x2120, LineBreakClass::AL),
+    (0x2121, 0x2122, LineBreakClass::AI),
+    (0x2123, 0x212A, LineBreakClass::AL),
+    (0x212B, 0x212B, LineBreakClass::AI),
+    (0x212C, 0x2153, LineBreakClass::AL),
+    (0x2154, 0x2155, LineBreakClass::AI),
+    (0x2156, 0x215A, LineBreakClass::AL),
+    (0x215B, 0x215B, LineBreakClass::AI),
+    (0x215C, 0x215D, LineBreakClass::AL),
+    (0x215E, 0x215E, LineBreakClass::AI),
+    (0x215F, 0x215F, LineBreakClass::AL),
+    (0x2160, 0x216B, LineBreakClass::AI),
+    (0x216C, 0x216F, LineBreakClass::AL),
+    (0x2170, 0x2179, LineBreakClass::AI),
+    (0x217A, 0x2188, LineBreakClass::AL),
+    (0x2189, 0x2189, LineBreakClass::AI),
+    (0x218A, 0x218B, LineBreakClass::AL),
+    (0x2190, 0x2199, LineBreakClass::AI),
+    (0x219A, 0x21D1, LineBreakClass::AL),
+    (0x21D2, 0x21D2, LineBreakClass::AI),
+    (0x21D3, 0x21D3, LineBreakClass::AL),
+    (0x21D4, 0x21D4, LineBreakClass::AI),
+    (0x21D5, 0x21FF, LineBreakClass::AL),
+    (0x2200, 0x2200, LineBreakClass::AI),
+    (0x2201, 0x2201, LineBreakClass::AL),
+    (0x2202, 0x2203, LineBreakClass::AI),
+    (0x2204, 0x2206, LineBreakClass::AL),
+    (0x2207, 0x2208, LineBreakClass::AI),
+    (0x2209, 0x220A, LineBreakClass::AL),
+    (0x220B, 0x220B, LineBreakClass::AI),
+    (0x220C, 0x220E, LineBreakClass::AL),
+    (0x220F, 0x220F, LineBreakClass::AI),
+    (0x2210, 0x2210, LineBreakClass::AL),
+    (0x2211, 0x2211, LineBreakClass::AI),
+    (0x2212, 0x2213, LineBreakClass::PR),
+    (0x2214, 0x2214, LineBreakClass::AL),
+    (0x2215, 0x2215, LineBreakClass::AI),
+    (0x2216, 0x2219, LineBreakClass::AL),
+    (0x221A, 0x221A, LineBreakClass::AI),
+    (0x221B, 0x221C, LineBreakClass::AL),
+    (0x221D, 0x2220, LineBreakClass::AI),
+    (0x2221, 0x2222, LineBreakClass::AL),
+    (0x2223, 0x2223, LineBreakClass::AI),
+    (0x2224, 0x2224, LineBreakClass::AL),
+    (0x2225, 0x2225, LineBreakClass::AI),
+    (0x2226, 0x2226, LineBreakClass::AL),
+    (0x2227, 0x222C, LineBreakClass::AI),
+    (0x222D, 0x222D, LineBreakClass::AL),
+    (0x222E, 0x222E, LineBreakClass::AI),
+    (0x222F, 0x2233, LineBreakClass::AL),
+    (0x2234, 0x2237, LineBreakClass::AI),
+    (0x2238, 0x223B, LineBreakClass::AL),
+    (0x223C, 0x223D, LineBreakClass::AI),
+    (0x223E, 0x2247, LineBreakClass::AL),
+    (0x2248, 0x2248, LineBreakClass::AI),
+    (0x2249, 0x224B, LineBreakClass::AL),
+    (0x224C, 0x224C, LineBreakClass::AI),
+    (0x224D, 0x2251, LineBreakClass::AL),
+    (0x2252, 0x2252, LineBreakClass::AI),
+    (0x2253, 0x225F, LineBreakClass::AL),
+    (0x2260, 0x2261, LineBreakClass::AI),
+    (0x2262, 0x2263, LineBreakClass::AL),
+    (0x2264, 0x2267, LineBreakClass::AI),
+    (0x2268, 0x2269, LineBreakClass::AL),
+    (0x226A, 0x226B, LineBreakClass::AI),
+    (0x226C, 0x226D, LineBreakClass::AL),
+    (0x226E, 0x226F, LineBreakClass::AI),
+    (0x2270, 0x2281, LineBreakClass::AL),
+    (0x2282, 0x2283, LineBreakClass::AI),
+    (0x2284, 0x2285, LineBreakClass::AL),
+    (0x2286, 0x2287, LineBreakClass::AI),
+    (0x2288, 0x2294, LineBreakClass::AL),
+    (0x2295, 0x2295, LineBreakClass::AI),
+    (0x2296, 0x2298, LineBreakClass::AL),
+    (0x2299, 0x2299, LineBreakClass::AI),
+    (0x229A, 0x22A4, LineBreakClass::AL),
+    (0x22A5, 0x22A5, LineBreakClass::AI),
+    (0x22A6, 0x22BE, LineBreakClass::AL),
+    (0x22BF, 0x22BF, LineBreakClass::AI),
+    (0x22C0, 0x22EE, LineBreakClass::AL),
+    (0x22EF, 0x22EF, LineBreakClass::IN),
+    (0x22F0, 0x2307, LineBreakClass::AL),
+    (0x2308, 0x2308, LineBreakClass::OP),
+    (0x2309, 0x2309, LineBreakClass::CL),
+    (0x230A, 0x230A, LineBreakClass::OP),
+    (0x230B, 0x230B, LineBreakClass::CL),
+    (0x230C, 0x2311, LineBreakClass::AL),
+    (0x2312, 0x2312, LineBreakClass::AI),
+    (0x2313, 0x2319, LineBreakClass::AL),
+    (0x231A, 0x231B, LineBreakClass::ID),
+    (0x231C, 0x2328, LineBreakClass::AL),
+    (0x2329, 0x2329, LineBreakClass::OP),
+    (0x232A, 0x232A, LineBreakClass::CL),
+    (0x232B, 0x23EF, LineBreakClass::AL),
+    (0x23F0, 0x23F3, LineBreakClass::ID),
+    (0x23F4, 0x2426, LineBreakClass::AL),
+    (0x2440, 0x244A, LineBreakClass::AL),
+    (0x2460, 0x24FE, LineBreakClass::AI),
+    (0x24FF, 0x24FF, LineBreakClass::AL),
+    (0x2500, 0x254B, LineBreakClass::AI),
+    (0x254C, 0x254F, LineBreakClass::AL),
+    (0x2550, 0x2574, LineBreakClass::AI),
+    (0x2575, 0x257F, LineBreakClass::AL),
+    (0x2580, 0x258F, LineBreakClass::AI),
+    (0x2590, 0x2591, LineBreakClass::AL),
+    (0x2592, 0x2595, LineBreakClass::AI),
+    (0x2596, 0x259F, LineBreakClass::AL),
+    (0x25A0, 0x25A1, LineBreakClass::AI),
+    (0x25A2, 0x25A2, LineBreakClass::AL),
+    (0x25A3, 0x25A9, LineBreakClass::AI),
+    (0x25AA, 0x25B1, LineBreakClass::AL),
+    (0x25B2, 0x25B3, LineBreakClass::AI),
+    (0x25B4, 0x25B5, LineBreakClass::AL),
+    (0x25B6, 0x25B7, LineBreakClass::AI),
+    (0x25B8, 0x25BB, LineBreakClass::AL),
+    (0x25BC, 0x25BD, LineBreakClass::AI),
+    (0x25BE, 0x25BF, LineBreakClass::AL),
+    (0x25C0, 0x25C1, LineBreakClass::AI),
+    (0x25C2, 0x25C5, LineBreakClass::AL),
+    (0x25C6, 0x25C8, LineBreakClass::AI),
+    (0x25C9, 0x25CA, LineBreakClass::AL),
+    (0x25CB, 0x25CB, LineBreakClass::AI),
+    (0x25CC, 0x25CD, LineBreakClass::AL),
+    (0x25CE, 0x25D1, LineBreakClass::AI),
+    (0x25D2, 0x25E1, LineBreakClass::AL),
+    (0x25E2, 0x25E5, LineBreakClass::AI),
+    (0x25E6, 0x25EE, LineBreakClass::AL),
+    (0x25EF, 0x25EF, LineBreakClass::AI),
+    (0x25F0, 0x25FF, LineBreakClass::AL),
+    (0x2600, 0x2603, LineBreakClass::ID),
+    (0x2604, 0x2604, LineBreakClass::AL),
+    (0x2605, 0x2606, LineBreakClass::AI),
+    (0x2607, 0x2608, LineBreakClass::AL),
+    (0x2609, 0x2609, LineBreakClass::AI),
+    (0x260A, 0x260D, LineBreakClass::AL),
+    (0x260E, 0x260F, LineBreakClass::AI),
+    (0x2610, 0x2613, LineBreakClass::AL),
+    (0x2614, 0x2615, LineBreakClass::ID),
+    (0x2616, 0x2617, LineBreakClass::AI),
+    (0x2618, 0x2618, LineBreakClass::ID),
+    (0x2619, 0x2619, LineBreakClass::AL),
+    (0x261A, 0x261C, LineBreakClass::ID),
+    (0x261D, 0x261D, LineBreakClass::EB),
+    (0x261E, 0x261F, LineBreakClass::ID),
+    (0x2620, 0x2638, LineBreakClass::AL),
+    (0x2639, 0x263B, LineBreakClass::ID),
+    (0x263C, 0x263F, LineBreakClass::AL),
+    (0x2640, 0x2640, LineBreakClass::AI),
+    (0x2641, 0x2641, LineBreakClass::AL),
+    (0x2642, 0x2642, LineBreakClass::AI),
+    (0x2643, 0x265F, LineBreakClass::AL),
+    (0x2660, 0x2661, LineBreakClass::AI),
+    (0x2662, 0x2662, LineBreakClass::AL),
+    (0x2663, 0x2665, LineBreakClass::AI),
+    (0x2666, 0x2666, LineBreakClass::AL),
+    (0x2667, 0x2667, LineBreakClass::AI),
+    (0x2668, 0x2668, LineBreakClass::ID),
+    (0x2669, 0x266A, LineBreakClass::AI),
+    (0x266B, 0x266B, LineBreakClass::AL),
+    (0x266C, 0x266D, LineBreakClass::AI),
+    (0x266E, 0x266E, LineBreakClass::AL),
+    (0x266F, 0x266F, LineBreakClass::AI),
+    (0x2670, 0x267E, LineBreakClass::AL),
+    (0x267F, 0x267F, LineBreakClass::ID),
+    (0x2680, 0x269D, LineBreakClass::AL),
+    (0x269E, 0x269F, LineBreakClass::AI),
+    (0x26A0, 0x26BC, LineBreakClass::AL),
+    (0x26BD, 0x26C8, LineBreakClass::ID),
+    (0x26C9, 0x26CC, LineBreakClass::AI),
+    (0x26CD, 0x26CD, LineBreakClass::ID),
+    (0x26CE, 0x26CE, LineBreakClass::AL),
+    (0x26CF, 0x26D1, LineBreakClass::ID),
+    (0x26D2, 0x26D2, LineBreakClass::AI),
+    (0x26D3, 0x26D4, LineBreakClass::ID),
+    (0x26D5, 0x26D7, LineBreakClass::AI),
+    (0x26D8, 0x26D9, LineBreakClass::ID),
+    (0x26DA, 0x26DB, LineBreakClass::AI),
+    (0x26DC, 0x26DC, LineBreakClass::ID),
+    (0x26DD, 0x26DE, LineBreakClass::AI),
+    (0x26DF, 0x26E1, LineBreakClass::ID),
+    (0x26E2, 0x26E2, LineBreakClass::AL),
+    (0x26E3, 0x26E3, LineBreakClass::AI),
+    (0x26E4, 0x26E7, LineBreakClass::AL),
+    (0x26E8, 0x26E9, LineBreakClass::AI),
+    (0x26EA, 0x26EA, LineBreakClass::ID),
+    (0x26EB, 0x26F0, LineBreakClass::AI),
+    (0x26F1, 0x26F5, LineBreakClass::ID),
+    (0x26F6, 0x26F6, LineBreakClass::AI),
+    (0x26F7, 0x26F8, LineBreakClass::ID),
+    (0x26F9, 0x26F9, LineBreakClass::EB),
+    (0x26FA, 0x26FA, LineBreakClass::ID),
+    (0x26FB, 0x26FC, LineBreakClass::AI),
+    (0x26FD, 0x2704, LineBreakClass::ID),
+    (0x2705, 0x2707, LineBreakClass::AL),
+    (0x2708, 0x2709, LineBreakClass::ID),
+    (0x270A, 0x270D, LineBreakClass::EB),
+    (0x270E, 0x2756, LineBreakClass::AL),
+    (0x2757, 0x2757, LineBreakClass::AI),
+    (0x2758, 0x275A, LineBreakClass::AL),
+    (0x275B, 0x2760, LineBreakClass::QU),
+    (0x2761, 0x2761, LineBreakClass::AL),
+    (0x2762, 0x2763, LineBreakClass::EX),
+    (0x2764, 0x2764, LineBreakClass::ID),
+    (0x2765, 0x2767, LineBreakClass::AL),
+    (0x2768, 0x2768, LineBreakClass::OP),
+    (0x2769, 0x2769, LineBreakClass::CL),
+    (0x276A, 0x276A, LineBreakClass::OP),
+    (0x276B, 0x276B, LineBreakClass::CL),
+    (0x276C, 0x276C, LineBreakClass::OP),
+    (0x276D, 0x276D, LineBreakClass::CL),
+    (0x276E, 0x276E, LineBreakClass::OP),
+    (0x276F, 0x276F, LineBreakClass::CL),
+    (0x2770, 0x2770, LineBreakClass::OP),
+    (0x2771, 0x2771, LineBreakClass::CL),
+    (0x2772, 0x2772, LineBreakClass::OP),
+    (0x2773, 0x2773, LineBreakClass::CL),
+    (0x2774, 0x2774, LineBreakClass::OP),
+    (0x2775, 0x2775, LineBreakClass::CL),
+    (0x2776, 0x2793, LineBreakClass::AI),
+    (0x2794, 0x27C4, LineBreakClass::AL),
+    (0x27C5, 0x27C5, LineBreakClass::OP),
+    (0x27C6, 0x27C6, LineBreakClass::CL),
+    (0x27C7, 0x27E5, LineBreakClass::AL),
+    (0x27E6, 0x27E6, LineBreakClass::OP),
+    (0x27E7, 0x27E7, LineBreakClass::CL),
+    (0x27E8, 0x27E8, LineBreakClass::OP),
+    (0x27E9, 0x27E9, LineBreakClass::CL),
+    (0x27EA, 0x27EA, LineBreakClass::OP),
+    (0x27EB, 0x27EB, LineBreakClass::CL),
+    (0x27EC, 0x27EC, LineBreakClass::OP),
+    (0x27ED, 0x27ED, LineBreakClass::CL),
+    (0x27EE, 0x27EE, LineBreakClass::OP),
+    (0x27EF, 0x27EF, LineBreakClass::CL),
+    (0x27F0, 0x2982, LineBreakClass::AL),
+    (0x2983, 0x2983, LineBreakClass::OP),
+    (0x2984, 0x2984, LineBreakClass::CL),
+    (0x2985, 0x2985, LineBreakClass::OP),
+    (0x2986, 0x2986, LineBreakClass::CL),
+    (0x2987, 0x2987, LineBreakClass::OP),
+    (0x2988, 0x2988, LineBreakClass::CL),
+    (0x2989, 0x2989, LineBreakClass::OP),
+    (0x298A, 0x298A, LineBreakClass::CL),
+    (0x298B, 0x298B, LineBreakClass::OP),
+    (0x298C, 0x298C, LineBreakClass::CL),
+    (0x298D, 0x298D, LineBreakClass::OP),
+    (0x298E, 0x298E, LineBreakClass::CL),
+    (0x298F, 0x298F, LineBreakClass::OP),
+    (0x2990, 0x2990, LineBreakClass::CL),
+    (0x2991, 0x2991, LineBreakClass::OP),
+    (0x2992, 0x2992, LineBreakClass::CL),
+    (0x2993, 0x2993, LineBreakClass::OP),
+    (0x2994, 0x2994, LineBreakClass::CL),
+    (0x2995, 0x2995, LineBreakClass::OP),
+    (0x2996, 0x2996, LineBreakClass::CL),
+    (0x2997, 0x2997, LineBreakClass::OP),
+    (0x2998, 0x2998, LineBreakClass::CL),
+    (0x2999, 0x29D7, LineBreakClass::AL),
+    (0x29D8, 0x29D8, LineBreakClass::OP),
+    (0x29D9, 0x29D9, LineBreakClass::CL),
+    (0x29DA, 0x29DA, LineBreakClass::OP),
+    (0x29DB, 0x29DB, LineBreakClass::CL),
+    (0x29DC, 0x29FB, LineBreakClass::AL),
+    (0x29FC, 0x29FC, LineBreakClass::OP),
+    (0x29FD, 0x29FD, LineBreakClass::CL),
+    (0x29FE, 0x2B54, LineBreakClass::AL),
+    (0x2B55, 0x2B59, LineBreakClass::AI),
+    (0x2B5A, 0x2B73, LineBreakClass::AL),
+    (0x2B76, 0x2B95, LineBreakClass::AL),
+    (0x2B97, 0x2CEE, LineBreakClass::AL),
+    (0x2CEF, 0x2CF1, LineBreakClass::CM),
+    (0x2CF2, 0x2CF3, LineBreakClass::AL),
+    (0x2CF9, 0x2CF9, LineBreakClass::EX),
+    (0x2CFA, 0x2CFC, LineBreakClass::BA),
+    (0x2CFD, 0x2CFD, LineBreakClass::AL),
+    (0x2CFE, 0x2CFE, LineBreakClass::EX),
+    (0x2CFF, 0x2CFF, LineBreakClass::BA),
+    (0x2D00, 0x2D25, LineBreakClass::AL),
+    (0x2D27, 0x2D27, LineBreakClass::AL),
+    (0x2D2D, 0x2D2D, LineBreakClass::AL),
+    (0x2D30, 0x2D67, LineBreakClass::AL),
+    (0x2D6F, 0x2D6F, LineBreakClass::AL),
+    (0x2D70, 0x2D70, LineBreakClass::BA),
+    (0x2D7F, 0x2D7F, LineBreakClass::CM),
+    (0x2D80, 0x2D96, LineBreakClass::AL),
+    (0x2DA0, 0x2DA6, LineBreakClass::AL),
+    (0x2DA8, 0x2DAE, LineBreakClass::AL),
+    (0x2DB0, 0x2DB6, LineBreakClass::AL),
+    (0x2DB8, 0x2DBE, LineBreakClass::AL),
+    (0x2DC0, 0x2DC6, LineBreakClass::AL),
+    (0x2DC8, 0x2DCE, LineBreakClass::AL),
+    (0x2DD0, 0x2DD6, LineBreakClass::AL),
+    (0x2DD8, 0x2DDE, LineBreakClass::AL),
+    (0x2DE0, 0x2DFF, LineBreakClass::CM),
+    (0x2E00, 0x2E0D, LineBreakClass::QU),
+    (0x2E0E, 0x2E15, LineBreakClass::BA),
+    (0x2E16, 0x2E16, LineBreakClass::AL),
+    (0x2E17, 0x2E17, LineBreakClass::BA),
+    (0x2E18, 0x2E18, LineBreakClass::OP),
+    (0x2E19, 0x2E19, LineBreakClass::BA),
+    (0x2E1A, 0x2E1B, LineBreakClass::AL),
+    (0x2E1C, 0x2E1D, LineBreakClass::QU),
+    (0x2E1E, 0x2E1F, LineBreakClass::AL),
+    (0x2E20, 0x2E21, LineBreakClass::QU),
+    (0x2E22, 0x2E22, LineBreakClass::OP),
+    (0x2E23, 0x2E23, LineBreakClass::CL),
+    (0x2E24, 0x2E24, LineBreakClass::OP),
+    (0x2E25, 0x2E25, LineBreakClass::CL),
+    (0x2E26, 0x2E26, LineBreakClass::OP),
+    (0x2E27, 0x2E27, LineBreakClass::CL),
+    (0x2E28, 0x2E28, LineBreakClass::OP),
+    (0x2E29, 0x2E29, LineBreakClass::CL),
+    (0x2E2A, 0x2E2D, LineBreakClass::BA),
+    (0x2E2E, 0x2E2E, LineBreakClass::EX),
+    (0x2E2F, 0x2E2F, LineBreakClass::AL),
+    (0x2E30, 0x2E31, LineBreakClass::BA),
+    (0x2E32, 0x2E32, LineBreakClass::AL),
+    (0x2E33, 0x2E34, LineBreakClass::BA),
+    (0x2E35, 0x2E39, LineBreakClass::AL),
+    (0x2E3A, 0x2E3B, LineBreakClass::B2),
+    (0x2E3C, 0x2E3E, LineBreakClass::BA),
+    (0x2E3F, 0x2E3F, LineBreakClass::AL),
+    (0x2E40, 0x2E41, LineBreakClass::BA),
+    (0x2E42, 0x2E42, LineBreakClass::OP),
+    (0x2E43, 0x2E4A, LineBreakClass::BA),
+    (0x2E4B, 0x2E4B, LineBreakClass::AL),
+    (0x2E4C, 0x2E4C, LineBreakClass::BA),
+    (0x2E4D, 0x2E4D, LineBreakClass::AL),
+    (0x2E4E, 0x2E4F, LineBreakClass::BA),
+    (0x2E50, 0x2E52, LineBreakClass::AL),
+    (0x2E53, 0x2E54, LineBreakClass::EX),
+    (0x2E55, 0x2E55, LineBreakClass::OP),
+    (0x2E56, 0x2E56, LineBreakClass::CL),
+    (0x2E57, 0x2E57, LineBreakClass::OP),
+    (0x2E58, 0x2E58, LineBreakClass::CL),
+    (0x2E59, 0x2E59, LineBreakClass::OP),
+    (0x2E5A, 0x2E5A, LineBreakClass::CL),
+    (0x2E5B, 0x2E5B, LineBreakClass::OP),
+    (0x2E5C, 0x2E5C, LineBreakClass::CL),
+    (0x2E5D, 0x2E5D, LineBreakClass::BA),
+    (0x2E80, 0x2E99, LineBreakClass::ID),
+    (0x2E9B, 0x2EF3, LineBreakClass::ID),
+    (0x2F00, 0x2FD5, LineBreakClass::ID),
+    (0x2FF0, 0x2FFB, LineBreakClass::ID),
+    (0x3000, 0x3000, LineBreakClass::BA),
+    (0x3001, 0x3002, LineBreakClass::CL),
+    (0x3003, 0x3004, LineBreakClass::ID),
+    (0x3005, 0x3005, LineBreakClass::NS),
+    (0x3006, 0x3007, LineBreakClass::ID),
+    (0x3008, 0x3008, LineBreakClass::OP),
+    (0x3009, 0x3009, LineBreakClass::CL),
+    (0x300A, 0x300A, LineBreakClass::OP),
+    (0x300B, 0x300B, LineBreakClass::CL),
+    (0x300C, 0x300C, LineBreakClass::OP),
+    (0x300D, 0x300D, LineBreakClass::CL),
+    (0x300E, 0x300E, LineBreakClass::OP),
+    (0x300F, 0x300F, LineBreakClass::CL),
+    (0x3010, 0x3010, LineBreakClass::OP),
+    (0x3011, 0x3011, LineBreakClass::CL),
+    (0x3012, 0x3013, LineBreakClass::ID),
+    (0x3014, 0x3014, LineBreakClass::OP),
+    (0x3015, 0x3015, LineBreakClass::CL),
+    (0x3016, 0x3016, LineBreakClass::OP),
+    (0x3017, 0x3017, LineBreakClass::CL),
+    (0x3018, 0x3018, LineBreakClass::OP),
+    (0x3019, 0x3019, LineBreakClass::CL),
+    (0x301A, 0x301A, LineBreakClass::OP),
+    (0x301B, 0x301B, LineBreakClass::CL),
+    (0x301C, 0x301C, LineBreakClass::NS),
+    (0x301D, 0x301D, LineBreakClass::OP),
+    (0x301E, 0x301F, LineBreakClass::CL),
+    (0x3020, 0x3029, LineBreakClass::ID),
+    (0x302A, 0x302F, LineBreakClass::CM),
+    (0x3030, 0x3034, LineBreakClass::ID),
+    (0x3035, 0x3035, LineBreakClass::CM),
+    (0x3036, 0x303A, LineBreakClass::ID),
+    (0x303B, 0x303C, LineBreakClass::NS),
+    (0x303D, 0x303F, LineBreakClass::ID),
+    (0x3041, 0x3041, LineBreakClass::CJ),
+    (0x3042, 0x3042, LineBreakClass::ID),
+    (0x3043, 0x3043, LineBreakClass::CJ),
+    (0x3044, 0x3044, LineBreakClass::ID),
+    (0x3045, 0x3045, LineBreakClass::CJ),
+    (0x3046, 0x3046, LineBreakClass::ID),
+    (0x3047, 0x3047, LineBreakClass::CJ),
+    (0x3048, 0x3048, LineBreakClass::ID),
+    (0x3049, 0x3049, LineBreakClass::CJ),
+    (0x304A, 0x3062, LineBreakClass::ID),
+    (0x3063, 0x3063, LineBreakClass::CJ),
+    (0x3064, 0x3082, LineBreakClass::ID),
+    (0x3083, 0x3083, LineBreakClass::CJ),
+    (0x3084, 0x3084, LineBreakClass::ID),
+    (0x3085, 0x3085, LineBreakClass::CJ),
+    (0x3086, 0x3086, LineBreakClass::ID),
+    (0x3087, 0x3087, LineBreakClass::CJ),
+    (0x3088, 0x308D, LineBreakClass::ID),
+    (0x308E, 0x308E, LineBreakClass::CJ),
+    (0x308F, 0x3094, LineBreakClass::ID),
+    (0x3095, 0x3096, LineBreakClass::CJ),
+    (0x3099, 0x309A, LineBreakClass::CM),
+    (0x309B, 0x309E, LineBreakClass::NS),
+    (0x309F, 0x309F, LineBreakClass::ID),
+    (0x30A0, 0x30A0, LineBreakClass::NS),
+    (0x30A1, 0x30A1, LineBreakClass::CJ),
+    (0x30A2, 0x30A2, LineBreakClass::ID),
+    (0x30A3, 0x30A3, LineBreakClass::CJ),
+    (0x30A4, 0x30A4, LineBreakClass::ID),
+    (0x30A5, 0x30A5, LineBreakClass::CJ),
+    (0x30A6, 0x30A6, LineBreakClass::ID),
+    (0x30A7, 0x30A7, LineBreakClass::CJ),
+    (0x30A8, 0x30A8, LineBreakClass::ID),
+    (0x30A9, 0x30A9, LineBreakClass::CJ),
+    (0x30AA, 0x30C2, LineBreakClass::ID),
+    (0x30C3, 0x30C3, LineBreakClass::CJ),
+    (0x30C4, 0x30E2, LineBreakClass::ID),
+    (0x30E3, 0x30E3, LineBreakClass::CJ),
+    (0x30E4, 0x30E4, LineBreakClass::ID),
+    (0x30E5, 0x30E5, LineBreakClass::CJ),
+    (0x30E6, 0x30E6, LineBreakClass::ID),
+    (0x30E7, 0x30E7, LineBreakClass::CJ),
+    (0x30E8, 0x30ED, LineBreakClass::ID),
+    (0x30EE, 0x30EE, LineBreakClass::CJ),
+    (0x30EF, 0x30F4, LineBreakClass::ID),
+    (0x30F5, 0x30F6, LineBreakClass::CJ),
+    (0x30F7, 0x30FA, LineBreakClass::ID),
+    (0x30FB, 0x30FB, LineBreakClass::NS),
+    (0x30FC, 0x30FC, LineBreakClass::CJ),
+    (0x30FD, 0x30FE, LineBreakClass::NS),
+    (0x30FF, 0x30FF, LineBreakClass::ID),
+    (0x3105, 0x312F, LineBreakClass::ID),
+    (0x3131, 0x318E, LineBreakClass::ID),
+    (0x3190, 0x31E3, LineBreakClass::ID),
+    (0x31F0, 0x31FF, LineBreakClass::CJ),
+    (0x3200, 0x321E, LineBreakClass::ID),
+    (0x3220, 0x3247, LineBreakClass::ID),
+    (0x3248, 0x324F, LineBreakClass::AI),
+    (0x3250, 0x4DBF, LineBreakClass::ID),
+    (0x4DC0, 0x4DFF, LineBreakClass::AL),
+    (0x4E00, 0xA014, LineBreakClass::ID),
+    (0xA015, 0xA015, LineBreakClass::NS),
+    (0xA016, 0xA48C, LineBreakClass::ID),
+    (0xA490, 0xA4C6, LineBreakClass::ID),
+    (0xA4D0, 0xA4FD, LineBreakClass::AL),
+    (0xA4FE, 0xA4FF, LineBreakClass::BA),
+    (0xA500, 0xA60C, LineBreakClass::AL),
+    (0xA60D, 0xA60D, LineBreakClass::BA),
+    (0xA60E, 0xA60E, LineBreakClass::EX),
+    (0xA60F, 0xA60F, LineBreakClass::BA),
+    (0xA610, 0xA61F, LineBreakClass::AL),
+    (0xA620, 0xA629, LineBreakClass::NU),
+    (0xA62A, 0xA62B, LineBreakClass::AL),
+    (0xA640, 0xA66E, LineBreakClass::AL),
+    (0xA66F, 0xA672, LineBreakClass::CM),
+    (0xA673, 0xA673, LineBreakClass::AL),
+    (0xA674, 0xA67D, LineBreakClass::CM),
+    (0xA67E, 0xA69D, LineBreakClass::AL),
+    (0xA69E, 0xA69F, LineBreakClass::CM),
+    (0xA6A0, 0xA6EF, LineBreakClass::AL),
+    (0xA6F0, 0xA6F1, LineBreakClass::CM),
+    (0xA6F2, 0xA6F2, LineBreakClass::AL),
+    (0xA6F3, 0xA6F7, LineBreakClass::BA),
+    (0xA700, 0xA7CA, LineBreakClass::AL),
+    (0xA7D0, 0xA7D1, LineBreakClass::AL),
+    (0xA7D3, 0xA7D3, LineBreakClass::AL),
+    (0xA7D5, 0xA7D9, LineBreakClass::AL),
+    (0xA7F2, 0xA801, LineBreakClass::AL),
+    (0xA802, 0xA802, LineBreakClass::CM),
+    (0xA803, 0xA805, LineBreakClass::AL),
+    (0xA806, 0xA806, LineBreakClass::CM),
+    (0xA807, 0xA80A, LineBreakClass::AL),
+    (0xA80B, 0xA80B, LineBreakClass::CM),
+    (0xA80C, 0xA822, LineBreakClass::AL),
+    (0xA823, 0xA827, LineBreakClass::CM),
+    (0xA828, 0xA82B, LineBreakClass::AL),
+    (0xA82C, 0xA82C, LineBreakClass::CM),
+    (0xA830, 0xA837, LineBreakClass::AL),
+    (0xA838, 0xA838, LineBreakClass::PO),
+    (0xA839, 0xA839, LineBreakClass::AL),
+    (0xA840, 0xA873, LineBreakClass::AL),
+    (0xA874, 0xA875, LineBreakClass::BB),
+    (0xA876, 0xA877, LineBreakClass::EX),
+    (0xA880, 0xA881, LineBreakClass::CM),
+    (0xA882, 0xA8B3, LineBreakClass::AL),
+    (0xA8B4, 0xA8C5, LineBreakClass::CM),
+    (0xA8CE, 0xA8CF, LineBreakClass::BA),
+    (0xA8D0, 0xA8D9, LineBreakClass::NU),
+    (0xA8E0, 0xA8F1, LineBreakClass::CM),
+    (0xA8F2, 0xA8FB, LineBreakClass::AL),
+    (0xA8FC, 0xA8FC, LineBreakClass::BB),
+    (0xA8FD, 0xA8FE, LineBreakClass::AL),
+    (0xA8FF, 0xA8FF, LineBreakClass::CM),
+    (0xA900, 0xA909, LineBreakClass::NU),
+    (0xA90A, 0xA925, LineBreakClass::AL),
+    (0xA926, 0xA92D, LineBreakClass::CM),
+    (0xA92E, 0xA92F, LineBreakClass::BA),
+    (0xA930, 0xA946, LineBreakClass::AL),
+    (0xA947, 0xA953, LineBreakClass::CM),
+    (0xA95F, 0xA95F, LineBreakClass::AL),
+    (0xA960, 0xA97C, LineBreakClass::JL),
+    (0xA980, 0xA983, LineBreakClass::CM),
+    (0xA984, 0xA9B2, LineBreakClass::AL),
+    (0xA9B3, 0xA9C0, LineBreakClass::CM),
+    (0xA9C1, 0xA9C6, LineBreakClass::AL),
+    (0xA9C7, 0xA9C9, LineBreakClass::BA),
+    (0xA9CA, 0xA9CD, LineBreakClass::AL),
+    (0xA9CF, 0xA9CF, LineBreakClass::AL),
+    (0xA9D0, 0xA9D9, LineBreakClass::NU),
+    (0xA9DE, 0xA9DF, LineBreakClass::AL),
+    (0xA9E0, 0xA9EF, LineBreakClass::SA),
+    (0xA9F0, 0xA9F9, LineBreakClass::NU),
+    (0xA9FA, 0xA9FE, LineBreakClass::SA),
+    (0xAA00, 0xAA28, LineBreakClass::AL),
+    (0xAA29, 0xAA36, LineBreakClass::CM),
+    (0xAA40, 0xAA42, LineBreakClass::AL),
+    (0xAA43, 0xAA43, LineBreakClass::CM),
+    (0xAA44, 0xAA4B, LineBreakClass::AL),
+    (0xAA4C, 0xAA4D, LineBreakClass::CM),
+    (0xAA50, 0xAA59, LineBreakClass::NU),
+    (0xAA5C, 0xAA5C, LineBreakClass::AL),
+    (0xAA5D, 0xAA5F, LineBreakClass::BA),
+    (0xAA60, 0xAAC2, LineBreakClass::SA),
+    (0xAADB, 0xAADF, LineBreakClass::SA),
+    (0xAAE0, 0xAAEA, LineBreakClass::AL),
+    (0xAAEB, 0xAAEF, LineBreakClass::CM),
+    (0xAAF0, 0xAAF1, LineBreakClass::BA),
+    (0xAAF2, 0xAAF4, LineBreakClass::AL),
+    (0xAAF5, 0xAAF6, LineBreakClass::CM),
+    (0xAB01, 0xAB06, LineBreakClass::AL),
+    (0xAB09, 0xAB0E, LineBreakClass::AL),
+    (0xAB11, 0xAB16, LineBreakClass::AL),
+    (0xAB20, 0xAB26, LineBreakClass::AL),
+    (0xAB28, 0xAB2E, LineBreakClass::AL),
+    (0xAB30, 0xAB6B, LineBreakClass::AL),
+    (0xAB70, 0xABE2, LineBreakClass::AL),
+    (0xABE3, 0xABEA, LineBreakClass::CM),
+    (0xABEB, 0xABEB, LineBreakClass::BA),
+    (0xABEC, 0xABED, LineBreakClass::CM),
+    (0xABF0, 0xABF9, LineBreakClass::NU),
+    (0xAC00, 0xAC00, LineBreakClass::H2),
+    (0xAC01, 0xAC1B, LineBreakClass::H3),
+    (0xAC1C, 0xAC1C, LineBreakClass::H2),
+    (0xAC1D, 0xAC37, LineBreakClass::H3),
+    (0xAC38, 0xAC38, LineBreakClass::H2),
+    (0xAC39, 0xAC53, LineBreakClass::H3),
+    (0xAC54, 0xAC54, LineBreakClass::H2),
+    (0xAC55, 0xAC6F, LineBreakClass::H3),
+    (0xAC70, 0xAC70, LineBreakClass::H2),
+    (0xAC71, 0xAC8B, LineBreakClass::H3),
+    (0xAC8C, 0xAC8C, LineBreakClass::H2),
+    (0xAC8D, 0xACA7, LineBreakClass::H3),
+    (0xACA8, 0xACA8, LineBreakClass::H2),
+    (0xACA9, 0xACC3, LineBreakClass::H3),
+    (0xACC4, 0xACC4, LineBreakClass::H2),
+    (0xACC5, 0xACDF, LineBreakClass::H3),
+    (0xACE0, 0xACE0, LineBreakClass::H2),
+    (0xACE1, 0xACFB, LineBreakClass::H3),
+    (0xACFC, 0xACFC, LineBreakClass::H2),
+    (0xACFD, 0xAD17, LineBreakClass::H3),
+    (0xAD18, 0xAD18, LineBreakClass::H2),
+    (0xAD19, 0xAD33, LineBreakClass::H3),
+    (0xAD34, 0xAD34, LineBreakClass::H2),
+    (0xAD35, 0xAD4F, LineBreakClass::H3),
+    (0xAD50, 0xAD50, LineBreakClass::H2),
+    (0xAD51, 0xAD6B, LineBreakClass::H3),
+    (0xAD6C, 0xAD6C, LineBreakClass::H2),
+    (0xAD6D, 0xAD87, LineBreakClass::H3),
+    (0xAD88, 0xAD88, LineBreakClass::H2),
+    (0xAD89, 0xADA3, LineBreakClass::H3),
+    (0xADA4, 0xADA4, LineBreakClass::H2),
+    (0xADA5, 0xADBF, LineBreakClass::H3),
+    (0xADC0, 0xADC0, LineBreakClass::H2),
+    (0xADC1, 0xADDB, LineBreakClass::H3),
+    (0xADDC, 0xADDC, LineBreakClass::H2),
+    (0xADDD, 0xADF7, LineBreakClass::H3),
+    (0xADF8, 0xADF8, LineBreakClass::H2),
+    (0xADF9, 0xAE13, LineBreakClass::H3),
+    (0xAE14, 0xAE14, LineBreakClass::H2),
+    (0xAE15, 0xAE2F, LineBreakClass::H3),
+    (0xAE30, 0xAE30, LineBreakClass::H2),
+    (0xAE31, 0xAE4B, LineBreakClass::H3),
+    (0xAE4C, 0xAE4C, LineBreakClass::H2),
+    (0xAE4D, 0xAE67, LineBreakClass::H3),
+    (0xAE68, 0xAE68, LineBreakClass::H2),
+    (0xAE69, 0xAE83, LineBreakClass::H3),
+    (0xAE84, 0xAE84, LineBreakClass::H2),
+    (0xAE85, 0xAE9F, LineBreakClass::H3),
+    (0xAEA0, 0xAEA0, LineBreakClass::H2),
+    (0xAEA1, 0xAEBB, LineBreakClass::H3),
+    (0xAEBC, 0xAEBC, LineBreakClass::H2),
+    (0xAEBD, 0xAED7, LineBreakClass::H3),
+    (0xAED8, 0xAED8, LineBreakClass::H2),
+    (0xAED9, 0xAEF3, LineBreakClass::H3),
+    (0xAEF4, 0xAEF4, LineBreakClass::H2),
+    (0xAEF5, 0xAF0F, LineBreakClass::H3),
+    (0xAF10, 0xAF10, LineBreakClass::H2),
+    (0xAF11, 0xAF2B, LineBreakClass::H3),
+    (0xAF2C, 0xAF2C, LineBreakClass::H2),
+    (0xAF2D, 0xAF47, LineBreakClass::H3),
+    (0xAF48, 0xAF48, LineBreakClass::H2),
+    (0xAF49, 0xAF63, LineBreakClass::H3),
+    (0xAF64, 0xAF64, LineBreakClass::H2),
+    (0xAF65, 0xAF7F, LineBreakClass::H3),
+    (0xAF80, 0xAF80, LineBreakClass::H2),
+    (0xAF81, 0xAF9B, LineBreakClass::H3),
+    (0xAF9C, 0xAF9C, LineBreakClass::H2),
+    (0xAF9D, 0xAFB7, LineBreakClass::H3),
+    (0xAFB8, 0xAFB8, LineBreakClass::H2),
+    (0xAFB9, 0xAFD3, LineBreakClass::H3),
+    (0xAFD4, 0xAFD4, LineBreakClass::H2),
+    (0xAFD5, 0xAFEF, LineBreakClass::H3),
+    (0xAFF0, 0xAFF0, LineBreakClass::H2),
+    (0xAFF1, 0xB00B, LineBreakClass::H3),
+    (0xB00C, 0xB00C, LineBreakClass::H2),
+    (0xB00D, 0xB027, LineBreakClass::H3),
+    (0xB028, 0xB028, LineBreakClass::H2),
+    (0xB029, 0xB043, LineBreakClass::H3),
+    (0xB044, 0xB044, LineBreakClass::H2),
+    (0xB045, 0xB05F, LineBreakClass::H3),
+    (0xB060, 0xB060, LineBreakClass::H2),
+    (0xB061, 0xB07B, LineBreakClass::H3),
+    (0xB07C, 0xB07C, LineBreakClass::H2),
+    (0xB07D, 0xB097, LineBreakClass::H3),
+    (0xB098, 0xB098, LineBreakClass::H2),
+    (0xB099, 0xB0B3, LineBreakClass::H3),
+    (0xB0B4, 0xB0B4, LineBreakClass::H2),
+    (0xB0B5, 0xB0CF, LineBreakClass::H3),
+    (0xB0D0, 0xB0D0, LineBreakClass::H2),
+    (0xB0D1, 0xB0EB, LineBreakClass::H3),
+    (0xB0EC, 0xB0EC, LineBreakClass::H2),
+    (0xB0ED, 0xB107, LineBreakClass::H3),
+    (0xB108, 0xB108, LineBreakClass::H2),
+    (0xB109, 0xB123, LineBreakClass::H3),
+    (0xB124, 0xB124, LineBreakClass::H2),
+    (0xB125, 0xB13F, LineBreakClass::H3),
+    (0xB140, 0xB140, LineBreakClass::H2),
+    (0xB141, 0xB15B, LineBreakClass::H3),
+    (0xB15C, 0xB15C, LineBreakClass::H2),
+    (0xB15D, 0xB177, LineBreakClass::H3),
+    (0xB178, 0xB178, LineBreakClass::H2),
+    (0xB179, 0xB193, LineBreakClass::H3),
+    (0xB194, 0xB194, LineBreakClass::H2),
+    (0xB195, 0xB1AF, LineBreakClass::H3),
+    (0xB1B0, 0xB1B0, LineBreakClass::H2),
+    (0xB1B1, 0xB1CB, LineBreakClass::H3),
+    (0xB1CC, 0xB1CC, LineBreakClass::H2),
+    (0xB1CD, 0xB1E7, LineBreakClass::H3),
+    (0xB1E8, 0xB1E8, LineBreakClass::H2),
+    (0xB1E9, 0xB203, LineBreakClass::H3),
+    (0xB204, 0xB204, LineBreakClass::H2),
+    (0xB205, 0xB21F, LineBreakClass::H3),
+    (0xB220, 0xB220, LineBreakClass::H2),
+    (0xB221, 0xB23B, LineBreakClass::H3),
+    (0xB23C, 0xB23C, LineBreakClass::H2),
+    (0xB23D, 0xB257, LineBreakClass::H3),
+    (0xB258, 0xB258, LineBreakClass::H2),
+    (0xB259, 0xB273, LineBreakClass::H3),
+    (0xB274, 0xB274, LineBreakClass::H2),
+    (0xB275, 0xB28F, LineBreakClass::H3),
+    (0xB290, 0xB290, LineBreakClass::H2),
+    (0xB291, 0xB2AB, LineBreakClass::H3),
+    (0xB2AC, 0xB2AC, LineBreakClass::H2),
+    (0xB2AD, 0xB2C7, LineBreakClass::H3),
+    (0xB2C8, 0xB2C8, LineBreakClass::H2),
+    (0xB2C9, 0xB2E3, LineBreakClass::H3),
+    (0xB2E4, 0xB2E4, LineBreakClass::H2),
+    (0xB2E5, 0xB2FF, LineBreakClass::H3),
+    (0xB300, 0xB300, LineBreakClass::H2),
+    (0xB301, 0xB31B, LineBreakClass::H3),
+    (0xB31C, 0xB31C, LineBreakClass::H2),
+    (0xB31D, 0xB337, LineBreakClass::H3),
+    (0xB338, 0xB338, LineBreakClass::H2),
+    (0xB339, 0xB353, LineBreakClass::H3),
+    (0xB354, 0xB354, LineBreakClass::H2),
+    (0xB355, 0xB36F, LineBreakClass::H3),
+    (0xB370, 0xB370, LineBreakClass::H2),
+    (0xB371, 0xB38B, LineBreakClass::H3),
+    (0xB38C, 0xB38C, LineBreakClass::H2),
+    (0xB38D, 0xB3A7, LineBreakClass::H3),
+    (0xB3A8, 0xB3A8, LineBreakClass::H2),
+    (0xB3A9, 0xB3C3, LineBreakClass::H3),
+    (0xB3C4, 0xB3C4, LineBreakClass::H2),
+    (0xB3C5, 0xB3DF, LineBreakClass::H3),
+    (0xB3E0, 0xB3E0, LineBreakClass::H2),
+    (0xB3E1, 0xB3FB, LineBreakClass::H3),
+    (0xB3FC, 0xB3FC, LineBreakClass::H2),
+    (0xB3FD, 0xB417, LineBreakClass::H3),
+    (0xB418, 0xB418, LineBreakClass::H2),
+    (0xB419, 0xB433, LineBreakClass::H3),
+    (0xB434, 0xB434, LineBreakClass::H2),
+    (0xB435, 0xB44F, LineBreakClass::H3),
+    (0xB450, 0xB450, LineBreakClass::H2),
+    (0xB451, 0xB46B, LineBreakClass::H3),
+    (0xB46C, 0xB46C, LineBreakClass::H2),
+    (0xB46D, 0xB487, LineBreakClass::H3),
+    (0xB488, 0xB488, LineBreakClass::H2),
+    (0xB489, 0xB4A3, LineBreakClass::H3),
+    (0xB4A4, 0xB4A4, LineBreakClass::H2),
+    (0xB4A5, 0xB4BF, LineBreakClass::H3),
+    (0xB4C0, 0xB4C0, LineBreakClass::H2),
+    (0xB4C1, 0xB4DB, LineBreakClass::H3),
+    (0xB4DC, 0xB4DC, LineBreakClass::H2),
+    (0xB4DD, 0xB4F7, LineBreakClass::H3),
+    (0xB4F8, 0xB4F8, LineBreakClass::H2),
+    (0xB4F9, 0xB513, LineBreakClass::H3),
+    (0xB514, 0xB514, LineBreakClass::H2),
+    (0xB515, 0xB52F, LineBreakClass::H3),
+    (0xB530, 0xB530, LineBreakClass::H2),
+    (0xB531, 0xB54B, LineBreakClass::H3),
+    (0xB54C, 0xB54C, LineBreakClass::H2),
+    (0xB54D, 0xB567, LineBreakClass::H3),
+    (0xB568, 0xB568, LineBreakClass::H2),
+    (0xB569, 0xB583, LineBreakClass::H3),
+    (0xB584, 0xB584, LineBreakClass::H2),
+    (0xB585, 0xB59F, LineBreakClass::H3),
+    (0xB5A0, 0xB5A0, LineBreakClass::H2),
+    (0xB5A1, 0xB5BB, LineBreakClass::H3),
+    (0xB5BC, 0xB5BC, LineBreakClass::H2),
+    (0xB5BD, 0xB5D7, LineBreakClass::H3),
+    (0xB5D8, 0xB5D8, LineBreakClass::H2),
+    (0xB5D9, 0xB5F3, LineBreakClass::H3),
+    (0xB5F4, 0xB5F4, LineBreakClass::H2),
+    (0xB5F5, 0xB60F, LineBreakClass::H3),
+    (0xB610, 0xB610, LineBreakClass::H2),
+    (0xB611, 0xB62B, LineBreakClass::H3),
+    (0xB62C, 0xB62C, LineBreakClass::H2),
+    (0xB62D, 0xB647, LineBreakClass::H3),
+    (0xB648, 0xB648, LineBreakClass::H2),
+    (0xB649, 0xB663, LineBreakClass::H3),
+    (0xB664, 0xB664, LineBreakClass::H2),
+    (0xB665, 0xB67F, LineBreakClass::H3),
+    (0xB680, 0xB680, LineBreakClass::H2),
+    (0xB681, 0xB69B, LineBreakClass::H3),
+    (0xB69C, 0xB69C, LineBreakClass::H2),
+    (0xB69D, 0xB6B7, LineBreakClass::H3),
+    (0xB6B8, 0xB6B8, LineBreakClass::H2),
+    (0xB6B9, 0xB6D3, LineBreakClass::H3),
+    (0xB6D4, 0xB6D4, LineBreakClass::H2),
+    (0xB6D5, 0xB6EF, LineBreakClass::H3),
+    (0xB6F0, 0xB6F0, LineBreakClass::H2),
+    (0xB6F1, 0xB70B, LineBreakClass::H3),
+    (0xB70C, 0xB70C, LineBreakClass::H2),
+    (0xB70D, 0xB727, LineBreakClass::H3),
+    (0xB728, 0xB728, LineBreakClass::H2),
+    (0xB729, 0xB743, LineBreakClass::H3),
+    (0xB744, 0xB744, LineBreakClass::H2),
+    (0xB745, 0xB75F, LineBreakClass::H3),
+    (0xB760, 0xB760, LineBreakClass::H2),
+    (0xB761, 0xB77B, LineBreakClass::H3),
+    (0xB77C, 0xB77C, LineBreakClass::H2),
+    (0xB77D, 0xB797, LineBreakClass::H3),
+    (0xB798, 0xB798, LineBreakClass::H2),
+    (0xB799, 0xB7B3, LineBreakClass::H3),
+    (0xB7B4, 0xB7B4, LineBreakClass::H2),
+    (0xB7B5, 0xB7CF, LineBreakClass::H3),
+    (0xB7D0, 0xB7D0, LineBreakClass::H2),
+    (0xB7D1, 0xB7EB, LineBreakClass::H3),
+    (0xB7EC, 0xB7EC, LineBreakClass::H2),
+    (0xB7ED, 0xB807, LineBreakClass::H3),
+    (0xB808, 0xB808, LineBreakClass::H2),
+    (0xB809, 0xB823, LineBreakClass::H3),
+    (0xB824, 0xB824, LineBreakClass::H2),
+    (0xB825, 0xB83F, LineBreakClass::H3),
+    (0xB840, 0xB840, LineBreakClass::H2),
+    (0xB841, 0xB85B, LineBreakClass::H3),
+    (0xB85C, 0xB85C, LineBreakClass::H2),
+    (0xB85D, 0xB877, LineBreakClass::H3),
+    (0xB878, 0xB878, LineBreakClass::H2),
+    (0xB879, 0xB893, LineBreakClass::H3),
+    (0xB894, 0xB894, LineBreakClass::H2),
+    (0xB895, 0xB8AF, LineBreakClass::H3),
+    (0xB8B0, 0xB8B0, LineBreakClass::H2),
+    (0xB8B1, 0xB8CB, LineBreakClass::H3),
+    (0xB8CC, 0xB8CC, LineBreakClass::H2),
+    (0xB8CD, 0xB8E7, LineBreakClass::H3),
+    (0xB8E8, 0xB8E8, LineBreakClass::H2),
+    (0xB8E9, 0xB903, LineBreakClass::H3),
+    (0xB904, 0xB904, LineBreakClass::H2),
+    (0xB905, 0xB91F, LineBreakClass::H3),
+    (0xB920, 0xB920, LineBreakClass::H2),
+    (0xB921, 0xB93B, LineBreakClass::H3),
+    (0xB93C, 0xB93C, LineBreakClass::H2),
+    (0xB93D, 0xB957, LineBreakClass::H3),
+    (0xB958, 0xB958, LineBreakClass::H2),
+    (0xB959, 0xB973, LineBreakClass::H3),
+    (0xB974, 0xB974, LineBreakClass::H2),
+    (0xB975, 0xB98F, LineBreakClass::H3),
+    (0xB990, 0xB990, LineBreakClass::H2),
+    (0xB991, 0xB9AB, LineBreakClass::H3),
+    (0xB9AC, 0xB9AC, LineBreakClass::H2),
+    (0xB9AD, 0xB9C7, LineBreakClass::H3),
+    (0xB9C8, 0xB9C8, LineBreakClass::H2),
+    (0xB9C9, 0xB9E3, LineBreakClass::H3),
+    (0xB9E4, 0xB9E4, LineBreakClass::H2),
+    (0xB9E5, 0xB9FF, LineBreakClass::H3),
+    (0xBA00, 0xBA00, LineBreakClass::H2),
+    (0xBA01, 0xBA1B, LineBreakClass::H3),
+    (0xBA1C, 0xBA1C, LineBreakClass::H2),
+    (0xBA1D, 0xBA37, LineBreakClass::H3),
+    (0xBA38, 0xBA38, LineBreakClass::H2),
+    (0xBA39, 0xBA53, LineBreakClass::H3),
+    (0xBA54, 0xBA54, LineBreakClass::H2),
+    (0xBA55, 0xBA6F, LineBreakClass::H3),
+    (0xBA70, 0xBA70, LineBreakClass::H2),
+    (0xBA71, 0xBA8B, LineBreakClass::H3),
+    (0xBA8C, 0xBA8C, LineBreakClass::H2),
+    (0xBA8D, 0xBAA7, LineBreakClass::H3),
+    (0xBAA8, 0xBAA8, LineBreakClass::H2),
+    (0xBAA9, 0xBAC3, LineBreakClass::H3),
+    (0xBAC4, 0xBAC4, LineBreakClass::H2),
+    (0xBAC5, 0xBADF, LineBreakClass::H3),
+    (0xBAE0, 0xBAE0, LineBreakClass::H2),
+    (0xBAE1, 0xBAFB, LineBreakClass::H3),
+    (0xBAFC, 0xBAFC, LineBreakClass::H2),
+    (0xBAFD, 0xBB17, LineBreakClass::H3),
+    (0xBB18, 0xBB18, LineBreakClass::H2),
+    (0xBB19, 0xBB33, LineBreakClass::H3),
+    (0xBB34, 0xBB34, LineBreakClass::H2),
+    (0xBB35, 0xBB4F, LineBreakClass::H3),
+    (0xBB50, 0xBB50, LineBreakClass::H2),
+    (0xBB51, 0xBB6B, LineBreakClass::H3),
+    (0xBB6C, 0xBB6C, LineBreakClass::H2),
+    (0xBB6D, 0xBB87, LineBreakClass::H3),
+    (0xBB88, 0xBB88, LineBreakClass::H2),
+    (0xBB89, 0xBBA3, LineBreakClass::H3),
+    (0xBBA4, 0xBBA4, LineBreakClass::H2),
+    (0xBBA5, 0xBBBF, LineBreakClass::H3),
+    (0xBBC0, 0xBBC0, LineBreakClass::H2),
+    (0xBBC1, 0xBBDB, LineBreakClass::H3),
+    (0xBBDC, 0xBBDC, LineBreakClass::H2),
+    (0xBBDD, 0xBBF7, LineBreakClass::H3),
+    (0xBBF8, 0xBBF8, LineBreakClass::H2),
+    (0xBBF9, 0xBC13, LineBreakClass::H3),
+    (0xBC14, 0xBC14, LineBreakClass::H2),
+    (0xBC15, 0xBC2F, LineBreakClass::H3),
+    (0xBC30, 0xBC30, LineBreakClass::H2),
+    (0xBC31, 0xBC4B, LineBreakClass::H3),
+    (0xBC4C, 0xBC4C, LineBreakClass::H2),
+    (0xBC4D, 0xBC67, LineBreakClass::H3),
+    (0xBC68, 0xBC68, LineBreakClass::H2),
+    (0xBC69, 0xBC83, LineBreakClass::H3),
+    (0xBC84, 0xBC84, LineBreakClass::H2),
+    (0xBC85, 0xBC9F, LineBreakClass::H3),
+    (0xBCA0, 0xBCA0, LineBreakClass::H2),
+    (0xBCA1, 0xBCBB, LineBreakClass::H3),
+    (0xBCBC, 0xBCBC, LineBreakClass::H2),
+    (0xBCBD, 0xBCD7, LineBreakClass::H3),
+    (0xBCD8, 0xBCD8, LineBreakClass::H2),
+    (0xBCD9, 0xBCF3, LineBreakClass::H3),
+    (0xBCF4, 0xBCF4, LineBreakClass::H2),
+    (0xBCF5, 0xBD0F, LineBreakClass::H3),
+    (0xBD10, 0xBD10, LineBreakClass::H2),
+    (0xBD11, 0xBD2B, LineBreakClass::H3),
+    (0xBD2C, 0xBD2C, LineBreakClass::H2),
+    (0xBD2D, 0xBD47, LineBreakClass::H3),
+    (0xBD48, 0xBD48, LineBreakClass::H2),
+    (0xBD49, 0xBD63, LineBreakClass::H3),
+    (0xBD64, 0xBD64, LineBreakClass::H2),
+    (0xBD65, 0xBD7F, LineBreakClass::H3),
+    (0xBD80, 0xBD80, LineBreakClass::H2),
+    (0xBD81, 0xBD9B, LineBreakClass::H3),
+    (0xBD9C, 0xBD9C, LineBreakClass::H2),
+    (0xBD9D, 0xBDB7, LineBreakClass::H3),
+    (0xBDB8, 0xBDB8, LineBreakClass::H2),
+    (0xBDB9, 0xBDD3, LineBreakClass::H3),
+    (0xBDD4, 0xBDD4, LineBreakClass::H2),
+    (0xBDD5, 0xBDEF, LineBreakClass::H3),
+    (0xBDF0, 0xBDF0, LineBreakClass::H2),
+    (0xBDF1, 0xBE0B, LineBreakClass::H3),
+    (0xBE0C, 0xBE0C, LineBreakClass::H2),
+    (0xBE0D, 0xBE27, LineBreakClass::H3),
+    (0xBE28, 0xBE28, LineBreakClass::H2),
+    (0xBE29, 0xBE43, LineBreakClass::H3),
+    (0xBE44, 0xBE44, LineBreakClass::H2),
+    (0xBE45, 0xBE5F, LineBreakClass::H3),
+    (0xBE60, 0xBE60, LineBreakClass::H2),
+    (0xBE61, 0xBE7B, LineBreakClass::H3),
+    (0xBE7C, 0xBE7C, LineBreakClass::H2),
+    (0xBE7D, 0xBE97, LineBreakClass::H3),
+    (0xBE98, 0xBE98, LineBreakClass::H2),
+    (0xBE99, 0xBEB3, LineBreakClass::H3),
+    (0xBEB4, 0xBEB4, LineBreakClass::H2),
+    (0xBEB5, 0xBECF, LineBreakClass::H3),
+    (0xBED0, 0xBED0, LineBreakClass::H2),
+    (0xBED1, 0xBEEB, LineBreakClass::H3),
+    (0xBEEC, 0xBEEC, LineBreakClass::H2),
+    (0xBEED, 0xBF07, LineBreakClass::H3),
+    (0xBF08, 0xBF08, LineBreakClass::H2),
+    (0xBF09, 0xBF23, LineBreakClass::H3),
+    (0xBF24, 0xBF24, LineBreakClass::H2),
+    (0xBF25, 0xBF3F, LineBreakClass::H3),
+    (0xBF40, 0xBF40, LineBreakClass::H2),
+    (0xBF41, 0xBF5B, LineBreakClass::H3),
+    (0xBF5C, 0xBF5C, LineBreakClass::H2),
+    (0xBF5D, 0xBF77, LineBreakClass::H3),
+    (0xBF78, 0xBF78, LineBreakClass::H2),
+    (0xBF79, 0xBF93, LineBreakClass::H3),
+    (0xBF94, 0xBF94, LineBreakClass::H2),
+    (0xBF95, 0xBFAF, LineBreakClass::H3),
+    (0xBFB0, 0xBFB0, LineBreakClass::H2),
+    (0xBFB1, 0xBFCB, LineBreakClass::H3),
+    (0xBFCC, 0xBFCC, LineBreakClass::H2),
+    (0xBFCD, 0xBFE7, LineBreakClass::H3),
+    (0xBFE8, 0xBFE8, LineBreakClass::H2),
+    (0xBFE9, 0xC003, LineBreakClass::H3),
+    (0xC004, 0xC004, LineBreakClass::H2),
+    (0xC005, 0xC01F, LineBreakClass::H3),
+    (0xC020, 0xC020, LineBreakClass::H2),
+    (0xC021, 0xC03B, LineBreakClass::H3),
+    (0xC03C, 0xC03C, LineBreakClass::H2),
+    (0xC03D, 0xC057, LineBreakClass::H3),
+    (0xC058, 0xC058, LineBreakClass::H2),
+    (0xC059, 0xC073, LineBreakClass::H3),
+    (0xC074, 0xC074, LineBreakClass::H2),
+    (0xC075, 0xC08F, LineBreakClass::H3),
+    (0xC090, 0xC090, LineBreakClass::H2),
+    (0xC091, 0xC0AB, LineBreakClass::H3),
+    (0xC0AC, 0xC0AC, LineBreakClass::H2),
+    (0xC0AD, 0xC0C7, LineBreakClass::H3),
+    (0xC0C8, 0xC0C8, LineBreakClass::H2),
+    (0xC0C9, 0xC0E3, LineBreakClass::H3),
+    (0xC0E4, 0xC0E4, LineBreakClass::H2),
+    (0xC0E5, 0xC0FF, LineBreakClass::H3),
+    (0xC100, 0xC100, LineBreakClass::H2),
+    (0xC101, 0xC11B, LineBreakClass::H3),
+    (0xC11C, 0xC11C, LineBreakClass::H2),
+    (0xC11D, 0xC137, LineBreakClass::H3),
+    (0xC138, 0xC138, LineBreakClass::H2),
+    (0xC139, 0xC153, LineBreakClass::H3),
+    (0xC154, 0xC154, LineBreakClass::H2),
+    (0xC155, 0xC16F, LineBreakClass::H3),
+    (0xC170, 0xC170, LineBreakClass::H2),
+    (0xC171, 0xC18B, LineBreakClass::H3),
+    (0xC18C, 0xC18C, LineBreakClass::H2),
+    (0xC18D, 0xC1A7, LineBreakClass::H3),
+    (0xC1A8, 0xC1A8, LineBreakClass::H2),
+    (0xC1A9, 0xC1C3, LineBreakClass::H3),
+    (0xC1C4, 0xC1C4, LineBreakClass::H2),
+    (0xC1C5, 0xC1DF, LineBreakClass::H3),
+    (0xC1E0, 0xC1E0, LineBreakClass::H2),
+    (0xC1E1, 0xC1FB, LineBreakClass::H3),
+    (0xC1FC, 0xC1FC, LineBreakClass::H2),
+    (0xC1FD, 0xC217, LineBreakClass::H3),
+    (0xC218, 0xC218, LineBreakClass::H2),
+    (0xC219, 0xC233, LineBreakClass::H3),
+    (0xC234, 0xC234, LineBreakClass::H2),
+    (0xC235, 0xC24F, LineBreakClass::H3),
+    (0xC250, 0xC250, LineBreakClass::H2),
+    (0xC251, 0xC26B, LineBreakClass::H3),
+    (0xC26C, 0xC26C, LineBreakClass::H2),
+    (0xC26D, 0xC287, LineBreakClass::H3),
+    (0xC288, 0xC288, LineBreakClass::H2),
+    (0xC289, 0xC2A3, LineBreakClass::H3),
+    (0xC2A4, 0xC2A4, LineBreakClass::H2),
+    (0xC2A5, 0xC2BF, LineBreakClass::H3),
+    (0xC2C0, 0xC2C0, LineBreakClass::H2),
+    (0xC2C1, 0xC2DB, LineBreakClass::H3),
+    (0xC2DC, 0xC2DC, LineBreakClass::H2),
+    (0xC2DD, 0xC2F7, LineBreakClass::H3),
+    (0xC2F8, 0xC2F8, LineBreakClass::H2),
+    (0xC2F9, 0xC313, LineBreakClass::H3),
+    (0xC314, 0xC314, LineBreakClass::H2),
+    (0xC315, 0xC32F, LineBreakClass::H3),
+    (0xC330, 0xC330, LineBreakClass::H2),
+    (0xC331, 0xC34B, LineBreakClass::H3),
+    (0xC34C, 0xC34C, LineBreakClass::H2),
+    (0xC34D, 0xC367, LineBreakClass::H3),
+    (0xC368, 0xC368, LineBreakClass::H2),
+    (0xC369, 0xC383, LineBreakClass::H3),
+    (0xC384, 0xC384, LineBreakClass::H2),
+    (0xC385, 0xC39F, LineBreakClass::H3),
+    (0xC3A0, 0xC3A0, LineBreakClass::H2),
+    (0xC3A1, 0xC3BB, LineBreakClass::H3),
+    (0xC3BC, 0xC3BC, LineBreakClass::H2),
+    (0xC3BD, 0xC3D7, LineBreakClass::H3),
+    (0xC3D8, 0xC3D8, LineBreakClass::H2),
+    (0xC3D9, 0xC3F3, LineBreakClass::H3),
+    (0xC3F4, 0xC3F4, LineBreakClass::H2),
+    (0xC3F5, 0xC40F, LineBreakClass::H3),
+    (0xC410, 0xC410, LineBreakClass::H2),
+    (0xC411, 0xC42B, LineBreakClass::H3),
+    (0xC42C, 0xC42C, LineBreakClass::H2),
+    (0xC42D, 0xC447, LineBreakClass::H3),
+    (0xC448, 0xC448, LineBreakClass::H2),
+    (0xC449, 0xC463, LineBreakClass::H3),
+    (0xC464, 0xC464, LineBreakClass::H2),
+    (0xC465, 0xC47F, LineBreakClass::H3),
+    (0xC480, 0xC480, LineBreakClass::H2),
+    (0xC481, 0xC49B, LineBreakClass::H3),
+    (0xC49C, 0xC49C, LineBreakClass::H2),
+    (0xC49D, 0xC4B7, LineBreakClass::H3),
+    (0xC4B8, 0xC4B8, LineBreakClass::H2),
+    (0xC4B9, 0xC4D3, LineBreakClass::H3),
+    (0xC4D4, 0xC4D4, LineBreakClass::H2),
+    (0xC4D5, 0xC4EF, LineBreakClass::H3),
+    (0xC4F0, 0xC4F0, LineBreakClass::H2),
+    (0xC4F1, 0xC50B, LineBreakClass::H3),
+    (0xC50C, 0xC50C, LineBreakClass::H2),
+    (0xC50D, 0xC527, LineBreakClass::H3),
+    (0xC528, 0xC528, LineBreakClass::H2),
+    (0xC529, 0xC543, LineBreakClass::H3),
+    (0xC544, 0xC544, LineBreakClass::H2),
+    (0xC545, 0xC55F, LineBreakClass::H3),
+    (0xC560, 0xC560, LineBreakClass::H2),
+    (0xC561, 0xC57B, LineBreakClass::H3),
+    (0xC57C, 0xC57C, LineBreakClass::H2),
+    (0xC57D, 0xC597, LineBreakClass::H3),
+    (0xC598, 0xC598, LineBreakClass::H2),
+    (0xC599, 0xC5B3, LineBreakClass::H3),
+    (0xC5B4, 0xC5B4, LineBreakClass::H2),
+    (0xC5B5, 0xC5CF, LineBreakClass::H3),
+    (0xC5D0, 0xC5D0, LineBreakClass::H2),
+    (0xC5D1, 0xC5EB, LineBreakClass::H3),
+    (0xC5EC, 0xC5EC, LineBreakClass::H2),
+    (0xC5ED, 0xC607, LineBreakClass::H3),
+    (0xC608, 0xC608, LineBreakClass::H2),
+    (0xC609, 0xC623, LineBreakClass::H3),
+    (0xC624, 0xC624, LineBreakClass::H2),
+    (0xC625, 0xC63F, LineBreakClass::H3),
+    (0xC640, 0xC640, LineBreakClass::H2),
+    (0xC641, 0xC65B, LineBreakClass::H3),
+    (0xC65C, 0xC65C, LineBreakClass::H2),
+    (0xC65D, 0xC677, LineBreakClass::H3),
+    (0xC678, 0xC678, LineBreakClass::H2),
+    (0xC679, 0xC693, LineBreakClass::H3),
+    (0xC694, 0xC694, LineBreakClass::H2),
+    (0xC695, 0xC6AF, LineBreakClass::H3),
+    (0xC6B0, 0xC6B0, LineBreakClass::H2),
+    (0xC6B1, 0xC6CB, LineBreakClass::H3),
+    (0xC6CC, 0xC6CC, LineBreakClass::H2),
+    (0xC6CD, 0xC6E7, LineBreakClass::H3),
+    (0xC6E8, 0xC6E8, LineBreakClass::H2),
+    (0xC6E9, 0xC703, LineBreakClass::H3),
+    (0xC704, 0xC704, LineBreakClass::H2),
+    (0xC705, 0xC71F, LineBreakClass::H3),
+    (0xC720, 0xC720, LineBreakClass::H2),
+    (0xC721, 0xC73B, LineBreakClass::H3),
+    (0xC73C, 0xC73C, LineBreakClass::H2),
+    (0xC73D, 0xC757, LineBreakClass::H3),
+    (0xC758, 0xC758, LineBreakClass::H2),
+    (0xC759, 0xC773, LineBreakClass::H3),
+    (0xC774, 0xC774, LineBreakClass::H2),
+    (0xC775, 0xC78F, LineBreakClass::H3),
+    (0xC790, 0xC790, LineBreakClass::H2),
+    (0xC791, 0xC7AB, LineBreakClass::H3),
+    (0xC7AC, 0xC7AC, LineBreakClass::H2),
+    (0xC7AD, 0xC7C7, LineBreakClass::H3),
+    (0xC7C8, 0xC7C8, LineBreakClass::H2),
+    (0xC7C9, 0xC7E3, LineBreakClass::H3),
+    (0xC7E4, 0xC7E4, LineBreakClass::H2),
+    (0xC7E5, 0xC7FF, LineBreakClass::H3),
+    (0xC800, 0xC800, LineBreakClass::H2),
+    (0xC801, 0xC81B, LineBreakClass::H3),
+    (0xC81C, 0xC81C, LineBreakClass::H2),
+    (0xC81D, 0xC837, LineBreakClass::H3),
+    (0xC838, 0xC838, LineBreakClass::H2),
+    (0xC839, 0xC853, LineBreakClass::H3),
+    (0xC854, 0xC854, LineBreakClass::H2),
+    (0xC855, 0xC86F, LineBreakClass::H3),
+    (0xC870, 0xC870, LineBreakClass::H2),
+    (0xC871, 0xC88B, LineBreakClass::H3),
+    (0xC88C, 0xC88C, LineBreakClass::H2),
+    (0xC88D, 0xC8A7, LineBreakClass::H3),
+    (0xC8A8, 0xC8A8, LineBreakClass::H2),
+    (0xC8A9, 0xC8C3, LineBreakClass::H3),
+    (0xC8C4, 0xC8C4, LineBreakClass::H2),
+    (0xC8C5, 0xC8DF, LineBreakClass::H3),
+    (0xC8E0, 0xC8E0, LineBreakClass::H2),
+    (0xC8E1, 0xC8FB, LineBreakClass::H3),
+    (0xC8FC, 0xC8FC, LineBreakClass::H2),
+    (0xC8FD, 0xC917, LineBreakClass::H3),
+    (0xC918, 0xC918, LineBreakClass::H2),
+    (0xC919, 0xC933, LineBreakClass::H3),
+    (0xC934, 0xC934, LineBreakClass::H2),
+    (0xC935, 0xC94F, LineBreakClass::H3),
+    (0xC950, 0xC950, LineBreakClass::H2),
+    (0xC951, 0xC96B, LineBreakClass::H3),
+    (0xC96C, 0xC96C, LineBreakClass::H2),
+    (0xC96D, 0xC987, LineBreakClass::H3),
+    (0xC988, 0xC988, LineBreakClass::H2),
+    (0xC989, 0xC9A3, LineBreakClass::H3),
+    (0xC9A4, 0xC9A4, LineBreakClass::H2),
+    (0xC9A5, 0xC9BF, LineBreakClass::H3),
+    (0xC9C0, 0xC9C0, LineBreakClass::H2),
+    (0xC9C1, 0xC9DB, LineBreakClass::H3),
+    (0xC9DC, 0xC9DC, LineBreakClass::H2),
+    (0xC9DD, 0xC9F7, LineBreakClass::H3),
+    (0xC9F8, 0xC9F8, LineBreakClass::H2),
+    (0xC9F9, 0xCA13, LineBreakClass::H3),
+    (0xCA14, 0xCA14, LineBreakClass::H2),
+    (0xCA15, 0xCA2F, LineBreakClass::H3),
+    (0xCA30, 0xCA30, LineBreakClass::H2),
+    (0xCA31, 0xCA4B, LineBreakClass::H3),
+    (0xCA4C, 0xCA4C, LineBreakClass::H2),
+    (0xCA4D, 0xCA67, LineBreakClass::H3),
+    (0xCA68, 0xCA68, LineBreakClass::H2),
+    (0xCA69, 0xCA83, LineBreakClass::H3),
+    (0xCA84, 0xCA84, LineBreakClass::H2),
+    (0xCA85, 0xCA9F, LineBreakClass::H3),
+    (0xCAA0, 0xCAA0, LineBreakClass::H2),
+    (0xCAA1, 0xCABB, LineBreakClass::H3),
+    (0xCABC, 0xCABC, LineBreakClass::H2),
+    (0xCABD, 0xCAD7, LineBreakClass::H3),
+    (0xCAD8, 0xCAD8, LineBreakClass::H2),
+    (0xCAD9, 0xCAF3, LineBreakClass::H3),
+    (0xCAF4, 0xCAF4, LineBreakClass::H2),
+    (0xCAF5, 0xCB0F, LineBreakClass::H3),
+    (0xCB10, 0xCB10, LineBreakClass::H2),
+    (0xCB11, 0xCB2B, LineBreakClass::H3),
+    (0xCB2C, 0xCB2C, LineBreakClass::H2),
+    (0xCB2D, 0xCB47, LineBreakClass::H3),
+    (0xCB48, 0xCB48, LineBreakClass::H2),
+    (0xCB49, 0xCB63, LineBreakClass::H3),
+    (0xCB64, 0xCB64, LineBreakClass::H2),
+    (0xCB65, 0xCB7F, LineBreakClass::H3),
+    (0xCB80, 0xCB80, LineBreakClass::H2),
+    (0xCB81, 0xCB9B, LineBreakClass::H3),
+    (0xCB9C, 0xCB9C, LineBreakClass::H2),
+    (0xCB9D, 0xCBB7, LineBreakClass::H3),
+    (0xCBB8, 0xCBB8, LineBreakClass::H2),
+    (0xCBB9, 0xCBD3, LineBreakClass::H3),
+    (0xCBD4, 0xCBD4, LineBreakClass::H2),
+    (0xCBD5, 0xCBEF, LineBreakClass::H3),
+    (0xCBF0, 0xCBF0, LineBreakClass::H2),
+    (0xCBF1, 0xCC0B, LineBreakClass::H3),
+    (0xCC0C, 0xCC0C, LineBreakClass::H2),
+    (0xCC0D, 0xCC27, LineBreakClass::H3),
+    (0xCC28, 0xCC28, LineBreakClass::H2),
+    (0xCC29, 0xCC43, LineBreakClass::H3),
+    (0xCC44, 0xCC44, LineBreakClass::H2),
+    (0xCC45, 0xCC5F, LineBreakClass::H3),
+    (0xCC60, 0xCC60, LineBreakClass::H2),
+    (0xCC61, 0xCC7B, LineBreakClass::H3),
+    (0xCC7C, 0xCC7C, LineBreakClass::H2),
+    (0xCC7D, 0xCC97, LineBreakClass::H3),
+    (0xCC98, 0xCC98, LineBreakClass::H2),
+    (0xCC99, 0xCCB3, LineBreakClass::H3),
+    (0xCCB4, 0xCCB4, LineBreakClass::H2),
+    (0xCCB5, 0xCCCF, LineBreakClass::H3),
+    (0xCCD0, 0xCCD0, LineBreakClass::H2),
+    (0xCCD1, 0xCCEB, LineBreakClass::H3),
+    (0xCCEC, 0xCCEC, LineBreakClass::H2),
+    (0xCCED, 0xCD07, LineBreakClass::H3),
+    (0xCD08, 0xCD08, LineBreakClass::H2),
+    (0xCD09, 0xCD23, LineBreakClass::H3),
+    (0xCD24, 0xCD24, LineBreakClass::H2),
+    (0xCD25, 0xCD3F, LineBreakClass::H3),
+    (0xCD40, 0xCD40, LineBreakClass::H2),
+    (0xCD41, 0xCD5B, LineBreakClass::H3),
+    (0xCD5C, 0xCD5C, LineBreakClass::H2),
+    (0xCD5D, 0xCD77, LineBreakClass::H3),
+    (0xCD78, 0xCD78, LineBreakClass::H2),
+    (0xCD79, 0xCD93, LineBreakClass::H3),
+    (0xCD94, 0xCD94, LineBreakClass::H2),
+    (0xCD95, 0xCDAF, LineBreakClass::H3),
+    (0xCDB0, 0xCDB0, LineBreakClass::H2),
+    (0xCDB1, 0xCDCB, LineBreakClass::H3),
+    (0xCDCC, 0xCDCC, LineBreakClass::H2),
+    (0xCDCD, 0xCDE7, LineBreakClass::H3),
+    (0xCDE8, 0xCDE8, LineBreakClass::H2),
+    (0xCDE9, 0xCE03, LineBreakClass::H3),
+    (0xCE04, 0xCE04, LineBreakClass::H2),
+    (0xCE05, 0xCE1F, LineBreakClass::H3),
+    (0xCE20, 0xCE20, LineBreakClass::H2),
+    (0xCE21, 0xCE3B, LineBreakClass::H3),
+    (0xCE3C, 0xCE3C, LineBreakClass::H2),
+    (0xCE3D, 0xCE57, LineBreakClass::H3),
+    (0xCE58, 0xCE58, LineBreakClass::H2),
+    (0xCE59, 0xCE73, LineBreakClass::H3),
+    (0xCE74, 0xCE74, LineBreakClass::H2),
+    (0xCE75, 0xCE8F, LineBreakClass::H3),
+    (0xCE90, 0xCE90, LineBreakClass::H2),
+    (0xCE91, 0xCEAB, LineBreakClass::H3),
+    (0xCEAC, 0xCEAC, LineBreakClass::H2),
+    (0xCEAD, 0xCEC7, LineBreakClass::H3),
+    (0xCEC8, 0xCEC8, LineBreakClass::H2),
+    (0xCEC9, 0xCEE3, LineBreakClass::H3),
+    (0xCEE4, 0xCEE4, LineBreakClass::H2),
+    (0xCEE5, 0xCEFF, LineBreakClass::H3),
+    (0xCF00, 0xCF00, LineBreakClass::H2),
+    (0xCF01, 0xCF1B, LineBreakClass::H3),
+    (0xCF1C, 0xCF1C, LineBreakClass::H2),
+    (0xCF1D, 0xCF37, LineBreakClass::H3),
+    (0xCF38, 0xCF38, LineBreakClass::H2),
+    (0xCF39, 0xCF53, LineBreakClass::H3),
+    (0xCF54, 0xCF54, LineBreakClass::H2),
+    (0xCF55, 0xCF6F, LineBreakClass::H3),
+    (0xCF70, 0xCF70, LineBreakClass::H2),
+    (0xCF71, 0xCF8B, LineBreakClass::H3),
+    (0xCF8C, 0xCF8C, LineBreakClass::H2),
+    (0xCF8D, 0xCFA7, LineBreakClass::H3),
+    (0xCFA8, 0xCFA8, LineBreakClass::H2),
+    (0xCFA9, 0xCFC3, LineBreakClass::H3),
+    (0xCFC4, 0xCFC4, LineBreakClass::H2),
+    (0xCFC5, 0xCFDF, LineBreakClass::H3),
+    (0xCFE0, 0xCFE0, LineBreakClass::H2),
+    (0xCFE1, 0xCFFB, LineBreakClass::H3),
+    (0xCFFC, 0xCFFC, LineBreakClass::H2),
+    (0xCFFD, 0xD017, LineBreakClass::H3),
+    (0xD018, 0xD018, LineBreakClass::H2),
+    (0xD019, 0xD033, LineBreakClass::H3),
+    (0xD034, 0xD034, LineBreakClass::H2),
+    (0xD035, 0xD04F, LineBreakClass::H3),
+    (0xD050, 0xD050, LineBreakClass::H2),
+    (0xD051, 0xD06B, LineBreakClass::H3),
+    (0xD06C, 0xD06C, LineBreakClass::H2),
+    (0xD06D, 0xD087, LineBreakClass::H3),
+    (0xD088, 0xD088, LineBreakClass::H2),
+    (0xD089, 0xD0A3, LineBreakClass::H3),
+    (0xD0A4, 0xD0A4, LineBreakClass::H2),
+    (0xD0A5, 0xD0BF, LineBreakClass::H3),
+    (0xD0C0, 0xD0C0, LineBreakClass::H2),
+    (0xD0C1, 0xD0DB, LineBreakClass::H3),
+    (0xD0DC, 0xD0DC, LineBreakClass::H2),
+    (0xD0DD, 0xD0F7, LineBreakClass::H3),
+    (0xD0F8, 0xD0F8, LineBreakClass::H2),
+    (0xD0F9, 0xD113, LineBreakClass::H3),
+    (0xD114, 0xD114, LineBreakClass::H2),
+    (0xD115, 0xD12F, LineBreakClass::H3),
+    (0xD130, 0xD130, LineBreakClass::H2),
+    (0xD131, 0xD14B, LineBreakClass::H3),
+    (0xD14C, 0xD14C, LineBreakClass::H2),
+    (0xD14D, 0xD167, LineBreakClass::H3),
+    (0xD168, 0xD168, LineBreakClass::H2),
+    (0xD169, 0xD183, LineBreakClass::H3),
+    (0xD184, 0xD184, LineBreakClass::H2),
+    (0xD185, 0xD19F, LineBreakClass::H3),
+    (0xD1A0, 0xD1A0, LineBreakClass::H2),
+    (0xD1A1, 0xD1BB, LineBreakClass::H3),
+    (0xD1BC, 0xD1BC, LineBreakClass::H2),
+    (0xD1BD, 0xD1D7, LineBreakClass::H3),
+    (0xD1D8, 0xD1D8, LineBreakClass::H2),
+    (0xD1D9, 0xD1F3, LineBreakClass::H3),
+    (0xD1F4, 0xD1F4, LineBreakClass::H2),
+    (0xD1F5, 0xD20F, LineBreakClass::H3),
+    (0xD210, 0xD210, LineBreakClass::H2),
+    (0xD211, 0xD22B, LineBreakClass::H3),
+    (0xD22C, 0xD22C, LineBreakClass::H2),
+    (0xD22D, 0xD247, LineBreakClass::H3),
+    (0xD248, 0xD248, LineBreakClass::H2),
+    (0xD249, 0xD263, LineBreakClass::H3),
+    (0xD264, 0xD264, LineBreakClass::H2),
+    (0xD265, 0xD27F, LineBreakClass::H3),
+    (0xD280, 0xD280, LineBreakClass::H2),
+    (0xD281, 0xD29B, LineBreakClass::H3),
+    (0xD29C, 0xD29C, LineBreakClass::H2),
+    (0xD29D, 0xD2B7, LineBreakClass::H3),
+    (0xD2B8, 0xD2B8, LineBreakClass::H2),
+    (0xD2B9, 0xD2D3, LineBreakClass::H3),
+    (0xD2D4, 0xD2D4, LineBreakClass::H2),
+    (0xD2D5, 0xD2EF, LineBreakClass::H3),
+    (0xD2F0, 0xD2F0, LineBreakClass::H2),
+    (0xD2F1, 0xD30B, LineBreakClass::H3),
+    (0xD30C, 0xD30C, LineBreakClass::H2),
+    (0xD30D, 0xD327, LineBreakClass::H3),
+    (0xD328, 0xD328, LineBreakClass::H2),
+    (0xD329, 0xD343, LineBreakClass::H3),
+    (0xD344, 0xD344, LineBreakClass::H2),
+    (0xD345, 0xD35F, LineBreakClass::H3),
+    (0xD360, 0xD360, LineBreakClass::H2),
+    (0xD361, 0xD37B, LineBreakClass::H3),
+    (0xD37C, 0xD37C, LineBreakClass::H2),
+    (0xD37D, 0xD397, LineBreakClass::H3),
+    (0xD398, 0xD398, LineBreakClass::H2),
+    (0xD399, 0xD3B3, LineBreakClass::H3),
+    (0xD3B4, 0xD3B4, LineBreakClass::H2),
+    (0xD3B5, 0xD3CF, LineBreakClass::H3),
+    (0xD3D0, 0xD3D0, LineBreakClass::H2),
+    (0xD3D1, 0xD3EB, LineBreakClass::H3),
+    (0xD3EC, 0xD3EC, LineBreakClass::H2),
+    (0xD3ED, 0xD407, LineBreakClass::H3),
+    (0xD408, 0xD408, LineBreakClass::H2),
+    (0xD409, 0xD423, LineBreakClass::H3),
+    (0xD424, 0xD424, LineBreakClass::H2),
+    (0xD425, 0xD43F, LineBreakClass::H3),
+    (0xD440, 0xD440, LineBreakClass::H2),
+    (0xD441, 0xD45B, LineBreakClass::H3),
+    (0xD45C, 0xD45C, LineBreakClass::H2),
+    (0xD45D, 0xD477, LineBreakClass::H3),
+    (0xD478, 0xD478, LineBreakClass::H2),
+    (0xD479, 0xD493, LineBreakClass::H3),
+    (0xD494, 0xD494, LineBreakClass::H2),
+    (0xD495, 0xD4AF, LineBreakClass::H3),
+    (0xD4B0, 0xD4B0, LineBreakClass::H2),
+    (0xD4B1, 0xD4CB, LineBreakClass::H3),
+    (0xD4CC, 0xD4CC, LineBreakClass::H2),
+    (0xD4CD, 0xD4E7, LineBreakClass::H3),
+    (0xD4E8, 0xD4E8, LineBreakClass::H2),
+    (0xD4E9, 0xD503, LineBreakClass::H3),
+    (0xD504, 0xD504, LineBreakClass::H2),
+    (0xD505, 0xD51F, LineBreakClass::H3),
+    (0xD520, 0xD520, LineBreakClass::H2),
+    (0xD521, 0xD53B, LineBreakClass::H3),
+    (0xD53C, 0xD53C, LineBreakClass::H2),
+    (0xD53D, 0xD557, LineBreakClass::H3),
+    (0xD558, 0xD558, LineBreakClass::H2),
+    (0xD559, 0xD573, LineBreakClass::H3),
+    (0xD574, 0xD574, LineBreakClass::H2),
+    (0xD575, 0xD58F, LineBreakClass::H3),
+    (0xD590, 0xD590, LineBreakClass::H2),
+    (0xD591, 0xD5AB, LineBreakClass::H3),
+    (0xD5AC, 0xD5AC, LineBreakClass::H2),
+    (0xD5AD, 0xD5C7, LineBreakClass::H3),
+    (0xD5C8, 0xD5C8, LineBreakClass::H2),
+    (0xD5C9, 0xD5E3, LineBreakClass::H3),
+    (0xD5E4, 0xD5E4, LineBreakClass::H2),
+    (0xD5E5, 0xD5FF, LineBreakClass::H3),
+    (0xD600, 0xD600, LineBreakClass::H2),
+    (0xD601, 0xD61B, LineBreakClass::H3),
+    (0xD61C, 0xD61C, LineBreakClass::H2),
+    (0xD61D, 0xD637, LineBreakClass::H3),
+    (0xD638, 0xD638, LineBreakClass::H2),
+    (0xD639, 0xD653, LineBreakClass::H3),
+    (0xD654, 0xD654, LineBreakClass::H2),
+    (0xD655, 0xD66F, LineBreakClass::H3),
+    (0xD670, 0xD670, LineBreakClass::H2),
+    (0xD671, 0xD68B, LineBreakClass::H3),
+    (0xD68C, 0xD68C, LineBreakClass::H2),
+    (0xD68D, 0xD6A7, LineBreakClass::H3),
+    (0xD6A8, 0xD6A8, LineBreakClass::H2),
+    (0xD6A9, 0xD6C3, LineBreakClass::H3),
+    (0xD6C4, 0xD6C4, LineBreakClass::H2),
+    (0xD6C5, 0xD6DF, LineBreakClass::H3),
+    (0xD6E0, 0xD6E0, LineBreakClass::H2),
+    (0xD6E1, 0xD6FB, LineBreakClass::H3),
+    (0xD6FC, 0xD6FC, LineBreakClass::H2),
+    (0xD6FD, 0xD717, LineBreakClass::H3),
+    (0xD718, 0xD718, LineBreakClass::H2),
+    (0xD719, 0xD733, LineBreakClass::H3),
+    (0xD734, 0xD734, LineBreakClass::H2),
+    (0xD735, 0xD74F, LineBreakClass::H3),
+    (0xD750, 0xD750, LineBreakClass::H2),
+    (0xD751, 0xD76B, LineBreakClass::H3),
+    (0xD76C, 0xD76C, LineBreakClass::H2),
+    (0xD76D, 0xD787, LineBreakClass::H3),
+    (0xD788, 0xD788, LineBreakClass::H2),
+    (0xD789, 0xD7A3, LineBreakClass::H3),
+    (0xD7B0, 0xD7C6, LineBreakClass::JV),
+    (0xD7CB, 0xD7FB, LineBreakClass::JT),
+    (0xD800, 0xDFFF, LineBreakClass::SG),
+    (0xF900, 0xFAFF, LineBreakClass::ID),
+    (0xFB00, 0xFB06, LineBreakClass::AL),
+    (0xFB13, 0xFB17, LineBreakClass::AL),
+    (0xFB1D, 0xFB1D, LineBreakClass::HL),
+    (0xFB1E, 0xFB1E, LineBreakClass::CM),
+    (0xFB1F, 0xFB28, LineBreakClass::HL),
+    (0xFB29, 0xFB29, LineBreakClass::AL),
+    (0xFB2A, 0xFB36, LineBreakClass::HL),
+    (0xFB38, 0xFB3C, LineBreakClass::HL),
+    (0xFB3E, 0xFB3E, LineBreakClass::HL),
+    (0xFB40, 0xFB41, LineBreakClass::HL),
+    (0xFB43, 0xFB44, LineBreakClass::HL),
+    (0xFB46, 0xFB4F, LineBreakClass::HL),
+    (0xFB50, 0xFBC2, LineBreakClass::AL),
+    (0xFBD3, 0xFD3D, LineBreakClass::AL),
+    (0xFD3E, 0xFD3E, LineBreakClass::CL),
+    (0xFD3F, 0xFD3F, LineBreakClass::OP),
+    (0xFD40, 0xFD8F, LineBreakClass::AL),
+    (0xFD92, 0xFDC7, LineBreakClass::AL),
+    (0xFDCF, 0xFDCF, LineBreakClass::AL),
+    (0xFDF0, 0xFDFB, LineBreakClass::AL),
+    (0xFDFC, 0xFDFC, LineBreakClass::PO),
+    (0xFDFD, 0xFDFF, LineBreakClass::AL),
+    (0xFE00, 0xFE0F, LineBreakClass::CM),
+    (0xFE10, 0xFE10, LineBreakClass::IS),
+    (0xFE11, 0xFE12, LineBreakClass::CL),
+    (0xFE13, 0xFE14, LineBreakClass::IS),
+    (0xFE15, 0xFE16, LineBreakClass::EX),
+    (0xFE17, 0xFE17, LineBreakClass::OP),
+    (0xFE18, 0xFE18, LineBreakClass::CL),
+    (0xFE19, 0xFE19, LineBreakClass::IN),
+    (0xFE20, 0xFE2F, LineBreakClass::CM),
+    (0xFE30, 0xFE34, LineBreakClass::ID),
+    (0xFE35, 0xFE35, LineBreakClass::OP),
+    (0xFE36, 0xFE36, LineBreakClass::CL),
+    (0xFE37, 0xFE37, LineBreakClass::OP),
+    (0xFE38, 0xFE38, LineBreakClass::CL),
+    (0xFE39, 0xFE39, LineBreakClass::OP),
+    (0xFE3A, 0xFE3A, LineBreakClass::CL),
+    (0xFE3B, 0xFE3B, LineBreakClass::OP),
+    (0xFE3C, 0xFE3C, LineBreakClass::CL),
+    (0xFE3D, 0xFE3D, LineBreakClass::OP),
+    (0xFE3E, 0xFE3E, LineBreakClass::CL),
+    (0xFE3F, 0xFE3F, LineBreakClass::OP),
+    (0xFE40, 0xFE40, LineBreakClass::CL),
+    (0xFE41, 0xFE41, LineBreakClass::OP),
+    (0xFE42, 0xFE42, LineBreakClass::CL),
+    (0xFE43, 0xFE43, LineBreakClass::OP),
+    (0xFE44, 0xFE44, LineBreakClass::CL),
+    (0xFE45, 0xFE46, LineBreakClass::ID),
+    (0xFE47, 0xFE47, LineBreakClass::OP),
+    (0xFE48, 0xFE48, LineBreakClass::CL),
+    (0xFE49, 0xFE4F, LineBreakClass::ID),
+    (0xFE50, 0xFE50, LineBreakClass::CL),
+    (0xFE51, 0xFE51, LineBreakClass::ID),
+    (0xFE52, 0xFE52, LineBreakClass::CL),
+    (0xFE54, 0xFE55, LineBreakClass::NS),
+    (0xFE56, 0xFE57, LineBreakClass::EX),
+    (0xFE58, 0xFE58, LineBreakClass::ID),
+    (0xFE59, 0xFE59, LineBreakClass::OP),
+    (0xFE5A, 0xFE5A, LineBreakClass::CL),
+    (0xFE5B, 0xFE5B, LineBreakClass::OP),
+    (0xFE5C, 0xFE5C, LineBreakClass::CL),
+    (0xFE5D, 0xFE5D, LineBreakClass::OP),
+    (0xFE5E, 0xFE5E, LineBreakClass::CL),
+    (0xFE5F, 0xFE66, LineBreakClass::ID),
+    (0xFE68, 0xFE68, LineBreakClass::ID),
+    (0xFE69, 0xFE69, LineBreakClass::PR),
+    (0xFE6A, 0xFE6A, LineBreakClass::PO),
+    (0xFE6B, 0xFE6B, LineBreakClass::ID),
+    (0xFE70, 0xFE74, LineBreakClass::AL),
+    (0xFE76, 0xFEFC, LineBreakClass::AL),
+    (0xFEFF, 0xFEFF, LineBreakClass::WJ),
+    (0xFF01, 0xFF01, LineBreakClass::EX),
+    (0xFF02, 0xFF03, LineBreakClass::ID),
+    (0xFF04, 0xFF04, LineBreakClass::PR),
+    (0xFF05, 0xFF05, LineBreakClass::PO),
+    (0xFF06, 0xFF07, LineBreakClass::ID),
+    (0xFF08, 0xFF08, LineBreakClass::OP),
+    (0xFF09, 0xFF09, LineBreakClass::CL),
+    (0xFF0A, 0xFF0B, LineBreakClass::ID),
+    (0xFF0C, 0xFF0C, LineBreakClass::CL),
+    (0xFF0D, 0xFF0D, LineBreakClass::ID),
+    (0xFF0E, 0xFF0E, LineBreakClass::CL),
+    (0xFF0F, 0xFF19, LineBreakClass::ID),
+    (0xFF1A, 0xFF1B, LineBreakClass::NS),
+    (0xFF1C, 0xFF1E, LineBreakClass::ID),
+    (0xFF1F, 0xFF1F, LineBreakClass::EX),
+    (0xFF20, 0xFF3A, LineBreakClass::ID),
+    (0xFF3B, 0xFF3B, LineBreakClass::OP),
+    (0xFF3C, 0xFF3C, LineBreakClass::ID),
+    (0xFF3D, 0xFF3D, LineBreakClass::CL),
+    (0xFF3E, 0xFF5A, LineBreakClass::ID),
+    (0xFF5B, 0xFF5B, LineBreakClass::OP),
+    (0xFF5C, 0xFF5C, LineBreakClass::ID),
+    (0xFF5D, 0xFF5D, LineBreakClass::CL),
+    (0xFF5E, 0xFF5E, LineBreakClass::ID),
+    (0xFF5F, 0xFF5F, LineBreakClass::OP),
+    (0xFF60, 0xFF61, LineBreakClass::CL),
+    (0xFF62, 0xFF62, LineBreakClass::OP),
+    (0xFF63, 0xFF64, LineBreakClass::CL),
+    (0xFF65, 0xFF65, LineBreakClass::NS),
+    (0xFF66, 0xFF66, LineBreakClass::ID),
+    (0xFF67, 0xFF70, LineBreakClass::CJ),
+    (0xFF71, 0xFF9D, LineBreakClass::ID),
+    (0xFF9E, 0xFF9F, LineBreakClass::NS),
+    (0xFFA0, 0xFFBE, LineBreakClass::ID),
+    (0xFFC2, 0xFFC7, LineBreakClass::ID),
+    (0xFFCA, 0xFFCF, LineBreakClass::ID),
+    (0xFFD2, 0xFFD7, LineBreakClass::ID),
+    (0xFFDA, 0xFFDC, LineBreakClass::ID),
+    (0xFFE0, 0xFFE0, LineBreakClass::PO),
+    (0xFFE1, 0xFFE1, LineBreakClass::PR),
+    (0xFFE2, 0xFFE4, LineBreakClass::ID),
+    (0xFFE5, 0xFFE6, LineBreakClass::PR),
+    (0xFFE8, 0xFFEE, LineBreakClass::AL),
+    (0xFFF9, 0xFFFB, LineBreakClass::CM),
+    (0xFFFC, 0xFFFC, LineBreakClass::CB),
+    (0xFFFD, 0xFFFD, LineBreakClass::AI),
+    (0x10000, 0x1000B, LineBreakClass::AL),
+    (0x1000D, 0x10026, LineBreakClass::AL),
+    (0x10028, 0x1003A, LineBreakClass::AL),
+    (0x1003C, 0x1003D, LineBreakClass::AL),
+    (0x1003F, 0x1004D, LineBreakClass::AL),
+    (0x10050, 0x1005D, LineBreakClass::AL),
+    (0x10080, 0x100FA, LineBreakClass::AL),
+    (0x10100, 0x10102, LineBreakClass::BA),
+    (0x10107, 0x10133, LineBreakClass::AL),
+    (0x10137, 0x1018E, LineBreakClass::AL),
+    (0x10190, 0x1019C, LineBreakClass::AL),
+    (0x101A0, 0x101A0, LineBreakClass::AL),
+    (0x101D0, 0x101FC, LineBreakClass::AL),
+    (0x101FD, 0x101FD, LineBreakClass::CM),
+    (0x10280, 0x1029C, LineBreakClass::AL),
+    (0x102A0, 0x102D0, LineBreakClass::AL),
+    (0x102E0, 0x102E0, LineBreakClass::CM),
+    (0x102E1, 0x102FB, LineBreakClass::AL),
+    (0x10300, 0x10323, LineBreakClass::AL),
+    (0x1032D, 0x1034A, LineBreakClass::AL),
+    (0x10350, 0x10375, LineBreakClass::AL),
+    (0x10376, 0x1037A, LineBreakClass::CM),
+    (0x10380, 0x1039D, LineBreakClass::AL),
+    (0x1039F, 0x1039F, LineBreakClass::BA),
+    (0x103A0, 0x103C3, LineBreakClass::AL),
+    (0x103C8, 0x103CF, LineBreakClass::AL),
+    (0x103D0, 0x103D0, LineBreakClass::BA),
+    (0x103D1, 0x103D5, LineBreakClass::AL),
+    (0x10400, 0x1049D, LineBreakClass::AL),
+    (0x104A0, 0x104A9, LineBreakClass::NU),
+    (0x104B0, 0x104D3, LineBreakClass::AL),
+    (0x104D8, 0x104FB, LineBreakClass::AL),
+    (0x10500, 0x10527, LineBreakClass::AL),
+    (0x10530, 0x10563, LineBreakClass::AL),
+    (0x1056F, 0x1057A, LineBreakClass::AL),
+    (0x1057C, 0x1058A, LineBreakClass::AL),
+    (0x1058C, 0x10592, LineBreakClass::AL),
+    (0x10594, 0x10595, LineBreakClass::AL),
+    (0x10597, 0x105A1, LineBreakClass::AL),
+    (0x105A3, 0x105B1, LineBreakClass::AL),
+    (0x105B3, 0x105B9, LineBreakClass::AL),
+    (0x105BB, 0x105BC, LineBreakClass::AL),
+    (0x10600, 0x10736, LineBreakClass::AL),
+    (0x10740, 0x10755, LineBreakClass::AL),
+    (0x10760, 0x10767, LineBreakClass::AL),
+    (0x10780, 0x10785, LineBreakClass::AL),
+    (0x10787, 0x107B0, LineBreakClass::AL),
+    (0x107B2, 0x107BA, LineBreakClass::AL),
+    (0x10800, 0x10805, LineBreakClass::AL),
+    (0x10808, 0x10808, LineBreakClass::AL),
+    (0x1080A, 0x10835, LineBreakClass::AL),
+    (0x10837, 0x10838, LineBreakClass::AL),
+    (0x1083C, 0x1083C, LineBreakClass::AL),
+    (0x1083F, 0x10855, LineBreakClass::AL),
+    (0x10857, 0x10857, LineBreakClass::BA),
+    (0x10858, 0x1089E, LineBreakClass::AL),
+    (0x108A7, 0x108AF, LineBreakClass::AL),
+    (0x108E0, 0x108F2, LineBreakClass::AL),
+    (0x108F4, 0x108F5, LineBreakClass::AL),
+    (0x108FB, 0x1091B, LineBreakClass::AL),
+    (0x1091F, 0x1091F, LineBreakClass::BA),
+    (0x10920, 0x10939, LineBreakClass::AL),
+    (0x1093F, 0x1093F, LineBreakClass::AL),
+    (0x10980, 0x109B7, LineBreakClass::AL),
+    (0x109BC, 0x109CF, LineBreakClass::AL),
+    (0x109D2, 0x10A00, LineBreakClass::AL),
+    (0x10A01, 0x10A03, LineBreakClass::CM),
+    (0x10A05, 0x10A06, LineBreakClass::CM),
+    (0x10A0C, 0x10A0F, LineBreakClass::CM),
+    (0x10A10, 0x10A13, LineBreakClass::AL),
+    (0x10A15, 0x10A17, LineBreakClass::AL),
+    (0x10A19, 0x10A35, LineBreakClass::AL),
+    (0x10A38, 0x10A3A, LineBreakClass::CM),
+    (0x10A3F, 0x10A3F, LineBreakClass::CM),
+    (0x10A40, 0x10A48, LineBreakClass::AL),
+    (0x10A50, 0x10A57, LineBreakClass::BA),
+    (0x10A58, 0x10A58, LineBreakClass::AL),
+    (0x10A60, 0x10A9F, LineBreakClass::AL),
+    (0x10AC0, 0x10AE4, LineBreakClass::AL),
+    (0x10AE5, 0x10AE6, LineBreakClass::CM),
+    (0x10AEB, 0x10AEF, LineBreakClass::AL),
+    (0x10AF0, 0x10AF5, LineBreakClass::BA),
+    (0x10AF6, 0x10AF6, LineBreakClass::IN),
+    (0x10B00, 0x10B35, LineBreakClass::AL),
+    (0x10B39, 0x10B3F, LineBreakClass::BA),
+    (0x10B40, 0x10B55, LineBreakClass::AL),
+    (0x10B58, 0x10B72, LineBreakClass::AL),
+    (0x10B78, 0x10B91, LineBreakClass::AL),
+    (0x10B99, 0x10B9C, LineBreakClass::AL),
+    (0x10BA9, 0x10BAF, LineBreakClass::AL),
+    (0x10C00, 0x10C48, LineBreakClass::AL),
+    (0x10C80, 0x10CB2, LineBreakClass::AL),
+    (0x10CC0, 0x10CF2, LineBreakClass::AL),
+    (0x10CFA, 0x10D23, LineBreakClass::AL),
+    (0x10D24, 0x10D27, LineBreakClass::CM),
+    (0x10D30, 0x10D39, LineBreakClass::NU),
+    (0x10E60, 0x10E7E, LineBreakClass::AL),
+    (0x10E80, 0x10EA9, LineBreakClass::AL),
+    (0x10EAB, 0x10EAC, LineBreakClass::CM),
+    (0x10EAD, 0x10EAD, LineBreakClass::BA),
+    (0x10EB0, 0x10EB1, LineBreakClass::AL),
+    (0x10F00, 0x10F27, LineBreakClass::AL),
+    (0x10F30, 0x10F45, LineBreakClass::AL),
+    (0x10F46, 0x10F50, LineBreakClass::CM),
+    (0x10F51, 0x10F59, LineBreakClass::AL),
+    (0x10F70, 0x10F81, LineBreakClass::AL),
+    (0x10F82, 0x10F85, LineBreakClass::CM),
+    (0x10F86, 0x10F89, LineBreakClass::AL),
+    (0x10FB0, 0x10FCB, LineBreakClass::AL),
+    (0x10FE0, 0x10FF6, LineBreakClass::AL),
+    (0x11000, 0x11002, LineBreakClass::CM),
+    (0x11003, 0x11037, LineBreakClass::AL),
+    (0x11038, 0x11046, LineBreakClass::CM),
+    (0x11047, 0x11048, LineBreakClass::BA),
+    (0x11049, 0x1104D, LineBreakClass::AL),
+    (0x11052, 0x11065, LineBreakClass::AL),
+    (0x11066, 0x1106F, LineBreakClass::NU),
+    (0x11070, 0x11070, LineBreakClass::CM),
+    (0x11071, 0x11072, LineBreakClass::AL),
+    (0x11073, 0x11074, LineBreakClass::CM),
+    (0x11075, 0x11075, LineBreakClass::AL),
+    (0x1107F, 0x11082, LineBreakClass::CM),
+    (0x11083, 0x110AF, LineBreakClass::AL),
+    (0x110B0, 0x110BA, LineBreakClass::CM),
+    (0x110BB, 0x110BD, LineBreakClass::AL),
+    (0x110BE, 0x110C1, LineBreakClass::BA),
+    (0x110C2, 0x110C2, LineBreakClass::CM),
+    (0x110CD, 0x110CD, LineBreakClass::AL),
+    (0x110D0, 0x110E8, LineBreakClass::AL),
+    (0x110F0, 0x110F9, LineBreakClass::NU),
+    (0x11100, 0x11102, LineBreakClass::CM),
+    (0x11103, 0x11126, LineBreakClass::AL),
+    (0x11127, 0x11134, LineBreakClass::CM),
+    (0x11136, 0x1113F, LineBreakClass::NU),
+    (0x11140, 0x11143, LineBreakClass::BA),
+    (0x11144, 0x11144, LineBreakClass::AL),
+    (0x11145, 0x11146, LineBreakClass::CM),
+    (0x11147, 0x11147, LineBreakClass::AL),
+    (0x11150, 0x11172, LineBreakClass::AL),
+    (0x11173, 0x11173, LineBreakClass::CM),
+    (0x11174, 0x11174, LineBreakClass::AL),
+    (0x11175, 0x11175, LineBreakClass::BB),
+    (0x11176, 0x11176, LineBreakClass::AL),
+    (0x11180, 0x11182, LineBreakClass::CM),
+    (0x11183, 0x111B2, LineBreakClass::AL),
+    (0x111B3, 0x111C0, LineBreakClass::CM),
+    (0x111C1, 0x111C4, LineBreakClass::AL),
+    (0x111C5, 0x111C6, LineBreakClass::BA),
+    (0x111C7, 0x111C7, LineBreakClass::AL),
+    (0x111C8, 0x111C8, LineBreakClass::BA),
+    (0x111C9, 0x111CC, LineBreakClass::CM),
+    (0x111CD, 0x111CD, LineBreakClass::AL),
+    (0x111CE, 0x111CF, LineBreakClass::CM),
+    (0x111D0, 0x111D9, LineBreakClass::NU),
+    (0x111DA, 0x111DA, LineBreakClass::AL),
+    (0x111DB, 0x111DB, LineBreakClass::BB),
+    (0x111DC, 0x111DC, LineBreakClass::AL),
+    (0x111DD, 0x111DF, LineBreakClass::BA),
+    (0x111E1, 0x111F4, LineBreakClass::AL),
+    (0x11200, 0x11211, LineBreakClass::AL),
+    (0x11213, 0x1122B, LineBreakClass::AL),
+    (0x1122C, 0x11237, LineBreakClass::CM),
+    (0x11238, 0x11239, LineBreakClass::BA),
+    (0x1123A, 0x1123A, LineBreakClass::AL),
+    (0x1123B, 0x1123C, LineBreakClass::BA),
+    (0x1123D, 0x1123D, LineBreakClass::AL),
+    (0x1123E, 0x1123E, LineBreakClass::CM),
+    (0x11280, 0x11286, LineBreakClass::AL),
+    (0x11288, 0x11288, LineBreakClass::AL),
+    (0x1128A, 0x1128D, LineBreakClass::AL),
+    (0x1128F, 0x1129D, LineBreakClass::AL),
+    (0x1129F, 0x112A8, LineBreakClass::AL),
+    (0x112A9, 0x112A9, LineBreakClass::BA),
+    (0x112B0, 0x112DE, LineBreakClass::AL),
+    (0x112DF, 0x112EA, LineBreakClass::CM),
+    (0x112F0, 0x112F9, LineBreakClass::NU),
+    (0x11300, 0x11303, LineBreakClass::CM),
+    (0x11305, 0x1130C, LineBreakClass::AL),
+    (0x1130F, 0x11310, LineBreakClass::AL),
+    (0x11313, 0x11328, LineBreakClass::AL),
+    (0x1132A, 0x11330, LineBreakClass::AL),
+    (0x11332, 0x11333, LineBreakClass::AL),
+    (0x11335, 0x11339, LineBreakClass::AL),
+    (0x1133B, 0x1133C, LineBreakClass::CM),
+    (0x1133D, 0x1133D, LineBreakClass::AL),
+    (0x1133E, 0x11344, LineBreakClass::CM),
+    (0x11347, 0x11348, LineBreakClass::CM),
+    (0x1134B, 0x1134D, LineBreakClass::CM),
+    (0x11350, 0x11350, LineBreakClass::AL),
+    (0x11357, 0x11357, LineBreakClass::CM),
+    (0x1135D, 0x11361, LineBreakClass::AL),
+    (0x11362, 0x11363, LineBreakClass::CM),
+    (0x11366, 0x1136C, LineBreakClass::CM),
+    (0x11370, 0x11374, LineBreakClass::CM),
+    (0x11400, 0x11434, LineBreakClass::AL),
+    (0x11435, 0x11446, LineBreakClass::CM),
+    (0x11447, 0x1144A, LineBreakClass::AL),
+    (0x1144B, 0x1144E, LineBreakClass::BA),
+    (0x1144F, 0x1144F, LineBreakClass::AL),
+    (0x11450, 0x11459, LineBreakClass::NU),
+    (0x1145A, 0x1145B, LineBreakClass::BA),
+    (0x1145D, 0x1145D, LineBreakClass::AL),
+    (0x1145E, 0x1145E, LineBreakClass::CM),
+    (0x1145F, 0x11461, LineBreakClass::AL),
+    (0x11480, 0x114AF, LineBreakClass::AL),
+    (0x114B0, 0x114C3, LineBreakClass::CM),
+    (0x114C4, 0x114C7, LineBreakClass::AL),
+    (0x114D0, 0x114D9, LineBreakClass::NU),
+    (0x11580, 0x115AE, LineBreakClass::AL),
+    (0x115AF, 0x115B5, LineBreakClass::CM),
+    (0x115B8, 0x115C0, LineBreakClass::CM),
+    (0x115C1, 0x115C1, LineBreakClass::BB),
+    (0x115C2, 0x115C3, LineBreakClass::BA),
+    (0x115C4, 0x115C5, LineBreakClass::EX),
+    (0x115C6, 0x115C8, LineBreakClass::AL),
+    (0x115C9, 0x115D7, LineBreakClass::BA),
+    (0x115D8, 0x115DB, LineBreakClass::AL),
+    (0x115DC, 0x115DD, LineBreakClass::CM),
+    (0x11600, 0x1162F, LineBreakClass::AL),
+    (0x11630, 0x11640, LineBreakClass::CM),
+    (0x11641, 0x11642, LineBreakClass::BA),
+    (0x11643, 0x11644, LineBreakClass::AL),
+    (0x11650, 0x11659, LineBreakClass::NU),
+    (0x11660, 0x1166C, LineBreakClass::BB),
+    (0x11680, 0x116AA, LineBreakClass::AL),
+    (0x116AB, 0x116B7, LineBreakClass::CM),
+    (0x116B8, 0x116B9, LineBreakClass::AL),
+    (0x116C0, 0x116C9, LineBreakClass::NU),
+    (0x11700, 0x1171A, LineBreakClass::SA),
+    (0x1171D, 0x1172B, LineBreakClass::SA),
+    (0x11730, 0x11739, LineBreakClass::NU),
+    (0x1173A, 0x1173B, LineBreakClass::SA),
+    (0x1173C, 0x1173E, LineBreakClass::BA),
+    (0x1173F, 0x11746, LineBreakClass::SA),
+    (0x11800, 0x1182B, LineBreakClass::AL),
+    (0x1182C, 0x1183A, LineBreakClass::CM),
+    (0x1183B, 0x1183B, LineBreakClass::AL),
+    (0x118A0, 0x118DF, LineBreakClass::AL),
+    (0x118E0, 0x118E9, LineBreakClass::NU),
+    (0x118EA, 0x118F2, LineBreakClass::AL),
+    (0x118FF, 0x11906, LineBreakClass::AL),
+    (0x11909, 0x11909, LineBreakClass::AL),
+    (0x1190C, 0x11913, LineBreakClass::AL),
+    (0x11915, 0x11916, LineBreakClass::AL),
+    (0x11918, 0x1192F, LineBreakClass::AL),
+    (0x11930, 0x11935, LineBreakClass::CM),
+    (0x11937, 0x11938, LineBreakClass::CM),
+    (0x1193B, 0x1193E, LineBreakClass::CM),
+    (0x1193F, 0x1193F, LineBreakClass::AL),
+    (0x11940, 0x11940, LineBreakClass::CM),
+    (0x11941, 0x11941, LineBreakClass::AL),
+    (0x11942, 0x11943, LineBreakClass::CM),
+    (0x11944, 0x11946, LineBreakClass::BA),
+    (0x11950, 0x11959, LineBreakClass::NU),
+    (0x119A0, 0x119A7, LineBreakClass::AL),
+    (0x119AA, 0x119D0, LineBreakClass::AL),
+    (0x119D1, 0x119D7, LineBreakClass::CM),
+    (0x119DA, 0x119E0, LineBreakClass::CM),
+    (0x119E1, 0x119E1, LineBreakClass::AL),
+    (0x119E2, 0x119E2, LineBreakClass::BB),
+    (0x119E3, 0x119E3, LineBreakClass::AL),
+    (0x119E4, 0x119E4, LineBreakClass::CM),
+    (0x11A00, 0x11A00, LineBreakClass::AL),
+    (0x11A01, 0x11A0A, LineBreakClass::CM),
+    (0x11A0B, 0x11A32, LineBreakClass::AL),
+    (0x11A33, 0x11A39, LineBreakClass::CM),
+    (0x11A3A, 0x11A3A, LineBreakClass::AL),
+    (0x11A3B, 0x11A3E, LineBreakClass::CM),
+    (0x11A3F, 0x11A3F, LineBreakClass::BB),
+    (0x11A40, 0x11A40, LineBreakClass::AL),
+    (0x11A41, 0x11A44, LineBreakClass::BA),
+    (0x11A45, 0x11A45, LineBreakClass::BB),
+    (0x11A46, 0x11A46, LineBreakClass::AL),
+    (0x11A47, 0x11A47, LineBreakClass::CM),
+    (0x11A50, 0x11A50, LineBreakClass::AL),
+    (0x11A51, 0x11A5B, LineBreakClass::CM),
+    (0x11A5C, 0x11A89, LineBreakClass::AL),
+    (0x11A8A, 0x11A99, LineBreakClass::CM),
+    (0x11A9A, 0x11A9C, LineBreakClass::BA),
+    (0x11A9D, 0x11A9D, LineBreakClass::AL),
+    (0x11A9E, 0x11AA0, LineBreakClass::BB),
+    (0x11AA1, 0x11AA2, LineBreakClass::BA),
+    (0x11AB0, 0x11AF8, LineBreakClass::AL),
+    (0x11C00, 0x11C08, LineBreakClass::AL),
+    (0x11C0A, 0x11C2E, LineBreakClass::AL),
+    (0x11C2F, 0x11C36, LineBreakClass::CM),
+    (0x11C38, 0x11C3F, LineBreakClass::CM),
+    (0x11C40, 0x11C40, LineBreakClass::AL),
+    (0x11C41, 0x11C45, LineBreakClass::BA),
+    (0x11C50, 0x11C59, LineBreakClass::NU),
+    (0x11C5A, 0x11C6C, LineBreakClass::AL),
+    (0x11C70, 0x11C70, LineBreakClass::BB),
+    (0x11C71, 0x11C71, LineBreakClass::EX),
+    (0x11C72, 0x11C8F, LineBreakClass::AL),
+    (0x11C92, 0x11CA7, LineBreakClass::CM),
+    (0x11CA9, 0x11CB6, LineBreakClass::CM),
+    (0x11D00, 0x11D06, LineBreakClass::AL),
+    (0x11D08, 0x11D09, LineBreakClass::AL),
+    (0x11D0B, 0x11D30, LineBreakClass::AL),
+    (0x11D31, 0x11D36, LineBreakClass::CM),
+    (0x11D3A, 0x11D3A, LineBreakClass::CM),
+    (0x11D3C, 0x11D3D, LineBreakClass::CM),
+    (0x11D3F, 0x11D45, LineBreakClass::CM),
+    (0x11D46, 0x11D46, LineBreakClass::AL),
+    (0x11D47, 0x11D47, LineBreakClass::CM),
+    (0x11D50, 0x11D59, LineBreakClass::NU),
+    (0x11D60, 0x11D65, LineBreakClass::AL),
+    (0x11D67, 0x11D68, LineBreakClass::AL),
+    (0x11D6A, 0x11D89, LineBreakClass::AL),
+    (0x11D8A, 0x11D8E, LineBreakClass::CM),
+    (0x11D90, 0x11D91, LineBreakClass::CM),
+    (0x11D93, 0x11D97, LineBreakClass::CM),
+    (0x11D98, 0x11D98, LineBreakClass::AL),
+    (0x11DA0, 0x11DA9, LineBreakClass::NU),
+    (0x11EE0, 0x11EF2, LineBreakClass::AL),
+    (0x11EF3, 0x11EF6, LineBreakClass::CM),
+    (0x11EF7, 0x11EF8, LineBreakClass::AL),
+    (0x11FB0, 0x11FB0, LineBreakClass::AL),
+    (0x11FC0, 0x11FDC, LineBreakClass::AL),
+    (0x11FDD, 0x11FE0, LineBreakClass::PO),
+    (0x11FE1, 0x11FF1, LineBreakClass::AL),
+    (0x11FFF, 0x11FFF, LineBreakClass::BA),
+    (0x12000, 0x12399, LineBreakClass::AL),
+    (0x12400, 0x1246E, LineBreakClass::AL),
+    (0x12470, 0x12474, LineBreakClass::BA),
+    (0x12480, 0x12543, LineBreakClass::AL),
+    (0x12F90, 0x12FF2, LineBreakClass::AL),
+    (0x13000, 0x13257, LineBreakClass::AL),
+    (0x13258, 0x1325A, LineBreakClass::OP),
+    (0x1325B, 0x1325D, LineBreakClass::CL),
+    (0x1325E, 0x13281, LineBreakClass::AL),
+    (0x13282, 0x13282, LineBreakClass::CL),
+    (0x13283, 0x13285, LineBreakClass::AL),
+    (0x13286, 0x13286, LineBreakClass::OP),
+    (0x13287, 0x13287, LineBreakClass::CL),
+    (0x13288, 0x13288, LineBreakClass::OP),
+    (0x13289, 0x13289, LineBreakClass::CL),
+    (0x1328A, 0x13378, LineBreakClass::AL),
+    (0x13379, 0x13379, LineBreakClass::OP),
+    (0x1337A, 0x1337B, LineBreakClass::CL),
+    (0x1337C, 0x1342E, LineBreakClass::AL),
+    (0x13430, 0x13436, LineBreakClass::GL),
+    (0x13437, 0x13437, LineBreakClass::OP),
+    (0x13438, 0x13438, LineBreakClass::CL),
+    (0x14400, 0x145CD, LineBreakClass::AL),
+    (0x145CE, 0x145CE, LineBreakClass::OP),
+    (0x145CF, 0x145CF, LineBreakClass::CL),
+    (0x145D0, 0x14646, LineBreakClass::AL),
+    (0x16800, 0x16A38, LineBreakClass::AL),
+    (0x16A40, 0x16A5E, LineBreakClass::AL),
+    (0x16A60, 0x16A69, LineBreakClass::NU),
+    (0x16A6E, 0x16A6F, LineBreakClass::BA),
+    (0x16A70, 0x16ABE, LineBreakClass::AL),
+    (0x16AC0, 0x16AC9, LineBreakClass::NU),
+    (0x16AD0, 0x16AED, LineBreakClass::AL),
+    (0x16AF0, 0x16AF4, LineBreakClass::CM),
+    (0x16AF5, 0x16AF5, LineBreakClass::BA),
+    (0x16B00, 0x16B2F, LineBreakClass::AL),
+    (0x16B30, 0x16B36, LineBreakClass::CM),
+    (0x16B37, 0x16B39, LineBreakClass::BA),
+    (0x16B3A, 0x16B43, LineBreakClass::AL),
+    (0x16B44, 0x16B44, LineBreakClass::BA),
+    (0x16B45, 0x16B45, LineBreakClass::AL),
+    (0x16B50, 0x16B59, LineBreakClass::NU),
+    (0x16B5B, 0x16B61, LineBreakClass::AL),
+    (0x16B63, 0x16B77, LineBreakClass::AL),
+    (0x16B7D, 0x16B8F, LineBreakClass::AL),
+    (0x16E40, 0x16E96, LineBreakClass::AL),
+    (0x16E97, 0x16E98, LineBreakClass::BA),
+    (0x16E99, 0x16E9A, LineBreakClass::AL),
+    (0x16F00, 0x16F4A, LineBreakClass::AL),
+    (0x16F4F, 0x16F4F, LineBreakClass::CM),
+    (0x16F50, 0x16F50, LineBreakClass::AL),
+    (0x16F51, 0x16F87, LineBreakClass::CM),
+    (0x16F8F, 0x16F92, LineBreakClass::CM),
+    (0x16F93, 0x16F9F, LineBreakClass::AL),
+    (0x16FE0, 0x16FE3, LineBreakClass::NS),
+    (0x16FE4, 0x16FE4, LineBreakClass::GL),
+    (0x16FF0, 0x16FF1, LineBreakClass::CM),
+    (0x17000, 0x187F7, LineBreakClass::ID),
+    (0x18800, 0x18AFF, LineBreakClass::ID),
+    (0x18B00, 0x18CD5, LineBreakClass::AL),
+    (0x18D00, 0x18D08, LineBreakClass::ID),
+    (0x1AFF0, 0x1AFF3, LineBreakClass::AL),
+    (0x1AFF5, 0x1AFFB, LineBreakClass::AL),
+    (0x1AFFD, 0x1AFFE, LineBreakClass::AL),
+    (0x1B000, 0x1B122, LineBreakClass::ID),
+    (0x1B150, 0x1B152, LineBreakClass::CJ),
+    (0x1B164, 0x1B167, LineBreakClass::CJ),
+    (0x1B170, 0x1B2FB, LineBreakClass::ID),
+    (0x1BC00, 0x1BC6A, LineBreakClass::AL),
+    (0x1BC70, 0x1BC7C, LineBreakClass::AL),
+    (0x1BC80, 0x1BC88, LineBreakClass::AL),
+    (0x1BC90, 0x1BC99, LineBreakClass::AL),
+    (0x1BC9C, 0x1BC9C, LineBreakClass::AL),
+    (0x1BC9D, 0x1BC9E, LineBreakClass::CM),
+    (0x1BC9F, 0x1BC9F, LineBreakClass::BA),
+    (0x1BCA0, 0x1BCA3, LineBreakClass::CM),
+    (0x1CF00, 0x1CF2D, LineBreakClass::CM),
+    (0x1CF30, 0x1CF46, LineBreakClass::CM),
+    (0x1CF50, 0x1CFC3, LineBreakClass::AL),
+    (0x1D000, 0x1D0F5, LineBreakClass::AL),
+    (0x1D100, 0x1D126, LineBreakClass::AL),
+    (0x1D129, 0x1D164, LineBreakClass::AL),
+    (0x1D165, 0x1D169, LineBreakClass::CM),
+    (0x1D16A, 0x1D16C, LineBreakClass::AL),
+    (0x1D16D, 0x1D182, LineBreakClass::CM),
+    (0x1D183, 0x1D184, LineBreakClass::AL),
+    (0x1D185, 0x1D18B, LineBreakClass::CM),
+    (0x1D18C, 0x1D1A9, LineBreakClass::AL),
+    (0x1D1AA, 0x1D1AD, LineBreakClass::CM),
+    (0x1D1AE, 0x1D1EA, LineBreakClass::AL),
+    (0x1D200, 0x1D241, LineBreakClass::AL),
+    (0x1D242, 0x1D244, LineBreakClass::CM),
+    (0x1D245, 0x1D245, LineBreakClass::AL),
+    (0x1D2E0, 0x1D2F3, LineBreakClass::AL),
+    (0x1D300, 0x1D356, LineBreakClass::AL),
+    (0x1D360, 0x1D378, LineBreakClass::AL),
+    (0x1D400, 0x1D454, LineBreakClass::AL),
+    (0x1D456, 0x1D49C, LineBreakClass::AL),
+    (0x1D49E, 0x1D49F, LineBreakClass::AL),
+    (0x1D4A2, 0x1D4A2, LineBreakClass::AL),
+    (0x1D4A5, 0x1D4A6, LineBreakClass::AL),
+    (0x1D4A9, 0x1D4AC, LineBreakClass::AL),
+    (0x1D4AE, 0x1D4B9, LineBreakClass::AL),
+    (0x1D4BB, 0x1D4BB, LineBreakClass::AL),
+    (0x1D4BD, 0x1D4C3, LineBreakClass::AL),
+    (0x1D4C5, 0x1D505, LineBreakClass::AL),
+    (0x1D507, 0x1D50A, LineBreakClass::AL),
+    (0x1D50D, 0x1D514, LineBreakClass::AL),
+    (0x1D516, 0x1D51C, LineBreakClass::AL),
+    (0x1D51E, 0x1D539, LineBreakClass::AL),
+    (0x1D53B, 0x1D53E, LineBreakClass::AL),
+    (0x1D540, 0x1D544, LineBreakClass::AL),
+    (0x1D546, 0x1D546, LineBreakClass::AL),
+    (0x1D54A, 0x1D550, LineBreakClass::AL),
+    (0x1D552, 0x1D6A5, LineBreakClass::AL),
+    (0x1D6A8, 0x1D7CB, LineBreakClass::AL),
+    (0x1D7CE, 0x1D7FF, LineBreakClass::NU),
+    (0x1D800, 0x1D9FF, LineBreakClass::AL),
+    (0x1DA00, 0x1DA36, LineBreakClass::CM),
+    (0x1DA37, 0x1DA3A, LineBreakClass::AL),
+    (0x1DA3B, 0x1DA6C, LineBreakClass::CM),
+    (0x1DA6D, 0x1DA74, LineBreakClass::AL),
+    (0x1DA75, 0x1DA75, LineBreakClass::CM),
+    (0x1DA76, 0x1DA83, LineBreakClass::AL),
+    (0x1DA84, 0x1DA84, LineBreakClass::CM),
+    (0x1DA85, 0x1DA86, LineBreakClass::AL),
+    (0x1DA87, 0x1DA8A, LineBreakClass::BA),
+    (0x1DA8B, 0x1DA8B, LineBreakClass::AL),
+    (0x1DA9B, 0x1DA9F, LineBreakClass::CM),
+    (0x1DAA1, 0x1DAAF, LineBreakClass::CM),
+    (0x1DF00, 0x1DF1E, LineBreakClass::AL),
+    (0x1E000, 0x1E006, LineBreakClass::CM),
+    (0x1E008, 0x1E018, LineBreakClass::CM),
+    (0x1E01B, 0x1E021, LineBreakClass::CM),
+    (0x1E023, 0x1E024, LineBreakClass::CM),
+    (0x1E026, 0x1E02A, LineBreakClass::CM),
+    (0x1E100, 0x1E12C, LineBreakClass::AL),
+    (0x1E130, 0x1E136, LineBreakClass::CM),
+    (0x1E137, 0x1E13D, LineBreakClass::AL),
+    (0x1E140, 0x1E149, LineBreakClass::NU),
+    (0x1E14E, 0x1E14F, LineBreakClass::AL),
+    (0x1E290, 0x1E2AD, LineBreakClass::AL),
+    (0x1E2AE, 0x1E2AE, LineBreakClass::CM),
+    (0x1E2C0, 0x1E2EB, LineBreakClass::AL),
+    (0x1E2EC, 0x1E2EF, LineBreakClass::CM),
+    (0x1E2F0, 0x1E2F9, LineBreakClass::NU),
+    (0x1E2FF, 0x1E2FF, LineBreakClass::PR),
+    (0x1E7E0, 0x1E7E6, LineBreakClass::AL),
+    (0x1E7E8, 0x1E7EB, LineBreakClass::AL),
+    (0x1E7ED, 0x1E7EE, LineBreakClass::AL),
+    (0x1E7F0, 0x1E7FE, LineBreakClass::AL),
+    (0x1E800, 0x1E8C4, LineBreakClass::AL),
+    (0x1E8C7, 0x1E8CF, LineBreakClass::AL),
+    (0x1E8D0, 0x1E8D6, LineBreakClass::CM),
+    (0x1E900, 0x1E943, LineBreakClass::AL),
+    (0x1E944, 0x1E94A, LineBreakClass::CM),
+    (0x1E94B, 0x1E94B, LineBreakClass::AL),
+    (0x1E950, 0x1E959, LineBreakClass::NU),
+    (0x1E95E, 0x1E95F, LineBreakClass::OP),
+    (0x1EC71, 0x1ECAB, LineBreakClass::AL),
+    (0x1ECAC, 0x1ECAC, LineBreakClass::PO),
+    (0x1ECAD, 0x1ECAF, LineBreakClass::AL),
+    (0x1ECB0, 0x1ECB0, LineBreakClass::PO),
+    (0x1ECB1, 0x1ECB4, LineBreakClass::AL),
+    (0x1ED01, 0x1ED3D, LineBreakClass::AL),
+    (0x1EE00, 0x1EE03, LineBreakClass::AL),
+    (0x1EE05, 0x1EE1F, LineBreakClass::AL),
+    (0x1EE21, 0x1EE22, LineBreakClass::AL),
+    (0x1EE24, 0x1EE24, LineBreakClass::AL),
+    (0x1EE27, 0x1EE27, LineBreakClass::AL),
+    (0x1EE29, 0x1EE32, LineBreakClass::AL),
+    (0x1EE34, 0x1EE37, LineBreakClass::AL),
+    (0x1EE39, 0x1EE39, LineBreakClass::AL),
+    (0x1EE3B, 0x1EE3B, LineBreakClass::AL),
+    (0x1EE42, 0x1EE42, LineBreakClass::AL),
+    (0x1EE47, 0x1EE47, LineBreakClass::AL),
+    (0x1EE49, 0x1EE49, LineBreakClass::AL),
+    (0x1EE4B, 0x1EE4B, LineBreakClass::AL),
+    (0x1EE4D, 0x1EE4F, LineBreakClass::AL),
+    (0x1EE51, 0x1EE52, LineBreakClass::AL),
+    (0x1EE54, 0x1EE54, LineBreakClass::AL),
+    (0x1EE57, 0x1EE57, LineBreakClass::AL),
+    (0x1EE59, 0x1EE59, LineBreakClass::AL),
+    (0x1EE5B, 0x1EE5B, LineBreakClass::AL),
+    (0x1EE5D, 0x1EE5D, LineBreakClass::AL),
+    (0x1EE5F, 0x1EE5F, LineBreakClass::AL),
+    (0x1EE61, 0x1EE62, LineBreakClass::AL),
+    (0x1EE64, 0x1EE64, LineBreakClass::AL),
+    (0x1EE67, 0x1EE6A, LineBreakClass::AL),
+    (0x1EE6C, 0x1EE72, LineBreakClass::AL),
+    (0x1EE74, 0x1EE77, LineBreakClass::AL),
+    (0x1EE79, 0x1EE7C, LineBreakClass::AL),
+    (0x1EE7E, 0x1EE7E, LineBreakClass::AL),
+    (0x1EE80, 0x1EE89, LineBreakClass::AL),
+    (0x1EE8B, 0x1EE9B, LineBreakClass::AL),
+    (0x1EEA1, 0x1EEA3, LineBreakClass::AL),
+    (0x1EEA5, 0x1EEA9, LineBreakClass::AL),
+    (0x1EEAB, 0x1EEBB, LineBreakClass::AL),
+    (0x1EEF0, 0x1EEF1, LineBreakClass::AL),
+    (0x1F000, 0x1F0FF, LineBreakClass::ID),
+    (0x1F100, 0x1F10C, LineBreakClass::AI),
+    (0x1F10D, 0x1F10F, LineBreakClass::ID),
+    (0x1F110, 0x1F12D, LineBreakClass::AI),
+    (0x1F12E, 0x1F12F, LineBreakClass::AL),
+    (0x1F130, 0x1F169, LineBreakClass::AI),
+    (0x1F16A, 0x1F16C, LineBreakClass::AL),
+    (0x1F16D, 0x1F16F, LineBreakClass::ID),
+    (0x1F170, 0x1F1AC, LineBreakClass::AI),
+    (0x1F1AD, 0x1F1E5, LineBreakClass::ID),
+    (0x1F1E6, 0x1F1FF, LineBreakClass::RI),
+    (0x1F200, 0x1F384, LineBreakClass::ID),
+    (0x1F385, 0x1F385, LineBreakClass::EB),
+    (0x1F386, 0x1F39B, LineBreakClass::ID),
+    (0x1F39C, 0x1F39D, LineBreakClass::AL),
+    (0x1F39E, 0x1F3B4, LineBreakClass::ID),
+    (0x1F3B5, 0x1F3B6, LineBreakClass::AL),
+    (0x1F3B7, 0x1F3BB, LineBreakClass::ID),
+    (0x1F3BC, 0x1F3BC, LineBreakClass::AL),
+    (0x1F3BD, 0x1F3C1, LineBreakClass::ID),
+    (0x1F3C2, 0x1F3C4, LineBreakClass::EB),
+    (0x1F3C5, 0x1F3C6, LineBreakClass::ID),
+    (0x1F3C7, 0x1F3C7, LineBreakClass::EB),
+    (0x1F3C8, 0x1F3C9, LineBreakClass::ID),
+    (0x1F3CA, 0x1F3CC, LineBreakClass::EB),
+    (0x1F3CD, 0x1F3FA, LineBreakClass::ID),
+    (0x1F3FB, 0x1F3FF, LineBreakClass::EM),
+    (0x1F400, 0x1F441, LineBreakClass::ID),
+    (0x1F442, 0x1F443, LineBreakClass::EB),
+    (0x1F444, 0x1F445, LineBreakClass::ID),
+    (0x1F446, 0x1F450, LineBreakClass::EB),
+    (0x1F451, 0x1F465, LineBreakClass::ID),
+    (0x1F466, 0x1F478, LineBreakClass::EB),
+    (0x1F479, 0x1F47B, LineBreakClass::ID),
+    (0x1F47C, 0x1F47C, LineBreakClass::EB),
+    (0x1F47D, 0x1F480, LineBreakClass::ID),
+    (0x1F481, 0x1F483, LineBreakClass::EB),
+    (0x1F484, 0x1F484, LineBreakClass::ID),
+    (0x1F485, 0x1F487, LineBreakClass::EB),
+    (0x1F488, 0x1F48E, LineBreakClass::ID),
+    (0x1F48F, 0x1F48F, LineBreakClass::EB),
+    (0x1F490, 0x1F490, LineBreakClass::ID),
+    (0x1F491, 0x1F491, LineBreakClass::EB),
+    (0x1F492, 0x1F49F, LineBreakClass::ID),
+    (0x1F4A0, 0x1F4A0, LineBreakClass::AL),
+    (0x1F4A1, 0x1F4A1, LineBreakClass::ID),
+    (0x1F4A2, 0x1F4A2, LineBreakClass::AL),
+    (0x1F4A3, 0x1F4A3, LineBreakClass::ID),
+    (0x1F4A4, 0x1F4A4, LineBreakClass::AL),
+    (0x1F4A5, 0x1F4A9, LineBreakClass::ID),
+    (0x1F4AA, 0x1F4AA, LineBreakClass::EB),
+    (0x1F4AB, 0x1F4AE, LineBreakClass::ID),
+    (0x1F4AF, 0x1F4AF, LineBreakClass::AL),
+    (0x1F4B0, 0x1F4B0, LineBreakClass::ID),
+    (0x1F4B1, 0x1F4B2, LineBreakClass::AL),
+    (0x1F4B3, 0x1F4FF, LineBreakClass::ID),
+    (0x1F500, 0x1F506, LineBreakClass::AL),
+    (0x1F507, 0x1F516, LineBreakClass::ID),
+    (0x1F517, 0x1F524, LineBreakClass::AL),
+    (0x1F525, 0x1F531, LineBreakClass::ID),
+    (0x1F532, 0x1F549, LineBreakClass::AL),
+    (0x1F54A, 0x1F573, LineBreakClass::ID),
+    (0x1F574, 0x1F575, LineBreakClass::EB),
+    (0x1F576, 0x1F579, LineBreakClass::ID),
+    (0x1F57A, 0x1F57A, LineBreakClass::EB),
+    (0x1F57B, 0x1F58F, LineBreakClass::ID),
+    (0x1F590, 0x1F590, LineBreakClass::EB),
+    (0x1F591, 0x1F594, LineBreakClass::ID),
+    (0x1F595, 0x1F596, LineBreakClass::EB),
+    (0x1F597, 0x1F5D3, LineBreakClass::ID),
+    (0x1F5D4, 0x1F5DB, LineBreakClass::AL),
+    (0x1F5DC, 0x1F5F3, LineBreakClass::ID),
+    (0x1F5F4, 0x1F5F9, LineBreakClass::AL),
+    (0x1F5FA, 0x1F644, LineBreakClass::ID),
+    (0x1F645, 0x1F647, LineBreakClass::EB),
+    (0x1F648, 0x1F64A, LineBreakClass::ID),
+    (0x1F64B, 0x1F64F, LineBreakClass::EB),
+    (0x1F650, 0x1F675, LineBreakClass::AL),
+    (0x1F676, 0x1F678, LineBreakClass::QU),
+    (0x1F679, 0x1F67B, LineBreakClass::NS),
+    (0x1F67C, 0x1F67F, LineBreakClass::AL),
+    (0x1F680, 0x1F6A2, LineBreakClass::ID),
+    (0x1F6A3, 0x1F6A3, LineBreakClass::EB),
+    (0x1F6A4, 0x1F6B3, LineBreakClass::ID),
+    (0x1F6B4, 0x1F6B6, LineBreakClass::EB),
+    (0x1F6B7, 0x1F6BF, LineBreakClass::ID),
+    (0x1F6C0, 0x1F6C0, LineBreakClass::EB),
+    (0x1F6C1, 0x1F6CB, LineBreakClass::ID),
+    (0x1F6CC, 0x1F6CC, LineBreakClass::EB),
+    (0x1F6CD, 0x1F6FF, LineBreakClass::ID),
+    (0x1F700, 0x1F773, LineBreakClass::AL),
+    (0x1F774, 0x1F77F, LineBreakClass::ID),
+    (0x1F780, 0x1F7D4, LineBreakClass::AL),
+    (0x1F7D5, 0x1F7FF, LineBreakClass::ID),
+    (0x1F800, 0x1F80B, LineBreakClass::AL),
+    (0x1F80C, 0x1F80F, LineBreakClass::ID),
+    (0x1F810, 0x1F847, LineBreakClass::AL),
+    (0x1F848, 0x1F84F, LineBreakClass::ID),
+    (0x1F850, 0x1F859, LineBreakClass::AL),
+    (0x1F85A, 0x1F85F, LineBreakClass::ID),
+    (0x1F860, 0x1F887, LineBreakClass::AL),
+    (0x1F888, 0x1F88F, LineBreakClass::ID),
+    (0x1F890, 0x1F8AD, LineBreakClass::AL),
+    (0x1F8AE, 0x1F8FF, LineBreakClass::ID),
+    (0x1F900, 0x1F90B, LineBreakClass::AL),
+    (0x1F90C, 0x1F90C, LineBreakClass::EB),
+    (0x1F90D, 0x1F90E, LineBreakClass::ID),
+    (0x1F90F, 0x1F90F, LineBreakClass::EB),
+    (0x1F910, 0x1F917, LineBreakClass::ID),
+    (0x1F918, 0x1F91F, LineBreakClass::EB),
+    (0x1F920, 0x1F925, LineBreakClass::ID),
+    (0x1F926, 0x1F926, LineBreakClass::EB),
+    (0x1F927, 0x1F92F, LineBreakClass::ID),
+    (0x1F930, 0x1F939, LineBreakClass::EB),
+    (0x1F93A, 0x1F93B, LineBreakClass::ID),
+    (0x1F93C, 0x1F93E, LineBreakClass::EB),
+    (0x1F93F, 0x1F976, LineBreakClass::ID),
+    (0x1F977, 0x1F977, LineBreakClass::EB),
+    (0x1F978, 0x1F9B4, LineBreakClass::ID),
+    (0x1F9B5, 0x1F9B6, LineBreakClass::EB),
+    (0x1F9B7, 0x1F9B7, LineBreakClass::ID),
+    (0x1F9B8, 0x1F9B9, LineBreakClass::EB),
+    (0x1F9BA, 0x1F9BA, LineBreakClass::ID),
+    (0x1F9BB, 0x1F9BB, LineBreakClass::EB),
+    (0x1F9BC, 0x1F9CC, LineBreakClass::ID),
+    (0x1F9CD, 0x1F9CF, LineBreakClass::EB),
+    (0x1F9D0, 0x1F9D0, LineBreakClass::ID),
+    (0x1F9D1, 0x1F9DD, LineBreakClass::EB),
+    (0x1F9DE, 0x1F9FF, LineBreakClass::ID),
+    (0x1FA00, 0x1FA53, LineBreakClass::AL),
+    (0x1FA54, 0x1FAC2, LineBreakClass::ID),
+    (0x1FAC3, 0x1FAC5, LineBreakClass::EB),
+    (0x1FAC6, 0x1FAEF, LineBreakClass::ID),
+    (0x1FAF0, 0x1FAF6, LineBreakClass::EB),
+    (0x1FAF7, 0x1FAFF, LineBreakClass::ID),
+    (0x1FB00, 0x1FB92, LineBreakClass::AL),
+    (0x1FB94, 0x1FBCA, LineBreakClass::AL),
+    (0x1FBF0, 0x1FBF9, LineBreakClass::NU),
+    (0x1FC00, 0x1FFFD, LineBreakClass::ID),
+    (0x20000, 0x2FFFD, LineBreakClass::ID),
+    (0x30000, 0x3FFFD, LineBreakClass::ID),
+    (0xE0001, 0xE0001, LineBreakClass::CM),
+    (0xE0020, 0xE007F, LineBreakClass::CM),
+    (0xE0100, 0xE01EF, LineBreakClass::CM),
+];
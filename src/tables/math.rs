@@ -0,0 +1,143 @@
+// Generated by `scripts/unicode.pl` from the Unicode 14.0.0
+// character database. Do not edit by hand.
+
+pub(crate) const MATH: &[(u32, u32)] = &[
+    (0x002B, 0x002B),
+    (0x003C, 0x003E),
+    (0x005E, 0x005E),
+    (0x007C, 0x007C),
+    (0x007E, 0x007E),
+    (0x00AC, 0x00AC),
+    (0x00B1, 0x00B1),
+    (0x00D7, 0x00D7),
+    (0x00F7, 0x00F7),
+    (0x03D0, 0x03D2),
+    (0x03D5, 0x03D5),
+    (0x03F0, 0x03F1),
+    (0x03F4, 0x03F6),
+    (0x0606, 0x0608),
+    (0x2016, 0x2016),
+    (0x2032, 0x2034),
+    (0x2040, 0x2040),
+    (0x2044, 0x2044),
+    (0x2052, 0x2052),
+    (0x2061, 0x2064),
+    (0x207A, 0x207E),
+    (0x208A, 0x208E),
+    (0x20D0, 0x20DC),
+    (0x20E1, 0x20E1),
+    (0x20E5, 0x20E6),
+    (0x20EB, 0x20EF),
+    (0x2102, 0x2102),
+    (0x2107, 0x2107),
+    (0x210A, 0x2113),
+    (0x2115, 0x2115),
+    (0x2118, 0x211D),
+    (0x2124, 0x2124),
+    (0x2128, 0x2129),
+    (0x212C, 0x212D),
+    (0x212F, 0x2131),
+    (0x2133, 0x2138),
+    (0x213C, 0x2149),
+    (0x214B, 0x214B),
+    (0x2190, 0x21A7),
+    (0x21A9, 0x21AE),
+    (0x21B0, 0x21B1),
+    (0x21B6, 0x21B7),
+    (0x21BC, 0x21DB),
+    (0x21DD, 0x21DD),
+    (0x21E4, 0x21E5),
+    (0x21F4, 0x22FF),
+    (0x2308, 0x230B),
+    (0x2320, 0x2321),
+    (0x237C, 0x237C),
+    (0x239B, 0x23B5),
+    (0x23B7, 0x23B7),
+    (0x23D0, 0x23D0),
+    (0x23DC, 0x23E2),
+    (0x25A0, 0x25A1),
+    (0x25AE, 0x25B7),
+    (0x25BC, 0x25C1),
+    (0x25C6, 0x25C7),
+    (0x25CA, 0x25CB),
+    (0x25CF, 0x25D3),
+    (0x25E2, 0x25E2),
+    (0x25E4, 0x25E4),
+    (0x25E7, 0x25EC),
+    (0x25F8, 0x25FF),
+    (0x2605, 0x2606),
+    (0x2640, 0x2640),
+    (0x2642, 0x2642),
+    (0x2660, 0x2663),
+    (0x266D, 0x266F),
+    (0x27C0, 0x27FF),
+    (0x2900, 0x2AFF),
+    (0x2B30, 0x2B44),
+    (0x2B47, 0x2B4C),
+    (0xFB29, 0xFB29),
+    (0xFE61, 0xFE66),
+    (0xFE68, 0xFE68),
+    (0xFF0B, 0xFF0B),
+    (0xFF1C, 0xFF1E),
+    (0xFF3C, 0xFF3C),
+    (0xFF3E, 0xFF3E),
+    (0xFF5C, 0xFF5C),
+    (0xFF5E, 0xFF5E),
+    (0xFFE2, 0xFFE2),
+    (0xFFE9, 0xFFEC),
+    (0x1D400, 0x1D454),
+    (0x1D456, 0x1D49C),
+    (0x1D49E, 0x1D49F),
+    (0x1D4A2, 0x1D4A2),
+    (0x1D4A5, 0x1D4A6),
+    (0x1D4A9, 0x1D4AC),
+    (0x1D4AE, 0x1D4B9),
+    (0x1D4BB, 0x1D4BB),
+    (0x1D4BD, 0x1D4C3),
+    (0x1D4C5, 0x1D505),
+    (0x1D507, 0x1D50A),
+    (0x1D50D, 0x1D514),
+    (0x1D516, 0x1D51C),
+    (0x1D51E, 0x1D539),
+    (0x1D53B, 0x1D53E),
+    (0x1D540, 0x1D544),
+    (0x1D546, 0x1D546),
+    (0x1D54A, 0x1D550),
+    (0x1D552, 0x1D6A5),
+    (0x1D6A8, 0x1D7CB),
+    (0x1D7CE, 0x1D7FF),
+    (0x1EE00, 0x1EE03),
+    (0x1EE05, 0x1EE1F),
+    (0x1EE21, 0x1EE22),
+    (0x1EE24, 0x1EE24),
+    (0x1EE27, 0x1EE27),
+    (0x1EE29, 0x1EE32),
+    (0x1EE34, 0x1EE37),
+    (0x1EE39, 0x1EE39),
+    (0x1EE3B, 0x1EE3B),
+    (0x1EE42, 0x1EE42),
+    (0x1EE47, 0x1EE47),
+    (0x1EE49, 0x1EE49),
+    (0x1EE4B, 0x1EE4B),
+    (0x1EE4D, 0x1EE4F),
+    (0x1EE51, 0x1EE52),
+    (0x1EE54, 0x1EE54),
+    (0x1EE57, 0x1EE57),
+    (0x1EE59, 0x1EE59),
+    (0x1EE5B, 0x1EE5B),
+    (0x1EE5D, 0x1EE5D),
+    (0x1EE5F, 0x1EE5F),
+    (0x1EE61, 0x1EE62),
+    (0x1EE64, 0x1EE64),
+    (0x1EE67, 0x1EE6A),
+    (0x1EE6C, 0x1EE72),
+    (0x1EE74, 0x1EE77),
+    (0x1EE79, 0x1EE7C),
+    (0x1EE7E, 0x1EE7E),
+    (0x1EE80, 0x1EE89),
+    (0x1EE8B, 0x1EE9B),
+    (0x1EEA1, 0x1EEA3),
+    (0x1EEA5, 0x1EEA9),
+    (0x1EEAB, 0x1EEBB),
+    (0x1EEF0, 0x1EEF1),
+];
@@ -0,0 +1,137 @@
+//! Unicode character database tables.
+//!
+//! The tables are generated by `scripts/unicode.pl`, and are pinned to a
+//! single Unicode version so results do not depend on the toolchain.
+
+mod block;
+mod case_folding;
+mod cased;
+#[cfg(feature = "confusables")]
+mod confusables;
+mod decimal;
+mod east_asian_width;
+#[cfg(feature = "emoji")]
+mod emoji;
+mod general_category;
+#[cfg(feature = "grapheme")]
+mod grapheme;
+mod joining_type;
+mod math;
+#[cfg(feature = "segmentation")]
+mod line_break;
+#[cfg(feature = "alloc")]
+mod normalization;
+mod numeric;
+mod pattern;
+mod punctuation;
+mod script;
+#[cfg(feature = "segmentation")]
+mod sentence_break;
+mod white_space;
+mod width;
+#[cfg(feature = "segmentation")]
+mod word_break;
+mod xid;
+
+pub use self::block::Block;
+pub use self::general_category::GeneralCategory;
+pub use self::joining_type::JoiningType;
+#[cfg(feature = "segmentation")]
+pub use self::line_break::LineBreakClass;
+pub use self::script::Script;
+pub(crate) use self::block::BLOCK;
+pub(crate) use self::case_folding::{FULL_CASE_FOLDING, SIMPLE_CASE_FOLDING};
+pub(crate) use self::cased::CASED;
+#[cfg(feature = "confusables")]
+pub(crate) use self::confusables::CONFUSABLES;
+pub(crate) use self::decimal::DECIMAL_DIGIT;
+pub(crate) use self::east_asian_width::{EastAsianWidth, EAST_ASIAN_WIDTH};
+#[cfg(feature = "emoji")]
+pub(crate) use self::emoji::{EMOJI, EMOJI_MODIFIER, EMOJI_MODIFIER_BASE, EMOJI_PRESENTATION};
+pub(crate) use self::general_category::GENERAL_CATEGORY;
+#[cfg(feature = "grapheme")]
+pub(crate) use self::grapheme::{GraphemeClusterBreak, EXTENDED_PICTOGRAPHIC, GRAPHEME_CLUSTER_BREAK};
+pub(crate) use self::joining_type::JOINING_TYPE;
+pub(crate) use self::math::MATH;
+#[cfg(feature = "segmentation")]
+pub(crate) use self::line_break::LINE_BREAK;
+#[cfg(feature = "alloc")]
+pub(crate) use self::normalization::{
+    CANONICAL_DECOMPOSITION, COMBINING_CLASS, COMPOSITION, NFC_QUICK_CHECK_MAYBE, NFC_QUICK_CHECK_NO,
+};
+pub(crate) use self::numeric::NUMERIC_VALUE;
+pub(crate) use self::pattern::{PATTERN_SYNTAX, PATTERN_WHITE_SPACE};
+pub(crate) use self::punctuation::{DASH, QUOTATION_MARK, TERMINAL_PUNCTUATION};
+pub(crate) use self::script::SCRIPT;
+#[cfg(feature = "alloc")]
+pub(crate) use self::script::SCRIPT_EXTENSIONS;
+#[cfg(feature = "segmentation")]
+pub(crate) use self::sentence_break::{SentenceBreak, SENTENCE_BREAK};
+pub(crate) use self::white_space::{DEFAULT_IGNORABLE, WHITE_SPACE};
+pub(crate) use self::width::WIDTH_FOLDING;
+#[cfg(feature = "segmentation")]
+pub(crate) use self::word_break::{WordBreak, WORD_BREAK};
+pub(crate) use self::xid::{ID_CONTINUE, ID_START, XID_CONTINUE, XID_START};
+
+/// Find the value of a character in a sorted table of inclusive ranges.
+#[inline]
+pub(crate) fn lookup<V: Copy + Default>(c: char, table: &[(u32, u32, V)]) -> V {
+    let c = c as u32;
+    match table.binary_search_by(|&(start, end, _)| {
+        if end < c {
+            core::cmp::Ordering::Less
+        } else if start > c {
+            core::cmp::Ordering::Greater
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }) {
+        Ok(index) => table[index].2,
+        Err(_) => V::default(),
+    }
+}
+
+/// Find the range containing a character in a sorted table of inclusive ranges.
+#[inline]
+pub(crate) fn find_range(c: char, table: &[(u32, u32)]) -> Option<(u32, u32)> {
+    let c = c as u32;
+    match table.binary_search_by(|&(start, end)| {
+        if end < c {
+            core::cmp::Ordering::Less
+        } else if start > c {
+            core::cmp::Ordering::Greater
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }) {
+        Ok(index) => Some(table[index]),
+        Err(_) => None,
+    }
+}
+
+/// Check if a character is in a sorted table of inclusive ranges.
+#[inline]
+pub(crate) fn contains(c: char, table: &[(u32, u32)]) -> bool {
+    find_range(c, table).is_some()
+}
+
+/// Find the mapping of a character in a sorted table of code point pairs.
+#[inline]
+pub(crate) fn lookup_map(c: char, table: &[(u32, u32)]) -> Option<char> {
+    let c = c as u32;
+    match table.binary_search_by_key(&c, |&(from, _)| from) {
+        // The tables only contain valid scalar values.
+        Ok(index) => core::char::from_u32(table[index].1),
+        Err(_) => None,
+    }
+}
+
+/// Find the string a character maps to in a sorted table.
+#[inline]
+pub(crate) fn lookup_str(c: char, table: &[(u32, &'static str)]) -> Option<&'static str> {
+    let c = c as u32;
+    match table.binary_search_by_key(&c, |&(from, _)| from) {
+        Ok(index) => Some(table[index].1),
+        Err(_) => None,
+    }
+}
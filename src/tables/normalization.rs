@@ -0,0 +1,3516 @@
+// Generated by `scripts/unicode.pl` from the Unicode 14.0.0
+// character database. Do not edit by hand.
+
+pub(crate) const CANONICAL_DECOMPOSITION: &[(u32, &str)] = &[
+    (0x00C0, "\u{41}\u{300}"),
+    (0x00C1, "\u{41}\u{301}"),
+    (0x00C2, "\u{41}\u{302}"),
+    (0x00C3, "\u{41}\u{303}"),
+    (0x00C4, "\u{41}\u{308}"),
+    (0x00C5, "\u{41}\u{30A}"),
+    (0x00C7, "\u{43}\u{327}"),
+    (0x00C8, "\u{45}\u{300}"),
+    (0x00C9, "\u{45}\u{301}"),
+    (0x00CA, "\u{45}\u{302}"),
+    (0x00CB, "\u{45}\u{308}"),
+    (0x00CC, "\u{49}\u{300}"),
+    (0x00CD, "\u{49}\u{301}"),
+    (0x00CE, "\u{49}\u{302}"),
+    (0x00CF, "\u{49}\u{308}"),
+    (0x00D1, "\u{4E}\u{303}"),
+    (0x00D2, "\u{4F}\u{300}"),
+    (0x00D3, "\u{4F}\u{301}"),
+    (0x00D4, "\u{4F}\u{302}"),
+    (0x00D5, "\u{4F}\u{303}"),
+    (0x00D6, "\u{4F}\u{308}"),
+    (0x00D9, "\u{55}\u{300}"),
+    (0x00DA, "\u{55}\u{301}"),
+    (0x00DB, "\u{55}\u{302}"),
+    (0x00DC, "\u{55}\u{308}"),
+    (0x00DD, "\u{59}\u{301}"),
+    (0x00E0, "\u{61}\u{300}"),
+    (0x00E1, "\u{61}\u{301}"),
+    (0x00E2, "\u{61}\u{302}"),
+    (0x00E3, "\u{61}\u{303}"),
+    (0x00E4, "\u{61}\u{308}"),
+    (0x00E5, "\u{61}\u{30A}"),
+    (0x00E7, "\u{63}\u{327}"),
+    (0x00E8, "\u{65}\u{300}"),
+    (0x00E9, "\u{65}\u{301}"),
+    (0x00EA, "\u{65}\u{302}"),
+    (0x00EB, "\u{65}\u{308}"),
+    (0x00EC, "\u{69}\u{300}"),
+    (0x00ED, "\u{69}\u{301}"),
+    (0x00EE, "\u{69}\u{302}"),
+    (0x00EF, "\u{69}\u{308}"),
+    (0x00F1, "\u{6E}\u{303}"),
+    (0x00F2, "\u{6F}\u{300}"),
+    (0x00F3, "\u{6F}\u{301}"),
+    (0x00F4, "\u{6F}\u{302}"),
+    (0x00F5, "\u{6F}\u{303}"),
+    (0x00F6, "\u{6F}\u{308}"),
+    (0x00F9, "\u{75}\u{300}"),
+    (0x00FA, "\u{75}\u{301}"),
+    (0x00FB, "\u{75}\u{302}"),
+    (0x00FC, "\u{75}\u{308}"),
+    (0x00FD, "\u{79}\u{301}"),
+    (0x00FF, "\u{79}\u{308}"),
+    (0x0100, "\u{41}\u{304}"),
+    (0x0101, "\u{61}\u{304}"),
+    (0x0102, "\u{41}\u{306}"),
+    (0x0103, "\u{61}\u{306}"),
+    (0x0104, "\u{41}\u{328}"),
+    (0x0105, "\u{61}\u{328}"),
+    (0x0106, "\u{43}\u{301}"),
+    (0x0107, "\u{63}\u{301}"),
+    (0x0108, "\u{43}\u{302}"),
+    (0x0109, "\u{63}\u{302}"),
+    (0x010A, "\u{43}\u{307}"),
+    (0x010B, "\u{63}\u{307}"),
+    (0x010C, "\u{43}\u{30C}"),
+    (0x010D, "\u{63}\u{30C}"),
+    (0x010E, "\u{44}\u{30C}"),
+    (0x010F, "\u{64}\u{30C}"),
+    (0x0112, "\u{45}\u{304}"),
+    (0x0113, "\u{65}\u{304}"),
+    (0x0114, "\u{45}\u{306}"),
+    (0x0115, "\u{65}\u{306}"),
+    (0x0116, "\u{45}\u{307}"),
+    (0x0117, "\u{65}\u{307}"),
+    (0x0118, "\u{45}\u{328}"),
+    (0x0119, "\u{65}\u{328}"),
+    (0x011A, "\u{45}\u{30C}"),
+    (0x011B, "\u{65}\u{30C}"),
+    (0x011C, "\u{47}\u{302}"),
+    (0x011D, "\u{67}\u{302}"),
+    (0x011E, "\u{47}\u{306}"),
+    (0x011F, "\u{67}\u{306}"),
+    (0x0120, "\u{47}\u{307}"),
+    (0x0121, "\u{67}\u{307}"),
+    (0x0122, "\u{47}\u{327}"),
+    (0x0123, "\u{67}\u{327}"),
+    (0x0124, "\u{48}\u{302}"),
+    (0x0125, "\u{68}\u{302}"),
+    (0x0128, "\u{49}\u{303}"),
+    (0x0129, "\u{69}\u{303}"),
+    (0x012A, "\u{49}\u{304}"),
+    (0x012B, "\u{69}\u{304}"),
+    (0x012C, "\u{49}\u{306}"),
+    (0x012D, "\u{69}\u{306}"),
+    (0x012E, "\u{49}\u{328}"),
+    (0x012F, "\u{69}\u{328}"),
+    (0x0130, "\u{49}\u{307}"),
+    (0x0134, "\u{4A}\u{302}"),
+    (0x0135, "\u{6A}\u{302}"),
+    (0x0136, "\u{4B}\u{327}"),
+    (0x0137, "\u{6B}\u{327}"),
+    (0x0139, "\u{4C}\u{301}"),
+    (0x013A, "\u{6C}\u{301}"),
+    (0x013B, "\u{4C}\u{327}"),
+    (0x013C, "\u{6C}\u{327}"),
+    (0x013D, "\u{4C}\u{30C}"),
+    (0x013E, "\u{6C}\u{30C}"),
+    (0x0143, "\u{4E}\u{301}"),
+    (0x0144, "\u{6E}\u{301}"),
+    (0x0145, "\u{4E}\u{327}"),
+    (0x0146, "\u{6E}\u{327}"),
+    (0x0147, "\u{4E}\u{30C}"),
+    (0x0148, "\u{6E}\u{30C}"),
+    (0x014C, "\u{4F}\u{304}"),
+    (0x014D, "\u{6F}\u{304}"),
+    (0x014E, "\u{4F}\u{306}"),
+    (0x014F, "\u{6F}\u{306}"),
+    (0x0150, "\u{4F}\u{30B}"),
+    (0x0151, "\u{6F}\u{30B}"),
+    (0x0154, "\u{52}\u{301}"),
+    (0x0155, "\u{72}\u{301}"),
+    (0x0156, "\u{52}\u{327}"),
+    (0x0157, "\u{72}\u{327}"),
+    (0x0158, "\u{52}\u{30C}"),
+    (0x0159, "\u{72}\u{30C}"),
+    (0x015A, "\u{53}\u{301}"),
+    (0x015B, "\u{73}\u{301}"),
+    (0x015C, "\u{53}\u{302}"),
+    (0x015D, "\u{73}\u{302}"),
+    (0x015E, "\u{53}\u{327}"),
+    (0x015F, "\u{73}\u{327}"),
+    (0x0160, "\u{53}\u{30C}"),
+    (0x0161, "\u{73}\u{30C}"),
+    (0x0162, "\u{54}\u{327}"),
+    (0x0163, "\u{74}\u{327}"),
+    (0x0164, "\u{54}\u{30C}"),
+    (0x0165, "\u{74}\u{30C}"),
+    (0x0168, "\u{55}\u{303}"),
+    (0x0169, "\u{75}\u{303}"),
+    (0x016A, "\u{55}\u{304}"),
+    (0x016B, "\u{75}\u{304}"),
+    (0x016C, "\u{55}\u{306}"),
+    (0x016D, "\u{75}\u{306}"),
+    (0x016E, "\u{55}\u{30A}"),
+    (0x016F, "\u{75}\u{30A}"),
+    (0x0170, "\u{55}\u{30B}"),
+    (0x0171, "\u{75}\u{30B}"),
+    (0x0172, "\u{55}\u{328}"),
+    (0x0173, "\u{75}\u{328}"),
+    (0x0174, "\u{57}\u{302}"),
+    (0x0175, "\u{77}\u{302}"),
+    (0x0176, "\u{59}\u{302}"),
+    (0x0177, "\u{79}\u{302}"),
+    (0x0178, "\u{59}\u{308}"),
+    (0x0179, "\u{5A}\u{301}"),
+    (0x017A, "\u{7A}\u{301}"),
+    (0x017B, "\u{5A}\u{307}"),
+    (0x017C, "\u{7A}\u{307}"),
+    (0x017D, "\u{5A}\u{30C}"),
+    (0x017E, "\u{7A}\u{30C}"),
+    (0x01A0, "\u{4F}\u{31B}"),
+    (0x01A1, "\u{6F}\u{31B}"),
+    (0x01AF, "\u{55}\u{31B}"),
+    (0x01B0, "\u{75}\u{31B}"),
+    (0x01CD, "\u{41}\u{30C}"),
+    (0x01CE, "\u{61}\u{30C}"),
+    (0x01CF, "\u{49}\u{30C}"),
+    (0x01D0, "\u{69}\u{30C}"),
+    (0x01D1, "\u{4F}\u{30C}"),
+    (0x01D2, "\u{6F}\u{30C}"),
+    (0x01D3, "\u{55}\u{30C}"),
+    (0x01D4, "\u{75}\u{30C}"),
+    (0x01D5, "\u{55}\u{308}\u{304}"),
+    (0x01D6, "\u{75}\u{308}\u{304}"),
+    (0x01D7, "\u{55}\u{308}\u{301}"),
+    (0x01D8, "\u{75}\u{308}\u{301}"),
+    (0x01D9, "\u{55}\u{308}\u{30C}"),
+    (0x01DA, "\u{75}\u{308}\u{30C}"),
+    (0x01DB, "\u{55}\u{308}\u{300}"),
+    (0x01DC, "\u{75}\u{308}\u{300}"),
+    (0x01DE, "\u{41}\u{308}\u{304}"),
+    (0x01DF, "\u{61}\u{308}\u{304}"),
+    (0x01E0, "\u{41}\u{307}\u{304}"),
+    (0x01E1, "\u{61}\u{307}\u{304}"),
+    (0x01E2, "\u{C6}\u{304}"),
+    (0x01E3, "\u{E6}\u{304}"),
+    (0x01E6, "\u{47}\u{30C}"),
+    (0x01E7, "\u{67}\u{30C}"),
+    (0x01E8, "\u{4B}\u{30C}"),
+    (0x01E9, "\u{6B}\u{30C}"),
+    (0x01EA, "\u{4F}\u{328}"),
+    (0x01EB, "\u{6F}\u{328}"),
+    (0x01EC, "\u{4F}\u{328}\u{304}"),
+    (0x01ED, "\u{6F}\u{328}\u{304}"),
+    (0x01EE, "\u{1B7}\u{30C}"),
+    (0x01EF, "\u{292}\u{30C}"),
+    (0x01F0, "\u{6A}\u{30C}"),
+    (0x01F4, "\u{47}\u{301}"),
+    (0x01F5, "\u{67}\u{301}"),
+    (0x01F8, "\u{4E}\u{300}"),
+    (0x01F9, "\u{6E}\u{300}"),
+    (0x01FA, "\u{41}\u{30A}\u{301}"),
+    (0x01FB, "\u{61}\u{30A}\u{301}"),
+    (0x01FC, "\u{C6}\u{301}"),
+    (0x01FD, "\u{E6}\u{301}"),
+    (0x01FE, "\u{D8}\u{301}"),
+    (0x01FF, "\u{F8}\u{301}"),
+    (0x0200, "\u{41}\u{30F}"),
+    (0x0201, "\u{61}\u{30F}"),
+    (0x0202, "\u{41}\u{311}"),
+    (0x0203, "\u{61}\u{311}"),
+    (0x0204, "\u{45}\u{30F}"),
+    (0x0205, "\u{65}\u{30F}"),
+    (0x0206, "\u{45}\u{311}"),
+    (0x0207, "\u{65}\u{311}"),
+    (0x0208, "\u{49}\u{30F}"),
+    (0x0209, "\u{69}\u{30F}"),
+    (0x020A, "\u{49}\u{311}"),
+    (0x020B, "\u{69}\u{311}"),
+    (0x020C, "\u{4F}\u{30F}"),
+    (0x020D, "\u{6F}\u{30F}"),
+    (0x020E, "\u{4F}\u{311}"),
+    (0x020F, "\u{6F}\u{311}"),
+    (0x0210, "\u{52}\u{30F}"),
+    (0x0211, "\u{72}\u{30F}"),
+    (0x0212, "\u{52}\u{311}"),
+    (0x0213, "\u{72}\u{311}"),
+    (0x0214, "\u{55}\u{30F}"),
+    (0x0215, "\u{75}\u{30F}"),
+    (0x0216, "\u{55}\u{311}"),
+    (0x0217, "\u{75}\u{311}"),
+    (0x0218, "\u{53}\u{326}"),
+    (0x0219, "\u{73}\u{326}"),
+    (0x021A, "\u{54}\u{326}"),
+    (0x021B, "\u{74}\u{326}"),
+    (0x021E, "\u{48}\u{30C}"),
+    (0x021F, "\u{68}\u{30C}"),
+    (0x0226, "\u{41}\u{307}"),
+    (0x0227, "\u{61}\u{307}"),
+    (0x0228, "\u{45}\u{327}"),
+    (0x0229, "\u{65}\u{327}"),
+    (0x022A, "\u{4F}\u{308}\u{304}"),
+    (0x022B, "\u{6F}\u{308}\u{304}"),
+    (0x022C, "\u{4F}\u{303}\u{304}"),
+    (0x022D, "\u{6F}\u{303}\u{304}"),
+    (0x022E, "\u{4F}\u{307}"),
+    (0x022F, "\u{6F}\u{307}"),
+    (0x0230, "\u{4F}\u{307}\u{304}"),
+    (0x0231, "\u{6F}\u{307}\u{304}"),
+    (0x0232, "\u{59}\u{304}"),
+    (0x0233, "\u{79}\u{304}"),
+    (0x0340, "\u{300}"),
+    (0x0341, "\u{301}"),
+    (0x0343, "\u{313}"),
+    (0x0344, "\u{308}\u{301}"),
+    (0x0374, "\u{2B9}"),
+    (0x037E, "\u{3B}"),
+    (0x0385, "\u{A8}\u{301}"),
+    (0x0386, "\u{391}\u{301}"),
+    (0x0387, "\u{B7}"),
+    (0x0388, "\u{395}\u{301}"),
+    (0x0389, "\u{397}\u{301}"),
+    (0x038A, "\u{399}\u{301}"),
+    (0x038C, "\u{39F}\u{301}"),
+    (0x038E, "\u{3A5}\u{301}"),
+    (0x038F, "\u{3A9}\u{301}"),
+    (0x0390, "\u{3B9}\u{308}\u{301}"),
+    (0x03AA, "\u{399}\u{308}"),
+    (0x03AB, "\u{3A5}\u{308}"),
+    (0x03AC, "\u{3B1}\u{301}"),
+    (0x03AD, "\u{3B5}\u{301}"),
+    (0x03AE, "\u{3B7}\u{301}"),
+    (0x03AF, "\u{3B9}\u{301}"),
+    (0x03B0, "\u{3C5}\u{308}\u{301}"),
+    (0x03CA, "\u{3B9}\u{308}"),
+    (0x03CB, "\u{3C5}\u{308}"),
+    (0x03CC, "\u{3BF}\u{301}"),
+    (0x03CD, "\u{3C5}\u{301}"),
+    (0x03CE, "\u{3C9}\u{301}"),
+    (0x03D3, "\u{3D2}\u{301}"),
+    (0x03D4, "\u{3D2}\u{308}"),
+    (0x0400, "\u{415}\u{300}"),
+    (0x0401, "\u{415}\u{308}"),
+    (0x0403, "\u{413}\u{301}"),
+    (0x0407, "\u{406}\u{308}"),
+    (0x040C, "\u{41A}\u{301}"),
+    (0x040D, "\u{418}\u{300}"),
+    (0x040E, "\u{423}\u{306}"),
+    (0x0419, "\u{418}\u{306}"),
+    (0x0439, "\u{438}\u{306}"),
+    (0x0450, "\u{435}\u{300}"),
+    (0x0451, "\u{435}\u{308}"),
+    (0x0453, "\u{433}\u{301}"),
+    (0x0457, "\u{456}\u{308}"),
+    (0x045C, "\u{43A}\u{301}"),
+    (0x045D, "\u{438}\u{300}"),
+    (0x045E, "\u{443}\u{306}"),
+    (0x0476, "\u{474}\u{30F}"),
+    (0x0477, "\u{475}\u{30F}"),
+    (0x04C1, "\u{416}\u{306}"),
+    (0x04C2, "\u{436}\u{306}"),
+    (0x04D0, "\u{410}\u{306}"),
+    (0x04D1, "\u{430}\u{306}"),
+    (0x04D2, "\u{410}\u{308}"),
+    (0x04D3, "\u{430}\u{308}"),
+    (0x04D6, "\u{415}\u{306}"),
+    (0x04D7, "\u{435}\u{306}"),
+    (0x04DA, "\u{4D8}\u{308}"),
+    (0x04DB, "\u{4D9}\u{308}"),
+    (0x04DC, "\u{416}\u{308}"),
+    (0x04DD, "\u{436}\u{308}"),
+    (0x04DE, "\u{417}\u{308}"),
+    (0x04DF, "\u{437}\u{308}"),
+    (0x04E2, "\u{418}\u{304}"),
+    (0x04E3, "\u{438}\u{304}"),
+    (0x04E4, "\u{418}\u{308}"),
+    (0x04E5, "\u{438}\u{308}"),
+    (0x04E6, "\u{41E}\u{308}"),
+    (0x04E7, "\u{43E}\u{308}"),
+    (0x04EA, "\u{4E8}\u{308}"),
+    (0x04EB, "\u{4E9}\u{308}"),
+    (0x04EC, "\u{42D}\u{308}"),
+    (0x04ED, "\u{44D}\u{308}"),
+    (0x04EE, "\u{423}\u{304}"),
+    (0x04EF, "\u{443}\u{304}"),
+    (0x04F0, "\u{423}\u{308}"),
+    (0x04F1, "\u{443}\u{308}"),
+    (0x04F2, "\u{423}\u{30B}"),
+    (0x04F3, "\u{443}\u{30B}"),
+    (0x04F4, "\u{427}\u{308}"),
+    (0x04F5, "\u{447}\u{308}"),
+    (0x04F8, "\u{42B}\u{308}"),
+    (0x04F9, "\u{44B}\u{308}"),
+    (0x0622, "\u{627}\u{653}"),
+    (0x0623, "\u{627}\u{654}"),
+    (0x0624, "\u{648}\u{654}"),
+    (0x0625, "\u{627}\u{655}"),
+    (0x0626, "\u{64A}\u{654}"),
+    (0x06C0, "\u{6D5}\u{654}"),
+    (0x06C2, "\u{6C1}\u{654}"),
+    (0x06D3, "\u{6D2}\u{654}"),
+    (0x0929, "\u{928}\u{93C}"),
+    (0x0931, "\u{930}\u{93C}"),
+    (0x0934, "\u{933}\u{93C}"),
+    (0x0958, "\u{915}\u{93C}"),
+    (0x0959, "\u{916}\u{93C}"),
+    (0x095A, "\u{917}\u{93C}"),
+    (0x095B, "\u{91C}\u{93C}"),
+    (0x095C, "\u{921}\u{93C}"),
+    (0x095D, "\u{922}\u{93C}"),
+    (0x095E, "\u{92B}\u{93C}"),
+    (0x095F, "\u{92F}\u{93C}"),
+    (0x09CB, "\u{9C7}\u{9BE}"),
+    (0x09CC, "\u{9C7}\u{9D7}"),
+    (0x09DC, "\u{9A1}\u{9BC}"),
+    (0x09DD, "\u{9A2}\u{9BC}"),
+    (0x09DF, "\u{9AF}\u{9BC}"),
+    (0x0A33, "\u{A32}\u{A3C}"),
+    (0x0A36, "\u{A38}\u{A3C}"),
+    (0x0A59, "\u{A16}\u{A3C}"),
+    (0x0A5A, "\u{A17}\u{A3C}"),
+    (0x0A5B, "\u{A1C}\u{A3C}"),
+    (0x0A5E, "\u{A2B}\u{A3C}"),
+    (0x0B48, "\u{B47}\u{B56}"),
+    (0x0B4B, "\u{B47}\u{B3E}"),
+    (0x0B4C, "\u{B47}\u{B57}"),
+    (0x0B5C, "\u{B21}\u{B3C}"),
+    (0x0B5D, "\u{B22}\u{B3C}"),
+    (0x0B94, "\u{B92}\u{BD7}"),
+    (0x0BCA, "\u{BC6}\u{BBE}"),
+    (0x0BCB, "\u{BC7}\u{BBE}"),
+    (0x0BCC, "\u{BC6}\u{BD7}"),
+    (0x0C48, "\u{C46}\u{C56}"),
+    (0x0CC0, "\u{CBF}\u{CD5}"),
+    (0x0CC7, "\u{CC6}\u{CD5}"),
+    (0x0CC8, "\u{CC6}\u{CD6}"),
+    (0x0CCA, "\u{CC6}\u{CC2}"),
+    (0x0CCB, "\u{CC6}\u{CC2}\u{CD5}"),
+    (0x0D4A, "\u{D46}\u{D3E}"),
+    (0x0D4B, "\u{D47}\u{D3E}"),
+    (0x0D4C, "\u{D46}\u{D57}"),
+    (0x0DDA, "\u{DD9}\u{DCA}"),
+    (0x0DDC, "\u{DD9}\u{DCF}"),
+    (0x0DDD, "\u{DD9}\u{DCF}\u{DCA}"),
+    (0x0DDE, "\u{DD9}\u{DDF}"),
+    (0x0F43, "\u{F42}\u{FB7}"),
+    (0x0F4D, "\u{F4C}\u{FB7}"),
+    (0x0F52, "\u{F51}\u{FB7}"),
+    (0x0F57, "\u{F56}\u{FB7}"),
+    (0x0F5C, "\u{F5B}\u{FB7}"),
+    (0x0F69, "\u{F40}\u{FB5}"),
+    (0x0F73, "\u{F71}\u{F72}"),
+    (0x0F75, "\u{F71}\u{F74}"),
+    (0x0F76, "\u{FB2}\u{F80}"),
+    (0x0F78, "\u{FB3}\u{F80}"),
+    (0x0F81, "\u{F71}\u{F80}"),
+    (0x0F93, "\u{F92}\u{FB7}"),
+    (0x0F9D, "\u{F9C}\u{FB7}"),
+    (0x0FA2, "\u{FA1}\u{FB7}"),
+    (0x0FA7, "\u{FA6}\u{FB7}"),
+    (0x0FAC, "\u{FAB}\u{FB7}"),
+    (0x0FB9, "\u{F90}\u{FB5}"),
+    (0x1026, "\u{1025}\u{102E}"),
+    (0x1B06, "\u{1B05}\u{1B35}"),
+    (0x1B08, "\u{1B07}\u{1B35}"),
+    (0x1B0A, "\u{1B09}\u{1B35}"),
+    (0x1B0C, "\u{1B0B}\u{1B35}"),
+    (0x1B0E, "\u{1B0D}\u{1B35}"),
+    (0x1B12, "\u{1B11}\u{1B35}"),
+    (0x1B3B, "\u{1B3A}\u{1B35}"),
+    (0x1B3D, "\u{1B3C}\u{1B35}"),
+    (0x1B40, "\u{1B3E}\u{1B35}"),
+    (0x1B41, "\u{1B3F}\u{1B35}"),
+    (0x1B43, "\u{1B42}\u{1B35}"),
+    (0x1E00, "\u{41}\u{325}"),
+    (0x1E01, "\u{61}\u{325}"),
+    (0x1E02, "\u{42}\u{307}"),
+    (0x1E03, "\u{62}\u{307}"),
+    (0x1E04, "\u{42}\u{323}"),
+    (0x1E05, "\u{62}\u{323}"),
+    (0x1E06, "\u{42}\u{331}"),
+    (0x1E07, "\u{62}\u{331}"),
+    (0x1E08, "\u{43}\u{327}\u{301}"),
+    (0x1E09, "\u{63}\u{327}\u{301}"),
+    (0x1E0A, "\u{44}\u{307}"),
+    (0x1E0B, "\u{64}\u{307}"),
+    (0x1E0C, "\u{44}\u{323}"),
+    (0x1E0D, "\u{64}\u{323}"),
+    (0x1E0E, "\u{44}\u{331}"),
+    (0x1E0F, "\u{64}\u{331}"),
+    (0x1E10, "\u{44}\u{327}"),
+    (0x1E11, "\u{64}\u{327}"),
+    (0x1E12, "\u{44}\u{32D}"),
+    (0x1E13, "\u{64}\u{32D}"),
+    (0x1E14, "\u{45}\u{304}\u{300}"),
+    (0x1E15, "\u{65}\u{304}\u{300}"),
+    (0x1E16, "\u{45}\u{304}\u{301}"),
+    (0x1E17, "\u{65}\u{304}\u{301}"),
+    (0x1E18, "\u{45}\u{32D}"),
+    (0x1E19, "\u{65}\u{32D}"),
+    (0x1E1A, "\u{45}\u{330}"),
+    (0x1E1B, "\u{65}\u{330}"),
+    (0x1E1C, "\u{45}\u{327}\u{306}"),
+    (0x1E1D, "\u{65}\u{327}\u{306}"),
+    (0x1E1E, "\u{46}\u{307}"),
+    (0x1E1F, "\u{66}\u{307}"),
+    (0x1E20, "\u{47}\u{304}"),
+    (0x1E21, "\u{67}\u{304}"),
+    (0x1E22, "\u{48}\u{307}"),
+    (0x1E23, "\u{68}\u{307}"),
+    (0x1E24, "\u{48}\u{323}"),
+    (0x1E25, "\u{68}\u{323}"),
+    (0x1E26, "\u{48}\u{308}"),
+    (0x1E27, "\u{68}\u{308}"),
+    (0x1E28, "\u{48}\u{327}"),
+    (0x1E29, "\u{68}\u{327}"),
+    (0x1E2A, "\u{48}\u{32E}"),
+    (0x1E2B, "\u{68}\u{32E}"),
+    (0x1E2C, "\u{49}\u{330}"),
+    (0x1E2D, "\u{69}\u{330}"),
+    (0x1E2E, "\u{49}\u{308}\u{301}"),
+    (0x1E2F, "\u{69}\u{308}\u{301}"),
+    (0x1E30, "\u{4B}\u{301}"),
+    (0x1E31, "\u{6B}\u{301}"),
+    (0x1E32, "\u{4B}\u{323}"),
+    (0x1E33, "\u{6B}\u{323}"),
+    (0x1E34, "\u{4B}\u{331}"),
+    (0x1E35, "\u{6B}\u{331}"),
+    (0x1E36, "\u{4C}\u{323}"),
+    (0x1E37, "\u{6C}\u{323}"),
+    (0x1E38, "\u{4C}\u{323}\u{304}"),
+    (0x1E39, "\u{6C}\u{323}\u{304}"),
+    (0x1E3A, "\u{4C}\u{331}"),
+    (0x1E3B, "\u{6C}\u{331}"),
+    (0x1E3C, "\u{4C}\u{32D}"),
+    (0x1E3D, "\u{6C}\u{32D}"),
+    (0x1E3E, "\u{4D}\u{301}"),
+    (0x1E3F, "\u{6D}\u{301}"),
+    (0x1E40, "\u{4D}\u{307}"),
+    (0x1E41, "\u{6D}\u{307}"),
+    (0x1E42, "\u{4D}\u{323}"),
+    (0x1E43, "\u{6D}\u{323}"),
+    (0x1E44, "\u{4E}\u{307}"),
+    (0x1E45, "\u{6E}\u{307}"),
+    (0x1E46, "\u{4E}\u{323}"),
+    (0x1E47, "\u{6E}\u{323}"),
+    (0x1E48, "\u{4E}\u{331}"),
+    (0x1E49, "\u{6E}\u{331}"),
+    (0x1E4A, "\u{4E}\u{32D}"),
+    (0x1E4B, "\u{6E}\u{32D}"),
+    (0x1E4C, "\u{4F}\u{303}\u{301}"),
+    (0x1E4D, "\u{6F}\u{303}\u{301}"),
+    (0x1E4E, "\u{4F}\u{303}\u{308}"),
+    (0x1E4F, "\u{6F}\u{303}\u{308}"),
+    (0x1E50, "\u{4F}\u{304}\u{300}"),
+    (0x1E51, "\u{6F}\u{304}\u{300}"),
+    (0x1E52, "\u{4F}\u{304}\u{301}"),
+    (0x1E53, "\u{6F}\u{304}\u{301}"),
+    (0x1E54, "\u{50}\u{301}"),
+    (0x1E55, "\u{70}\u{301}"),
+    (0x1E56, "\u{50}\u{307}"),
+    (0x1E57, "\u{70}\u{307}"),
+    (0x1E58, "\u{52}\u{307}"),
+    (0x1E59, "\u{72}\u{307}"),
+    (0x1E5A, "\u{52}\u{323}"),
+    (0x1E5B, "\u{72}\u{323}"),
+    (0x1E5C, "\u{52}\u{323}\u{304}"),
+    (0x1E5D, "\u{72}\u{323}\u{304}"),
+    (0x1E5E, "\u{52}\u{331}"),
+    (0x1E5F, "\u{72}\u{331}"),
+    (0x1E60, "\u{53}\u{307}"),
+    (0x1E61, "\u{73}\u{307}"),
+    (0x1E62, "\u{53}\u{323}"),
+    (0x1E63, "\u{73}\u{323}"),
+    (0x1E64, "\u{53}\u{301}\u{307}"),
+    (0x1E65, "\u{73}\u{301}\u{307}"),
+    (0x1E66, "\u{53}\u{30C}\u{307}"),
+    (0x1E67, "\u{73}\u{30C}\u{307}"),
+    (0x1E68, "\u{53}\u{323}\u{307}"),
+    (0x1E69, "\u{73}\u{323}\u{307}"),
+    (0x1E6A, "\u{54}\u{307}"),
+    (0x1E6B, "\u{74}\u{307}"),
+    (0x1E6C, "\u{54}\u{323}"),
+    (0x1E6D, "\u{74}\u{323}"),
+    (0x1E6E, "\u{54}\u{331}"),
+    (0x1E6F, "\u{74}\u{331}"),
+    (0x1E70, "\u{54}\u{32D}"),
+    (0x1E71, "\u{74}\u{32D}"),
+    (0x1E72, "\u{55}\u{324}"),
+    (0x1E73, "\u{75}\u{324}"),
+    (0x1E74, "\u{55}\u{330}"),
+    (0x1E75, "\u{75}\u{330}"),
+    (0x1E76, "\u{55}\u{32D}"),
+    (0x1E77, "\u{75}\u{32D}"),
+    (0x1E78, "\u{55}\u{303}\u{301}"),
+    (0x1E79, "\u{75}\u{303}\u{301}"),
+    (0x1E7A, "\u{55}\u{304}\u{308}"),
+    (0x1E7B, "\u{75}\u{304}\u{308}"),
+    (0x1E7C, "\u{56}\u{303}"),
+    (0x1E7D, "\u{76}\u{303}"),
+    (0x1E7E, "\u{56}\u{323}"),
+    (0x1E7F, "\u{76}\u{323}"),
+    (0x1E80, "\u{57}\u{300}"),
+    (0x1E81, "\u{77}\u{300}"),
+    (0x1E82, "\u{57}\u{301}"),
+    (0x1E83, "\u{77}\u{301}"),
+    (0x1E84, "\u{57}\u{308}"),
+    (0x1E85, "\u{77}\u{308}"),
+    (0x1E86, "\u{57}\u{307}"),
+    (0x1E87, "\u{77}\u{307}"),
+    (0x1E88, "\u{57}\u{323}"),
+    (0x1E89, "\u{77}\u{323}"),
+    (0x1E8A, "\u{58}\u{307}"),
+    (0x1E8B, "\u{78}\u{307}"),
+    (0x1E8C, "\u{58}\u{308}"),
+    (0x1E8D, "\u{78}\u{308}"),
+    (0x1E8E, "\u{59}\u{307}"),
+    (0x1E8F, "\u{79}\u{307}"),
+    (0x1E90, "\u{5A}\u{302}"),
+    (0x1E91, "\u{7A}\u{302}"),
+    (0x1E92, "\u{5A}\u{323}"),
+    (0x1E93, "\u{7A}\u{323}"),
+    (0x1E94, "\u{5A}\u{331}"),
+    (0x1E95, "\u{7A}\u{331}"),
+    (0x1E96, "\u{68}\u{331}"),
+    (0x1E97, "\u{74}\u{308}"),
+    (0x1E98, "\u{77}\u{30A}"),
+    (0x1E99, "\u{79}\u{30A}"),
+    (0x1E9B, "\u{17F}\u{307}"),
+    (0x1EA0, "\u{41}\u{323}"),
+    (0x1EA1, "\u{61}\u{323}"),
+    (0x1EA2, "\u{41}\u{309}"),
+    (0x1EA3, "\u{61}\u{309}"),
+    (0x1EA4, "\u{41}\u{302}\u{301}"),
+    (0x1EA5, "\u{61}\u{302}\u{301}"),
+    (0x1EA6, "\u{41}\u{302}\u{300}"),
+    (0x1EA7, "\u{61}\u{302}\u{300}"),
+    (0x1EA8, "\u{41}\u{302}\u{309}"),
+    (0x1EA9, "\u{61}\u{302}\u{309}"),
+    (0x1EAA, "\u{41}\u{302}\u{303}"),
+    (0x1EAB, "\u{61}\u{302}\u{303}"),
+    (0x1EAC, "\u{41}\u{323}\u{302}"),
+    (0x1EAD, "\u{61}\u{323}\u{302}"),
+    (0x1EAE, "\u{41}\u{306}\u{301}"),
+    (0x1EAF, "\u{61}\u{306}\u{301}"),
+    (0x1EB0, "\u{41}\u{306}\u{300}"),
+    (0x1EB1, "\u{61}\u{306}\u{300}"),
+    (0x1EB2, "\u{41}\u{306}\u{309}"),
+    (0x1EB3, "\u{61}\u{306}\u{309}"),
+    (0x1EB4, "\u{41}\u{306}\u{303}"),
+    (0x1EB5, "\u{61}\u{306}\u{303}"),
+    (0x1EB6, "\u{41}\u{323}\u{306}"),
+    (0x1EB7, "\u{61}\u{323}\u{306}"),
+    (0x1EB8, "\u{45}\u{323}"),
+    (0x1EB9, "\u{65}\u{323}"),
+    (0x1EBA, "\u{45}\u{309}"),
+    (0x1EBB, "\u{65}\u{309}"),
+    (0x1EBC, "\u{45}\u{303}"),
+    (0x1EBD, "\u{65}\u{303}"),
+    (0x1EBE, "\u{45}\u{302}\u{301}"),
+    (0x1EBF, "\u{65}\u{302}\u{301}"),
+    (0x1EC0, "\u{45}\u{302}\u{300}"),
+    (0x1EC1, "\u{65}\u{302}\u{300}"),
+    (0x1EC2, "\u{45}\u{302}\u{309}"),
+    (0x1EC3, "\u{65}\u{302}\u{309}"),
+    (0x1EC4, "\u{45}\u{302}\u{303}"),
+    (0x1EC5, "\u{65}\u{302}\u{303}"),
+    (0x1EC6, "\u{45}\u{323}\u{302}"),
+    (0x1EC7, "\u{65}\u{323}\u{302}"),
+    (0x1EC8, "\u{49}\u{309}"),
+    (0x1EC9, "\u{69}\u{309}"),
+    (0x1ECA, "\u{49}\u{323}"),
+    (0x1ECB, "\u{69}\u{323}"),
+    (0x1ECC, "\u{4F}\u{323}"),
+    (0x1ECD, "\u{6F}\u{323}"),
+    (0x1ECE, "\u{4F}\u{309}"),
+    (0x1ECF, "\u{6F}\u{309}"),
+    (0x1ED0, "\u{4F}\u{302}\u{301}"),
+    (0x1ED1, "\u{6F}\u{302}\u{301}"),
+    (0x1ED2, "\u{4F}\u{302}\u{300}"),
+    (0x1ED3, "\u{6F}\u{302}\u{300}"),
+    (0x1ED4, "\u{4F}\u{302}\u{309}"),
+    (0x1ED5, "\u{6F}\u{302}\u{309}"),
+    (0x1ED6, "\u{4F}\u{302}\u{303}"),
+    (0x1ED7, "\u{6F}\u{302}\u{303}"),
+    (0x1ED8, "\u{4F}\u{323}\u{302}"),
+    (0x1ED9, "\u{6F}\u{323}\u{302}"),
+    (0x1EDA, "\u{4F}\u{31B}\u{301}"),
+    (0x1EDB, "\u{6F}\u{31B}\u{301}"),
+    (0x1EDC, "\u{4F}\u{31B}\u{300}"),
+    (0x1EDD, "\u{6F}\u{31B}\u{300}"),
+    (0x1EDE, "\u{4F}\u{31B}\u{309}"),
+    (0x1EDF, "\u{6F}\u{31B}\u{309}"),
+    (0x1EE0, "\u{4F}\u{31B}\u{303}"),
+    (0x1EE1, "\u{6F}\u{31B}\u{303}"),
+    (0x1EE2, "\u{4F}\u{31B}\u{323}"),
+    (0x1EE3, "\u{6F}\u{31B}\u{323}"),
+    (0x1EE4, "\u{55}\u{323}"),
+    (0x1EE5, "\u{75}\u{323}"),
+    (0x1EE6, "\u{55}\u{309}"),
+    (0x1EE7, "\u{75}\u{309}"),
+    (0x1EE8, "\u{55}\u{31B}\u{301}"),
+    (0x1EE9, "\u{75}\u{31B}\u{301}"),
+    (0x1EEA, "\u{55}\u{31B}\u{300}"),
+    (0x1EEB, "\u{75}\u{31B}\u{300}"),
+    (0x1EEC, "\u{55}\u{31B}\u{309}"),
+    (0x1EED, "\u{75}\u{31B}\u{309}"),
+    (0x1EEE, "\u{55}\u{31B}\u{303}"),
+    (0x1EEF, "\u{75}\u{31B}\u{303}"),
+    (0x1EF0, "\u{55}\u{31B}\u{323}"),
+    (0x1EF1, "\u{75}\u{31B}\u{323}"),
+    (0x1EF2, "\u{59}\u{300}"),
+    (0x1EF3, "\u{79}\u{300}"),
+    (0x1EF4, "\u{59}\u{323}"),
+    (0x1EF5, "\u{79}\u{323}"),
+    (0x1EF6, "\u{59}\u{309}"),
+    (0x1EF7, "\u{79}\u{309}"),
+    (0x1EF8, "\u{59}\u{303}"),
+    (0x1EF9, "\u{79}\u{303}"),
+    (0x1F00, "\u{3B1}\u{313}"),
+    (0x1F01, "\u{3B1}\u{314}"),
+    (0x1F02, "\u{3B1}\u{313}\u{300}"),
+    (0x1F03, "\u{3B1}\u{314}\u{300}"),
+    (0x1F04, "\u{3B1}\u{313}\u{301}"),
+    (0x1F05, "\u{3B1}\u{314}\u{301}"),
+    (0x1F06, "\u{3B1}\u{313}\u{342}"),
+    (0x1F07, "\u{3B1}\u{314}\u{342}"),
+    (0x1F08, "\u{391}\u{313}"),
+    (0x1F09, "\u{391}\u{314}"),
+    (0x1F0A, "\u{391}\u{313}\u{300}"),
+    (0x1F0B, "\u{391}\u{314}\u{300}"),
+    (0x1F0C, "\u{391}\u{313}\u{301}"),
+    (0x1F0D, "\u{391}\u{314}\u{301}"),
+    (0x1F0E, "\u{391}\u{313}\u{342}"),
+    (0x1F0F, "\u{391}\u{314}\u{342}"),
+    (0x1F10, "\u{3B5}\u{313}"),
+    (0x1F11, "\u{3B5}\u{314}"),
+    (0x1F12, "\u{3B5}\u{313}\u{300}"),
+    (0x1F13, "\u{3B5}\u{314}\u{300}"),
+    (0x1F14, "\u{3B5}\u{313}\u{301}"),
+    (0x1F15, "\u{3B5}\u{314}\u{301}"),
+    (0x1F18, "\u{395}\u{313}"),
+    (0x1F19, "\u{395}\u{314}"),
+    (0x1F1A, "\u{395}\u{313}\u{300}"),
+    (0x1F1B, "\u{395}\u{314}\u{300}"),
+    (0x1F1C, "\u{395}\u{313}\u{301}"),
+    (0x1F1D, "\u{395}\u{314}\u{301}"),
+    (0x1F20, "\u{3B7}\u{313}"),
+    (0x1F21, "\u{3B7}\u{314}"),
+    (0x1F22, "\u{3B7}\u{313}\u{300}"),
+    (0x1F23, "\u{3B7}\u{314}\u{300}"),
+    (0x1F24, "\u{3B7}\u{313}\u{301}"),
+    (0x1F25, "\u{3B7}\u{314}\u{301}"),
+    (0x1F26, "\u{3B7}\u{313}\u{342}"),
+    (0x1F27, "\u{3B7}\u{314}\u{342}"),
+    (0x1F28, "\u{397}\u{313}"),
+    (0x1F29, "\u{397}\u{314}"),
+    (0x1F2A, "\u{397}\u{313}\u{300}"),
+    (0x1F2B, "\u{397}\u{314}\u{300}"),
+    (0x1F2C, "\u{397}\u{313}\u{301}"),
+    (0x1F2D, "\u{397}\u{314}\u{301}"),
+    (0x1F2E, "\u{397}\u{313}\u{342}"),
+    (0x1F2F, "\u{397}\u{314}\u{342}"),
+    (0x1F30, "\u{3B9}\u{313}"),
+    (0x1F31, "\u{3B9}\u{314}"),
+    (0x1F32, "\u{3B9}\u{313}\u{300}"),
+    (0x1F33, "\u{3B9}\u{314}\u{300}"),
+    (0x1F34, "\u{3B9}\u{313}\u{301}"),
+    (0x1F35, "\u{3B9}\u{314}\u{301}"),
+    (0x1F36, "\u{3B9}\u{313}\u{342}"),
+    (0x1F37, "\u{3B9}\u{314}\u{342}"),
+    (0x1F38, "\u{399}\u{313}"),
+    (0x1F39, "\u{399}\u{314}"),
+    (0x1F3A, "\u{399}\u{313}\u{300}"),
+    (0x1F3B, "\u{399}\u{314}\u{300}"),
+    (0x1F3C, "\u{399}\u{313}\u{301}"),
+    (0x1F3D, "\u{399}\u{314}\u{301}"),
+    (0x1F3E, "\u{399}\u{313}\u{342}"),
+    (0x1F3F, "\u{399}\u{314}\u{342}"),
+    (0x1F40, "\u{3BF}\u{313}"),
+    (0x1F41, "\u{3BF}\u{314}"),
+    (0x1F42, "\u{3BF}\u{313}\u{300}"),
+    (0x1F43, "\u{3BF}\u{314}\u{300}"),
+    (0x1F44, "\u{3BF}\u{313}\u{301}"),
+    (0x1F45, "\u{3BF}\u{314}\u{301}"),
+    (0x1F48, "\u{39F}\u{313}"),
+    (0x1F49, "\u{39F}\u{314}"),
+    (0x1F4A, "\u{39F}\u{313}\u{300}"),
+    (0x1F4B, "\u{39F}\u{314}\u{300}"),
+    (0x1F4C, "\u{39F}\u{313}\u{301}"),
+    (0x1F4D, "\u{39F}\u{314}\u{301}"),
+    (0x1F50, "\u{3C5}\u{313}"),
+    (0x1F51, "\u{3C5}\u{314}"),
+    (0x1F52, "\u{3C5}\u{313}\u{300}"),
+    (0x1F53, "\u{3C5}\u{314}\u{300}"),
+    (0x1F54, "\u{3C5}\u{313}\u{301}"),
+    (0x1F55, "\u{3C5}\u{314}\u{301}"),
+    (0x1F56, "\u{3C5}\u{313}\u{342}"),
+    (0x1F57, "\u{3C5}\u{314}\u{342}"),
+    (0x1F59, "\u{3A5}\u{314}"),
+    (0x1F5B, "\u{3A5}\u{314}\u{300}"),
+    (0x1F5D, "\u{3A5}\u{314}\u{301}"),
+    (0x1F5F, "\u{3A5}\u{314}\u{342}"),
+    (0x1F60, "\u{3C9}\u{313}"),
+    (0x1F61, "\u{3C9}\u{314}"),
+    (0x1F62, "\u{3C9}\u{313}\u{300}"),
+    (0x1F63, "\u{3C9}\u{314}\u{300}"),
+    (0x1F64, "\u{3C9}\u{313}\u{301}"),
+    (0x1F65, "\u{3C9}\u{314}\u{301}"),
+    (0x1F66, "\u{3C9}\u{313}\u{342}"),
+    (0x1F67, "\u{3C9}\u{314}\u{342}"),
+    (0x1F68, "\u{3A9}\u{313}"),
+    (0x1F69, "\u{3A9}\u{314}"),
+    (0x1F6A, "\u{3A9}\u{313}\u{300}"),
+    (0x1F6B, "\u{3A9}\u{314}\u{300}"),
+    (0x1F6C, "\u{3A9}\u{313}\u{301}"),
+    (0x1F6D, "\u{3A9}\u{314}\u{301}"),
+    (0x1F6E, "\u{3A9}\u{313}\u{342}"),
+    (0x1F6F, "\u{3A9}\u{314}\u{342}"),
+    (0x1F70, "\u{3B1}\u{300}"),
+    (0x1F71, "\u{3B1}\u{301}"),
+    (0x1F72, "\u{3B5}\u{300}"),
+    (0x1F73, "\u{3B5}\u{301}"),
+    (0x1F74, "\u{3B7}\u{300}"),
+    (0x1F75, "\u{3B7}\u{301}"),
+    (0x1F76, "\u{3B9}\u{300}"),
+    (0x1F77, "\u{3B9}\u{301}"),
+    (0x1F78, "\u{3BF}\u{300}"),
+    (0x1F79, "\u{3BF}\u{301}"),
+    (0x1F7A, "\u{3C5}\u{300}"),
+    (0x1F7B, "\u{3C5}\u{301}"),
+    (0x1F7C, "\u{3C9}\u{300}"),
+    (0x1F7D, "\u{3C9}\u{301}"),
+    (0x1F80, "\u{3B1}\u{313}\u{345}"),
+    (0x1F81, "\u{3B1}\u{314}\u{345}"),
+    (0x1F82, "\u{3B1}\u{313}\u{300}\u{345}"),
+    (0x1F83, "\u{3B1}\u{314}\u{300}\u{345}"),
+    (0x1F84, "\u{3B1}\u{313}\u{301}\u{345}"),
+    (0x1F85, "\u{3B1}\u{314}\u{301}\u{345}"),
+    (0x1F86, "\u{3B1}\u{313}\u{342}\u{345}"),
+    (0x1F87, "\u{3B1}\u{314}\u{342}\u{345}"),
+    (0x1F88, "\u{391}\u{313}\u{345}"),
+    (0x1F89, "\u{391}\u{314}\u{345}"),
+    (0x1F8A, "\u{391}\u{313}\u{300}\u{345}"),
+    (0x1F8B, "\u{391}\u{314}\u{300}\u{345}"),
+    (0x1F8C, "\u{391}\u{313}\u{301}\u{345}"),
+    (0x1F8D, "\u{391}\u{314}\u{301}\u{345}"),
+    (0x1F8E, "\u{391}\u{313}\u{342}\u{345}"),
+    (0x1F8F, "\u{391}\u{314}\u{342}\u{345}"),
+    (0x1F90, "\u{3B7}\u{313}\u{345}"),
+    (0x1F91, "\u{3B7}\u{314}\u{345}"),
+    (0x1F92, "\u{3B7}\u{313}\u{300}\u{345}"),
+    (0x1F93, "\u{3B7}\u{314}\u{300}\u{345}"),
+    (0x1F94, "\u{3B7}\u{313}\u{301}\u{345}"),
+    (0x1F95, "\u{3B7}\u{314}\u{301}\u{345}"),
+    (0x1F96, "\u{3B7}\u{313}\u{342}\u{345}"),
+    (0x1F97, "\u{3B7}\u{314}\u{342}\u{345}"),
+    (0x1F98, "\u{397}\u{313}\u{345}"),
+    (0x1F99, "\u{397}\u{314}\u{345}"),
+    (0x1F9A, "\u{397}\u{313}\u{300}\u{345}"),
+    (0x1F9B, "\u{397}\u{314}\u{300}\u{345}"),
+    (0x1F9C, "\u{397}\u{313}\u{301}\u{345}"),
+    (0x1F9D, "\u{397}\u{314}\u{301}\u{345}"),
+    (0x1F9E, "\u{397}\u{313}\u{342}\u{345}"),
+    (0x1F9F, "\u{397}\u{314}\u{342}\u{345}"),
+    (0x1FA0, "\u{3C9}\u{313}\u{345}"),
+    (0x1FA1, "\u{3C9}\u{314}\u{345}"),
+    (0x1FA2, "\u{3C9}\u{313}\u{300}\u{345}"),
+    (0x1FA3, "\u{3C9}\u{314}\u{300}\u{345}"),
+    (0x1FA4, "\u{3C9}\u{313}\u{301}\u{345}"),
+    (0x1FA5, "\u{3C9}\u{314}\u{301}\u{345}"),
+    (0x1FA6, "\u{3C9}\u{313}\u{342}\u{345}"),
+    (0x1FA7, "\u{3C9}\u{314}\u{342}\u{345}"),
+    (0x1FA8, "\u{3A9}\u{313}\u{345}"),
+    (0x1FA9, "\u{3A9}\u{314}\u{345}"),
+    (0x1FAA, "\u{3A9}\u{313}\u{300}\u{345}"),
+    (0x1FAB, "\u{3A9}\u{314}\u{300}\u{345}"),
+    (0x1FAC, "\u{3A9}\u{313}\u{301}\u{345}"),
+    (0x1FAD, "\u{3A9}\u{314}\u{301}\u{345}"),
+    (0x1FAE, "\u{3A9}\u{313}\u{342}\u{345}"),
+    (0x1FAF, "\u{3A9}\u{314}\u{342}\u{345}"),
+    (0x1FB0, "\u{3B1}\u{306}"),
+    (0x1FB1, "\u{3B1}\u{304}"),
+    (0x1FB2, "\u{3B1}\u{300}\u{345}"),
+    (0x1FB3, "\u{3B1}\u{345}"),
+    (0x1FB4, "\u{3B1}\u{301}\u{345}"),
+    (0x1FB6, "\u{3B1}\u{342}"),
+    (0x1FB7, "\u{3B1}\u{342}\u{345}"),
+    (0x1FB8, "\u{391}\u{306}"),
+    (0x1FB9, "\u{391}\u{304}"),
+    (0x1FBA, "\u{391}\u{300}"),
+    (0x1FBB, "\u{391}\u{301}"),
+    (0x1FBC, "\u{391}\u{345}"),
+    (0x1FBE, "\u{3B9}"),
+    (0x1FC1, "\u{A8}\u{342}"),
+    (0x1FC2, "\u{3B7}\u{300}\u{345}"),
+    (0x1FC3, "\u{3B7}\u{345}"),
+    (0x1FC4, "\u{3B7}\u{301}\u{345}"),
+    (0x1FC6, "\u{3B7}\u{342}"),
+    (0x1FC7, "\u{3B7}\u{342}\u{345}"),
+    (0x1FC8, "\u{395}\u{300}"),
+    (0x1FC9, "\u{395}\u{301}"),
+    (0x1FCA, "\u{397}\u{300}"),
+    (0x1FCB, "\u{397}\u{301}"),
+    (0x1FCC, "\u{397}\u{345}"),
+    (0x1FCD, "\u{1FBF}\u{300}"),
+    (0x1FCE, "\u{1FBF}\u{301}"),
+    (0x1FCF, "\u{1FBF}\u{342}"),
+    (0x1FD0, "\u{3B9}\u{306}"),
+    (0x1FD1, "\u{3B9}\u{304}"),
+    (0x1FD2, "\u{3B9}\u{308}\u{300}"),
+    (0x1FD3, "\u{3B9}\u{308}\u{301}"),
+    (0x1FD6, "\u{3B9}\u{342}"),
+    (0x1FD7, "\u{3B9}\u{308}\u{342}"),
+    (0x1FD8, "\u{399}\u{306}"),
+    (0x1FD9, "\u{399}\u{304}"),
+    (0x1FDA, "\u{399}\u{300}"),
+    (0x1FDB, "\u{399}\u{301}"),
+    (0x1FDD, "\u{1FFE}\u{300}"),
+    (0x1FDE, "\u{1FFE}\u{301}"),
+    (0x1FDF, "\u{1FFE}\u{342}"),
+    (0x1FE0, "\u{3C5}\u{306}"),
+    (0x1FE1, "\u{3C5}\u{304}"),
+    (0x1FE2, "\u{3C5}\u{308}\u{300}"),
+    (0x1FE3, "\u{3C5}\u{308}\u{301}"),
+    (0x1FE4, "\u{3C1}\u{313}"),
+    (0x1FE5, "\u{3C1}\u{314}"),
+    (0x1FE6, "\u{3C5}\u{342}"),
+    (0x1FE7, "\u{3C5}\u{308}\u{342}"),
+    (0x1FE8, "\u{3A5}\u{306}"),
+    (0x1FE9, "\u{3A5}\u{304}"),
+    (0x1FEA, "\u{3A5}\u{300}"),
+    (0x1FEB, "\u{3A5}\u{301}"),
+    (0x1FEC, "\u{3A1}\u{314}"),
+    (0x1FED, "\u{A8}\u{300}"),
+    (0x1FEE, "\u{A8}\u{301}"),
+    (0x1FEF, "\u{60}"),
+    (0x1FF2, "\u{3C9}\u{300}\u{345}"),
+    (0x1FF3, "\u{3C9}\u{345}"),
+    (0x1FF4, "\u{3C9}\u{301}\u{345}"),
+    (0x1FF6, "\u{3C9}\u{342}"),
+    (0x1FF7, "\u{3C9}\u{342}\u{345}"),
+    (0x1FF8, "\u{39F}\u{300}"),
+    (0x1FF9, "\u{39F}\u{301}"),
+    (0x1FFA, "\u{3A9}\u{300}"),
+    (0x1FFB, "\u{3A9}\u{301}"),
+    (0x1FFC, "\u{3A9}\u{345}"),
+    (0x1FFD, "\u{B4}"),
+    (0x2000, "\u{2002}"),
+    (0x2001, "\u{2003}"),
+    (0x2126, "\u{3A9}"),
+    (0x212A, "\u{4B}"),
+    (0x212B, "\u{41}\u{30A}"),
+    (0x219A, "\u{2190}\u{338}"),
+    (0x219B, "\u{2192}\u{338}"),
+    (0x21AE, "\u{2194}\u{338}"),
+    (0x21CD, "\u{21D0}\u{338}"),
+    (0x21CE, "\u{21D4}\u{338}"),
+    (0x21CF, "\u{21D2}\u{338}"),
+    (0x2204, "\u{2203}\u{338}"),
+    (0x2209, "\u{2208}\u{338}"),
+    (0x220C, "\u{220B}\u{338}"),
+    (0x2224, "\u{2223}\u{338}"),
+    (0x2226, "\u{2225}\u{338}"),
+    (0x2241, "\u{223C}\u{338}"),
+    (0x2244, "\u{2243}\u{338}"),
+    (0x2247, "\u{2245}\u{338}"),
+    (0x2249, "\u{2248}\u{338}"),
+    (0x2260, "\u{3D}\u{338}"),
+    (0x2262, "\u{2261}\u{338}"),
+    (0x226D, "\u{224D}\u{338}"),
+    (0x226E, "\u{3C}\u{338}"),
+    (0x226F, "\u{3E}\u{338}"),
+    (0x2270, "\u{2264}\u{338}"),
+    (0x2271, "\u{2265}\u{338}"),
+    (0x2274, "\u{2272}\u{338}"),
+    (0x2275, "\u{2273}\u{338}"),
+    (0x2278, "\u{2276}\u{338}"),
+    (0x2279, "\u{2277}\u{338}"),
+    (0x2280, "\u{227A}\u{338}"),
+    (0x2281, "\u{227B}\u{338}"),
+    (0x2284, "\u{2282}\u{338}"),
+    (0x2285, "\u{2283}\u{338}"),
+    (0x2288, "\u{2286}\u{338}"),
+    (0x2289, "\u{2287}\u{338}"),
+    (0x22AC, "\u{22A2}\u{338}"),
+    (0x22AD, "\u{22A8}\u{338}"),
+    (0x22AE, "\u{22A9}\u{338}"),
+    (0x22AF, "\u{22AB}\u{338}"),
+    (0x22E0, "\u{227C}\u{338}"),
+    (0x22E1, "\u{227D}\u{338}"),
+    (0x22E2, "\u{2291}\u{338}"),
+    (0x22E3, "\u{2292}\u{338}"),
+    (0x22EA, "\u{22B2}\u{338}"),
+    (0x22EB, "\u{22B3}\u{338}"),
+    (0x22EC, "\u{22B4}\u{338}"),
+    (0x22ED, "\u{22B5}\u{338}"),
+    (0x2329, "\u{3008}"),
+    (0x232A, "\u{3009}"),
+    (0x2ADC, "\u{2ADD}\u{338}"),
+    (0x304C, "\u{304B}\u{3099}"),
+    (0x304E, "\u{304D}\u{3099}"),
+    (0x3050, "\u{304F}\u{3099}"),
+    (0x3052, "\u{3051}\u{3099}"),
+    (0x3054, "\u{3053}\u{3099}"),
+    (0x3056, "\u{3055}\u{3099}"),
+    (0x3058, "\u{3057}\u{3099}"),
+    (0x305A, "\u{3059}\u{3099}"),
+    (0x305C, "\u{305B}\u{3099}"),
+    (0x305E, "\u{305D}\u{3099}"),
+    (0x3060, "\u{305F}\u{3099}"),
+    (0x3062, "\u{3061}\u{3099}"),
+    (0x3065, "\u{3064}\u{3099}"),
+    (0x3067, "\u{3066}\u{3099}"),
+    (0x3069, "\u{3068}\u{3099}"),
+    (0x3070, "\u{306F}\u{3099}"),
+    (0x3071, "\u{306F}\u{309A}"),
+    (0x3073, "\u{3072}\u{3099}"),
+    (0x3074, "\u{3072}\u{309A}"),
+    (0x3076, "\u{3075}\u{3099}"),
+    (0x3077, "\u{3075}\u{309A}"),
+    (0x3079, "\u{3078}\u{3099}"),
+    (0x307A, "\u{3078}\u{309A}"),
+    (0x307C, "\u{307B}\u{3099}"),
+    (0x307D, "\u{307B}\u{309A}"),
+    (0x3094, "\u{3046}\u{3099}"),
+    (0x309E, "\u{309D}\u{3099}"),
+    (0x30AC, "\u{30AB}\u{3099}"),
+    (0x30AE, "\u{30AD}\u{3099}"),
+    (0x30B0, "\u{30AF}\u{3099}"),
+    (0x30B2, "\u{30B1}\u{3099}"),
+    (0x30B4, "\u{30B3}\u{3099}"),
+    (0x30B6, "\u{30B5}\u{3099}"),
+    (0x30B8, "\u{30B7}\u{3099}"),
+    (0x30BA, "\u{30B9}\u{3099}"),
+    (0x30BC, "\u{30BB}\u{3099}"),
+    (0x30BE, "\u{30BD}\u{3099}"),
+    (0x30C0, "\u{30BF}\u{3099}"),
+    (0x30C2, "\u{30C1}\u{3099}"),
+    (0x30C5, "\u{30C4}\u{3099}"),
+    (0x30C7, "\u{30C6}\u{3099}"),
+    (0x30C9, "\u{30C8}\u{3099}"),
+    (0x30D0, "\u{30CF}\u{3099}"),
+    (0x30D1, "\u{30CF}\u{309A}"),
+    (0x30D3, "\u{30D2}\u{3099}"),
+    (0x30D4, "\u{30D2}\u{309A}"),
+    (0x30D6, "\u{30D5}\u{3099}"),
+    (0x30D7, "\u{30D5}\u{309A}"),
+    (0x30D9, "\u{30D8}\u{3099}"),
+    (0x30DA, "\u{30D8}\u{309A}"),
+    (0x30DC, "\u{30DB}\u{3099}"),
+    (0x30DD, "\u{30DB}\u{309A}"),
+    (0x30F4, "\u{30A6}\u{3099}"),
+    (0x30F7, "\u{30EF}\u{3099}"),
+    (0x30F8, "\u{30F0}\u{3099}"),
+    (0x30F9, "\u{30F1}\u{3099}"),
+    (0x30FA, "\u{30F2}\u{3099}"),
+    (0x30FE, "\u{30FD}\u{3099}"),
+    (0xF900, "\u{8C48}"),
+    (0xF901, "\u{66F4}"),
+    (0xF902, "\u{8ECA}"),
+    (0xF903, "\u{8CC8}"),
+    (0xF904, "\u{6ED1}"),
+    (0xF905, "\u{4E32}"),
+    (0xF906, "\u{53E5}"),
+    (0xF907, "\u{9F9C}"),
+    (0xF908, "\u{9F9C}"),
+    (0xF909, "\u{5951}"),
+    (0xF90A, "\u{91D1}"),
+    (0xF90B, "\u{5587}"),
+    (0xF90C, "\u{5948}"),
+    (0xF90D, "\u{61F6}"),
+    (0xF90E, "\u{7669}"),
+    (0xF90F, "\u{7F85}"),
+    (0xF910, "\u{863F}"),
+    (0xF911, "\u{87BA}"),
+    (0xF912, "\u{88F8}"),
+    (0xF913, "\u{908F}"),
+    (0xF914, "\u{6A02}"),
+    (0xF915, "\u{6D1B}"),
+    (0xF916, "\u{70D9}"),
+    (0xF917, "\u{73DE}"),
+    (0xF918, "\u{843D}"),
+    (0xF919, "\u{916A}"),
+    (0xF91A, "\u{99F1}"),
+    (0xF91B, "\u{4E82}"),
+    (0xF91C, "\u{5375}"),
+    (0xF91D, "\u{6B04}"),
+    (0xF91E, "\u{721B}"),
+    (0xF91F, "\u{862D}"),
+    (0xF920, "\u{9E1E}"),
+    (0xF921, "\u{5D50}"),
+    (0xF922, "\u{6FEB}"),
+    (0xF923, "\u{85CD}"),
+    (0xF924, "\u{8964}"),
+    (0xF925, "\u{62C9}"),
+    (0xF926, "\u{81D8}"),
+    (0xF927, "\u{881F}"),
+    (0xF928, "\u{5ECA}"),
+    (0xF929, "\u{6717}"),
+    (0xF92A, "\u{6D6A}"),
+    (0xF92B, "\u{72FC}"),
+    (0xF92C, "\u{90CE}"),
+    (0xF92D, "\u{4F86}"),
+    (0xF92E, "\u{51B7}"),
+    (0xF92F, "\u{52DE}"),
+    (0xF930, "\u{64C4}"),
+    (0xF931, "\u{6AD3}"),
+    (0xF932, "\u{7210}"),
+    (0xF933, "\u{76E7}"),
+    (0xF934, "\u{8001}"),
+    (0xF935, "\u{8606}"),
+    (0xF936, "\u{865C}"),
+    (0xF937, "\u{8DEF}"),
+    (0xF938, "\u{9732}"),
+    (0xF939, "\u{9B6F}"),
+    (0xF93A, "\u{9DFA}"),
+    (0xF93B, "\u{788C}"),
+    (0xF93C, "\u{797F}"),
+    (0xF93D, "\u{7DA0}"),
+    (0xF93E, "\u{83C9}"),
+    (0xF93F, "\u{9304}"),
+    (0xF940, "\u{9E7F}"),
+    (0xF941, "\u{8AD6}"),
+    (0xF942, "\u{58DF}"),
+    (0xF943, "\u{5F04}"),
+    (0xF944, "\u{7C60}"),
+    (0xF945, "\u{807E}"),
+    (0xF946, "\u{7262}"),
+    (0xF947, "\u{78CA}"),
+    (0xF948, "\u{8CC2}"),
+    (0xF949, "\u{96F7}"),
+    (0xF94A, "\u{58D8}"),
+    (0xF94B, "\u{5C62}"),
+    (0xF94C, "\u{6A13}"),
+    (0xF94D, "\u{6DDA}"),
+    (0xF94E, "\u{6F0F}"),
+    (0xF94F, "\u{7D2F}"),
+    (0xF950, "\u{7E37}"),
+    (0xF951, "\u{964B}"),
+    (0xF952, "\u{52D2}"),
+    (0xF953, "\u{808B}"),
+    (0xF954, "\u{51DC}"),
+    (0xF955, "\u{51CC}"),
+    (0xF956, "\u{7A1C}"),
+    (0xF957, "\u{7DBE}"),
+    (0xF958, "\u{83F1}"),
+    (0xF959, "\u{9675}"),
+    (0xF95A, "\u{8B80}"),
+    (0xF95B, "\u{62CF}"),
+    (0xF95C, "\u{6A02}"),
+    (0xF95D, "\u{8AFE}"),
+    (0xF95E, "\u{4E39}"),
+    (0xF95F, "\u{5BE7}"),
+    (0xF960, "\u{6012}"),
+    (0xF961, "\u{7387}"),
+    (0xF962, "\u{7570}"),
+    (0xF963, "\u{5317}"),
+    (0xF964, "\u{78FB}"),
+    (0xF965, "\u{4FBF}"),
+    (0xF966, "\u{5FA9}"),
+    (0xF967, "\u{4E0D}"),
+    (0xF968, "\u{6CCC}"),
+    (0xF969, "\u{6578}"),
+    (0xF96A, "\u{7D22}"),
+    (0xF96B, "\u{53C3}"),
+    (0xF96C, "\u{585E}"),
+    (0xF96D, "\u{7701}"),
+    (0xF96E, "\u{8449}"),
+    (0xF96F, "\u{8AAA}"),
+    (0xF970, "\u{6BBA}"),
+    (0xF971, "\u{8FB0}"),
+    (0xF972, "\u{6C88}"),
+    (0xF973, "\u{62FE}"),
+    (0xF974, "\u{82E5}"),
+    (0xF975, "\u{63A0}"),
+    (0xF976, "\u{7565}"),
+    (0xF977, "\u{4EAE}"),
+    (0xF978, "\u{5169}"),
+    (0xF979, "\u{51C9}"),
+    (0xF97A, "\u{6881}"),
+    (0xF97B, "\u{7CE7}"),
+    (0xF97C, "\u{826F}"),
+    (0xF97D, "\u{8AD2}"),
+    (0xF97E, "\u{91CF}"),
+    (0xF97F, "\u{52F5}"),
+    (0xF980, "\u{5442}"),
+    (0xF981, "\u{5973}"),
+    (0xF982, "\u{5EEC}"),
+    (0xF983, "\u{65C5}"),
+    (0xF984, "\u{6FFE}"),
+    (0xF985, "\u{792A}"),
+    (0xF986, "\u{95AD}"),
+    (0xF987, "\u{9A6A}"),
+    (0xF988, "\u{9E97}"),
+    (0xF989, "\u{9ECE}"),
+    (0xF98A, "\u{529B}"),
+    (0xF98B, "\u{66C6}"),
+    (0xF98C, "\u{6B77}"),
+    (0xF98D, "\u{8F62}"),
+    (0xF98E, "\u{5E74}"),
+    (0xF98F, "\u{6190}"),
+    (0xF990, "\u{6200}"),
+    (0xF991, "\u{649A}"),
+    (0xF992, "\u{6F23}"),
+    (0xF993, "\u{7149}"),
+    (0xF994, "\u{7489}"),
+    (0xF995, "\u{79CA}"),
+    (0xF996, "\u{7DF4}"),
+    (0xF997, "\u{806F}"),
+    (0xF998, "\u{8F26}"),
+    (0xF999, "\u{84EE}"),
+    (0xF99A, "\u{9023}"),
+    (0xF99B, "\u{934A}"),
+    (0xF99C, "\u{5217}"),
+    (0xF99D, "\u{52A3}"),
+    (0xF99E, "\u{54BD}"),
+    (0xF99F, "\u{70C8}"),
+    (0xF9A0, "\u{88C2}"),
+    (0xF9A1, "\u{8AAA}"),
+    (0xF9A2, "\u{5EC9}"),
+    (0xF9A3, "\u{5FF5}"),
+    (0xF9A4, "\u{637B}"),
+    (0xF9A5, "\u{6BAE}"),
+    (0xF9A6, "\u{7C3E}"),
+    (0xF9A7, "\u{7375}"),
+    (0xF9A8, "\u{4EE4}"),
+    (0xF9A9, "\u{56F9}"),
+    (0xF9AA, "\u{5BE7}"),
+    (0xF9AB, "\u{5DBA}"),
+    (0xF9AC, "\u{601C}"),
+    (0xF9AD, "\u{73B2}"),
+    (0xF9AE, "\u{7469}"),
+    (0xF9AF, "\u{7F9A}"),
+    (0xF9B0, "\u{8046}"),
+    (0xF9B1, "\u{9234}"),
+    (0xF9B2, "\u{96F6}"),
+    (0xF9B3, "\u{9748}"),
+    (0xF9B4, "\u{9818}"),
+    (0xF9B5, "\u{4F8B}"),
+    (0xF9B6, "\u{79AE}"),
+    (0xF9B7, "\u{91B4}"),
+    (0xF9B8, "\u{96B8}"),
+    (0xF9B9, "\u{60E1}"),
+    (0xF9BA, "\u{4E86}"),
+    (0xF9BB, "\u{50DA}"),
+    (0xF9BC, "\u{5BEE}"),
+    (0xF9BD, "\u{5C3F}"),
+    (0xF9BE, "\u{6599}"),
+    (0xF9BF, "\u{6A02}"),
+    (0xF9C0, "\u{71CE}"),
+    (0xF9C1, "\u{7642}"),
+    (0xF9C2, "\u{84FC}"),
+    (0xF9C3, "\u{907C}"),
+    (0xF9C4, "\u{9F8D}"),
+    (0xF9C5, "\u{6688}"),
+    (0xF9C6, "\u{962E}"),
+    (0xF9C7, "\u{5289}"),
+    (0xF9C8, "\u{677B}"),
+    (0xF9C9, "\u{67F3}"),
+    (0xF9CA, "\u{6D41}"),
+    (0xF9CB, "\u{6E9C}"),
+    (0xF9CC, "\u{7409}"),
+    (0xF9CD, "\u{7559}"),
+    (0xF9CE, "\u{786B}"),
+    (0xF9CF, "\u{7D10}"),
+    (0xF9D0, "\u{985E}"),
+    (0xF9D1, "\u{516D}"),
+    (0xF9D2, "\u{622E}"),
+    (0xF9D3, "\u{9678}"),
+    (0xF9D4, "\u{502B}"),
+    (0xF9D5, "\u{5D19}"),
+    (0xF9D6, "\u{6DEA}"),
+    (0xF9D7, "\u{8F2A}"),
+    (0xF9D8, "\u{5F8B}"),
+    (0xF9D9, "\u{6144}"),
+    (0xF9DA, "\u{6817}"),
+    (0xF9DB, "\u{7387}"),
+    (0xF9DC, "\u{9686}"),
+    (0xF9DD, "\u{5229}"),
+    (0xF9DE, "\u{540F}"),
+    (0xF9DF, "\u{5C65}"),
+    (0xF9E0, "\u{6613}"),
+    (0xF9E1, "\u{674E}"),
+    (0xF9E2, "\u{68A8}"),
+    (0xF9E3, "\u{6CE5}"),
+    (0xF9E4, "\u{7406}"),
+    (0xF9E5, "\u{75E2}"),
+    (0xF9E6, "\u{7F79}"),
+    (0xF9E7, "\u{88CF}"),
+    (0xF9E8, "\u{88E1}"),
+    (0xF9E9, "\u{91CC}"),
+    (0xF9EA, "\u{96E2}"),
+    (0xF9EB, "\u{533F}"),
+    (0xF9EC, "\u{6EBA}"),
+    (0xF9ED, "\u{541D}"),
+    (0xF9EE, "\u{71D0}"),
+    (0xF9EF, "\u{7498}"),
+    (0xF9F0, "\u{85FA}"),
+    (0xF9F1, "\u{96A3}"),
+    (0xF9F2, "\u{9C57}"),
+    (0xF9F3, "\u{9E9F}"),
+    (0xF9F4, "\u{6797}"),
+    (0xF9F5, "\u{6DCB}"),
+    (0xF9F6, "\u{81E8}"),
+    (0xF9F7, "\u{7ACB}"),
+    (0xF9F8, "\u{7B20}"),
+    (0xF9F9, "\u{7C92}"),
+    (0xF9FA, "\u{72C0}"),
+    (0xF9FB, "\u{7099}"),
+    (0xF9FC, "\u{8B58}"),
+    (0xF9FD, "\u{4EC0}"),
+    (0xF9FE, "\u{8336}"),
+    (0xF9FF, "\u{523A}"),
+    (0xFA00, "\u{5207}"),
+    (0xFA01, "\u{5EA6}"),
+    (0xFA02, "\u{62D3}"),
+    (0xFA03, "\u{7CD6}"),
+    (0xFA04, "\u{5B85}"),
+    (0xFA05, "\u{6D1E}"),
+    (0xFA06, "\u{66B4}"),
+    (0xFA07, "\u{8F3B}"),
+    (0xFA08, "\u{884C}"),
+    (0xFA09, "\u{964D}"),
+    (0xFA0A, "\u{898B}"),
+    (0xFA0B, "\u{5ED3}"),
+    (0xFA0C, "\u{5140}"),
+    (0xFA0D, "\u{55C0}"),
+    (0xFA10, "\u{585A}"),
+    (0xFA12, "\u{6674}"),
+    (0xFA15, "\u{51DE}"),
+    (0xFA16, "\u{732A}"),
+    (0xFA17, "\u{76CA}"),
+    (0xFA18, "\u{793C}"),
+    (0xFA19, "\u{795E}"),
+    (0xFA1A, "\u{7965}"),
+    (0xFA1B, "\u{798F}"),
+    (0xFA1C, "\u{9756}"),
+    (0xFA1D, "\u{7CBE}"),
+    (0xFA1E, "\u{7FBD}"),
+    (0xFA20, "\u{8612}"),
+    (0xFA22, "\u{8AF8}"),
+    (0xFA25, "\u{9038}"),
+    (0xFA26, "\u{90FD}"),
+    (0xFA2A, "\u{98EF}"),
+    (0xFA2B, "\u{98FC}"),
+    (0xFA2C, "\u{9928}"),
+    (0xFA2D, "\u{9DB4}"),
+    (0xFA2E, "\u{90DE}"),
+    (0xFA2F, "\u{96B7}"),
+    (0xFA30, "\u{4FAE}"),
+    (0xFA31, "\u{50E7}"),
+    (0xFA32, "\u{514D}"),
+    (0xFA33, "\u{52C9}"),
+    (0xFA34, "\u{52E4}"),
+    (0xFA35, "\u{5351}"),
+    (0xFA36, "\u{559D}"),
+    (0xFA37, "\u{5606}"),
+    (0xFA38, "\u{5668}"),
+    (0xFA39, "\u{5840}"),
+    (0xFA3A, "\u{58A8}"),
+    (0xFA3B, "\u{5C64}"),
+    (0xFA3C, "\u{5C6E}"),
+    (0xFA3D, "\u{6094}"),
+    (0xFA3E, "\u{6168}"),
+    (0xFA3F, "\u{618E}"),
+    (0xFA40, "\u{61F2}"),
+    (0xFA41, "\u{654F}"),
+    (0xFA42, "\u{65E2}"),
+    (0xFA43, "\u{6691}"),
+    (0xFA44, "\u{6885}"),
+    (0xFA45, "\u{6D77}"),
+    (0xFA46, "\u{6E1A}"),
+    (0xFA47, "\u{6F22}"),
+    (0xFA48, "\u{716E}"),
+    (0xFA49, "\u{722B}"),
+    (0xFA4A, "\u{7422}"),
+    (0xFA4B, "\u{7891}"),
+    (0xFA4C, "\u{793E}"),
+    (0xFA4D, "\u{7949}"),
+    (0xFA4E, "\u{7948}"),
+    (0xFA4F, "\u{7950}"),
+    (0xFA50, "\u{7956}"),
+    (0xFA51, "\u{795D}"),
+    (0xFA52, "\u{798D}"),
+    (0xFA53, "\u{798E}"),
+    (0xFA54, "\u{7A40}"),
+    (0xFA55, "\u{7A81}"),
+    (0xFA56, "\u{7BC0}"),
+    (0xFA57, "\u{7DF4}"),
+    (0xFA58, "\u{7E09}"),
+    (0xFA59, "\u{7E41}"),
+    (0xFA5A, "\u{7F72}"),
+    (0xFA5B, "\u{8005}"),
+    (0xFA5C, "\u{81ED}"),
+    (0xFA5D, "\u{8279}"),
+    (0xFA5E, "\u{8279}"),
+    (0xFA5F, "\u{8457}"),
+    (0xFA60, "\u{8910}"),
+    (0xFA61, "\u{8996}"),
+    (0xFA62, "\u{8B01}"),
+    (0xFA63, "\u{8B39}"),
+    (0xFA64, "\u{8CD3}"),
+    (0xFA65, "\u{8D08}"),
+    (0xFA66, "\u{8FB6}"),
+    (0xFA67, "\u{9038}"),
+    (0xFA68, "\u{96E3}"),
+    (0xFA69, "\u{97FF}"),
+    (0xFA6A, "\u{983B}"),
+    (0xFA6B, "\u{6075}"),
+    (0xFA6C, "\u{242EE}"),
+    (0xFA6D, "\u{8218}"),
+    (0xFA70, "\u{4E26}"),
+    (0xFA71, "\u{51B5}"),
+    (0xFA72, "\u{5168}"),
+    (0xFA73, "\u{4F80}"),
+    (0xFA74, "\u{5145}"),
+    (0xFA75, "\u{5180}"),
+    (0xFA76, "\u{52C7}"),
+    (0xFA77, "\u{52FA}"),
+    (0xFA78, "\u{559D}"),
+    (0xFA79, "\u{5555}"),
+    (0xFA7A, "\u{5599}"),
+    (0xFA7B, "\u{55E2}"),
+    (0xFA7C, "\u{585A}"),
+    (0xFA7D, "\u{58B3}"),
+    (0xFA7E, "\u{5944}"),
+    (0xFA7F, "\u{5954}"),
+    (0xFA80, "\u{5A62}"),
+    (0xFA81, "\u{5B28}"),
+    (0xFA82, "\u{5ED2}"),
+    (0xFA83, "\u{5ED9}"),
+    (0xFA84, "\u{5F69}"),
+    (0xFA85, "\u{5FAD}"),
+    (0xFA86, "\u{60D8}"),
+    (0xFA87, "\u{614E}"),
+    (0xFA88, "\u{6108}"),
+    (0xFA89, "\u{618E}"),
+    (0xFA8A, "\u{6160}"),
+    (0xFA8B, "\u{61F2}"),
+    (0xFA8C, "\u{6234}"),
+    (0xFA8D, "\u{63C4}"),
+    (0xFA8E, "\u{641C}"),
+    (0xFA8F, "\u{6452}"),
+    (0xFA90, "\u{6556}"),
+    (0xFA91, "\u{6674}"),
+    (0xFA92, "\u{6717}"),
+    (0xFA93, "\u{671B}"),
+    (0xFA94, "\u{6756}"),
+    (0xFA95, "\u{6B79}"),
+    (0xFA96, "\u{6BBA}"),
+    (0xFA97, "\u{6D41}"),
+    (0xFA98, "\u{6EDB}"),
+    (0xFA99, "\u{6ECB}"),
+    (0xFA9A, "\u{6F22}"),
+    (0xFA9B, "\u{701E}"),
+    (0xFA9C, "\u{716E}"),
+    (0xFA9D, "\u{77A7}"),
+    (0xFA9E, "\u{7235}"),
+    (0xFA9F, "\u{72AF}"),
+    (0xFAA0, "\u{732A}"),
+    (0xFAA1, "\u{7471}"),
+    (0xFAA2, "\u{7506}"),
+    (0xFAA3, "\u{753B}"),
+    (0xFAA4, "\u{761D}"),
+    (0xFAA5, "\u{761F}"),
+    (0xFAA6, "\u{76CA}"),
+    (0xFAA7, "\u{76DB}"),
+    (0xFAA8, "\u{76F4}"),
+    (0xFAA9, "\u{774A}"),
+    (0xFAAA, "\u{7740}"),
+    (0xFAAB, "\u{78CC}"),
+    (0xFAAC, "\u{7AB1}"),
+    (0xFAAD, "\u{7BC0}"),
+    (0xFAAE, "\u{7C7B}"),
+    (0xFAAF, "\u{7D5B}"),
+    (0xFAB0, "\u{7DF4}"),
+    (0xFAB1, "\u{7F3E}"),
+    (0xFAB2, "\u{8005}"),
+    (0xFAB3, "\u{8352}"),
+    (0xFAB4, "\u{83EF}"),
+    (0xFAB5, "\u{8779}"),
+    (0xFAB6, "\u{8941}"),
+    (0xFAB7, "\u{8986}"),
+    (0xFAB8, "\u{8996}"),
+    (0xFAB9, "\u{8ABF}"),
+    (0xFABA, "\u{8AF8}"),
+    (0xFABB, "\u{8ACB}"),
+    (0xFABC, "\u{8B01}"),
+    (0xFABD, "\u{8AFE}"),
+    (0xFABE, "\u{8AED}"),
+    (0xFABF, "\u{8B39}"),
+    (0xFAC0, "\u{8B8A}"),
+    (0xFAC1, "\u{8D08}"),
+    (0xFAC2, "\u{8F38}"),
+    (0xFAC3, "\u{9072}"),
+    (0xFAC4, "\u{9199}"),
+    (0xFAC5, "\u{9276}"),
+    (0xFAC6, "\u{967C}"),
+    (0xFAC7, "\u{96E3}"),
+    (0xFAC8, "\u{9756}"),
+    (0xFAC9, "\u{97DB}"),
+    (0xFACA, "\u{97FF}"),
+    (0xFACB, "\u{980B}"),
+    (0xFACC, "\u{983B}"),
+    (0xFACD, "\u{9B12}"),
+    (0xFACE, "\u{9F9C}"),
+    (0xFACF, "\u{2284A}"),
+    (0xFAD0, "\u{22844}"),
+    (0xFAD1, "\u{233D5}"),
+    (0xFAD2, "\u{3B9D}"),
+    (0xFAD3, "\u{4018}"),
+    (0xFAD4, "\u{4039}"),
+    (0xFAD5, "\u{25249}"),
+    (0xFAD6, "\u{25CD0}"),
+    (0xFAD7, "\u{27ED3}"),
+    (0xFAD8, "\u{9F43}"),
+    (0xFAD9, "\u{9F8E}"),
+    (0xFB1D, "\u{5D9}\u{5B4}"),
+    (0xFB1F, "\u{5F2}\u{5B7}"),
+    (0xFB2A, "\u{5E9}\u{5C1}"),
+    (0xFB2B, "\u{5E9}\u{5C2}"),
+    (0xFB2C, "\u{5E9}\u{5BC}\u{5C1}"),
+    (0xFB2D, "\u{5E9}\u{5BC}\u{5C2}"),
+    (0xFB2E, "\u{5D0}\u{5B7}"),
+    (0xFB2F, "\u{5D0}\u{5B8}"),
+    (0xFB30, "\u{5D0}\u{5BC}"),
+    (0xFB31, "\u{5D1}\u{5BC}"),
+    (0xFB32, "\u{5D2}\u{5BC}"),
+    (0xFB33, "\u{5D3}\u{5BC}"),
+    (0xFB34, "\u{5D4}\u{5BC}"),
+    (0xFB35, "\u{5D5}\u{5BC}"),
+    (0xFB36, "\u{5D6}\u{5BC}"),
+    (0xFB38, "\u{5D8}\u{5BC}"),
+    (0xFB39, "\u{5D9}\u{5BC}"),
+    (0xFB3A, "\u{5DA}\u{5BC}"),
+    (0xFB3B, "\u{5DB}\u{5BC}"),
+    (0xFB3C, "\u{5DC}\u{5BC}"),
+    (0xFB3E, "\u{5DE}\u{5BC}"),
+    (0xFB40, "\u{5E0}\u{5BC}"),
+    (0xFB41, "\u{5E1}\u{5BC}"),
+    (0xFB43, "\u{5E3}\u{5BC}"),
+    (0xFB44, "\u{5E4}\u{5BC}"),
+    (0xFB46, "\u{5E6}\u{5BC}"),
+    (0xFB47, "\u{5E7}\u{5BC}"),
+    (0xFB48, "\u{5E8}\u{5BC}"),
+    (0xFB49, "\u{5E9}\u{5BC}"),
+    (0xFB4A, "\u{5EA}\u{5BC}"),
+    (0xFB4B, "\u{5D5}\u{5B9}"),
+    (0xFB4C, "\u{5D1}\u{5BF}"),
+    (0xFB4D, "\u{5DB}\u{5BF}"),
+    (0xFB4E, "\u{5E4}\u{5BF}"),
+    (0x1109A, "\u{11099}\u{110BA}"),
+    (0x1109C, "\u{1109B}\u{110BA}"),
+    (0x110AB, "\u{110A5}\u{110BA}"),
+    (0x1112E, "\u{11131}\u{11127}"),
+    (0x1112F, "\u{11132}\u{11127}"),
+    (0x1134B, "\u{11347}\u{1133E}"),
+    (0x1134C, "\u{11347}\u{11357}"),
+    (0x114BB, "\u{114B9}\u{114BA}"),
+    (0x114BC, "\u{114B9}\u{114B0}"),
+    (0x114BE, "\u{114B9}\u{114BD}"),
+    (0x115BA, "\u{115B8}\u{115AF}"),
+    (0x115BB, "\u{115B9}\u{115AF}"),
+    (0x11938, "\u{11935}\u{11930}"),
+    (0x1D15E, "\u{1D157}\u{1D165}"),
+    (0x1D15F, "\u{1D158}\u{1D165}"),
+    (0x1D160, "\u{1D158}\u{1D165}\u{1D16E}"),
+    (0x1D161, "\u{1D158}\u{1D165}\u{1D16F}"),
+    (0x1D162, "\u{1D158}\u{1D165}\u{1D170}"),
+    (0x1D163, "\u{1D158}\u{1D165}\u{1D171}"),
+    (0x1D164, "\u{1D158}\u{1D165}\u{1D172}"),
+    (0x1D1BB, "\u{1D1B9}\u{1D165}"),
+    (0x1D1BC, "\u{1D1BA}\u{1D165}"),
+    (0x1D1BD, "\u{1D1B9}\u{1D165}\u{1D16E}"),
+    (0x1D1BE, "\u{1D1BA}\u{1D165}\u{1D16E}"),
+    (0x1D1BF, "\u{1D1B9}\u{1D165}\u{1D16F}"),
+    (0x1D1C0, "\u{1D1BA}\u{1D165}\u{1D16F}"),
+    (0x2F800, "\u{4E3D}"),
+    (0x2F801, "\u{4E38}"),
+    (0x2F802, "\u{4E41}"),
+    (0x2F803, "\u{20122}"),
+    (0x2F804, "\u{4F60}"),
+    (0x2F805, "\u{4FAE}"),
+    (0x2F806, "\u{4FBB}"),
+    (0x2F807, "\u{5002}"),
+    (0x2F808, "\u{507A}"),
+    (0x2F809, "\u{5099}"),
+    (0x2F80A, "\u{50E7}"),
+    (0x2F80B, "\u{50CF}"),
+    (0x2F80C, "\u{349E}"),
+    (0x2F80D, "\u{2063A}"),
+    (0x2F80E, "\u{514D}"),
+    (0x2F80F, "\u{5154}"),
+    (0x2F810, "\u{5164}"),
+    (0x2F811, "\u{5177}"),
+    (0x2F812, "\u{2051C}"),
+    (0x2F813, "\u{34B9}"),
+    (0x2F814, "\u{5167}"),
+    (0x2F815, "\u{518D}"),
+    (0x2F816, "\u{2054B}"),
+    (0x2F817, "\u{5197}"),
+    (0x2F818, "\u{51A4}"),
+    (0x2F819, "\u{4ECC}"),
+    (0x2F81A, "\u{51AC}"),
+    (0x2F81B, "\u{51B5}"),
+    (0x2F81C, "\u{291DF}"),
+    (0x2F81D, "\u{51F5}"),
+    (0x2F81E, "\u{5203}"),
+    (0x2F81F, "\u{34DF}"),
+    (0x2F820, "\u{523B}"),
+    (0x2F821, "\u{5246}"),
+    (0x2F822, "\u{5272}"),
+    (0x2F823, "\u{5277}"),
+    (0x2F824, "\u{3515}"),
+    (0x2F825, "\u{52C7}"),
+    (0x2F826, "\u{52C9}"),
+    (0x2F827, "\u{52E4}"),
+    (0x2F828, "\u{52FA}"),
+    (0x2F829, "\u{5305}"),
+    (0x2F82A, "\u{5306}"),
+    (0x2F82B, "\u{5317}"),
+    (0x2F82C, "\u{5349}"),
+    (0x2F82D, "\u{5351}"),
+    (0x2F82E, "\u{535A}"),
+    (0x2F82F, "\u{5373}"),
+    (0x2F830, "\u{537D}"),
+    (0x2F831, "\u{537F}"),
+    (0x2F832, "\u{537F}"),
+    (0x2F833, "\u{537F}"),
+    (0x2F834, "\u{20A2C}"),
+    (0x2F835, "\u{7070}"),
+    (0x2F836, "\u{53CA}"),
+    (0x2F837, "\u{53DF}"),
+    (0x2F838, "\u{20B63}"),
+    (0x2F839, "\u{53EB}"),
+    (0x2F83A, "\u{53F1}"),
+    (0x2F83B, "\u{5406}"),
+    (0x2F83C, "\u{549E}"),
+    (0x2F83D, "\u{5438}"),
+    (0x2F83E, "\u{5448}"),
+    (0x2F83F, "\u{5468}"),
+    (0x2F840, "\u{54A2}"),
+    (0x2F841, "\u{54F6}"),
+    (0x2F842, "\u{5510}"),
+    (0x2F843, "\u{5553}"),
+    (0x2F844, "\u{5563}"),
+    (0x2F845, "\u{5584}"),
+    (0x2F846, "\u{5584}"),
+    (0x2F847, "\u{5599}"),
+    (0x2F848, "\u{55AB}"),
+    (0x2F849, "\u{55B3}"),
+    (0x2F84A, "\u{55C2}"),
+    (0x2F84B, "\u{5716}"),
+    (0x2F84C, "\u{5606}"),
+    (0x2F84D, "\u{5717}"),
+    (0x2F84E, "\u{5651}"),
+    (0x2F84F, "\u{5674}"),
+    (0x2F850, "\u{5207}"),
+    (0x2F851, "\u{58EE}"),
+    (0x2F852, "\u{57CE}"),
+    (0x2F853, "\u{57F4}"),
+    (0x2F854, "\u{580D}"),
+    (0x2F855, "\u{578B}"),
+    (0x2F856, "\u{5832}"),
+    (0x2F857, "\u{5831}"),
+    (0x2F858, "\u{58AC}"),
+    (0x2F859, "\u{214E4}"),
+    (0x2F85A, "\u{58F2}"),
+    (0x2F85B, "\u{58F7}"),
+    (0x2F85C, "\u{5906}"),
+    (0x2F85D, "\u{591A}"),
+    (0x2F85E, "\u{5922}"),
+    (0x2F85F, "\u{5962}"),
+    (0x2F860, "\u{216A8}"),
+    (0x2F861, "\u{216EA}"),
+    (0x2F862, "\u{59EC}"),
+    (0x2F863, "\u{5A1B}"),
+    (0x2F864, "\u{5A27}"),
+    (0x2F865, "\u{59D8}"),
+    (0x2F866, "\u{5A66}"),
+    (0x2F867, "\u{36EE}"),
+    (0x2F868, "\u{36FC}"),
+    (0x2F869, "\u{5B08}"),
+    (0x2F86A, "\u{5B3E}"),
+    (0x2F86B, "\u{5B3E}"),
+    (0x2F86C, "\u{219C8}"),
+    (0x2F86D, "\u{5BC3}"),
+    (0x2F86E, "\u{5BD8}"),
+    (0x2F86F, "\u{5BE7}"),
+    (0x2F870, "\u{5BF3}"),
+    (0x2F871, "\u{21B18}"),
+    (0x2F872, "\u{5BFF}"),
+    (0x2F873, "\u{5C06}"),
+    (0x2F874, "\u{5F53}"),
+    (0x2F875, "\u{5C22}"),
+    (0x2F876, "\u{3781}"),
+    (0x2F877, "\u{5C60}"),
+    (0x2F878, "\u{5C6E}"),
+    (0x2F879, "\u{5CC0}"),
+    (0x2F87A, "\u{5C8D}"),
+    (0x2F87B, "\u{21DE4}"),
+    (0x2F87C, "\u{5D43}"),
+    (0x2F87D, "\u{21DE6}"),
+    (0x2F87E, "\u{5D6E}"),
+    (0x2F87F, "\u{5D6B}"),
+    (0x2F880, "\u{5D7C}"),
+    (0x2F881, "\u{5DE1}"),
+    (0x2F882, "\u{5DE2}"),
+    (0x2F883, "\u{382F}"),
+    (0x2F884, "\u{5DFD}"),
+    (0x2F885, "\u{5E28}"),
+    (0x2F886, "\u{5E3D}"),
+    (0x2F887, "\u{5E69}"),
+    (0x2F888, "\u{3862}"),
+    (0x2F889, "\u{22183}"),
+    (0x2F88A, "\u{387C}"),
+    (0x2F88B, "\u{5EB0}"),
+    (0x2F88C, "\u{5EB3}"),
+    (0x2F88D, "\u{5EB6}"),
+    (0x2F88E, "\u{5ECA}"),
+    (0x2F88F, "\u{2A392}"),
+    (0x2F890, "\u{5EFE}"),
+    (0x2F891, "\u{22331}"),
+    (0x2F892, "\u{22331}"),
+    (0x2F893, "\u{8201}"),
+    (0x2F894, "\u{5F22}"),
+    (0x2F895, "\u{5F22}"),
+    (0x2F896, "\u{38C7}"),
+    (0x2F897, "\u{232B8}"),
+    (0x2F898, "\u{261DA}"),
+    (0x2F899, "\u{5F62}"),
+    (0x2F89A, "\u{5F6B}"),
+    (0x2F89B, "\u{38E3}"),
+    (0x2F89C, "\u{5F9A}"),
+    (0x2F89D, "\u{5FCD}"),
+    (0x2F89E, "\u{5FD7}"),
+    (0x2F89F, "\u{5FF9}"),
+    (0x2F8A0, "\u{6081}"),
+    (0x2F8A1, "\u{393A}"),
+    (0x2F8A2, "\u{391C}"),
+    (0x2F8A3, "\u{6094}"),
+    (0x2F8A4, "\u{226D4}"),
+    (0x2F8A5, "\u{60C7}"),
+    (0x2F8A6, "\u{6148}"),
+    (0x2F8A7, "\u{614C}"),
+    (0x2F8A8, "\u{614E}"),
+    (0x2F8A9, "\u{614C}"),
+    (0x2F8AA, "\u{617A}"),
+    (0x2F8AB, "\u{618E}"),
+    (0x2F8AC, "\u{61B2}"),
+    (0x2F8AD, "\u{61A4}"),
+    (0x2F8AE, "\u{61AF}"),
+    (0x2F8AF, "\u{61DE}"),
+    (0x2F8B0, "\u{61F2}"),
+    (0x2F8B1, "\u{61F6}"),
+    (0x2F8B2, "\u{6210}"),
+    (0x2F8B3, "\u{621B}"),
+    (0x2F8B4, "\u{625D}"),
+    (0x2F8B5, "\u{62B1}"),
+    (0x2F8B6, "\u{62D4}"),
+    (0x2F8B7, "\u{6350}"),
+    (0x2F8B8, "\u{22B0C}"),
+    (0x2F8B9, "\u{633D}"),
+    (0x2F8BA, "\u{62FC}"),
+    (0x2F8BB, "\u{6368}"),
+    (0x2F8BC, "\u{6383}"),
+    (0x2F8BD, "\u{63E4}"),
+    (0x2F8BE, "\u{22BF1}"),
+    (0x2F8BF, "\u{6422}"),
+    (0x2F8C0, "\u{63C5}"),
+    (0x2F8C1, "\u{63A9}"),
+    (0x2F8C2, "\u{3A2E}"),
+    (0x2F8C3, "\u{6469}"),
+    (0x2F8C4, "\u{647E}"),
+    (0x2F8C5, "\u{649D}"),
+    (0x2F8C6, "\u{6477}"),
+    (0x2F8C7, "\u{3A6C}"),
+    (0x2F8C8, "\u{654F}"),
+    (0x2F8C9, "\u{656C}"),
+    (0x2F8CA, "\u{2300A}"),
+    (0x2F8CB, "\u{65E3}"),
+    (0x2F8CC, "\u{66F8}"),
+    (0x2F8CD, "\u{6649}"),
+    (0x2F8CE, "\u{3B19}"),
+    (0x2F8CF, "\u{6691}"),
+    (0x2F8D0, "\u{3B08}"),
+    (0x2F8D1, "\u{3AE4}"),
+    (0x2F8D2, "\u{5192}"),
+    (0x2F8D3, "\u{5195}"),
+    (0x2F8D4, "\u{6700}"),
+    (0x2F8D5, "\u{669C}"),
+    (0x2F8D6, "\u{80AD}"),
+    (0x2F8D7, "\u{43D9}"),
+    (0x2F8D8, "\u{6717}"),
+    (0x2F8D9, "\u{671B}"),
+    (0x2F8DA, "\u{6721}"),
+    (0x2F8DB, "\u{675E}"),
+    (0x2F8DC, "\u{6753}"),
+    (0x2F8DD, "\u{233C3}"),
+    (0x2F8DE, "\u{3B49}"),
+    (0x2F8DF, "\u{67FA}"),
+    (0x2F8E0, "\u{6785}"),
+    (0x2F8E1, "\u{6852}"),
+    (0x2F8E2, "\u{6885}"),
+    (0x2F8E3, "\u{2346D}"),
+    (0x2F8E4, "\u{688E}"),
+    (0x2F8E5, "\u{681F}"),
+    (0x2F8E6, "\u{6914}"),
+    (0x2F8E7, "\u{3B9D}"),
+    (0x2F8E8, "\u{6942}"),
+    (0x2F8E9, "\u{69A3}"),
+    (0x2F8EA, "\u{69EA}"),
+    (0x2F8EB, "\u{6AA8}"),
+    (0x2F8EC, "\u{236A3}"),
+    (0x2F8ED, "\u{6ADB}"),
+    (0x2F8EE, "\u{3C18}"),
+    (0x2F8EF, "\u{6B21}"),
+    (0x2F8F0, "\u{238A7}"),
+    (0x2F8F1, "\u{6B54}"),
+    (0x2F8F2, "\u{3C4E}"),
+    (0x2F8F3, "\u{6B72}"),
+    (0x2F8F4, "\u{6B9F}"),
+    (0x2F8F5, "\u{6BBA}"),
+    (0x2F8F6, "\u{6BBB}"),
+    (0x2F8F7, "\u{23A8D}"),
+    (0x2F8F8, "\u{21D0B}"),
+    (0x2F8F9, "\u{23AFA}"),
+    (0x2F8FA, "\u{6C4E}"),
+    (0x2F8FB, "\u{23CBC}"),
+    (0x2F8FC, "\u{6CBF}"),
+    (0x2F8FD, "\u{6CCD}"),
+    (0x2F8FE, "\u{6C67}"),
+    (0x2F8FF, "\u{6D16}"),
+    (0x2F900, "\u{6D3E}"),
+    (0x2F901, "\u{6D77}"),
+    (0x2F902, "\u{6D41}"),
+    (0x2F903, "\u{6D69}"),
+    (0x2F904, "\u{6D78}"),
+    (0x2F905, "\u{6D85}"),
+    (0x2F906, "\u{23D1E}"),
+    (0x2F907, "\u{6D34}"),
+    (0x2F908, "\u{6E2F}"),
+    (0x2F909, "\u{6E6E}"),
+    (0x2F90A, "\u{3D33}"),
+    (0x2F90B, "\u{6ECB}"),
+    (0x2F90C, "\u{6EC7}"),
+    (0x2F90D, "\u{23ED1}"),
+    (0x2F90E, "\u{6DF9}"),
+    (0x2F90F, "\u{6F6E}"),
+    (0x2F910, "\u{23F5E}"),
+    (0x2F911, "\u{23F8E}"),
+    (0x2F912, "\u{6FC6}"),
+    (0x2F913, "\u{7039}"),
+    (0x2F914, "\u{701E}"),
+    (0x2F915, "\u{701B}"),
+    (0x2F916, "\u{3D96}"),
+    (0x2F917, "\u{704A}"),
+    (0x2F918, "\u{707D}"),
+    (0x2F919, "\u{7077}"),
+    (0x2F91A, "\u{70AD}"),
+    (0x2F91B, "\u{20525}"),
+    (0x2F91C, "\u{7145}"),
+    (0x2F91D, "\u{24263}"),
+    (0x2F91E, "\u{719C}"),
+    (0x2F91F, "\u{243AB}"),
+    (0x2F920, "\u{7228}"),
+    (0x2F921, "\u{7235}"),
+    (0x2F922, "\u{7250}"),
+    (0x2F923, "\u{24608}"),
+    (0x2F924, "\u{7280}"),
+    (0x2F925, "\u{7295}"),
+    (0x2F926, "\u{24735}"),
+    (0x2F927, "\u{24814}"),
+    (0x2F928, "\u{737A}"),
+    (0x2F929, "\u{738B}"),
+    (0x2F92A, "\u{3EAC}"),
+    (0x2F92B, "\u{73A5}"),
+    (0x2F92C, "\u{3EB8}"),
+    (0x2F92D, "\u{3EB8}"),
+    (0x2F92E, "\u{7447}"),
+    (0x2F92F, "\u{745C}"),
+    (0x2F930, "\u{7471}"),
+    (0x2F931, "\u{7485}"),
+    (0x2F932, "\u{74CA}"),
+    (0x2F933, "\u{3F1B}"),
+    (0x2F934, "\u{7524}"),
+    (0x2F935, "\u{24C36}"),
+    (0x2F936, "\u{753E}"),
+    (0x2F937, "\u{24C92}"),
+    (0x2F938, "\u{7570}"),
+    (0x2F939, "\u{2219F}"),
+    (0x2F93A, "\u{7610}"),
+    (0x2F93B, "\u{24FA1}"),
+    (0x2F93C, "\u{24FB8}"),
+    (0x2F93D, "\u{25044}"),
+    (0x2F93E, "\u{3FFC}"),
+    (0x2F93F, "\u{4008}"),
+    (0x2F940, "\u{76F4}"),
+    (0x2F941, "\u{250F3}"),
+    (0x2F942, "\u{250F2}"),
+    (0x2F943, "\u{25119}"),
+    (0x2F944, "\u{25133}"),
+    (0x2F945, "\u{771E}"),
+    (0x2F946, "\u{771F}"),
+    (0x2F947, "\u{771F}"),
+    (0x2F948, "\u{774A}"),
+    (0x2F949, "\u{4039}"),
+    (0x2F94A, "\u{778B}"),
+    (0x2F94B, "\u{4046}"),
+    (0x2F94C, "\u{4096}"),
+    (0x2F94D, "\u{2541D}"),
+    (0x2F94E, "\u{784E}"),
+    (0x2F94F, "\u{788C}"),
+    (0x2F950, "\u{78CC}"),
+    (0x2F951, "\u{40E3}"),
+    (0x2F952, "\u{25626}"),
+    (0x2F953, "\u{7956}"),
+    (0x2F954, "\u{2569A}"),
+    (0x2F955, "\u{256C5}"),
+    (0x2F956, "\u{798F}"),
+    (0x2F957, "\u{79EB}"),
+    (0x2F958, "\u{412F}"),
+    (0x2F959, "\u{7A40}"),
+    (0x2F95A, "\u{7A4A}"),
+    (0x2F95B, "\u{7A4F}"),
+    (0x2F95C, "\u{2597C}"),
+    (0x2F95D, "\u{25AA7}"),
+    (0x2F95E, "\u{25AA7}"),
+    (0x2F95F, "\u{7AEE}"),
+    (0x2F960, "\u{4202}"),
+    (0x2F961, "\u{25BAB}"),
+    (0x2F962, "\u{7BC6}"),
+    (0x2F963, "\u{7BC9}"),
+    (0x2F964, "\u{4227}"),
+    (0x2F965, "\u{25C80}"),
+    (0x2F966, "\u{7CD2}"),
+    (0x2F967, "\u{42A0}"),
+    (0x2F968, "\u{7CE8}"),
+    (0x2F969, "\u{7CE3}"),
+    (0x2F96A, "\u{7D00}"),
+    (0x2F96B, "\u{25F86}"),
+    (0x2F96C, "\u{7D63}"),
+    (0x2F96D, "\u{4301}"),
+    (0x2F96E, "\u{7DC7}"),
+    (0x2F96F, "\u{7E02}"),
+    (0x2F970, "\u{7E45}"),
+    (0x2F971, "\u{4334}"),
+    (0x2F972, "\u{26228}"),
+    (0x2F973, "\u{26247}"),
+    (0x2F974, "\u{4359}"),
+    (0x2F975, "\u{262D9}"),
+    (0x2F976, "\u{7F7A}"),
+    (0x2F977, "\u{2633E}"),
+    (0x2F978, "\u{7F95}"),
+    (0x2F979, "\u{7FFA}"),
+    (0x2F97A, "\u{8005}"),
+    (0x2F97B, "\u{264DA}"),
+    (0x2F97C, "\u{26523}"),
+    (0x2F97D, "\u{8060}"),
+    (0x2F97E, "\u{265A8}"),
+    (0x2F97F, "\u{8070}"),
+    (0x2F980, "\u{2335F}"),
+    (0x2F981, "\u{43D5}"),
+    (0x2F982, "\u{80B2}"),
+    (0x2F983, "\u{8103}"),
+    (0x2F984, "\u{440B}"),
+    (0x2F985, "\u{813E}"),
+    (0x2F986, "\u{5AB5}"),
+    (0x2F987, "\u{267A7}"),
+    (0x2F988, "\u{267B5}"),
+    (0x2F989, "\u{23393}"),
+    (0x2F98A, "\u{2339C}"),
+    (0x2F98B, "\u{8201}"),
+    (0x2F98C, "\u{8204}"),
+    (0x2F98D, "\u{8F9E}"),
+    (0x2F98E, "\u{446B}"),
+    (0x2F98F, "\u{8291}"),
+    (0x2F990, "\u{828B}"),
+    (0x2F991, "\u{829D}"),
+    (0x2F992, "\u{52B3}"),
+    (0x2F993, "\u{82B1}"),
+    (0x2F994, "\u{82B3}"),
+    (0x2F995, "\u{82BD}"),
+    (0x2F996, "\u{82E6}"),
+    (0x2F997, "\u{26B3C}"),
+    (0x2F998, "\u{82E5}"),
+    (0x2F999, "\u{831D}"),
+    (0x2F99A, "\u{8363}"),
+    (0x2F99B, "\u{83AD}"),
+    (0x2F99C, "\u{8323}"),
+    (0x2F99D, "\u{83BD}"),
+    (0x2F99E, "\u{83E7}"),
+    (0x2F99F, "\u{8457}"),
+    (0x2F9A0, "\u{8353}"),
+    (0x2F9A1, "\u{83CA}"),
+    (0x2F9A2, "\u{83CC}"),
+    (0x2F9A3, "\u{83DC}"),
+    (0x2F9A4, "\u{26C36}"),
+    (0x2F9A5, "\u{26D6B}"),
+    (0x2F9A6, "\u{26CD5}"),
+    (0x2F9A7, "\u{452B}"),
+    (0x2F9A8, "\u{84F1}"),
+    (0x2F9A9, "\u{84F3}"),
+    (0x2F9AA, "\u{8516}"),
+    (0x2F9AB, "\u{273CA}"),
+    (0x2F9AC, "\u{8564}"),
+    (0x2F9AD, "\u{26F2C}"),
+    (0x2F9AE, "\u{455D}"),
+    (0x2F9AF, "\u{4561}"),
+    (0x2F9B0, "\u{26FB1}"),
+    (0x2F9B1, "\u{270D2}"),
+    (0x2F9B2, "\u{456B}"),
+    (0x2F9B3, "\u{8650}"),
+    (0x2F9B4, "\u{865C}"),
+    (0x2F9B5, "\u{8667}"),
+    (0x2F9B6, "\u{8669}"),
+    (0x2F9B7, "\u{86A9}"),
+    (0x2F9B8, "\u{8688}"),
+    (0x2F9B9, "\u{870E}"),
+    (0x2F9BA, "\u{86E2}"),
+    (0x2F9BB, "\u{8779}"),
+    (0x2F9BC, "\u{8728}"),
+    (0x2F9BD, "\u{876B}"),
+    (0x2F9BE, "\u{8786}"),
+    (0x2F9BF, "\u{45D7}"),
+    (0x2F9C0, "\u{87E1}"),
+    (0x2F9C1, "\u{8801}"),
+    (0x2F9C2, "\u{45F9}"),
+    (0x2F9C3, "\u{8860}"),
+    (0x2F9C4, "\u{8863}"),
+    (0x2F9C5, "\u{27667}"),
+    (0x2F9C6, "\u{88D7}"),
+    (0x2F9C7, "\u{88DE}"),
+    (0x2F9C8, "\u{4635}"),
+    (0x2F9C9, "\u{88FA}"),
+    (0x2F9CA, "\u{34BB}"),
+    (0x2F9CB, "\u{278AE}"),
+    (0x2F9CC, "\u{27966}"),
+    (0x2F9CD, "\u{46BE}"),
+    (0x2F9CE, "\u{46C7}"),
+    (0x2F9CF, "\u{8AA0}"),
+    (0x2F9D0, "\u{8AED}"),
+    (0x2F9D1, "\u{8B8A}"),
+    (0x2F9D2, "\u{8C55}"),
+    (0x2F9D3, "\u{27CA8}"),
+    (0x2F9D4, "\u{8CAB}"),
+    (0x2F9D5, "\u{8CC1}"),
+    (0x2F9D6, "\u{8D1B}"),
+    (0x2F9D7, "\u{8D77}"),
+    (0x2F9D8, "\u{27F2F}"),
+    (0x2F9D9, "\u{20804}"),
+    (0x2F9DA, "\u{8DCB}"),
+    (0x2F9DB, "\u{8DBC}"),
+    (0x2F9DC, "\u{8DF0}"),
+    (0x2F9DD, "\u{208DE}"),
+    (0x2F9DE, "\u{8ED4}"),
+    (0x2F9DF, "\u{8F38}"),
+    (0x2F9E0, "\u{285D2}"),
+    (0x2F9E1, "\u{285ED}"),
+    (0x2F9E2, "\u{9094}"),
+    (0x2F9E3, "\u{90F1}"),
+    (0x2F9E4, "\u{9111}"),
+    (0x2F9E5, "\u{2872E}"),
+    (0x2F9E6, "\u{911B}"),
+    (0x2F9E7, "\u{9238}"),
+    (0x2F9E8, "\u{92D7}"),
+    (0x2F9E9, "\u{92D8}"),
+    (0x2F9EA, "\u{927C}"),
+    (0x2F9EB, "\u{93F9}"),
+    (0x2F9EC, "\u{9415}"),
+    (0x2F9ED, "\u{28BFA}"),
+    (0x2F9EE, "\u{958B}"),
+    (0x2F9EF, "\u{4995}"),
+    (0x2F9F0, "\u{95B7}"),
+    (0x2F9F1, "\u{28D77}"),
+    (0x2F9F2, "\u{49E6}"),
+    (0x2F9F3, "\u{96C3}"),
+    (0x2F9F4, "\u{5DB2}"),
+    (0x2F9F5, "\u{9723}"),
+    (0x2F9F6, "\u{29145}"),
+    (0x2F9F7, "\u{2921A}"),
+    (0x2F9F8, "\u{4A6E}"),
+    (0x2F9F9, "\u{4A76}"),
+    (0x2F9FA, "\u{97E0}"),
+    (0x2F9FB, "\u{2940A}"),
+    (0x2F9FC, "\u{4AB2}"),
+    (0x2F9FD, "\u{29496}"),
+    (0x2F9FE, "\u{980B}"),
+    (0x2F9FF, "\u{980B}"),
+    (0x2FA00, "\u{9829}"),
+    (0x2FA01, "\u{295B6}"),
+    (0x2FA02, "\u{98E2}"),
+    (0x2FA03, "\u{4B33}"),
+    (0x2FA04, "\u{9929}"),
+    (0x2FA05, "\u{99A7}"),
+    (0x2FA06, "\u{99C2}"),
+    (0x2FA07, "\u{99FE}"),
+    (0x2FA08, "\u{4BCE}"),
+    (0x2FA09, "\u{29B30}"),
+    (0x2FA0A, "\u{9B12}"),
+    (0x2FA0B, "\u{9C40}"),
+    (0x2FA0C, "\u{9CFD}"),
+    (0x2FA0D, "\u{4CCE}"),
+    (0x2FA0E, "\u{4CED}"),
+    (0x2FA0F, "\u{9D67}"),
+    (0x2FA10, "\u{2A0CE}"),
+    (0x2FA11, "\u{4CF8}"),
+    (0x2FA12, "\u{2A105}"),
+    (0x2FA13, "\u{2A20E}"),
+    (0x2FA14, "\u{2A291}"),
+    (0x2FA15, "\u{9EBB}"),
+    (0x2FA16, "\u{4D56}"),
+    (0x2FA17, "\u{9EF9}"),
+    (0x2FA18, "\u{9EFE}"),
+    (0x2FA19, "\u{9F05}"),
+    (0x2FA1A, "\u{9F0F}"),
+    (0x2FA1B, "\u{9F16}"),
+    (0x2FA1C, "\u{9F3B}"),
+    (0x2FA1D, "\u{2A600}"),
+];
+
+pub(crate) const COMBINING_CLASS: &[(u32, u32, u8)] = &[
+    (0x0300, 0x0314, 230),
+    (0x0315, 0x0315, 232),
+    (0x0316, 0x0319, 220),
+    (0x031A, 0x031A, 232),
+    (0x031B, 0x031B, 216),
+    (0x031C, 0x0320, 220),
+    (0x0321, 0x0322, 202),
+    (0x0323, 0x0326, 220),
+    (0x0327, 0x0328, 202),
+    (0x0329, 0x0333, 220),
+    (0x0334, 0x0338, 1),
+    (0x0339, 0x033C, 220),
+    (0x033D, 0x0344, 230),
+    (0x0345, 0x0345, 240),
+    (0x0346, 0x0346, 230),
+    (0x0347, 0x0349, 220),
+    (0x034A, 0x034C, 230),
+    (0x034D, 0x034E, 220),
+    (0x0350, 0x0352, 230),
+    (0x0353, 0x0356, 220),
+    (0x0357, 0x0357, 230),
+    (0x0358, 0x0358, 232),
+    (0x0359, 0x035A, 220),
+    (0x035B, 0x035B, 230),
+    (0x035C, 0x035C, 233),
+    (0x035D, 0x035E, 234),
+    (0x035F, 0x035F, 233),
+    (0x0360, 0x0361, 234),
+    (0x0362, 0x0362, 233),
+    (0x0363, 0x036F, 230),
+    (0x0483, 0x0487, 230),
+    (0x0591, 0x0591, 220),
+    (0x0592, 0x0595, 230),
+    (0x0596, 0x0596, 220),
+    (0x0597, 0x0599, 230),
+    (0x059A, 0x059A, 222),
+    (0x059B, 0x059B, 220),
+    (0x059C, 0x05A1, 230),
+    (0x05A2, 0x05A7, 220),
+    (0x05A8, 0x05A9, 230),
+    (0x05AA, 0x05AA, 220),
+    (0x05AB, 0x05AC, 230),
+    (0x05AD, 0x05AD, 222),
+    (0x05AE, 0x05AE, 228),
+    (0x05AF, 0x05AF, 230),
+    (0x05B0, 0x05B0, 10),
+    (0x05B1, 0x05B1, 11),
+    (0x05B2, 0x05B2, 12),
+    (0x05B3, 0x05B3, 13),
+    (0x05B4, 0x05B4, 14),
+    (0x05B5, 0x05B5, 15),
+    (0x05B6, 0x05B6, 16),
+    (0x05B7, 0x05B7, 17),
+    (0x05B8, 0x05B8, 18),
+    (0x05B9, 0x05BA, 19),
+    (0x05BB, 0x05BB, 20),
+    (0x05BC, 0x05BC, 21),
+    (0x05BD, 0x05BD, 22),
+    (0x05BF, 0x05BF, 23),
+    (0x05C1, 0x05C1, 24),
+    (0x05C2, 0x05C2, 25),
+    (0x05C4, 0x05C4, 230),
+    (0x05C5, 0x05C5, 220),
+    (0x05C7, 0x05C7, 18),
+    (0x0610, 0x0617, 230),
+    (0x0618, 0x0618, 30),
+    (0x0619, 0x0619, 31),
+    (0x061A, 0x061A, 32),
+    (0x064B, 0x064B, 27),
+    (0x064C, 0x064C, 28),
+    (0x064D, 0x064D, 29),
+    (0x064E, 0x064E, 30),
+    (0x064F, 0x064F, 31),
+    (0x0650, 0x0650, 32),
+    (0x0651, 0x0651, 33),
+    (0x0652, 0x0652, 34),
+    (0x0653, 0x0654, 230),
+    (0x0655, 0x0656, 220),
+    (0x0657, 0x065B, 230),
+    (0x065C, 0x065C, 220),
+    (0x065D, 0x065E, 230),
+    (0x065F, 0x065F, 220),
+    (0x0670, 0x0670, 35),
+    (0x06D6, 0x06DC, 230),
+    (0x06DF, 0x06E2, 230),
+    (0x06E3, 0x06E3, 220),
+    (0x06E4, 0x06E4, 230),
+    (0x06E7, 0x06E8, 230),
+    (0x06EA, 0x06EA, 220),
+    (0x06EB, 0x06EC, 230),
+    (0x06ED, 0x06ED, 220),
+    (0x0711, 0x0711, 36),
+    (0x0730, 0x0730, 230),
+    (0x0731, 0x0731, 220),
+    (0x0732, 0x0733, 230),
+    (0x0734, 0x0734, 220),
+    (0x0735, 0x0736, 230),
+    (0x0737, 0x0739, 220),
+    (0x073A, 0x073A, 230),
+    (0x073B, 0x073C, 220),
+    (0x073D, 0x073D, 230),
+    (0x073E, 0x073E, 220),
+    (0x073F, 0x0741, 230),
+    (0x0742, 0x0742, 220),
+    (0x0743, 0x0743, 230),
+    (0x0744, 0x0744, 220),
+    (0x0745, 0x0745, 230),
+    (0x0746, 0x0746, 220),
+    (0x0747, 0x0747, 230),
+    (0x0748, 0x0748, 220),
+    (0x0749, 0x074A, 230),
+    (0x07EB, 0x07F1, 230),
+    (0x07F2, 0x07F2, 220),
+    (0x07F3, 0x07F3, 230),
+    (0x07FD, 0x07FD, 220),
+    (0x0816, 0x0819, 230),
+    (0x081B, 0x0823, 230),
+    (0x0825, 0x0827, 230),
+    (0x0829, 0x082D, 230),
+    (0x0859, 0x085B, 220),
+    (0x0898, 0x0898, 230),
+    (0x0899, 0x089B, 220),
+    (0x089C, 0x089F, 230),
+    (0x08CA, 0x08CE, 230),
+    (0x08CF, 0x08D3, 220),
+    (0x08D4, 0x08E1, 230),
+    (0x08E3, 0x08E3, 220),
+    (0x08E4, 0x08E5, 230),
+    (0x08E6, 0x08E6, 220),
+    (0x08E7, 0x08E8, 230),
+    (0x08E9, 0x08E9, 220),
+    (0x08EA, 0x08EC, 230),
+    (0x08ED, 0x08EF, 220),
+    (0x08F0, 0x08F0, 27),
+    (0x08F1, 0x08F1, 28),
+    (0x08F2, 0x08F2, 29),
+    (0x08F3, 0x08F5, 230),
+    (0x08F6, 0x08F6, 220),
+    (0x08F7, 0x08F8, 230),
+    (0x08F9, 0x08FA, 220),
+    (0x08FB, 0x08FF, 230),
+    (0x093C, 0x093C, 7),
+    (0x094D, 0x094D, 9),
+    (0x0951, 0x0951, 230),
+    (0x0952, 0x0952, 220),
+    (0x0953, 0x0954, 230),
+    (0x09BC, 0x09BC, 7),
+    (0x09CD, 0x09CD, 9),
+    (0x09FE, 0x09FE, 230),
+    (0x0A3C, 0x0A3C, 7),
+    (0x0A4D, 0x0A4D, 9),
+    (0x0ABC, 0x0ABC, 7),
+    (0x0ACD, 0x0ACD, 9),
+    (0x0B3C, 0x0B3C, 7),
+    (0x0B4D, 0x0B4D, 9),
+    (0x0BCD, 0x0BCD, 9),
+    (0x0C3C, 0x0C3C, 7),
+    (0x0C4D, 0x0C4D, 9),
+    (0x0C55, 0x0C55, 84),
+    (0x0C56, 0x0C56, 91),
+    (0x0CBC, 0x0CBC, 7),
+    (0x0CCD, 0x0CCD, 9),
+    (0x0D3B, 0x0D3C, 9),
+    (0x0D4D, 0x0D4D, 9),
+    (0x0DCA, 0x0DCA, 9),
+    (0x0E38, 0x0E39, 103),
+    (0x0E3A, 0x0E3A, 9),
+    (0x0E48, 0x0E4B, 107),
+    (0x0EB8, 0x0EB9, 118),
+    (0x0EBA, 0x0EBA, 9),
+    (0x0EC8, 0x0ECB, 122),
+    (0x0F18, 0x0F19, 220),
+    (0x0F35, 0x0F35, 220),
+    (0x0F37, 0x0F37, 220),
+    (0x0F39, 0x0F39, 216),
+    (0x0F71, 0x0F71, 129),
+    (0x0F72, 0x0F72, 130),
+    (0x0F74, 0x0F74, 132),
+    (0x0F7A, 0x0F7D, 130),
+    (0x0F80, 0x0F80, 130),
+    (0x0F82, 0x0F83, 230),
+    (0x0F84, 0x0F84, 9),
+    (0x0F86, 0x0F87, 230),
+    (0x0FC6, 0x0FC6, 220),
+    (0x1037, 0x1037, 7),
+    (0x1039, 0x103A, 9),
+    (0x108D, 0x108D, 220),
+    (0x135D, 0x135F, 230),
+    (0x1714, 0x1715, 9),
+    (0x1734, 0x1734, 9),
+    (0x17D2, 0x17D2, 9),
+    (0x17DD, 0x17DD, 230),
+    (0x18A9, 0x18A9, 228),
+    (0x1939, 0x1939, 222),
+    (0x193A, 0x193A, 230),
+    (0x193B, 0x193B, 220),
+    (0x1A17, 0x1A17, 230),
+    (0x1A18, 0x1A18, 220),
+    (0x1A60, 0x1A60, 9),
+    (0x1A75, 0x1A7C, 230),
+    (0x1A7F, 0x1A7F, 220),
+    (0x1AB0, 0x1AB4, 230),
+    (0x1AB5, 0x1ABA, 220),
+    (0x1ABB, 0x1ABC, 230),
+    (0x1ABD, 0x1ABD, 220),
+    (0x1ABF, 0x1AC0, 220),
+    (0x1AC1, 0x1AC2, 230),
+    (0x1AC3, 0x1AC4, 220),
+    (0x1AC5, 0x1AC9, 230),
+    (0x1ACA, 0x1ACA, 220),
+    (0x1ACB, 0x1ACE, 230),
+    (0x1B34, 0x1B34, 7),
+    (0x1B44, 0x1B44, 9),
+    (0x1B6B, 0x1B6B, 230),
+    (0x1B6C, 0x1B6C, 220),
+    (0x1B6D, 0x1B73, 230),
+    (0x1BAA, 0x1BAB, 9),
+    (0x1BE6, 0x1BE6, 7),
+    (0x1BF2, 0x1BF3, 9),
+    (0x1C37, 0x1C37, 7),
+    (0x1CD0, 0x1CD2, 230),
+    (0x1CD4, 0x1CD4, 1),
+    (0x1CD5, 0x1CD9, 220),
+    (0x1CDA, 0x1CDB, 230),
+    (0x1CDC, 0x1CDF, 220),
+    (0x1CE0, 0x1CE0, 230),
+    (0x1CE2, 0x1CE8, 1),
+    (0x1CED, 0x1CED, 220),
+    (0x1CF4, 0x1CF4, 230),
+    (0x1CF8, 0x1CF9, 230),
+    (0x1DC0, 0x1DC1, 230),
+    (0x1DC2, 0x1DC2, 220),
+    (0x1DC3, 0x1DC9, 230),
+    (0x1DCA, 0x1DCA, 220),
+    (0x1DCB, 0x1DCC, 230),
+    (0x1DCD, 0x1DCD, 234),
+    (0x1DCE, 0x1DCE, 214),
+    (0x1DCF, 0x1DCF, 220),
+    (0x1DD0, 0x1DD0, 202),
+    (0x1DD1, 0x1DF5, 230),
+    (0x1DF6, 0x1DF6, 232),
+    (0x1DF7, 0x1DF8, 228),
+    (0x1DF9, 0x1DF9, 220),
+    (0x1DFA, 0x1DFA, 218),
+    (0x1DFB, 0x1DFB, 230),
+    (0x1DFC, 0x1DFC, 233),
+    (0x1DFD, 0x1DFD, 220),
+    (0x1DFE, 0x1DFE, 230),
+    (0x1DFF, 0x1DFF, 220),
+    (0x20D0, 0x20D1, 230),
+    (0x20D2, 0x20D3, 1),
+    (0x20D4, 0x20D7, 230),
+    (0x20D8, 0x20DA, 1),
+    (0x20DB, 0x20DC, 230),
+    (0x20E1, 0x20E1, 230),
+    (0x20E5, 0x20E6, 1),
+    (0x20E7, 0x20E7, 230),
+    (0x20E8, 0x20E8, 220),
+    (0x20E9, 0x20E9, 230),
+    (0x20EA, 0x20EB, 1),
+    (0x20EC, 0x20EF, 220),
+    (0x20F0, 0x20F0, 230),
+    (0x2CEF, 0x2CF1, 230),
+    (0x2D7F, 0x2D7F, 9),
+    (0x2DE0, 0x2DFF, 230),
+    (0x302A, 0x302A, 218),
+    (0x302B, 0x302B, 228),
+    (0x302C, 0x302C, 232),
+    (0x302D, 0x302D, 222),
+    (0x302E, 0x302F, 224),
+    (0x3099, 0x309A, 8),
+    (0xA66F, 0xA66F, 230),
+    (0xA674, 0xA67D, 230),
+    (0xA69E, 0xA69F, 230),
+    (0xA6F0, 0xA6F1, 230),
+    (0xA806, 0xA806, 9),
+    (0xA82C, 0xA82C, 9),
+    (0xA8C4, 0xA8C4, 9),
+    (0xA8E0, 0xA8F1, 230),
+    (0xA92B, 0xA92D, 220),
+    (0xA953, 0xA953, 9),
+    (0xA9B3, 0xA9B3, 7),
+    (0xA9C0, 0xA9C0, 9),
+    (0xAAB0, 0xAAB0, 230),
+    (0xAAB2, 0xAAB3, 230),
+    (0xAAB4, 0xAAB4, 220),
+    (0xAAB7, 0xAAB8, 230),
+    (0xAABE, 0xAABF, 230),
+    (0xAAC1, 0xAAC1, 230),
+    (0xAAF6, 0xAAF6, 9),
+    (0xABED, 0xABED, 9),
+    (0xFB1E, 0xFB1E, 26),
+    (0xFE20, 0xFE26, 230),
+    (0xFE27, 0xFE2D, 220),
+    (0xFE2E, 0xFE2F, 230),
+    (0x101FD, 0x101FD, 220),
+    (0x102E0, 0x102E0, 220),
+    (0x10376, 0x1037A, 230),
+    (0x10A0D, 0x10A0D, 220),
+    (0x10A0F, 0x10A0F, 230),
+    (0x10A38, 0x10A38, 230),
+    (0x10A39, 0x10A39, 1),
+    (0x10A3A, 0x10A3A, 220),
+    (0x10A3F, 0x10A3F, 9),
+    (0x10AE5, 0x10AE5, 230),
+    (0x10AE6, 0x10AE6, 220),
+    (0x10D24, 0x10D27, 230),
+    (0x10EAB, 0x10EAC, 230),
+    (0x10F46, 0x10F47, 220),
+    (0x10F48, 0x10F4A, 230),
+    (0x10F4B, 0x10F4B, 220),
+    (0x10F4C, 0x10F4C, 230),
+    (0x10F4D, 0x10F50, 220),
+    (0x10F82, 0x10F82, 230),
+    (0x10F83, 0x10F83, 220),
+    (0x10F84, 0x10F84, 230),
+    (0x10F85, 0x10F85, 220),
+    (0x11046, 0x11046, 9),
+    (0x11070, 0x11070, 9),
+    (0x1107F, 0x1107F, 9),
+    (0x110B9, 0x110B9, 9),
+    (0x110BA, 0x110BA, 7),
+    (0x11100, 0x11102, 230),
+    (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7),
+    (0x111C0, 0x111C0, 9),
+    (0x111CA, 0x111CA, 7),
+    (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7),
+    (0x112E9, 0x112E9, 7),
+    (0x112EA, 0x112EA, 9),
+    (0x1133B, 0x1133C, 7),
+    (0x1134D, 0x1134D, 9),
+    (0x11366, 0x1136C, 230),
+    (0x11370, 0x11374, 230),
+    (0x11442, 0x11442, 9),
+    (0x11446, 0x11446, 7),
+    (0x1145E, 0x1145E, 230),
+    (0x114C2, 0x114C2, 9),
+    (0x114C3, 0x114C3, 7),
+    (0x115BF, 0x115BF, 9),
+    (0x115C0, 0x115C0, 7),
+    (0x1163F, 0x1163F, 9),
+    (0x116B6, 0x116B6, 9),
+    (0x116B7, 0x116B7, 7),
+    (0x1172B, 0x1172B, 9),
+    (0x11839, 0x11839, 9),
+    (0x1183A, 0x1183A, 7),
+    (0x1193D, 0x1193E, 9),
+    (0x11943, 0x11943, 7),
+    (0x119E0, 0x119E0, 9),
+    (0x11A34, 0x11A34, 9),
+    (0x11A47, 0x11A47, 9),
+    (0x11A99, 0x11A99, 9),
+    (0x11C3F, 0x11C3F, 9),
+    (0x11D42, 0x11D42, 7),
+    (0x11D44, 0x11D45, 9),
+    (0x11D97, 0x11D97, 9),
+    (0x16AF0, 0x16AF4, 1),
+    (0x16B30, 0x16B36, 230),
+    (0x16FF0, 0x16FF1, 6),
+    (0x1BC9E, 0x1BC9E, 1),
+    (0x1D165, 0x1D166, 216),
+    (0x1D167, 0x1D169, 1),
+    (0x1D16D, 0x1D16D, 226),
+    (0x1D16E, 0x1D172, 216),
+    (0x1D17B, 0x1D182, 220),
+    (0x1D185, 0x1D189, 230),
+    (0x1D18A, 0x1D18B, 220),
+    (0x1D1AA, 0x1D1AD, 230),
+    (0x1D242, 0x1D244, 230),
+    (0x1E000, 0x1E006, 230),
+    (0x1E008, 0x1E018, 230),
+    (0x1E01B, 0x1E021, 230),
+    (0x1E023, 0x1E024, 230),
+    (0x1E026, 0x1E02A, 230),
+    (0x1E130, 0x1E136, 230),
+    (0x1E2AE, 0x1E2AE, 230),
+    (0x1E2EC, 0x1E2EF, 230),
+    (0x1E8D0, 0x1E8D6, 220),
+    (0x1E944, 0x1E949, 230),
+    (0x1E94A, 0x1E94A, 7),
+];
+
+pub(crate) const COMPOSITION: &[(u32, u32, u32)] = &[
+    (0x003C, 0x0338, 0x226E),
+    (0x003D, 0x0338, 0x2260),
+    (0x003E, 0x0338, 0x226F),
+    (0x0041, 0x0300, 0x00C0),
+    (0x0041, 0x0301, 0x00C1),
+    (0x0041, 0x0302, 0x00C2),
+    (0x0041, 0x0303, 0x00C3),
+    (0x0041, 0x0304, 0x0100),
+    (0x0041, 0x0306, 0x0102),
+    (0x0041, 0x0307, 0x0226),
+    (0x0041, 0x0308, 0x00C4),
+    (0x0041, 0x0309, 0x1EA2),
+    (0x0041, 0x030A, 0x00C5),
+    (0x0041, 0x030C, 0x01CD),
+    (0x0041, 0x030F, 0x0200),
+    (0x0041, 0x0311, 0x0202),
+    (0x0041, 0x0323, 0x1EA0),
+    (0x0041, 0x0325, 0x1E00),
+    (0x0041, 0x0328, 0x0104),
+    (0x0042, 0x0307, 0x1E02),
+    (0x0042, 0x0323, 0x1E04),
+    (0x0042, 0x0331, 0x1E06),
+    (0x0043, 0x0301, 0x0106),
+    (0x0043, 0x0302, 0x0108),
+    (0x0043, 0x0307, 0x010A),
+    (0x0043, 0x030C, 0x010C),
+    (0x0043, 0x0327, 0x00C7),
+    (0x0044, 0x0307, 0x1E0A),
+    (0x0044, 0x030C, 0x010E),
+    (0x0044, 0x0323, 0x1E0C),
+    (0x0044, 0x0327, 0x1E10),
+    (0x0044, 0x032D, 0x1E12),
+    (0x0044, 0x0331, 0x1E0E),
+    (0x0045, 0x0300, 0x00C8),
+    (0x0045, 0x0301, 0x00C9),
+    (0x0045, 0x0302, 0x00CA),
+    (0x0045, 0x0303, 0x1EBC),
+    (0x0045, 0x0304, 0x0112),
+    (0x0045, 0x0306, 0x0114),
+    (0x0045, 0x0307, 0x0116),
+    (0x0045, 0x0308, 0x00CB),
+    (0x0045, 0x0309, 0x1EBA),
+    (0x0045, 0x030C, 0x011A),
+    (0x0045, 0x030F, 0x0204),
+    (0x0045, 0x0311, 0x0206),
+    (0x0045, 0x0323, 0x1EB8),
+    (0x0045, 0x0327, 0x0228),
+    (0x0045, 0x0328, 0x0118),
+    (0x0045, 0x032D, 0x1E18),
+    (0x0045, 0x0330, 0x1E1A),
+    (0x0046, 0x0307, 0x1E1E),
+    (0x0047, 0x0301, 0x01F4),
+    (0x0047, 0x0302, 0x011C),
+    (0x0047, 0x0304, 0x1E20),
+    (0x0047, 0x0306, 0x011E),
+    (0x0047, 0x0307, 0x0120),
+    (0x0047, 0x030C, 0x01E6),
+    (0x0047, 0x0327, 0x0122),
+    (0x0048, 0x0302, 0x0124),
+    (0x0048, 0x0307, 0x1E22),
+    (0x0048, 0x0308, 0x1E26),
+    (0x0048, 0x030C, 0x021E),
+    (0x0048, 0x0323, 0x1E24),
+    (0x0048, 0x0327, 0x1E28),
+    (0x0048, 0x032E, 0x1E2A),
+    (0x0049, 0x0300, 0x00CC),
+    (0x0049, 0x0301, 0x00CD),
+    (0x0049, 0x0302, 0x00CE),
+    (0x0049, 0x0303, 0x0128),
+    (0x0049, 0x0304, 0x012A),
+    (0x0049, 0x0306, 0x012C),
+    (0x0049, 0x0307, 0x0130),
+    (0x0049, 0x0308, 0x00CF),
+    (0x0049, 0x0309, 0x1EC8),
+    (0x0049, 0x030C, 0x01CF),
+    (0x0049, 0x030F, 0x0208),
+    (0x0049, 0x0311, 0x020A),
+    (0x0049, 0x0323, 0x1ECA),
+    (0x0049, 0x0328, 0x012E),
+    (0x0049, 0x0330, 0x1E2C),
+    (0x004A, 0x0302, 0x0134),
+    (0x004B, 0x0301, 0x1E30),
+    (0x004B, 0x030C, 0x01E8),
+    (0x004B, 0x0323, 0x1E32),
+    (0x004B, 0x0327, 0x0136),
+    (0x004B, 0x0331, 0x1E34),
+    (0x004C, 0x0301, 0x0139),
+    (0x004C, 0x030C, 0x013D),
+    (0x004C, 0x0323, 0x1E36),
+    (0x004C, 0x0327, 0x013B),
+    (0x004C, 0x032D, 0x1E3C),
+    (0x004C, 0x0331, 0x1E3A),
+    (0x004D, 0x0301, 0x1E3E),
+    (0x004D, 0x0307, 0x1E40),
+    (0x004D, 0x0323, 0x1E42),
+    (0x004E, 0x0300, 0x01F8),
+    (0x004E, 0x0301, 0x0143),
+    (0x004E, 0x0303, 0x00D1),
+    (0x004E, 0x0307, 0x1E44),
+    (0x004E, 0x030C, 0x0147),
+    (0x004E, 0x0323, 0x1E46),
+    (0x004E, 0x0327, 0x0145),
+    (0x004E, 0x032D, 0x1E4A),
+    (0x004E, 0x0331, 0x1E48),
+    (0x004F, 0x0300, 0x00D2),
+    (0x004F, 0x0301, 0x00D3),
+    (0x004F, 0x0302, 0x00D4),
+    (0x004F, 0x0303, 0x00D5),
+    (0x004F, 0x0304, 0x014C),
+    (0x004F, 0x0306, 0x014E),
+    (0x004F, 0x0307, 0x022E),
+    (0x004F, 0x0308, 0x00D6),
+    (0x004F, 0x0309, 0x1ECE),
+    (0x004F, 0x030B, 0x0150),
+    (0x004F, 0x030C, 0x01D1),
+    (0x004F, 0x030F, 0x020C),
+    (0x004F, 0x0311, 0x020E),
+    (0x004F, 0x031B, 0x01A0),
+    (0x004F, 0x0323, 0x1ECC),
+    (0x004F, 0x0328, 0x01EA),
+    (0x0050, 0x0301, 0x1E54),
+    (0x0050, 0x0307, 0x1E56),
+    (0x0052, 0x0301, 0x0154),
+    (0x0052, 0x0307, 0x1E58),
+    (0x0052, 0x030C, 0x0158),
+    (0x0052, 0x030F, 0x0210),
+    (0x0052, 0x0311, 0x0212),
+    (0x0052, 0x0323, 0x1E5A),
+    (0x0052, 0x0327, 0x0156),
+    (0x0052, 0x0331, 0x1E5E),
+    (0x0053, 0x0301, 0x015A),
+    (0x0053, 0x0302, 0x015C),
+    (0x0053, 0x0307, 0x1E60),
+    (0x0053, 0x030C, 0x0160),
+    (0x0053, 0x0323, 0x1E62),
+    (0x0053, 0x0326, 0x0218),
+    (0x0053, 0x0327, 0x015E),
+    (0x0054, 0x0307, 0x1E6A),
+    (0x0054, 0x030C, 0x0164),
+    (0x0054, 0x0323, 0x1E6C),
+    (0x0054, 0x0326, 0x021A),
+    (0x0054, 0x0327, 0x0162),
+    (0x0054, 0x032D, 0x1E70),
+    (0x0054, 0x0331, 0x1E6E),
+    (0x0055, 0x0300, 0x00D9),
+    (0x0055, 0x0301, 0x00DA),
+    (0x0055, 0x0302, 0x00DB),
+    (0x0055, 0x0303, 0x0168),
+    (0x0055, 0x0304, 0x016A),
+    (0x0055, 0x0306, 0x016C),
+    (0x0055, 0x0308, 0x00DC),
+    (0x0055, 0x0309, 0x1EE6),
+    (0x0055, 0x030A, 0x016E),
+    (0x0055, 0x030B, 0x0170),
+    (0x0055, 0x030C, 0x01D3),
+    (0x0055, 0x030F, 0x0214),
+    (0x0055, 0x0311, 0x0216),
+    (0x0055, 0x031B, 0x01AF),
+    (0x0055, 0x0323, 0x1EE4),
+    (0x0055, 0x0324, 0x1E72),
+    (0x0055, 0x0328, 0x0172),
+    (0x0055, 0x032D, 0x1E76),
+    (0x0055, 0x0330, 0x1E74),
+    (0x0056, 0x0303, 0x1E7C),
+    (0x0056, 0x0323, 0x1E7E),
+    (0x0057, 0x0300, 0x1E80),
+    (0x0057, 0x0301, 0x1E82),
+    (0x0057, 0x0302, 0x0174),
+    (0x0057, 0x0307, 0x1E86),
+    (0x0057, 0x0308, 0x1E84),
+    (0x0057, 0x0323, 0x1E88),
+    (0x0058, 0x0307, 0x1E8A),
+    (0x0058, 0x0308, 0x1E8C),
+    (0x0059, 0x0300, 0x1EF2),
+    (0x0059, 0x0301, 0x00DD),
+    (0x0059, 0x0302, 0x0176),
+    (0x0059, 0x0303, 0x1EF8),
+    (0x0059, 0x0304, 0x0232),
+    (0x0059, 0x0307, 0x1E8E),
+    (0x0059, 0x0308, 0x0178),
+    (0x0059, 0x0309, 0x1EF6),
+    (0x0059, 0x0323, 0x1EF4),
+    (0x005A, 0x0301, 0x0179),
+    (0x005A, 0x0302, 0x1E90),
+    (0x005A, 0x0307, 0x017B),
+    (0x005A, 0x030C, 0x017D),
+    (0x005A, 0x0323, 0x1E92),
+    (0x005A, 0x0331, 0x1E94),
+    (0x0061, 0x0300, 0x00E0),
+    (0x0061, 0x0301, 0x00E1),
+    (0x0061, 0x0302, 0x00E2),
+    (0x0061, 0x0303, 0x00E3),
+    (0x0061, 0x0304, 0x0101),
+    (0x0061, 0x0306, 0x0103),
+    (0x0061, 0x0307, 0x0227),
+    (0x0061, 0x0308, 0x00E4),
+    (0x0061, 0x0309, 0x1EA3),
+    (0x0061, 0x030A, 0x00E5),
+    (0x0061, 0x030C, 0x01CE),
+    (0x0061, 0x030F, 0x0201),
+    (0x0061, 0x0311, 0x0203),
+    (0x0061, 0x0323, 0x1EA1),
+    (0x0061, 0x0325, 0x1E01),
+    (0x0061, 0x0328, 0x0105),
+    (0x0062, 0x0307, 0x1E03),
+    (0x0062, 0x0323, 0x1E05),
+    (0x0062, 0x0331, 0x1E07),
+    (0x0063, 0x0301, 0x0107),
+    (0x0063, 0x0302, 0x0109),
+    (0x0063, 0x0307, 0x010B),
+    (0x0063, 0x030C, 0x010D),
+    (0x0063, 0x0327, 0x00E7),
+    (0x0064, 0x0307, 0x1E0B),
+    (0x0064, 0x030C, 0x010F),
+    (0x0064, 0x0323, 0x1E0D),
+    (0x0064, 0x0327, 0x1E11),
+    (0x0064, 0x032D, 0x1E13),
+    (0x0064, 0x0331, 0x1E0F),
+    (0x0065, 0x0300, 0x00E8),
+    (0x0065, 0x0301, 0x00E9),
+    (0x0065, 0x0302, 0x00EA),
+    (0x0065, 0x0303, 0x1EBD),
+    (0x0065, 0x0304, 0x0113),
+    (0x0065, 0x0306, 0x0115),
+    (0x0065, 0x0307, 0x0117),
+    (0x0065, 0x0308, 0x00EB),
+    (0x0065, 0x0309, 0x1EBB),
+    (0x0065, 0x030C, 0x011B),
+    (0x0065, 0x030F, 0x0205),
+    (0x0065, 0x0311, 0x0207),
+    (0x0065, 0x0323, 0x1EB9),
+    (0x0065, 0x0327, 0x0229),
+    (0x0065, 0x0328, 0x0119),
+    (0x0065, 0x032D, 0x1E19),
+    (0x0065, 0x0330, 0x1E1B),
+    (0x0066, 0x0307, 0x1E1F),
+    (0x0067, 0x0301, 0x01F5),
+    (0x0067, 0x0302, 0x011D),
+    (0x0067, 0x0304, 0x1E21),
+    (0x0067, 0x0306, 0x011F),
+    (0x0067, 0x0307, 0x0121),
+    (0x0067, 0x030C, 0x01E7),
+    (0x0067, 0x0327, 0x0123),
+    (0x0068, 0x0302, 0x0125),
+    (0x0068, 0x0307, 0x1E23),
+    (0x0068, 0x0308, 0x1E27),
+    (0x0068, 0x030C, 0x021F),
+    (0x0068, 0x0323, 0x1E25),
+    (0x0068, 0x0327, 0x1E29),
+    (0x0068, 0x032E, 0x1E2B),
+    (0x0068, 0x0331, 0x1E96),
+    (0x0069, 0x0300, 0x00EC),
+    (0x0069, 0x0301, 0x00ED),
+    (0x0069, 0x0302, 0x00EE),
+    (0x0069, 0x0303, 0x0129),
+    (0x0069, 0x0304, 0x012B),
+    (0x0069, 0x0306, 0x012D),
+    (0x0069, 0x0308, 0x00EF),
+    (0x0069, 0x0309, 0x1EC9),
+    (0x0069, 0x030C, 0x01D0),
+    (0x0069, 0x030F, 0x0209),
+    (0x0069, 0x0311, 0x020B),
+    (0x0069, 0x0323, 0x1ECB),
+    (0x0069, 0x0328, 0x012F),
+    (0x0069, 0x0330, 0x1E2D),
+    (0x006A, 0x0302, 0x0135),
+    (0x006A, 0x030C, 0x01F0),
+    (0x006B, 0x0301, 0x1E31),
+    (0x006B, 0x030C, 0x01E9),
+    (0x006B, 0x0323, 0x1E33),
+    (0x006B, 0x0327, 0x0137),
+    (0x006B, 0x0331, 0x1E35),
+    (0x006C, 0x0301, 0x013A),
+    (0x006C, 0x030C, 0x013E),
+    (0x006C, 0x0323, 0x1E37),
+    (0x006C, 0x0327, 0x013C),
+    (0x006C, 0x032D, 0x1E3D),
+    (0x006C, 0x0331, 0x1E3B),
+    (0x006D, 0x0301, 0x1E3F),
+    (0x006D, 0x0307, 0x1E41),
+    (0x006D, 0x0323, 0x1E43),
+    (0x006E, 0x0300, 0x01F9),
+    (0x006E, 0x0301, 0x0144),
+    (0x006E, 0x0303, 0x00F1),
+    (0x006E, 0x0307, 0x1E45),
+    (0x006E, 0x030C, 0x0148),
+    (0x006E, 0x0323, 0x1E47),
+    (0x006E, 0x0327, 0x0146),
+    (0x006E, 0x032D, 0x1E4B),
+    (0x006E, 0x0331, 0x1E49),
+    (0x006F, 0x0300, 0x00F2),
+    (0x006F, 0x0301, 0x00F3),
+    (0x006F, 0x0302, 0x00F4),
+    (0x006F, 0x0303, 0x00F5),
+    (0x006F, 0x0304, 0x014D),
+    (0x006F, 0x0306, 0x014F),
+    (0x006F, 0x0307, 0x022F),
+    (0x006F, 0x0308, 0x00F6),
+    (0x006F, 0x0309, 0x1ECF),
+    (0x006F, 0x030B, 0x0151),
+    (0x006F, 0x030C, 0x01D2),
+    (0x006F, 0x030F, 0x020D),
+    (0x006F, 0x0311, 0x020F),
+    (0x006F, 0x031B, 0x01A1),
+    (0x006F, 0x0323, 0x1ECD),
+    (0x006F, 0x0328, 0x01EB),
+    (0x0070, 0x0301, 0x1E55),
+    (0x0070, 0x0307, 0x1E57),
+    (0x0072, 0x0301, 0x0155),
+    (0x0072, 0x0307, 0x1E59),
+    (0x0072, 0x030C, 0x0159),
+    (0x0072, 0x030F, 0x0211),
+    (0x0072, 0x0311, 0x0213),
+    (0x0072, 0x0323, 0x1E5B),
+    (0x0072, 0x0327, 0x0157),
+    (0x0072, 0x0331, 0x1E5F),
+    (0x0073, 0x0301, 0x015B),
+    (0x0073, 0x0302, 0x015D),
+    (0x0073, 0x0307, 0x1E61),
+    (0x0073, 0x030C, 0x0161),
+    (0x0073, 0x0323, 0x1E63),
+    (0x0073, 0x0326, 0x0219),
+    (0x0073, 0x0327, 0x015F),
+    (0x0074, 0x0307, 0x1E6B),
+    (0x0074, 0x0308, 0x1E97),
+    (0x0074, 0x030C, 0x0165),
+    (0x0074, 0x0323, 0x1E6D),
+    (0x0074, 0x0326, 0x021B),
+    (0x0074, 0x0327, 0x0163),
+    (0x0074, 0x032D, 0x1E71),
+    (0x0074, 0x0331, 0x1E6F),
+    (0x0075, 0x0300, 0x00F9),
+    (0x0075, 0x0301, 0x00FA),
+    (0x0075, 0x0302, 0x00FB),
+    (0x0075, 0x0303, 0x0169),
+    (0x0075, 0x0304, 0x016B),
+    (0x0075, 0x0306, 0x016D),
+    (0x0075, 0x0308, 0x00FC),
+    (0x0075, 0x0309, 0x1EE7),
+    (0x0075, 0x030A, 0x016F),
+    (0x0075, 0x030B, 0x0171),
+    (0x0075, 0x030C, 0x01D4),
+    (0x0075, 0x030F, 0x0215),
+    (0x0075, 0x0311, 0x0217),
+    (0x0075, 0x031B, 0x01B0),
+    (0x0075, 0x0323, 0x1EE5),
+    (0x0075, 0x0324, 0x1E73),
+    (0x0075, 0x0328, 0x0173),
+    (0x0075, 0x032D, 0x1E77),
+    (0x0075, 0x0330, 0x1E75),
+    (0x0076, 0x0303, 0x1E7D),
+    (0x0076, 0x0323, 0x1E7F),
+    (0x0077, 0x0300, 0x1E81),
+    (0x0077, 0x0301, 0x1E83),
+    (0x0077, 0x0302, 0x0175),
+    (0x0077, 0x0307, 0x1E87),
+    (0x0077, 0x0308, 0x1E85),
+    (0x0077, 0x030A, 0x1E98),
+    (0x0077, 0x0323, 0x1E89),
+    (0x0078, 0x0307, 0x1E8B),
+    (0x0078, 0x0308, 0x1E8D),
+    (0x0079, 0x0300, 0x1EF3),
+    (0x0079, 0x0301, 0x00FD),
+    (0x0079, 0x0302, 0x0177),
+    (0x0079, 0x0303, 0x1EF9),
+    (0x0079, 0x0304, 0x0233),
+    (0x0079, 0x0307, 0x1E8F),
+    (0x0079, 0x0308, 0x00FF),
+    (0x0079, 0x0309, 0x1EF7),
+    (0x0079, 0x030A, 0x1E99),
+    (0x0079, 0x0323, 0x1EF5),
+    (0x007A, 0x0301, 0x017A),
+    (0x007A, 0x0302, 0x1E91),
+    (0x007A, 0x0307, 0x017C),
+    (0x007A, 0x030C, 0x017E),
+    (0x007A, 0x0323, 0x1E93),
+    (0x007A, 0x0331, 0x1E95),
+    (0x00A8, 0x0300, 0x1FED),
+    (0x00A8, 0x0301, 0x0385),
+    (0x00A8, 0x0342, 0x1FC1),
+    (0x00C2, 0x0300, 0x1EA6),
+    (0x00C2, 0x0301, 0x1EA4),
+    (0x00C2, 0x0303, 0x1EAA),
+    (0x00C2, 0x0309, 0x1EA8),
+    (0x00C4, 0x0304, 0x01DE),
+    (0x00C5, 0x0301, 0x01FA),
+    (0x00C6, 0x0301, 0x01FC),
+    (0x00C6, 0x0304, 0x01E2),
+    (0x00C7, 0x0301, 0x1E08),
+    (0x00CA, 0x0300, 0x1EC0),
+    (0x00CA, 0x0301, 0x1EBE),
+    (0x00CA, 0x0303, 0x1EC4),
+    (0x00CA, 0x0309, 0x1EC2),
+    (0x00CF, 0x0301, 0x1E2E),
+    (0x00D4, 0x0300, 0x1ED2),
+    (0x00D4, 0x0301, 0x1ED0),
+    (0x00D4, 0x0303, 0x1ED6),
+    (0x00D4, 0x0309, 0x1ED4),
+    (0x00D5, 0x0301, 0x1E4C),
+    (0x00D5, 0x0304, 0x022C),
+    (0x00D5, 0x0308, 0x1E4E),
+    (0x00D6, 0x0304, 0x022A),
+    (0x00D8, 0x0301, 0x01FE),
+    (0x00DC, 0x0300, 0x01DB),
+    (0x00DC, 0x0301, 0x01D7),
+    (0x00DC, 0x0304, 0x01D5),
+    (0x00DC, 0x030C, 0x01D9),
+    (0x00E2, 0x0300, 0x1EA7),
+    (0x00E2, 0x0301, 0x1EA5),
+    (0x00E2, 0x0303, 0x1EAB),
+    (0x00E2, 0x0309, 0x1EA9),
+    (0x00E4, 0x0304, 0x01DF),
+    (0x00E5, 0x0301, 0x01FB),
+    (0x00E6, 0x0301, 0x01FD),
+    (0x00E6, 0x0304, 0x01E3),
+    (0x00E7, 0x0301, 0x1E09),
+    (0x00EA, 0x0300, 0x1EC1),
+    (0x00EA, 0x0301, 0x1EBF),
+    (0x00EA, 0x0303, 0x1EC5),
+    (0x00EA, 0x0309, 0x1EC3),
+    (0x00EF, 0x0301, 0x1E2F),
+    (0x00F4, 0x0300, 0x1ED3),
+    (0x00F4, 0x0301, 0x1ED1),
+    (0x00F4, 0x0303, 0x1ED7),
+    (0x00F4, 0x0309, 0x1ED5),
+    (0x00F5, 0x0301, 0x1E4D),
+    (0x00F5, 0x0304, 0x022D),
+    (0x00F5, 0x0308, 0x1E4F),
+    (0x00F6, 0x0304, 0x022B),
+    (0x00F8, 0x0301, 0x01FF),
+    (0x00FC, 0x0300, 0x01DC),
+    (0x00FC, 0x0301, 0x01D8),
+    (0x00FC, 0x0304, 0x01D6),
+    (0x00FC, 0x030C, 0x01DA),
+    (0x0102, 0x0300, 0x1EB0),
+    (0x0102, 0x0301, 0x1EAE),
+    (0x0102, 0x0303, 0x1EB4),
+    (0x0102, 0x0309, 0x1EB2),
+    (0x0103, 0x0300, 0x1EB1),
+    (0x0103, 0x0301, 0x1EAF),
+    (0x0103, 0x0303, 0x1EB5),
+    (0x0103, 0x0309, 0x1EB3),
+    (0x0112, 0x0300, 0x1E14),
+    (0x0112, 0x0301, 0x1E16),
+    (0x0113, 0x0300, 0x1E15),
+    (0x0113, 0x0301, 0x1E17),
+    (0x014C, 0x0300, 0x1E50),
+    (0x014C, 0x0301, 0x1E52),
+    (0x014D, 0x0300, 0x1E51),
+    (0x014D, 0x0301, 0x1E53),
+    (0x015A, 0x0307, 0x1E64),
+    (0x015B, 0x0307, 0x1E65),
+    (0x0160, 0x0307, 0x1E66),
+    (0x0161, 0x0307, 0x1E67),
+    (0x0168, 0x0301, 0x1E78),
+    (0x0169, 0x0301, 0x1E79),
+    (0x016A, 0x0308, 0x1E7A),
+    (0x016B, 0x0308, 0x1E7B),
+    (0x017F, 0x0307, 0x1E9B),
+    (0x01A0, 0x0300, 0x1EDC),
+    (0x01A0, 0x0301, 0x1EDA),
+    (0x01A0, 0x0303, 0x1EE0),
+    (0x01A0, 0x0309, 0x1EDE),
+    (0x01A0, 0x0323, 0x1EE2),
+    (0x01A1, 0x0300, 0x1EDD),
+    (0x01A1, 0x0301, 0x1EDB),
+    (0x01A1, 0x0303, 0x1EE1),
+    (0x01A1, 0x0309, 0x1EDF),
+    (0x01A1, 0x0323, 0x1EE3),
+    (0x01AF, 0x0300, 0x1EEA),
+    (0x01AF, 0x0301, 0x1EE8),
+    (0x01AF, 0x0303, 0x1EEE),
+    (0x01AF, 0x0309, 0x1EEC),
+    (0x01AF, 0x0323, 0x1EF0),
+    (0x01B0, 0x0300, 0x1EEB),
+    (0x01B0, 0x0301, 0x1EE9),
+    (0x01B0, 0x0303, 0x1EEF),
+    (0x01B0, 0x0309, 0x1EED),
+    (0x01B0, 0x0323, 0x1EF1),
+    (0x01B7, 0x030C, 0x01EE),
+    (0x01EA, 0x0304, 0x01EC),
+    (0x01EB, 0x0304, 0x01ED),
+    (0x0226, 0x0304, 0x01E0),
+    (0x0227, 0x0304, 0x01E1),
+    (0x0228, 0x0306, 0x1E1C),
+    (0x0229, 0x0306, 0x1E1D),
+    (0x022E, 0x0304, 0x0230),
+    (0x022F, 0x0304, 0x0231),
+    (0x0292, 0x030C, 0x01EF),
+    (0x0391, 0x0300, 0x1FBA),
+    (0x0391, 0x0301, 0x0386),
+    (0x0391, 0x0304, 0x1FB9),
+    (0x0391, 0x0306, 0x1FB8),
+    (0x0391, 0x0313, 0x1F08),
+    (0x0391, 0x0314, 0x1F09),
+    (0x0391, 0x0345, 0x1FBC),
+    (0x0395, 0x0300, 0x1FC8),
+    (0x0395, 0x0301, 0x0388),
+    (0x0395, 0x0313, 0x1F18),
+    (0x0395, 0x0314, 0x1F19),
+    (0x0397, 0x0300, 0x1FCA),
+    (0x0397, 0x0301, 0x0389),
+    (0x0397, 0x0313, 0x1F28),
+    (0x0397, 0x0314, 0x1F29),
+    (0x0397, 0x0345, 0x1FCC),
+    (0x0399, 0x0300, 0x1FDA),
+    (0x0399, 0x0301, 0x038A),
+    (0x0399, 0x0304, 0x1FD9),
+    (0x0399, 0x0306, 0x1FD8),
+    (0x0399, 0x0308, 0x03AA),
+    (0x0399, 0x0313, 0x1F38),
+    (0x0399, 0x0314, 0x1F39),
+    (0x039F, 0x0300, 0x1FF8),
+    (0x039F, 0x0301, 0x038C),
+    (0x039F, 0x0313, 0x1F48),
+    (0x039F, 0x0314, 0x1F49),
+    (0x03A1, 0x0314, 0x1FEC),
+    (0x03A5, 0x0300, 0x1FEA),
+    (0x03A5, 0x0301, 0x038E),
+    (0x03A5, 0x0304, 0x1FE9),
+    (0x03A5, 0x0306, 0x1FE8),
+    (0x03A5, 0x0308, 0x03AB),
+    (0x03A5, 0x0314, 0x1F59),
+    (0x03A9, 0x0300, 0x1FFA),
+    (0x03A9, 0x0301, 0x038F),
+    (0x03A9, 0x0313, 0x1F68),
+    (0x03A9, 0x0314, 0x1F69),
+    (0x03A9, 0x0345, 0x1FFC),
+    (0x03AC, 0x0345, 0x1FB4),
+    (0x03AE, 0x0345, 0x1FC4),
+    (0x03B1, 0x0300, 0x1F70),
+    (0x03B1, 0x0301, 0x03AC),
+    (0x03B1, 0x0304, 0x1FB1),
+    (0x03B1, 0x0306, 0x1FB0),
+    (0x03B1, 0x0313, 0x1F00),
+    (0x03B1, 0x0314, 0x1F01),
+    (0x03B1, 0x0342, 0x1FB6),
+    (0x03B1, 0x0345, 0x1FB3),
+    (0x03B5, 0x0300, 0x1F72),
+    (0x03B5, 0x0301, 0x03AD),
+    (0x03B5, 0x0313, 0x1F10),
+    (0x03B5, 0x0314, 0x1F11),
+    (0x03B7, 0x0300, 0x1F74),
+    (0x03B7, 0x0301, 0x03AE),
+    (0x03B7, 0x0313, 0x1F20),
+    (0x03B7, 0x0314, 0x1F21),
+    (0x03B7, 0x0342, 0x1FC6),
+    (0x03B7, 0x0345, 0x1FC3),
+    (0x03B9, 0x0300, 0x1F76),
+    (0x03B9, 0x0301, 0x03AF),
+    (0x03B9, 0x0304, 0x1FD1),
+    (0x03B9, 0x0306, 0x1FD0),
+    (0x03B9, 0x0308, 0x03CA),
+    (0x03B9, 0x0313, 0x1F30),
+    (0x03B9, 0x0314, 0x1F31),
+    (0x03B9, 0x0342, 0x1FD6),
+    (0x03BF, 0x0300, 0x1F78),
+    (0x03BF, 0x0301, 0x03CC),
+    (0x03BF, 0x0313, 0x1F40),
+    (0x03BF, 0x0314, 0x1F41),
+    (0x03C1, 0x0313, 0x1FE4),
+    (0x03C1, 0x0314, 0x1FE5),
+    (0x03C5, 0x0300, 0x1F7A),
+    (0x03C5, 0x0301, 0x03CD),
+    (0x03C5, 0x0304, 0x1FE1),
+    (0x03C5, 0x0306, 0x1FE0),
+    (0x03C5, 0x0308, 0x03CB),
+    (0x03C5, 0x0313, 0x1F50),
+    (0x03C5, 0x0314, 0x1F51),
+    (0x03C5, 0x0342, 0x1FE6),
+    (0x03C9, 0x0300, 0x1F7C),
+    (0x03C9, 0x0301, 0x03CE),
+    (0x03C9, 0x0313, 0x1F60),
+    (0x03C9, 0x0314, 0x1F61),
+    (0x03C9, 0x0342, 0x1FF6),
+    (0x03C9, 0x0345, 0x1FF3),
+    (0x03CA, 0x0300, 0x1FD2),
+    (0x03CA, 0x0301, 0x0390),
+    (0x03CA, 0x0342, 0x1FD7),
+    (0x03CB, 0x0300, 0x1FE2),
+    (0x03CB, 0x0301, 0x03B0),
+    (0x03CB, 0x0342, 0x1FE7),
+    (0x03CE, 0x0345, 0x1FF4),
+    (0x03D2, 0x0301, 0x03D3),
+    (0x03D2, 0x0308, 0x03D4),
+    (0x0406, 0x0308, 0x0407),
+    (0x0410, 0x0306, 0x04D0),
+    (0x0410, 0x0308, 0x04D2),
+    (0x0413, 0x0301, 0x0403),
+    (0x0415, 0x0300, 0x0400),
+    (0x0415, 0x0306, 0x04D6),
+    (0x0415, 0x0308, 0x0401),
+    (0x0416, 0x0306, 0x04C1),
+    (0x0416, 0x0308, 0x04DC),
+    (0x0417, 0x0308, 0x04DE),
+    (0x0418, 0x0300, 0x040D),
+    (0x0418, 0x0304, 0x04E2),
+    (0x0418, 0x0306, 0x0419),
+    (0x0418, 0x0308, 0x04E4),
+    (0x041A, 0x0301, 0x040C),
+    (0x041E, 0x0308, 0x04E6),
+    (0x0423, 0x0304, 0x04EE),
+    (0x0423, 0x0306, 0x040E),
+    (0x0423, 0x0308, 0x04F0),
+    (0x0423, 0x030B, 0x04F2),
+    (0x0427, 0x0308, 0x04F4),
+    (0x042B, 0x0308, 0x04F8),
+    (0x042D, 0x0308, 0x04EC),
+    (0x0430, 0x0306, 0x04D1),
+    (0x0430, 0x0308, 0x04D3),
+    (0x0433, 0x0301, 0x0453),
+    (0x0435, 0x0300, 0x0450),
+    (0x0435, 0x0306, 0x04D7),
+    (0x0435, 0x0308, 0x0451),
+    (0x0436, 0x0306, 0x04C2),
+    (0x0436, 0x0308, 0x04DD),
+    (0x0437, 0x0308, 0x04DF),
+    (0x0438, 0x0300, 0x045D),
+    (0x0438, 0x0304, 0x04E3),
+    (0x0438, 0x0306, 0x0439),
+    (0x0438, 0x0308, 0x04E5),
+    (0x043A, 0x0301, 0x045C),
+    (0x043E, 0x0308, 0x04E7),
+    (0x0443, 0x0304, 0x04EF),
+    (0x0443, 0x0306, 0x045E),
+    (0x0443, 0x0308, 0x04F1),
+    (0x0443, 0x030B, 0x04F3),
+    (0x0447, 0x0308, 0x04F5),
+    (0x044B, 0x0308, 0x04F9),
+    (0x044D, 0x0308, 0x04ED),
+    (0x0456, 0x0308, 0x0457),
+    (0x0474, 0x030F, 0x0476),
+    (0x0475, 0x030F, 0x0477),
+    (0x04D8, 0x0308, 0x04DA),
+    (0x04D9, 0x0308, 0x04DB),
+    (0x04E8, 0x0308, 0x04EA),
+    (0x04E9, 0x0308, 0x04EB),
+    (0x0627, 0x0653, 0x0622),
+    (0x0627, 0x0654, 0x0623),
+    (0x0627, 0x0655, 0x0625),
+    (0x0648, 0x0654, 0x0624),
+    (0x064A, 0x0654, 0x0626),
+    (0x06C1, 0x0654, 0x06C2),
+    (0x06D2, 0x0654, 0x06D3),
+    (0x06D5, 0x0654, 0x06C0),
+    (0x0928, 0x093C, 0x0929),
+    (0x0930, 0x093C, 0x0931),
+    (0x0933, 0x093C, 0x0934),
+    (0x09C7, 0x09BE, 0x09CB),
+    (0x09C7, 0x09D7, 0x09CC),
+    (0x0B47, 0x0B3E, 0x0B4B),
+    (0x0B47, 0x0B56, 0x0B48),
+    (0x0B47, 0x0B57, 0x0B4C),
+    (0x0B92, 0x0BD7, 0x0B94),
+    (0x0BC6, 0x0BBE, 0x0BCA),
+    (0x0BC6, 0x0BD7, 0x0BCC),
+    (0x0BC7, 0x0BBE, 0x0BCB),
+    (0x0C46, 0x0C56, 0x0C48),
+    (0x0CBF, 0x0CD5, 0x0CC0),
+    (0x0CC6, 0x0CC2, 0x0CCA),
+    (0x0CC6, 0x0CD5, 0x0CC7),
+    (0x0CC6, 0x0CD6, 0x0CC8),
+    (0x0CCA, 0x0CD5, 0x0CCB),
+    (0x0D46, 0x0D3E, 0x0D4A),
+    (0x0D46, 0x0D57, 0x0D4C),
+    (0x0D47, 0x0D3E, 0x0D4B),
+    (0x0DD9, 0x0DCA, 0x0DDA),
+    (0x0DD9, 0x0DCF, 0x0DDC),
+    (0x0DD9, 0x0DDF, 0x0DDE),
+    (0x0DDC, 0x0DCA, 0x0DDD),
+    (0x1025, 0x102E, 0x1026),
+    (0x1B05, 0x1B35, 0x1B06),
+    (0x1B07, 0x1B35, 0x1B08),
+    (0x1B09, 0x1B35, 0x1B0A),
+    (0x1B0B, 0x1B35, 0x1B0C),
+    (0x1B0D, 0x1B35, 0x1B0E),
+    (0x1B11, 0x1B35, 0x1B12),
+    (0x1B3A, 0x1B35, 0x1B3B),
+    (0x1B3C, 0x1B35, 0x1B3D),
+    (0x1B3E, 0x1B35, 0x1B40),
+    (0x1B3F, 0x1B35, 0x1B41),
+    (0x1B42, 0x1B35, 0x1B43),
+    (0x1E36, 0x0304, 0x1E38),
+    (0x1E37, 0x0304, 0x1E39),
+    (0x1E5A, 0x0304, 0x1E5C),
+    (0x1E5B, 0x0304, 0x1E5D),
+    (0x1E62, 0x0307, 0x1E68),
+    (0x1E63, 0x0307, 0x1E69),
+    (0x1EA0, 0x0302, 0x1EAC),
+    (0x1EA0, 0x0306, 0x1EB6),
+    (0x1EA1, 0x0302, 0x1EAD),
+    (0x1EA1, 0x0306, 0x1EB7),
+    (0x1EB8, 0x0302, 0x1EC6),
+    (0x1EB9, 0x0302, 0x1EC7),
+    (0x1ECC, 0x0302, 0x1ED8),
+    (0x1ECD, 0x0302, 0x1ED9),
+    (0x1F00, 0x0300, 0x1F02),
+    (0x1F00, 0x0301, 0x1F04),
+    (0x1F00, 0x0342, 0x1F06),
+    (0x1F00, 0x0345, 0x1F80),
+    (0x1F01, 0x0300, 0x1F03),
+    (0x1F01, 0x0301, 0x1F05),
+    (0x1F01, 0x0342, 0x1F07),
+    (0x1F01, 0x0345, 0x1F81),
+    (0x1F02, 0x0345, 0x1F82),
+    (0x1F03, 0x0345, 0x1F83),
+    (0x1F04, 0x0345, 0x1F84),
+    (0x1F05, 0x0345, 0x1F85),
+    (0x1F06, 0x0345, 0x1F86),
+    (0x1F07, 0x0345, 0x1F87),
+    (0x1F08, 0x0300, 0x1F0A),
+    (0x1F08, 0x0301, 0x1F0C),
+    (0x1F08, 0x0342, 0x1F0E),
+    (0x1F08, 0x0345, 0x1F88),
+    (0x1F09, 0x0300, 0x1F0B),
+    (0x1F09, 0x0301, 0x1F0D),
+    (0x1F09, 0x0342, 0x1F0F),
+    (0x1F09, 0x0345, 0x1F89),
+    (0x1F0A, 0x0345, 0x1F8A),
+    (0x1F0B, 0x0345, 0x1F8B),
+    (0x1F0C, 0x0345, 0x1F8C),
+    (0x1F0D, 0x0345, 0x1F8D),
+    (0x1F0E, 0x0345, 0x1F8E),
+    (0x1F0F, 0x0345, 0x1F8F),
+    (0x1F10, 0x0300, 0x1F12),
+    (0x1F10, 0x0301, 0x1F14),
+    (0x1F11, 0x0300, 0x1F13),
+    (0x1F11, 0x0301, 0x1F15),
+    (0x1F18, 0x0300, 0x1F1A),
+    (0x1F18, 0x0301, 0x1F1C),
+    (0x1F19, 0x0300, 0x1F1B),
+    (0x1F19, 0x0301, 0x1F1D),
+    (0x1F20, 0x0300, 0x1F22),
+    (0x1F20, 0x0301, 0x1F24),
+    (0x1F20, 0x0342, 0x1F26),
+    (0x1F20, 0x0345, 0x1F90),
+    (0x1F21, 0x0300, 0x1F23),
+    (0x1F21, 0x0301, 0x1F25),
+    (0x1F21, 0x0342, 0x1F27),
+    (0x1F21, 0x0345, 0x1F91),
+    (0x1F22, 0x0345, 0x1F92),
+    (0x1F23, 0x0345, 0x1F93),
+    (0x1F24, 0x0345, 0x1F94),
+    (0x1F25, 0x0345, 0x1F95),
+    (0x1F26, 0x0345, 0x1F96),
+    (0x1F27, 0x0345, 0x1F97),
+    (0x1F28, 0x0300, 0x1F2A),
+    (0x1F28, 0x0301, 0x1F2C),
+    (0x1F28, 0x0342, 0x1F2E),
+    (0x1F28, 0x0345, 0x1F98),
+    (0x1F29, 0x0300, 0x1F2B),
+    (0x1F29, 0x0301, 0x1F2D),
+    (0x1F29, 0x0342, 0x1F2F),
+    (0x1F29, 0x0345, 0x1F99),
+    (0x1F2A, 0x0345, 0x1F9A),
+    (0x1F2B, 0x0345, 0x1F9B),
+    (0x1F2C, 0x0345, 0x1F9C),
+    (0x1F2D, 0x0345, 0x1F9D),
+    (0x1F2E, 0x0345, 0x1F9E),
+    (0x1F2F, 0x0345, 0x1F9F),
+    (0x1F30, 0x0300, 0x1F32),
+    (0x1F30, 0x0301, 0x1F34),
+    (0x1F30, 0x0342, 0x1F36),
+    (0x1F31, 0x0300, 0x1F33),
+    (0x1F31, 0x0301, 0x1F35),
+    (0x1F31, 0x0342, 0x1F37),
+    (0x1F38, 0x0300, 0x1F3A),
+    (0x1F38, 0x0301, 0x1F3C),
+    (0x1F38, 0x0342, 0x1F3E),
+    (0x1F39, 0x0300, 0x1F3B),
+    (0x1F39, 0x0301, 0x1F3D),
+    (0x1F39, 0x0342, 0x1F3F),
+    (0x1F40, 0x0300, 0x1F42),
+    (0x1F40, 0x0301, 0x1F44),
+    (0x1F41, 0x0300, 0x1F43),
+    (0x1F41, 0x0301, 0x1F45),
+    (0x1F48, 0x0300, 0x1F4A),
+    (0x1F48, 0x0301, 0x1F4C),
+    (0x1F49, 0x0300, 0x1F4B),
+    (0x1F49, 0x0301, 0x1F4D),
+    (0x1F50, 0x0300, 0x1F52),
+    (0x1F50, 0x0301, 0x1F54),
+    (0x1F50, 0x0342, 0x1F56),
+    (0x1F51, 0x0300, 0x1F53),
+    (0x1F51, 0x0301, 0x1F55),
+    (0x1F51, 0x0342, 0x1F57),
+    (0x1F59, 0x0300, 0x1F5B),
+    (0x1F59, 0x0301, 0x1F5D),
+    (0x1F59, 0x0342, 0x1F5F),
+    (0x1F60, 0x0300, 0x1F62),
+    (0x1F60, 0x0301, 0x1F64),
+    (0x1F60, 0x0342, 0x1F66),
+    (0x1F60, 0x0345, 0x1FA0),
+    (0x1F61, 0x0300, 0x1F63),
+    (0x1F61, 0x0301, 0x1F65),
+    (0x1F61, 0x0342, 0x1F67),
+    (0x1F61, 0x0345, 0x1FA1),
+    (0x1F62, 0x0345, 0x1FA2),
+    (0x1F63, 0x0345, 0x1FA3),
+    (0x1F64, 0x0345, 0x1FA4),
+    (0x1F65, 0x0345, 0x1FA5),
+    (0x1F66, 0x0345, 0x1FA6),
+    (0x1F67, 0x0345, 0x1FA7),
+    (0x1F68, 0x0300, 0x1F6A),
+    (0x1F68, 0x0301, 0x1F6C),
+    (0x1F68, 0x0342, 0x1F6E),
+    (0x1F68, 0x0345, 0x1FA8),
+    (0x1F69, 0x0300, 0x1F6B),
+    (0x1F69, 0x0301, 0x1F6D),
+    (0x1F69, 0x0342, 0x1F6F),
+    (0x1F69, 0x0345, 0x1FA9),
+    (0x1F6A, 0x0345, 0x1FAA),
+    (0x1F6B, 0x0345, 0x1FAB),
+    (0x1F6C, 0x0345, 0x1FAC),
+    (0x1F6D, 0x0345, 0x1FAD),
+    (0x1F6E, 0x0345, 0x1FAE),
+    (0x1F6F, 0x0345, 0x1FAF),
+    (0x1F70, 0x0345, 0x1FB2),
+    (0x1F74, 0x0345, 0x1FC2),
+    (0x1F7C, 0x0345, 0x1FF2),
+    (0x1FB6, 0x0345, 0x1FB7),
+    (0x1FBF, 0x0300, 0x1FCD),
+    (0x1FBF, 0x0301, 0x1FCE),
+    (0x1FBF, 0x0342, 0x1FCF),
+    (0x1FC6, 0x0345, 0x1FC7),
+    (0x1FF6, 0x0345, 0x1FF7),
+    (0x1FFE, 0x0300, 0x1FDD),
+    (0x1FFE, 0x0301, 0x1FDE),
+    (0x1FFE, 0x0342, 0x1FDF),
+    (0x2190, 0x0338, 0x219A),
+    (0x2192, 0x0338, 0x219B),
+    (0x2194, 0x0338, 0x21AE),
+    (0x21D0, 0x0338, 0x21CD),
+    (0x21D2, 0x0338, 0x21CF),
+    (0x21D4, 0x0338, 0x21CE),
+    (0x2203, 0x0338, 0x2204),
+    (0x2208, 0x0338, 0x2209),
+    (0x220B, 0x0338, 0x220C),
+    (0x2223, 0x0338, 0x2224),
+    (0x2225, 0x0338, 0x2226),
+    (0x223C, 0x0338, 0x2241),
+    (0x2243, 0x0338, 0x2244),
+    (0x2245, 0x0338, 0x2247),
+    (0x2248, 0x0338, 0x2249),
+    (0x224D, 0x0338, 0x226D),
+    (0x2261, 0x0338, 0x2262),
+    (0x2264, 0x0338, 0x2270),
+    (0x2265, 0x0338, 0x2271),
+    (0x2272, 0x0338, 0x2274),
+    (0x2273, 0x0338, 0x2275),
+    (0x2276, 0x0338, 0x2278),
+    (0x2277, 0x0338, 0x2279),
+    (0x227A, 0x0338, 0x2280),
+    (0x227B, 0x0338, 0x2281),
+    (0x227C, 0x0338, 0x22E0),
+    (0x227D, 0x0338, 0x22E1),
+    (0x2282, 0x0338, 0x2284),
+    (0x2283, 0x0338, 0x2285),
+    (0x2286, 0x0338, 0x2288),
+    (0x2287, 0x0338, 0x2289),
+    (0x2291, 0x0338, 0x22E2),
+    (0x2292, 0x0338, 0x22E3),
+    (0x22A2, 0x0338, 0x22AC),
+    (0x22A8, 0x0338, 0x22AD),
+    (0x22A9, 0x0338, 0x22AE),
+    (0x22AB, 0x0338, 0x22AF),
+    (0x22B2, 0x0338, 0x22EA),
+    (0x22B3, 0x0338, 0x22EB),
+    (0x22B4, 0x0338, 0x22EC),
+    (0x22B5, 0x0338, 0x22ED),
+    (0x3046, 0x3099, 0x3094),
+    (0x304B, 0x3099, 0x304C),
+    (0x304D, 0x3099, 0x304E),
+    (0x304F, 0x3099, 0x3050),
+    (0x3051, 0x3099, 0x3052),
+    (0x3053, 0x3099, 0x3054),
+    (0x3055, 0x3099, 0x3056),
+    (0x3057, 0x3099, 0x3058),
+    (0x3059, 0x3099, 0x305A),
+    (0x305B, 0x3099, 0x305C),
+    (0x305D, 0x3099, 0x305E),
+    (0x305F, 0x3099, 0x3060),
+    (0x3061, 0x3099, 0x3062),
+    (0x3064, 0x3099, 0x3065),
+    (0x3066, 0x3099, 0x3067),
+    (0x3068, 0x3099, 0x3069),
+    (0x306F, 0x3099, 0x3070),
+    (0x306F, 0x309A, 0x3071),
+    (0x3072, 0x3099, 0x3073),
+    (0x3072, 0x309A, 0x3074),
+    (0x3075, 0x3099, 0x3076),
+    (0x3075, 0x309A, 0x3077),
+    (0x3078, 0x3099, 0x3079),
+    (0x3078, 0x309A, 0x307A),
+    (0x307B, 0x3099, 0x307C),
+    (0x307B, 0x309A, 0x307D),
+    (0x309D, 0x3099, 0x309E),
+    (0x30A6, 0x3099, 0x30F4),
+    (0x30AB, 0x3099, 0x30AC),
+    (0x30AD, 0x3099, 0x30AE),
+    (0x30AF, 0x3099, 0x30B0),
+    (0x30B1, 0x3099, 0x30B2),
+    (0x30B3, 0x3099, 0x30B4),
+    (0x30B5, 0x3099, 0x30B6),
+    (0x30B7, 0x3099, 0x30B8),
+    (0x30B9, 0x3099, 0x30BA),
+    (0x30BB, 0x3099, 0x30BC),
+    (0x30BD, 0x3099, 0x30BE),
+    (0x30BF, 0x3099, 0x30C0),
+    (0x30C1, 0x3099, 0x30C2),
+    (0x30C4, 0x3099, 0x30C5),
+    (0x30C6, 0x3099, 0x30C7),
+    (0x30C8, 0x3099, 0x30C9),
+    (0x30CF, 0x3099, 0x30D0),
+    (0x30CF, 0x309A, 0x30D1),
+    (0x30D2, 0x3099, 0x30D3),
+    (0x30D2, 0x309A, 0x30D4),
+    (0x30D5, 0x3099, 0x30D6),
+    (0x30D5, 0x309A, 0x30D7),
+    (0x30D8, 0x3099, 0x30D9),
+    (0x30D8, 0x309A, 0x30DA),
+    (0x30DB, 0x3099, 0x30DC),
+    (0x30DB, 0x309A, 0x30DD),
+    (0x30EF, 0x3099, 0x30F7),
+    (0x30F0, 0x3099, 0x30F8),
+    (0x30F1, 0x3099, 0x30F9),
+    (0x30F2, 0x3099, 0x30FA),
+    (0x30FD, 0x3099, 0x30FE),
+    (0x11099, 0x110BA, 0x1109A),
+    (0x1109B, 0x110BA, 0x1109C),
+    (0x110A5, 0x110BA, 0x110AB),
+    (0x11131, 0x11127, 0x1112E),
+    (0x11132, 0x11127, 0x1112F),
+    (0x11347, 0x1133E, 0x1134B),
+    (0x11347, 0x11357, 0x1134C),
+    (0x114B9, 0x114B0, 0x114BC),
+    (0x114B9, 0x114BA, 0x114BB),
+    (0x114B9, 0x114BD, 0x114BE),
+    (0x115B8, 0x115AF, 0x115BA),
+    (0x115B9, 0x115AF, 0x115BB),
+    (0x11935, 0x11930, 0x11938),
+];
+
+pub(crate) const NFC_QUICK_CHECK_NO: &[(u32, u32)] = &[
+    (0x0340, 0x0341),
+    (0x0343, 0x0344),
+    (0x0374, 0x0374),
+    (0x037E, 0x037E),
+    (0x0387, 0x0387),
+    (0x0958, 0x095F),
+    (0x09DC, 0x09DD),
+    (0x09DF, 0x09DF),
+    (0x0A33, 0x0A33),
+    (0x0A36, 0x0A36),
+    (0x0A59, 0x0A5B),
+    (0x0A5E, 0x0A5E),
+    (0x0B5C, 0x0B5D),
+    (0x0F43, 0x0F43),
+    (0x0F4D, 0x0F4D),
+    (0x0F52, 0x0F52),
+    (0x0F57, 0x0F57),
+    (0x0F5C, 0x0F5C),
+    (0x0F69, 0x0F69),
+    (0x0F73, 0x0F73),
+    (0x0F75, 0x0F76),
+    (0x0F78, 0x0F78),
+    (0x0F81, 0x0F81),
+    (0x0F93, 0x0F93),
+    (0x0F9D, 0x0F9D),
+    (0x0FA2, 0x0FA2),
+    (0x0FA7, 0x0FA7),
+    (0x0FAC, 0x0FAC),
+    (0x0FB9, 0x0FB9),
+    (0x1F71, 0x1F71),
+    (0x1F73, 0x1F73),
+    (0x1F75, 0x1F75),
+    (0x1F77, 0x1F77),
+    (0x1F79, 0x1F79),
+    (0x1F7B, 0x1F7B),
+    (0x1F7D, 0x1F7D),
+    (0x1FBB, 0x1FBB),
+    (0x1FBE, 0x1FBE),
+    (0x1FC9, 0x1FC9),
+    (0x1FCB, 0x1FCB),
+    (0x1FD3, 0x1FD3),
+    (0x1FDB, 0x1FDB),
+    (0x1FE3, 0x1FE3),
+    (0x1FEB, 0x1FEB),
+    (0x1FEE, 0x1FEF),
+    (0x1FF9, 0x1FF9),
+    (0x1FFB, 0x1FFB),
+    (0x1FFD, 0x1FFD),
+    (0x2000, 0x2001),
+    (0x2126, 0x2126),
+    (0x212A, 0x212B),
+    (0x2329, 0x232A),
+    (0x2ADC, 0x2ADC),
+    (0xF900, 0xFA0D),
+    (0xFA10, 0xFA10),
+    (0xFA12, 0xFA12),
+    (0xFA15, 0xFA1E),
+    (0xFA20, 0xFA20),
+    (0xFA22, 0xFA22),
+    (0xFA25, 0xFA26),
+    (0xFA2A, 0xFA6D),
+    (0xFA70, 0xFAD9),
+    (0xFB1D, 0xFB1D),
+    (0xFB1F, 0xFB1F),
+    (0xFB2A, 0xFB36),
+    (0xFB38, 0xFB3C),
+    (0xFB3E, 0xFB3E),
+    (0xFB40, 0xFB41),
+    (0xFB43, 0xFB44),
+    (0xFB46, 0xFB4E),
+    (0x1D15E, 0x1D164),
+    (0x1D1BB, 0x1D1C0),
+    (0x2F800, 0x2FA1D),
+];
+
+pub(crate) const NFC_QUICK_CHECK_MAYBE: &[(u32, u32)] = &[
+    (0x0300, 0x0304),
+    (0x0306, 0x030C),
+    (0x030F, 0x030F),
+    (0x0311, 0x0311),
+    (0x0313, 0x0314),
+    (0x031B, 0x031B),
+    (0x0323, 0x0328),
+    (0x032D, 0x032E),
+    (0x0330, 0x0331),
+    (0x0338, 0x0338),
+    (0x0342, 0x0342),
+    (0x0345, 0x0345),
+    (0x0653, 0x0655),
+    (0x093C, 0x093C),
+    (0x09BE, 0x09BE),
+    (0x09D7, 0x09D7),
+    (0x0B3E, 0x0B3E),
+    (0x0B56, 0x0B57),
+    (0x0BBE, 0x0BBE),
+    (0x0BD7, 0x0BD7),
+    (0x0C56, 0x0C56),
+    (0x0CC2, 0x0CC2),
+    (0x0CD5, 0x0CD6),
+    (0x0D3E, 0x0D3E),
+    (0x0D57, 0x0D57),
+    (0x0DCA, 0x0DCA),
+    (0x0DCF, 0x0DCF),
+    (0x0DDF, 0x0DDF),
+    (0x102E, 0x102E),
+    (0x1161, 0x1175),
+    (0x11A8, 0x11C2),
+    (0x1B35, 0x1B35),
+    (0x3099, 0x309A),
+    (0x110BA, 0x110BA),
+    (0x11127, 0x11127),
+    (0x1133E, 0x1133E),
+    (0x11357, 0x11357),
+    (0x114B0, 0x114B0),
+    (0x114BA, 0x114BA),
+    (0x114BD, 0x114BD),
+    (0x115AF, 0x115AF),
+    (0x11930, 0x11930),
+];
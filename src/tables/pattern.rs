@@ -0,0 +1,41 @@
+// Generated by `scripts/unicode.pl` from the Unicode 14.0.0
+// character database. Do not edit by hand.
+
+pub(crate) const PATTERN_SYNTAX: &[(u32, u32)] = &[
+    (0x0021, 0x002F),
+    (0x003A, 0x0040),
+    (0x005B, 0x005E),
+    (0x0060, 0x0060),
+    (0x007B, 0x007E),
+    (0x00A1, 0x00A7),
+    (0x00A9, 0x00A9),
+    (0x00AB, 0x00AC),
+    (0x00AE, 0x00AE),
+    (0x00B0, 0x00B1),
+    (0x00B6, 0x00B6),
+    (0x00BB, 0x00BB),
+    (0x00BF, 0x00BF),
+    (0x00D7, 0x00D7),
+    (0x00F7, 0x00F7),
+    (0x2010, 0x2027),
+    (0x2030, 0x203E),
+    (0x2041, 0x2053),
+    (0x2055, 0x205E),
+    (0x2190, 0x245F),
+    (0x2500, 0x2775),
+    (0x2794, 0x2BFF),
+    (0x2E00, 0x2E7F),
+    (0x3001, 0x3003),
+    (0x3008, 0x3020),
+    (0x3030, 0x3030),
+    (0xFD3E, 0xFD3F),
+    (0xFE45, 0xFE46),
+];
+
+pub(crate) const PATTERN_WHITE_SPACE: &[(u32, u32)] = &[
+    (0x0009, 0x000D),
+    (0x0020, 0x0020),
+    (0x0085, 0x0085),
+    (0x200E, 0x200F),
+    (0x2028, 0x2029),
+];
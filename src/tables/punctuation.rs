@@ -0,0 +1,154 @@
+// Generated by `scripts/unicode.pl` from the Unicode 14.0.0
+// character database. Do not edit by hand.
+
+pub(crate) const DASH: &[(u32, u32)] = &[
+    (0x002D, 0x002D),
+    (0x058A, 0x058A),
+    (0x05BE, 0x05BE),
+    (0x1400, 0x1400),
+    (0x1806, 0x1806),
+    (0x2010, 0x2015),
+    (0x2053, 0x2053),
+    (0x207B, 0x207B),
+    (0x208B, 0x208B),
+    (0x2212, 0x2212),
+    (0x2E17, 0x2E17),
+    (0x2E1A, 0x2E1A),
+    (0x2E3A, 0x2E3B),
+    (0x2E40, 0x2E40),
+    (0x2E5D, 0x2E5D),
+    (0x301C, 0x301C),
+    (0x3030, 0x3030),
+    (0x30A0, 0x30A0),
+    (0xFE31, 0xFE32),
+    (0xFE58, 0xFE58),
+    (0xFE63, 0xFE63),
+    (0xFF0D, 0xFF0D),
+    (0x10EAD, 0x10EAD),
+];
+
+pub(crate) const QUOTATION_MARK: &[(u32, u32)] = &[
+    (0x0022, 0x0022),
+    (0x0027, 0x0027),
+    (0x00AB, 0x00AB),
+    (0x00BB, 0x00BB),
+    (0x2018, 0x201F),
+    (0x2039, 0x203A),
+    (0x2E42, 0x2E42),
+    (0x300C, 0x300F),
+    (0x301D, 0x301F),
+    (0xFE41, 0xFE44),
+    (0xFF02, 0xFF02),
+    (0xFF07, 0xFF07),
+    (0xFF62, 0xFF63),
+];
+
+pub(crate) const TERMINAL_PUNCTUATION: &[(u32, u32)] = &[
+    (0x0021, 0x0021),
+    (0x002C, 0x002C),
+    (0x002E, 0x002E),
+    (0x003A, 0x003B),
+    (0x003F, 0x003F),
+    (0x037E, 0x037E),
+    (0x0387, 0x0387),
+    (0x0589, 0x0589),
+    (0x05C3, 0x05C3),
+    (0x060C, 0x060C),
+    (0x061B, 0x061B),
+    (0x061D, 0x061F),
+    (0x06D4, 0x06D4),
+    (0x0700, 0x070A),
+    (0x070C, 0x070C),
+    (0x07F8, 0x07F9),
+    (0x0830, 0x083E),
+    (0x085E, 0x085E),
+    (0x0964, 0x0965),
+    (0x0E5A, 0x0E5B),
+    (0x0F08, 0x0F08),
+    (0x0F0D, 0x0F12),
+    (0x104A, 0x104B),
+    (0x1361, 0x1368),
+    (0x166E, 0x166E),
+    (0x16EB, 0x16ED),
+    (0x1735, 0x1736),
+    (0x17D4, 0x17D6),
+    (0x17DA, 0x17DA),
+    (0x1802, 0x1805),
+    (0x1808, 0x1809),
+    (0x1944, 0x1945),
+    (0x1AA8, 0x1AAB),
+    (0x1B5A, 0x1B5B),
+    (0x1B5D, 0x1B5F),
+    (0x1B7D, 0x1B7E),
+    (0x1C3B, 0x1C3F),
+    (0x1C7E, 0x1C7F),
+    (0x203C, 0x203D),
+    (0x2047, 0x2049),
+    (0x2E2E, 0x2E2E),
+    (0x2E3C, 0x2E3C),
+    (0x2E41, 0x2E41),
+    (0x2E4C, 0x2E4C),
+    (0x2E4E, 0x2E4F),
+    (0x2E53, 0x2E54),
+    (0x3001, 0x3002),
+    (0xA4FE, 0xA4FF),
+    (0xA60D, 0xA60F),
+    (0xA6F3, 0xA6F7),
+    (0xA876, 0xA877),
+    (0xA8CE, 0xA8CF),
+    (0xA92F, 0xA92F),
+    (0xA9C7, 0xA9C9),
+    (0xAA5D, 0xAA5F),
+    (0xAADF, 0xAADF),
+    (0xAAF0, 0xAAF1),
+    (0xABEB, 0xABEB),
+    (0xFE50, 0xFE52),
+    (0xFE54, 0xFE57),
+    (0xFF01, 0xFF01),
+    (0xFF0C, 0xFF0C),
+    (0xFF0E, 0xFF0E),
+    (0xFF1A, 0xFF1B),
+    (0xFF1F, 0xFF1F),
+    (0xFF61, 0xFF61),
+    (0xFF64, 0xFF64),
+    (0x1039F, 0x1039F),
+    (0x103D0, 0x103D0),
+    (0x10857, 0x10857),
+    (0x1091F, 0x1091F),
+    (0x10A56, 0x10A57),
+    (0x10AF0, 0x10AF5),
+    (0x10B3A, 0x10B3F),
+    (0x10B99, 0x10B9C),
+    (0x10F55, 0x10F59),
+    (0x10F86, 0x10F89),
+    (0x11047, 0x1104D),
+    (0x110BE, 0x110C1),
+    (0x11141, 0x11143),
+    (0x111C5, 0x111C6),
+    (0x111CD, 0x111CD),
+    (0x111DE, 0x111DF),
+    (0x11238, 0x1123C),
+    (0x112A9, 0x112A9),
+    (0x1144B, 0x1144D),
+    (0x1145A, 0x1145B),
+    (0x115C2, 0x115C5),
+    (0x115C9, 0x115D7),
+    (0x11641, 0x11642),
+    (0x1173C, 0x1173E),
+    (0x11944, 0x11944),
+    (0x11946, 0x11946),
+    (0x11A42, 0x11A43),
+    (0x11A9B, 0x11A9C),
+    (0x11AA1, 0x11AA2),
+    (0x11C41, 0x11C43),
+    (0x11C71, 0x11C71),
+    (0x11EF7, 0x11EF8),
+    (0x12470, 0x12474),
+    (0x16A6E, 0x16A6F),
+    (0x16AF5, 0x16AF5),
+    (0x16B37, 0x16B39),
+    (0x16B44, 0x16B44),
+    (0x16E97, 0x16E98),
+    (0x1BC9F, 0x1BC9F),
+    (0x1DA87, 0x1DA8A),
+];
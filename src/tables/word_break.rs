@@ -0,0 +1,1086 @@
+// Generated by `scripts/unicode.pl` from the Unicode 14.0.0
+// character database. Do not edit by hand.
+
+/// The Unicode `Word_Break` property of a character.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum WordBreak {
+    /// ALetter
+    ALetter,
+    /// CR
+    Cr,
+    /// Double Quote
+    DoubleQuote,
+    /// Extend
+    Extend,
+    /// ExtendNumLet
+    ExtendNumLet,
+    /// Format
+    Format,
+    /// Hebrew Letter
+    HebrewLetter,
+    /// Katakana
+    Katakana,
+    /// LF
+    Lf,
+    /// MidLetter
+    MidLetter,
+    /// MidNum
+    MidNum,
+    /// MidNumLet
+    MidNumLet,
+    /// Newline
+    Newline,
+    /// Numeric
+    Numeric,
+    /// Other
+    Other,
+    /// Regional Indicator
+    RegionalIndicator,
+    /// Single Quote
+    SingleQuote,
+    /// WSegSpace
+    WSegSpace,
+    /// ZWJ
+    Zwj,
+}
+
+impl Default for WordBreak {
+    #[inline]
+    fn default() -> Self {
+        WordBreak::Other
+    }
+}
+
+pub(crate) const WORD_BREAK: &[(u32, u32, WordBreak)] = &[
+    (0x000A, 0x000A, WordBreak::Lf),
+    (0x000B, 0x000C, WordBreak::Newline),
+    (0x000D, 0x000D, WordBreak::Cr),
+    (0x0020, 0x0020, WordBreak::WSegSpace),
+    (0x0022, 0x0022, WordBreak::DoubleQuote),
+    (0x0027, 0x0027, WordBreak::SingleQuote),
+    (0x002C, 0x002C, WordBreak::MidNum),
+    (0x002E, 0x002E, WordBreak::MidNumLet),
+    (0x0030, 0x0039, WordBreak::Numeric),
+    (0x003A, 0x003A, WordBreak::MidLetter),
+    (0x003B, 0x003B, WordBreak::MidNum),
+    (0x0041, 0x005A, WordBreak::ALetter),
+    (0x005F, 0x005F, WordBreak::ExtendNumLet),
+    (0x0061, 0x007A, WordBreak::ALetter),
+    (0x0085, 0x0085, WordBreak::Newline),
+    (0x00AA, 0x00AA, WordBreak::ALetter),
+    (0x00AD, 0x00AD, WordBreak::Format),
+    (0x00B5, 0x00B5, WordBreak::ALetter),
+    (0x00B7, 0x00B7, WordBreak::MidLetter),
+    (0x00BA, 0x00BA, WordBreak::ALetter),
+    (0x00C0, 0x00D6, WordBreak::ALetter),
+    (0x00D8, 0x00F6, WordBreak::ALetter),
+    (0x00F8, 0x02D7, WordBreak::ALetter),
+    (0x02DE, 0x02FF, WordBreak::ALetter),
+    (0x0300, 0x036F, WordBreak::Extend),
+    (0x0370, 0x0374, WordBreak::ALetter),
+    (0x0376, 0x0377, WordBreak::ALetter),
+    (0x037A, 0x037D, WordBreak::ALetter),
+    (0x037E, 0x037E, WordBreak::MidNum),
+    (0x037F, 0x037F, WordBreak::ALetter),
+    (0x0386, 0x0386, WordBreak::ALetter),
+    (0x0387, 0x0387, WordBreak::MidLetter),
+    (0x0388, 0x038A, WordBreak::ALetter),
+    (0x038C, 0x038C, WordBreak::ALetter),
+    (0x038E, 0x03A1, WordBreak::ALetter),
+    (0x03A3, 0x03F5, WordBreak::ALetter),
+    (0x03F7, 0x0481, WordBreak::ALetter),
+    (0x0483, 0x0489, WordBreak::Extend),
+    (0x048A, 0x052F, WordBreak::ALetter),
+    (0x0531, 0x0556, WordBreak::ALetter),
+    (0x0559, 0x055C, WordBreak::ALetter),
+    (0x055E, 0x055E, WordBreak::ALetter),
+    (0x055F, 0x055F, WordBreak::MidLetter),
+    (0x0560, 0x0588, WordBreak::ALetter),
+    (0x0589, 0x0589, WordBreak::MidNum),
+    (0x058A, 0x058A, WordBreak::ALetter),
+    (0x0591, 0x05BD, WordBreak::Extend),
+    (0x05BF, 0x05BF, WordBreak::Extend),
+    (0x05C1, 0x05C2, WordBreak::Extend),
+    (0x05C4, 0x05C5, WordBreak::Extend),
+    (0x05C7, 0x05C7, WordBreak::Extend),
+    (0x05D0, 0x05EA, WordBreak::HebrewLetter),
+    (0x05EF, 0x05F2, WordBreak::HebrewLetter),
+    (0x05F3, 0x05F3, WordBreak::ALetter),
+    (0x05F4, 0x05F4, WordBreak::MidLetter),
+    (0x0600, 0x0605, WordBreak::Format),
+    (0x060C, 0x060D, WordBreak::MidNum),
+    (0x0610, 0x061A, WordBreak::Extend),
+    (0x061C, 0x061C, WordBreak::Format),
+    (0x0620, 0x064A, WordBreak::ALetter),
+    (0x064B, 0x065F, WordBreak::Extend),
+    (0x0660, 0x0669, WordBreak::Numeric),
+    (0x066B, 0x066B, WordBreak::Numeric),
+    (0x066C, 0x066C, WordBreak::MidNum),
+    (0x066E, 0x066F, WordBreak::ALetter),
+    (0x0670, 0x0670, WordBreak::Extend),
+    (0x0671, 0x06D3, WordBreak::ALetter),
+    (0x06D5, 0x06D5, WordBreak::ALetter),
+    (0x06D6, 0x06DC, WordBreak::Extend),
+    (0x06DD, 0x06DD, WordBreak::Format),
+    (0x06DF, 0x06E4, WordBreak::Extend),
+    (0x06E5, 0x06E6, WordBreak::ALetter),
+    (0x06E7, 0x06E8, WordBreak::Extend),
+    (0x06EA, 0x06ED, WordBreak::Extend),
+    (0x06EE, 0x06EF, WordBreak::ALetter),
+    (0x06F0, 0x06F9, WordBreak::Numeric),
+    (0x06FA, 0x06FC, WordBreak::ALetter),
+    (0x06FF, 0x06FF, WordBreak::ALetter),
+    (0x070F, 0x070F, WordBreak::Format),
+    (0x0710, 0x0710, WordBreak::ALetter),
+    (0x0711, 0x0711, WordBreak::Extend),
+    (0x0712, 0x072F, WordBreak::ALetter),
+    (0x0730, 0x074A, WordBreak::Extend),
+    (0x074D, 0x07A5, WordBreak::ALetter),
+    (0x07A6, 0x07B0, WordBreak::Extend),
+    (0x07B1, 0x07B1, WordBreak::ALetter),
+    (0x07C0, 0x07C9, WordBreak::Numeric),
+    (0x07CA, 0x07EA, WordBreak::ALetter),
+    (0x07EB, 0x07F3, WordBreak::Extend),
+    (0x07F4, 0x07F5, WordBreak::ALetter),
+    (0x07F8, 0x07F8, WordBreak::MidNum),
+    (0x07FA, 0x07FA, WordBreak::ALetter),
+    (0x07FD, 0x07FD, WordBreak::Extend),
+    (0x0800, 0x0815, WordBreak::ALetter),
+    (0x0816, 0x0819, WordBreak::Extend),
+    (0x081A, 0x081A, WordBreak::ALetter),
+    (0x081B, 0x0823, WordBreak::Extend),
+    (0x0824, 0x0824, WordBreak::ALetter),
+    (0x0825, 0x0827, WordBreak::Extend),
+    (0x0828, 0x0828, WordBreak::ALetter),
+    (0x0829, 0x082D, WordBreak::Extend),
+    (0x0840, 0x0858, WordBreak::ALetter),
+    (0x0859, 0x085B, WordBreak::Extend),
+    (0x0860, 0x086A, WordBreak::ALetter),
+    (0x0870, 0x0887, WordBreak::ALetter),
+    (0x0889, 0x088E, WordBreak::ALetter),
+    (0x0890, 0x0891, WordBreak::Format),
+    (0x0898, 0x089F, WordBreak::Extend),
+    (0x08A0, 0x08C9, WordBreak::ALetter),
+    (0x08CA, 0x08E1, WordBreak::Extend),
+    (0x08E2, 0x08E2, WordBreak::Format),
+    (0x08E3, 0x0903, WordBreak::Extend),
+    (0x0904, 0x0939, WordBreak::ALetter),
+    (0x093A, 0x093C, WordBreak::Extend),
+    (0x093D, 0x093D, WordBreak::ALetter),
+    (0x093E, 0x094F, WordBreak::Extend),
+    (0x0950, 0x0950, WordBreak::ALetter),
+    (0x0951, 0x0957, WordBreak::Extend),
+    (0x0958, 0x0961, WordBreak::ALetter),
+    (0x0962, 0x0963, WordBreak::Extend),
+    (0x0966, 0x096F, WordBreak::Numeric),
+    (0x0971, 0x0980, WordBreak::ALetter),
+    (0x0981, 0x0983, WordBreak::Extend),
+    (0x0985, 0x098C, WordBreak::ALetter),
+    (0x098F, 0x0990, WordBreak::ALetter),
+    (0x0993, 0x09A8, WordBreak::ALetter),
+    (0x09AA, 0x09B0, WordBreak::ALetter),
+    (0x09B2, 0x09B2, WordBreak::ALetter),
+    (0x09B6, 0x09B9, WordBreak::ALetter),
+    (0x09BC, 0x09BC, WordBreak::Extend),
+    (0x09BD, 0x09BD, WordBreak::ALetter),
+    (0x09BE, 0x09C4, WordBreak::Extend),
+    (0x09C7, 0x09C8, WordBreak::Extend),
+    (0x09CB, 0x09CD, WordBreak::Extend),
+    (0x09CE, 0x09CE, WordBreak::ALetter),
+    (0x09D7, 0x09D7, WordBreak::Extend),
+    (0x09DC, 0x09DD, WordBreak::ALetter),
+    (0x09DF, 0x09E1, WordBreak::ALetter),
+    (0x09E2, 0x09E3, WordBreak::Extend),
+    (0x09E6, 0x09EF, WordBreak::Numeric),
+    (0x09F0, 0x09F1, WordBreak::ALetter),
+    (0x09FC, 0x09FC, WordBreak::ALetter),
+    (0x09FE, 0x09FE, WordBreak::Extend),
+    (0x0A01, 0x0A03, WordBreak::Extend),
+    (0x0A05, 0x0A0A, WordBreak::ALetter),
+    (0x0A0F, 0x0A10, WordBreak::ALetter),
+    (0x0A13, 0x0A28, WordBreak::ALetter),
+    (0x0A2A, 0x0A30, WordBreak::ALetter),
+    (0x0A32, 0x0A33, WordBreak::ALetter),
+    (0x0A35, 0x0A36, WordBreak::ALetter),
+    (0x0A38, 0x0A39, WordBreak::ALetter),
+    (0x0A3C, 0x0A3C, WordBreak::Extend),
+    (0x0A3E, 0x0A42, WordBreak::Extend),
+    (0x0A47, 0x0A48, WordBreak::Extend),
+    (0x0A4B, 0x0A4D, WordBreak::Extend),
+    (0x0A51, 0x0A51, WordBreak::Extend),
+    (0x0A59, 0x0A5C, WordBreak::ALetter),
+    (0x0A5E, 0x0A5E, WordBreak::ALetter),
+    (0x0A66, 0x0A6F, WordBreak::Numeric),
+    (0x0A70, 0x0A71, WordBreak::Extend),
+    (0x0A72, 0x0A74, WordBreak::ALetter),
+    (0x0A75, 0x0A75, WordBreak::Extend),
+    (0x0A81, 0x0A83, WordBreak::Extend),
+    (0x0A85, 0x0A8D, WordBreak::ALetter),
+    (0x0A8F, 0x0A91, WordBreak::ALetter),
+    (0x0A93, 0x0AA8, WordBreak::ALetter),
+    (0x0AAA, 0x0AB0, WordBreak::ALetter),
+    (0x0AB2, 0x0AB3, WordBreak::ALetter),
+    (0x0AB5, 0x0AB9, WordBreak::ALetter),
+    (0x0ABC, 0x0ABC, WordBreak::Extend),
+    (0x0ABD, 0x0ABD, WordBreak::ALetter),
+    (0x0ABE, 0x0AC5, WordBreak::Extend),
+    (0x0AC7, 0x0AC9, WordBreak::Extend),
+    (0x0ACB, 0x0ACD, WordBreak::Extend),
+    (0x0AD0, 0x0AD0, WordBreak::ALetter),
+    (0x0AE0, 0x0AE1, WordBreak::ALetter),
+    (0x0AE2, 0x0AE3, WordBreak::Extend),
+    (0x0AE6, 0x0AEF, WordBreak::Numeric),
+    (0x0AF9, 0x0AF9, WordBreak::ALetter),
+    (0x0AFA, 0x0AFF, WordBreak::Extend),
+    (0x0B01, 0x0B03, WordBreak::Extend),
+    (0x0B05, 0x0B0C, WordBreak::ALetter),
+    (0x0B0F, 0x0B10, WordBreak::ALetter),
+    (0x0B13, 0x0B28, WordBreak::ALetter),
+    (0x0B2A, 0x0B30, WordBreak::ALetter),
+    (0x0B32, 0x0B33, WordBreak::ALetter),
+    (0x0B35, 0x0B39, WordBreak::ALetter),
+    (0x0B3C, 0x0B3C, WordBreak::Extend),
+    (0x0B3D, 0x0B3D, WordBreak::ALetter),
+    (0x0B3E, 0x0B44, WordBreak::Extend),
+    (0x0B47, 0x0B48, WordBreak::Extend),
+    (0x0B4B, 0x0B4D, WordBreak::Extend),
+    (0x0B55, 0x0B57, WordBreak::Extend),
+    (0x0B5C, 0x0B5D, WordBreak::ALetter),
+    (0x0B5F, 0x0B61, WordBreak::ALetter),
+    (0x0B62, 0x0B63, WordBreak::Extend),
+    (0x0B66, 0x0B6F, WordBreak::Numeric),
+    (0x0B71, 0x0B71, WordBreak::ALetter),
+    (0x0B82, 0x0B82, WordBreak::Extend),
+    (0x0B83, 0x0B83, WordBreak::ALetter),
+    (0x0B85, 0x0B8A, WordBreak::ALetter),
+    (0x0B8E, 0x0B90, WordBreak::ALetter),
+    (0x0B92, 0x0B95, WordBreak::ALetter),
+    (0x0B99, 0x0B9A, WordBreak::ALetter),
+    (0x0B9C, 0x0B9C, WordBreak::ALetter),
+    (0x0B9E, 0x0B9F, WordBreak::ALetter),
+    (0x0BA3, 0x0BA4, WordBreak::ALetter),
+    (0x0BA8, 0x0BAA, WordBreak::ALetter),
+    (0x0BAE, 0x0BB9, WordBreak::ALetter),
+    (0x0BBE, 0x0BC2, WordBreak::Extend),
+    (0x0BC6, 0x0BC8, WordBreak::Extend),
+    (0x0BCA, 0x0BCD, WordBreak::Extend),
+    (0x0BD0, 0x0BD0, WordBreak::ALetter),
+    (0x0BD7, 0x0BD7, WordBreak::Extend),
+    (0x0BE6, 0x0BEF, WordBreak::Numeric),
+    (0x0C00, 0x0C04, WordBreak::Extend),
+    (0x0C05, 0x0C0C, WordBreak::ALetter),
+    (0x0C0E, 0x0C10, WordBreak::ALetter),
+    (0x0C12, 0x0C28, WordBreak::ALetter),
+    (0x0C2A, 0x0C39, WordBreak::ALetter),
+    (0x0C3C, 0x0C3C, WordBreak::Extend),
+    (0x0C3D, 0x0C3D, WordBreak::ALetter),
+    (0x0C3E, 0x0C44, WordBreak::Extend),
+    (0x0C46, 0x0C48, WordBreak::Extend),
+    (0x0C4A, 0x0C4D, WordBreak::Extend),
+    (0x0C55, 0x0C56, WordBreak::Extend),
+    (0x0C58, 0x0C5A, WordBreak::ALetter),
+    (0x0C5D, 0x0C5D, WordBreak::ALetter),
+    (0x0C60, 0x0C61, WordBreak::ALetter),
+    (0x0C62, 0x0C63, WordBreak::Extend),
+    (0x0C66, 0x0C6F, WordBreak::Numeric),
+    (0x0C80, 0x0C80, WordBreak::ALetter),
+    (0x0C81, 0x0C83, WordBreak::Extend),
+    (0x0C85, 0x0C8C, WordBreak::ALetter),
+    (0x0C8E, 0x0C90, WordBreak::ALetter),
+    (0x0C92, 0x0CA8, WordBreak::ALetter),
+    (0x0CAA, 0x0CB3, WordBreak::ALetter),
+    (0x0CB5, 0x0CB9, WordBreak::ALetter),
+    (0x0CBC, 0x0CBC, WordBreak::Extend),
+    (0x0CBD, 0x0CBD, WordBreak::ALetter),
+    (0x0CBE, 0x0CC4, WordBreak::Extend),
+    (0x0CC6, 0x0CC8, WordBreak::Extend),
+    (0x0CCA, 0x0CCD, WordBreak::Extend),
+    (0x0CD5, 0x0CD6, WordBreak::Extend),
+    (0x0CDD, 0x0CDE, WordBreak::ALetter),
+    (0x0CE0, 0x0CE1, WordBreak::ALetter),
+    (0x0CE2, 0x0CE3, WordBreak::Extend),
+    (0x0CE6, 0x0CEF, WordBreak::Numeric),
+    (0x0CF1, 0x0CF2, WordBreak::ALetter),
+    (0x0D00, 0x0D03, WordBreak::Extend),
+    (0x0D04, 0x0D0C, WordBreak::ALetter),
+    (0x0D0E, 0x0D10, WordBreak::ALetter),
+    (0x0D12, 0x0D3A, WordBreak::ALetter),
+    (0x0D3B, 0x0D3C, WordBreak::Extend),
+    (0x0D3D, 0x0D3D, WordBreak::ALetter),
+    (0x0D3E, 0x0D44, WordBreak::Extend),
+    (0x0D46, 0x0D48, WordBreak::Extend),
+    (0x0D4A, 0x0D4D, WordBreak::Extend),
+    (0x0D4E, 0x0D4E, WordBreak::ALetter),
+    (0x0D54, 0x0D56, WordBreak::ALetter),
+    (0x0D57, 0x0D57, WordBreak::Extend),
+    (0x0D5F, 0x0D61, WordBreak::ALetter),
+    (0x0D62, 0x0D63, WordBreak::Extend),
+    (0x0D66, 0x0D6F, WordBreak::Numeric),
+    (0x0D7A, 0x0D7F, WordBreak::ALetter),
+    (0x0D81, 0x0D83, WordBreak::Extend),
+    (0x0D85, 0x0D96, WordBreak::ALetter),
+    (0x0D9A, 0x0DB1, WordBreak::ALetter),
+    (0x0DB3, 0x0DBB, WordBreak::ALetter),
+    (0x0DBD, 0x0DBD, WordBreak::ALetter),
+    (0x0DC0, 0x0DC6, WordBreak::ALetter),
+    (0x0DCA, 0x0DCA, WordBreak::Extend),
+    (0x0DCF, 0x0DD4, WordBreak::Extend),
+    (0x0DD6, 0x0DD6, WordBreak::Extend),
+    (0x0DD8, 0x0DDF, WordBreak::Extend),
+    (0x0DE6, 0x0DEF, WordBreak::Numeric),
+    (0x0DF2, 0x0DF3, WordBreak::Extend),
+    (0x0E31, 0x0E31, WordBreak::Extend),
+    (0x0E34, 0x0E3A, WordBreak::Extend),
+    (0x0E47, 0x0E4E, WordBreak::Extend),
+    (0x0E50, 0x0E59, WordBreak::Numeric),
+    (0x0EB1, 0x0EB1, WordBreak::Extend),
+    (0x0EB4, 0x0EBC, WordBreak::Extend),
+    (0x0EC8, 0x0ECD, WordBreak::Extend),
+    (0x0ED0, 0x0ED9, WordBreak::Numeric),
+    (0x0F00, 0x0F00, WordBreak::ALetter),
+    (0x0F18, 0x0F19, WordBreak::Extend),
+    (0x0F20, 0x0F29, WordBreak::Numeric),
+    (0x0F35, 0x0F35, WordBreak::Extend),
+    (0x0F37, 0x0F37, WordBreak::Extend),
+    (0x0F39, 0x0F39, WordBreak::Extend),
+    (0x0F3E, 0x0F3F, WordBreak::Extend),
+    (0x0F40, 0x0F47, WordBreak::ALetter),
+    (0x0F49, 0x0F6C, WordBreak::ALetter),
+    (0x0F71, 0x0F84, WordBreak::Extend),
+    (0x0F86, 0x0F87, WordBreak::Extend),
+    (0x0F88, 0x0F8C, WordBreak::ALetter),
+    (0x0F8D, 0x0F97, WordBreak::Extend),
+    (0x0F99, 0x0FBC, WordBreak::Extend),
+    (0x0FC6, 0x0FC6, WordBreak::Extend),
+    (0x102B, 0x103E, WordBreak::Extend),
+    (0x1040, 0x1049, WordBreak::Numeric),
+    (0x1056, 0x1059, WordBreak::Extend),
+    (0x105E, 0x1060, WordBreak::Extend),
+    (0x1062, 0x1064, WordBreak::Extend),
+    (0x1067, 0x106D, WordBreak::Extend),
+    (0x1071, 0x1074, WordBreak::Extend),
+    (0x1082, 0x108D, WordBreak::Extend),
+    (0x108F, 0x108F, WordBreak::Extend),
+    (0x1090, 0x1099, WordBreak::Numeric),
+    (0x109A, 0x109D, WordBreak::Extend),
+    (0x10A0, 0x10C5, WordBreak::ALetter),
+    (0x10C7, 0x10C7, WordBreak::ALetter),
+    (0x10CD, 0x10CD, WordBreak::ALetter),
+    (0x10D0, 0x10FA, WordBreak::ALetter),
+    (0x10FC, 0x1248, WordBreak::ALetter),
+    (0x124A, 0x124D, WordBreak::ALetter),
+    (0x1250, 0x1256, WordBreak::ALetter),
+    (0x1258, 0x1258, WordBreak::ALetter),
+    (0x125A, 0x125D, WordBreak::ALetter),
+    (0x1260, 0x1288, WordBreak::ALetter),
+    (0x128A, 0x128D, WordBreak::ALetter),
+    (0x1290, 0x12B0, WordBreak::ALetter),
+    (0x12B2, 0x12B5, WordBreak::ALetter),
+    (0x12B8, 0x12BE, WordBreak::ALetter),
+    (0x12C0, 0x12C0, WordBreak::ALetter),
+    (0x12C2, 0x12C5, WordBreak::ALetter),
+    (0x12C8, 0x12D6, WordBreak::ALetter),
+    (0x12D8, 0x1310, WordBreak::ALetter),
+    (0x1312, 0x1315, WordBreak::ALetter),
+    (0x1318, 0x135A, WordBreak::ALetter),
+    (0x135D, 0x135F, WordBreak::Extend),
+    (0x1380, 0x138F, WordBreak::ALetter),
+    (0x13A0, 0x13F5, WordBreak::ALetter),
+    (0x13F8, 0x13FD, WordBreak::ALetter),
+    (0x1401, 0x166C, WordBreak::ALetter),
+    (0x166F, 0x167F, WordBreak::ALetter),
+    (0x1680, 0x1680, WordBreak::WSegSpace),
+    (0x1681, 0x169A, WordBreak::ALetter),
+    (0x16A0, 0x16EA, WordBreak::ALetter),
+    (0x16EE, 0x16F8, WordBreak::ALetter),
+    (0x1700, 0x1711, WordBreak::ALetter),
+    (0x1712, 0x1715, WordBreak::Extend),
+    (0x171F, 0x1731, WordBreak::ALetter),
+    (0x1732, 0x1734, WordBreak::Extend),
+    (0x1740, 0x1751, WordBreak::ALetter),
+    (0x1752, 0x1753, WordBreak::Extend),
+    (0x1760, 0x176C, WordBreak::ALetter),
+    (0x176E, 0x1770, WordBreak::ALetter),
+    (0x1772, 0x1773, WordBreak::Extend),
+    (0x17B4, 0x17D3, WordBreak::Extend),
+    (0x17DD, 0x17DD, WordBreak::Extend),
+    (0x17E0, 0x17E9, WordBreak::Numeric),
+    (0x180B, 0x180D, WordBreak::Extend),
+    (0x180E, 0x180E, WordBreak::Format),
+    (0x180F, 0x180F, WordBreak::Extend),
+    (0x1810, 0x1819, WordBreak::Numeric),
+    (0x1820, 0x1878, WordBreak::ALetter),
+    (0x1880, 0x1884, WordBreak::ALetter),
+    (0x1885, 0x1886, WordBreak::Extend),
+    (0x1887, 0x18A8, WordBreak::ALetter),
+    (0x18A9, 0x18A9, WordBreak::Extend),
+    (0x18AA, 0x18AA, WordBreak::ALetter),
+    (0x18B0, 0x18F5, WordBreak::ALetter),
+    (0x1900, 0x191E, WordBreak::ALetter),
+    (0x1920, 0x192B, WordBreak::Extend),
+    (0x1930, 0x193B, WordBreak::Extend),
+    (0x1946, 0x194F, WordBreak::Numeric),
+    (0x19D0, 0x19D9, WordBreak::Numeric),
+    (0x1A00, 0x1A16, WordBreak::ALetter),
+    (0x1A17, 0x1A1B, WordBreak::Extend),
+    (0x1A55, 0x1A5E, WordBreak::Extend),
+    (0x1A60, 0x1A7C, WordBreak::Extend),
+    (0x1A7F, 0x1A7F, WordBreak::Extend),
+    (0x1A80, 0x1A89, WordBreak::Numeric),
+    (0x1A90, 0x1A99, WordBreak::Numeric),
+    (0x1AB0, 0x1ACE, WordBreak::Extend),
+    (0x1B00, 0x1B04, WordBreak::Extend),
+    (0x1B05, 0x1B33, WordBreak::ALetter),
+    (0x1B34, 0x1B44, WordBreak::Extend),
+    (0x1B45, 0x1B4C, WordBreak::ALetter),
+    (0x1B50, 0x1B59, WordBreak::Numeric),
+    (0x1B6B, 0x1B73, WordBreak::Extend),
+    (0x1B80, 0x1B82, WordBreak::Extend),
+    (0x1B83, 0x1BA0, WordBreak::ALetter),
+    (0x1BA1, 0x1BAD, WordBreak::Extend),
+    (0x1BAE, 0x1BAF, WordBreak::ALetter),
+    (0x1BB0, 0x1BB9, WordBreak::Numeric),
+    (0x1BBA, 0x1BE5, WordBreak::ALetter),
+    (0x1BE6, 0x1BF3, WordBreak::Extend),
+    (0x1C00, 0x1C23, WordBreak::ALetter),
+    (0x1C24, 0x1C37, WordBreak::Extend),
+    (0x1C40, 0x1C49, WordBreak::Numeric),
+    (0x1C4D, 0x1C4F, WordBreak::ALetter),
+    (0x1C50, 0x1C59, WordBreak::Numeric),
+    (0x1C5A, 0x1C7D, WordBreak::ALetter),
+    (0x1C80, 0x1C88, WordBreak::ALetter),
+    (0x1C90, 0x1CBA, WordBreak::ALetter),
+    (0x1CBD, 0x1CBF, WordBreak::ALetter),
+    (0x1CD0, 0x1CD2, WordBreak::Extend),
+    (0x1CD4, 0x1CE8, WordBreak::Extend),
+    (0x1CE9, 0x1CEC, WordBreak::ALetter),
+    (0x1CED, 0x1CED, WordBreak::Extend),
+    (0x1CEE, 0x1CF3, WordBreak::ALetter),
+    (0x1CF4, 0x1CF4, WordBreak::Extend),
+    (0x1CF5, 0x1CF6, WordBreak::ALetter),
+    (0x1CF7, 0x1CF9, WordBreak::Extend),
+    (0x1CFA, 0x1CFA, WordBreak::ALetter),
+    (0x1D00, 0x1DBF, WordBreak::ALetter),
+    (0x1DC0, 0x1DFF, WordBreak::Extend),
+    (0x1E00, 0x1F15, WordBreak::ALetter),
+    (0x1F18, 0x1F1D, WordBreak::ALetter),
+    (0x1F20, 0x1F45, WordBreak::ALetter),
+    (0x1F48, 0x1F4D, WordBreak::ALetter),
+    (0x1F50, 0x1F57, WordBreak::ALetter),
+    (0x1F59, 0x1F59, WordBreak::ALetter),
+    (0x1F5B, 0x1F5B, WordBreak::ALetter),
+    (0x1F5D, 0x1F5D, WordBreak::ALetter),
+    (0x1F5F, 0x1F7D, WordBreak::ALetter),
+    (0x1F80, 0x1FB4, WordBreak::ALetter),
+    (0x1FB6, 0x1FBC, WordBreak::ALetter),
+    (0x1FBE, 0x1FBE, WordBreak::ALetter),
+    (0x1FC2, 0x1FC4, WordBreak::ALetter),
+    (0x1FC6, 0x1FCC, WordBreak::ALetter),
+    (0x1FD0, 0x1FD3, WordBreak::ALetter),
+    (0x1FD6, 0x1FDB, WordBreak::ALetter),
+    (0x1FE0, 0x1FEC, WordBreak::ALetter),
+    (0x1FF2, 0x1FF4, WordBreak::ALetter),
+    (0x1FF6, 0x1FFC, WordBreak::ALetter),
+    (0x2000, 0x2006, WordBreak::WSegSpace),
+    (0x2008, 0x200A, WordBreak::WSegSpace),
+    (0x200C, 0x200C, WordBreak::Extend),
+    (0x200D, 0x200D, WordBreak::Zwj),
+    (0x200E, 0x200F, WordBreak::Format),
+    (0x2018, 0x2019, WordBreak::MidNumLet),
+    (0x2024, 0x2024, WordBreak::MidNumLet),
+    (0x2027, 0x2027, WordBreak::MidLetter),
+    (0x2028, 0x2029, WordBreak::Newline),
+    (0x202A, 0x202E, WordBreak::Format),
+    (0x202F, 0x202F, WordBreak::ExtendNumLet),
+    (0x203F, 0x2040, WordBreak::ExtendNumLet),
+    (0x2044, 0x2044, WordBreak::MidNum),
+    (0x2054, 0x2054, WordBreak::ExtendNumLet),
+    (0x205F, 0x205F, WordBreak::WSegSpace),
+    (0x2060, 0x2064, WordBreak::Format),
+    (0x2066, 0x206F, WordBreak::Format),
+    (0x2071, 0x2071, WordBreak::ALetter),
+    (0x207F, 0x207F, WordBreak::ALetter),
+    (0x2090, 0x209C, WordBreak::ALetter),
+    (0x20D0, 0x20F0, WordBreak::Extend),
+    (0x2102, 0x2102, WordBreak::ALetter),
+    (0x2107, 0x2107, WordBreak::ALetter),
+    (0x210A, 0x2113, WordBreak::ALetter),
+    (0x2115, 0x2115, WordBreak::ALetter),
+    (0x2119, 0x211D, WordBreak::ALetter),
+    (0x2124, 0x2124, WordBreak::ALetter),
+    (0x2126, 0x2126, WordBreak::ALetter),
+    (0x2128, 0x2128, WordBreak::ALetter),
+    (0x212A, 0x212D, WordBreak::ALetter),
+    (0x212F, 0x2139, WordBreak::ALetter),
+    (0x213C, 0x213F, WordBreak::ALetter),
+    (0x2145, 0x2149, WordBreak::ALetter),
+    (0x214E, 0x214E, WordBreak::ALetter),
+    (0x2160, 0x2188, WordBreak::ALetter),
+    (0x24B6, 0x24E9, WordBreak::ALetter),
+    (0x2C00, 0x2CE4, WordBreak::ALetter),
+    (0x2CEB, 0x2CEE, WordBreak::ALetter),
+    (0x2CEF, 0x2CF1, WordBreak::Extend),
+    (0x2CF2, 0x2CF3, WordBreak::ALetter),
+    (0x2D00, 0x2D25, WordBreak::ALetter),
+    (0x2D27, 0x2D27, WordBreak::ALetter),
+    (0x2D2D, 0x2D2D, WordBreak::ALetter),
+    (0x2D30, 0x2D67, WordBreak::ALetter),
+    (0x2D6F, 0x2D6F, WordBreak::ALetter),
+    (0x2D7F, 0x2D7F, WordBreak::Extend),
+    (0x2D80, 0x2D96, WordBreak::ALetter),
+    (0x2DA0, 0x2DA6, WordBreak::ALetter),
+    (0x2DA8, 0x2DAE, WordBreak::ALetter),
+    (0x2DB0, 0x2DB6, WordBreak::ALetter),
+    (0x2DB8, 0x2DBE, WordBreak::ALetter),
+    (0x2DC0, 0x2DC6, WordBreak::ALetter),
+    (0x2DC8, 0x2DCE, WordBreak::ALetter),
+    (0x2DD0, 0x2DD6, WordBreak::ALetter),
+    (0x2DD8, 0x2DDE, WordBreak::ALetter),
+    (0x2DE0, 0x2DFF, WordBreak::Extend),
+    (0x2E2F, 0x2E2F, WordBreak::ALetter),
+    (0x3000, 0x3000, WordBreak::WSegSpace),
+    (0x3005, 0x3005, WordBreak::ALetter),
+    (0x302A, 0x302F, WordBreak::Extend),
+    (0x3031, 0x3035, WordBreak::Katakana),
+    (0x303B, 0x303C, WordBreak::ALetter),
+    (0x3099, 0x309A, WordBreak::Extend),
+    (0x309B, 0x309C, WordBreak::Katakana),
+    (0x30A0, 0x30FA, WordBreak::Katakana),
+    (0x30FC, 0x30FF, WordBreak::Katakana),
+    (0x3105, 0x312F, WordBreak::ALetter),
+    (0x3131, 0x318E, WordBreak::ALetter),
+    (0x31A0, 0x31BF, WordBreak::ALetter),
+    (0x31F0, 0x31FF, WordBreak::Katakana),
+    (0x32D0, 0x32FE, WordBreak::Katakana),
+    (0x3300, 0x3357, WordBreak::Katakana),
+    (0xA000, 0xA48C, WordBreak::ALetter),
+    (0xA4D0, 0xA4FD, WordBreak::ALetter),
+    (0xA500, 0xA60C, WordBreak::ALetter),
+    (0xA610, 0xA61F, WordBreak::ALetter),
+    (0xA620, 0xA629, WordBreak::Numeric),
+    (0xA62A, 0xA62B, WordBreak::ALetter),
+    (0xA640, 0xA66E, WordBreak::ALetter),
+    (0xA66F, 0xA672, WordBreak::Extend),
+    (0xA674, 0xA67D, WordBreak::Extend),
+    (0xA67F, 0xA69D, WordBreak::ALetter),
+    (0xA69E, 0xA69F, WordBreak::Extend),
+    (0xA6A0, 0xA6EF, WordBreak::ALetter),
+    (0xA6F0, 0xA6F1, WordBreak::Extend),
+    (0xA708, 0xA7CA, WordBreak::ALetter),
+    (0xA7D0, 0xA7D1, WordBreak::ALetter),
+    (0xA7D3, 0xA7D3, WordBreak::ALetter),
+    (0xA7D5, 0xA7D9, WordBreak::ALetter),
+    (0xA7F2, 0xA801, WordBreak::ALetter),
+    (0xA802, 0xA802, WordBreak::Extend),
+    (0xA803, 0xA805, WordBreak::ALetter),
+    (0xA806, 0xA806, WordBreak::Extend),
+    (0xA807, 0xA80A, WordBreak::ALetter),
+    (0xA80B, 0xA80B, WordBreak::Extend),
+    (0xA80C, 0xA822, WordBreak::ALetter),
+    (0xA823, 0xA827, WordBreak::Extend),
+    (0xA82C, 0xA82C, WordBreak::Extend),
+    (0xA840, 0xA873, WordBreak::ALetter),
+    (0xA880, 0xA881, WordBreak::Extend),
+    (0xA882, 0xA8B3, WordBreak::ALetter),
+    (0xA8B4, 0xA8C5, WordBreak::Extend),
+    (0xA8D0, 0xA8D9, WordBreak::Numeric),
+    (0xA8E0, 0xA8F1, WordBreak::Extend),
+    (0xA8F2, 0xA8F7, WordBreak::ALetter),
+    (0xA8FB, 0xA8FB, WordBreak::ALetter),
+    (0xA8FD, 0xA8FE, WordBreak::ALetter),
+    (0xA8FF, 0xA8FF, WordBreak::Extend),
+    (0xA900, 0xA909, WordBreak::Numeric),
+    (0xA90A, 0xA925, WordBreak::ALetter),
+    (0xA926, 0xA92D, WordBreak::Extend),
+    (0xA930, 0xA946, WordBreak::ALetter),
+    (0xA947, 0xA953, WordBreak::Extend),
+    (0xA960, 0xA97C, WordBreak::ALetter),
+    (0xA980, 0xA983, WordBreak::Extend),
+    (0xA984, 0xA9B2, WordBreak::ALetter),
+    (0xA9B3, 0xA9C0, WordBreak::Extend),
+    (0xA9CF, 0xA9CF, WordBreak::ALetter),
+    (0xA9D0, 0xA9D9, WordBreak::Numeric),
+    (0xA9E5, 0xA9E5, WordBreak::Extend),
+    (0xA9F0, 0xA9F9, WordBreak::Numeric),
+    (0xAA00, 0xAA28, WordBreak::ALetter),
+    (0xAA29, 0xAA36, WordBreak::Extend),
+    (0xAA40, 0xAA42, WordBreak::ALetter),
+    (0xAA43, 0xAA43, WordBreak::Extend),
+    (0xAA44, 0xAA4B, WordBreak::ALetter),
+    (0xAA4C, 0xAA4D, WordBreak::Extend),
+    (0xAA50, 0xAA59, WordBreak::Numeric),
+    (0xAA7B, 0xAA7D, WordBreak::Extend),
+    (0xAAB0, 0xAAB0, WordBreak::Extend),
+    (0xAAB2, 0xAAB4, WordBreak::Extend),
+    (0xAAB7, 0xAAB8, WordBreak::Extend),
+    (0xAABE, 0xAABF, WordBreak::Extend),
+    (0xAAC1, 0xAAC1, WordBreak::Extend),
+    (0xAAE0, 0xAAEA, WordBreak::ALetter),
+    (0xAAEB, 0xAAEF, WordBreak::Extend),
+    (0xAAF2, 0xAAF4, WordBreak::ALetter),
+    (0xAAF5, 0xAAF6, WordBreak::Extend),
+    (0xAB01, 0xAB06, WordBreak::ALetter),
+    (0xAB09, 0xAB0E, WordBreak::ALetter),
+    (0xAB11, 0xAB16, WordBreak::ALetter),
+    (0xAB20, 0xAB26, WordBreak::ALetter),
+    (0xAB28, 0xAB2E, WordBreak::ALetter),
+    (0xAB30, 0xAB69, WordBreak::ALetter),
+    (0xAB70, 0xABE2, WordBreak::ALetter),
+    (0xABE3, 0xABEA, WordBreak::Extend),
+    (0xABEC, 0xABED, WordBreak::Extend),
+    (0xABF0, 0xABF9, WordBreak::Numeric),
+    (0xAC00, 0xD7A3, WordBreak::ALetter),
+    (0xD7B0, 0xD7C6, WordBreak::ALetter),
+    (0xD7CB, 0xD7FB, WordBreak::ALetter),
+    (0xFB00, 0xFB06, WordBreak::ALetter),
+    (0xFB13, 0xFB17, WordBreak::ALetter),
+    (0xFB1D, 0xFB1D, WordBreak::HebrewLetter),
+    (0xFB1E, 0xFB1E, WordBreak::Extend),
+    (0xFB1F, 0xFB28, WordBreak::HebrewLetter),
+    (0xFB2A, 0xFB36, WordBreak::HebrewLetter),
+    (0xFB38, 0xFB3C, WordBreak::HebrewLetter),
+    (0xFB3E, 0xFB3E, WordBreak::HebrewLetter),
+    (0xFB40, 0xFB41, WordBreak::HebrewLetter),
+    (0xFB43, 0xFB44, WordBreak::HebrewLetter),
+    (0xFB46, 0xFB4F, WordBreak::HebrewLetter),
+    (0xFB50, 0xFBB1, WordBreak::ALetter),
+    (0xFBD3, 0xFD3D, WordBreak::ALetter),
+    (0xFD50, 0xFD8F, WordBreak::ALetter),
+    (0xFD92, 0xFDC7, WordBreak::ALetter),
+    (0xFDF0, 0xFDFB, WordBreak::ALetter),
+    (0xFE00, 0xFE0F, WordBreak::Extend),
+    (0xFE10, 0xFE10, WordBreak::MidNum),
+    (0xFE13, 0xFE13, WordBreak::MidLetter),
+    (0xFE14, 0xFE14, WordBreak::MidNum),
+    (0xFE20, 0xFE2F, WordBreak::Extend),
+    (0xFE33, 0xFE34, WordBreak::ExtendNumLet),
+    (0xFE4D, 0xFE4F, WordBreak::ExtendNumLet),
+    (0xFE50, 0xFE50, WordBreak::MidNum),
+    (0xFE52, 0xFE52, WordBreak::MidNumLet),
+    (0xFE54, 0xFE54, WordBreak::MidNum),
+    (0xFE55, 0xFE55, WordBreak::MidLetter),
+    (0xFE70, 0xFE74, WordBreak::ALetter),
+    (0xFE76, 0xFEFC, WordBreak::ALetter),
+    (0xFEFF, 0xFEFF, WordBreak::Format),
+    (0xFF07, 0xFF07, WordBreak::MidNumLet),
+    (0xFF0C, 0xFF0C, WordBreak::MidNum),
+    (0xFF0E, 0xFF0E, WordBreak::MidNumLet),
+    (0xFF10, 0xFF19, WordBreak::Numeric),
+    (0xFF1A, 0xFF1A, WordBreak::MidLetter),
+    (0xFF1B, 0xFF1B, WordBreak::MidNum),
+    (0xFF21, 0xFF3A, WordBreak::ALetter),
+    (0xFF3F, 0xFF3F, WordBreak::ExtendNumLet),
+    (0xFF41, 0xFF5A, WordBreak::ALetter),
+    (0xFF66, 0xFF9D, WordBreak::Katakana),
+    (0xFF9E, 0xFF9F, WordBreak::Extend),
+    (0xFFA0, 0xFFBE, WordBreak::ALetter),
+    (0xFFC2, 0xFFC7, WordBreak::ALetter),
+    (0xFFCA, 0xFFCF, WordBreak::ALetter),
+    (0xFFD2, 0xFFD7, WordBreak::ALetter),
+    (0xFFDA, 0xFFDC, WordBreak::ALetter),
+    (0xFFF9, 0xFFFB, WordBreak::Format),
+    (0x10000, 0x1000B, WordBreak::ALetter),
+    (0x1000D, 0x10026, WordBreak::ALetter),
+    (0x10028, 0x1003A, WordBreak::ALetter),
+    (0x1003C, 0x1003D, WordBreak::ALetter),
+    (0x1003F, 0x1004D, WordBreak::ALetter),
+    (0x10050, 0x1005D, WordBreak::ALetter),
+    (0x10080, 0x100FA, WordBreak::ALetter),
+    (0x10140, 0x10174, WordBreak::ALetter),
+    (0x101FD, 0x101FD, WordBreak::Extend),
+    (0x10280, 0x1029C, WordBreak::ALetter),
+    (0x102A0, 0x102D0, WordBreak::ALetter),
+    (0x102E0, 0x102E0, WordBreak::Extend),
+    (0x10300, 0x1031F, WordBreak::ALetter),
+    (0x1032D, 0x1034A, WordBreak::ALetter),
+    (0x10350, 0x10375, WordBreak::ALetter),
+    (0x10376, 0x1037A, WordBreak::Extend),
+    (0x10380, 0x1039D, WordBreak::ALetter),
+    (0x103A0, 0x103C3, WordBreak::ALetter),
+    (0x103C8, 0x103CF, WordBreak::ALetter),
+    (0x103D1, 0x103D5, WordBreak::ALetter),
+    (0x10400, 0x1049D, WordBreak::ALetter),
+    (0x104A0, 0x104A9, WordBreak::Numeric),
+    (0x104B0, 0x104D3, WordBreak::ALetter),
+    (0x104D8, 0x104FB, WordBreak::ALetter),
+    (0x10500, 0x10527, WordBreak::ALetter),
+    (0x10530, 0x10563, WordBreak::ALetter),
+    (0x10570, 0x1057A, WordBreak::ALetter),
+    (0x1057C, 0x1058A, WordBreak::ALetter),
+    (0x1058C, 0x10592, WordBreak::ALetter),
+    (0x10594, 0x10595, WordBreak::ALetter),
+    (0x10597, 0x105A1, WordBreak::ALetter),
+    (0x105A3, 0x105B1, WordBreak::ALetter),
+    (0x105B3, 0x105B9, WordBreak::ALetter),
+    (0x105BB, 0x105BC, WordBreak::ALetter),
+    (0x10600, 0x10736, WordBreak::ALetter),
+    (0x10740, 0x10755, WordBreak::ALetter),
+    (0x10760, 0x10767, WordBreak::ALetter),
+    (0x10780, 0x10785, WordBreak::ALetter),
+    (0x10787, 0x107B0, WordBreak::ALetter),
+    (0x107B2, 0x107BA, WordBreak::ALetter),
+    (0x10800, 0x10805, WordBreak::ALetter),
+    (0x10808, 0x10808, WordBreak::ALetter),
+    (0x1080A, 0x10835, WordBreak::ALetter),
+    (0x10837, 0x10838, WordBreak::ALetter),
+    (0x1083C, 0x1083C, WordBreak::ALetter),
+    (0x1083F, 0x10855, WordBreak::ALetter),
+    (0x10860, 0x10876, WordBreak::ALetter),
+    (0x10880, 0x1089E, WordBreak::ALetter),
+    (0x108E0, 0x108F2, WordBreak::ALetter),
+    (0x108F4, 0x108F5, WordBreak::ALetter),
+    (0x10900, 0x10915, WordBreak::ALetter),
+    (0x10920, 0x10939, WordBreak::ALetter),
+    (0x10980, 0x109B7, WordBreak::ALetter),
+    (0x109BE, 0x109BF, WordBreak::ALetter),
+    (0x10A00, 0x10A00, WordBreak::ALetter),
+    (0x10A01, 0x10A03, WordBreak::Extend),
+    (0x10A05, 0x10A06, WordBreak::Extend),
+    (0x10A0C, 0x10A0F, WordBreak::Extend),
+    (0x10A10, 0x10A13, WordBreak::ALetter),
+    (0x10A15, 0x10A17, WordBreak::ALetter),
+    (0x10A19, 0x10A35, WordBreak::ALetter),
+    (0x10A38, 0x10A3A, WordBreak::Extend),
+    (0x10A3F, 0x10A3F, WordBreak::Extend),
+    (0x10A60, 0x10A7C, WordBreak::ALetter),
+    (0x10A80, 0x10A9C, WordBreak::ALetter),
+    (0x10AC0, 0x10AC7, WordBreak::ALetter),
+    (0x10AC9, 0x10AE4, WordBreak::ALetter),
+    (0x10AE5, 0x10AE6, WordBreak::Extend),
+    (0x10B00, 0x10B35, WordBreak::ALetter),
+    (0x10B40, 0x10B55, WordBreak::ALetter),
+    (0x10B60, 0x10B72, WordBreak::ALetter),
+    (0x10B80, 0x10B91, WordBreak::ALetter),
+    (0x10C00, 0x10C48, WordBreak::ALetter),
+    (0x10C80, 0x10CB2, WordBreak::ALetter),
+    (0x10CC0, 0x10CF2, WordBreak::ALetter),
+    (0x10D00, 0x10D23, WordBreak::ALetter),
+    (0x10D24, 0x10D27, WordBreak::Extend),
+    (0x10D30, 0x10D39, WordBreak::Numeric),
+    (0x10E80, 0x10EA9, WordBreak::ALetter),
+    (0x10EAB, 0x10EAC, WordBreak::Extend),
+    (0x10EB0, 0x10EB1, WordBreak::ALetter),
+    (0x10F00, 0x10F1C, WordBreak::ALetter),
+    (0x10F27, 0x10F27, WordBreak::ALetter),
+    (0x10F30, 0x10F45, WordBreak::ALetter),
+    (0x10F46, 0x10F50, WordBreak::Extend),
+    (0x10F70, 0x10F81, WordBreak::ALetter),
+    (0x10F82, 0x10F85, WordBreak::Extend),
+    (0x10FB0, 0x10FC4, WordBreak::ALetter),
+    (0x10FE0, 0x10FF6, WordBreak::ALetter),
+    (0x11000, 0x11002, WordBreak::Extend),
+    (0x11003, 0x11037, WordBreak::ALetter),
+    (0x11038, 0x11046, WordBreak::Extend),
+    (0x11066, 0x1106F, WordBreak::Numeric),
+    (0x11070, 0x11070, WordBreak::Extend),
+    (0x11071, 0x11072, WordBreak::ALetter),
+    (0x11073, 0x11074, WordBreak::Extend),
+    (0x11075, 0x11075, WordBreak::ALetter),
+    (0x1107F, 0x11082, WordBreak::Extend),
+    (0x11083, 0x110AF, WordBreak::ALetter),
+    (0x110B0, 0x110BA, WordBreak::Extend),
+    (0x110BD, 0x110BD, WordBreak::Format),
+    (0x110C2, 0x110C2, WordBreak::Extend),
+    (0x110CD, 0x110CD, WordBreak::Format),
+    (0x110D0, 0x110E8, WordBreak::ALetter),
+    (0x110F0, 0x110F9, WordBreak::Numeric),
+    (0x11100, 0x11102, WordBreak::Extend),
+    (0x11103, 0x11126, WordBreak::ALetter),
+    (0x11127, 0x11134, WordBreak::Extend),
+    (0x11136, 0x1113F, WordBreak::Numeric),
+    (0x11144, 0x11144, WordBreak::ALetter),
+    (0x11145, 0x11146, WordBreak::Extend),
+    (0x11147, 0x11147, WordBreak::ALetter),
+    (0x11150, 0x11172, WordBreak::ALetter),
+    (0x11173, 0x11173, WordBreak::Extend),
+    (0x11176, 0x11176, WordBreak::ALetter),
+    (0x11180, 0x11182, WordBreak::Extend),
+    (0x11183, 0x111B2, WordBreak::ALetter),
+    (0x111B3, 0x111C0, WordBreak::Extend),
+    (0x111C1, 0x111C4, WordBreak::ALetter),
+    (0x111C9, 0x111CC, WordBreak::Extend),
+    (0x111CE, 0x111CF, WordBreak::Extend),
+    (0x111D0, 0x111D9, WordBreak::Numeric),
+    (0x111DA, 0x111DA, WordBreak::ALetter),
+    (0x111DC, 0x111DC, WordBreak::ALetter),
+    (0x11200, 0x11211, WordBreak::ALetter),
+    (0x11213, 0x1122B, WordBreak::ALetter),
+    (0x1122C, 0x11237, WordBreak::Extend),
+    (0x1123E, 0x1123E, WordBreak::Extend),
+    (0x11280, 0x11286, WordBreak::ALetter),
+    (0x11288, 0x11288, WordBreak::ALetter),
+    (0x1128A, 0x1128D, WordBreak::ALetter),
+    (0x1128F, 0x1129D, WordBreak::ALetter),
+    (0x1129F, 0x112A8, WordBreak::ALetter),
+    (0x112B0, 0x112DE, WordBreak::ALetter),
+    (0x112DF, 0x112EA, WordBreak::Extend),
+    (0x112F0, 0x112F9, WordBreak::Numeric),
+    (0x11300, 0x11303, WordBreak::Extend),
+    (0x11305, 0x1130C, WordBreak::ALetter),
+    (0x1130F, 0x11310, WordBreak::ALetter),
+    (0x11313, 0x11328, WordBreak::ALetter),
+    (0x1132A, 0x11330, WordBreak::ALetter),
+    (0x11332, 0x11333, WordBreak::ALetter),
+    (0x11335, 0x11339, WordBreak::ALetter),
+    (0x1133B, 0x1133C, WordBreak::Extend),
+    (0x1133D, 0x1133D, WordBreak::ALetter),
+    (0x1133E, 0x11344, WordBreak::Extend),
+    (0x11347, 0x11348, WordBreak::Extend),
+    (0x1134B, 0x1134D, WordBreak::Extend),
+    (0x11350, 0x11350, WordBreak::ALetter),
+    (0x11357, 0x11357, WordBreak::Extend),
+    (0x1135D, 0x11361, WordBreak::ALetter),
+    (0x11362, 0x11363, WordBreak::Extend),
+    (0x11366, 0x1136C, WordBreak::Extend),
+    (0x11370, 0x11374, WordBreak::Extend),
+    (0x11400, 0x11434, WordBreak::ALetter),
+    (0x11435, 0x11446, WordBreak::Extend),
+    (0x11447, 0x1144A, WordBreak::ALetter),
+    (0x11450, 0x11459, WordBreak::Numeric),
+    (0x1145E, 0x1145E, WordBreak::Extend),
+    (0x1145F, 0x11461, WordBreak::ALetter),
+    (0x11480, 0x114AF, WordBreak::ALetter),
+    (0x114B0, 0x114C3, WordBreak::Extend),
+    (0x114C4, 0x114C5, WordBreak::ALetter),
+    (0x114C7, 0x114C7, WordBreak::ALetter),
+    (0x114D0, 0x114D9, WordBreak::Numeric),
+    (0x11580, 0x115AE, WordBreak::ALetter),
+    (0x115AF, 0x115B5, WordBreak::Extend),
+    (0x115B8, 0x115C0, WordBreak::Extend),
+    (0x115D8, 0x115DB, WordBreak::ALetter),
+    (0x115DC, 0x115DD, WordBreak::Extend),
+    (0x11600, 0x1162F, WordBreak::ALetter),
+    (0x11630, 0x11640, WordBreak::Extend),
+    (0x11644, 0x11644, WordBreak::ALetter),
+    (0x11650, 0x11659, WordBreak::Numeric),
+    (0x11680, 0x116AA, WordBreak::ALetter),
+    (0x116AB, 0x116B7, WordBreak::Extend),
+    (0x116B8, 0x116B8, WordBreak::ALetter),
+    (0x116C0, 0x116C9, WordBreak::Numeric),
+    (0x1171D, 0x1172B, WordBreak::Extend),
+    (0x11730, 0x11739, WordBreak::Numeric),
+    (0x11800, 0x1182B, WordBreak::ALetter),
+    (0x1182C, 0x1183A, WordBreak::Extend),
+    (0x118A0, 0x118DF, WordBreak::ALetter),
+    (0x118E0, 0x118E9, WordBreak::Numeric),
+    (0x118FF, 0x11906, WordBreak::ALetter),
+    (0x11909, 0x11909, WordBreak::ALetter),
+    (0x1190C, 0x11913, WordBreak::ALetter),
+    (0x11915, 0x11916, WordBreak::ALetter),
+    (0x11918, 0x1192F, WordBreak::ALetter),
+    (0x11930, 0x11935, WordBreak::Extend),
+    (0x11937, 0x11938, WordBreak::Extend),
+    (0x1193B, 0x1193E, WordBreak::Extend),
+    (0x1193F, 0x1193F, WordBreak::ALetter),
+    (0x11940, 0x11940, WordBreak::Extend),
+    (0x11941, 0x11941, WordBreak::ALetter),
+    (0x11942, 0x11943, WordBreak::Extend),
+    (0x11950, 0x11959, WordBreak::Numeric),
+    (0x119A0, 0x119A7, WordBreak::ALetter),
+    (0x119AA, 0x119D0, WordBreak::ALetter),
+    (0x119D1, 0x119D7, WordBreak::Extend),
+    (0x119DA, 0x119E0, WordBreak::Extend),
+    (0x119E1, 0x119E1, WordBreak::ALetter),
+    (0x119E3, 0x119E3, WordBreak::ALetter),
+    (0x119E4, 0x119E4, WordBreak::Extend),
+    (0x11A00, 0x11A00, WordBreak::ALetter),
+    (0x11A01, 0x11A0A, WordBreak::Extend),
+    (0x11A0B, 0x11A32, WordBreak::ALetter),
+    (0x11A33, 0x11A39, WordBreak::Extend),
+    (0x11A3A, 0x11A3A, WordBreak::ALetter),
+    (0x11A3B, 0x11A3E, WordBreak::Extend),
+    (0x11A47, 0x11A47, WordBreak::Extend),
+    (0x11A50, 0x11A50, WordBreak::ALetter),
+    (0x11A51, 0x11A5B, WordBreak::Extend),
+    (0x11A5C, 0x11A89, WordBreak::ALetter),
+    (0x11A8A, 0x11A99, WordBreak::Extend),
+    (0x11A9D, 0x11A9D, WordBreak::ALetter),
+    (0x11AB0, 0x11AF8, WordBreak::ALetter),
+    (0x11C00, 0x11C08, WordBreak::ALetter),
+    (0x11C0A, 0x11C2E, WordBreak::ALetter),
+    (0x11C2F, 0x11C36, WordBreak::Extend),
+    (0x11C38, 0x11C3F, WordBreak::Extend),
+    (0x11C40, 0x11C40, WordBreak::ALetter),
+    (0x11C50, 0x11C59, WordBreak::Numeric),
+    (0x11C72, 0x11C8F, WordBreak::ALetter),
+    (0x11C92, 0x11CA7, WordBreak::Extend),
+    (0x11CA9, 0x11CB6, WordBreak::Extend),
+    (0x11D00, 0x11D06, WordBreak::ALetter),
+    (0x11D08, 0x11D09, WordBreak::ALetter),
+    (0x11D0B, 0x11D30, WordBreak::ALetter),
+    (0x11D31, 0x11D36, WordBreak::Extend),
+    (0x11D3A, 0x11D3A, WordBreak::Extend),
+    (0x11D3C, 0x11D3D, WordBreak::Extend),
+    (0x11D3F, 0x11D45, WordBreak::Extend),
+    (0x11D46, 0x11D46, WordBreak::ALetter),
+    (0x11D47, 0x11D47, WordBreak::Extend),
+    (0x11D50, 0x11D59, WordBreak::Numeric),
+    (0x11D60, 0x11D65, WordBreak::ALetter),
+    (0x11D67, 0x11D68, WordBreak::ALetter),
+    (0x11D6A, 0x11D89, WordBreak::ALetter),
+    (0x11D8A, 0x11D8E, WordBreak::Extend),
+    (0x11D90, 0x11D91, WordBreak::Extend),
+    (0x11D93, 0x11D97, WordBreak::Extend),
+    (0x11D98, 0x11D98, WordBreak::ALetter),
+    (0x11DA0, 0x11DA9, WordBreak::Numeric),
+    (0x11EE0, 0x11EF2, WordBreak::ALetter),
+    (0x11EF3, 0x11EF6, WordBreak::Extend),
+    (0x11FB0, 0x11FB0, WordBreak::ALetter),
+    (0x12000, 0x12399, WordBreak::ALetter),
+    (0x12400, 0x1246E, WordBreak::ALetter),
+    (0x12480, 0x12543, WordBreak::ALetter),
+    (0x12F90, 0x12FF0, WordBreak::ALetter),
+    (0x13000, 0x1342E, WordBreak::ALetter),
+    (0x13430, 0x13438, WordBreak::Format),
+    (0x14400, 0x14646, WordBreak::ALetter),
+    (0x16800, 0x16A38, WordBreak::ALetter),
+    (0x16A40, 0x16A5E, WordBreak::ALetter),
+    (0x16A60, 0x16A69, WordBreak::Numeric),
+    (0x16A70, 0x16ABE, WordBreak::ALetter),
+    (0x16AC0, 0x16AC9, WordBreak::Numeric),
+    (0x16AD0, 0x16AED, WordBreak::ALetter),
+    (0x16AF0, 0x16AF4, WordBreak::Extend),
+    (0x16B00, 0x16B2F, WordBreak::ALetter),
+    (0x16B30, 0x16B36, WordBreak::Extend),
+    (0x16B40, 0x16B43, WordBreak::ALetter),
+    (0x16B50, 0x16B59, WordBreak::Numeric),
+    (0x16B63, 0x16B77, WordBreak::ALetter),
+    (0x16B7D, 0x16B8F, WordBreak::ALetter),
+    (0x16E40, 0x16E7F, WordBreak::ALetter),
+    (0x16F00, 0x16F4A, WordBreak::ALetter),
+    (0x16F4F, 0x16F4F, WordBreak::Extend),
+    (0x16F50, 0x16F50, WordBreak::ALetter),
+    (0x16F51, 0x16F87, WordBreak::Extend),
+    (0x16F8F, 0x16F92, WordBreak::Extend),
+    (0x16F93, 0x16F9F, WordBreak::ALetter),
+    (0x16FE0, 0x16FE1, WordBreak::ALetter),
+    (0x16FE3, 0x16FE3, WordBreak::ALetter),
+    (0x16FE4, 0x16FE4, WordBreak::Extend),
+    (0x16FF0, 0x16FF1, WordBreak::Extend),
+    (0x1AFF0, 0x1AFF3, WordBreak::Katakana),
+    (0x1AFF5, 0x1AFFB, WordBreak::Katakana),
+    (0x1AFFD, 0x1AFFE, WordBreak::Katakana),
+    (0x1B000, 0x1B000, WordBreak::Katakana),
+    (0x1B120, 0x1B122, WordBreak::Katakana),
+    (0x1B164, 0x1B167, WordBreak::Katakana),
+    (0x1BC00, 0x1BC6A, WordBreak::ALetter),
+    (0x1BC70, 0x1BC7C, WordBreak::ALetter),
+    (0x1BC80, 0x1BC88, WordBreak::ALetter),
+    (0x1BC90, 0x1BC99, WordBreak::ALetter),
+    (0x1BC9D, 0x1BC9E, WordBreak::Extend),
+    (0x1BCA0, 0x1BCA3, WordBreak::Format),
+    (0x1CF00, 0x1CF2D, WordBreak::Extend),
+    (0x1CF30, 0x1CF46, WordBreak::Extend),
+    (0x1D165, 0x1D169, WordBreak::Extend),
+    (0x1D16D, 0x1D172, WordBreak::Extend),
+    (0x1D173, 0x1D17A, WordBreak::Format),
+    (0x1D17B, 0x1D182, WordBreak::Extend),
+    (0x1D185, 0x1D18B, WordBreak::Extend),
+    (0x1D1AA, 0x1D1AD, WordBreak::Extend),
+    (0x1D242, 0x1D244, WordBreak::Extend),
+    (0x1D400, 0x1D454, WordBreak::ALetter),
+    (0x1D456, 0x1D49C, WordBreak::ALetter),
+    (0x1D49E, 0x1D49F, WordBreak::ALetter),
+    (0x1D4A2, 0x1D4A2, WordBreak::ALetter),
+    (0x1D4A5, 0x1D4A6, WordBreak::ALetter),
+    (0x1D4A9, 0x1D4AC, WordBreak::ALetter),
+    (0x1D4AE, 0x1D4B9, WordBreak::ALetter),
+    (0x1D4BB, 0x1D4BB, WordBreak::ALetter),
+    (0x1D4BD, 0x1D4C3, WordBreak::ALetter),
+    (0x1D4C5, 0x1D505, WordBreak::ALetter),
+    (0x1D507, 0x1D50A, WordBreak::ALetter),
+    (0x1D50D, 0x1D514, WordBreak::ALetter),
+    (0x1D516, 0x1D51C, WordBreak::ALetter),
+    (0x1D51E, 0x1D539, WordBreak::ALetter),
+    (0x1D53B, 0x1D53E, WordBreak::ALetter),
+    (0x1D540, 0x1D544, WordBreak::ALetter),
+    (0x1D546, 0x1D546, WordBreak::ALetter),
+    (0x1D54A, 0x1D550, WordBreak::ALetter),
+    (0x1D552, 0x1D6A5, WordBreak::ALetter),
+    (0x1D6A8, 0x1D6C0, WordBreak::ALetter),
+    (0x1D6C2, 0x1D6DA, WordBreak::ALetter),
+    (0x1D6DC, 0x1D6FA, WordBreak::ALetter),
+    (0x1D6FC, 0x1D714, WordBreak::ALetter),
+    (0x1D716, 0x1D734, WordBreak::ALetter),
+    (0x1D736, 0x1D74E, WordBreak::ALetter),
+    (0x1D750, 0x1D76E, WordBreak::ALetter),
+    (0x1D770, 0x1D788, WordBreak::ALetter),
+    (0x1D78A, 0x1D7A8, WordBreak::ALetter),
+    (0x1D7AA, 0x1D7C2, WordBreak::ALetter),
+    (0x1D7C4, 0x1D7CB, WordBreak::ALetter),
+    (0x1D7CE, 0x1D7FF, WordBreak::Numeric),
+    (0x1DA00, 0x1DA36, WordBreak::Extend),
+    (0x1DA3B, 0x1DA6C, WordBreak::Extend),
+    (0x1DA75, 0x1DA75, WordBreak::Extend),
+    (0x1DA84, 0x1DA84, WordBreak::Extend),
+    (0x1DA9B, 0x1DA9F, WordBreak::Extend),
+    (0x1DAA1, 0x1DAAF, WordBreak::Extend),
+    (0x1DF00, 0x1DF1E, WordBreak::ALetter),
+    (0x1E000, 0x1E006, WordBreak::Extend),
+    (0x1E008, 0x1E018, WordBreak::Extend),
+    (0x1E01B, 0x1E021, WordBreak::Extend),
+    (0x1E023, 0x1E024, WordBreak::Extend),
+    (0x1E026, 0x1E02A, WordBreak::Extend),
+    (0x1E100, 0x1E12C, WordBreak::ALetter),
+    (0x1E130, 0x1E136, WordBreak::Extend),
+    (0x1E137, 0x1E13D, WordBreak::ALetter),
+    (0x1E140, 0x1E149, WordBreak::Numeric),
+    (0x1E14E, 0x1E14E, WordBreak::ALetter),
+    (0x1E290, 0x1E2AD, WordBreak::ALetter),
+    (0x1E2AE, 0x1E2AE, WordBreak::Extend),
+    (0x1E2C0, 0x1E2EB, WordBreak::ALetter),
+    (0x1E2EC, 0x1E2EF, WordBreak::Extend),
+    (0x1E2F0, 0x1E2F9, WordBreak::Numeric),
+    (0x1E7E0, 0x1E7E6, WordBreak::ALetter),
+    (0x1E7E8, 0x1E7EB, WordBreak::ALetter),
+    (0x1E7ED, 0x1E7EE, WordBreak::ALetter),
+    (0x1E7F0, 0x1E7FE, WordBreak::ALetter),
+    (0x1E800, 0x1E8C4, WordBreak::ALetter),
+    (0x1E8D0, 0x1E8D6, WordBreak::Extend),
+    (0x1E900, 0x1E943, WordBreak::ALetter),
+    (0x1E944, 0x1E94A, WordBreak::Extend),
+    (0x1E94B, 0x1E94B, WordBreak::ALetter),
+    (0x1E950, 0x1E959, WordBreak::Numeric),
+    (0x1EE00, 0x1EE03, WordBreak::ALetter),
+    (0x1EE05, 0x1EE1F, WordBreak::ALetter),
+    (0x1EE21, 0x1EE22, WordBreak::ALetter),
+    (0x1EE24, 0x1EE24, WordBreak::ALetter),
+    (0x1EE27, 0x1EE27, WordBreak::ALetter),
+    (0x1EE29, 0x1EE32, WordBreak::ALetter),
+    (0x1EE34, 0x1EE37, WordBreak::ALetter),
+    (0x1EE39, 0x1EE39, WordBreak::ALetter),
+    (0x1EE3B, 0x1EE3B, WordBreak::ALetter),
+    (0x1EE42, 0x1EE42, WordBreak::ALetter),
+    (0x1EE47, 0x1EE47, WordBreak::ALetter),
+    (0x1EE49, 0x1EE49, WordBreak::ALetter),
+    (0x1EE4B, 0x1EE4B, WordBreak::ALetter),
+    (0x1EE4D, 0x1EE4F, WordBreak::ALetter),
+    (0x1EE51, 0x1EE52, WordBreak::ALetter),
+    (0x1EE54, 0x1EE54, WordBreak::ALetter),
+    (0x1EE57, 0x1EE57, WordBreak::ALetter),
+    (0x1EE59, 0x1EE59, WordBreak::ALetter),
+    (0x1EE5B, 0x1EE5B, WordBreak::ALetter),
+    (0x1EE5D, 0x1EE5D, WordBreak::ALetter),
+    (0x1EE5F, 0x1EE5F, WordBreak::ALetter),
+    (0x1EE61, 0x1EE62, WordBreak::ALetter),
+    (0x1EE64, 0x1EE64, WordBreak::ALetter),
+    (0x1EE67, 0x1EE6A, WordBreak::ALetter),
+    (0x1EE6C, 0x1EE72, WordBreak::ALetter),
+    (0x1EE74, 0x1EE77, WordBreak::ALetter),
+    (0x1EE79, 0x1EE7C, WordBreak::ALetter),
+    (0x1EE7E, 0x1EE7E, WordBreak::ALetter),
+    (0x1EE80, 0x1EE89, WordBreak::ALetter),
+    (0x1EE8B, 0x1EE9B, WordBreak::ALetter),
+    (0x1EEA1, 0x1EEA3, WordBreak::ALetter),
+    (0x1EEA5, 0x1EEA9, WordBreak::ALetter),
+    (0x1EEAB, 0x1EEBB, WordBreak::ALetter),
+    (0x1F130, 0x1F149, WordBreak::ALetter),
+    (0x1F150, 0x1F169, WordBreak::ALetter),
+    (0x1F170, 0x1F189, WordBreak::ALetter),
+    (0x1F1E6, 0x1F1FF, WordBreak::RegionalIndicator),
+    (0x1F3FB, 0x1F3FF, WordBreak::Extend),
+    (0x1FBF0, 0x1FBF9, WordBreak::Numeric),
+    (0xE0001, 0xE0001, WordBreak::Format),
+    (0xE0020, 0xE007F, WordBreak::Extend),
+    (0xE0100, 0xE01EF, WordBreak::Extend),
+];
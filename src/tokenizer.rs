@@ -0,0 +1,289 @@
+//! Lazy tokenization of input into runs of character classes.
+
+use super::*;
+use nom::{InputIter, InputLength, InputTakeAtPosition};
+
+/// A built-in character class, matching the `is_*` helpers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CharClass {
+    /// Lowercase and uppercase alphabetic characters, see `is_alphabetic`.
+    Alpha,
+    /// Lowercase alphabetic characters, see `is_lowercase`.
+    Lower,
+    /// Uppercase alphabetic characters, see `is_uppercase`.
+    Upper,
+    /// Whitespace characters, see `is_whitespace`.
+    Space,
+    /// Alphabetic and numeric characters, see `is_alphanumeric`.
+    Alphanumeric,
+    /// Control characters, see `is_control`.
+    Control,
+    /// Numeric characters, see `is_numeric`.
+    Digit,
+    /// ASCII characters, see `is_ascii`.
+    Ascii,
+    /// Characters with the `White_Space` property, see `is_white_space_property`.
+    WhiteSpace,
+    /// Characters with the `Cased` property, see `is_cased`.
+    Cased,
+    /// Mongolian script characters, see `is_mongolian`.
+    Mongolian,
+    /// Tibetan script characters, see `is_tibetan`.
+    Tibetan,
+    /// Private Use Area characters, see `is_private_use`.
+    PrivateUse,
+    /// Basic Multilingual Plane characters, see `is_bmp`.
+    Bmp,
+    /// Supplementary plane characters, see `is_supplementary`.
+    Supplementary,
+    /// Halfwidth and Fullwidth Forms characters, see `is_fullwidth_form`.
+    FullwidthForm,
+    /// Items that are not scalar values, see `is_invalid_scalar`.
+    InvalidScalar,    /// Characters that are not whitespace, see `is_non_space`.
+    NonSpace,
+    /// Whitespace and default ignorable characters, see `is_space_or_ignorable`.
+    SpaceOrIgnorable,
+    /// ASCII spaces and tabs, see `is_ascii_space`.
+    AsciiSpace,
+    /// Tabs and space separators, see `is_horizontal_space`.
+    HorizontalSpace,
+    /// Horizontal spaces that allow a line break, see `is_breaking_space`.
+    BreakingSpace,
+    /// Non-breaking spaces, see `is_non_breaking_space`.
+    NonBreakingSpace,
+    /// Line terminators, see `is_line_terminator`.
+    VerticalSpace,
+    /// C0 control characters, see `is_c0_control`.
+    C0Control,
+    /// C1 control characters, see `is_c1_control`.
+    C1Control,
+    /// Combining Diacritical Marks characters, see `is_combining_marks`.
+    CombiningMarks,
+    /// Combining Diacritical Marks Extended characters, see `is_combining_marks_extended`.
+    CombiningMarksExtended,
+    /// Combining Diacritical Marks Supplement characters, see `is_combining_marks_supplement`.
+    CombiningMarksSupplement,
+    /// Combining Diacritical Marks for Symbols characters, see `is_combining_marks_for_symbols`.
+    CombiningMarksForSymbols,
+    /// Decimal digits, see `is_decimal_digit`.
+    Decimal,
+    /// Assigned characters, see `is_assigned`.
+    Assigned,
+    /// Characters with the `Pattern_Syntax` property, see `is_pattern_syntax`.
+    Operator,
+    /// Characters with the `Pattern_White_Space` property, see `is_pattern_white_space`.
+    PatternWhiteSpace,
+    /// Characters with the `Dash` property, see `is_dash`.
+    Dash,
+    /// Characters with the `Quotation_Mark` property, see `is_quotation_mark`.
+    QuotationMark,
+    /// Characters with the `Terminal_Punctuation` property, see `is_terminal_punctuation`.
+    TerminalPunctuation,
+    /// Characters with the `Math` property, see `is_math`.
+    Math,
+    /// Symbols, see `is_symbol`.
+    Symbol,
+    /// Currency symbols, see `is_currency_symbol`.
+    CurrencySymbol,
+    /// Modifier symbols, see `is_modifier_symbol`.
+    ModifierSymbol,
+    /// Math symbols, see `is_math_symbol`.
+    MathSymbol,
+    /// Other symbols, see `is_other_symbol`.
+    OtherSymbol,
+    /// Dual joining characters, see `is_dual_joining`.
+    DualJoining,
+    /// Characters transparent to cursive joining, see `is_transparent`.
+    Transparent,
+    /// Variation selectors, see `is_variation_selector`.
+    VariationSelector,
+    #[cfg(feature = "emoji")]
+    /// Characters with the `Emoji` property, see `is_emoji`.
+    Emoji,
+}
+
+impl CharClass {
+    /// Check if a character is in the class.
+    #[inline]
+    pub fn matches<T: IsChar>(self, item: T) -> bool {
+        match self {
+            CharClass::Alpha => is_alphabetic(item),
+            CharClass::Lower => is_lowercase(item),
+            CharClass::Upper => is_uppercase(item),
+            CharClass::Space => is_whitespace(item),
+            CharClass::Alphanumeric => is_alphanumeric(item),
+            CharClass::Control => is_control(item),
+            CharClass::Digit => is_numeric(item),
+            CharClass::Ascii => is_ascii(item),
+            CharClass::WhiteSpace => is_white_space_property(item),
+            CharClass::Cased => is_cased(item),
+            CharClass::Mongolian => is_mongolian(item),
+            CharClass::Tibetan => is_tibetan(item),
+            CharClass::PrivateUse => is_private_use(item),
+            CharClass::Bmp => is_bmp(item),
+            CharClass::Supplementary => is_supplementary(item),
+            CharClass::FullwidthForm => is_fullwidth_form(item),
+            CharClass::InvalidScalar => is_invalid_scalar(item),
+            CharClass::NonSpace => is_non_space(item),
+            CharClass::SpaceOrIgnorable => is_space_or_ignorable(item),
+            CharClass::AsciiSpace => is_ascii_space(item),
+            CharClass::HorizontalSpace => is_horizontal_space(item),
+            CharClass::BreakingSpace => is_breaking_space(item),
+            CharClass::NonBreakingSpace => is_non_breaking_space(item),
+            CharClass::VerticalSpace => is_line_terminator(item),
+            CharClass::C0Control => is_c0_control(item),
+            CharClass::C1Control => is_c1_control(item),
+            CharClass::CombiningMarks => is_combining_marks(item),
+            CharClass::CombiningMarksExtended => is_combining_marks_extended(item),
+            CharClass::CombiningMarksSupplement => is_combining_marks_supplement(item),
+            CharClass::CombiningMarksForSymbols => is_combining_marks_for_symbols(item),
+            CharClass::Decimal => is_decimal_digit(item),
+            CharClass::Assigned => is_assigned(item),
+            CharClass::Operator => is_pattern_syntax(item),
+            CharClass::PatternWhiteSpace => is_pattern_white_space(item),
+            CharClass::Dash => is_dash(item),
+            CharClass::QuotationMark => is_quotation_mark(item),
+            CharClass::TerminalPunctuation => is_terminal_punctuation(item),
+            CharClass::Math => is_math(item),
+            CharClass::Symbol => is_symbol(item),
+            CharClass::CurrencySymbol => is_currency_symbol(item),
+            CharClass::ModifierSymbol => is_modifier_symbol(item),
+            CharClass::MathSymbol => is_math_symbol(item),
+            CharClass::OtherSymbol => is_other_symbol(item),
+            CharClass::DualJoining => is_dual_joining(item),
+            CharClass::Transparent => is_transparent(item),
+            CharClass::VariationSelector => is_variation_selector(item),
+            #[cfg(feature = "emoji")]
+            CharClass::Emoji => is_emoji(item),
+        }
+    }
+}
+
+/// An iterator over the maximal runs of character classes in an input.
+///
+/// Each step finds the first class in `classes` matching the next
+/// character, and yields it with the longest run of characters in that
+/// class. Iteration stops at the end of the input, or at a character no
+/// class matches, and the unconsumed input is then available from
+/// `remaining`.
+#[derive(Clone, Debug)]
+pub struct ClassTokenizer<'a, T> {
+    input: T,
+    classes: &'a [CharClass],
+}
+
+impl<'a, T> ClassTokenizer<'a, T> {
+    /// Create a tokenizer over `input`, trying `classes` in order.
+    #[inline]
+    pub fn new(input: T, classes: &'a [CharClass]) -> Self {
+        ClassTokenizer { input, classes }
+    }
+
+    /// Get the input that has not been tokenized yet.
+    #[inline]
+    pub fn remaining(&self) -> &T {
+        &self.input
+    }
+}
+
+impl<'a, T> Iterator for ClassTokenizer<'a, T>
+    where T: Clone + InputIter + InputLength + InputTakeAtPosition,
+          <T as InputIter>::Item: IsChar + Copy,
+          <T as InputTakeAtPosition>::Item: IsChar
+{
+    type Item = (CharClass, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.input.iter_elements().next()?;
+        let class = *self.classes.iter().find(|class| class.matches(first))?;
+        let (rest, run) = self.input
+            .split_at_position_complete::<_, ()>(|item| !class.matches(item))
+            .ok()?;
+        self.input = rest;
+        Some((class, run))
+    }
+}
+
+/// Tokenize `input` into runs of `classes`.
+///
+/// This is shorthand for `ClassTokenizer::new`.
+#[inline]
+pub fn tokenize<T>(input: T, classes: &[CharClass]) -> ClassTokenizer<'_, T> {
+    ClassTokenizer::new(input, classes)
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CodePoint;
+
+    #[test]
+    fn tokenize_test() {
+        let classes = [CharClass::Alpha, CharClass::Digit, CharClass::Space];
+        let tokens: Vec<_> = tokenize("erfüllen 123조선글", &classes).collect();
+        assert_eq!(tokens, [
+            (CharClass::Alpha, "erfüllen"),
+            (CharClass::Space, " "),
+            (CharClass::Digit, "123"),
+            (CharClass::Alpha, "조선글"),
+        ]);
+
+        // Classes are tried in order, so earlier classes take precedence.
+        let classes = [CharClass::Upper, CharClass::Alphanumeric];
+        let tokens: Vec<_> = tokenize("ABc1D", &classes).collect();
+        assert_eq!(tokens, [
+            (CharClass::Upper, "AB"),
+            (CharClass::Alphanumeric, "c1D"),
+        ]);
+
+        assert_eq!(tokenize("", &classes).next(), None);
+    }
+
+    #[test]
+    fn matches_test() {
+        assert!(CharClass::NonBreakingSpace.matches('\u{A0}'));
+        assert!(!CharClass::BreakingSpace.matches('\u{A0}'));
+        assert!(CharClass::CurrencySymbol.matches('€'));
+        assert!(CharClass::Decimal.matches('٣'));
+        assert!(!CharClass::Decimal.matches('½'));
+        assert!(CharClass::VariationSelector.matches('\u{FE0F}'));
+        assert!(CharClass::Transparent.matches('\u{64B}'));
+
+        let classes = [CharClass::Symbol, CharClass::AsciiSpace, CharClass::Decimal];
+        let tokens: Vec<_> = tokenize("$+ 12", &classes).collect();
+        assert_eq!(tokens, [
+            (CharClass::Symbol, "$+"),
+            (CharClass::AsciiSpace, " "),
+            (CharClass::Decimal, "12"),
+        ]);
+    }
+
+    #[test]
+    fn remaining_test() {
+        let classes = [CharClass::Alpha, CharClass::Space];
+        let mut tokenizer = tokenize("ab cd, ef", &classes);
+        assert_eq!(tokenizer.next(), Some((CharClass::Alpha, "ab")));
+        assert_eq!(tokenizer.next(), Some((CharClass::Space, " ")));
+        assert_eq!(tokenizer.next(), Some((CharClass::Alpha, "cd")));
+        assert_eq!(tokenizer.next(), None);
+        assert_eq!(tokenizer.remaining(), &", ef");
+    }
+
+    #[test]
+    fn code_point_test() {
+        let points = [CodePoint(0x61), CodePoint(0xD800), CodePoint(0xDFFF), CodePoint(0x62)];
+        let classes = [CharClass::Alpha, CharClass::InvalidScalar];
+        let tokens: Vec<_> = tokenize(CharSlice(&points), &classes)
+            .map(|(class, run)| (class, run.len()))
+            .collect();
+        assert_eq!(tokens, [
+            (CharClass::Alpha, 1),
+            (CharClass::InvalidScalar, 2),
+            (CharClass::Alpha, 1),
+        ]);
+    }
+}
@@ -0,0 +1,268 @@
+//! UTF-8 decoding and byte order mark helpers for byte inputs.
+//!
+//! A streaming byte parser that stops inside a multi-byte sequence must
+//! know whether more input could complete the code point, or whether the
+//! bytes can never form a valid one. These helpers follow the well-formed
+//! byte sequences of the Unicode Standard (Table 3-7), so overlong forms,
+//! surrogates and values above U+10FFFF are rejected as soon as the first
+//! offending byte is seen.
+
+use core::num::NonZeroUsize;
+use nom::error::{ErrorKind, ParseError};
+use nom::{Err, Needed};
+
+/// Why the UTF-8 sequence at the start of a byte slice could not be decoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IncompleteReason {
+    /// The bytes are a valid prefix, and need this many more bytes.
+    Truncated(NonZeroUsize),
+    /// The bytes can never form a valid code point.
+    Invalid,
+}
+
+impl IncompleteReason {
+    /// Get the error kind reported for the reason, if it is an error.
+    ///
+    /// Truncated sequences are not errors, since more input may follow.
+    #[inline]
+    pub fn error_kind(&self) -> Option<ErrorKind> {
+        match self {
+            IncompleteReason::Truncated(_) => None,
+            IncompleteReason::Invalid => Some(ErrorKind::Char),
+        }
+    }
+
+    /// Convert the reason into a nom error at `input`.
+    ///
+    /// Truncated sequences become `Incomplete` with the exact number of
+    /// bytes needed, and invalid sequences an `ErrorKind::Char` error.
+    #[inline]
+    pub fn into_err<I, E: ParseError<I>>(self, input: I) -> Err<E> {
+        match self {
+            IncompleteReason::Truncated(needed) => Err::Incomplete(Needed::Size(needed)),
+            IncompleteReason::Invalid => Err::Error(E::from_error_kind(input, ErrorKind::Char)),
+        }
+    }
+}
+
+// Get the sequence length and the valid range of the second byte for a
+// leading byte, or `None` if the byte cannot start a sequence.
+#[inline]
+fn sequence_info(lead: u8) -> Option<(usize, u8, u8)> {
+    match lead {
+        0x00..=0x7F => Some((1, 0, 0)),
+        0xC2..=0xDF => Some((2, 0x80, 0xBF)),
+        0xE0 => Some((3, 0xA0, 0xBF)),
+        0xE1..=0xEC | 0xEE..=0xEF => Some((3, 0x80, 0xBF)),
+        0xED => Some((3, 0x80, 0x9F)),
+        0xF0 => Some((4, 0x90, 0xBF)),
+        0xF1..=0xF3 => Some((4, 0x80, 0xBF)),
+        0xF4 => Some((4, 0x80, 0x8F)),
+        _ => None,
+    }
+}
+
+/// Decode the first scalar value in `bytes`.
+///
+/// Returns the character and the number of bytes it used, or why it
+/// could not be decoded. An empty slice is truncated, needing 1 byte.
+pub fn decode_utf8(bytes: &[u8]) -> Result<(char, usize), IncompleteReason> {
+    let lead = match bytes.first() {
+        Some(&lead) => lead,
+        None => return Err(IncompleteReason::Truncated(NonZeroUsize::new(1).unwrap())),
+    };
+    let (len, lower, upper) = sequence_info(lead).ok_or(IncompleteReason::Invalid)?;
+    if len == 1 {
+        return Ok((lead as char, 1));
+    }
+
+    let mut value = (lead as u32) & (0x7F >> len);
+    for index in 1..len {
+        let byte = match bytes.get(index) {
+            Some(&byte) => byte,
+            None => return Err(IncompleteReason::Truncated(NonZeroUsize::new(len - index).unwrap())),
+        };
+        let (min, max) = if index == 1 { (lower, upper) } else { (0x80, 0xBF) };
+        if byte < min || byte > max {
+            return Err(IncompleteReason::Invalid);
+        }
+        value = (value << 6) | (byte & 0x3F) as u32;
+    }
+
+    // The byte ranges exclude surrogates and values above U+10FFFF.
+    let c = core::char::from_u32(value).ok_or(IncompleteReason::Invalid)?;
+    Ok((c, len))
+}
+
+/// Get the number of additional bytes expected to finish the leading sequence.
+///
+/// Given the partial sequence at the end of a buffer, returns `Some(0)` if
+/// it already holds a complete code point, `Some(n)` if `n` more
+/// continuation bytes are needed, or `None` if the bytes can never form
+/// a valid code point. An empty buffer has no sequence to finish, and
+/// also returns `None`.
+#[inline]
+pub fn continuation_bytes_needed(partial: &[u8]) -> Option<usize> {
+    if partial.is_empty() {
+        return None;
+    }
+    match decode_utf8(partial) {
+        Ok(_) => Some(0),
+        Err(IncompleteReason::Truncated(needed)) => Some(needed.get()),
+        Err(IncompleteReason::Invalid) => None,
+    }
+}
+
+/// Inputs whose first scalar value can be decoded.
+///
+/// This is implemented for `&str`, which is always valid UTF-8, and for
+/// `&[u8]`, which may end inside a multi-byte sequence, or hold bytes
+/// that are not UTF-8 at all.
+pub trait DecodeChar {
+    /// Decode the first scalar value.
+    ///
+    /// Returns the character and the number of items it used, or why it
+    /// could not be decoded. An empty input is truncated, needing 1 item.
+    fn decode_char(&self) -> Result<(char, usize), IncompleteReason>;
+}
+
+impl DecodeChar for &str {
+    #[inline]
+    fn decode_char(&self) -> Result<(char, usize), IncompleteReason> {
+        match self.chars().next() {
+            Some(c) => Ok((c, c.len_utf8())),
+            None => Err(IncompleteReason::Truncated(NonZeroUsize::new(1).unwrap())),
+        }
+    }
+}
+
+impl DecodeChar for &[u8] {
+    #[inline]
+    fn decode_char(&self) -> Result<(char, usize), IncompleteReason> {
+        decode_utf8(self)
+    }
+}
+
+/// A byte order mark, and the encoding it signals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Bom {
+    /// `EF BB BF`.
+    Utf8,
+    /// `FF FE`.
+    Utf16Le,
+    /// `FE FF`.
+    Utf16Be,
+    /// `FF FE 00 00`.
+    Utf32Le,
+    /// `00 00 FE FF`.
+    Utf32Be,
+}
+
+impl Bom {
+    /// Get the bytes of the byte order mark.
+    #[inline]
+    pub fn as_bytes(&self) -> &'static [u8] {
+        match self {
+            Bom::Utf8 => &[0xEF, 0xBB, 0xBF],
+            Bom::Utf16Le => &[0xFF, 0xFE],
+            Bom::Utf16Be => &[0xFE, 0xFF],
+            Bom::Utf32Le => &[0xFF, 0xFE, 0x00, 0x00],
+            Bom::Utf32Be => &[0x00, 0x00, 0xFE, 0xFF],
+        }
+    }
+}
+
+/// Detect a leading byte order mark in `bytes`.
+///
+/// Returns the mark and the bytes after it, or `None` if there is no
+/// mark. `FF FE 00 00` is read as UTF-32LE rather than UTF-16LE followed
+/// by a null character.
+pub fn detect_bom(bytes: &[u8]) -> Option<(Bom, &[u8])> {
+    // Longer marks first, since UTF-16LE is a prefix of UTF-32LE.
+    [Bom::Utf32Le, Bom::Utf32Be, Bom::Utf8, Bom::Utf16Le, Bom::Utf16Be].iter()
+        .find(|bom| bytes.starts_with(bom.as_bytes()))
+        .map(|&bom| (bom, &bytes[bom.as_bytes().len()..]))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::error::Error as NError;
+
+    fn truncated(needed: usize) -> IncompleteReason {
+        IncompleteReason::Truncated(NonZeroUsize::new(needed).unwrap())
+    }
+
+    #[test]
+    fn decode_utf8_test() {
+        assert_eq!(decode_utf8(b"a"), Ok(('a', 1)));
+        assert_eq!(decode_utf8("ü".as_bytes()), Ok(('ü', 2)));
+        assert_eq!(decode_utf8("조x".as_bytes()), Ok(('조', 3)));
+        assert_eq!(decode_utf8("𝄞".as_bytes()), Ok(('𝄞', 4)));
+        assert_eq!(decode_utf8(b""), Err(truncated(1)));
+
+        // Truncated, but valid, prefixes.
+        assert_eq!(decode_utf8(&[0xC3]), Err(truncated(1)));
+        assert_eq!(decode_utf8(&[0xEC]), Err(truncated(2)));
+        assert_eq!(decode_utf8(&[0xEC, 0xA1]), Err(truncated(1)));
+        assert_eq!(decode_utf8(&[0xF0]), Err(truncated(3)));
+        assert_eq!(decode_utf8(&[0xF0, 0x9D, 0x84]), Err(truncated(1)));
+
+        // Bytes that can never start or continue a valid sequence.
+        assert_eq!(decode_utf8(&[0x80]), Err(IncompleteReason::Invalid));
+        assert_eq!(decode_utf8(&[0xC0]), Err(IncompleteReason::Invalid));
+        assert_eq!(decode_utf8(&[0xF5]), Err(IncompleteReason::Invalid));
+        assert_eq!(decode_utf8(&[0xC3, 0x41]), Err(IncompleteReason::Invalid));
+        // Overlong, surrogate and out-of-range second bytes.
+        assert_eq!(decode_utf8(&[0xE0, 0x80]), Err(IncompleteReason::Invalid));
+        assert_eq!(decode_utf8(&[0xED, 0xA0]), Err(IncompleteReason::Invalid));
+        assert_eq!(decode_utf8(&[0xF0, 0x80]), Err(IncompleteReason::Invalid));
+        assert_eq!(decode_utf8(&[0xF4, 0x90]), Err(IncompleteReason::Invalid));
+    }
+
+    #[test]
+    fn continuation_bytes_needed_test() {
+        assert_eq!(continuation_bytes_needed(b"a"), Some(0));
+        assert_eq!(continuation_bytes_needed(&[0xC3]), Some(1));
+        assert_eq!(continuation_bytes_needed(&[0xE2, 0x82]), Some(1));
+        assert_eq!(continuation_bytes_needed(&[0xF0, 0x9F]), Some(2));
+        assert_eq!(continuation_bytes_needed(&[0xED, 0xBF]), None);
+        assert_eq!(continuation_bytes_needed(&[0xFF]), None);
+        assert_eq!(continuation_bytes_needed(b""), None);
+    }
+
+    #[test]
+    fn decode_char_test() {
+        assert_eq!("ü!".decode_char(), Ok(('ü', 2)));
+        assert_eq!("".decode_char(), Err(truncated(1)));
+        assert_eq!((&b"\xC3\xBC!"[..]).decode_char(), Ok(('ü', 2)));
+        assert_eq!((&b"\xE2\x82"[..]).decode_char(), Err(truncated(1)));
+        assert_eq!((&b"\xFF"[..]).decode_char(), Err(IncompleteReason::Invalid));
+    }
+
+    #[test]
+    fn detect_bom_test() {
+        assert_eq!(detect_bom(b"\xEF\xBB\xBFtext"), Some((Bom::Utf8, &b"text"[..])));
+        assert_eq!(detect_bom(b"\xFF\xFEa\x00"), Some((Bom::Utf16Le, &b"a\x00"[..])));
+        assert_eq!(detect_bom(b"\xFE\xFF\x00a"), Some((Bom::Utf16Be, &b"\x00a"[..])));
+        assert_eq!(detect_bom(b"\xFF\xFE\x00\x00a\x00\x00\x00"), Some((Bom::Utf32Le, &b"a\x00\x00\x00"[..])));
+        assert_eq!(detect_bom(b"\x00\x00\xFE\xFF"), Some((Bom::Utf32Be, &b""[..])));
+        assert_eq!(detect_bom(b"text\xEF\xBB\xBF"), None);
+        assert_eq!(detect_bom(b"\xEF\xBB"), None);
+        assert_eq!(detect_bom(b""), None);
+    }
+
+    #[test]
+    fn into_err_test() {
+        let input: &[u8] = &[0xF0, 0x9F];
+        let err: Err<NError<&[u8]>> = truncated(2).into_err(input);
+        assert_eq!(err, Err::Incomplete(Needed::new(2)));
+        let err: Err<NError<&[u8]>> = IncompleteReason::Invalid.into_err(input);
+        assert_eq!(err, Err::Error(NError::new(input, ErrorKind::Char)));
+        assert_eq!(truncated(1).error_kind(), None);
+        assert_eq!(IncompleteReason::Invalid.error_kind(), Some(ErrorKind::Char));
+    }
+}
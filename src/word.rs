@@ -0,0 +1,239 @@
+//! Word boundaries, following UAX #29.
+//!
+//! This implements the default word boundary rules of Unicode 14.0,
+//! using the tables bundled with the crate. Every character belongs to a
+//! word segment, so spaces and punctuation form segments of their own.
+
+use crate::tables::{self, WordBreak as Wb};
+
+/// Get the `Word_Break` property of a character.
+#[inline]
+fn break_property(c: char) -> Wb {
+    tables::lookup(c, tables::WORD_BREAK)
+}
+
+// `ALetter` or `Hebrew_Letter`.
+#[inline]
+fn is_ah_letter(wb: Wb) -> bool {
+    matches!(wb, Wb::ALetter | Wb::HebrewLetter)
+}
+
+// `MidLetter`, `MidNumLet` or `Single_Quote`.
+#[inline]
+fn is_mid_letter(wb: Wb) -> bool {
+    matches!(wb, Wb::MidLetter | Wb::MidNumLet | Wb::SingleQuote)
+}
+
+// `MidNum`, `MidNumLet` or `Single_Quote`.
+#[inline]
+fn is_mid_num(wb: Wb) -> bool {
+    matches!(wb, Wb::MidNum | Wb::MidNumLet | Wb::SingleQuote)
+}
+
+// The break class the next non-ignored character must have to join a
+// character to the word, for the rules that look ahead (WB6, WB7b, WB12).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Lookahead {
+    AhLetter,
+    HebrewLetter,
+    Numeric,
+}
+
+impl Lookahead {
+    #[inline]
+    fn accepts(self, wb: Wb) -> bool {
+        match self {
+            Lookahead::AhLetter => is_ah_letter(wb),
+            Lookahead::HebrewLetter => wb == Wb::HebrewLetter,
+            Lookahead::Numeric => wb == Wb::Numeric,
+        }
+    }
+}
+
+// If there is a boundary before a character.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Decision {
+    Break,
+    Keep,
+    // No boundary, and the character is ignored (WB4).
+    Ignore,
+    // No boundary, if the next non-ignored character is accepted.
+    Lookahead(Lookahead),
+}
+
+// Apply the rules after WB4, with the previous two non-ignored classes,
+// and the count of regional indicators ending the word.
+fn decide(prev2: Option<Wb>, prev: Wb, next: Wb, regional: usize) -> Decision {
+    let keep_if = |keep: bool| if keep { Decision::Keep } else { Decision::Break };
+    match (prev, next) {
+        (p, n) if is_ah_letter(p) && is_ah_letter(n) => Decision::Keep,
+        (Wb::HebrewLetter, Wb::SingleQuote) => Decision::Keep,
+        (p, n) if is_ah_letter(p) && is_mid_letter(n) => Decision::Lookahead(Lookahead::AhLetter),
+        (p, n) if is_mid_letter(p) && is_ah_letter(n) => keep_if(prev2.map_or(false, is_ah_letter)),
+        (Wb::HebrewLetter, Wb::DoubleQuote) => Decision::Lookahead(Lookahead::HebrewLetter),
+        (Wb::DoubleQuote, Wb::HebrewLetter) => keep_if(prev2 == Some(Wb::HebrewLetter)),
+        (Wb::Numeric, Wb::Numeric) => Decision::Keep,
+        (p, Wb::Numeric) if is_ah_letter(p) => Decision::Keep,
+        (Wb::Numeric, n) if is_ah_letter(n) => Decision::Keep,
+        (p, Wb::Numeric) if is_mid_num(p) => keep_if(prev2 == Some(Wb::Numeric)),
+        (Wb::Numeric, n) if is_mid_num(n) => Decision::Lookahead(Lookahead::Numeric),
+        (Wb::Katakana, Wb::Katakana) => Decision::Keep,
+        (p, Wb::ExtendNumLet) => keep_if(is_ah_letter(p) || matches!(p, Wb::Numeric | Wb::Katakana | Wb::ExtendNumLet)),
+        (Wb::ExtendNumLet, n) => keep_if(is_ah_letter(n) || matches!(n, Wb::Numeric | Wb::Katakana)),
+        (Wb::RegionalIndicator, Wb::RegionalIndicator) => keep_if(regional % 2 == 1),
+        _ => Decision::Break,
+    }
+}
+
+/// Find the end of the first word segment of a sequence of characters.
+///
+/// The characters are given with their indexes, and `None` for items
+/// that are not scalar values, which always end the segment. `length` is
+/// the length of the input. Returns `Err` with the end at the end of the
+/// input if more input could move it, which streaming parsers need to
+/// treat as incomplete.
+pub(crate) fn word_end<I>(chars: I, length: usize) -> Result<usize, usize>
+    where I: IntoIterator<Item = (usize, Option<char>)>
+{
+    let mut chars = chars.into_iter();
+    let first = match chars.next() {
+        Some((_, Some(c))) => break_property(c),
+        Some((index, None)) => return Ok(index),
+        None => return Err(length),
+    };
+
+    // The class of the previous character, and of the previous two
+    // characters that were not ignored (WB4).
+    let mut raw = first;
+    let mut prev = first;
+    let mut prev2 = None;
+    let mut regional = usize::from(first == Wb::RegionalIndicator);
+    // A possible boundary, before a character that needs lookahead.
+    let mut pending: Option<(usize, Lookahead)> = None;
+    for (index, c) in chars {
+        let c = match c {
+            Some(c) => c,
+            None => return Ok(pending.map_or(index, |(start, _)| start)),
+        };
+        let next = break_property(c);
+        let decision = match (raw, next) {
+            (Wb::Cr, Wb::Lf) => Decision::Keep,
+            (Wb::Cr, _) | (Wb::Lf, _) | (Wb::Newline, _) => Decision::Break,
+            (_, Wb::Cr) | (_, Wb::Lf) | (_, Wb::Newline) => Decision::Break,
+            (Wb::Zwj, _) if tables::contains(c, tables::EXTENDED_PICTOGRAPHIC) => Decision::Keep,
+            (Wb::WSegSpace, Wb::WSegSpace) => Decision::Keep,
+            (_, Wb::Extend) | (_, Wb::Format) | (_, Wb::Zwj) => Decision::Ignore,
+            _ => decide(prev2, prev, next, regional),
+        };
+        if decision != Decision::Ignore {
+            if let Some((start, lookahead)) = pending.take() {
+                if !lookahead.accepts(next) {
+                    return Ok(start);
+                }
+            }
+        }
+        match decision {
+            Decision::Break => return Ok(index),
+            Decision::Ignore => (),
+            Decision::Keep | Decision::Lookahead(_) => {
+                if let Decision::Lookahead(lookahead) = decision {
+                    pending = Some((index, lookahead));
+                }
+                prev2 = Some(prev);
+                prev = next;
+                regional = if next == Wb::RegionalIndicator { regional + 1 } else { 0 };
+            },
+        }
+        raw = next;
+    }
+
+    match pending {
+        Some((start, _)) => Err(start),
+        // A line break always ends the segment (WB3a).
+        None if matches!(raw, Wb::Lf | Wb::Newline) => Ok(length),
+        None => Err(length),
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(s: &str) -> Vec<&str> {
+        let mut words = Vec::new();
+        let mut rest = s;
+        while !rest.is_empty() {
+            let end = word_end(rest.char_indices().map(|(i, c)| (i, Some(c))), rest.len()).unwrap_or_else(|end| end);
+            words.push(&rest[..end]);
+            rest = &rest[end..];
+        }
+        words
+    }
+
+    #[test]
+    fn word_end_test() {
+        assert_eq!(words("can't stop"), ["can't", " ", "stop"]);
+        assert_eq!(words("U.S.A."), ["U.S.A", "."]);
+        assert_eq!(words("3.14,1,000"), ["3.14,1,000"]);
+        assert_eq!(words("a1_b2"), ["a1_b2"]);
+        assert_eq!(words("can'"), ["can", "'"]);
+        assert_eq!(words("a:b"), ["a:b"]);
+        assert_eq!(words("a..b"), ["a", ".", ".", "b"]);
+        // Hebrew gershayim and geresh.
+        assert_eq!(words("צה\"ל"), ["צה\"ל"]);
+        assert_eq!(words("ג'"), ["ג'"]);
+        // Ideographs are each alone, but katakana join.
+        assert_eq!(words("日本語カタカナ"), ["日", "本", "語", "カタカナ"]);
+        // Spaces, line breaks, marks and emoji.
+        assert_eq!(words("a  \t b"), ["a", "  ", "\t", " ", "b"]);
+        assert_eq!(words("\r\n\n"), ["\r\n", "\n"]);
+        assert_eq!(words("e\u{301}t\u{200B}é"), ["e\u{301}t", "\u{200B}", "é"]);
+        assert_eq!(words("a\u{301}.\u{301}b"), ["a\u{301}.\u{301}b"]);
+        assert_eq!(words("👩\u{200D}👧!"), ["👩\u{200D}👧", "!"]);
+        assert_eq!(words("🇫🇷🇩🇪🇺"), ["🇫🇷", "🇩🇪", "🇺"]);
+    }
+
+    // The test cases of `WordBreakTest.txt`. The file is for Unicode
+    // 15.1, which made the prepended concatenation marks `Numeric`, so
+    // the cases with those are skipped.
+    #[test]
+    fn conformance_test() {
+        let data = include_str!("../scripts/data/WordBreakTest.txt");
+        let changed = ["06DD", "070F"];
+        let cases = data.lines()
+            .filter(|line| !line.starts_with('#'))
+            .filter(|line| !changed.iter().any(|code| line.contains(code)));
+        for line in cases {
+            let mut text = String::new();
+            let mut expected = Vec::new();
+            for token in line.split_whitespace() {
+                match token {
+                    "\u{F7}" if !text.is_empty() => expected.push(text.len()),
+                    "\u{F7}" | "\u{D7}" => (),
+                    code => text.push(core::char::from_u32(u32::from_str_radix(code, 16).unwrap()).unwrap()),
+                }
+            }
+            let actual: Vec<_> = words(&text).iter()
+                .scan(0, |end, word| {
+                    *end += word.len();
+                    Some(*end)
+                })
+                .collect();
+            assert_eq!(actual, expected, "{}", line);
+        }
+    }
+
+    #[test]
+    fn provisional_test() {
+        let end = |s: &str| word_end(s.char_indices().map(|(i, c)| (i, Some(c))), s.len());
+        assert_eq!(end("can't "), Ok(5));
+        assert_eq!(end("can'"), Err(3));
+        assert_eq!(end("can"), Err(3));
+        assert_eq!(end("a\n"), Ok(1));
+        assert_eq!(end("\n"), Ok(1));
+        assert_eq!(end(""), Err(0));
+    }
+}